@@ -41,33 +41,57 @@ pub fn bdsk_file_encode(relative_path: String) -> Option<String> {
     Some(STANDARD.encode(&buffer))
 }
 
-/// Extract all Bdsk-File paths from a fields map
-pub fn bdsk_file_extract_all(fields: HashMap<String, String>) -> Vec<String> {
-    let mut paths = Vec::new();
-
-    for (key, value) in fields {
-        if key.to_lowercase().starts_with("bdsk-file-") {
-            if let Some(path) = bdsk_file_decode(value) {
-                paths.push(path);
-            }
-        }
+/// Parse the numeric suffix of a `Bdsk-File-N` key (case-insensitive).
+///
+/// Returns `Some(None)` for a Bdsk-File key without a numeric suffix, and
+/// `None` for keys that are not Bdsk-File fields at all.
+fn bdsk_file_index(key: &str) -> Option<Option<u32>> {
+    const PREFIX: &str = "bdsk-file-";
+    // `get` rather than slicing: the prefix length may split a multibyte
+    // character in an arbitrary field name
+    let prefix = key.get(..PREFIX.len())?;
+    if !prefix.eq_ignore_ascii_case(PREFIX) {
+        return None;
     }
+    Some(key[PREFIX.len()..].parse().ok())
+}
 
-    paths.sort();
-    paths
+/// Extract all Bdsk-File paths from a fields map
+///
+/// Paths are returned in the numeric order of their `Bdsk-File-N` suffix
+/// (so `Bdsk-File-10` follows `Bdsk-File-2`). Gaps in the numbering are
+/// skipped; keys without a numeric suffix sort last, by key.
+pub fn bdsk_file_extract_all(fields: HashMap<String, String>) -> Vec<String> {
+    let mut indexed: Vec<(Option<u32>, String, String)> = fields
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let index = bdsk_file_index(&key)?;
+            let path = bdsk_file_decode(value)?;
+            Some((index, key, path))
+        })
+        .collect();
+
+    // `None` sorts before `Some`, so map un-numbered keys past every index
+    indexed.sort_by(|(a_idx, a_key, _), (b_idx, b_key, _)| {
+        (a_idx.is_none(), a_idx, a_key).cmp(&(b_idx.is_none(), b_idx, b_key))
+    });
+
+    indexed.into_iter().map(|(_, _, path)| path).collect()
 }
 
 /// Create Bdsk-File fields from a list of paths
+///
+/// Keys are numbered consecutively from `Bdsk-File-1` in list order. Empty
+/// paths are skipped without leaving a gap in the numbering, so the result
+/// round-trips through [`bdsk_file_extract_all`].
 pub fn bdsk_file_create_fields(paths: Vec<String>) -> HashMap<String, String> {
-    let mut fields = HashMap::new();
-
-    for (index, path) in paths.into_iter().enumerate() {
-        if let Some(encoded) = bdsk_file_encode(path) {
-            fields.insert(format!("Bdsk-File-{}", index + 1), encoded);
-        }
-    }
-
-    fields
+    paths
+        .into_iter()
+        .filter(|path| !path.trim().is_empty())
+        .filter_map(bdsk_file_encode)
+        .enumerate()
+        .map(|(index, encoded)| (format!("Bdsk-File-{}", index + 1), encoded))
+        .collect()
 }
 
 #[cfg(test)]
@@ -130,4 +154,83 @@ mod tests {
         assert_eq!(decoded1, Some("Papers/A.pdf".to_string()));
         assert_eq!(decoded2, Some("Papers/B.pdf".to_string()));
     }
+
+    #[test]
+    fn test_extract_all_numeric_order() {
+        // Lexicographic order of both keys and paths would differ from suffix order
+        let mut fields = HashMap::new();
+        for (index, path) in [
+            (10, "Papers/A.pdf"),
+            (2, "Papers/C.pdf"),
+            (1, "Papers/Z.pdf"),
+        ] {
+            fields.insert(
+                format!("Bdsk-File-{}", index),
+                bdsk_file_encode(path.to_string()).unwrap(),
+            );
+        }
+
+        let paths = bdsk_file_extract_all(fields);
+        assert_eq!(paths, vec!["Papers/Z.pdf", "Papers/C.pdf", "Papers/A.pdf"]);
+    }
+
+    #[test]
+    fn test_three_attachments_roundtrip_in_order() {
+        let paths = vec![
+            "Papers/Zwicky1933.pdf".to_string(),
+            "Supplements/data.zip".to_string(),
+            "Papers/Appendix.pdf".to_string(),
+        ];
+        let fields = bdsk_file_create_fields(paths.clone());
+        let mut keys: Vec<&String> = fields.keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["Bdsk-File-1", "Bdsk-File-2", "Bdsk-File-3"]);
+
+        assert_eq!(bdsk_file_extract_all(fields), paths);
+    }
+
+    #[test]
+    fn test_roundtrip_with_gaps() {
+        // Gapped numbering on input is renumbered consecutively on output
+        let mut fields = HashMap::new();
+        fields.insert(
+            "Bdsk-File-1".to_string(),
+            bdsk_file_encode("first.pdf".to_string()).unwrap(),
+        );
+        fields.insert(
+            "bdsk-file-4".to_string(),
+            bdsk_file_encode("second.pdf".to_string()).unwrap(),
+        );
+        fields.insert(
+            "Bdsk-File-7".to_string(),
+            bdsk_file_encode("third.pdf".to_string()).unwrap(),
+        );
+
+        let paths = bdsk_file_extract_all(fields);
+        assert_eq!(paths, vec!["first.pdf", "second.pdf", "third.pdf"]);
+
+        // Empty entries in a path list do not leave holes in the numbering
+        let mut with_blank = paths.clone();
+        with_blank.insert(1, String::new());
+        let recreated = bdsk_file_create_fields(with_blank);
+        assert_eq!(recreated.len(), 3);
+        assert!(recreated.contains_key("Bdsk-File-3"));
+        assert_eq!(bdsk_file_extract_all(recreated), paths);
+    }
+
+    #[test]
+    fn test_non_ascii_field_names_are_skipped() {
+        // Byte 10 falls inside the "é" of the first key
+        assert_eq!(bdsk_file_index("bdsk-fileé-1"), None);
+        assert_eq!(bdsk_file_index("Schlüsselwort"), None);
+        assert_eq!(bdsk_file_index("Bdsk-File-3"), Some(Some(3)));
+
+        let mut fields = HashMap::new();
+        fields.insert("bdsk-fileé-1".to_string(), "ignored".to_string());
+        fields.insert(
+            "Bdsk-File-1".to_string(),
+            bdsk_file_encode("Papers/A.pdf".to_string()).unwrap(),
+        );
+        assert_eq!(bdsk_file_extract_all(fields), vec!["Papers/A.pdf"]);
+    }
 }
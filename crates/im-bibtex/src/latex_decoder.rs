@@ -1,13 +1,16 @@
 //! LaTeX character decoding
 //!
-//! Decodes LaTeX special characters and commands to Unicode.
+//! Decodes LaTeX special characters and commands to Unicode, and encodes
+//! Unicode letters back to LaTeX for export.
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
 /// Decode LaTeX special characters to Unicode
 pub fn decode_latex(input: String) -> String {
-    let mut result = input;
+    // Accents and special letters first, so bare control words like \o
+    // are matched as whole words rather than as prefixes of \omega
+    let mut result = decode_accents(&input);
 
     // Combine all patterns and sort by length (longest first) to avoid partial matches
     let all_patterns = ALL_PATTERNS.iter();
@@ -28,127 +31,250 @@ pub fn decode_latex(input: String) -> String {
     result
 }
 
-// ===== Accent Patterns =====
+// ===== Accents and Special Letters =====
+
+/// Accented letters: (accent command, base letter, precomposed character).
+///
+/// Punctuation accents (`\"`, `\'`, `` \` ``, `\^`, `\~`, `\=`, `\.`) may be
+/// written `\"a` or `\"{a}`; letter accents (`\c`, `\v`, `\u`, `\H`, `\k`,
+/// `\r`, `\d`, `\b`, `\t`) need a brace or space: `\H{o}`, `\c c`. The first
+/// entry for a character is the one [`encode_latex`] emits.
+#[rustfmt::skip]
+const ACCENTED_LETTERS: &[(char, char, char)] = &[
+    // Umlaut (diaeresis)
+    ('"', 'a', 'ä'), ('"', 'A', 'Ä'), ('"', 'e', 'ë'), ('"', 'E', 'Ë'),
+    ('"', 'i', 'ï'), ('"', 'I', 'Ï'), ('"', 'o', 'ö'), ('"', 'O', 'Ö'),
+    ('"', 'u', 'ü'), ('"', 'U', 'Ü'), ('"', 'y', 'ÿ'), ('"', 'Y', 'Ÿ'),
+    // Acute accent
+    ('\'', 'a', 'á'), ('\'', 'A', 'Á'), ('\'', 'c', 'ć'), ('\'', 'C', 'Ć'),
+    ('\'', 'e', 'é'), ('\'', 'E', 'É'), ('\'', 'g', 'ǵ'), ('\'', 'G', 'Ǵ'),
+    ('\'', 'i', 'í'), ('\'', 'I', 'Í'), ('\'', 'l', 'ĺ'), ('\'', 'L', 'Ĺ'),
+    ('\'', 'n', 'ń'), ('\'', 'N', 'Ń'), ('\'', 'o', 'ó'), ('\'', 'O', 'Ó'),
+    ('\'', 'r', 'ŕ'), ('\'', 'R', 'Ŕ'), ('\'', 's', 'ś'), ('\'', 'S', 'Ś'),
+    ('\'', 'u', 'ú'), ('\'', 'U', 'Ú'), ('\'', 'y', 'ý'), ('\'', 'Y', 'Ý'),
+    ('\'', 'z', 'ź'), ('\'', 'Z', 'Ź'),
+    // Grave accent
+    ('`', 'a', 'à'), ('`', 'A', 'À'), ('`', 'e', 'è'), ('`', 'E', 'È'),
+    ('`', 'i', 'ì'), ('`', 'I', 'Ì'), ('`', 'n', 'ǹ'), ('`', 'N', 'Ǹ'),
+    ('`', 'o', 'ò'), ('`', 'O', 'Ò'), ('`', 'u', 'ù'), ('`', 'U', 'Ù'),
+    // Circumflex
+    ('^', 'a', 'â'), ('^', 'A', 'Â'), ('^', 'c', 'ĉ'), ('^', 'C', 'Ĉ'),
+    ('^', 'e', 'ê'), ('^', 'E', 'Ê'), ('^', 'g', 'ĝ'), ('^', 'G', 'Ĝ'),
+    ('^', 'h', 'ĥ'), ('^', 'H', 'Ĥ'), ('^', 'i', 'î'), ('^', 'I', 'Î'),
+    ('^', 'j', 'ĵ'), ('^', 'J', 'Ĵ'), ('^', 'o', 'ô'), ('^', 'O', 'Ô'),
+    ('^', 's', 'ŝ'), ('^', 'S', 'Ŝ'), ('^', 'u', 'û'), ('^', 'U', 'Û'),
+    ('^', 'w', 'ŵ'), ('^', 'W', 'Ŵ'), ('^', 'y', 'ŷ'), ('^', 'Y', 'Ŷ'),
+    // Tilde
+    ('~', 'a', 'ã'), ('~', 'A', 'Ã'), ('~', 'i', 'ĩ'), ('~', 'I', 'Ĩ'),
+    ('~', 'n', 'ñ'), ('~', 'N', 'Ñ'), ('~', 'o', 'õ'), ('~', 'O', 'Õ'),
+    ('~', 'u', 'ũ'), ('~', 'U', 'Ũ'),
+    // Macron
+    ('=', 'a', 'ā'), ('=', 'A', 'Ā'), ('=', 'e', 'ē'), ('=', 'E', 'Ē'),
+    ('=', 'i', 'ī'), ('=', 'I', 'Ī'), ('=', 'o', 'ō'), ('=', 'O', 'Ō'),
+    ('=', 'u', 'ū'), ('=', 'U', 'Ū'),
+    // Dot above
+    ('.', 'c', 'ċ'), ('.', 'C', 'Ċ'), ('.', 'e', 'ė'), ('.', 'E', 'Ė'),
+    ('.', 'g', 'ġ'), ('.', 'G', 'Ġ'), ('.', 'I', 'İ'), ('.', 'z', 'ż'),
+    ('.', 'Z', 'Ż'),
+    // Breve
+    ('u', 'a', 'ă'), ('u', 'A', 'Ă'), ('u', 'e', 'ĕ'), ('u', 'E', 'Ĕ'),
+    ('u', 'g', 'ğ'), ('u', 'G', 'Ğ'), ('u', 'i', 'ĭ'), ('u', 'I', 'Ĭ'),
+    ('u', 'o', 'ŏ'), ('u', 'O', 'Ŏ'), ('u', 'u', 'ŭ'), ('u', 'U', 'Ŭ'),
+    // Caron (háček)
+    ('v', 'c', 'č'), ('v', 'C', 'Č'), ('v', 'd', 'ď'), ('v', 'D', 'Ď'),
+    ('v', 'e', 'ě'), ('v', 'E', 'Ě'), ('v', 'n', 'ň'), ('v', 'N', 'Ň'),
+    ('v', 'r', 'ř'), ('v', 'R', 'Ř'), ('v', 's', 'š'), ('v', 'S', 'Š'),
+    ('v', 't', 'ť'), ('v', 'T', 'Ť'), ('v', 'z', 'ž'), ('v', 'Z', 'Ž'),
+    // Double acute (Hungarian umlaut)
+    ('H', 'o', 'ő'), ('H', 'O', 'Ő'), ('H', 'u', 'ű'), ('H', 'U', 'Ű'),
+    // Cedilla
+    ('c', 'c', 'ç'), ('c', 'C', 'Ç'), ('c', 'e', 'ȩ'), ('c', 'E', 'Ȩ'),
+    ('c', 'g', 'ģ'), ('c', 'G', 'Ģ'), ('c', 'k', 'ķ'), ('c', 'K', 'Ķ'),
+    ('c', 'l', 'ļ'), ('c', 'L', 'Ļ'), ('c', 'n', 'ņ'), ('c', 'N', 'Ņ'),
+    ('c', 'r', 'ŗ'), ('c', 'R', 'Ŗ'), ('c', 's', 'ş'), ('c', 'S', 'Ş'),
+    ('c', 't', 'ţ'), ('c', 'T', 'Ţ'),
+    // Ogonek
+    ('k', 'a', 'ą'), ('k', 'A', 'Ą'), ('k', 'e', 'ę'), ('k', 'E', 'Ę'),
+    ('k', 'i', 'į'), ('k', 'I', 'Į'), ('k', 'u', 'ų'), ('k', 'U', 'Ų'),
+    // Ring
+    ('r', 'a', 'å'), ('r', 'A', 'Å'), ('r', 'u', 'ů'), ('r', 'U', 'Ů'),
+    // Dot below
+    ('d', 'a', 'ạ'), ('d', 'A', 'Ạ'), ('d', 'e', 'ẹ'), ('d', 'E', 'Ẹ'),
+    ('d', 'o', 'ọ'), ('d', 'O', 'Ọ'),
+];
+
+/// Special letters written as bare control words: (command, character).
+///
+/// Longer names come first so the decoder's alternation prefers `\oe` over `\o`.
+#[rustfmt::skip]
+const SPECIAL_LETTERS: &[(&str, &str)] = &[
+    ("aa", "å"), ("AA", "Å"),
+    ("ae", "æ"), ("AE", "Æ"),
+    ("oe", "œ"), ("OE", "Œ"),
+    ("ss", "ß"), ("SS", "SS"),
+    ("dh", "ð"), ("DH", "Ð"),
+    ("dj", "đ"), ("DJ", "Đ"),
+    ("ng", "ŋ"), ("NG", "Ŋ"),
+    ("th", "þ"), ("TH", "Þ"),
+    ("o", "ø"), ("O", "Ø"),
+    ("l", "ł"), ("L", "Ł"),
+    ("i", "ı"), ("j", "ȷ"),
+];
+
+/// Combining mark for an accent command, used when no precomposed form exists.
+fn combining_mark(accent: char) -> Option<char> {
+    Some(match accent {
+        '"' => '\u{0308}',
+        '\'' => '\u{0301}',
+        '`' => '\u{0300}',
+        '^' => '\u{0302}',
+        '~' => '\u{0303}',
+        '=' => '\u{0304}',
+        '.' => '\u{0307}',
+        'u' => '\u{0306}',
+        'v' => '\u{030C}',
+        'H' => '\u{030B}',
+        'c' => '\u{0327}',
+        'k' => '\u{0328}',
+        'r' => '\u{030A}',
+        'd' => '\u{0323}',
+        'b' => '\u{0331}',
+        't' => '\u{0361}',
+        _ => return None,
+    })
+}
 
 lazy_static! {
-    static ref ACCENT_PATTERNS: Vec<(&'static str, &'static str)> = vec![
-        // Umlaut (diaeresis)
-        ("\\\"a", "ä"), ("\\\"A", "Ä"),
-        ("\\\"e", "ë"), ("\\\"E", "Ë"),
-        ("\\\"i", "ï"), ("\\\"I", "Ï"),
-        ("\\\"o", "ö"), ("\\\"O", "Ö"),
-        ("\\\"u", "ü"), ("\\\"U", "Ü"),
-        ("\\\"y", "ÿ"), ("\\\"Y", "Ÿ"),
-        ("\\\"{a}", "ä"), ("\\\"{A}", "Ä"),
-        ("\\\"{e}", "ë"), ("\\\"{E}", "Ë"),
-        ("\\\"{i}", "ï"), ("\\\"{I}", "Ï"),
-        ("\\\"{o}", "ö"), ("\\\"{O}", "Ö"),
-        ("\\\"{u}", "ü"), ("\\\"{U}", "Ü"),
-
-        // Acute accent
-        ("\\'a", "á"), ("\\'A", "Á"),
-        ("\\'e", "é"), ("\\'E", "É"),
-        ("\\'i", "í"), ("\\'I", "Í"),
-        ("\\'o", "ó"), ("\\'O", "Ó"),
-        ("\\'u", "ú"), ("\\'U", "Ú"),
-        ("\\'y", "ý"), ("\\'Y", "Ý"),
-        ("\\'{a}", "á"), ("\\'{A}", "Á"),
-        ("\\'{e}", "é"), ("\\'{E}", "É"),
-        ("\\'{i}", "í"), ("\\'{I}", "Í"),
-        ("\\'{\\i}", "í"), // acute on dotless i
-        ("\\'{o}", "ó"), ("\\'{O}", "Ó"),
-        ("\\'{u}", "ú"), ("\\'{U}", "Ú"),
-
-        // Grave accent
-        ("\\`a", "à"), ("\\`A", "À"),
-        ("\\`e", "è"), ("\\`E", "È"),
-        ("\\`i", "ì"), ("\\`I", "Ì"),
-        ("\\`o", "ò"), ("\\`O", "Ò"),
-        ("\\`u", "ù"), ("\\`U", "Ù"),
-        ("\\`{a}", "à"), ("\\`{A}", "À"),
-        ("\\`{e}", "è"), ("\\`{E}", "È"),
-        ("\\`{o}", "ò"), ("\\`{O}", "Ò"),
-        ("\\`{u}", "ù"), ("\\`{U}", "Ù"),
-
-        // Circumflex
-        ("\\^a", "â"), ("\\^A", "Â"),
-        ("\\^e", "ê"), ("\\^E", "Ê"),
-        ("\\^i", "î"), ("\\^I", "Î"),
-        ("\\^o", "ô"), ("\\^O", "Ô"),
-        ("\\^u", "û"), ("\\^U", "Û"),
-        ("\\^{a}", "â"), ("\\^{A}", "Â"),
-        ("\\^{e}", "ê"), ("\\^{E}", "Ê"),
-        ("\\^{o}", "ô"), ("\\^{O}", "Ô"),
-        ("\\^{u}", "û"), ("\\^{U}", "Û"),
-
-        // Tilde
-        ("\\~a", "ã"), ("\\~A", "Ã"),
-        ("\\~n", "ñ"), ("\\~N", "Ñ"),
-        ("\\~o", "õ"), ("\\~O", "Õ"),
-        ("\\~{a}", "ã"), ("\\~{A}", "Ã"),
-        ("\\~{n}", "ñ"), ("\\~{N}", "Ñ"),
-        ("\\~{o}", "õ"), ("\\~{O}", "Õ"),
-
-        // Cedilla
-        ("\\c c", "ç"), ("\\c C", "Ç"),
-        ("\\c{c}", "ç"), ("\\c{C}", "Ç"),
-
-        // Ring
-        ("\\r a", "å"), ("\\r A", "Å"),
-        ("\\r{a}", "å"), ("\\r{A}", "Å"),
-        ("\\aa", "å"), ("\\AA", "Å"),
-
-        // Caron (háček)
-        ("\\v c", "č"), ("\\v C", "Č"),
-        ("\\v s", "š"), ("\\v S", "Š"),
-        ("\\v z", "ž"), ("\\v Z", "Ž"),
-        ("\\v{c}", "č"), ("\\v{C}", "Č"),
-        ("\\v{s}", "š"), ("\\v{S}", "Š"),
-        ("\\v{z}", "ž"), ("\\v{Z}", "Ž"),
-
-        // Breve
-        ("\\u a", "ă"), ("\\u A", "Ă"),
-        ("\\u{a}", "ă"), ("\\u{A}", "Ă"),
-
-        // Macron
-        ("\\=a", "ā"), ("\\=A", "Ā"),
-        ("\\=e", "ē"), ("\\=E", "Ē"),
-        ("\\=i", "ī"), ("\\=I", "Ī"),
-        ("\\=o", "ō"), ("\\=O", "Ō"),
-        ("\\=u", "ū"), ("\\=U", "Ū"),
-
-        // Dot above
-        ("\\.z", "ż"), ("\\.Z", "Ż"),
-        ("\\.{z}", "ż"), ("\\.{Z}", "Ż"),
-
-        // Ogonek
-        ("\\k a", "ą"), ("\\k A", "Ą"),
-        ("\\k e", "ę"), ("\\k E", "Ę"),
-        ("\\k{a}", "ą"), ("\\k{A}", "Ą"),
-        ("\\k{e}", "ę"), ("\\k{E}", "Ę"),
-
-        // Stroke
-        ("\\l", "ł"), ("\\L", "Ł"),
-        ("\\o", "ø"), ("\\O", "Ø"),
-
-        // Dotless i
-        ("\\i", "ı"),
-        ("{\\i}", "ı"),
-    ];
+    static ref SPECIAL_LETTER_NAMES: String = SPECIAL_LETTERS
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join("|");
+
+    // {\ss}, {\AA}, ...
+    static ref BRACED_SPECIAL_LETTER: Regex =
+        Regex::new(&format!(r"\{{\\({})\}}", *SPECIAL_LETTER_NAMES)).unwrap();
+
+    // \ss, \AA{}, \o␣ (TeX swallows the space after a control word)
+    static ref SPECIAL_LETTER: Regex =
+        Regex::new(&format!(r"\\({})(?:\{{\}}|[ \t]+|\b)", *SPECIAL_LETTER_NAMES)).unwrap();
+
+    // \"a, \"{a}, \'{\i}
+    static ref PUNCT_ACCENT: Regex = Regex::new(
+        r#"\\(["'`^~=.])\s*(?:\{\s*(\\[ij]|[a-zA-Z])\s*\}|(\\[ij]\b|[a-zA-Z]))"#
+    )
+    .unwrap();
+
+    // \H{o}, \c c, \v{s}
+    static ref LETTER_ACCENT: Regex = Regex::new(
+        r"\\([cvuHkrdbt])(?:\s*\{\s*(\\[ij]|[a-zA-Z])\s*\}|[ \t]+(\\[ij]\b|[a-zA-Z]))"
+    )
+    .unwrap();
+}
+
+fn special_letter(name: &str) -> Option<&'static str> {
+    SPECIAL_LETTERS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, letter)| *letter)
+}
+
+fn accented_letter(accent: char, base: &str) -> String {
+    // Dotless \i and \j take accents as plain i and j
+    let base_char = match base {
+        "\\i" => 'i',
+        "\\j" => 'j',
+        other => other.chars().next().unwrap_or_default(),
+    };
+    if let Some(&(_, _, composed)) = ACCENTED_LETTERS
+        .iter()
+        .find(|(a, b, _)| *a == accent && *b == base_char)
+    {
+        return composed.to_string();
+    }
+    match combining_mark(accent) {
+        Some(mark) => format!("{}{}", base_char, mark),
+        None => base_char.to_string(),
+    }
+}
+
+fn decode_accents(input: &str) -> String {
+    let replace_accent = |caps: &regex::Captures| {
+        let accent = caps[1].chars().next().unwrap_or_default();
+        let base = caps
+            .get(2)
+            .or_else(|| caps.get(3))
+            .map_or("", |m| m.as_str());
+        accented_letter(accent, base)
+    };
+
+    let result = PUNCT_ACCENT.replace_all(input, replace_accent);
+    let result = LETTER_ACCENT.replace_all(&result, replace_accent);
+    let result = BRACED_SPECIAL_LETTER.replace_all(&result, |caps: &regex::Captures| {
+        special_letter(&caps[1]).unwrap_or_default().to_string()
+    });
+    SPECIAL_LETTER
+        .replace_all(&result, |caps: &regex::Captures| {
+            special_letter(&caps[1]).unwrap_or_default().to_string()
+        })
+        .into_owned()
+}
+
+/// Encode Unicode characters as LaTeX for BibTeX export.
+///
+/// The inverse of [`decode_latex`] for accented and special letters: `Å`
+/// becomes `{\AA}`, `ő` becomes `{\H{o}}`, `ß` becomes `{\ss}`. Dashes and a
+/// few text symbols are also encoded; ASCII text and characters without a
+/// LaTeX form pass through unchanged, so `decode_latex(encode_latex(s)) == s`
+/// for names and titles.
+pub fn encode_latex(input: String) -> String {
+    let mut result = String::with_capacity(input.len());
+    for c in input.chars() {
+        if c.is_ascii() {
+            result.push(c);
+        } else if let Some((name, _)) = SPECIAL_LETTERS
+            .iter()
+            .find(|(_, letter)| letter.chars().eq(std::iter::once(c)))
+        {
+            result.push_str(&format!("{{\\{}}}", name));
+        } else if let Some(&(accent, base, _)) = ACCENTED_LETTERS
+            .iter()
+            .find(|(_, _, composed)| *composed == c)
+        {
+            if accent.is_ascii_alphabetic() {
+                result.push_str(&format!("{{\\{}{{{}}}}}", accent, base));
+            } else {
+                result.push_str(&format!("{{\\{}{}}}", accent, base));
+            }
+        } else if let Some(encoded) = encode_symbol(c) {
+            result.push_str(encoded);
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn encode_symbol(c: char) -> Option<&'static str> {
+    Some(match c {
+        '—' => "---",
+        '–' => "--",
+        '…' => "{\\ldots}",
+        '§' => "{\\S}",
+        '¶' => "{\\P}",
+        '†' => "{\\dag}",
+        '‡' => "{\\ddag}",
+        '©' => "{\\copyright}",
+        '£' => "{\\pounds}",
+        '€' => "{\\euro}",
+        _ => return None,
+    })
 }
 
 // ===== Special Characters =====
 
 lazy_static! {
     static ref SPECIAL_CHARACTERS: Vec<(&'static str, &'static str)> = vec![
-        // Ligatures
-        ("\\ae", "æ"), ("\\AE", "Æ"),
-        ("\\oe", "œ"), ("\\OE", "Œ"),
-        ("\\ss", "ß"),
-        ("{\\ae}", "æ"), ("{\\AE}", "Æ"),
-        ("{\\oe}", "œ"), ("{\\OE}", "Œ"),
-        ("{\\ss}", "ß"),
-
         // Punctuation
         ("---", "—"),  // em dash
         ("--", "–"),   // en dash
@@ -244,11 +370,10 @@ lazy_static! {
     /// Combined patterns sorted by length (longest first) to avoid partial matches.
     static ref ALL_PATTERNS: Vec<(&'static str, &'static str)> = {
         let mut patterns: Vec<(&str, &str)> = Vec::new();
-        patterns.extend(ACCENT_PATTERNS.iter().copied());
         patterns.extend(SPECIAL_CHARACTERS.iter().copied());
         patterns.extend(MATH_SYMBOLS.iter().copied());
         // Sort by pattern length, longest first
-        patterns.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+        patterns.sort_by_key(|p| std::cmp::Reverse(p.0.len()));
         patterns
     };
 }
@@ -341,6 +466,68 @@ mod tests {
         assert_eq!(decode_latex(r#"caf\'{e}"#.to_string()), "café");
     }
 
+    #[test]
+    fn test_scandinavian_and_special_letters() {
+        assert_eq!(decode_latex(r#"\AA{}ngstr\"om"#.to_string()), "Ångström");
+        assert_eq!(decode_latex(r#"{\AA}ngstr{\"o}m"#.to_string()), "Ångström");
+        assert_eq!(decode_latex(r#"S{\o}ren"#.to_string()), "Søren");
+        assert_eq!(decode_latex(r#"Bj\o rn"#.to_string()), "Bjørn");
+        assert_eq!(decode_latex(r#"Stra{\ss}e"#.to_string()), "Straße");
+        assert_eq!(decode_latex(r#"Stra\ss e"#.to_string()), "Straße");
+        assert_eq!(decode_latex(r#"c\oe ur"#.to_string()), "cœur");
+        assert_eq!(decode_latex(r#"{\OE}uvre"#.to_string()), "Œuvre");
+    }
+
+    #[test]
+    fn test_letter_accents() {
+        assert_eq!(decode_latex(r#"Erd\H{o}s"#.to_string()), "Erdős");
+        assert_eq!(decode_latex(r#"Erd{\H o}s"#.to_string()), "Erdős");
+        assert_eq!(decode_latex(r#"Fran\c{c}ois"#.to_string()), "François");
+        assert_eq!(decode_latex(r#"Dvo\v{r}\'ak"#.to_string()), "Dvořák");
+        assert_eq!(decode_latex(r#"Ko\c{c}"#.to_string()), "Koç");
+        assert_eq!(decode_latex(r#"\'{\i}"#.to_string()), "í");
+        // No precomposed form: falls back to a combining mark
+        assert_eq!(decode_latex(r#"\d{x}"#.to_string()), "x\u{0323}");
+    }
+
+    #[test]
+    fn test_control_words_not_mangled() {
+        assert_eq!(decode_latex(r#"\omega"#.to_string()), "ω");
+        assert_eq!(decode_latex(r#"\infty"#.to_string()), "∞");
+        assert_eq!(decode_latex(r#"\lambda"#.to_string()), "λ");
+        assert_eq!(decode_latex(r#"\cite{x}"#.to_string()), "x");
+    }
+
+    #[test]
+    fn test_encode_latex() {
+        assert_eq!(encode_latex("Å".to_string()), r#"{\AA}"#);
+        assert_eq!(encode_latex("ø".to_string()), r#"{\o}"#);
+        assert_eq!(encode_latex("ß".to_string()), r#"{\ss}"#);
+        assert_eq!(encode_latex("ő".to_string()), r#"{\H{o}}"#);
+        assert_eq!(encode_latex("Müller".to_string()), r#"M{\"u}ller"#);
+        assert_eq!(encode_latex("plain ASCII".to_string()), "plain ASCII");
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        for name in [
+            "Ångström",
+            "Søren Kierkegaard",
+            "Øresund",
+            "Straße",
+            "Erdős Pál",
+            "Dvořák",
+            "Łukasiewicz",
+            "Æsir and Œuvre",
+            "Þórr",
+            "pages 1–10",
+        ] {
+            let encoded = encode_latex(name.to_string());
+            assert!(encoded.is_ascii(), "{} encoded to {}", name, encoded);
+            assert_eq!(decode_latex(encoded), name);
+        }
+    }
+
     #[test]
     fn test_special_characters() {
         assert_eq!(decode_latex(r#"10\% off"#.to_string()), "10% off");
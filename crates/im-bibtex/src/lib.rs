@@ -49,7 +49,7 @@ pub use parser::{parse, parse_entry, BibTeXParseError, BibTeXParseResult, ParseE
 
 // LaTeX and journal macro functions
 pub use journal_macros::{expand_journal_macro, get_all_journal_macro_names, is_journal_macro};
-pub use latex_decoder::decode_latex;
+pub use latex_decoder::{decode_latex, encode_latex};
 
 // BibDesk file reference codec
 pub use bdsk_file::{
@@ -22,7 +22,7 @@ pub enum BibSyncAction {
 pub enum AutomationCommand {
     /// Search across all configured sources
     Search(SearchCommand),
-    /// Import publications from URL or content
+    /// Import publications from a URL, local file, or inline content
     Import(ImportCommand),
     /// Open a publication by identifier
     Open(OpenCommand),
@@ -51,9 +51,16 @@ pub struct SearchCommand {
     pub auto_import: bool,
 }
 
+/// Import request
+///
+/// `path` takes precedence over `content`: when a URL carries both, the
+/// inline content is dropped at parse time so callers only ever see one
+/// local source.
 #[derive(uniffi::Record, Clone, Debug, PartialEq)]
 pub struct ImportCommand {
     pub url: Option<String>,
+    /// Local file to import (e.g. a .bib file)
+    pub path: Option<String>,
     pub content: Option<String>,
     pub format: Option<String>,
     pub library: Option<String>,
@@ -199,12 +206,21 @@ fn parse_search_command(params: &HashMap<String, String>) -> AutomationCommand {
 }
 
 fn parse_import_command(params: &HashMap<String, String>) -> AutomationCommand {
-    AutomationCommand::Import(ImportCommand {
-        url: params.get("url").cloned(),
-        content: params
+    let path = params.get("path").or_else(|| params.get("file")).cloned();
+    // A file path wins over inline content
+    let content = if path.is_some() {
+        None
+    } else {
+        params
             .get("content")
             .or_else(|| params.get("data"))
-            .cloned(),
+            .cloned()
+    };
+
+    AutomationCommand::Import(ImportCommand {
+        url: params.get("url").cloned(),
+        path,
+        content,
         format: params.get("format").or_else(|| params.get("fmt")).cloned(),
         library: params.get("library").or_else(|| params.get("lib")).cloned(),
     })
@@ -338,6 +354,27 @@ pub fn build_search_url(query: String, source: Option<String>, max_results: Opti
     build_search_url_internal(query, source, max_results)
 }
 
+pub fn build_import_url_internal(
+    path: String,
+    format: Option<String>,
+    library: Option<String>,
+) -> String {
+    let mut url = format!("imbib://import?path={}", urlencoding::encode(&path));
+    if let Some(fmt) = format {
+        url.push_str(&format!("&format={}", urlencoding::encode(&fmt)));
+    }
+    if let Some(lib) = library {
+        url.push_str(&format!("&library={}", urlencoding::encode(&lib)));
+    }
+    url
+}
+
+#[cfg(feature = "native")]
+#[uniffi::export]
+pub fn build_import_url(path: String, format: Option<String>, library: Option<String>) -> String {
+    build_import_url_internal(path, format, library)
+}
+
 pub fn build_open_url_internal(
     doi: Option<String>,
     arxiv_id: Option<String>,
@@ -513,6 +550,48 @@ mod tests {
         assert!(url.contains("max=20"));
     }
 
+    #[test]
+    fn test_roundtrip_import_path() {
+        let path = "/Users/me/Papers/My Library & Notes/refs.bib";
+        let url = build_import_url(path.to_string(), Some("bibtex".to_string()), None);
+        assert!(url.starts_with("imbib://import?path=%2FUsers%2Fme%2FPapers"));
+        assert!(!url.contains(' '));
+
+        let result = parse_url_command(url);
+        assert!(result.error.is_none());
+        match result.command {
+            Some(AutomationCommand::Import(cmd)) => {
+                assert_eq!(cmd.path.as_deref(), Some(path));
+                assert_eq!(cmd.format.as_deref(), Some("bibtex"));
+                assert_eq!(cmd.library, None);
+            }
+            _ => panic!("Roundtrip failed for import"),
+        }
+    }
+
+    #[test]
+    fn test_parse_import_path_wins_over_content() {
+        let result = parse_url_command(
+            "imbib://import?content=%40article%7Bx%2C%7D&path=%2Ftmp%2Frefs.bib".to_string(),
+        );
+        match result.command {
+            Some(AutomationCommand::Import(cmd)) => {
+                assert_eq!(cmd.path.as_deref(), Some("/tmp/refs.bib"));
+                assert_eq!(cmd.content, None);
+            }
+            _ => panic!("Expected import command"),
+        }
+
+        let result = parse_url_command("imbib://import?content=%40article%7Bx%2C%7D".to_string());
+        match result.command {
+            Some(AutomationCommand::Import(cmd)) => {
+                assert_eq!(cmd.path, None);
+                assert_eq!(cmd.content.as_deref(), Some("@article{x,}"));
+            }
+            _ => panic!("Expected import command"),
+        }
+    }
+
     #[test]
     fn test_parse_invalid_scheme() {
         let result = parse_url_command("https://example.com".to_string());
@@ -80,7 +80,7 @@ pub fn parse_author_string(input: String) -> Vec<Author> {
 }
 
 /// Parse a single author string into an Author struct
-fn parse_single_author(input: &str) -> Author {
+pub(crate) fn parse_single_author(input: &str) -> Author {
     let trimmed = input.trim();
     if trimmed.is_empty() {
         return Author::new("Unknown".to_string());
@@ -0,0 +1,192 @@
+//! Author name aliasing
+//!
+//! Large libraries accumulate variants of the same author's name
+//! ("P. A. M. Dirac", "Paul Dirac", "Dirac, P."). The alias store maps each
+//! registered variant to one canonical [`Author`] so author-centric views can
+//! treat them as a single person. Canonical authors are keyed by ORCID when
+//! they have one, otherwise by their normalized name.
+
+use super::author::{parse_single_author, Author};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Maps author name variants to canonical authors
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AuthorAliasStore {
+    /// Canonical key -> canonical author
+    canonical: HashMap<String, Author>,
+    /// Normalized variant name -> canonical key
+    aliases: HashMap<String, String>,
+}
+
+impl AuthorAliasStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `variant` as another name for `canonical`
+    ///
+    /// The canonical author's own name is registered too, and re-registering
+    /// an existing canonical author (same ORCID or name) keeps the first
+    /// record.
+    pub fn register_alias(&mut self, variant: &str, canonical: &Author) {
+        let key = canonical_key(canonical);
+        self.canonical
+            .entry(key.clone())
+            .or_insert_with(|| canonical.clone());
+        self.aliases
+            .insert(normalize_name(&canonical.display_name()), key.clone());
+        self.aliases.insert(
+            normalize_name(&parse_single_author(variant).display_name()),
+            key,
+        );
+    }
+
+    /// Resolve a name to its canonical author
+    ///
+    /// Names that haven't been registered are parsed as a new author.
+    pub fn canonicalize(&self, name: &str) -> Author {
+        self.canonicalize_author(&parse_single_author(name))
+    }
+
+    /// Resolve an author record to its canonical author
+    ///
+    /// An ORCID match takes precedence over the name, and a record whose
+    /// ORCID differs from the canonical author's never matches by name.
+    pub fn canonicalize_author(&self, author: &Author) -> Author {
+        self.lookup(author)
+            .and_then(|key| self.canonical.get(&key))
+            .cloned()
+            .unwrap_or_else(|| author.clone())
+    }
+
+    /// Key identifying the person an author record refers to
+    ///
+    /// Records with the same key are the same person: the canonical key for
+    /// registered variants, otherwise the record's own ORCID or normalized
+    /// name.
+    pub fn key(&self, author: &Author) -> String {
+        self.lookup(author).unwrap_or_else(|| canonical_key(author))
+    }
+
+    /// Number of canonical authors
+    pub fn len(&self) -> usize {
+        self.canonical.len()
+    }
+
+    /// Whether no aliases have been registered
+    pub fn is_empty(&self) -> bool {
+        self.canonical.is_empty()
+    }
+
+    fn lookup(&self, author: &Author) -> Option<String> {
+        let orcid = author.orcid.as_deref().and_then(normalize_orcid);
+        if let Some(orcid) = &orcid {
+            let key = format!("orcid:{}", orcid);
+            if self.canonical.contains_key(&key) {
+                return Some(key);
+            }
+        }
+        let key = self.aliases.get(&normalize_name(&author.display_name()))?;
+
+        // A shared name doesn't make two different ORCID holders one person
+        let candidate_orcid = self
+            .canonical
+            .get(key)
+            .and_then(|c| c.orcid.as_deref())
+            .and_then(normalize_orcid);
+        match (orcid, candidate_orcid) {
+            (Some(orcid), Some(candidate)) if orcid != candidate => None,
+            _ => Some(key.clone()),
+        }
+    }
+}
+
+/// Key for a canonical author: ORCID when present, otherwise the name
+fn canonical_key(author: &Author) -> String {
+    match author.orcid.as_deref().and_then(normalize_orcid) {
+        Some(orcid) => format!("orcid:{}", orcid),
+        None => format!("name:{}", normalize_name(&author.display_name())),
+    }
+}
+
+/// Normalize a name for comparison: lowercase, no periods, single spaces
+fn normalize_name(name: &str) -> String {
+    name.replace('.', " ")
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Strip any URL prefix from an ORCID and uppercase the checksum digit
+fn normalize_orcid(orcid: &str) -> Option<String> {
+    let id = orcid.trim().trim_end_matches('/');
+    let id = id.rsplit('/').next().unwrap_or(id).to_uppercase();
+    (!id.is_empty()).then_some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dirac() -> Author {
+        Author::new("Dirac".to_string())
+            .with_given_name("Paul Adrien Maurice")
+            .with_orcid("https://orcid.org/0000-0000-0000-000x")
+    }
+
+    #[test]
+    fn test_canonicalize_registered_variants() {
+        let mut store = AuthorAliasStore::new();
+        store.register_alias("P. A. M. Dirac", &dirac());
+        store.register_alias("Paul Dirac", &dirac());
+
+        assert_eq!(store.len(), 1);
+        for name in ["P. A. M. Dirac", "Dirac, P. A. M.", "paul dirac"] {
+            let author = store.canonicalize(name);
+            assert_eq!(author.given_name.as_deref(), Some("Paul Adrien Maurice"));
+        }
+
+        let unregistered = store.canonicalize("P. Dirac");
+        assert_eq!(unregistered.given_name.as_deref(), Some("P."));
+    }
+
+    #[test]
+    fn test_orcid_takes_precedence() {
+        let mut store = AuthorAliasStore::new();
+        store.register_alias("Paul Dirac", &dirac());
+
+        let record = Author::new("Dirac".to_string())
+            .with_given_name("P.")
+            .with_orcid("0000-0000-0000-000X");
+        assert_eq!(store.key(&record), store.key(&dirac()));
+        assert_eq!(store.key(&record), "orcid:0000-0000-0000-000X");
+    }
+
+    #[test]
+    fn test_same_name_different_orcids_stay_apart() {
+        let mut store = AuthorAliasStore::new();
+        store.register_alias("J. Smith", &smith("0000-0001-1111-1111"));
+
+        // Same name, but an ORCID that was never registered
+        let other = smith("0000-0002-2222-2222");
+        assert_eq!(store.key(&other), "orcid:0000-0002-2222-2222");
+        assert_eq!(store.canonicalize_author(&other).orcid, other.orcid);
+
+        // Records without an ORCID still resolve by name
+        let unidentified = Author::new("Smith".to_string()).with_given_name("John");
+        assert_eq!(store.key(&unidentified), "orcid:0000-0001-1111-1111");
+        assert_eq!(
+            store.key(&smith("0000-0001-1111-1111")),
+            store.key(&unidentified)
+        );
+    }
+
+    fn smith(orcid: &str) -> Author {
+        Author::new("Smith".to_string())
+            .with_given_name("John")
+            .with_orcid(orcid)
+    }
+}
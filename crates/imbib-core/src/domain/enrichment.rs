@@ -3,7 +3,9 @@
 //! Types for publication enrichment from external sources like Semantic Scholar,
 //! OpenAlex, and ADS.
 
+use super::{Author, AuthorAliasStore, Identifiers, Publication, Source};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Open access availability status
 #[derive(
@@ -65,6 +67,44 @@ pub struct AuthorStats {
     pub affiliations: Vec<String>,
 }
 
+impl AuthorStats {
+    /// Aggregate author occurrences (one per authored paper) into per-person stats.
+    ///
+    /// Occurrences are merged when `aliases` resolves them to the same person
+    /// (see [`AuthorAliasStore::key`]); unregistered name variants stay
+    /// separate. Each entry's `author_id` is that key and `name` is the
+    /// canonical display name. Entries are in order of first appearance.
+    pub fn aggregate(authors: &[Author], aliases: &AuthorAliasStore) -> Vec<AuthorStats> {
+        let mut stats: Vec<AuthorStats> = Vec::new();
+        let mut index_by_key: HashMap<String, usize> = HashMap::new();
+
+        for author in authors {
+            let key = aliases.key(author);
+            let index = *index_by_key.entry(key.clone()).or_insert_with(|| {
+                stats.push(AuthorStats {
+                    author_id: key,
+                    name: aliases.canonicalize_author(author).display_name(),
+                    h_index: None,
+                    citation_count: None,
+                    paper_count: Some(0),
+                    affiliations: Vec::new(),
+                });
+                stats.len() - 1
+            });
+
+            let entry = &mut stats[index];
+            entry.paper_count = entry.paper_count.map(|n| n + 1);
+            if let Some(affiliation) = &author.affiliation {
+                if !entry.affiliations.contains(affiliation) {
+                    entry.affiliations.push(affiliation.clone());
+                }
+            }
+        }
+
+        stats
+    }
+}
+
 /// Enrichment data for a publication
 #[derive(Debug, Clone, Serialize, Deserialize, uniffi::Record)]
 pub struct EnrichmentData {
@@ -175,6 +215,213 @@ pub fn enrichment_capability_display_name(capability: EnrichmentCapability) -> S
     enrichment_capability_display_name_internal(capability)
 }
 
+/// When a source last provided enrichment for a publication
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct EnrichmentFetch {
+    /// Enriched publication
+    pub publication_id: String,
+    /// Source that was queried
+    pub source: Source,
+    /// Unix timestamp of the fetch
+    pub fetched_at_unix: i64,
+}
+
+/// A publication whose enrichment for one capability is missing or stale
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct EnrichmentTask {
+    /// Publication to enrich
+    pub publication_id: String,
+    /// Capability to fetch
+    pub capability: EnrichmentCapability,
+    /// How urgently the task should run
+    pub priority: EnrichmentPriority,
+    /// Sources that can provide the capability for this publication
+    pub sources: Vec<Source>,
+    /// Fraction of core metadata fields present (0.0 to 1.0)
+    pub completeness: f64,
+}
+
+/// Build an enrichment work queue for one capability across a library.
+///
+/// A publication is queued when the capability's data is missing (as
+/// [`EnrichmentPriority::LibraryPaper`]) or when no source that provides the
+/// capability has fetched it within `threshold_days` (as
+/// [`EnrichmentPriority::BackgroundSync`]). Fetch times come from `fetches`,
+/// plus the publication's `enrichment_date` for its `enrichment_source`; a
+/// recent fetch from a source that can't provide the capability doesn't make
+/// it fresh. Publications without an identifier that any source can use for
+/// the capability are skipped. Tasks are ordered by priority, then least
+/// complete first.
+pub(crate) fn scan_stale_enrichment_internal(
+    pubs: &[Publication],
+    fetches: &[EnrichmentFetch],
+    threshold_days: i32,
+    capability: EnrichmentCapability,
+) -> Vec<EnrichmentTask> {
+    let now = chrono::Utc::now().timestamp();
+    let threshold_seconds = (threshold_days as i64) * 24 * 60 * 60;
+
+    let mut tasks: Vec<EnrichmentTask> = pubs
+        .iter()
+        .filter_map(|publication| {
+            let sources = capability_sources(&publication.identifiers, capability);
+            if sources.is_empty() {
+                return None;
+            }
+
+            let priority = if !has_capability_data(publication, capability) {
+                EnrichmentPriority::LibraryPaper
+            } else {
+                let last_fetch = sources
+                    .iter()
+                    .filter_map(|source| last_fetched(publication, source, fetches))
+                    .max();
+                match last_fetch {
+                    Some(fetched_at) if now - fetched_at <= threshold_seconds => return None,
+                    _ => EnrichmentPriority::BackgroundSync,
+                }
+            };
+
+            Some(EnrichmentTask {
+                publication_id: publication.id.clone(),
+                capability,
+                priority,
+                sources,
+                completeness: completeness(publication),
+            })
+        })
+        .collect();
+
+    tasks.sort_by(|a, b| {
+        a.priority.cmp(&b.priority).then(
+            a.completeness
+                .partial_cmp(&b.completeness)
+                .unwrap_or(std::cmp::Ordering::Equal),
+        )
+    });
+    tasks
+}
+
+#[cfg(feature = "native")]
+#[uniffi::export]
+pub fn scan_stale_enrichment(
+    publications: Vec<Publication>,
+    fetches: Vec<EnrichmentFetch>,
+    threshold_days: i32,
+    capability: EnrichmentCapability,
+) -> Vec<EnrichmentTask> {
+    scan_stale_enrichment_internal(&publications, &fetches, threshold_days, capability)
+}
+
+/// Unix time a source last enriched a publication, if ever
+fn last_fetched(
+    publication: &Publication,
+    source: &Source,
+    fetches: &[EnrichmentFetch],
+) -> Option<i64> {
+    let recorded = fetches
+        .iter()
+        .filter(|fetch| fetch.publication_id == publication.id && fetch.source == *source)
+        .map(|fetch| fetch.fetched_at_unix);
+    let legacy = publication
+        .enrichment_source
+        .as_deref()
+        .filter(|name| name.eq_ignore_ascii_case(source.as_str()))
+        .and(publication.enrichment_date.as_deref())
+        .and_then(parse_timestamp);
+    recorded.chain(legacy).max()
+}
+
+/// Whether a publication already holds data for a capability.
+///
+/// Capabilities with no field on `Publication` count as present once the
+/// publication has been enriched at all.
+fn has_capability_data(publication: &Publication, capability: EnrichmentCapability) -> bool {
+    match capability {
+        EnrichmentCapability::CitationCount => publication.citation_count.is_some(),
+        EnrichmentCapability::References => publication.reference_count.is_some(),
+        EnrichmentCapability::Abstract => publication.abstract_text.is_some(),
+        EnrichmentCapability::PdfUrl => !publication.linked_files.is_empty(),
+        EnrichmentCapability::Venue => {
+            publication.journal.is_some() || publication.booktitle.is_some()
+        }
+        EnrichmentCapability::Citations
+        | EnrichmentCapability::AuthorStats
+        | EnrichmentCapability::OpenAccess => publication.enrichment_date.is_some(),
+    }
+}
+
+/// Sources that can provide a capability given the available identifiers
+fn capability_sources(identifiers: &Identifiers, capability: EnrichmentCapability) -> Vec<Source> {
+    use EnrichmentCapability as C;
+
+    let mut sources = Vec::new();
+    let mut add = |candidates: &[Source]| {
+        for source in candidates {
+            if !sources.contains(source) {
+                sources.push(source.clone());
+            }
+        }
+    };
+
+    if identifiers.doi.is_some() {
+        match capability {
+            C::CitationCount | C::References => {
+                add(&[Source::SemanticScholar, Source::OpenAlex, Source::Crossref])
+            }
+            C::Citations | C::Abstract | C::AuthorStats => {
+                add(&[Source::SemanticScholar, Source::OpenAlex])
+            }
+            C::PdfUrl | C::OpenAccess => add(&[Source::OpenAlex]),
+            C::Venue => add(&[Source::Crossref, Source::OpenAlex]),
+        }
+    }
+    if identifiers.arxiv_id.is_some() {
+        match capability {
+            C::Abstract | C::PdfUrl | C::OpenAccess => add(&[Source::ArXiv]),
+            C::CitationCount | C::References | C::Citations | C::AuthorStats => {
+                add(&[Source::SemanticScholar])
+            }
+            C::Venue => {}
+        }
+    }
+    if identifiers.bibcode.is_some() && capability != C::OpenAccess {
+        add(&[Source::ADS]);
+    }
+    if identifiers.pmid.is_some() && matches!(capability, C::Abstract | C::Venue) {
+        add(&[Source::PubMed]);
+    }
+    if identifiers.pmcid.is_some() && matches!(capability, C::PdfUrl | C::OpenAccess) {
+        add(&[Source::PubMed]);
+    }
+
+    sources
+}
+
+/// Fraction of core metadata fields present on a publication
+fn completeness(publication: &Publication) -> f64 {
+    let fields = [
+        !publication.authors.is_empty(),
+        publication.year.is_some(),
+        publication.abstract_text.is_some(),
+        publication.journal.is_some() || publication.booktitle.is_some(),
+        publication.citation_count.is_some(),
+        !publication.linked_files.is_empty(),
+    ];
+    fields.iter().filter(|&&present| present).count() as f64 / fields.len() as f64
+}
+
+/// Parse an ISO 8601 timestamp or date into Unix seconds
+fn parse_timestamp(value: &str) -> Option<i64> {
+    if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.timestamp());
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|datetime| datetime.and_utc().timestamp())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,6 +444,117 @@ mod tests {
         assert!(EnrichmentPriority::RecentlyViewed < EnrichmentPriority::LibraryPaper);
     }
 
+    #[test]
+    fn test_author_stats_aggregate_with_aliases() {
+        let dirac = Author::new("Dirac".to_string()).with_given_name("Paul Adrien Maurice");
+        let mut aliases = AuthorAliasStore::new();
+        aliases.register_alias("P. A. M. Dirac", &dirac);
+        aliases.register_alias("Paul Dirac", &dirac);
+
+        let authors: Vec<Author> = [
+            "P. A. M. Dirac",
+            "Paul Dirac",
+            "Dirac, P. A. M.",
+            "P. Dirac",
+        ]
+        .iter()
+        .map(|name| crate::domain::author::parse_single_author(name))
+        .collect();
+
+        let stats = AuthorStats::aggregate(&authors, &aliases);
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].name, "Paul Adrien Maurice Dirac");
+        assert_eq!(stats[0].paper_count, Some(3));
+        // "P. Dirac" isn't registered yet, so it stays separate
+        assert_eq!(stats[1].name, "P. Dirac");
+        assert_eq!(stats[1].paper_count, Some(1));
+
+        aliases.register_alias("P. Dirac", &dirac);
+        let stats = AuthorStats::aggregate(&authors, &aliases);
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].paper_count, Some(4));
+    }
+
+    fn publication(
+        id: &str,
+        citation_count: Option<i32>,
+        enriched_days_ago: Option<i64>,
+    ) -> Publication {
+        let mut publication =
+            Publication::new(id.to_string(), "article".to_string(), "A paper".to_string());
+        publication.id = id.to_string();
+        publication.identifiers.doi = Some(format!("10.1000/{}", id));
+        publication.citation_count = citation_count;
+        publication.enrichment_source = Some("semanticscholar".to_string());
+        publication.enrichment_date = enriched_days_ago
+            .map(|days| (chrono::Utc::now() - chrono::Duration::days(days)).to_rfc3339());
+        publication
+    }
+
+    fn fetch(id: &str, source: Source, days_ago: i64) -> EnrichmentFetch {
+        EnrichmentFetch {
+            publication_id: id.to_string(),
+            source,
+            fetched_at_unix: (chrono::Utc::now() - chrono::Duration::days(days_ago)).timestamp(),
+        }
+    }
+
+    #[test]
+    fn test_scan_stale_enrichment() {
+        let mut unresolvable = publication("unresolvable", None, None);
+        unresolvable.identifiers.doi = None;
+
+        let pubs = vec![
+            publication("fresh", Some(10), Some(1)),
+            publication("stale", Some(10), Some(60)),
+            publication("missing", None, None),
+            unresolvable,
+        ];
+
+        let tasks =
+            scan_stale_enrichment_internal(&pubs, &[], 30, EnrichmentCapability::CitationCount);
+        let ids: Vec<&str> = tasks.iter().map(|t| t.publication_id.as_str()).collect();
+        assert_eq!(ids, ["missing", "stale"]);
+
+        assert_eq!(tasks[0].priority, EnrichmentPriority::LibraryPaper);
+        assert_eq!(tasks[1].priority, EnrichmentPriority::BackgroundSync);
+        assert!(tasks[1].sources.contains(&Source::SemanticScholar));
+    }
+
+    #[test]
+    fn test_scan_stale_enrichment_per_source() {
+        let mut venue_only = publication("venue-only", None, None);
+        venue_only.abstract_text = Some("An abstract".to_string());
+        venue_only.enrichment_source = None;
+        let mut refreshed = venue_only.clone();
+        refreshed.id = "refreshed".to_string();
+        let pubs = vec![venue_only, refreshed];
+
+        let fetches = vec![
+            // Crossref can't provide abstracts, so this fetch doesn't count
+            fetch("venue-only", Source::Crossref, 1),
+            fetch("venue-only", Source::SemanticScholar, 90),
+            fetch("refreshed", Source::SemanticScholar, 90),
+            fetch("refreshed", Source::OpenAlex, 2),
+        ];
+
+        let tasks =
+            scan_stale_enrichment_internal(&pubs, &fetches, 30, EnrichmentCapability::Abstract);
+        let ids: Vec<&str> = tasks.iter().map(|t| t.publication_id.as_str()).collect();
+        assert_eq!(ids, ["venue-only"]);
+        assert_eq!(tasks[0].priority, EnrichmentPriority::BackgroundSync);
+
+        // The publication-wide date only counts for the source that set it
+        let mut legacy = publication("legacy", Some(10), Some(1));
+        legacy.enrichment_source = Some("crossref".to_string());
+        legacy.identifiers.doi = None;
+        legacy.identifiers.arxiv_id = Some("2101.00001".to_string());
+        let tasks =
+            scan_stale_enrichment_internal(&[legacy], &[], 30, EnrichmentCapability::CitationCount);
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].sources, vec![Source::SemanticScholar]);
+    }
+
     #[test]
     fn test_enrichment_capability_display_names() {
         assert_eq!(
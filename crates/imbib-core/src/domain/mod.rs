@@ -7,6 +7,7 @@
 
 // Local modules with uniffi attributes
 mod author;
+mod author_alias;
 mod collection;
 mod enrichment;
 mod identifiers;
@@ -21,9 +22,11 @@ mod validation;
 // Re-export all types from local modules
 pub use author::{parse_author_string, Author};
 pub(crate) use author::parse_author_string_internal;
+pub use author_alias::AuthorAliasStore;
 pub use collection::Collection;
 pub use enrichment::{
-    AuthorStats, EnrichmentCapability, EnrichmentData, EnrichmentPriority, OpenAccessStatus,
+    scan_stale_enrichment, AuthorStats, EnrichmentCapability, EnrichmentData, EnrichmentFetch,
+    EnrichmentPriority, EnrichmentTask, OpenAccessStatus,
 };
 pub use identifiers::Identifiers;
 pub use library::Library;
@@ -11,6 +11,19 @@ pub enum ExportFormat {
     RIS,
 }
 
+/// Entry order for multi-publication exports
+#[derive(uniffi::Enum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExportSortOrder {
+    /// Keep the order publications were passed in
+    #[default]
+    Input,
+    CiteKey,
+    /// Oldest first; publications without a year go last
+    Year,
+    /// First author's family name, then year
+    Author,
+}
+
 /// Options for export
 #[derive(uniffi::Record, Clone, Debug)]
 pub struct ExportOptions {
@@ -18,6 +31,10 @@ pub struct ExportOptions {
     pub include_keywords: bool,
     pub include_extra_fields: bool,
     pub sort_fields: bool,
+    /// Entry order for `export_bibtex_multiple`
+    pub sort_by: ExportSortOrder,
+    /// Group entries by entry type under `% === Articles ===` style headers
+    pub group_by_type: bool,
 }
 
 impl Default for ExportOptions {
@@ -27,6 +44,8 @@ impl Default for ExportOptions {
             include_keywords: true,
             include_extra_fields: true,
             sort_fields: false,
+            sort_by: ExportSortOrder::Input,
+            group_by_type: false,
         }
     }
 }
@@ -50,11 +69,101 @@ pub fn export_bibtex(publication: &Publication, options: &ExportOptions) -> Stri
 #[cfg(feature = "native")]
 #[uniffi::export]
 pub fn export_bibtex_multiple(publications: Vec<Publication>, options: &ExportOptions) -> String {
-    let entries: Vec<BibTeXEntry> = publications
+    let mut publications = publications;
+    sort_publications(&mut publications, options.sort_by);
+
+    let to_entries = |pubs: &[&Publication]| -> Vec<BibTeXEntry> {
+        pubs.iter()
+            .map(|p| filter_entry(publication_to_bibtex_entry(p), options))
+            .collect()
+    };
+
+    if !options.group_by_type {
+        let all: Vec<&Publication> = publications.iter().collect();
+        return crate::bibtex_format_entries(to_entries(&all));
+    }
+
+    // Groups in alphabetical order of entry type; entries keep the sort order
+    let mut groups: Vec<(String, Vec<&Publication>)> = Vec::new();
+    for publication in &publications {
+        let entry_type = publication.entry_type.to_lowercase();
+        match groups.iter_mut().find(|(t, _)| *t == entry_type) {
+            Some((_, members)) => members.push(publication),
+            None => groups.push((entry_type, vec![publication])),
+        }
+    }
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+    groups
         .iter()
-        .map(|p| filter_entry(publication_to_bibtex_entry(p), options))
-        .collect();
-    crate::bibtex_format_entries(entries)
+        .map(|(entry_type, members)| {
+            format!(
+                "% === {} ===\n\n{}",
+                entry_type_group_label(entry_type),
+                crate::bibtex_format_entries(to_entries(members))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn sort_publications(publications: &mut [Publication], order: ExportSortOrder) {
+    let first_author = |p: &Publication| {
+        p.authors
+            .first()
+            .map(|a| a.family_name.to_lowercase())
+            .unwrap_or_default()
+    };
+    // Missing years sort after every real year
+    let year = |p: &Publication| p.year.unwrap_or(i32::MAX);
+
+    match order {
+        ExportSortOrder::Input => {}
+        ExportSortOrder::CiteKey => {
+            publications.sort_by(|a, b| a.cite_key.cmp(&b.cite_key));
+        }
+        ExportSortOrder::Year => {
+            publications.sort_by(|a, b| year(a).cmp(&year(b)).then(a.cite_key.cmp(&b.cite_key)));
+        }
+        ExportSortOrder::Author => {
+            publications.sort_by(|a, b| {
+                first_author(a)
+                    .cmp(&first_author(b))
+                    .then(year(a).cmp(&year(b)))
+                    .then(a.cite_key.cmp(&b.cite_key))
+            });
+        }
+    }
+}
+
+/// Plural section label for an entry type (e.g. "article" -> "Articles")
+fn entry_type_group_label(entry_type: &str) -> String {
+    let label = match entry_type {
+        "article" => "Articles",
+        "book" => "Books",
+        "booklet" => "Booklets",
+        "inbook" => "Book Sections",
+        "incollection" => "Book Chapters",
+        "inproceedings" | "conference" => "Conference Papers",
+        "proceedings" => "Proceedings",
+        "manual" => "Manuals",
+        "mastersthesis" => "Master's Theses",
+        "phdthesis" => "PhD Theses",
+        "techreport" => "Technical Reports",
+        "unpublished" => "Unpublished",
+        "online" => "Online Resources",
+        "software" => "Software",
+        "dataset" => "Datasets",
+        "misc" => "Miscellaneous",
+        other => {
+            let mut chars = other.chars();
+            return match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => "Other".to_string(),
+            };
+        }
+    };
+    label.to_string()
 }
 
 /// Export single publication to RIS
@@ -195,6 +304,74 @@ mod tests {
         assert!(result.contains("second2024"));
     }
 
+    fn dated(cite_key: &str, entry_type: &str, year: Option<i32>) -> Publication {
+        let mut publication = Publication::new(
+            cite_key.to_string(),
+            entry_type.to_string(),
+            format!("Title of {}", cite_key),
+        );
+        publication.year = year;
+        publication
+    }
+
+    fn positions(result: &str, needles: &[&str]) -> Vec<usize> {
+        needles
+            .iter()
+            .map(|n| result.find(n).unwrap_or_else(|| panic!("{} missing", n)))
+            .collect()
+    }
+
+    #[test]
+    fn test_export_multiple_sorted_by_year() {
+        let publications = vec![
+            dated("undated", "misc", None),
+            dated("recent2024", "article", Some(2024)),
+            dated("classic1998", "book", Some(1998)),
+            dated("middle2010", "inproceedings", Some(2010)),
+        ];
+        let options = ExportOptions {
+            sort_by: ExportSortOrder::Year,
+            ..Default::default()
+        };
+        let result = export_bibtex_multiple(publications, &options);
+
+        let pos = positions(
+            &result,
+            &["{classic1998,", "{middle2010,", "{recent2024,", "{undated,"],
+        );
+        assert!(pos.windows(2).all(|w| w[0] < w[1]), "{}", result);
+        assert!(!result.contains("% ==="));
+    }
+
+    #[test]
+    fn test_export_multiple_grouped_by_type() {
+        let publications = vec![
+            dated("zeta2020", "article", Some(2020)),
+            dated("tome2001", "book", Some(2001)),
+            dated("alpha2022", "article", Some(2022)),
+        ];
+        let options = ExportOptions {
+            sort_by: ExportSortOrder::CiteKey,
+            group_by_type: true,
+            ..Default::default()
+        };
+        let result = export_bibtex_multiple(publications, &options);
+
+        assert!(result.starts_with("% === Articles ===\n\n@article{alpha2022,"));
+        assert_eq!(result.matches("% === Articles ===").count(), 1);
+        let pos = positions(
+            &result,
+            &[
+                "% === Articles ===",
+                "{alpha2022,",
+                "{zeta2020,",
+                "% === Books ===",
+                "{tome2001,",
+            ],
+        );
+        assert!(pos.windows(2).all(|w| w[0] < w[1]), "{}", result);
+    }
+
     #[test]
     fn test_export_ris() {
         let mut pub_ = Publication::new(
@@ -3,6 +3,7 @@
 use crate::domain::Publication;
 use lazy_static::lazy_static;
 use regex::Regex;
+use std::collections::HashSet;
 
 lazy_static! {
     static ref UNSAFE_CHARS: Regex = Regex::new(r#"[<>:"/\\|?*\x00-\x1f]"#).unwrap();
@@ -10,6 +11,21 @@ lazy_static! {
     static ref MULTIPLE_UNDERSCORES: Regex = Regex::new(r"_+").unwrap();
 }
 
+/// A field that can appear in a generated filename
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilenameComponent {
+    /// First author's family name ("Unknown" when there are no authors)
+    Author,
+    /// Publication year
+    Year,
+    /// First significant words of the title
+    Title,
+    /// Journal name
+    Journal,
+    /// Citation key
+    CiteKey,
+}
+
 /// Options for filename generation
 #[derive(uniffi::Record, Clone, Debug)]
 pub struct FilenameOptions {
@@ -17,6 +33,8 @@ pub struct FilenameOptions {
     pub include_year: bool,
     pub title_words: u32,
     pub separator: String,
+    /// Components in the order they appear; empty components are skipped
+    pub components: Vec<FilenameComponent>,
 }
 
 impl Default for FilenameOptions {
@@ -26,10 +44,24 @@ impl Default for FilenameOptions {
             include_year: true,
             title_words: 3,
             separator: "_".to_string(),
+            components: vec![
+                FilenameComponent::Author,
+                FilenameComponent::Year,
+                FilenameComponent::Title,
+            ],
         }
     }
 }
 
+/// Field values available to the filename layout
+struct FilenameFields<'a> {
+    author: String,
+    year: Option<i32>,
+    title: &'a str,
+    journal: Option<&'a str>,
+    cite_key: Option<&'a str>,
+}
+
 pub(crate) fn default_filename_options_internal() -> FilenameOptions {
     FilenameOptions::default()
 }
@@ -44,39 +76,83 @@ pub(crate) fn generate_pdf_filename_internal(
     publication: &Publication,
     options: &FilenameOptions,
 ) -> String {
-    let mut parts = Vec::new();
+    build_filename(&publication_fields(publication), options)
+}
 
+fn publication_fields(publication: &Publication) -> FilenameFields<'_> {
     // Author (first author's family name)
     let author = publication
         .authors
         .first()
         .map(|a| sanitize_component(&a.family_name))
         .unwrap_or_else(|| "Unknown".to_string());
-    parts.push(author);
 
-    // Year
-    if options.include_year {
-        if let Some(year) = publication.year {
-            parts.push(year.to_string());
-        }
+    FilenameFields {
+        author,
+        year: publication.year,
+        title: &publication.title,
+        journal: publication.journal.as_deref(),
+        cite_key: Some(&publication.cite_key),
     }
+}
 
-    // Title (first N significant words)
-    let title = extract_title_words(&publication.title, options.title_words as usize);
-    if !title.is_empty() {
-        parts.push(title);
-    }
+#[cfg(feature = "native")]
+#[uniffi::export]
+pub fn generate_pdf_filename(publication: &Publication, options: &FilenameOptions) -> String {
+    generate_pdf_filename_internal(publication, options)
+}
 
-    let filename = parts.join(&options.separator);
-    let truncated = truncate_filename(&filename, options.max_length as usize);
+/// Generate a filename that doesn't collide with any of `existing`
+///
+/// On a collision a suffix (`_a`, `_b`, ..., `_z`, `_aa`, ...) joined with the
+/// configured separator is inserted before the extension. The name is
+/// truncated to leave room for the suffix, so it stays within `max_length`.
+/// Comparison ignores case, since the default filesystems on macOS and
+/// Windows do.
+pub(crate) fn generate_unique_pdf_filename_internal(
+    publication: &Publication,
+    options: &FilenameOptions,
+    existing: &[String],
+) -> String {
+    let fields = publication_fields(publication);
+    let base = build_filename(&fields, options);
+    let taken: HashSet<String> = existing.iter().map(|name| name.to_lowercase()).collect();
+    if !taken.contains(&base.to_lowercase()) {
+        return base;
+    }
 
-    format!("{}.pdf", truncated)
+    let stem = join_components(&fields, options);
+    (0..)
+        .map(|index| {
+            let suffix = format!("{}{}", options.separator, letter_suffix(index));
+            let room = (options.max_length as usize).saturating_sub(suffix.len());
+            format!("{}{}.pdf", truncate_filename(&stem, room), suffix)
+        })
+        .find(|candidate| !taken.contains(&candidate.to_lowercase()))
+        .expect("suffixes are unbounded")
 }
 
 #[cfg(feature = "native")]
 #[uniffi::export]
-pub fn generate_pdf_filename(publication: &Publication, options: &FilenameOptions) -> String {
-    generate_pdf_filename_internal(publication, options)
+pub fn generate_unique_pdf_filename(
+    publication: &Publication,
+    options: &FilenameOptions,
+    existing: Vec<String>,
+) -> String {
+    generate_unique_pdf_filename_internal(publication, options, &existing)
+}
+
+/// Spreadsheet-style letters: 0 -> "a", 25 -> "z", 26 -> "aa"
+fn letter_suffix(mut index: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push(b'a' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    letters.iter().rev().map(|&b| b as char).collect()
 }
 
 pub(crate) fn generate_pdf_filename_from_metadata_internal(
@@ -85,8 +161,6 @@ pub(crate) fn generate_pdf_filename_from_metadata_internal(
     year: Option<i32>,
     options: &FilenameOptions,
 ) -> String {
-    let mut parts = Vec::new();
-
     // Author
     let author = authors
         .first()
@@ -102,25 +176,15 @@ pub(crate) fn generate_pdf_filename_from_metadata_internal(
             }
         })
         .unwrap_or_else(|| "Unknown".to_string());
-    parts.push(author);
-
-    // Year
-    if options.include_year {
-        if let Some(y) = year {
-            parts.push(y.to_string());
-        }
-    }
-
-    // Title
-    let title_part = extract_title_words(&title, options.title_words as usize);
-    if !title_part.is_empty() {
-        parts.push(title_part);
-    }
-
-    let filename = parts.join(&options.separator);
-    let truncated = truncate_filename(&filename, options.max_length as usize);
 
-    format!("{}.pdf", truncated)
+    let fields = FilenameFields {
+        author,
+        year,
+        title: &title,
+        journal: None,
+        cite_key: None,
+    };
+    build_filename(&fields, options)
 }
 
 #[cfg(feature = "native")]
@@ -134,6 +198,43 @@ pub fn generate_pdf_filename_from_metadata(
     generate_pdf_filename_from_metadata_internal(title, authors, year, options)
 }
 
+/// Lay out the configured components and truncate to `max_length`
+fn build_filename(fields: &FilenameFields, options: &FilenameOptions) -> String {
+    let filename = join_components(fields, options);
+    let truncated = truncate_filename(&filename, options.max_length as usize);
+
+    format!("{}.pdf", truncated)
+}
+
+/// Join the configured components, skipping any that are empty
+fn join_components(fields: &FilenameFields, options: &FilenameOptions) -> String {
+    let parts: Vec<String> = options
+        .components
+        .iter()
+        .map(|component| match component {
+            FilenameComponent::Author => fields.author.clone(),
+            FilenameComponent::Year => fields
+                .year
+                .filter(|_| options.include_year)
+                .map(|y| y.to_string())
+                .unwrap_or_default(),
+            // First N significant words
+            FilenameComponent::Title => {
+                extract_title_words(fields.title, options.title_words as usize)
+            }
+            FilenameComponent::Journal => {
+                fields.journal.map(sanitize_component).unwrap_or_default()
+            }
+            FilenameComponent::CiteKey => {
+                fields.cite_key.map(sanitize_component).unwrap_or_default()
+            }
+        })
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    parts.join(&options.separator)
+}
+
 fn sanitize_component(input: &str) -> String {
     let cleaned = UNSAFE_CHARS.replace_all(input, "");
     let normalized = MULTIPLE_SPACES.replace_all(&cleaned, " ");
@@ -234,6 +335,127 @@ mod tests {
         assert!(filename.starts_with("Unknown_"));
     }
 
+    #[test]
+    fn test_generate_filename_year_first() {
+        let mut pub_ = Publication::new(
+            "einstein1905".to_string(),
+            "article".to_string(),
+            "On the Electrodynamics of Moving Bodies".to_string(),
+        );
+        pub_.year = Some(1905);
+        pub_.authors.push(Author::new("Einstein".to_string()));
+
+        let options = FilenameOptions {
+            separator: "-".to_string(),
+            components: vec![
+                FilenameComponent::Year,
+                FilenameComponent::Author,
+                FilenameComponent::Title,
+            ],
+            ..Default::default()
+        };
+        let filename = generate_pdf_filename(&pub_, &options);
+        assert_eq!(filename, "1905-Einstein-Electrodynamics_Moving_Bodies.pdf");
+
+        // A missing year leaves no dangling separator
+        pub_.year = None;
+        let filename = generate_pdf_filename(&pub_, &options);
+        assert_eq!(filename, "Einstein-Electrodynamics_Moving_Bodies.pdf");
+    }
+
+    #[test]
+    fn test_generate_filename_journal_prefix() {
+        let mut pub_ = Publication::new(
+            "einstein1905".to_string(),
+            "article".to_string(),
+            "On the Electrodynamics of Moving Bodies".to_string(),
+        );
+        pub_.year = Some(1905);
+        pub_.journal = Some("Annalen der Physik".to_string());
+        pub_.authors.push(Author::new("Einstein".to_string()));
+
+        let options = FilenameOptions {
+            components: vec![
+                FilenameComponent::Journal,
+                FilenameComponent::Author,
+                FilenameComponent::Year,
+            ],
+            ..Default::default()
+        };
+        let filename = generate_pdf_filename(&pub_, &options);
+        assert_eq!(filename, "Annalen_der_Physik_Einstein_1905.pdf");
+
+        let filename = generate_pdf_filename_from_metadata(
+            "A Great Paper".to_string(),
+            vec!["Smith, John".to_string()],
+            Some(2024),
+            &options,
+        );
+        assert_eq!(filename, "Smith_2024.pdf");
+    }
+
+    #[test]
+    fn test_generate_unique_filename() {
+        let mut first = Publication::new(
+            "smith2024a".to_string(),
+            "article".to_string(),
+            "Galaxy Rotation Curves".to_string(),
+        );
+        first.year = Some(2024);
+        first.authors.push(Author::new("Smith".to_string()));
+        let mut second = first.clone();
+        second.cite_key = "smith2024b".to_string();
+
+        let options = default_filename_options();
+        let mut existing = vec!["Smith_2024_Galaxy_Rotation_Curves.pdf".to_string()];
+
+        let first_name = generate_unique_pdf_filename(&first, &options, existing.clone());
+        assert_eq!(first_name, "Smith_2024_Galaxy_Rotation_Curves_a.pdf");
+        existing.push(first_name.clone());
+
+        let second_name = generate_unique_pdf_filename(&second, &options, existing.clone());
+        assert_eq!(second_name, "Smith_2024_Galaxy_Rotation_Curves_b.pdf");
+        assert!(!existing.contains(&second_name));
+
+        // No collision keeps the base name
+        let fresh = generate_unique_pdf_filename(&first, &options, Vec::new());
+        assert_eq!(fresh, "Smith_2024_Galaxy_Rotation_Curves.pdf");
+    }
+
+    #[test]
+    fn test_unique_filename_at_length_limit() {
+        let mut publication = Publication::new(
+            "abcdefghijklmnop2024".to_string(),
+            "article".to_string(),
+            "Untitled".to_string(),
+        );
+        publication
+            .authors
+            .push(Author::new("Abcdefghijklmnop".to_string()));
+        let options = FilenameOptions {
+            max_length: 10,
+            components: vec![FilenameComponent::Author],
+            ..default_filename_options()
+        };
+
+        let mut existing = vec![generate_pdf_filename(&publication, &options)];
+        assert_eq!(existing[0], "Abcdefghij.pdf");
+        for expected in ["Abcdefgh_a.pdf", "Abcdefgh_b.pdf"] {
+            let name = generate_unique_pdf_filename(&publication, &options, existing.clone());
+            assert_eq!(name, expected);
+            assert!(name.trim_end_matches(".pdf").len() <= 10);
+            existing.push(name);
+        }
+    }
+
+    #[test]
+    fn test_letter_suffix() {
+        assert_eq!(letter_suffix(0), "a");
+        assert_eq!(letter_suffix(25), "z");
+        assert_eq!(letter_suffix(26), "aa");
+        assert_eq!(letter_suffix(27), "ab");
+    }
+
     #[test]
     fn test_sanitize_unsafe_chars() {
         let result = sanitize_component("Test: A <File> Name?");
@@ -283,15 +283,7 @@ pub fn parse_reference_filter(input: String) -> ParsedFilter {
     let filter = ReferenceFilter::parse(&input);
 
     // Reconstruct raw strings for flag/tag queries
-    let flag_query_raw = filter.flag_query.as_ref().map(|fq| {
-        use impress_flags::FlagQuery;
-        match fq {
-            FlagQuery::HasColor(c) => format!("flag:{}", c.display_name().to_lowercase()),
-            FlagQuery::NotColor(c) => format!("-flag:{}", c.display_name().to_lowercase()),
-            FlagQuery::AnyFlag => "flag:*".to_string(),
-            FlagQuery::NoFlag => "-flag:*".to_string(),
-        }
-    });
+    let flag_query_raw = filter.flag_query.as_ref().map(|fq| fq.to_string());
 
     let tag_query_raws: Vec<String> = filter.tag_queries.iter().map(format_tag_query).collect();
 
@@ -55,14 +55,15 @@ pub use bibtex::{
 };
 pub use deduplication::{DeduplicationMatch, DuplicateGroup};
 pub use domain::{
-    Author, AuthorStats, Collection, EnrichmentCapability, EnrichmentData, EnrichmentPriority,
-    FileStorageType, Identifiers, Library, LinkedFile, OpenAccessStatus, PaperStub, PdfLink,
-    PdfLinkType, Publication, SearchResult, Source, Tag, ValidationError, ValidationSeverity,
+    Author, AuthorAliasStore, AuthorStats, Collection, EnrichmentCapability, EnrichmentData,
+    EnrichmentFetch, EnrichmentPriority, EnrichmentTask, FileStorageType, Identifiers, Library,
+    LinkedFile, OpenAccessStatus, PaperStub, PdfLink, PdfLinkType, Publication, SearchResult,
+    Source, Tag, ValidationError, ValidationSeverity,
 };
 pub use error::FfiError;
 
-pub use export::{ExportFormat, ExportOptions};
-pub use filename::FilenameOptions;
+pub use export::{ExportFormat, ExportOptions, ExportSortOrder};
+pub use filename::{FilenameComponent, FilenameOptions};
 pub use identifiers::{
     CiteKeyFormatValidation, EnrichmentSource, ExtractedIdentifier, IdentifierType,
     PreferredIdentifier,
@@ -70,9 +71,9 @@ pub use identifiers::{
 pub use import::{ImportError, ImportFormat, ImportResult};
 pub use merge::{Conflict, MergeResult, MergeStrategy};
 pub use ris::{RISEntry, RISTag, RISType};
-pub use search::{ADSDatabase, QueryLogic};
+pub use search::{ADSDatabase, QueryExpr, QueryLogic, QueryParseError, QueryTarget};
 #[cfg(feature = "native")]
-pub use search::{AnnIndex, AnnIndexConfig, AnnIndexItem, AnnSimilarityResult};
+pub use search::{AnnIndex, AnnIndexConfig, AnnIndexError, AnnIndexItem, AnnSimilarityResult};
 #[cfg(not(target_arch = "wasm32"))]
 pub use search::{HelpDocument, HelpPlatform, HelpSearchError, HelpSearchIndex, HelpSearchResult};
 #[cfg(not(target_arch = "wasm32"))]
@@ -83,7 +83,7 @@ pub fn to_bibtex(entry: RISEntry) -> BibTeXEntry {
     }
 
     // Keywords
-    let keywords: Vec<&str> = entry.get_all_tags("KW");
+    let keywords = entry.keywords();
     if !keywords.is_empty() {
         bibtex.add_field("keywords", keywords.join(", "));
     }
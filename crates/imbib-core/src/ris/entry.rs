@@ -241,6 +241,21 @@ impl RISEntry {
             .collect()
     }
 
+    /// Get every tag's values grouped by tag, in order of first appearance
+    ///
+    /// Values within a group keep their file order, so repeated tags like
+    /// `AU` or `KW` come back as ordered lists.
+    pub fn grouped_tags(&self) -> Vec<(&str, Vec<&str>)> {
+        let mut groups: Vec<(&str, Vec<&str>)> = Vec::new();
+        for t in &self.tags {
+            match groups.iter_mut().find(|(tag, _)| *tag == t.tag) {
+                Some((_, values)) => values.push(t.value.as_str()),
+                None => groups.push((t.tag.as_str(), vec![t.value.as_str()])),
+            }
+        }
+        groups
+    }
+
     /// Get the title (T1 or TI tag)
     pub fn title(&self) -> Option<&str> {
         self.get_tag("T1").or_else(|| self.get_tag("TI"))
@@ -283,6 +298,11 @@ impl RISEntry {
     pub fn abstract_text(&self) -> Option<&str> {
         self.get_tag("AB").or_else(|| self.get_tag("N2"))
     }
+
+    /// Get all keywords (KW tags)
+    pub fn keywords(&self) -> Vec<&str> {
+        self.get_all_tags("KW")
+    }
 }
 
 #[cfg(test)]
@@ -12,11 +12,18 @@ pub fn parse(input: String) -> Result<Vec<RISEntry>, ParseError> {
 }
 
 /// Internal parsing function
+///
+/// Repeated tags (e.g. several `AU  -` lines) are kept as separate tags in
+/// file order. Lines that don't start with a tag continue the previous tag's
+/// value. `ER  -` always closes the current record.
 fn parse_ris(input: &str) -> Result<Vec<RISEntry>, ParseError> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
     let mut entries = Vec::new();
     let mut current_entry: Option<RISEntry> = None;
     let mut raw_lines = Vec::new();
-    let mut last_tag: Option<String> = None;
+    // Index into `current_entry.tags` of the tag continuation lines extend
+    let mut last_tag: Option<usize> = None;
 
     for line in input.lines() {
         // Skip empty lines
@@ -55,24 +62,22 @@ fn parse_ris(input: &str) -> Result<Vec<RISEntry>, ParseError> {
                 }
                 _ => {
                     // Regular tag
-                    raw_lines.push(line.to_string());
                     if let Some(ref mut entry) = current_entry {
-                        entry.add_tag(tag.clone(), value);
-                        last_tag = Some(tag);
+                        raw_lines.push(line.to_string());
+                        entry.add_tag(tag, value);
+                        last_tag = Some(entry.tags.len() - 1);
                     }
                 }
             }
         } else if let Some(ref mut entry) = current_entry {
             // Continuation line (doesn't start with tag) — append to last tag's value
             raw_lines.push(line.to_string());
-            if let Some(ref tag) = last_tag {
+            if let Some(last) = last_tag.and_then(|i| entry.tags.get_mut(i)) {
                 let trimmed = line.trim();
-                if !trimmed.is_empty() {
-                    if let Some(last) = entry.tags.iter_mut().rev().find(|t| t.tag == *tag) {
-                        last.value.push(' ');
-                        last.value.push_str(trimmed);
-                    }
+                if !last.value.is_empty() {
+                    last.value.push(' ');
                 }
+                last.value.push_str(trimmed);
             }
         }
     }
@@ -89,15 +94,11 @@ fn parse_ris(input: &str) -> Result<Vec<RISEntry>, ParseError> {
 /// Parse a single RIS line into tag and value
 fn parse_ris_line(line: &str) -> Option<(String, String)> {
     // RIS format: XX  - value (two chars, two spaces, dash, space, value)
-    // Some variants: XX - value (two chars, space, dash, space, value)
-    // ER tags may appear as "ER  -" with no value
-
-    if line.len() < 2 {
-        return None;
-    }
+    // Variants seen in the wild: "XX - value", "XX- value", "XX  -value"
+    // ER tags may appear as "ER  -" with no value, or just "ER"
 
     // Check for valid tag prefix (two uppercase/digit characters)
-    let tag = &line[0..2];
+    let tag = line.get(0..2)?;
     if !tag
         .chars()
         .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
@@ -106,24 +107,19 @@ fn parse_ris_line(line: &str) -> Option<(String, String)> {
     }
 
     let rest = &line[2..];
-
-    // Find the separator "  - " or " - " or "- " (with value after)
-    // Also handle "  -" or " -" at end of line (no value, e.g. "ER  -")
-    let value_start = if rest.starts_with("  - ") {
-        4
-    } else if rest.starts_with(" - ") {
-        3
-    } else if rest.starts_with("- ") {
-        2
-    } else if rest.trim() == "-" || rest.trim().is_empty() {
-        // Tag with no value (e.g., "ER  -" or "ER")
+    if tag == "ER" && rest.trim().is_empty() {
         return Some((tag.to_string(), String::new()));
-    } else {
+    }
+
+    // Spaces before the dash are optional, but without them the dash must be
+    // followed by a space so prose like "CO-authored" stays a continuation
+    let after_spaces = rest.trim_start_matches(' ');
+    let value = after_spaces.strip_prefix('-')?;
+    if after_spaces.len() == rest.len() && !value.is_empty() && !value.starts_with(' ') {
         return None;
-    };
+    }
 
-    let value = rest[value_start..].to_string();
-    Some((tag.to_string(), value))
+    Some((tag.to_string(), value.trim().to_string()))
 }
 
 #[cfg(test)]
@@ -212,5 +208,77 @@ ER  -
             Some(("TI".to_string(), "A Title".to_string()))
         );
         assert_eq!(parse_ris_line("invalid"), None);
+        assert_eq!(
+            parse_ris_line("AU  -Smith, John"),
+            Some(("AU".to_string(), "Smith, John".to_string()))
+        );
+        assert_eq!(parse_ris_line("CO-authored with"), None);
+        assert_eq!(parse_ris_line("US"), None);
+        assert_eq!(parse_ris_line("日本語"), None);
+    }
+
+    #[test]
+    fn test_parse_wrapped_abstract() {
+        let input = "TY  - JOUR\n\
+                     TI  - Galaxy Formation\n\
+                     AB  - We study the formation of galaxies\n\
+                     \x20\x20in cosmological simulations.\n\
+                     \n\
+                     US and European teams contributed.\n\
+                     KW  - galaxies\n\
+                     ER  -\n";
+        let entries = parse(input.to_string()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].abstract_text(),
+            Some(
+                "We study the formation of galaxies in cosmological simulations. \
+                 US and European teams contributed."
+            )
+        );
+        assert_eq!(entries[0].keywords(), vec!["galaxies"]);
+    }
+
+    #[test]
+    fn test_parse_repeated_authors() {
+        let input = "TY  - JOUR\n\
+                     AU  - Smith, John\n\
+                     KW  - cosmology\n\
+                     AU  - Doe, Jane\n\
+                     AU  -Lee, Kim\n\
+                     KW  - dark matter\n\
+                     ER  -\n";
+        let entries = parse(input.to_string()).unwrap();
+        let entry = &entries[0];
+        assert_eq!(
+            entry.authors(),
+            vec!["Smith, John", "Doe, Jane", "Lee, Kim"]
+        );
+        assert_eq!(entry.keywords(), vec!["cosmology", "dark matter"]);
+
+        let groups = entry.grouped_tags();
+        assert_eq!(
+            groups[0],
+            ("AU", vec!["Smith, John", "Doe, Jane", "Lee, Kim"])
+        );
+        assert_eq!(groups[1], ("KW", vec!["cosmology", "dark matter"]));
+    }
+
+    #[test]
+    fn test_parse_er_without_trailing_newline() {
+        let input = "TY  - JOUR\nTI  - First\nER  -\nTY  - BOOK\nTI  - Second\nER  -";
+        let entries = parse(input.to_string()).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].title(), Some("Second"));
+        assert_eq!(
+            entries[1].raw_ris.as_deref(),
+            Some("TY  - BOOK\nTI  - Second\nER  -")
+        );
+
+        // CRLF line endings and a byte-order mark
+        let input = "\u{feff}TY  - JOUR\r\nTI  - Windows\r\nER  - \r\n";
+        let entries = parse(input.to_string()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].title(), Some("Windows"));
     }
 }
@@ -2,12 +2,50 @@
 //!
 //! Provides O(log n) similarity search for embeddings, significantly faster
 //! than brute-force O(n) search for large collections.
+//!
+//! An index can be saved to disk with [`AnnIndex::save`] and restored with
+//! [`AnnIndex::load`] so large libraries don't need re-embedding on launch.
 
 #[cfg(feature = "native")]
 use hnsw_rs::prelude::*;
 
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 use std::sync::RwLock;
+use thiserror::Error;
+
+/// Magic bytes at the start of a saved index file
+const FILE_MAGIC: &[u8; 6] = b"IMBANN";
+
+/// Version of the saved index format; bump when the layout changes
+const FILE_VERSION: u32 = 1;
+
+/// Errors from saving or loading an index
+///
+/// Any error from [`AnnIndex::load`] means the saved file can't be used and
+/// the index should be rebuilt from the embedding store.
+#[derive(Error, Debug)]
+pub enum AnnIndexError {
+    #[error("IO error: {0}")]
+    Io(String),
+    #[error("Index file has format version {found}, expected {expected}")]
+    VersionMismatch { found: u32, expected: u32 },
+    #[error("Index file is corrupt: {0}")]
+    Corrupt(String),
+}
+
+impl From<std::io::Error> for AnnIndexError {
+    fn from(e: std::io::Error) -> Self {
+        AnnIndexError::Io(e.to_string())
+    }
+}
+
+/// On-disk contents of a saved index
+#[derive(Serialize, Deserialize)]
+struct SavedIndex {
+    ids: Vec<String>,
+    vectors: Vec<Vec<f32>>,
+}
 
 /// Result of a similarity search
 #[derive(uniffi::Record, Clone, Debug, Serialize, Deserialize)]
@@ -45,6 +83,8 @@ impl Default for AnnIndexConfig {
 pub struct AnnIndex {
     hnsw: RwLock<Hnsw<'static, f32, DistCosine>>,
     id_map: RwLock<Vec<String>>,
+    /// Embeddings in insertion order, kept so the index can be saved
+    vectors: RwLock<Vec<Vec<f32>>>,
     config: AnnIndexConfig,
 }
 
@@ -67,6 +107,7 @@ impl AnnIndex {
         Self {
             hnsw: RwLock::new(hnsw),
             id_map: RwLock::new(Vec::new()),
+            vectors: RwLock::new(Vec::new()),
             config,
         }
     }
@@ -86,6 +127,7 @@ impl AnnIndex {
         let mut id_map = self.id_map.write().unwrap();
         let idx = id_map.len();
         id_map.push(publication_id.to_string());
+        self.vectors.write().unwrap().push(embedding.to_vec());
         drop(id_map);
 
         let hnsw = self.hnsw.read().unwrap();
@@ -99,6 +141,7 @@ impl AnnIndex {
         }
 
         let mut id_map = self.id_map.write().unwrap();
+        let mut vectors = self.vectors.write().unwrap();
         let start_idx = id_map.len();
 
         let data: Vec<(&Vec<f32>, usize)> = items
@@ -106,10 +149,12 @@ impl AnnIndex {
             .enumerate()
             .map(|(i, (id, emb))| {
                 id_map.push(id.clone());
+                vectors.push(emb.clone());
                 (emb, start_idx + i)
             })
             .collect();
 
+        drop(vectors);
         drop(id_map);
 
         let hnsw = self.hnsw.read().unwrap();
@@ -135,13 +180,81 @@ impl AnnIndex {
             .collect()
     }
 
-    /// Serialize index to bytes
-    pub fn save(&self) -> Result<Vec<u8>, String> {
+    /// Serialize the index to bytes, prefixed with a version header
+    ///
+    /// hnsw_rs can't serialize a graph it borrows into, so the ids and
+    /// embeddings are saved and the graph is rebuilt from them on load.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, AnnIndexError> {
+        // Hold both locks so a concurrent add can't split ids from vectors
         let id_map = self.id_map.read().unwrap();
-        // Note: hnsw_rs doesn't directly support serialization of the index
-        // We save the id_map and would need to rebuild the index on load
-        bincode::serialize(&(id_map.clone(), &self.config))
-            .map_err(|e| format!("Serialization error: {}", e))
+        let vectors = self.vectors.read().unwrap();
+        let saved = SavedIndex {
+            ids: id_map.clone(),
+            vectors: vectors.clone(),
+        };
+        drop(vectors);
+        drop(id_map);
+        let payload =
+            bincode::serialize(&saved).map_err(|e| AnnIndexError::Corrupt(e.to_string()))?;
+
+        let mut bytes = Vec::with_capacity(FILE_MAGIC.len() + 4 + payload.len());
+        bytes.extend_from_slice(FILE_MAGIC);
+        bytes.extend_from_slice(&FILE_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    /// Restore an index from bytes produced by [`AnnIndex::to_bytes`]
+    pub fn from_bytes(bytes: &[u8], config: AnnIndexConfig) -> Result<Self, AnnIndexError> {
+        let header_len = FILE_MAGIC.len() + 4;
+        if bytes.len() < header_len || &bytes[..FILE_MAGIC.len()] != FILE_MAGIC {
+            return Err(AnnIndexError::Corrupt("missing file header".to_string()));
+        }
+
+        let mut version = [0u8; 4];
+        version.copy_from_slice(&bytes[FILE_MAGIC.len()..header_len]);
+        let version = u32::from_le_bytes(version);
+        if version != FILE_VERSION {
+            return Err(AnnIndexError::VersionMismatch {
+                found: version,
+                expected: FILE_VERSION,
+            });
+        }
+
+        let saved: SavedIndex = bincode::deserialize(&bytes[header_len..])
+            .map_err(|e| AnnIndexError::Corrupt(e.to_string()))?;
+        if saved.ids.len() != saved.vectors.len() {
+            return Err(AnnIndexError::Corrupt(format!(
+                "{} ids but {} embeddings",
+                saved.ids.len(),
+                saved.vectors.len()
+            )));
+        }
+
+        let index = Self::with_config(config);
+        index.add_batch(saved.ids.into_iter().zip(saved.vectors).collect());
+        Ok(index)
+    }
+
+    /// Save the index to a file
+    ///
+    /// The file is written alongside and renamed into place, so a crash
+    /// mid-save leaves the previous file intact.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), AnnIndexError> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, self.to_bytes()?)?;
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Load an index saved with [`AnnIndex::save`]
+    ///
+    /// Returns an error (never panics) if the file is missing, corrupt, or
+    /// from an incompatible version; callers should then rebuild the index.
+    pub fn load(path: impl AsRef<Path>, config: AnnIndexConfig) -> Result<Self, AnnIndexError> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes(&bytes, config)
     }
 
     /// Get the configuration used for this index
@@ -278,6 +391,35 @@ pub fn ann_index_size(handle_id: u64) -> u32 {
     }
 }
 
+/// Save an index to a file
+#[cfg(feature = "native")]
+#[uniffi::export]
+pub fn ann_index_save(handle_id: u64, path: String) -> bool {
+    let registry = ANN_INDEX_REGISTRY.read().unwrap();
+    match registry.get(&handle_id) {
+        Some(index) => index.save(&path).is_ok(),
+        None => false,
+    }
+}
+
+/// Load a saved index, returning a handle, or None if the file can't be
+/// used and the index must be rebuilt
+#[cfg(feature = "native")]
+#[uniffi::export]
+pub fn ann_index_load(path: String) -> Option<u64> {
+    let index = AnnIndex::load(&path, AnnIndexConfig::default()).ok()?;
+
+    let mut counter = ANN_INDEX_COUNTER.lock().unwrap();
+    *counter += 1;
+    let handle_id = *counter;
+    drop(counter);
+
+    let mut registry = ANN_INDEX_REGISTRY.write().unwrap();
+    registry.insert(handle_id, index);
+
+    Some(handle_id)
+}
+
 /// Close and release an index
 #[cfg(feature = "native")]
 #[uniffi::export]
@@ -337,6 +479,63 @@ mod tests {
         assert_eq!(results[0].publication_id, "pub2");
     }
 
+    #[test]
+    #[cfg(feature = "native")]
+    fn test_save_load_round_trip() {
+        let index = AnnIndex::new();
+        for i in 0..50 {
+            let angle = i as f32 * 0.1;
+            index.add(&format!("pub{}", i), &[angle.cos(), angle.sin(), 0.5]);
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ann.idx");
+        index.save(&path).unwrap();
+
+        let loaded = AnnIndex::load(&path, AnnIndexConfig::default()).unwrap();
+        assert_eq!(loaded.len(), index.len());
+
+        let query = [0.8f32.cos(), 0.8f32.sin(), 0.5];
+        let ids = |results: Vec<AnnSimilarityResult>| -> Vec<String> {
+            results.into_iter().map(|r| r.publication_id).collect()
+        };
+        assert_eq!(ids(loaded.search(&query, 5)), ids(index.search(&query, 5)));
+    }
+
+    #[test]
+    #[cfg(feature = "native")]
+    fn test_load_rejects_bad_files() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let corrupt = dir.path().join("corrupt.idx");
+        std::fs::write(&corrupt, b"not an index").unwrap();
+        assert!(matches!(
+            AnnIndex::load(&corrupt, AnnIndexConfig::default()),
+            Err(AnnIndexError::Corrupt(_))
+        ));
+
+        let mut bytes = AnnIndex::new().to_bytes().unwrap();
+        bytes[FILE_MAGIC.len()] = 99;
+        let old = dir.path().join("old.idx");
+        std::fs::write(&old, &bytes).unwrap();
+        assert!(matches!(
+            AnnIndex::load(&old, AnnIndexConfig::default()),
+            Err(AnnIndexError::VersionMismatch { found: 99, .. })
+        ));
+
+        let truncated = AnnIndex::new();
+        truncated.add("pub1", &[1.0, 0.0, 0.0]);
+        let bytes = truncated.to_bytes().unwrap();
+        assert!(
+            AnnIndex::from_bytes(&bytes[..bytes.len() - 3], AnnIndexConfig::default()).is_err()
+        );
+
+        assert!(matches!(
+            AnnIndex::load(dir.path().join("missing.idx"), AnnIndexConfig::default()),
+            Err(AnnIndexError::Io(_))
+        ));
+    }
+
     #[test]
     #[cfg(feature = "native")]
     fn test_handle_api() {
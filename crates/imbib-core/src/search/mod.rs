@@ -42,14 +42,16 @@ pub mod chunk_index;
 #[cfg(feature = "native")]
 pub use query_builder::{
     build_arxiv_author_category_query, build_classic_query, build_paper_query,
-    is_classic_form_empty, is_paper_form_empty, ADSDatabase, QueryLogic,
+    is_classic_form_empty, is_paper_form_empty, translate_boolean_query, ADSDatabase, QueryLogic,
 };
+pub use query_builder::{build_boolean_query, QueryTarget};
 
 #[cfg(feature = "native")]
 pub use query_parser::{
     parse_arxiv_query, parse_classic_query, parse_paper_query, ParsedArXivForm, ParsedArXivTerm,
     ParsedClassicForm, ParsedPaperForm,
 };
+pub use query_parser::{parse_boolean_query, QueryExpr, QueryParseError};
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use index::*;
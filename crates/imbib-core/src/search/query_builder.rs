@@ -5,6 +5,8 @@
 //! Note: Date-based filtering (e.g., ArXivDateFilter) stays in Swift
 //! since it requires Calendar/DateFormatter (Apple-specific).
 
+use super::query_parser::{parse_boolean_query, QueryExpr, QueryParseError};
+
 /// Boolean logic for combining search terms
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, uniffi::Enum)]
 pub enum QueryLogic {
@@ -43,6 +45,122 @@ impl ADSDatabase {
     }
 }
 
+/// Search API a boolean query is rendered for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum QueryTarget {
+    Ads,
+    ArXiv,
+}
+
+/// Render a boolean query tree in the target API's syntax.
+///
+/// Field names are normalized for the target (`author`/`au`, `title`/`ti`,
+/// `abstract`/`abs`), and parentheses are added only where precedence needs
+/// them. For arXiv, unfielded terms search `all:`, `year:` becomes a
+/// `submittedDate` range, and `a AND NOT b` becomes `a ANDNOT b`; arXiv has no
+/// standalone negation, so any other `NOT` is an error.
+pub fn build_boolean_query(
+    expr: &QueryExpr,
+    target: QueryTarget,
+) -> Result<String, QueryParseError> {
+    render_expr(expr, target)
+}
+
+/// Operator precedence used to decide where parentheses are needed
+fn precedence(expr: &QueryExpr) -> u8 {
+    match expr {
+        QueryExpr::Or(..) => 1,
+        QueryExpr::And(..) => 2,
+        QueryExpr::Not(..) => 3,
+        QueryExpr::Term { .. } => 4,
+    }
+}
+
+fn render_operand(
+    expr: &QueryExpr,
+    parent: u8,
+    target: QueryTarget,
+) -> Result<String, QueryParseError> {
+    let rendered = render_expr(expr, target)?;
+    if precedence(expr) < parent {
+        Ok(format!("({})", rendered))
+    } else {
+        Ok(rendered)
+    }
+}
+
+fn render_expr(expr: &QueryExpr, target: QueryTarget) -> Result<String, QueryParseError> {
+    let prec = precedence(expr);
+    match expr {
+        QueryExpr::Term { field, value } => Ok(render_term(field.as_deref(), value, target)),
+        QueryExpr::Or(a, b) => Ok(format!(
+            "{} OR {}",
+            render_operand(a, prec, target)?,
+            render_operand(b, prec, target)?
+        )),
+        QueryExpr::And(a, b) => match (target, b.as_ref()) {
+            (QueryTarget::ArXiv, QueryExpr::Not(negated)) => Ok(format!(
+                "{} ANDNOT {}",
+                render_operand(a, prec, target)?,
+                render_operand(negated, prec + 1, target)?
+            )),
+            _ => Ok(format!(
+                "{} AND {}",
+                render_operand(a, prec, target)?,
+                render_operand(b, prec, target)?
+            )),
+        },
+        QueryExpr::Not(a) => match target {
+            QueryTarget::Ads => Ok(format!("NOT {}", render_operand(a, prec, target)?)),
+            QueryTarget::ArXiv => Err(QueryParseError::UnsupportedNot),
+        },
+    }
+}
+
+fn render_term(field: Option<&str>, value: &str, target: QueryTarget) -> String {
+    let field = field.map(str::to_lowercase);
+    match target {
+        QueryTarget::Ads => match field.as_deref() {
+            None => value.to_string(),
+            Some("au") => format!("author:{}", value),
+            Some("ti") => format!("title:{}", value),
+            Some("abstract") => format!("abs:{}", value),
+            Some(f) => format!("{}:{}", f, value),
+        },
+        QueryTarget::ArXiv => match field.as_deref() {
+            None | Some("all") => format!("all:{}", value),
+            Some("author") => format!("au:{}", value),
+            Some("title") => format!("ti:{}", value),
+            Some("abstract") => format!("abs:{}", value),
+            Some("year") => arxiv_year_range(value).unwrap_or_else(|| format!("all:{}", value)),
+            Some(f) => format!("{}:{}", f, value),
+        },
+    }
+}
+
+/// Convert `1925` or `1920-1930` to an arXiv submittedDate range
+fn arxiv_year_range(value: &str) -> Option<String> {
+    let (from, to) = value.split_once('-').unwrap_or((value, value));
+    let valid = |y: &str| y.len() == 4 && y.bytes().all(|b| b.is_ascii_digit());
+    if !valid(from) || !valid(to) {
+        return None;
+    }
+    Some(format!(
+        "submittedDate:[{}01010000 TO {}12312359]",
+        from, to
+    ))
+}
+
+/// Parse a boolean query and render it for the target API.
+#[cfg(feature = "native")]
+#[uniffi::export]
+pub fn translate_boolean_query(
+    query: String,
+    target: QueryTarget,
+) -> Result<String, QueryParseError> {
+    build_boolean_query(&parse_boolean_query(&query)?, target)
+}
+
 /// Build an ADS query from classic form fields.
 ///
 /// # Arguments
@@ -400,6 +518,33 @@ mod tests {
         assert!(query.contains("doctype:article"));
     }
 
+    #[test]
+    fn test_translate_grouped_boolean_query() {
+        let query = "(au:einstein OR author:bohr) AND year:1925".to_string();
+        assert_eq!(
+            translate_boolean_query(query.clone(), QueryTarget::Ads).unwrap(),
+            "(author:einstein OR author:bohr) AND year:1925"
+        );
+        assert_eq!(
+            translate_boolean_query(query, QueryTarget::ArXiv).unwrap(),
+            "(au:einstein OR au:bohr) AND submittedDate:[192501010000 TO 192512312359]"
+        );
+
+        assert_eq!(
+            translate_boolean_query("title:quantum NOT (a OR b)".to_string(), QueryTarget::ArXiv)
+                .unwrap(),
+            "ti:quantum ANDNOT (all:a OR all:b)"
+        );
+        assert!(matches!(
+            translate_boolean_query("NOT a".to_string(), QueryTarget::ArXiv),
+            Err(QueryParseError::UnsupportedNot)
+        ));
+        assert!(matches!(
+            translate_boolean_query("(a OR b".to_string(), QueryTarget::Ads),
+            Err(QueryParseError::UnclosedOpen { position: 0 })
+        ));
+    }
+
     #[test]
     fn test_build_paper_query() {
         let query = build_paper_query(
@@ -1,8 +1,12 @@
 //! Parse ADS/arXiv query strings back to form fields
+//!
+//! Also parses free-form boolean queries such as
+//! `(author:einstein OR author:bohr) AND year:1925` into a [`QueryExpr`] tree.
 
 use super::query_builder::{ADSDatabase, QueryLogic};
 use lazy_static::lazy_static;
 use regex::Regex;
+use thiserror::Error;
 
 lazy_static! {
     static ref AUTHOR_PATTERN: Regex = Regex::new(r#"author:"([^"]+)""#).unwrap();
@@ -292,6 +296,249 @@ pub fn parse_arxiv_query(query: String) -> Option<ParsedArXivForm> {
     Some(state)
 }
 
+/// Boolean query tree
+///
+/// `NOT` binds tightest, then `AND` (explicit or implied by adjacent terms),
+/// then `OR`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryExpr {
+    /// A search term, optionally restricted to a field (`author:einstein`)
+    Term {
+        field: Option<String>,
+        value: String,
+    },
+    And(Box<QueryExpr>, Box<QueryExpr>),
+    Or(Box<QueryExpr>, Box<QueryExpr>),
+    Not(Box<QueryExpr>),
+}
+
+/// Error parsing a boolean query; positions are byte offsets into the query
+#[derive(Error, Debug, Clone, PartialEq, uniffi::Error)]
+pub enum QueryParseError {
+    #[error("Empty query")]
+    Empty,
+    #[error("Expected a search term at position {position}")]
+    ExpectedTerm { position: u32 },
+    #[error("Unmatched ')' at position {position}")]
+    UnmatchedClose { position: u32 },
+    #[error("Unclosed '(' at position {position}")]
+    UnclosedOpen { position: u32 },
+    #[error("Unterminated quote at position {position}")]
+    UnterminatedQuote { position: u32 },
+    #[error("NOT must follow another term for this target")]
+    UnsupportedNot,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Word(String),
+}
+
+/// Split a query into tokens, keeping quoted phrases (and any `field:` prefix
+/// attached to them) together.
+fn tokenize(query: &str) -> Result<Vec<(Token, usize)>, QueryParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = query.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push((Token::Open, start));
+            }
+            ')' => {
+                chars.next();
+                tokens.push((Token::Close, start));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&(pos, c)) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    chars.next();
+                    word.push(c);
+                    if c == '"' {
+                        // Consume through the closing quote
+                        loop {
+                            match chars.next() {
+                                Some((_, '"')) => {
+                                    word.push('"');
+                                    break;
+                                }
+                                Some((_, c)) => word.push(c),
+                                None => {
+                                    return Err(QueryParseError::UnterminatedQuote {
+                                        position: pos as u32,
+                                    })
+                                }
+                            }
+                        }
+                    }
+                }
+                let token = match word.as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Word(word),
+                };
+                tokens.push((token, start));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive-descent parser over the token stream
+struct BooleanParser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    len: usize,
+}
+
+impl BooleanParser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    /// Byte offset of the current token, or the end of the query
+    fn offset(&self) -> u32 {
+        self.tokens
+            .get(self.pos)
+            .map_or(self.len, |&(_, offset)| offset) as u32
+    }
+
+    fn parse_or(&mut self) -> Result<QueryExpr, QueryParseError> {
+        let mut expr = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            let rhs = self.parse_and()?;
+            expr = QueryExpr::Or(Box::new(expr), Box::new(rhs));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<QueryExpr, QueryParseError> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            match self.peek() {
+                Some(Token::And) => self.pos += 1,
+                // Adjacent terms are implicitly ANDed
+                Some(Token::Open | Token::Not | Token::Word(_)) => {}
+                _ => break,
+            }
+            let rhs = self.parse_unary()?;
+            expr = QueryExpr::And(Box::new(expr), Box::new(rhs));
+        }
+        Ok(expr)
+    }
+
+    fn parse_unary(&mut self) -> Result<QueryExpr, QueryParseError> {
+        if self.peek() == Some(&Token::Not) {
+            self.pos += 1;
+            return Ok(QueryExpr::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<QueryExpr, QueryParseError> {
+        let position = self.offset();
+        match self.peek().cloned() {
+            Some(Token::Open) => self.parse_group(None),
+            Some(Token::Word(word)) => {
+                self.pos += 1;
+                match split_field(&word) {
+                    // `title:(a b)` applies the field to the whole group
+                    (Some(field), "") if self.peek() == Some(&Token::Open) => {
+                        self.parse_group(Some(field))
+                    }
+                    (_, "") => Err(QueryParseError::ExpectedTerm {
+                        position: position + word.len() as u32,
+                    }),
+                    (field, value) => Ok(QueryExpr::Term {
+                        field: field.map(str::to_string),
+                        value: value.to_string(),
+                    }),
+                }
+            }
+            Some(Token::Close) => Err(QueryParseError::UnmatchedClose { position }),
+            _ => Err(QueryParseError::ExpectedTerm { position }),
+        }
+    }
+
+    fn parse_group(&mut self, field: Option<&str>) -> Result<QueryExpr, QueryParseError> {
+        let open = self.offset();
+        self.pos += 1;
+        let mut expr = self.parse_or()?;
+        if self.peek() != Some(&Token::Close) {
+            return Err(QueryParseError::UnclosedOpen { position: open });
+        }
+        self.pos += 1;
+        if let Some(field) = field {
+            apply_field(&mut expr, field);
+        }
+        Ok(expr)
+    }
+}
+
+/// Split `field:value` into its parts; quoted values are never split
+fn split_field(word: &str) -> (Option<&str>, &str) {
+    match word.find(':') {
+        Some(colon) if !word[..colon].contains('"') && colon > 0 => {
+            (Some(&word[..colon]), &word[colon + 1..])
+        }
+        _ => (None, word),
+    }
+}
+
+/// Give every unfielded term in `expr` the given field
+fn apply_field(expr: &mut QueryExpr, field: &str) {
+    match expr {
+        QueryExpr::Term {
+            field: f @ None, ..
+        } => *f = Some(field.to_string()),
+        QueryExpr::Term { .. } => {}
+        QueryExpr::And(a, b) | QueryExpr::Or(a, b) => {
+            apply_field(a, field);
+            apply_field(b, field);
+        }
+        QueryExpr::Not(a) => apply_field(a, field),
+    }
+}
+
+/// Parse a boolean query with `AND`/`OR`/`NOT` and parentheses
+pub fn parse_boolean_query(query: &str) -> Result<QueryExpr, QueryParseError> {
+    let tokens = tokenize(query)?;
+    if tokens.is_empty() {
+        return Err(QueryParseError::Empty);
+    }
+
+    let mut parser = BooleanParser {
+        tokens,
+        pos: 0,
+        len: query.len(),
+    };
+    let expr = parser.parse_or()?;
+    match parser.peek() {
+        None => Ok(expr),
+        Some(Token::Close) => Err(QueryParseError::UnmatchedClose {
+            position: parser.offset(),
+        }),
+        Some(_) => Err(QueryParseError::ExpectedTerm {
+            position: parser.offset(),
+        }),
+    }
+}
+
 fn clean_query_part(part: &str) -> String {
     part.replace(['(', ')'], "")
         .replace(" AND ", " ")
@@ -330,6 +577,65 @@ mod tests {
         assert_eq!(parsed.search_terms.len(), 3);
     }
 
+    fn term(field: &str, value: &str) -> QueryExpr {
+        QueryExpr::Term {
+            field: Some(field.to_string()),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_boolean_query_grouped() {
+        let expr = parse_boolean_query("(author:einstein OR author:bohr) AND year:1925").unwrap();
+        assert_eq!(
+            expr,
+            QueryExpr::And(
+                Box::new(QueryExpr::Or(
+                    Box::new(term("author", "einstein")),
+                    Box::new(term("author", "bohr")),
+                )),
+                Box::new(term("year", "1925")),
+            )
+        );
+
+        // NOT binds tighter than AND, which binds tighter than OR
+        let expr = parse_boolean_query("a OR b NOT c").unwrap();
+        let QueryExpr::Or(_, rhs) = expr else {
+            panic!("expected OR at the root");
+        };
+        assert!(matches!(*rhs, QueryExpr::And(_, ref c) if matches!(**c, QueryExpr::Not(_))));
+    }
+
+    #[test]
+    fn test_parse_boolean_query_field_group_and_quotes() {
+        let expr = parse_boolean_query(r#"title:(dark matter) author:"Rubin, V.""#).unwrap();
+        assert_eq!(
+            expr,
+            QueryExpr::And(
+                Box::new(QueryExpr::And(
+                    Box::new(term("title", "dark")),
+                    Box::new(term("title", "matter")),
+                )),
+                Box::new(term("author", "\"Rubin, V.\"")),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_boolean_query_mismatched_parens() {
+        assert_eq!(
+            parse_boolean_query("(author:einstein OR author:bohr AND year:1925"),
+            Err(QueryParseError::UnclosedOpen { position: 0 })
+        );
+        let err = parse_boolean_query("author:einstein) AND year:1925").unwrap_err();
+        assert_eq!(err, QueryParseError::UnmatchedClose { position: 15 });
+        assert_eq!(err.to_string(), "Unmatched ')' at position 15");
+        assert_eq!(
+            parse_boolean_query("a AND"),
+            Err(QueryParseError::ExpectedTerm { position: 5 })
+        );
+    }
+
     #[test]
     fn test_parse_classic_query_with_filters() {
         let query = "author:\"Smith, J.\" collection:astronomy property:refereed doctype:article";
@@ -3,6 +3,7 @@
 //! Enables "find similar papers" functionality by computing
 //! vector embeddings and using cosine similarity.
 
+use crate::domain::Publication;
 use fastembed::{EmbeddingModel, InitOptions, TextEmbedding};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -201,6 +202,67 @@ pub struct SimilarityResult {
     pub similarity: f32,
 }
 
+/// Minimum cosine similarity for a publication to count as related
+pub const RELATED_MIN_SIMILARITY: f32 = 0.5;
+
+/// Outcome of a related-publications lookup
+#[derive(Clone, Debug)]
+pub enum RelatedPublications {
+    /// Publications related to the target, most similar first. Empty when
+    /// nothing clears [`RELATED_MIN_SIMILARITY`].
+    Found(Vec<SimilarityResult>),
+    /// The target has no embedding in the corpus yet, so nothing can be
+    /// ranked; embed it first
+    NoEmbedding,
+}
+
+impl RelatedPublications {
+    /// The related publications (empty if the target has no embedding)
+    pub fn results(&self) -> &[SimilarityResult] {
+        match self {
+            RelatedPublications::Found(results) => results,
+            RelatedPublications::NoEmbedding => &[],
+        }
+    }
+}
+
+/// Find the `k` publications in `corpus` most similar to `target`
+///
+/// The target's own embedding is looked up in the corpus by id and excluded
+/// from the results. Candidates embedded with a different model are skipped,
+/// since their vectors aren't comparable, as are candidates below
+/// [`RELATED_MIN_SIMILARITY`].
+pub fn related_publications(
+    target: &Publication,
+    corpus: &[PublicationEmbedding],
+    k: usize,
+) -> RelatedPublications {
+    let Some(target_embedding) = corpus.iter().find(|emb| emb.publication_id == target.id) else {
+        return RelatedPublications::NoEmbedding;
+    };
+
+    let mut results: Vec<SimilarityResult> = corpus
+        .iter()
+        .filter(|emb| emb.publication_id != target.id && emb.model == target_embedding.model)
+        .filter_map(|emb| {
+            let similarity = cosine_similarity(&target_embedding.vector, &emb.vector);
+            (similarity >= RELATED_MIN_SIMILARITY).then(|| SimilarityResult {
+                publication_id: emb.publication_id.clone(),
+                similarity,
+            })
+        })
+        .collect();
+
+    results.sort_by(|a, b| {
+        b.similarity
+            .partial_cmp(&a.similarity)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    results.truncate(k);
+    RelatedPublications::Found(results)
+}
+
 /// Embedding storage format for persistence
 #[derive(uniffi::Record, Clone, Debug, Serialize, Deserialize)]
 pub struct StoredEmbedding {
@@ -255,4 +317,46 @@ mod tests {
         assert_eq!(results[0].publication_id, "a");
         assert!(results[0].similarity > results[1].similarity);
     }
+
+    #[test]
+    fn test_related_publications() {
+        let embedding = |id: &str, vector: Vec<f32>| PublicationEmbedding {
+            publication_id: id.to_string(),
+            vector,
+            model: "test".to_string(),
+        };
+
+        let mut target = Publication::new(
+            "Target2024".to_string(),
+            "article".to_string(),
+            "Target".to_string(),
+        );
+        target.id = "target".to_string();
+
+        let corpus = vec![
+            embedding("far", vec![0.0, 0.0, 1.0]),
+            embedding("near", vec![0.9, 0.1, 0.0]),
+            embedding("target", vec![1.0, 0.0, 0.0]),
+            embedding("nearest", vec![1.0, 0.01, 0.0]),
+            embedding("middling", vec![0.7, 0.7, 0.0]),
+        ];
+
+        let related = related_publications(&target, &corpus, 2);
+        let ids: Vec<&str> = related
+            .results()
+            .iter()
+            .map(|r| r.publication_id.as_str())
+            .collect();
+        assert_eq!(ids, ["nearest", "near"]);
+
+        // "far" is below the threshold even with room for it
+        let related = related_publications(&target, &corpus, 10);
+        assert_eq!(related.results().len(), 3);
+
+        target.id = "unembedded".to_string();
+        assert!(matches!(
+            related_publications(&target, &corpus, 2),
+            RelatedPublications::NoEmbedding
+        ));
+    }
 }
@@ -9,9 +9,11 @@ pub mod arxiv;
 pub mod crossref;
 pub mod pubmed;
 pub mod traits;
+pub mod unpaywall;
 
 #[cfg(feature = "native")]
 pub use arxiv::*;
 pub use crossref::*;
 pub use pubmed::*;
 pub use traits::*;
+pub use unpaywall::{OpenAccessInfo, UnpaywallSource};
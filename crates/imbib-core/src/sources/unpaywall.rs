@@ -0,0 +1,213 @@
+//! Unpaywall source plugin for open-access status
+//!
+//! API docs: https://unpaywall.org/products/api
+//! Rate limit: 100,000 requests per day; an email address is required
+
+use super::traits::{SourceError, SourceMetadata};
+use crate::domain::{OpenAccessStatus, PdfLink, PdfLinkType};
+use serde::Deserialize;
+
+#[cfg(feature = "native")]
+use crate::http::HttpClient;
+
+const BASE_URL: &str = "https://api.unpaywall.org/v2";
+
+#[derive(Debug, Deserialize)]
+struct UnpaywallResponse {
+    is_oa: Option<bool>,
+    oa_status: Option<String>,
+    best_oa_location: Option<UnpaywallLocation>,
+    #[serde(default)]
+    error: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnpaywallLocation {
+    url: Option<String>,
+    url_for_pdf: Option<String>,
+    host_type: Option<String>,
+    version: Option<String>,
+}
+
+/// Open-access status and best available OA copy for a DOI
+#[derive(uniffi::Record, Clone, Debug)]
+pub struct OpenAccessInfo {
+    pub status: OpenAccessStatus,
+    pub pdf_link: Option<PdfLink>,
+}
+
+impl OpenAccessInfo {
+    fn unknown() -> Self {
+        Self {
+            status: OpenAccessStatus::Unknown,
+            pdf_link: None,
+        }
+    }
+}
+
+pub struct UnpaywallSource;
+
+impl UnpaywallSource {
+    pub fn metadata() -> SourceMetadata {
+        SourceMetadata {
+            id: "unpaywall",
+            name: "Unpaywall",
+            description: "Open-access status and legal free copies for DOIs",
+            base_url: "https://api.unpaywall.org",
+            rate_limit_per_second: 10.0,
+            supports_bibtex: false,
+            supports_ris: false,
+            requires_api_key: false,
+        }
+    }
+
+    /// Build the lookup URL for a DOI
+    pub fn lookup_url(doi: &str, email: &str) -> String {
+        let doi = doi
+            .trim()
+            .trim_start_matches("https://doi.org/")
+            .trim_start_matches("http://doi.org/")
+            .trim_start_matches("doi:");
+        // DOIs keep their slash; Unpaywall expects it unescaped in the path
+        format!(
+            "{}/{}?email={}",
+            BASE_URL,
+            urlencoding::encode(doi).replace("%2F", "/"),
+            urlencoding::encode(email)
+        )
+    }
+
+    /// Parse an Unpaywall DOI response
+    ///
+    /// Error responses (e.g. a DOI Unpaywall doesn't know) map to
+    /// `OpenAccessStatus::Unknown` rather than an error.
+    pub fn parse_response(json: &str) -> Result<OpenAccessInfo, SourceError> {
+        let response: UnpaywallResponse = serde_json::from_str(json)
+            .map_err(|e| SourceError::Parse(format!("Invalid Unpaywall JSON: {}", e)))?;
+
+        if response.error {
+            return Ok(OpenAccessInfo::unknown());
+        }
+
+        let status = match response.oa_status.as_deref() {
+            Some("gold") => OpenAccessStatus::Gold,
+            Some("green") => OpenAccessStatus::Green,
+            Some("bronze") => OpenAccessStatus::Bronze,
+            Some("hybrid") => OpenAccessStatus::Hybrid,
+            Some("closed") => OpenAccessStatus::Closed,
+            _ if response.is_oa == Some(false) => OpenAccessStatus::Closed,
+            _ => OpenAccessStatus::Unknown,
+        };
+
+        let pdf_link = response.best_oa_location.and_then(|loc| {
+            let url = loc.url_for_pdf.or(loc.url)?;
+            let description = match (loc.host_type.as_deref(), loc.version.as_deref()) {
+                (Some("publisher"), _) => "Open access (publisher)".to_string(),
+                (Some("repository"), Some(version)) => {
+                    format!("Open access (repository, {})", version)
+                }
+                (Some("repository"), None) => "Open access (repository)".to_string(),
+                _ => "Open access".to_string(),
+            };
+            Some(PdfLink {
+                url,
+                link_type: PdfLinkType::OpenAccess,
+                description: Some(description),
+            })
+        });
+
+        Ok(OpenAccessInfo { status, pdf_link })
+    }
+}
+
+/// Fetch open-access status and best OA PDF link for a DOI
+#[cfg(feature = "native")]
+pub async fn fetch_oa_info(doi: &str, email: &str) -> Result<OpenAccessInfo, SourceError> {
+    let client = HttpClient::new("imbib/1.0 (https://imbib.app)");
+    let response = client.get(&UnpaywallSource::lookup_url(doi, email)).await?;
+
+    match response.status {
+        200 => UnpaywallSource::parse_response(&response.body),
+        404 => Ok(OpenAccessInfo::unknown()),
+        status => Err(SourceError::Http(crate::http::HttpError::RequestFailed {
+            message: format!("Status {}", status),
+        })),
+    }
+}
+
+/// Fetch open-access status for a DOI
+#[cfg(feature = "native")]
+pub async fn fetch_oa_status(doi: &str, email: &str) -> Result<OpenAccessStatus, SourceError> {
+    fetch_oa_info(doi, email).await.map(|info| info.status)
+}
+
+/// Parse Unpaywall DOI response JSON (exported for FFI)
+#[uniffi::export]
+pub fn parse_unpaywall_response(json: String) -> Result<OpenAccessInfo, crate::error::FfiError> {
+    UnpaywallSource::parse_response(&json).map_err(|e| crate::error::FfiError::ParseError {
+        message: format!("{:?}", e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Trimmed recording of https://api.unpaywall.org/v2/10.7717/peerj.4375
+    const GOLD_RESPONSE: &str = r#"{
+        "doi": "10.7717/peerj.4375",
+        "is_oa": true,
+        "oa_status": "gold",
+        "best_oa_location": {
+            "host_type": "publisher",
+            "is_best": true,
+            "license": "cc-by",
+            "url": "https://doi.org/10.7717/peerj.4375",
+            "url_for_landing_page": "https://doi.org/10.7717/peerj.4375",
+            "url_for_pdf": "https://peerj.com/articles/4375.pdf",
+            "version": "publishedVersion"
+        },
+        "title": "The state of OA"
+    }"#;
+
+    const UNKNOWN_DOI_RESPONSE: &str = r#"{
+        "HTTP_status_code": 404,
+        "error": true,
+        "message": "'10.1234/nope' is an invalid doi. See https://doi.org/10.1234/nope"
+    }"#;
+
+    #[test]
+    fn test_parse_gold_response() {
+        let info = UnpaywallSource::parse_response(GOLD_RESPONSE).unwrap();
+        assert_eq!(info.status, OpenAccessStatus::Gold);
+
+        let link = info.pdf_link.unwrap();
+        assert_eq!(link.url, "https://peerj.com/articles/4375.pdf");
+        assert!(matches!(link.link_type, PdfLinkType::OpenAccess));
+        assert_eq!(link.description.as_deref(), Some("Open access (publisher)"));
+    }
+
+    #[test]
+    fn test_parse_unknown_doi() {
+        let info = UnpaywallSource::parse_response(UNKNOWN_DOI_RESPONSE).unwrap();
+        assert_eq!(info.status, OpenAccessStatus::Unknown);
+        assert!(info.pdf_link.is_none());
+    }
+
+    #[test]
+    fn test_parse_closed_response() {
+        let json =
+            r#"{"doi": "10.1/x", "is_oa": false, "oa_status": "closed", "best_oa_location": null}"#;
+        let info = UnpaywallSource::parse_response(json).unwrap();
+        assert_eq!(info.status, OpenAccessStatus::Closed);
+        assert!(info.pdf_link.is_none());
+    }
+
+    #[test]
+    fn test_lookup_url() {
+        assert_eq!(
+            UnpaywallSource::lookup_url("https://doi.org/10.1038/nature12373", "me@example.org"),
+            "https://api.unpaywall.org/v2/10.1038/nature12373?email=me%40example.org"
+        );
+    }
+}
@@ -113,6 +113,7 @@ pub fn publication_to_item(pub_data: &Publication, library_id: Option<ItemId>) -
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: pub_data.tags.clone(),
@@ -255,6 +256,7 @@ pub fn library_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -297,6 +299,7 @@ pub fn collection_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -341,6 +344,7 @@ pub fn tag_definition_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -388,6 +392,7 @@ pub fn linked_file_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -441,6 +446,7 @@ pub fn smart_search_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -473,6 +479,7 @@ pub fn muted_item_to_item(mute_type: &str, value: &str) -> Item {
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -512,6 +519,7 @@ pub fn dismissed_paper_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -561,6 +569,7 @@ pub fn scix_library_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -606,6 +615,7 @@ pub fn annotation_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -647,6 +657,7 @@ pub fn comment_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -690,6 +701,7 @@ pub fn assignment_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -733,6 +745,7 @@ pub fn activity_record_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -774,6 +787,7 @@ pub fn recommendation_profile_to_item(
         author: "system".into(),
         author_kind: ActorKind::System,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
@@ -835,6 +849,7 @@ pub fn artifact_to_item(
         author: "user".into(),
         author_kind: ActorKind::Human,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags,
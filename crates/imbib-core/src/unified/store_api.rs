@@ -25,6 +25,8 @@ pub enum StoreApiError {
     InvalidInput(String),
     #[error("Storage error: {0}")]
     Storage(String),
+    #[error("Conflict: {0}")]
+    Conflict(String),
 }
 
 impl From<impress_core::StoreError> for StoreApiError {
@@ -37,6 +39,9 @@ impl From<impress_core::StoreError> for StoreApiError {
             impress_core::StoreError::SchemaNotFound(s) => StoreApiError::NotFound(s),
             impress_core::StoreError::Validation(msg) => StoreApiError::InvalidInput(msg),
             impress_core::StoreError::Storage(msg) => StoreApiError::Storage(msg),
+            e @ impress_core::StoreError::RevisionConflict { .. } => {
+                StoreApiError::Conflict(e.to_string())
+            }
         }
     }
 }
@@ -39,6 +39,17 @@ impl FfiImapClient {
         })
     }
 
+    /// Connect with an OAuth2 access token (XOAUTH2, or LOGIN if unsupported).
+    ///
+    /// Fails with `TokenExpired` when the server rejects the token.
+    pub fn new_oauth2(config: crate::ffi_types::AccountConfig, access_token: String) -> Result<Self> {
+        let internal_config = config.to_internal();
+        let client = crate::imap::ImapClient::new_oauth2(&internal_config, &access_token)?;
+        Ok(Self {
+            inner: Mutex::new(client),
+        })
+    }
+
     pub fn list_mailboxes(&self) -> Result<Vec<crate::ffi_types::Mailbox>> {
         let mut guard = self.inner.lock().map_err(|_| ImpartError::Imap("Lock poisoned".to_string()))?;
         let mailboxes = guard.list_mailboxes()?;
@@ -51,12 +62,26 @@ impl FfiImapClient {
         Ok(envelopes.into_iter().map(Into::into).collect())
     }
 
+    /// Fetch headers only, for listing; bodies are loaded with `fetch_body`.
+    pub fn fetch_headers(&self, mailbox_name: String, uids: Vec<u32>) -> Result<Vec<crate::ffi_types::Envelope>> {
+        let mut guard = self.inner.lock().map_err(|_| ImpartError::Imap("Lock poisoned".to_string()))?;
+        let envelopes = guard.fetch_headers(&mailbox_name, &uids)?;
+        Ok(envelopes.into_iter().map(Into::into).collect())
+    }
+
     pub fn fetch_message(&self, mailbox_name: String, uid: u32) -> Result<crate::ffi_types::ParsedMessage> {
         let mut guard = self.inner.lock().map_err(|_| ImpartError::Imap("Lock poisoned".to_string()))?;
         let parsed = guard.fetch_message(&mailbox_name, uid)?;
         Ok(parsed.into())
     }
 
+    /// Load the body of a message previously listed with `fetch_headers`.
+    pub fn fetch_body(&self, mailbox_name: String, uid: u32) -> Result<crate::ffi_types::MessageBody> {
+        let mut guard = self.inner.lock().map_err(|_| ImpartError::Imap("Lock poisoned".to_string()))?;
+        let parsed = guard.fetch_message(&mailbox_name, uid)?;
+        Ok(parsed.into())
+    }
+
     pub fn set_flags(&self, mailbox_name: String, uids: Vec<u32>, flags: Vec<String>, add: bool) -> Result<()> {
         let mut guard = self.inner.lock().map_err(|_| ImpartError::Imap("Lock poisoned".to_string()))?;
         guard.set_flags(&mailbox_name, &uids, &flags, add)
@@ -88,6 +113,16 @@ impl FfiSmtpClient {
         Ok(Self { inner: client })
     }
 
+    /// Send with an OAuth2 access token (XOAUTH2, or LOGIN if unsupported).
+    ///
+    /// The token is checked when sending, which fails with `TokenExpired`
+    /// if the server rejects it.
+    pub fn new_oauth2(config: crate::ffi_types::AccountConfig, access_token: String) -> Result<Self> {
+        let internal_config = config.to_internal();
+        let client = crate::smtp::SmtpClient::new_oauth2(&internal_config, &access_token)?;
+        Ok(Self { inner: client })
+    }
+
     pub fn send(&self, draft: crate::ffi_types::DraftMessage) -> Result<()> {
         let internal_draft = crate::smtp::DraftMessage {
             from_email: draft.from_email,
@@ -209,6 +209,26 @@ impl From<mime::ParsedMessage> for ParsedMessage {
     }
 }
 
+// MARK: - MessageBody
+
+/// Message body loaded on demand after a headers-only fetch (FFI-compatible).
+#[derive(Debug, Clone)]
+pub struct MessageBody {
+    pub text_body: Option<String>,
+    pub html_body: Option<String>,
+    pub attachments: Vec<Attachment>,
+}
+
+impl From<mime::ParsedMessage> for MessageBody {
+    fn from(m: mime::ParsedMessage) -> Self {
+        Self {
+            text_body: m.text_body,
+            html_body: m.html_body,
+            attachments: m.attachments.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 // MARK: - DraftMessage
 
 /// Draft message for sending (FFI-compatible).
@@ -5,6 +5,11 @@ use crate::types::{Address, Envelope, Mailbox};
 use crate::mime::ParsedMessage;
 use imap::{ClientBuilder, Session};
 use std::borrow::Cow;
+use std::cell::RefCell;
+
+/// FETCH items for headers-only listing. `BODY.PEEK` leaves `\Seen` untouched
+/// and `[HEADER]` keeps the server from sending the message body.
+const HEADERS_FETCH_QUERY: &str = "(UID FLAGS ENVELOPE BODY.PEEK[HEADER])";
 
 // MARK: - IMAP Client
 
@@ -16,17 +21,63 @@ pub struct ImapClient {
 impl ImapClient {
     /// Create a new IMAP connection.
     pub fn new(config: &crate::types::AccountConfig, password: &str) -> Result<Self> {
-        let client = ClientBuilder::new(&config.imap_host, config.imap_port)
+        let client = Self::connect(config)?;
+        Self::login(client, &config.email, password)
+    }
+
+    /// Create a new IMAP connection authenticated with an OAuth2 access token.
+    ///
+    /// Uses SASL XOAUTH2 when the server advertises `AUTH=XOAUTH2`, and LOGIN
+    /// with the token as password otherwise. A rejected token is reported as
+    /// [`ImpartError::TokenExpired`] so the caller can refresh it and retry.
+    pub fn new_oauth2(config: &crate::types::AccountConfig, access_token: &str) -> Result<Self> {
+        let client = Self::connect(config)?;
+        Self::authenticate_oauth2(client, &config.email, access_token)
+    }
+
+    fn connect(config: &crate::types::AccountConfig) -> Result<imap::Client<imap::Connection>> {
+        ClientBuilder::new(&config.imap_host, config.imap_port)
             .connect()
-            .map_err(|e| ImpartError::Network(e.to_string()))?;
+            .map_err(|e| ImpartError::Network(e.to_string()))
+    }
 
+    /// Log in over an already-connected client.
+    fn login(client: imap::Client<imap::Connection>, email: &str, password: &str) -> Result<Self> {
         let session = client
-            .login(&config.email, password)
+            .login(email, password)
             .map_err(|e| ImpartError::Auth(e.0.to_string()))?;
 
         Ok(Self { session })
     }
 
+    /// Authenticate over an already-connected client with XOAUTH2.
+    fn authenticate_oauth2(
+        mut client: imap::Client<imap::Connection>,
+        email: &str,
+        access_token: &str,
+    ) -> Result<Self> {
+        let supports_xoauth2 = client
+            .capabilities()
+            .map_err(|e| ImpartError::Imap(e.to_string()))?
+            .has_str("AUTH=XOAUTH2");
+        if !supports_xoauth2 {
+            return Self::login(client, email, access_token);
+        }
+
+        let authenticator = XOAuth2 {
+            user: email.to_string(),
+            access_token: access_token.to_string(),
+            server_error: RefCell::new(None),
+        };
+        match client.authenticate("XOAUTH2", &authenticator) {
+            Ok(session) => Ok(Self { session }),
+            Err((imap::Error::No(e), _)) => Err(ImpartError::TokenExpired(
+                authenticator.server_error.borrow_mut().take().unwrap_or_else(|| format!("{:?}", e)),
+            )),
+            Err((e, _)) => Err(ImpartError::Auth(e.to_string())),
+        }
+    }
+
     /// List all mailboxes.
     pub fn list_mailboxes(&mut self) -> Result<Vec<Mailbox>> {
         let mailboxes = self
@@ -86,6 +137,47 @@ impl ImapClient {
         Ok(envelopes)
     }
 
+    /// Fetch only the headers of the given messages.
+    ///
+    /// Bodies are not downloaded; load them on demand with [`Self::fetch_message`].
+    /// Unlike [`Self::fetch_envelopes`], this also fills in `references` and
+    /// `date`, which ENVELOPE alone doesn't carry.
+    pub fn fetch_headers(&mut self, mailbox_name: &str, uids: &[u32]) -> Result<Vec<Envelope>> {
+        if uids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.session
+            .select(mailbox_name)
+            .map_err(|e| ImpartError::Imap(e.to_string()))?;
+
+        let uid_str = uids.iter().map(|u| u.to_string()).collect::<Vec<_>>().join(",");
+        let messages = self
+            .session
+            .uid_fetch(&uid_str, HEADERS_FETCH_QUERY)
+            .map_err(|e| ImpartError::Imap(e.to_string()))?;
+
+        let mut envelopes = Vec::new();
+        for msg in messages.iter() {
+            let uid = msg.uid.unwrap_or(0);
+            let headers = msg.header().map(crate::mime::parse_headers).transpose()?;
+
+            let envelope = match (msg.envelope(), headers) {
+                (Some(env), Some(headers)) => merge_headers(convert_envelope(uid, env, &msg.flags()), headers),
+                (Some(env), None) => convert_envelope(uid, env, &msg.flags()),
+                (None, Some(mut headers)) => {
+                    headers.uid = uid;
+                    headers.flags = msg.flags().iter().map(|f| format!("{:?}", f)).collect();
+                    headers
+                }
+                (None, None) => continue,
+            };
+            envelopes.push(envelope);
+        }
+
+        Ok(envelopes)
+    }
+
     /// Fetch full message by UID.
     pub fn fetch_message(&mut self, mailbox_name: &str, uid: u32) -> Result<ParsedMessage> {
         self.session
@@ -163,6 +255,52 @@ impl ImapClient {
     }
 }
 
+// MARK: - XOAUTH2
+
+/// SASL XOAUTH2 initial client response, before base64 encoding.
+///
+/// See <https://developers.google.com/gmail/imap/xoauth2-protocol>.
+pub(crate) fn xoauth2_response(user: &str, access_token: &str) -> String {
+    format!("user={}\x01auth=Bearer {}\x01\x01", user, access_token)
+}
+
+/// XOAUTH2 authenticator for `AUTHENTICATE`.
+///
+/// The server answers a rejected token with a challenge carrying a JSON
+/// error; we keep it for the error message and send the empty response the
+/// protocol requires before the server's final NO.
+struct XOAuth2 {
+    user: String,
+    access_token: String,
+    server_error: RefCell<Option<String>>,
+}
+
+impl imap::Authenticator for XOAuth2 {
+    type Response = String;
+
+    fn process(&self, challenge: &[u8]) -> Self::Response {
+        if challenge.is_empty() {
+            xoauth2_response(&self.user, &self.access_token)
+        } else {
+            *self.server_error.borrow_mut() = Some(String::from_utf8_lossy(challenge).into_owned());
+            String::new()
+        }
+    }
+}
+
+/// Fill in the fields ENVELOPE lacks from the parsed header block.
+///
+/// The server-parsed ENVELOPE addresses are kept; they handle quoting and
+/// encoded words better than our header parser.
+fn merge_headers(mut envelope: Envelope, headers: Envelope) -> Envelope {
+    envelope.references = headers.references;
+    envelope.date = headers.date.or(envelope.date);
+    envelope.message_id = envelope.message_id.or(headers.message_id);
+    envelope.in_reply_to = envelope.in_reply_to.or(headers.in_reply_to);
+    envelope.subject = envelope.subject.or(headers.subject);
+    envelope
+}
+
 /// Convert imap-proto envelope to our Envelope type.
 fn convert_envelope(uid: u32, env: &imap_proto::Envelope, flags: &[imap::types::Flag]) -> Envelope {
     fn cow_to_string(cow: &Option<Cow<[u8]>>) -> Option<String> {
@@ -211,4 +349,185 @@ mod tests {
         let result = cow.as_ref().map(|c| String::from_utf8_lossy(c).to_string());
         assert_eq!(result, Some("test".to_string()));
     }
+
+    const HEADER_BLOCK: &str = "Message-ID: <child@example.com>\r\n\
+        In-Reply-To: <parent@example.com>\r\n\
+        References: <root@example.com> <parent@example.com>\r\n\
+        Subject: Hello\r\n\
+        Date: Mon, 7 Feb 1994 21:52:25 -0800\r\n\
+        \r\n";
+
+    /// Minimal scripted IMAP server. Returns its address and a handle that
+    /// yields every command line it received once the client logs out.
+    fn mock_server() -> (std::net::SocketAddr, std::thread::JoinHandle<Vec<String>>) {
+        mock_server_with("IMAP4rev1", true)
+    }
+
+    /// Mock server advertising `capabilities`. AUTHENTICATE exchanges are
+    /// recorded line by line and succeed only if `accept_token` is set.
+    fn mock_server_with(
+        capabilities: &'static str,
+        accept_token: bool,
+    ) -> (std::net::SocketAddr, std::thread::JoinHandle<Vec<String>>) {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut writer = stream.try_clone().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut commands = Vec::new();
+
+            writer.write_all(b"* OK IMAP4rev1 mock ready\r\n").unwrap();
+
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 {
+                let command = line.trim_end().to_string();
+                line.clear();
+                let mut parts = command.splitn(3, ' ');
+                let tag = parts.next().unwrap_or_default().to_string();
+                let verb = parts.next().unwrap_or_default().to_ascii_uppercase();
+                commands.push(command.clone());
+
+                let response = match verb.as_str() {
+                    "CAPABILITY" => format!("* CAPABILITY {}\r\n{} OK CAPABILITY completed\r\n", capabilities, tag),
+                    "AUTHENTICATE" => {
+                        // Empty challenge, then the client's initial response
+                        writer.write_all(b"+ \r\n").unwrap();
+                        reader.read_line(&mut line).unwrap();
+                        commands.push(line.trim_end().to_string());
+                        line.clear();
+                        if accept_token {
+                            format!("{} OK AUTHENTICATE completed\r\n", tag)
+                        } else {
+                            // Error challenge, answered with an empty line
+                            writer
+                                .write_all(b"+ eyJzdGF0dXMiOiI0MDEiLCJzY2hlbWVzIjoiQmVhcmVyIn0=\r\n")
+                                .unwrap();
+                            reader.read_line(&mut line).unwrap();
+                            commands.push(line.trim_end().to_string());
+                            line.clear();
+                            format!("{} NO [AUTHENTICATIONFAILED] Invalid credentials\r\n", tag)
+                        }
+                    }
+                    "SELECT" => format!(
+                        "* 1 EXISTS\r\n* 0 RECENT\r\n* FLAGS (\\Seen)\r\n{} OK [READ-WRITE] SELECT completed\r\n",
+                        tag
+                    ),
+                    "UID" => format!(
+                        "* 1 FETCH (UID 42 FLAGS (\\Seen) ENVELOPE (\"Mon, 7 Feb 1994 21:52:25 -0800\" \"Hello\" \
+                         ((\"Alice\" NIL \"alice\" \"example.com\")) ((\"Alice\" NIL \"alice\" \"example.com\")) \
+                         ((\"Alice\" NIL \"alice\" \"example.com\")) ((NIL NIL \"bob\" \"example.com\")) NIL NIL \
+                         \"<parent@example.com>\" \"<child@example.com>\") BODY[HEADER] {{{}}}\r\n{})\r\n\
+                         {} OK UID FETCH completed\r\n",
+                        HEADER_BLOCK.len(),
+                        HEADER_BLOCK,
+                        tag
+                    ),
+                    "LOGOUT" => format!("* BYE logging out\r\n{} OK LOGOUT completed\r\n", tag),
+                    _ => format!("{} OK completed\r\n", tag),
+                };
+                writer.write_all(response.as_bytes()).unwrap();
+                if verb == "LOGOUT" {
+                    break;
+                }
+            }
+            commands
+        });
+
+        (addr, handle)
+    }
+
+    #[test]
+    fn test_fetch_headers_requests_only_headers() {
+        let (addr, server) = mock_server();
+
+        let stream = std::net::TcpStream::connect(addr).unwrap();
+        let mut client = imap::Client::new(Box::new(stream) as imap::Connection);
+        client.read_greeting().unwrap();
+        let mut imap = ImapClient::login(client, "alice@example.com", "secret").unwrap();
+
+        let envelopes = imap.fetch_headers("INBOX", &[42]).unwrap();
+        imap.disconnect();
+        let commands = server.join().unwrap();
+
+        let fetch = commands
+            .iter()
+            .find(|c| c.to_ascii_uppercase().contains("UID FETCH"))
+            .expect("a UID FETCH command");
+        assert!(fetch.contains("BODY.PEEK[HEADER]"));
+        assert!(!fetch.contains("BODY[]"));
+        assert!(!fetch.contains("BODY.PEEK[]"));
+        assert!(!fetch.contains("RFC822"));
+
+        assert_eq!(envelopes.len(), 1);
+        let env = &envelopes[0];
+        assert_eq!(env.uid, 42);
+        assert_eq!(env.subject.as_deref(), Some("Hello"));
+        assert_eq!(env.from[0].email, "alice@example.com");
+        assert_eq!(env.references, vec!["root@example.com", "parent@example.com"]);
+        assert!(env.date.is_some());
+    }
+
+    #[test]
+    fn test_xoauth2_client_response() {
+        let (addr, server) = mock_server_with("IMAP4rev1 AUTH=XOAUTH2", true);
+
+        let stream = std::net::TcpStream::connect(addr).unwrap();
+        let mut client = imap::Client::new(Box::new(stream) as imap::Connection);
+        client.read_greeting().unwrap();
+        let mut imap = ImapClient::authenticate_oauth2(client, "alice@example.com", "ya29.a0-token").unwrap();
+        imap.disconnect();
+        let commands = server.join().unwrap();
+
+        let auth = commands
+            .iter()
+            .position(|c| c.to_ascii_uppercase().contains("AUTHENTICATE XOAUTH2"))
+            .expect("an AUTHENTICATE XOAUTH2 command");
+        // base64("user=alice@example.com^Aauth=Bearer ya29.a0-token^A^A")
+        assert_eq!(
+            commands[auth + 1],
+            "dXNlcj1hbGljZUBleGFtcGxlLmNvbQFhdXRoPUJlYXJlciB5YTI5LmEwLXRva2VuAQE="
+        );
+        assert!(!commands.iter().any(|c| c.to_ascii_uppercase().contains(" LOGIN ")));
+    }
+
+    #[test]
+    fn test_xoauth2_falls_back_to_login() {
+        let (addr, server) = mock_server_with("IMAP4rev1", true);
+
+        let stream = std::net::TcpStream::connect(addr).unwrap();
+        let mut client = imap::Client::new(Box::new(stream) as imap::Connection);
+        client.read_greeting().unwrap();
+        let mut imap = ImapClient::authenticate_oauth2(client, "alice@example.com", "token").unwrap();
+        imap.disconnect();
+        let commands = server.join().unwrap();
+
+        assert!(commands.iter().any(|c| c.to_ascii_uppercase().contains(" LOGIN ")));
+        assert!(!commands.iter().any(|c| c.to_ascii_uppercase().contains("AUTHENTICATE")));
+    }
+
+    #[test]
+    fn test_rejected_token_is_token_expired() {
+        let (addr, _server) = mock_server_with("IMAP4rev1 AUTH=XOAUTH2", false);
+
+        let stream = std::net::TcpStream::connect(addr).unwrap();
+        let mut client = imap::Client::new(Box::new(stream) as imap::Connection);
+        client.read_greeting().unwrap();
+        match ImapClient::authenticate_oauth2(client, "alice@example.com", "expired") {
+            Err(ImpartError::TokenExpired(message)) => assert!(message.contains("401")),
+            Err(e) => panic!("expected TokenExpired, got {}", e),
+            Ok(_) => panic!("expected TokenExpired, got a session"),
+        }
+    }
+
+    #[test]
+    fn test_xoauth2_response_format() {
+        assert_eq!(
+            xoauth2_response("bob@example.com", "tok"),
+            "user=bob@example.com\x01auth=Bearer tok\x01\x01"
+        );
+    }
 }
@@ -40,6 +40,7 @@ pub mod types;
 pub mod mime;
 pub mod threading;
 pub mod mbox;
+pub mod search;
 
 // Provenance event sourcing for research conversations
 pub mod provenance;
@@ -96,10 +97,18 @@ pub enum ImpartError {
     #[error("Authentication failed: {0}")]
     Auth(String),
 
+    /// The OAuth2 access token was rejected; refresh it and reconnect.
+    #[error("OAuth2 token expired or rejected: {0}")]
+    TokenExpired(String),
+
     /// Network error.
     #[error("Network error: {0}")]
     Network(String),
 
+    /// Search index error (e.g. an unreadable or outdated saved index).
+    #[error("Search index error: {0}")]
+    Search(String),
+
     /// I/O error.
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
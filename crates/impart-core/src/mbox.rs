@@ -22,8 +22,9 @@
 
 use crate::{Address, ImpartError, Result};
 use chrono::{DateTime, Utc};
-use std::io::{BufRead, BufReader, Write};
-use std::path::Path;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 // MARK: - Mbox Message
@@ -164,7 +165,7 @@ impl MboxMessage {
 
         // Body with From_ escaping (MBOXRD style)
         for line in self.text_body.lines() {
-            if line.starts_with("From ") || line.starts_with(">From ") {
+            if line.trim_start_matches('>').starts_with("From ") {
                 output.push('>');
             }
             output.push_str(line);
@@ -210,6 +211,7 @@ impl MboxFile {
                 .map_err(|e| ImpartError::Io(e))?;
         }
 
+        invalidate_index(path);
         Ok(())
     }
 
@@ -236,7 +238,7 @@ impl MboxFile {
             }
 
             if let Some(ref mut msg) = current_message {
-                msg.push_str(&line);
+                msg.push_str(unescape_from_line(&line));
                 msg.push('\n');
             }
         }
@@ -252,6 +254,9 @@ impl MboxFile {
     }
 
     /// Append a message to an existing mbox file.
+    ///
+    /// Prefer [`MboxWriter`], which indexes messages and recovers from
+    /// interrupted writes.
     pub fn append_message(path: &Path, message: &MboxMessage) -> Result<()> {
         let mut file = std::fs::OpenOptions::new()
             .create(true)
@@ -262,12 +267,236 @@ impl MboxFile {
         file.write_all(message.to_mbox_string().as_bytes())
             .map_err(|e| ImpartError::Io(e))?;
 
+        invalidate_index(path);
         Ok(())
     }
 }
 
+// MARK: - Mbox Writer
+
+/// Location of one message within an mbox file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MboxIndexEntry {
+    /// Message-ID header value, if the message has one.
+    pub message_id: Option<String>,
+    /// Byte offset of the message's `From ` line.
+    pub offset: u64,
+    /// Length of the message in bytes, including its trailing blank line.
+    pub length: u64,
+}
+
+/// Sidecar index of the messages in an mbox file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MboxIndex {
+    /// Messages in file order.
+    pub entries: Vec<MboxIndexEntry>,
+}
+
+impl MboxIndex {
+    /// Byte offset just past the last indexed message.
+    pub fn end(&self) -> u64 {
+        self.entries.last().map(|e| e.offset + e.length).unwrap_or(0)
+    }
+}
+
+/// Appends messages to an mbox file without rewriting it.
+///
+/// Keeps an index of message offsets next to the mbox (`<file>.idx`), replaced
+/// atomically once a message has been fully written and synced. The mbox is
+/// never truncated: anything past the last indexed message (messages another
+/// client appended, or the remains of an interrupted append) is scanned and
+/// added to the index on open. Existing mbox files without an index, or whose
+/// index no longer matches the file, are scanned in full.
+#[derive(Debug)]
+pub struct MboxWriter {
+    path: PathBuf,
+    index_path: PathBuf,
+    index: MboxIndex,
+}
+
+impl MboxWriter {
+    /// Open (or prepare to create) an mbox file for appending.
+    pub fn open(path: &Path) -> Result<Self> {
+        let index_path = index_path_for(path);
+        let len = match std::fs::metadata(path) {
+            Ok(metadata) => metadata.len(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(ImpartError::Io(e)),
+        };
+
+        let mut index = std::fs::read(&index_path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<MboxIndex>(&bytes).ok())
+            .filter(|index| index.end() <= len && last_entry_matches(path, index));
+        if index.is_none() {
+            let contents = if len > 0 { std::fs::read(path)? } else { Vec::new() };
+            index = Some(MboxIndex { entries: scan_entries(&contents) });
+        }
+        let mut index = index.unwrap_or_default();
+
+        let end = index.end();
+        let mut extended = false;
+        if len > end {
+            // Messages written after the index was last saved
+            let mut tail = Vec::new();
+            let mut file = std::fs::File::open(path)?;
+            file.seek(SeekFrom::Start(end))?;
+            file.read_to_end(&mut tail)?;
+            for mut entry in scan_entries(&tail) {
+                entry.offset += end;
+                index.entries.push(entry);
+                extended = true;
+            }
+        }
+
+        let writer = Self {
+            path: path.to_path_buf(),
+            index_path,
+            index,
+        };
+        if extended {
+            writer.save_index()?;
+        }
+        Ok(writer)
+    }
+
+    /// Path of the mbox file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The current message index.
+    pub fn index(&self) -> &MboxIndex {
+        &self.index
+    }
+
+    /// Append a single message, writing only that message's bytes.
+    pub fn append(&mut self, message: &MboxMessage) -> Result<MboxIndexEntry> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&self.path)?;
+
+        let mut offset = file.metadata()?.len();
+        if offset > 0 {
+            // A new From_ line must start at the beginning of a line
+            let mut last = [0u8; 1];
+            file.seek(SeekFrom::End(-1))?;
+            file.read_exact(&mut last)?;
+            if last[0] != b'\n' {
+                file.write_all(b"\n")?;
+                offset += 1;
+            }
+        }
+
+        let bytes = message.to_mbox_string().into_bytes();
+        file.write_all(&bytes)?;
+        file.sync_data()?;
+
+        let entry = MboxIndexEntry {
+            message_id: Some(message.message_id.clone()),
+            offset,
+            length: bytes.len() as u64,
+        };
+        self.index.entries.push(entry.clone());
+        self.save_index()?;
+
+        Ok(entry)
+    }
+
+    /// Replace the index file atomically (write a temp file, then rename).
+    fn save_index(&self) -> Result<()> {
+        let json = serde_json::to_vec(&self.index)
+            .map_err(|e| ImpartError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e)))?;
+
+        let mut tmp_path = self.index_path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let mut tmp = std::fs::File::create(&tmp_path)?;
+        tmp.write_all(&json)?;
+        tmp.sync_all()?;
+        std::fs::rename(&tmp_path, &self.index_path)?;
+        Ok(())
+    }
+}
+
+/// Index file kept next to an mbox: `<file>.idx`.
+fn index_path_for(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".idx");
+    PathBuf::from(name)
+}
+
+/// Remove a writer index made stale by writing the mbox directly; the next
+/// [`MboxWriter::open`] rebuilds it from the file.
+fn invalidate_index(path: &Path) {
+    let _ = std::fs::remove_file(index_path_for(path));
+}
+
+/// Whether the index's last entry still points at a `From ` line, i.e. the
+/// mbox hasn't been rewritten since the index was saved.
+fn last_entry_matches(path: &Path, index: &MboxIndex) -> bool {
+    let Some(last) = index.entries.last() else {
+        return true;
+    };
+    let mut start = [0u8; 5];
+    std::fs::File::open(path)
+        .and_then(|mut file| {
+            file.seek(SeekFrom::Start(last.offset))?;
+            file.read_exact(&mut start)
+        })
+        .map(|_| &start == b"From ")
+        .unwrap_or(false)
+}
+
+/// Build index entries by scanning an mbox for `From ` separator lines.
+fn scan_entries(contents: &[u8]) -> Vec<MboxIndexEntry> {
+    let mut starts = Vec::new();
+    let mut line_start = 0;
+    while line_start < contents.len() {
+        if contents[line_start..].starts_with(b"From ") {
+            starts.push(line_start);
+        }
+        line_start = match contents[line_start..].iter().position(|&b| b == b'\n') {
+            Some(newline) => line_start + newline + 1,
+            None => contents.len(),
+        };
+    }
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = starts.get(i + 1).copied().unwrap_or(contents.len());
+            let text = String::from_utf8_lossy(&contents[start..end]);
+            let message_id = text
+                .lines()
+                .take_while(|line| !line.is_empty())
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("Message-ID").then(|| value.trim().to_string())
+                });
+            MboxIndexEntry {
+                message_id,
+                offset: start as u64,
+                length: (end - start) as u64,
+            }
+        })
+        .collect()
+}
+
 // MARK: - Parsing
 
+/// Undo MBOXRD escaping: `>From `, `>>From `, ... lose one `>`.
+fn unescape_from_line(line: &str) -> &str {
+    match line.strip_prefix('>') {
+        Some(rest) if rest.trim_start_matches('>').starts_with("From ") => rest,
+        _ => line,
+    }
+}
+
 /// Parse a single mbox message from text.
 fn parse_mbox_message(text: &str) -> Result<MboxMessage> {
     let raw = text.as_bytes();
@@ -364,7 +593,7 @@ impl ConversationMbox {
         message.add_header("X-Impart-Conversation-Title", &self.title);
 
         // Append to file
-        MboxFile::append_message(&self.path, &message)?;
+        MboxWriter::open(&self.path)?.append(&message)?;
 
         self.messages.push(message);
         Ok(())
@@ -530,4 +759,98 @@ mod tests {
         assert!(conv.messages[1].in_reply_to.is_some());
         assert!(!conv.messages[1].references.is_empty());
     }
+
+    #[test]
+    fn test_writer_appends_to_existing_mbox() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("archive.mbox");
+
+        let mut mbox = MboxFile::new();
+        for subject in ["First", "Second"] {
+            mbox.add_message(MboxMessage::new(
+                Address::new("sender@example.com"),
+                vec![Address::new("recipient@example.com")],
+                subject.into(),
+                format!("{} body", subject),
+            ));
+        }
+        mbox.write_to_file(&path).unwrap();
+        let original = std::fs::read(&path).unwrap();
+
+        let mut writer = MboxWriter::open(&path).unwrap();
+        assert_eq!(writer.index().entries.len(), 2);
+
+        let body = "From the top\n>From a quote\nplain line";
+        let message = MboxMessage::new(
+            Address::new("sender@example.com"),
+            vec![Address::new("recipient@example.com")],
+            "Third".into(),
+            body.into(),
+        );
+        let entry = writer.append(&message).unwrap();
+        assert_eq!(entry.offset, original.len() as u64);
+
+        // Earlier bytes are untouched
+        let contents = std::fs::read(&path).unwrap();
+        assert_eq!(&contents[..original.len()], &original[..]);
+
+        let read = MboxFile::read_from_file(&path).unwrap();
+        let subjects: Vec<&str> = read.messages.iter().map(|m| m.subject.as_str()).collect();
+        assert_eq!(subjects, vec!["First", "Second", "Third"]);
+        assert_eq!(read.messages[0].text_body.trim(), "First body");
+        assert_eq!(read.messages[2].text_body.trim(), body);
+        assert_eq!(read.messages[2].message_id, message.message_id);
+
+        // The index survives reopening
+        let writer = MboxWriter::open(&path).unwrap();
+        assert_eq!(writer.index().entries.len(), 3);
+        assert_eq!(writer.index().end(), contents.len() as u64);
+    }
+
+    #[test]
+    fn test_writer_keeps_messages_appended_elsewhere() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("archive.mbox");
+        let message = |subject: &str| {
+            MboxMessage::new(
+                Address::new("sender@example.com"),
+                vec![Address::new("recipient@example.com")],
+                subject.into(),
+                "body".into(),
+            )
+        };
+
+        MboxWriter::open(&path).unwrap().append(&message("Kept")).unwrap();
+
+        // Another client appends without updating the index
+        let external = message("External");
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(external.to_mbox_string().as_bytes()).unwrap();
+        drop(file);
+        let len = std::fs::metadata(&path).unwrap().len();
+
+        let mut writer = MboxWriter::open(&path).unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), len);
+        assert_eq!(writer.index().entries.len(), 2);
+        assert_eq!(writer.index().end(), len);
+        assert_eq!(
+            writer.index().entries[1].message_id.as_deref(),
+            Some(external.message_id.as_str())
+        );
+        writer.append(&message("Next")).unwrap();
+
+        let read = MboxFile::read_from_file(&path).unwrap();
+        let subjects: Vec<&str> = read.messages.iter().map(|m| m.subject.as_str()).collect();
+        assert_eq!(subjects, vec!["Kept", "External", "Next"]);
+
+        // A partial message from an interrupted append is kept too
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"From sender@example.com Mon Jan  1 00:00:00 2024\nSubject: Tor").unwrap();
+        drop(file);
+        let len = std::fs::metadata(&path).unwrap().len();
+
+        let writer = MboxWriter::open(&path).unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), len);
+        assert_eq!(writer.index().entries.len(), 4);
+    }
 }
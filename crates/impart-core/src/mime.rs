@@ -4,7 +4,7 @@
 
 use crate::{ImpartError, Result};
 use crate::types::{Address, Envelope};
-use mailparse::{parse_mail, MailHeaderMap, ParsedMail};
+use mailparse::{parse_mail, DispositionType, MailHeader, MailHeaderMap, ParsedMail};
 
 // MARK: - Parsed Message
 
@@ -49,7 +49,7 @@ pub struct Attachment {
 pub fn parse_message(raw: &[u8]) -> Result<ParsedMessage> {
     let parsed = parse_mail(raw).map_err(|e| ImpartError::Mime(e.to_string()))?;
 
-    let envelope = extract_envelope(&parsed)?;
+    let envelope = extract_envelope(&parsed.headers)?;
     let (text_body, html_body, attachments) = extract_body_parts(&parsed)?;
 
     Ok(ParsedMessage {
@@ -60,10 +60,17 @@ pub fn parse_message(raw: &[u8]) -> Result<ParsedMessage> {
     })
 }
 
-/// Extract envelope from parsed mail.
-fn extract_envelope(mail: &ParsedMail) -> Result<Envelope> {
-    let headers = &mail.headers;
+/// Parse a raw RFC 5322 header block into an envelope.
+///
+/// Used for headers-only fetches, where the message body was never downloaded.
+pub fn parse_headers(raw: &[u8]) -> Result<Envelope> {
+    let (headers, _) =
+        mailparse::parse_headers(raw).map_err(|e| ImpartError::Mime(e.to_string()))?;
+    extract_envelope(&headers)
+}
 
+/// Extract envelope from message headers.
+fn extract_envelope(headers: &[MailHeader]) -> Result<Envelope> {
     let mut envelope = Envelope::new(0);
 
     // Message-ID
@@ -160,34 +167,12 @@ fn extract_parts_recursive(
 
     if mail.subparts.is_empty() {
         // Leaf part
-        if content_type == "text/plain" && text_body.is_none() {
+        if is_attachment(mail) {
+            attachments.push(attachment_from_part(mail));
+        } else if content_type == "text/plain" && text_body.is_none() {
             text_body.replace(mail.get_body().unwrap_or_default());
         } else if content_type == "text/html" && html_body.is_none() {
             html_body.replace(mail.get_body().unwrap_or_default());
-        } else if !content_type.starts_with("text/") {
-            // Attachment
-            let filename = mail
-                .get_content_disposition()
-                .params
-                .get("filename")
-                .cloned()
-                .or_else(|| mail.ctype.params.get("name").cloned())
-                .unwrap_or_else(|| "attachment".to_string());
-
-            let content_id = mail
-                .headers
-                .get_first_value("Content-ID")
-                .map(|s| s.trim_matches(|c| c == '<' || c == '>').to_string());
-
-            let data = mail.get_body_raw().unwrap_or_default();
-
-            attachments.push(Attachment {
-                filename,
-                mime_type: content_type,
-                size: data.len(),
-                content_id,
-                data,
-            });
         }
     } else {
         // Multipart - recurse into subparts
@@ -199,6 +184,223 @@ fn extract_parts_recursive(
     Ok(())
 }
 
+// MARK: - Attachments
+
+/// A parsed MIME message tree, for pulling out individual parts.
+pub struct MimeMessage<'a> {
+    mail: ParsedMail<'a>,
+}
+
+impl<'a> MimeMessage<'a> {
+    /// Parse a raw email message.
+    pub fn parse(raw: &'a [u8]) -> Result<Self> {
+        let mail = parse_mail(raw).map_err(|e| ImpartError::Mime(e.to_string()))?;
+        Ok(Self { mail })
+    }
+
+    /// Attachments in document order, with transfer encoding decoded.
+    ///
+    /// Inline text and HTML body parts are skipped.
+    pub fn attachments(&self) -> Vec<Attachment> {
+        let mut attachments = Vec::new();
+        collect_attachments(&self.mail, &mut attachments);
+        attachments
+    }
+}
+
+fn collect_attachments(mail: &ParsedMail, attachments: &mut Vec<Attachment>) {
+    if mail.subparts.is_empty() {
+        if is_attachment(mail) {
+            attachments.push(attachment_from_part(mail));
+        }
+    } else {
+        for part in &mail.subparts {
+            collect_attachments(part, attachments);
+        }
+    }
+}
+
+/// Whether a leaf part is an attachment rather than part of the body.
+///
+/// Non-text parts always count (inline images keep their Content-ID);
+/// text parts only when marked as attachments or carrying a filename.
+fn is_attachment(part: &ParsedMail) -> bool {
+    if part.get_content_disposition().disposition == DispositionType::Attachment {
+        return true;
+    }
+    let content_type = part.ctype.mimetype.to_lowercase();
+    match content_type.as_str() {
+        "text/plain" | "text/html" => false,
+        t if t.starts_with("text/") => part_filename(part).is_some(),
+        _ => true,
+    }
+}
+
+fn attachment_from_part(part: &ParsedMail) -> Attachment {
+    let content_id = part
+        .headers
+        .get_first_value("Content-ID")
+        .map(|s| s.trim_matches(|c| c == '<' || c == '>').to_string());
+
+    let data = part.get_body_raw().unwrap_or_default();
+
+    Attachment {
+        filename: part_filename(part).unwrap_or_else(|| "attachment".to_string()),
+        mime_type: part.ctype.mimetype.to_lowercase(),
+        size: data.len(),
+        content_id,
+        data,
+    }
+}
+
+/// Filename from `Content-Disposition`, falling back to the `Content-Type` name.
+fn part_filename(part: &ParsedMail) -> Option<String> {
+    part.headers
+        .get_first_value("Content-Disposition")
+        .and_then(|v| header_param(&v, "filename"))
+        .or_else(|| {
+            part.headers
+                .get_first_value("Content-Type")
+                .and_then(|v| header_param(&v, "name"))
+        })
+        .filter(|name| !name.is_empty())
+}
+
+/// Look up a parameter in a structured header value.
+///
+/// Handles RFC 2231 charset-encoded values (`name*=utf-8''%C3%A9`) and
+/// continuations (`name*0=...; name*1*=...`), preferring them over the
+/// plain `name=` form when both are present.
+fn header_param(value: &str, name: &str) -> Option<String> {
+    let name = name.to_ascii_lowercase();
+    let mut plain = None;
+    let mut extended = None;
+    let mut sections: Vec<(u32, bool, String)> = Vec::new();
+
+    for param in split_params(value).into_iter().skip(1) {
+        let Some((key, val)) = param.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let val = unquote(val.trim());
+        let Some(suffix) = key.strip_prefix(name.as_str()) else {
+            continue;
+        };
+
+        match suffix {
+            "" => plain = Some(val),
+            "*" => extended = Some(val),
+            _ => {
+                let Some(section) = suffix.strip_prefix('*') else {
+                    continue;
+                };
+                let (index, encoded) = match section.strip_suffix('*') {
+                    Some(index) => (index, true),
+                    None => (section, false),
+                };
+                if let Ok(index) = index.parse() {
+                    sections.push((index, encoded, val));
+                }
+            }
+        }
+    }
+
+    if let Some(value) = extended {
+        return Some(decode_extended(&value));
+    }
+
+    if !sections.is_empty() {
+        sections.sort_by_key(|(index, _, _)| *index);
+        let mut charset = String::new();
+        let mut bytes = Vec::new();
+        for (index, encoded, value) in &sections {
+            if !*encoded {
+                bytes.extend_from_slice(value.as_bytes());
+            } else if *index == 0 {
+                let (cs, rest) = split_charset(value);
+                charset = cs.to_string();
+                bytes.extend(percent_decode(rest));
+            } else {
+                bytes.extend(percent_decode(value));
+            }
+        }
+        return Some(decode_charset(&charset, &bytes));
+    }
+
+    plain
+}
+
+/// Split a header value on `;`, ignoring separators inside quoted strings.
+fn split_params(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut in_quotes = false;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (i, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => {
+                parts.push(value[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(value[start..].trim());
+    parts
+}
+
+fn unquote(value: &str) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(inner) => inner.replace("\\\"", "\"").replace("\\\\", "\\"),
+        None => value.to_string(),
+    }
+}
+
+/// Decode an RFC 2231 `charset'language'percent-encoded` value.
+fn decode_extended(value: &str) -> String {
+    let (charset, rest) = split_charset(value);
+    decode_charset(charset, &percent_decode(rest))
+}
+
+/// Split `charset'language'rest` into the charset and the encoded text.
+fn split_charset(value: &str) -> (&str, &str) {
+    let mut parts = value.splitn(3, '\'');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(charset), Some(_language), Some(rest)) => (charset, rest),
+        _ => ("", value),
+    }
+}
+
+fn percent_decode(value: &str) -> Vec<u8> {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).filter(|h| h.iter().all(u8::is_ascii_hexdigit));
+        if let (b'%', Some(hex)) = (bytes[i], hex) {
+            // Two ASCII hex digits always parse
+            let hex = std::str::from_utf8(hex).unwrap_or_default();
+            out.push(u8::from_str_radix(hex, 16).unwrap_or_default());
+            i += 3;
+            continue;
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    out
+}
+
+fn decode_charset(charset: &str, bytes: &[u8]) -> String {
+    match charset.to_ascii_lowercase().as_str() {
+        "iso-8859-1" | "latin1" | "latin-1" => bytes.iter().map(|&b| b as char).collect(),
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
 // MARK: - Tests
 
 #[cfg(test)]
@@ -229,4 +431,73 @@ mod tests {
         assert_eq!(addrs[1].name, None);
         assert_eq!(addrs[1].email, "jane@example.com");
     }
+
+    #[test]
+    fn test_pdf_attachment() {
+        let raw = b"From: sender@example.com\r\n\
+            Subject: Paper\r\n\
+            MIME-Version: 1.0\r\n\
+            Content-Type: multipart/mixed; boundary=\"XYZ\"\r\n\
+            \r\n\
+            --XYZ\r\n\
+            Content-Type: text/plain; charset=utf-8\r\n\
+            \r\n\
+            See attached.\r\n\
+            --XYZ\r\n\
+            Content-Type: application/pdf; name=\"draft.pdf\"\r\n\
+            Content-Disposition: attachment; filename=\"paper.pdf\"\r\n\
+            Content-Transfer-Encoding: base64\r\n\
+            \r\n\
+            JVBERi0xLjQK\r\n\
+            --XYZ--\r\n";
+
+        let message = MimeMessage::parse(raw).unwrap();
+        let attachments = message.attachments();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].filename, "paper.pdf");
+        assert_eq!(attachments[0].mime_type, "application/pdf");
+        assert_eq!(attachments[0].data, b"%PDF-1.4\n");
+        assert_eq!(attachments[0].size, 9);
+
+        // The body part is not an attachment
+        let parsed = parse_message(raw).unwrap();
+        assert_eq!(parsed.text_body.as_deref().map(str::trim), Some("See attached."));
+        assert_eq!(parsed.attachments.len(), 1);
+    }
+
+    #[test]
+    fn test_rfc2231_encoded_filename() {
+        let raw = "Subject: Notes\r\n\
+            MIME-Version: 1.0\r\n\
+            Content-Type: multipart/mixed; boundary=\"XYZ\"\r\n\
+            \r\n\
+            --XYZ\r\n\
+            Content-Type: text/plain\r\n\
+            Content-Disposition: attachment;\r\n \
+            filename*=UTF-8''%C3%BCbersicht%20%E2%82%AC.txt\r\n\
+            \r\n\
+            hello\r\n\
+            --XYZ--\r\n";
+
+        let message = MimeMessage::parse(raw.as_bytes()).unwrap();
+        let attachments = message.attachments();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].filename, "übersicht €.txt");
+        assert_eq!(attachments[0].mime_type, "text/plain");
+    }
+
+    #[test]
+    fn test_header_param_continuations() {
+        let value = "attachment; filename*0*=UTF-8''%C3%A9t%C3%A9; filename*1=\"-report.pdf\"";
+        assert_eq!(header_param(value, "filename").as_deref(), Some("été-report.pdf"));
+
+        let value = "attachment; filename*1=\"b.pdf\"; filename*0=\"a\"";
+        assert_eq!(header_param(value, "filename").as_deref(), Some("ab.pdf"));
+
+        let value = "application/pdf; name=\"semi;colon.pdf\"";
+        assert_eq!(header_param(value, "name").as_deref(), Some("semi;colon.pdf"));
+
+        let value = "attachment; filename*=iso-8859-1'en'caf%E9.txt";
+        assert_eq!(header_param(value, "filename").as_deref(), Some("café.txt"));
+    }
 }
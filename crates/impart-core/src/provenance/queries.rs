@@ -13,22 +13,72 @@ use crate::Result;
 ///
 /// Returns events in reverse chronological order (most recent first),
 /// following the causation chain from the given event back to its origins.
+/// Tool invocations are also followed through their `derived_from`
+/// references to the events that produced those inputs, so the lineage of a
+/// tool's result includes the tools and sources it came from.
 pub fn trace_lineage(store: &EventStore, event_id: &ProvenanceEventId) -> Result<Vec<ProvenanceEvent>> {
     let mut lineage = Vec::new();
-    let mut current_id = Some(*event_id);
-
-    while let Some(id) = current_id {
-        if let Some(event) = store.get(&id)? {
-            current_id = event.causation_id;
-            lineage.push(event);
-        } else {
-            break;
+    let mut seen = std::collections::HashSet::new();
+    let mut pending = vec![*event_id];
+    // Loaded on the first tool step, to resolve its references
+    let mut all_events: Option<Vec<ProvenanceEvent>> = None;
+
+    while let Some(id) = pending.pop() {
+        if !seen.insert(id) {
+            continue;
+        }
+        let Some(event) = store.get(&id)? else {
+            continue;
+        };
+
+        if let Some(cause) = event.causation_id {
+            pending.push(cause);
+        }
+        if let ProvenancePayload::ToolInvoked { derived_from, .. } = &event.payload {
+            if all_events.is_none() {
+                all_events = Some(store.all_events()?);
+            }
+            let events = all_events.as_deref().unwrap_or_default();
+            for reference in derived_from {
+                if let Some(source) = producer_of(events, reference, event.sequence) {
+                    pending.push(source.id);
+                }
+            }
         }
+        lineage.push(event);
     }
 
+    lineage.sort_by_key(|e| std::cmp::Reverse(e.sequence));
     Ok(lineage)
 }
 
+/// Trace how a result was produced, starting from its reference.
+///
+/// `reference` is anything an event produces: a tool's `outputs_ref`, an
+/// artifact URI, or a message, insight, or decision ID. Returns the lineage
+/// of the most recent event that produced it (see [`trace_lineage`]), or an
+/// empty list if nothing did.
+pub fn trace_derivation(store: &EventStore, reference: &str) -> Result<Vec<ProvenanceEvent>> {
+    let events = store.all_events()?;
+    match producer_of(&events, reference, u64::MAX) {
+        Some(producer) => trace_lineage(store, &producer.id),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Latest event before `before_sequence` that produced `reference`.
+fn producer_of<'a>(
+    events: &'a [ProvenanceEvent],
+    reference: &str,
+    before_sequence: u64,
+) -> Option<&'a ProvenanceEvent> {
+    events
+        .iter()
+        .filter(|e| e.sequence < before_sequence)
+        .filter(|e| e.payload.output_ref() == Some(reference))
+        .max_by_key(|e| e.sequence)
+}
+
 /// Trace forward from an event to find all events it caused.
 ///
 /// Returns events in chronological order.
@@ -236,6 +286,95 @@ pub fn events_in_time_range(
     Ok(in_range)
 }
 
+// MARK: - Diffing
+
+/// What changed in the provenance store over a time window.
+#[derive(Debug, Clone)]
+pub struct ProvenanceDiff {
+    /// Start of the window (inclusive).
+    pub from: chrono::DateTime<chrono::Utc>,
+    /// End of the window (exclusive).
+    pub to: chrono::DateTime<chrono::Utc>,
+    /// Number of events recorded in the window.
+    pub event_count: usize,
+    /// URIs of artifacts first introduced in the window, in order.
+    pub artifacts_added: Vec<String>,
+    /// Decisions made or revised in the window, in order.
+    pub decisions: Vec<ProvenanceEvent>,
+    /// Actors and invited participants first seen in the window, in order.
+    pub participants_joined: Vec<String>,
+}
+
+impl ProvenanceDiff {
+    /// Whether nothing was recorded in the window.
+    pub fn is_empty(&self) -> bool {
+        self.event_count == 0
+    }
+}
+
+/// Summarize what changed between `from` (inclusive) and `to` (exclusive).
+///
+/// Adjacent windows therefore never double-count an event stamped exactly
+/// on their shared boundary. Artifacts and participants only count as new
+/// if they did not appear before `from`; a participant is new per
+/// conversation.
+pub fn diff_events(
+    store: &EventStore,
+    from: chrono::DateTime<chrono::Utc>,
+    to: chrono::DateTime<chrono::Utc>,
+) -> Result<ProvenanceDiff> {
+    let mut events = store.all_events()?;
+    events.sort_by_key(|e| (e.timestamp, e.sequence));
+
+    let mut known_artifacts = std::collections::HashSet::new();
+    let mut known_participants = std::collections::HashSet::new();
+    let mut diff = ProvenanceDiff {
+        from,
+        to,
+        event_count: 0,
+        artifacts_added: Vec::new(),
+        decisions: Vec::new(),
+        participants_joined: Vec::new(),
+    };
+
+    for event in events.into_iter().take_while(|e| e.timestamp < to) {
+        let in_window = event.timestamp >= from;
+        if in_window {
+            diff.event_count += 1;
+        }
+
+        let mut participants = vec![event.actor_id.clone()];
+        if let ProvenancePayload::ConversationCreated { participants: invited, .. } = &event.payload {
+            participants.extend(invited.iter().cloned());
+        }
+        for participant in participants {
+            let key = (event.conversation_id.clone(), participant.clone());
+            if known_participants.insert(key)
+                && in_window
+                && !diff.participants_joined.contains(&participant)
+            {
+                diff.participants_joined.push(participant);
+            }
+        }
+
+        if let ProvenancePayload::ArtifactIntroduced { artifact_uri, .. } = &event.payload {
+            if known_artifacts.insert(artifact_uri.clone()) && in_window {
+                diff.artifacts_added.push(artifact_uri.clone());
+            }
+        }
+
+        let is_decision = matches!(
+            event.payload,
+            ProvenancePayload::DecisionMade { .. } | ProvenancePayload::DecisionRevised { .. }
+        );
+        if is_decision && in_window {
+            diff.decisions.push(event);
+        }
+    }
+
+    Ok(diff)
+}
+
 // MARK: - Conversation Statistics
 
 /// Statistics about a conversation's provenance.
@@ -368,6 +507,49 @@ mod tests {
         assert_eq!(lineage.len(), 2); // Message + ConversationCreated
     }
 
+    #[test]
+    fn test_trace_through_tool_invocation() {
+        let mut store = create_store_with_events();
+        let paper = "impress://imbib/papers/Fowler2012";
+
+        // A search over the paper, then a code run over the search results
+        let search = store
+            .append(ProvenanceEvent::new(
+                "conv-1".to_string(),
+                ProvenancePayload::ToolInvoked {
+                    tool: "ads_search".to_string(),
+                    inputs: r#"{"query":"citations"}"#.to_string(),
+                    outputs_ref: "search-results-1".to_string(),
+                    derived_from: vec![paper.to_string()],
+                },
+                "counsel@impart.local".to_string(),
+            ))
+            .unwrap();
+        let run = store
+            .append(ProvenanceEvent::new(
+                "conv-1".to_string(),
+                ProvenancePayload::ToolInvoked {
+                    tool: "python".to_string(),
+                    inputs: r#"{"script":"plot_citations.py"}"#.to_string(),
+                    outputs_ref: "impress://implore/figures/citations".to_string(),
+                    derived_from: vec!["search-results-1".to_string(), "msg-1".to_string()],
+                },
+                "counsel@impart.local".to_string(),
+            ))
+            .unwrap();
+
+        let lineage = trace_derivation(&store, "impress://implore/figures/citations").unwrap();
+        let sequences: Vec<u64> = lineage.iter().map(|e| e.sequence).collect();
+        // Code run, search, paper, message, and the message's cause
+        assert_eq!(sequences, vec![run.sequence, search.sequence, 3, 2, 1]);
+        assert!(matches!(
+            &lineage[2].payload,
+            ProvenancePayload::ArtifactIntroduced { artifact_uri, .. } if artifact_uri == paper
+        ));
+
+        assert!(trace_derivation(&store, "unknown-ref").unwrap().is_empty());
+    }
+
     #[test]
     fn test_artifact_history() {
         let store = create_store_with_events();
@@ -404,4 +586,69 @@ mod tests {
         assert_eq!(actors.len(), 1);
         assert_eq!(actors[0], "user@example.com");
     }
+
+    #[test]
+    fn test_diff_events_second_day() {
+        use chrono::{TimeZone, Utc};
+
+        let day1 = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        let day2 = Utc.with_ymd_and_hms(2024, 3, 2, 0, 0, 0).unwrap();
+        let day3 = Utc.with_ymd_and_hms(2024, 3, 3, 0, 0, 0).unwrap();
+
+        let mut store = EventStore::in_memory();
+        let mut append = |timestamp, payload, actor: &str| {
+            let mut event = ProvenanceEvent::new("conv-1".to_string(), payload, actor.to_string());
+            event.timestamp = timestamp;
+            store.append(event).unwrap();
+        };
+
+        let artifact = |uri: &str| ProvenancePayload::ArtifactIntroduced {
+            artifact_uri: uri.to_string(),
+            artifact_type: "paper".to_string(),
+            version: None,
+            display_name: uri.to_string(),
+        };
+        let decision = |id: &str| ProvenancePayload::DecisionMade {
+            decision_id: id.to_string(),
+            description: id.to_string(),
+            rationale: String::new(),
+            alternatives_considered: Vec::new(),
+        };
+
+        // Day one
+        append(
+            day1,
+            ProvenancePayload::ConversationCreated {
+                title: "Surface codes".to_string(),
+                participants: vec!["user@example.com".to_string()],
+            },
+            "user@example.com",
+        );
+        append(day1, artifact("impress://imbib/papers/Fowler2012"), "user@example.com");
+        append(day1, decision("d1"), "user@example.com");
+
+        // Day two, starting exactly on the boundary
+        append(day2, artifact("impress://imbib/papers/Kitaev2003"), "counsel@impart.local");
+        append(day2, artifact("impress://imbib/papers/Fowler2012"), "user@example.com");
+        append(day2 + chrono::Duration::hours(5), decision("d2"), "user@example.com");
+
+        // Day three, exactly on the end boundary
+        append(day3, decision("d3"), "late@example.com");
+
+        let diff = diff_events(&store, day2, day3).unwrap();
+        assert_eq!(diff.event_count, 3);
+        assert_eq!(diff.artifacts_added, vec!["impress://imbib/papers/Kitaev2003"]);
+        assert_eq!(diff.decisions.len(), 1);
+        assert!(matches!(
+            &diff.decisions[0].payload,
+            ProvenancePayload::DecisionMade { decision_id, .. } if decision_id == "d2"
+        ));
+        assert_eq!(diff.participants_joined, vec!["counsel@impart.local"]);
+
+        // The first day's window sees none of the second day's events
+        let diff = diff_events(&store, day1, day2).unwrap();
+        assert_eq!(diff.event_count, 3);
+        assert_eq!(diff.artifacts_added, vec!["impress://imbib/papers/Fowler2012"]);
+        assert_eq!(diff.participants_joined, vec!["user@example.com"]);
+    }
 }
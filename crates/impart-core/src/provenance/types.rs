@@ -129,6 +129,8 @@ pub enum ProvenanceEntityType {
     Insight,
     /// Decision made during research.
     Decision,
+    /// Agent tool invocation.
+    Tool,
     /// System event.
     System,
 }
@@ -141,6 +143,7 @@ impl std::fmt::Display for ProvenanceEntityType {
             ProvenanceEntityType::Artifact => write!(f, "artifact"),
             ProvenanceEntityType::Insight => write!(f, "insight"),
             ProvenanceEntityType::Decision => write!(f, "decision"),
+            ProvenanceEntityType::Tool => write!(f, "tool"),
             ProvenanceEntityType::System => write!(f, "system"),
         }
     }
@@ -267,6 +270,15 @@ pub enum ProvenancePayload {
         revision_reason: String,
     },
 
+    // Tool events
+    /// An agent invoked a tool (search, code run, ...).
+    ToolInvoked {
+        tool: String,
+        inputs: String,              // Tool inputs, serialized as JSON
+        outputs_ref: String,         // Reference to the produced output
+        derived_from: Vec<String>,   // Message IDs, artifact URIs, or earlier outputs_refs
+    },
+
     // System events
     /// System was paused.
     SystemPaused { reason: Option<String> },
@@ -366,6 +378,10 @@ impl ProvenancePayload {
                 format!("Decision revised: {}", new_description)
             }
 
+            ProvenancePayload::ToolInvoked { tool, outputs_ref, .. } => {
+                format!("Tool {} produced {}", tool, outputs_ref)
+            }
+
             ProvenancePayload::SystemPaused { reason } => {
                 if let Some(r) = reason {
                     format!("System paused: {}", r)
@@ -413,11 +429,30 @@ impl ProvenancePayload {
             ProvenancePayload::DecisionMade { .. }
             | ProvenancePayload::DecisionRevised { .. } => ProvenanceEntityType::Decision,
 
+            ProvenancePayload::ToolInvoked { .. } => ProvenanceEntityType::Tool,
+
             ProvenancePayload::SystemPaused { .. }
             | ProvenancePayload::SystemResumed
             | ProvenancePayload::SnapshotCreated { .. } => ProvenanceEntityType::System,
         }
     }
+
+    /// Reference to what this event produced, as other events would list it
+    /// in `derived_from`.
+    pub fn output_ref(&self) -> Option<&str> {
+        match self {
+            ProvenancePayload::MessageSent { message_id, .. } => Some(message_id),
+            ProvenancePayload::SideConversationSynthesized {
+                synthesis_message_id,
+                ..
+            } => Some(synthesis_message_id),
+            ProvenancePayload::ArtifactIntroduced { artifact_uri, .. } => Some(artifact_uri),
+            ProvenancePayload::InsightRecorded { insight_id, .. } => Some(insight_id),
+            ProvenancePayload::DecisionMade { decision_id, .. } => Some(decision_id),
+            ProvenancePayload::ToolInvoked { outputs_ref, .. } => Some(outputs_ref),
+            _ => None,
+        }
+    }
 }
 
 // MARK: - Artifact Type
@@ -536,6 +571,20 @@ mod tests {
         assert_eq!(decision_payload.entity_type(), ProvenanceEntityType::Decision);
     }
 
+    #[test]
+    fn test_tool_invoked_payload() {
+        let payload = ProvenancePayload::ToolInvoked {
+            tool: "ads_search".to_string(),
+            inputs: r#"{"query":"surface code threshold"}"#.to_string(),
+            outputs_ref: "tool-output-1".to_string(),
+            derived_from: vec!["msg-1".to_string()],
+        };
+
+        assert_eq!(payload.entity_type(), ProvenanceEntityType::Tool);
+        assert_eq!(payload.output_ref(), Some("tool-output-1"));
+        assert_eq!(payload.description(), "Tool ads_search produced tool-output-1");
+    }
+
     #[test]
     fn test_artifact_type_parsing() {
         assert_eq!("paper".parse::<ArtifactType>().unwrap(), ArtifactType::Paper);
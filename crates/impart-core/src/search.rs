@@ -0,0 +1,573 @@
+//! Full-text search over messages.
+//!
+//! Ranks matches by a BM25 term score blended with a recency decay, so a
+//! recent message that is nearly as relevant beats an old exact match.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use impart_core::search::{SearchIndex, SearchOptions};
+//!
+//! let mut index = SearchIndex::new();
+//! index.add(SearchDocument::from(&parsed_message));
+//! let results = index.search("surface code threshold", &SearchOptions::default());
+//! ```
+//!
+//! # Persistence
+//!
+//! The index is kept up to date incrementally ([`SearchIndex::add`] and
+//! [`SearchIndex::remove`] by message ID) and saved between runs with
+//! [`SearchIndex::save`]. Saved files include the tokenized term counts, so
+//! [`SearchIndex::load`] doesn't re-tokenize anything. Files written with a
+//! different [`SEARCH_INDEX_SCHEMA_VERSION`] are rejected; rebuild the index
+//! from the messages in that case.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::mime::ParsedMessage;
+use crate::{ImpartError, Result};
+
+/// Version of the saved index format; bump when it changes.
+pub const SEARCH_INDEX_SCHEMA_VERSION: u32 = 1;
+
+/// BM25 term frequency saturation.
+const BM25_K1: f64 = 1.2;
+
+/// BM25 document length normalization.
+const BM25_B: f64 = 0.75;
+
+/// Subject terms count this many times towards a document's term frequencies.
+const SUBJECT_BOOST: usize = 2;
+
+// MARK: - Documents
+
+/// A searchable message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchDocument {
+    /// Message ID (or any caller-chosen key).
+    pub id: String,
+
+    /// Subject line.
+    pub subject: Option<String>,
+
+    /// Plain text body.
+    pub body: String,
+
+    /// Message date; undated messages get no recency credit.
+    pub date: Option<DateTime<Utc>>,
+}
+
+impl From<&ParsedMessage> for SearchDocument {
+    fn from(message: &ParsedMessage) -> Self {
+        Self {
+            id: message.envelope.message_id.clone().unwrap_or_default(),
+            subject: message.envelope.subject.clone(),
+            body: message.text_body.clone().unwrap_or_default(),
+            date: message.envelope.date,
+        }
+    }
+}
+
+// MARK: - Options
+
+/// How results are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RankingMode {
+    /// Weighted blend of relevance and recency.
+    #[default]
+    Combined,
+
+    /// Textual relevance only.
+    Relevance,
+
+    /// Newest matches first.
+    Recency,
+}
+
+/// Search options.
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    /// How results are ordered.
+    pub mode: RankingMode,
+
+    /// Weight of normalized relevance in combined mode.
+    pub relevance_weight: f64,
+
+    /// Weight of recency in combined mode.
+    pub recency_weight: f64,
+
+    /// Age at which a message's recency score halves.
+    pub half_life_days: f64,
+
+    /// Maximum number of results.
+    pub limit: Option<usize>,
+
+    /// Time recency is measured from; defaults to now.
+    pub now: Option<DateTime<Utc>>,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            mode: RankingMode::Combined,
+            relevance_weight: 0.6,
+            recency_weight: 0.4,
+            half_life_days: 30.0,
+            limit: None,
+            now: None,
+        }
+    }
+}
+
+impl SearchOptions {
+    /// Set the ranking mode.
+    pub fn with_mode(mut self, mode: RankingMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the relevance and recency weights for combined mode.
+    pub fn with_weights(mut self, relevance: f64, recency: f64) -> Self {
+        self.relevance_weight = relevance;
+        self.recency_weight = recency;
+        self
+    }
+
+    /// Set the recency half-life in days.
+    pub fn with_half_life_days(mut self, days: f64) -> Self {
+        self.half_life_days = days;
+        self
+    }
+
+    /// Limit the number of results.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Measure recency from a fixed time instead of now.
+    pub fn with_now(mut self, now: DateTime<Utc>) -> Self {
+        self.now = Some(now);
+        self
+    }
+}
+
+// MARK: - Results
+
+/// A ranked search hit.
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    /// ID of the matching document.
+    pub id: String,
+
+    /// Score the results are sorted by.
+    pub score: f64,
+
+    /// Relevance normalized to 0..=1 against the best match.
+    pub relevance: f64,
+
+    /// Recency decay in 0..=1 (1 = now).
+    pub recency: f64,
+}
+
+// MARK: - Index
+
+/// In-memory inverted index over messages, keyed by document ID.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    documents: HashMap<String, IndexedDocument>,
+    /// Number of documents containing each term.
+    document_frequency: HashMap<String, usize>,
+    total_length: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexedDocument {
+    document: SearchDocument,
+    term_frequency: HashMap<String, usize>,
+    length: usize,
+}
+
+impl SearchIndex {
+    /// Create an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of indexed documents.
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Whether the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Whether a document with this ID is indexed.
+    pub fn contains(&self, id: &str) -> bool {
+        self.documents.contains_key(id)
+    }
+
+    /// Add a document to the index, replacing any with the same ID.
+    pub fn add(&mut self, document: SearchDocument) {
+        self.remove(&document.id);
+
+        let mut term_frequency: HashMap<String, usize> = HashMap::new();
+        let subject_terms = document
+            .subject
+            .as_deref()
+            .map(tokenize)
+            .unwrap_or_default();
+        for term in subject_terms {
+            *term_frequency.entry(term).or_default() += SUBJECT_BOOST;
+        }
+        for term in tokenize(&document.body) {
+            *term_frequency.entry(term).or_default() += 1;
+        }
+
+        for term in term_frequency.keys() {
+            *self.document_frequency.entry(term.clone()).or_default() += 1;
+        }
+        let length = term_frequency.values().sum();
+        self.total_length += length;
+        self.documents.insert(
+            document.id.clone(),
+            IndexedDocument {
+                document,
+                term_frequency,
+                length,
+            },
+        );
+    }
+
+    /// Remove a document by ID. Returns whether it was indexed.
+    pub fn remove(&mut self, id: &str) -> bool {
+        let Some(removed) = self.documents.remove(id) else {
+            return false;
+        };
+        for term in removed.term_frequency.keys() {
+            if let Some(count) = self.document_frequency.get_mut(term) {
+                *count -= 1;
+                if *count == 0 {
+                    self.document_frequency.remove(term);
+                }
+            }
+        }
+        self.total_length -= removed.length;
+        true
+    }
+
+    /// Save the index to a file.
+    ///
+    /// Written to a temporary file first and renamed into place, so a crash
+    /// mid-save leaves the previous index intact.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let stored = StoredIndex {
+            schema_version: SEARCH_INDEX_SCHEMA_VERSION,
+            index: self,
+        };
+        let json = serde_json::to_vec(&stored).map_err(|e| ImpartError::Search(e.to_string()))?;
+        let temp = path.with_extension("tmp");
+        std::fs::write(&temp, json)?;
+        std::fs::rename(&temp, path)?;
+        Ok(())
+    }
+
+    /// Load an index saved with [`Self::save`].
+    ///
+    /// Fails with [`ImpartError::Search`] if the file was written with a
+    /// different schema version.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read(path)?;
+
+        #[derive(Deserialize)]
+        struct Version {
+            schema_version: u32,
+        }
+        let version: Version =
+            serde_json::from_slice(&json).map_err(|e| ImpartError::Search(e.to_string()))?;
+        if version.schema_version != SEARCH_INDEX_SCHEMA_VERSION {
+            return Err(ImpartError::Search(format!(
+                "index schema version {} is not supported (expected {}); rebuild the index",
+                version.schema_version, SEARCH_INDEX_SCHEMA_VERSION
+            )));
+        }
+
+        let stored: StoredIndex<SearchIndex> =
+            serde_json::from_slice(&json).map_err(|e| ImpartError::Search(e.to_string()))?;
+        Ok(stored.index)
+    }
+
+    /// Search for documents matching any query term, best first.
+    pub fn search(&self, query: &str, options: &SearchOptions) -> Vec<SearchResult> {
+        let terms: HashSet<String> = tokenize(query).into_iter().collect();
+        if terms.is_empty() || self.documents.is_empty() {
+            return Vec::new();
+        }
+
+        let now = options.now.unwrap_or_else(Utc::now);
+        let scored: Vec<(&IndexedDocument, f64)> = self
+            .documents
+            .values()
+            .map(|doc| (doc, self.bm25(doc, &terms)))
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+        let best = scored.iter().map(|(_, score)| *score).fold(0.0, f64::max);
+
+        let mut results: Vec<SearchResult> = scored
+            .into_iter()
+            .map(|(doc, bm25)| {
+                let relevance = bm25 / best;
+                let recency = doc
+                    .document
+                    .date
+                    .map_or(0.0, |date| recency_score(date, now, options.half_life_days));
+                let score = match options.mode {
+                    RankingMode::Combined => {
+                        options.relevance_weight * relevance + options.recency_weight * recency
+                    }
+                    RankingMode::Relevance => relevance,
+                    RankingMode::Recency => recency,
+                };
+                SearchResult {
+                    id: doc.document.id.clone(),
+                    score,
+                    relevance,
+                    recency,
+                }
+            })
+            .collect();
+
+        results.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then(b.relevance.total_cmp(&a.relevance))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        if let Some(limit) = options.limit {
+            results.truncate(limit);
+        }
+        results
+    }
+
+    /// BM25 score of a document for a set of query terms.
+    fn bm25(&self, doc: &IndexedDocument, terms: &HashSet<String>) -> f64 {
+        let count = self.documents.len() as f64;
+        let average_length = (self.total_length as f64 / count).max(1.0);
+
+        terms
+            .iter()
+            .filter_map(|term| {
+                let tf = *doc.term_frequency.get(term)? as f64;
+                let df = self.document_frequency.get(term).copied().unwrap_or(0) as f64;
+                let idf = (1.0 + (count - df + 0.5) / (df + 0.5)).ln();
+                let norm = BM25_K1 * (1.0 - BM25_B + BM25_B * doc.length as f64 / average_length);
+                Some(idf * tf * (BM25_K1 + 1.0) / (tf + norm))
+            })
+            .sum()
+    }
+}
+
+/// Saved index with its format version.
+#[derive(Serialize, Deserialize)]
+struct StoredIndex<I> {
+    schema_version: u32,
+    index: I,
+}
+
+/// Exponential decay by age: 1.0 now, 0.5 after one half-life.
+///
+/// Future-dated messages count as brand new.
+fn recency_score(date: DateTime<Utc>, now: DateTime<Utc>, half_life_days: f64) -> f64 {
+    if half_life_days <= 0.0 {
+        return 0.0;
+    }
+    let age_days = (now - date).num_seconds().max(0) as f64 / 86_400.0;
+    0.5_f64.powf(age_days / half_life_days)
+}
+
+/// Split text into lowercase alphanumeric terms.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+// MARK: - Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn doc(id: &str, subject: &str, body: &str, date: DateTime<Utc>) -> SearchDocument {
+        SearchDocument {
+            id: id.to_string(),
+            subject: Some(subject.to_string()),
+            body: body.to_string(),
+            date: Some(date),
+        }
+    }
+
+    fn index(now: DateTime<Utc>) -> SearchIndex {
+        let mut index = SearchIndex::new();
+        index.add(doc(
+            "old-exact",
+            "Surface code threshold",
+            "The surface code threshold is near one percent.",
+            now - Duration::days(730),
+        ));
+        index.add(doc(
+            "new-partial",
+            "Threshold estimates",
+            "Revised surface code threshold estimates from the new decoder runs.",
+            now - Duration::days(1),
+        ));
+        index.add(doc("unrelated", "Lunch", "Pizza on Friday?", now));
+        index
+    }
+
+    #[test]
+    fn test_newer_message_outranks_old_exact_match() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let index = index(now);
+        let options = SearchOptions::default().with_now(now);
+
+        let results = index.search("surface code threshold", &options);
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["new-partial", "old-exact"]);
+        assert!(results[0].relevance < results[1].relevance);
+
+        let relevance = index.search(
+            "surface code threshold",
+            &options.clone().with_mode(RankingMode::Relevance),
+        );
+        assert_eq!(relevance[0].id, "old-exact");
+        assert_eq!(relevance[0].relevance, 1.0);
+    }
+
+    #[test]
+    fn test_recency_mode_orders_by_date() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let index = index(now);
+        let options = SearchOptions::default()
+            .with_now(now)
+            .with_mode(RankingMode::Recency);
+
+        let results = index.search("threshold", &options);
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["new-partial", "old-exact"]);
+    }
+
+    #[test]
+    fn test_no_match_and_limit() {
+        let now = Utc::now();
+        let index = index(now);
+        assert!(index.search("qubit", &SearchOptions::default()).is_empty());
+        assert!(index.search("  ", &SearchOptions::default()).is_empty());
+
+        let results = index.search("threshold", &SearchOptions::default().with_limit(1));
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_incremental_add_and_remove() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let mut index = index(now);
+        let options = SearchOptions::default().with_now(now);
+
+        assert!(index.remove("old-exact"));
+        assert!(!index.remove("old-exact"));
+        let ids: Vec<String> = index
+            .search("threshold", &options)
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, vec!["new-partial"]);
+
+        // Re-adding an ID replaces the earlier version
+        index.add(doc("unrelated", "Lunch", "Threshold pizza on Friday?", now));
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.search("pizza", &options).len(), 1);
+        assert_eq!(index.search("threshold", &options).len(), 2);
+
+        // Term statistics match a fresh index of the same documents
+        let mut fresh = SearchIndex::new();
+        fresh.add(doc(
+            "new-partial",
+            "Threshold estimates",
+            "Revised surface code threshold estimates from the new decoder runs.",
+            now - Duration::days(1),
+        ));
+        fresh.add(doc("unrelated", "Lunch", "Threshold pizza on Friday?", now));
+        assert_eq!(index.document_frequency, fresh.document_frequency);
+        assert_eq!(index.total_length, fresh.total_length);
+    }
+
+    #[test]
+    fn test_persist_and_reload() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let options = SearchOptions::default().with_now(now);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("search-index.json");
+
+        let index = index(now);
+        index.save(&path).unwrap();
+
+        let mut loaded = SearchIndex::load(&path).unwrap();
+        assert_eq!(loaded.len(), 3);
+        let search = |index: &SearchIndex, query: &str| -> Vec<String> {
+            index
+                .search(query, &options)
+                .into_iter()
+                .map(|r| r.id)
+                .collect()
+        };
+        assert_eq!(
+            search(&loaded, "surface code threshold"),
+            search(&index, "surface code threshold")
+        );
+        assert_eq!(search(&loaded, "pizza"), vec!["unrelated"]);
+
+        // Messages arriving after the reload are indexed incrementally
+        loaded.add(doc(
+            "newest",
+            "Decoder",
+            "Threshold of the union-find decoder",
+            now,
+        ));
+        loaded.save(&path).unwrap();
+        let reloaded = SearchIndex::load(&path).unwrap();
+        assert!(reloaded.contains("newest"));
+        assert_eq!(search(&reloaded, "decoder"), vec!["newest", "new-partial"]);
+    }
+
+    #[test]
+    fn test_load_rejects_other_schema_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("search-index.json");
+        std::fs::write(&path, r#"{"schema_version":999,"index":{}}"#).unwrap();
+
+        match SearchIndex::load(&path) {
+            Err(ImpartError::Search(message)) => assert!(message.contains("999")),
+            other => panic!(
+                "expected a schema version error, got {:?}",
+                other.map(|i| i.len())
+            ),
+        }
+    }
+
+    #[test]
+    fn test_recency_score() {
+        let now = Utc::now();
+        assert_eq!(recency_score(now, now, 30.0), 1.0);
+        assert!((recency_score(now - Duration::days(30), now, 30.0) - 0.5).abs() < 1e-9);
+        assert_eq!(recency_score(now + Duration::days(3), now, 30.0), 1.0);
+    }
+}
@@ -4,9 +4,18 @@ use crate::{ImpartError, Result};
 use crate::types::AccountConfig;
 use lettre::{
     message::{header::ContentType, Mailbox as LettreMailbox, Message as LettreMessage},
-    transport::smtp::authentication::Credentials,
+    transport::smtp::{
+        authentication::{Credentials, Mechanism},
+        client::{SmtpConnection, TlsParameters},
+        commands::Auth,
+        extension::ClientId,
+    },
     SmtpTransport, Transport,
 };
+use std::time::Duration;
+
+/// Timeout for OAuth2 connections, matching lettre's transport default.
+const OAUTH2_TIMEOUT: Duration = Duration::from_secs(60);
 
 // MARK: - Draft Message
 
@@ -25,11 +34,29 @@ pub struct DraftMessage {
 
 /// SMTP client for sending messages.
 pub struct SmtpClient {
-    transport: SmtpTransport,
+    delivery: Delivery,
     #[allow(dead_code)]
     from_email: String,
 }
 
+/// How the client reaches the server.
+enum Delivery {
+    /// lettre's transport, logging in with a password.
+    Password(SmtpTransport),
+    /// A connection per message, authenticated by [`authenticate_oauth2`].
+    ///
+    /// lettre's transport gives up on the error challenge a server sends for
+    /// a rejected XOAUTH2 token, before the final reply, so the exchange is
+    /// driven here instead.
+    OAuth2 {
+        host: String,
+        port: u16,
+        starttls: bool,
+        email: String,
+        access_token: String,
+    },
+}
+
 impl SmtpClient {
     /// Create a new SMTP client.
     pub fn new(config: &AccountConfig, password: &str) -> Result<Self> {
@@ -37,20 +64,34 @@ impl SmtpClient {
 
         let transport = if config.smtp_starttls {
             SmtpTransport::starttls_relay(&config.smtp_host)
-                .map_err(|e| ImpartError::Smtp(e.to_string()))?
-                .credentials(creds)
-                .port(config.smtp_port)
-                .build()
         } else {
             SmtpTransport::relay(&config.smtp_host)
-                .map_err(|e| ImpartError::Smtp(e.to_string()))?
-                .credentials(creds)
-                .port(config.smtp_port)
-                .build()
-        };
+        }
+        .map_err(|e| ImpartError::Smtp(e.to_string()))?
+        .credentials(creds)
+        .port(config.smtp_port)
+        .build();
 
         Ok(Self {
-            transport,
+            delivery: Delivery::Password(transport),
+            from_email: config.email.clone(),
+        })
+    }
+
+    /// Create a new SMTP client authenticating with an OAuth2 access token.
+    ///
+    /// Uses XOAUTH2 when the server offers it, and LOGIN with the token as
+    /// password otherwise. SMTP connects lazily, so a rejected token surfaces
+    /// from [`Self::send`] as [`ImpartError::TokenExpired`].
+    pub fn new_oauth2(config: &AccountConfig, access_token: &str) -> Result<Self> {
+        Ok(Self {
+            delivery: Delivery::OAuth2 {
+                host: config.smtp_host.clone(),
+                port: config.smtp_port,
+                starttls: config.smtp_starttls,
+                email: config.email.clone(),
+                access_token: access_token.to_string(),
+            },
             from_email: config.email.clone(),
         })
     }
@@ -91,9 +132,24 @@ impl SmtpClient {
                 .map_err(|e| ImpartError::Smtp(e.to_string()))?
         };
 
-        self.transport
-            .send(&message)
-            .map_err(|e| ImpartError::Smtp(e.to_string()))?;
+        match &self.delivery {
+            Delivery::Password(transport) => {
+                transport
+                    .send(&message)
+                    .map_err(|e| ImpartError::Smtp(e.to_string()))?;
+            }
+            Delivery::OAuth2 {
+                host,
+                port,
+                starttls,
+                email,
+                access_token,
+            } => {
+                let mut conn = connect(host, *port, *starttls)?;
+                authenticate_oauth2(&mut conn, email, access_token)?;
+                deliver(&mut conn, &message)?;
+            }
+        }
 
         Ok(())
     }
@@ -104,6 +160,81 @@ impl SmtpClient {
     }
 }
 
+/// Open a connection, with implicit TLS or STARTTLS.
+fn connect(host: &str, port: u16, starttls: bool) -> Result<SmtpConnection> {
+    let hello = ClientId::default();
+    let tls = TlsParameters::new(host.to_string()).map_err(|e| ImpartError::Smtp(e.to_string()))?;
+    let mut conn = SmtpConnection::connect(
+        (host, port),
+        Some(OAUTH2_TIMEOUT),
+        &hello,
+        (!starttls).then_some(&tls),
+        None,
+    )
+    .map_err(|e| ImpartError::Network(e.to_string()))?;
+    if starttls {
+        conn.starttls(&tls, &hello)
+            .map_err(|e| ImpartError::Smtp(e.to_string()))?;
+    }
+    Ok(conn)
+}
+
+/// Authenticate an open connection with an OAuth2 access token.
+///
+/// A rejected XOAUTH2 token is answered with a 334 error challenge; we send
+/// the empty response the protocol requires, and the server's 535 that
+/// follows is reported as [`ImpartError::TokenExpired`]. A 535 to LOGIN
+/// with the token as password is reported the same way.
+fn authenticate_oauth2(conn: &mut SmtpConnection, email: &str, access_token: &str) -> Result<()> {
+    let credentials = Credentials::new(email.to_string(), access_token.to_string());
+    if !conn.server_info().supports_auth_mechanism(Mechanism::Xoauth2) {
+        return conn
+            .auth(&[Mechanism::Login], &credentials)
+            .map(drop)
+            .map_err(auth_error);
+    }
+
+    let auth = Auth::new(Mechanism::Xoauth2, credentials, None)
+        .map_err(|e| ImpartError::Smtp(e.to_string()))?;
+    let response = conn.command(auth).map_err(auth_error)?;
+    if !response.has_code(334) {
+        return Ok(());
+    }
+
+    // The challenge carries the server's JSON error, base64-encoded
+    let challenge = response.first_word().unwrap_or_default().to_string();
+    match conn.command("\r\n") {
+        Err(e) => match auth_error(e) {
+            ImpartError::TokenExpired(message) => Err(ImpartError::TokenExpired(format!(
+                "{} (challenge {})",
+                message, challenge
+            ))),
+            other => Err(other),
+        },
+        Ok(_) => Err(ImpartError::Auth(
+            "Server accepted an XOAUTH2 error challenge".to_string(),
+        )),
+    }
+}
+
+/// Classify a failed AUTH: a 535 reply means the token was refused.
+fn auth_error(error: lettre::transport::smtp::Error) -> ImpartError {
+    if error.status().map(u16::from) == Some(535) {
+        ImpartError::TokenExpired(error.to_string())
+    } else {
+        ImpartError::Auth(error.to_string())
+    }
+}
+
+/// Send a message over an authenticated connection and close it.
+fn deliver(conn: &mut SmtpConnection, message: &LettreMessage) -> Result<()> {
+    conn.send(message.envelope(), &message.formatted())
+        .map_err(|e| ImpartError::Smtp(e.to_string()))?;
+    // The message is accepted; a failed QUIT doesn't change that
+    let _ = conn.quit();
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,4 +251,114 @@ mod tests {
         };
         assert_eq!(draft.to_emails.len(), 1);
     }
+
+    /// Minimal scripted SMTP server advertising `auth`. Returns its address
+    /// and a handle that yields every line it received once the client quits.
+    fn mock_server(
+        auth: &'static str,
+        accept_token: bool,
+    ) -> (std::net::SocketAddr, std::thread::JoinHandle<Vec<String>>) {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut writer = stream.try_clone().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut lines = Vec::new();
+
+            writer.write_all(b"220 mock ESMTP ready\r\n").unwrap();
+
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 {
+                let command = line.trim_end().to_string();
+                line.clear();
+                lines.push(command.clone());
+                let verb = command.split(' ').next().unwrap_or_default().to_ascii_uppercase();
+
+                let response = match verb.as_str() {
+                    "EHLO" => format!("250-mock\r\n250 AUTH {}\r\n", auth),
+                    "AUTH" if accept_token => "235 2.7.0 Accepted\r\n".to_string(),
+                    "AUTH" => {
+                        // Error challenge, answered with an empty line
+                        writer
+                            .write_all(b"334 eyJzdGF0dXMiOiI0MDEiLCJzY2hlbWVzIjoiQmVhcmVyIn0=\r\n")
+                            .unwrap();
+                        reader.read_line(&mut line).unwrap();
+                        lines.push(line.trim_end().to_string());
+                        line.clear();
+                        "535 5.7.8 Username and Password not accepted\r\n".to_string()
+                    }
+                    "MAIL" | "RCPT" => "250 OK\r\n".to_string(),
+                    "DATA" => {
+                        writer.write_all(b"354 Go ahead\r\n").unwrap();
+                        while reader.read_line(&mut line).unwrap() > 0 && line != ".\r\n" {
+                            line.clear();
+                        }
+                        line.clear();
+                        "250 OK queued\r\n".to_string()
+                    }
+                    "QUIT" => {
+                        writer.write_all(b"221 Bye\r\n").unwrap();
+                        break;
+                    }
+                    _ => "502 Command not implemented\r\n".to_string(),
+                };
+                writer.write_all(response.as_bytes()).unwrap();
+            }
+            lines
+        });
+
+        (addr, handle)
+    }
+
+    fn mock_connection(addr: std::net::SocketAddr) -> SmtpConnection {
+        SmtpConnection::connect(addr, Some(Duration::from_secs(5)), &ClientId::default(), None, None)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_rejected_token_is_token_expired() {
+        let (addr, server) = mock_server("XOAUTH2 LOGIN", false);
+
+        let mut conn = mock_connection(addr);
+        match authenticate_oauth2(&mut conn, "alice@example.com", "expired") {
+            Err(ImpartError::TokenExpired(message)) => {
+                assert!(message.contains("535"));
+                assert!(message.contains("eyJzdGF0dXMiOiI0MDEi"));
+            }
+            Err(e) => panic!("expected TokenExpired, got {}", e),
+            Ok(()) => panic!("expected TokenExpired, got a session"),
+        }
+        conn.quit().unwrap();
+
+        let lines = server.join().unwrap();
+        let auth = lines.iter().position(|l| l.starts_with("AUTH XOAUTH2 ")).unwrap();
+        assert_eq!(lines[auth + 1], "");
+    }
+
+    #[test]
+    fn test_xoauth2_send() {
+        let (addr, server) = mock_server("XOAUTH2 LOGIN", true);
+
+        let mut conn = mock_connection(addr);
+        authenticate_oauth2(&mut conn, "alice@example.com", "ya29.a0-token").unwrap();
+        let message = LettreMessage::builder()
+            .from("alice@example.com".parse().unwrap())
+            .to("bob@example.com".parse().unwrap())
+            .subject("Hello")
+            .body("Hi Bob".to_string())
+            .unwrap();
+        deliver(&mut conn, &message).unwrap();
+
+        let lines = server.join().unwrap();
+        assert!(lines.contains(
+            &"AUTH XOAUTH2 dXNlcj1hbGljZUBleGFtcGxlLmNvbQFhdXRoPUJlYXJlciB5YTI5LmEwLXRva2VuAQE="
+                .to_string()
+        ));
+        assert!(lines.contains(&"RCPT TO:<bob@example.com>".to_string()));
+        assert_eq!(lines.last().map(String::as_str), Some("QUIT"));
+    }
 }
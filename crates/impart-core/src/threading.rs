@@ -11,10 +11,33 @@ use std::collections::{HashMap, HashSet};
 
 // MARK: - Threading
 
+/// Options controlling optional steps of the threading algorithm.
+#[derive(Debug, Clone, Default)]
+pub struct ThreadingOptions {
+    /// Merge threads whose root subjects match once `Re:`/`Fwd:` prefixes are
+    /// stripped (JWZ step 5). This rescues replies from clients that drop
+    /// `References`, but can merge unrelated messages sharing a generic
+    /// subject, so it is off by default.
+    pub gather_by_subject: bool,
+}
+
+impl ThreadingOptions {
+    /// Enable or disable subject-based gathering.
+    pub fn with_subject_gathering(mut self, enabled: bool) -> Self {
+        self.gather_by_subject = enabled;
+        self
+    }
+}
+
 /// Thread a list of messages using the JWZ algorithm.
 ///
 /// Returns a list of threads, each containing message IDs in thread order.
 pub fn thread_messages(envelopes: &[Envelope]) -> Vec<Thread> {
+    thread_messages_with_options(envelopes, &ThreadingOptions::default())
+}
+
+/// Thread a list of messages using the JWZ algorithm with the given options.
+pub fn thread_messages_with_options(envelopes: &[Envelope], options: &ThreadingOptions) -> Vec<Thread> {
     if envelopes.is_empty() {
         return Vec::new();
     }
@@ -92,7 +115,14 @@ pub fn thread_messages(envelopes: &[Envelope]) -> Vec<Thread> {
         .cloned()
         .collect();
 
-    // Step 3: Build threads from roots
+    // Step 3: Optionally gather roots left disconnected by subject
+    let roots = if options.gather_by_subject {
+        gather_by_subject(roots, &mut id_table)
+    } else {
+        roots
+    };
+
+    // Step 4: Build threads from roots
     let mut threads: Vec<Thread> = Vec::new();
 
     for root_id in roots {
@@ -153,6 +183,101 @@ impl Container {
     }
 }
 
+/// Group roots sharing a normalized subject under a single root.
+///
+/// The original message (no `Re:`/`Fwd:` prefix) leads its group, falling
+/// back to the earliest message; the other roots become its children.
+fn gather_by_subject(roots: Vec<String>, id_table: &mut HashMap<String, Container>) -> Vec<String> {
+    let mut by_subject: HashMap<String, Vec<String>> = HashMap::new();
+    let mut gathered = Vec::new();
+
+    for root_id in roots {
+        let subject = root_envelope(&root_id, id_table)
+            .and_then(|e| e.subject.as_deref())
+            .map(normalize_subject)
+            .filter(|s| !s.is_empty());
+        match subject {
+            Some(subject) => by_subject.entry(subject).or_default().push(root_id),
+            None => gathered.push(root_id),
+        }
+    }
+
+    for mut group in by_subject.into_values() {
+        group.sort_by_cached_key(|id| {
+            let envelope = root_envelope(id, id_table);
+            let is_reply = envelope
+                .and_then(|e| e.subject.as_deref())
+                .is_some_and(|s| strip_subject_prefixes(s).1);
+            let date = envelope.and_then(|e| e.date);
+            (is_reply, date.is_none(), date, id.clone())
+        });
+
+        let mut group = group.into_iter();
+        let Some(leader) = group.next() else {
+            continue;
+        };
+        let others: Vec<String> = group.collect();
+        if let Some(container) = id_table.get_mut(&leader) {
+            container.children.extend(others);
+        }
+        gathered.push(leader);
+    }
+
+    gathered
+}
+
+/// The envelope representing a root: its own, or its first child's if the
+/// root is a placeholder for a message we don't have.
+fn root_envelope<'a>(root_id: &str, id_table: &'a HashMap<String, Container>) -> Option<&'a Envelope> {
+    let container = id_table.get(root_id)?;
+    container.envelope.as_ref().or_else(|| {
+        container
+            .children
+            .iter()
+            .find_map(|child| id_table.get(child)?.envelope.as_ref())
+    })
+}
+
+/// Normalize a subject for gathering (strip reply/forward prefixes, lowercase).
+fn normalize_subject(subject: &str) -> String {
+    strip_subject_prefixes(subject).0.to_lowercase()
+}
+
+/// Strip any number of `Re:`, `Fwd:`, `Fw:` prefixes (including counted
+/// forms like `Re[2]:`). Returns the remaining subject and whether anything
+/// was stripped.
+fn strip_subject_prefixes(subject: &str) -> (&str, bool) {
+    let mut rest = subject.trim();
+    let mut stripped = false;
+
+    'outer: loop {
+        for prefix in ["re", "fwd", "fw"] {
+            let Some(head) = rest.get(..prefix.len()) else {
+                continue;
+            };
+            if !head.eq_ignore_ascii_case(prefix) {
+                continue;
+            }
+            let mut tail = &rest[prefix.len()..];
+            if let Some(counted) = tail.strip_prefix('[') {
+                if let Some(end) = counted.find(']') {
+                    if counted[..end].chars().all(|c| c.is_ascii_digit()) {
+                        tail = &counted[end + 1..];
+                    }
+                }
+            }
+            if let Some(tail) = tail.trim_start().strip_prefix(':') {
+                rest = tail.trim_start();
+                stripped = true;
+                continue 'outer;
+            }
+        }
+        break;
+    }
+
+    (rest, stripped)
+}
+
 /// Normalize a message ID (remove angle brackets, lowercase).
 fn normalize_message_id(id: &str) -> String {
     id.trim()
@@ -259,4 +384,41 @@ mod tests {
         );
         assert_eq!(normalize_message_id("  <test>  "), "test");
     }
+
+    fn with_subject(mut envelope: Envelope, subject: &str) -> Envelope {
+        envelope.subject = Some(subject.to_string());
+        envelope
+    }
+
+    #[test]
+    fn test_subject_gathering() {
+        let envelopes = vec![
+            with_subject(make_envelope(1, "<orig@example.com>", None, &[]), "Meeting notes"),
+            with_subject(make_envelope(2, "<reply@example.com>", None, &[]), "RE: Re[2]: meeting notes"),
+            with_subject(make_envelope(3, "<other@example.com>", None, &[]), "Budget"),
+        ];
+
+        let threads = thread_messages(&envelopes);
+        assert_eq!(threads.len(), 3);
+
+        let options = ThreadingOptions::default().with_subject_gathering(true);
+        let threads = thread_messages_with_options(&envelopes, &options);
+        assert_eq!(threads.len(), 2);
+
+        let meeting = threads
+            .iter()
+            .find(|t| t.message_ids.len() == 2)
+            .expect("subject-matching messages threaded together");
+        assert_eq!(meeting.root_message_id, "orig@example.com");
+        assert_eq!(meeting.message_ids, vec!["orig@example.com", "reply@example.com"]);
+    }
+
+    #[test]
+    fn test_strip_subject_prefixes() {
+        assert_eq!(strip_subject_prefixes("Re: Fwd: Hello"), ("Hello", true));
+        assert_eq!(strip_subject_prefixes("FW:  Re[3]: Hello"), ("Hello", true));
+        assert_eq!(strip_subject_prefixes("Hello"), ("Hello", false));
+        assert_eq!(strip_subject_prefixes("Regarding: Hello"), ("Regarding: Hello", false));
+        assert_eq!(normalize_subject("Re: HELLO"), "hello");
+    }
 }
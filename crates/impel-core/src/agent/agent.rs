@@ -16,11 +16,22 @@ pub enum AgentStatus {
     Working,
     /// Agent is paused (not accepting work)
     Paused,
+    /// Agent stopped sending heartbeats and is presumed dead
+    Offline,
     /// Agent has been terminated
     Terminated,
 }
 
 impl AgentStatus {
+    /// All statuses, in declaration order
+    pub const ALL: [AgentStatus; 5] = [
+        AgentStatus::Idle,
+        AgentStatus::Working,
+        AgentStatus::Paused,
+        AgentStatus::Offline,
+        AgentStatus::Terminated,
+    ];
+
     /// Check if the agent can accept work
     pub fn can_accept_work(&self) -> bool {
         matches!(self, AgentStatus::Idle)
@@ -38,6 +49,7 @@ impl std::fmt::Display for AgentStatus {
             AgentStatus::Idle => write!(f, "IDLE"),
             AgentStatus::Working => write!(f, "WORKING"),
             AgentStatus::Paused => write!(f, "PAUSED"),
+            AgentStatus::Offline => write!(f, "OFFLINE"),
             AgentStatus::Terminated => write!(f, "TERMINATED"),
         }
     }
@@ -60,6 +72,9 @@ pub struct Agent {
     pub registered_at: DateTime<Utc>,
     /// Last activity timestamp
     pub last_active_at: DateTime<Utc>,
+    /// Last time the agent reported it is alive
+    #[serde(default = "Utc::now")]
+    pub last_heartbeat: DateTime<Utc>,
     /// Number of threads completed
     pub threads_completed: u64,
     /// Custom metadata
@@ -78,6 +93,7 @@ impl Agent {
             auth_token: None,
             registered_at: now,
             last_active_at: now,
+            last_heartbeat: now,
             threads_completed: 0,
             metadata: std::collections::HashMap::new(),
         }
@@ -149,6 +165,30 @@ impl Agent {
         self.last_active_at = Utc::now();
     }
 
+    /// Record a heartbeat, bringing an offline agent back online
+    pub fn heartbeat(&mut self, at: DateTime<Utc>) {
+        self.last_heartbeat = at;
+        if self.status == AgentStatus::Offline {
+            self.status = AgentStatus::Idle;
+        }
+    }
+
+    /// Check if the agent has missed heartbeats for longer than `timeout`
+    ///
+    /// Terminated and already-offline agents are never stale.
+    pub fn is_stale(&self, now: DateTime<Utc>, timeout: chrono::Duration) -> bool {
+        !matches!(self.status, AgentStatus::Offline | AgentStatus::Terminated)
+            && now - self.last_heartbeat >= timeout
+    }
+
+    /// Mark the agent offline, dropping its current thread
+    pub fn go_offline(&mut self) {
+        if self.status != AgentStatus::Terminated {
+            self.status = AgentStatus::Offline;
+            self.current_thread = None;
+        }
+    }
+
     /// Get the time since last activity
     pub fn time_since_activity(&self) -> chrono::Duration {
         Utc::now() - self.last_active_at
@@ -198,6 +238,24 @@ mod tests {
         assert_eq!(agent.threads_completed, 1);
     }
 
+    #[test]
+    fn test_heartbeat_staleness() {
+        let mut agent = Agent::new("test-1".to_string(), AgentType::Research);
+        let timeout = chrono::Duration::seconds(60);
+        let later = agent.last_heartbeat + chrono::Duration::seconds(90);
+        assert!(agent.is_stale(later, timeout));
+
+        agent.assign_thread(ThreadId::new());
+        agent.go_offline();
+        assert_eq!(agent.status, AgentStatus::Offline);
+        assert!(agent.current_thread.is_none());
+        assert!(!agent.is_stale(later, timeout));
+
+        agent.heartbeat(later);
+        assert_eq!(agent.status, AgentStatus::Idle);
+        assert!(!agent.is_stale(later, timeout));
+    }
+
     #[test]
     fn test_pause_resume() {
         let mut agent = Agent::new("test-1".to_string(), AgentType::Research);
@@ -7,7 +7,7 @@ use crate::error::{AgentError, Result};
 use crate::thread::ThreadId;
 
 /// Registry for managing agents
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct AgentRegistry {
     agents: HashMap<String, Agent>,
     type_counters: HashMap<AgentType, u32>,
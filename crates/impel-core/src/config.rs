@@ -3,12 +3,16 @@
 //! Centralized configuration for system parameters including temperature
 //! coefficients, timing, and behavior settings.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::agent::AgentType;
 use crate::thread::TemperatureCoefficients;
 
 /// System-wide configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ImpelConfig {
     /// Temperature calculation parameters
     pub temperature: TemperatureConfig,
@@ -33,9 +37,12 @@ impl Default for ImpelConfig {
 
 /// Temperature calculation configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TemperatureConfig {
     /// Coefficients for the temperature formula
     pub coefficients: TemperatureCoefficients,
+    /// Per-agent-type coefficients used instead of `coefficients`
+    pub agent_overrides: HashMap<AgentType, TemperatureCoefficients>,
     /// Half-life for temperature decay in hours
     pub decay_half_life_hours: u32,
     /// Window for recent activity calculation in hours
@@ -52,6 +59,7 @@ impl Default for TemperatureConfig {
     fn default() -> Self {
         Self {
             coefficients: TemperatureCoefficients::default(),
+            agent_overrides: HashMap::new(),
             decay_half_life_hours: 24,
             activity_window_hours: 24,
             breakthrough_window_days: 7,
@@ -61,8 +69,18 @@ impl Default for TemperatureConfig {
     }
 }
 
+impl TemperatureConfig {
+    /// Coefficients an agent of the given type uses for work selection
+    pub fn coefficients_for(&self, agent_type: AgentType) -> &TemperatureCoefficients {
+        self.agent_overrides
+            .get(&agent_type)
+            .unwrap_or(&self.coefficients)
+    }
+}
+
 /// Agent behavior configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AgentConfig {
     /// Maximum concurrent agents per thread
     pub max_agents_per_thread: u32,
@@ -74,6 +92,8 @@ pub struct AgentConfig {
     pub max_cycle_duration_minutes: u32,
     /// Maximum retries before escalation
     pub max_retries: u32,
+    /// Seconds without a heartbeat before an agent is marked offline
+    pub heartbeat_timeout_seconds: u32,
 }
 
 impl Default for AgentConfig {
@@ -84,12 +104,14 @@ impl Default for AgentConfig {
             cooldown_seconds: 60,
             max_cycle_duration_minutes: 120,
             max_retries: 3,
+            heartbeat_timeout_seconds: 300,
         }
     }
 }
 
 /// Escalation behavior configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct EscalationConfig {
     /// Auto-escalate after this many blocked cycles
     pub auto_escalate_after_cycles: u32,
@@ -100,6 +122,8 @@ pub struct EscalationConfig {
     pub response_time_critical_hours: u32,
     /// Maximum open escalations before warning
     pub max_open_escalations_warning: u32,
+    /// Escalate threads that stay blocked this long
+    pub stuck_threshold_hours: u32,
 }
 
 impl Default for EscalationConfig {
@@ -111,12 +135,14 @@ impl Default for EscalationConfig {
             response_time_high_hours: 4,
             response_time_critical_hours: 1,
             max_open_escalations_warning: 10,
+            stuck_threshold_hours: 48,
         }
     }
 }
 
 /// System timing configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TimingConfig {
     /// Temperature recalculation interval in minutes
     pub temperature_recalc_minutes: u32,
@@ -164,41 +190,122 @@ impl ImpelConfig {
         serde_json::to_string_pretty(self)
     }
 
+    /// Load and validate configuration from a TOML string
+    ///
+    /// Missing sections and fields take their defaults.
+    pub fn from_toml_str(toml_str: &str) -> Result<Self, Vec<ConfigError>> {
+        let config: Self =
+            toml::from_str(toml_str).map_err(|e| vec![ConfigError::Parse(e.to_string())])?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Replace this configuration with one loaded from a TOML string
+    ///
+    /// The current configuration is kept if the new one fails to parse or
+    /// validate, so a bad edit never takes effect.
+    pub fn reload_from_toml_str(&mut self, toml_str: &str) -> Result<(), Vec<ConfigError>> {
+        *self = Self::from_toml_str(toml_str)?;
+        Ok(())
+    }
+
     /// Validate configuration values
-    pub fn validate(&self) -> Result<(), ConfigError> {
+    ///
+    /// Reports every problem found rather than stopping at the first.
+    pub fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+        let temperature = &self.temperature;
+
         // Temperature thresholds must be in valid range
-        if self.temperature.hot_threshold <= self.temperature.warm_threshold {
-            return Err(ConfigError::InvalidThresholds(
+        if temperature.hot_threshold <= temperature.warm_threshold {
+            errors.push(ConfigError::InvalidThresholds(
                 "hot_threshold must be greater than warm_threshold".to_string(),
             ));
         }
-
-        if self.temperature.hot_threshold > 1.0 || self.temperature.hot_threshold < 0.0 {
-            return Err(ConfigError::OutOfRange(
+        if !(0.0..=1.0).contains(&temperature.hot_threshold) {
+            errors.push(ConfigError::OutOfRange(
                 "hot_threshold must be between 0.0 and 1.0".to_string(),
             ));
         }
-
-        if self.temperature.warm_threshold > 1.0 || self.temperature.warm_threshold < 0.0 {
-            return Err(ConfigError::OutOfRange(
+        if !(0.0..=1.0).contains(&temperature.warm_threshold) {
+            errors.push(ConfigError::OutOfRange(
                 "warm_threshold must be between 0.0 and 1.0".to_string(),
             ));
         }
 
-        // Decay half-life must be positive
-        if self.temperature.decay_half_life_hours == 0 {
-            return Err(ConfigError::OutOfRange(
-                "decay_half_life_hours must be positive".to_string(),
-            ));
+        let coefficients = std::iter::once(("coefficients".to_string(), &temperature.coefficients))
+            .chain(
+                temperature
+                    .agent_overrides
+                    .iter()
+                    .map(|(agent_type, c)| (format!("agent_overrides.{}", agent_type.name()), c)),
+            );
+        for (name, c) in coefficients {
+            if [c.alpha, c.beta, c.gamma, c.delta]
+                .iter()
+                .any(|v| !v.is_finite() || *v < 0.0)
+            {
+                errors.push(ConfigError::OutOfRange(format!(
+                    "{} must be finite and non-negative",
+                    name
+                )));
+            }
         }
 
-        Ok(())
+        // Windows, intervals, and limits must be positive
+        let positive = [
+            ("decay_half_life_hours", temperature.decay_half_life_hours),
+            ("activity_window_hours", temperature.activity_window_hours),
+            (
+                "breakthrough_window_days",
+                temperature.breakthrough_window_days,
+            ),
+            ("max_agents_per_thread", self.agent.max_agents_per_thread),
+            ("claim_expiry_minutes", self.agent.claim_expiry_minutes),
+            (
+                "heartbeat_timeout_seconds",
+                self.agent.heartbeat_timeout_seconds,
+            ),
+            (
+                "max_cycle_duration_minutes",
+                self.agent.max_cycle_duration_minutes,
+            ),
+            (
+                "temperature_recalc_minutes",
+                self.timing.temperature_recalc_minutes,
+            ),
+            (
+                "snapshot_interval_minutes",
+                self.timing.snapshot_interval_minutes,
+            ),
+            ("event_retention_days", self.timing.event_retention_days),
+            (
+                "stuck_threshold_hours",
+                self.escalation.stuck_threshold_hours,
+            ),
+        ];
+        for (name, value) in positive {
+            if value == 0 {
+                errors.push(ConfigError::OutOfRange(format!(
+                    "{} must be positive",
+                    name
+                )));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
     }
 }
 
 /// Configuration validation error
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfigError {
+    /// Configuration text could not be parsed
+    Parse(String),
     /// Threshold values are invalid relative to each other
     InvalidThresholds(String),
     /// Value is out of valid range
@@ -210,6 +317,7 @@ pub enum ConfigError {
 impl std::fmt::Display for ConfigError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            ConfigError::Parse(msg) => write!(f, "Parse error: {}", msg),
             ConfigError::InvalidThresholds(msg) => write!(f, "Invalid thresholds: {}", msg),
             ConfigError::OutOfRange(msg) => write!(f, "Value out of range: {}", msg),
             ConfigError::MissingField(msg) => write!(f, "Missing field: {}", msg),
@@ -240,6 +348,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_agent_override_serialization() {
+        let mut config = ImpelConfig::default();
+        config.temperature.agent_overrides.insert(
+            AgentType::Adversarial,
+            TemperatureCoefficients {
+                beta: 0.6,
+                ..Default::default()
+            },
+        );
+
+        let parsed = ImpelConfig::from_json(&config.to_json().unwrap()).unwrap();
+        assert_eq!(
+            parsed
+                .temperature
+                .coefficients_for(AgentType::Adversarial)
+                .beta,
+            0.6
+        );
+        assert_eq!(
+            parsed
+                .temperature
+                .coefficients_for(AgentType::Research)
+                .beta,
+            0.3
+        );
+    }
+
     #[test]
     fn test_invalid_thresholds() {
         let mut config = ImpelConfig::default();
@@ -254,4 +390,71 @@ mod tests {
         config.temperature.hot_threshold = 1.5;
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_reports_all_errors() {
+        let mut config = ImpelConfig::default();
+        config.temperature.decay_half_life_hours = 0;
+        config.agent.max_agents_per_thread = 0;
+
+        let errors = config.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ConfigError::OutOfRange("decay_half_life_hours must be positive".to_string()),
+                ConfigError::OutOfRange("max_agents_per_thread must be positive".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_toml_str() {
+        let config = ImpelConfig::from_toml_str(
+            r#"
+            [temperature]
+            hot_threshold = 0.8
+
+            [agent]
+            max_retries = 5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.temperature.hot_threshold, 0.8);
+        assert_eq!(config.agent.max_retries, 5);
+        assert_eq!(config.agent.max_agents_per_thread, 3);
+
+        let errors = ImpelConfig::from_toml_str(
+            r#"
+            [timing]
+            snapshot_interval_minutes = 0
+
+            [temperature.coefficients]
+            alpha = -0.2
+            beta = 0.3
+            gamma = 0.1
+            delta = 0.5
+            "#,
+        )
+        .unwrap_err();
+        assert_eq!(errors.len(), 2);
+
+        assert!(matches!(
+            ImpelConfig::from_toml_str("[agent]\nmax_retries = \"many\"").unwrap_err()[..],
+            [ConfigError::Parse(_)]
+        ));
+    }
+
+    #[test]
+    fn test_reload_keeps_config_on_error() {
+        let mut config = ImpelConfig::default();
+        config
+            .reload_from_toml_str("[agent]\nmax_retries = 7")
+            .unwrap();
+        assert_eq!(config.agent.max_retries, 7);
+
+        assert!(config
+            .reload_from_toml_str("[agent]\nmax_retries = 9\nclaim_expiry_minutes = 0")
+            .is_err());
+        assert_eq!(config.agent.max_retries, 7);
+    }
 }
@@ -447,26 +447,13 @@ impl Command {
                 created_by,
                 thread_id,
             } => {
-                let mut escalation =
-                    Escalation::new(category, title.clone(), description, created_by.clone());
+                let mut escalation = Escalation::new(category, title, description, created_by);
                 if let Some(tid) = thread_id {
                     escalation = escalation.with_thread(tid);
                 }
 
-                let event = Event::new(
-                    escalation.id.clone(),
-                    EntityType::Escalation,
-                    EventPayload::EscalationCreated {
-                        category,
-                        title,
-                        thread_id: escalation.thread_id.map(|t| t.to_string()),
-                    },
-                )
-                .with_actor(created_by);
-
-                state.add_escalation(escalation);
-
-                Ok(vec![state.apply_event(event)?])
+                // Duplicates of an open escalation are merged into it
+                Ok(vec![state.create_escalation(escalation)?])
             }
 
             Command::AcknowledgeEscalation { escalation_id, by } => {
@@ -1,12 +1,18 @@
 //! Coordination state aggregate root
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::agent::AgentRegistry;
-use crate::error::Result;
-use crate::escalation::Escalation;
-use crate::event::{Event, EventStore, InMemoryEventStore, Projection, SystemProjection};
-use crate::thread::{Thread, ThreadId};
+use chrono::{DateTime, Duration, Utc};
+
+use crate::agent::{AgentRegistry, AgentStatus, AgentType};
+use crate::config::{AgentConfig, EscalationConfig, TemperatureConfig};
+use crate::error::{AgentError, BatchRejected, ImpelError, Result, ThreadError};
+use crate::escalation::{Escalation, EscalationCategory};
+use crate::event::{
+    EntityType, Event, EventId, EventPayload, EventStore, InMemoryEventStore, Projection,
+    SystemProjection,
+};
+use crate::thread::{Thread, ThreadId, ThreadState};
 
 /// The aggregate root for the impel coordination state
 pub struct CoordinationState {
@@ -18,6 +24,12 @@ pub struct CoordinationState {
     escalations: HashMap<String, Escalation>,
     /// Whether the system is paused
     paused: bool,
+    /// Temperature coefficients used for work selection
+    temperature_config: TemperatureConfig,
+    /// Escalation settings, including the stuck-thread threshold
+    escalation_config: EscalationConfig,
+    /// Agent settings, including the heartbeat timeout
+    agent_config: AgentConfig,
 }
 
 impl CoordinationState {
@@ -28,9 +40,35 @@ impl CoordinationState {
             projection: SystemProjection::new(),
             escalations: HashMap::new(),
             paused: false,
+            temperature_config: TemperatureConfig::default(),
+            escalation_config: EscalationConfig::default(),
+            agent_config: AgentConfig::default(),
         }
     }
 
+    /// Use the given temperature configuration for work selection
+    pub fn with_temperature_config(mut self, config: TemperatureConfig) -> Self {
+        self.temperature_config = config;
+        self
+    }
+
+    /// Get the temperature configuration
+    pub fn temperature_config(&self) -> &TemperatureConfig {
+        &self.temperature_config
+    }
+
+    /// Use the given escalation configuration
+    pub fn with_escalation_config(mut self, config: EscalationConfig) -> Self {
+        self.escalation_config = config;
+        self
+    }
+
+    /// Use the given agent configuration
+    pub fn with_agent_config(mut self, config: AgentConfig) -> Self {
+        self.agent_config = config;
+        self
+    }
+
     /// Record an event and update projections
     pub fn apply_event(&mut self, event: Event) -> Result<Event> {
         let event = self.event_store.append(event)?;
@@ -46,6 +84,33 @@ impl CoordinationState {
         Ok(event)
     }
 
+    /// Record a batch of events atomically
+    ///
+    /// Events are validated in order, each against the state left by the
+    /// events before it, so a batch may create a thread and then move it
+    /// through several transitions. If any event is invalid nothing is
+    /// recorded and the offending event's index is returned.
+    pub fn apply_batch(
+        &mut self,
+        events: Vec<Event>,
+    ) -> std::result::Result<Vec<Event>, BatchRejected> {
+        let mut staged = self.projection.clone();
+        for (index, event) in events.iter().enumerate() {
+            validate_event(&staged, event)
+                .and_then(|()| staged.apply(event))
+                .map_err(|error| BatchRejected { index, error })?;
+        }
+
+        events
+            .into_iter()
+            .enumerate()
+            .map(|(index, event)| {
+                self.apply_event(event)
+                    .map_err(|error| BatchRejected { index, error })
+            })
+            .collect()
+    }
+
     /// Get the current sequence number
     pub fn current_sequence(&self) -> u64 {
         self.event_store.current_sequence()
@@ -99,6 +164,69 @@ impl CoordinationState {
         self.projection.threads.by_temperature()
     }
 
+    /// Temperature of a thread as seen by an agent of the given type
+    ///
+    /// Uses the agent type's coefficient override if one is configured,
+    /// otherwise the global coefficients.
+    pub fn effective_temperature(&self, thread: &Thread, agent_type: AgentType) -> f64 {
+        thread
+            .temperature
+            .effective_value(self.temperature_config.coefficients_for(agent_type))
+    }
+
+    /// Get available threads sorted by effective temperature for an agent type
+    /// (hottest first)
+    pub fn available_threads_for(&self, agent_type: AgentType) -> Vec<&Thread> {
+        let mut threads: Vec<_> = self
+            .available_threads()
+            .map(|t| (self.effective_temperature(t, agent_type), t))
+            .collect();
+        threads.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        threads.into_iter().map(|(_, t)| t).collect()
+    }
+
+    /// Plan one round of work assignment for several agents at once
+    ///
+    /// Each agent gets at most one available thread and no thread goes to
+    /// two agents. Agents holding the fewest claimed threads choose first
+    /// (ties by ID), each taking its hottest remaining thread by effective
+    /// temperature (ties by thread ID), so equal-temperature work is spread
+    /// across agents instead of everyone reaching for the same thread.
+    /// Unknown agents and agents that are paused, offline, or terminated
+    /// are skipped. Nothing is claimed; callers issue the claims.
+    pub fn assign_round(&self, agent_ids: &[&str]) -> Vec<(String, ThreadId)> {
+        let mut agents: Vec<_> = agent_ids
+            .iter()
+            .filter_map(|id| self.agents().get(id))
+            .filter(|a| matches!(a.status, AgentStatus::Idle | AgentStatus::Working))
+            .map(|a| {
+                let load = self.threads().filter(|t| t.is_claimed_by(&a.id)).count();
+                (load, a.id.clone(), a.agent_type)
+            })
+            .collect();
+        agents.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+        agents.dedup_by(|a, b| a.1 == b.1);
+
+        let mut taken = HashSet::new();
+        let mut assignments = Vec::new();
+        for (_, agent_id, agent_type) in agents {
+            let pick = self
+                .available_threads()
+                .filter(|t| !taken.contains(&t.id))
+                .map(|t| (self.effective_temperature(t, agent_type), t))
+                .max_by(|a, b| {
+                    a.0.partial_cmp(&b.0)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                        .then_with(|| b.1.id.to_string().cmp(&a.1.id.to_string()))
+                });
+            if let Some((_, thread)) = pick {
+                taken.insert(thread.id.clone());
+                assignments.push((agent_id, thread.id.clone()));
+            }
+        }
+        assignments
+    }
+
     // ==================== Agent Operations ====================
 
     /// Get the agent registry
@@ -111,6 +239,94 @@ impl CoordinationState {
         self.projection.agents.registry_mut()
     }
 
+    /// Record a heartbeat from an agent
+    ///
+    /// An agent previously marked offline by the reaper comes back as idle.
+    pub fn record_heartbeat(&mut self, agent_id: &str, at: DateTime<Utc>) -> Result<AgentStatus> {
+        let agent = self
+            .agents_mut()
+            .get_mut(agent_id)
+            .ok_or_else(|| AgentError::NotFound(agent_id.to_string()))?;
+        if agent.status == AgentStatus::Terminated {
+            return Err(ImpelError::InvalidOperation(format!(
+                "Agent {} has been terminated",
+                agent_id
+            )));
+        }
+
+        let was_offline = agent.status == AgentStatus::Offline;
+        agent.heartbeat(at);
+        let status = agent.status;
+        if was_offline {
+            self.apply_event(
+                Event::new(
+                    agent_id.to_string(),
+                    EntityType::Agent,
+                    EventPayload::AgentStatusChanged {
+                        from: AgentStatus::Offline.to_string(),
+                        to: AgentStatus::Idle.to_string(),
+                    },
+                )
+                .with_actor(agent_id.to_string()),
+            )?;
+        }
+        Ok(status)
+    }
+
+    /// Mark agents silent past the heartbeat timeout offline and release
+    /// their claimed threads so other agents can pick them up
+    ///
+    /// Returns the IDs of agents reaped by this call.
+    pub fn reap_stale_agents(&mut self, now: DateTime<Utc>) -> Result<Vec<String>> {
+        let timeout = Duration::seconds(self.agent_config.heartbeat_timeout_seconds as i64);
+
+        let stale: Vec<(String, AgentStatus)> = self
+            .agents()
+            .all()
+            .filter(|a| a.is_stale(now, timeout))
+            .map(|a| (a.id.clone(), a.status))
+            .collect();
+
+        for (agent_id, status) in &stale {
+            let claimed: Vec<String> = self
+                .projection
+                .threads
+                .all()
+                .filter(|t| t.is_claimed_by(agent_id))
+                .map(|t| t.id.to_string())
+                .collect();
+            for thread_id in claimed {
+                self.apply_event(
+                    Event::new(
+                        thread_id,
+                        EntityType::Thread,
+                        EventPayload::ThreadReleased {
+                            agent_id: agent_id.clone(),
+                        },
+                    )
+                    .with_actor("reaper".to_string()),
+                )?;
+            }
+
+            if let Some(agent) = self.agents_mut().get_mut(agent_id) {
+                agent.go_offline();
+            }
+            self.apply_event(
+                Event::new(
+                    agent_id.clone(),
+                    EntityType::Agent,
+                    EventPayload::AgentStatusChanged {
+                        from: status.to_string(),
+                        to: AgentStatus::Offline.to_string(),
+                    },
+                )
+                .with_actor("reaper".to_string()),
+            )?;
+        }
+
+        Ok(stale.into_iter().map(|(id, _)| id).collect())
+    }
+
     // ==================== Escalation Operations ====================
 
     /// Add an escalation
@@ -118,6 +334,52 @@ impl CoordinationState {
         self.escalations.insert(escalation.id.clone(), escalation);
     }
 
+    /// Create an escalation, merging it into an open duplicate if one exists
+    ///
+    /// Escalations with the same [`dedup_key`](Escalation::dedup_key) as an
+    /// open escalation bump its occurrence count and add their context to it
+    /// instead of being stored separately, and are recorded as
+    /// `EscalationRecurred` rather than `EscalationCreated`. Returns the
+    /// recorded event, whose entity ID is the escalation that now holds the
+    /// report.
+    pub fn create_escalation(&mut self, escalation: Escalation) -> Result<Event> {
+        let key = escalation.dedup_key();
+        let reported_by = escalation.created_by.clone();
+        let existing = self
+            .escalations
+            .values_mut()
+            .find(|e| e.status.is_open() && e.dedup_key() == key);
+
+        let event = match existing {
+            Some(existing) => {
+                existing.record_occurrence(escalation);
+                Event::new(
+                    existing.id.clone(),
+                    EntityType::Escalation,
+                    EventPayload::EscalationRecurred {
+                        reported_by: reported_by.clone(),
+                        occurrence_count: existing.occurrence_count,
+                    },
+                )
+            }
+            None => {
+                let event = Event::new(
+                    escalation.id.clone(),
+                    EntityType::Escalation,
+                    EventPayload::EscalationCreated {
+                        category: escalation.category,
+                        title: escalation.title.clone(),
+                        thread_id: escalation.thread_id.map(|t| t.to_string()),
+                    },
+                );
+                self.add_escalation(escalation);
+                event
+            }
+        };
+
+        self.apply_event(event.with_actor(reported_by))
+    }
+
     /// Get an escalation by ID
     pub fn get_escalation(&self, id: &str) -> Option<&Escalation> {
         self.escalations.get(id)
@@ -148,6 +410,47 @@ impl CoordinationState {
         self.escalations.values()
     }
 
+    /// Escalate threads that have been blocked longer than the stuck threshold
+    ///
+    /// Each blocked period escalates at most once: the thread projection
+    /// records the escalation on the thread from its `EscalationCreated`
+    /// event, so this survives a rebuild, and clears it when the thread is
+    /// unblocked or the escalation resolved. Returns the IDs of escalations
+    /// raised by this call.
+    pub fn check_stuck_threads(&mut self, now: DateTime<Utc>) -> Result<Vec<String>> {
+        let threshold = Duration::hours(self.escalation_config.stuck_threshold_hours as i64);
+
+        let stuck: Vec<(String, String, DateTime<Utc>)> = self
+            .projection
+            .threads
+            .by_state(ThreadState::Blocked)
+            .filter(|t| t.stuck_escalation_id.is_none())
+            .filter_map(|t| {
+                let since = t.blocked_since?;
+                (now - since >= threshold)
+                    .then(|| (t.id.to_string(), t.metadata.title.clone(), since))
+            })
+            .collect();
+
+        let mut raised = Vec::new();
+        for (thread_id, title, since) in stuck {
+            let escalation = Escalation::new(
+                EscalationCategory::Stuck,
+                format!("Thread blocked: {}", title),
+                format!(
+                    "Blocked since {} with no progress for {} hours",
+                    since.to_rfc3339(),
+                    (now - since).num_hours()
+                ),
+                "watchdog".to_string(),
+            )
+            .with_thread(ThreadId::parse(&thread_id)?);
+            raised.push(self.create_escalation(escalation)?.entity_id);
+        }
+
+        Ok(raised)
+    }
+
     // ==================== Event Operations ====================
 
     /// Get events since a sequence number
@@ -155,6 +458,14 @@ impl CoordinationState {
         self.event_store.events_after(sequence)
     }
 
+    /// Get events recorded after the event with the given ID
+    ///
+    /// Returns `None` if no such event is in the store.
+    pub fn events_after_id(&self, id: &EventId) -> Option<Vec<&Event>> {
+        let sequence = self.event_store.get(id)?.sequence;
+        Some(self.event_store.events_after(sequence))
+    }
+
     /// Get all events
     pub fn all_events(&self) -> Vec<&Event> {
         self.event_store.all_events()
@@ -231,6 +542,62 @@ impl CoordinationState {
     }
 }
 
+/// Check that a thread event is consistent with the projected state
+///
+/// Mirrors the checks the thread commands make: transitions must start from
+/// the thread's current state and be allowed by the state machine, and
+/// claims and releases must respect current ownership.
+fn validate_event(projection: &SystemProjection, event: &Event) -> Result<()> {
+    if event.entity_type != EntityType::Thread {
+        return Ok(());
+    }
+
+    if let EventPayload::ThreadCreated { .. } = event.payload {
+        ThreadId::parse(&event.entity_id)?;
+        if projection.threads.get(&event.entity_id).is_some() {
+            return Err(ImpelError::InvalidOperation(format!(
+                "Thread {} already exists",
+                event.entity_id
+            )));
+        }
+        return Ok(());
+    }
+
+    let thread = projection
+        .threads
+        .get(&event.entity_id)
+        .ok_or_else(|| ThreadError::NotFound(event.entity_id.clone()))?;
+
+    match &event.payload {
+        EventPayload::ThreadStateChanged { from, to, .. }
+            if thread.state != *from || !from.can_transition_to(to) =>
+        {
+            Err(ThreadError::InvalidStateTransition {
+                from: thread.state.to_string(),
+                to: to.to_string(),
+            }
+            .into())
+        }
+        EventPayload::ThreadClaimed { .. } if !thread.state.is_claimable() => {
+            Err(ThreadError::NotClaimable(event.entity_id.clone(), thread.state.to_string()).into())
+        }
+        EventPayload::ThreadClaimed { .. } if thread.is_claimed() => {
+            Err(ThreadError::AlreadyClaimed(
+                event.entity_id.clone(),
+                thread.claimed_by.clone().unwrap_or_default(),
+            )
+            .into())
+        }
+        EventPayload::ThreadReleased { agent_id } if !thread.is_claimed_by(agent_id) => {
+            Err(ImpelError::InvalidOperation(format!(
+                "Agent {} does not own thread {}",
+                agent_id, event.entity_id
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
 impl Default for CoordinationState {
     fn default() -> Self {
         Self::new()
@@ -240,9 +607,7 @@ impl Default for CoordinationState {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::agent::AgentType;
-    use crate::event::{EntityType, EventPayload};
-    use crate::thread::ThreadState;
+    use crate::thread::Temperature;
 
     #[test]
     fn test_coordination_state_creation() {
@@ -288,6 +653,56 @@ mod tests {
         assert_eq!(thread.state, ThreadState::Active);
     }
 
+    #[test]
+    fn test_apply_batch_is_atomic() {
+        let mut state = CoordinationState::new();
+        let thread_id = ThreadId::new().to_string();
+        let created = Event::new(
+            thread_id.clone(),
+            EntityType::Thread,
+            EventPayload::ThreadCreated {
+                title: "Batch".to_string(),
+                description: String::new(),
+                parent_id: None,
+            },
+        );
+        let transition = |from, to| {
+            Event::new(
+                thread_id.clone(),
+                EntityType::Thread,
+                EventPayload::ThreadStateChanged {
+                    from,
+                    to,
+                    reason: None,
+                },
+            )
+        };
+
+        // Review is only reachable from Active
+        let rejected = state
+            .apply_batch(vec![
+                created.clone(),
+                transition(ThreadState::Embryo, ThreadState::Review),
+            ])
+            .unwrap_err();
+        assert_eq!(rejected.index, 1);
+        assert!(state.get_thread(&thread_id).is_none());
+        assert_eq!(state.current_sequence(), 0);
+
+        let applied = state
+            .apply_batch(vec![
+                created,
+                transition(ThreadState::Embryo, ThreadState::Active),
+                transition(ThreadState::Active, ThreadState::Review),
+            ])
+            .unwrap();
+        assert_eq!(applied.len(), 3);
+        assert_eq!(
+            state.get_thread(&thread_id).unwrap().state,
+            ThreadState::Review
+        );
+    }
+
     #[test]
     fn test_pause_resume() {
         let mut state = CoordinationState::new();
@@ -313,9 +728,327 @@ mod tests {
         assert!(!state.is_paused());
     }
 
+    #[test]
+    fn test_effective_temperature_per_agent_type() {
+        use crate::thread::{TemperatureCoefficients, ThreadMetadata};
+
+        let mut config = TemperatureConfig::default();
+        config.agent_overrides.insert(
+            AgentType::Adversarial,
+            TemperatureCoefficients {
+                alpha: 0.0,
+                beta: 0.6,
+                ..Default::default()
+            },
+        );
+        let mut state = CoordinationState::new().with_temperature_config(config);
+
+        let coefficients = TemperatureCoefficients::default();
+        let mut busy = Thread::new(ThreadMetadata {
+            title: "Busy".to_string(),
+            ..Default::default()
+        });
+        busy.temperature = Temperature::with_priority(0.3);
+        busy.temperature.record_activity(1.0, &coefficients);
+
+        let mut breakthrough = Thread::new(ThreadMetadata {
+            title: "Breakthrough".to_string(),
+            ..Default::default()
+        });
+        breakthrough.temperature = Temperature::with_priority(0.3);
+        breakthrough
+            .temperature
+            .record_breakthrough(0.5, &coefficients);
+
+        // Same thread, different temperatures
+        assert!((state.effective_temperature(&busy, AgentType::Research) - 0.5).abs() < 1e-9);
+        assert!((state.effective_temperature(&busy, AgentType::Adversarial) - 0.3).abs() < 1e-9);
+        assert!(
+            (state.effective_temperature(&breakthrough, AgentType::Research) - 0.45).abs() < 1e-9
+        );
+        assert!(
+            (state.effective_temperature(&breakthrough, AgentType::Adversarial) - 0.6).abs() < 1e-9
+        );
+
+        state.projection.threads.add_thread(busy);
+        state.projection.threads.add_thread(breakthrough);
+
+        let research = state.available_threads_for(AgentType::Research);
+        assert_eq!(research[0].metadata.title, "Busy");
+        let adversarial = state.available_threads_for(AgentType::Adversarial);
+        assert_eq!(adversarial[0].metadata.title, "Breakthrough");
+    }
+
+    #[test]
+    fn test_assign_round_spreads_threads() {
+        use crate::agent::Agent;
+        use crate::thread::ThreadMetadata;
+
+        let mut state = CoordinationState::new();
+        for i in 0..3 {
+            let mut thread = Thread::new(ThreadMetadata {
+                title: format!("Thread {}", i),
+                ..Default::default()
+            });
+            thread.temperature = Temperature::with_priority(0.5);
+            state.projection.threads.add_thread(thread);
+        }
+        for id in ["agent-a", "agent-b", "agent-c"] {
+            state
+                .agents_mut()
+                .register(Agent::new(id.to_string(), AgentType::Research))
+                .unwrap();
+        }
+
+        let round = state.assign_round(&["agent-c", "agent-a", "agent-b"]);
+        assert_eq!(round.len(), 3);
+        let agents: Vec<&str> = round.iter().map(|(a, _)| a.as_str()).collect();
+        assert_eq!(agents, vec!["agent-a", "agent-b", "agent-c"]);
+        let threads: HashSet<_> = round.iter().map(|(_, t)| t).collect();
+        assert_eq!(threads.len(), 3);
+
+        // Planning is deterministic
+        assert_eq!(
+            state.assign_round(&["agent-a", "agent-b", "agent-c"]),
+            round
+        );
+    }
+
+    #[test]
+    fn test_assign_round_prefers_least_loaded() {
+        use crate::agent::Agent;
+        use crate::thread::ThreadMetadata;
+
+        let mut state = CoordinationState::new();
+        let mut held = Thread::new(ThreadMetadata::default());
+        held.claim("agent-a").unwrap();
+        state.projection.threads.add_thread(held);
+
+        let mut hot = Thread::new(ThreadMetadata {
+            title: "Hot".to_string(),
+            ..Default::default()
+        });
+        hot.temperature = Temperature::with_priority(0.9);
+        let hot_id = hot.id.clone();
+        state.projection.threads.add_thread(hot);
+        let mut cold = Thread::new(ThreadMetadata::default());
+        cold.temperature = Temperature::with_priority(0.1);
+        state.projection.threads.add_thread(cold);
+
+        for id in ["agent-a", "agent-b"] {
+            state
+                .agents_mut()
+                .register(Agent::new(id.to_string(), AgentType::Research))
+                .unwrap();
+        }
+
+        // agent-a already holds a thread, so agent-b gets first pick
+        let round = state.assign_round(&["agent-a", "agent-b", "missing"]);
+        assert_eq!(round.len(), 2);
+        assert_eq!(round[0], ("agent-b".to_string(), hot_id));
+        assert_eq!(round[1].0, "agent-a");
+    }
+
+    #[test]
+    fn test_duplicate_escalations_collapse() {
+        let mut state = CoordinationState::new();
+        let thread_id = ThreadId::new();
+        let report = |agent: &str, details: &str| {
+            Escalation::new(
+                EscalationCategory::Stuck,
+                "Dataset unavailable".to_string(),
+                details.to_string(),
+                agent.to_string(),
+            )
+            .with_thread(thread_id)
+        };
+
+        let first = state
+            .create_escalation(report("research-1", "Mirror is down"))
+            .unwrap();
+        let second = state
+            .create_escalation(report("research-2", "Archive times out"))
+            .unwrap();
+        assert_eq!(first.entity_id, second.entity_id);
+        assert!(matches!(
+            first.payload,
+            EventPayload::EscalationCreated { .. }
+        ));
+        assert!(matches!(
+            &second.payload,
+            EventPayload::EscalationRecurred { reported_by, occurrence_count: 2 }
+                if reported_by == "research-2"
+        ));
+        let first = first.entity_id;
+
+        let open = state.open_escalations();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].occurrence_count, 2);
+        assert_eq!(open[0].description, "Mirror is down");
+        assert_eq!(open[0].additional_context.len(), 1);
+        assert_eq!(open[0].additional_context[0].reported_by, "research-2");
+
+        // Resolving the merged escalation clears every report of the issue
+        state
+            .get_escalation_mut(&first)
+            .unwrap()
+            .resolve("human".to_string(), "Mirror restored".to_string());
+        assert!(state.open_escalations().is_empty());
+
+        // A fresh report after resolution is a new escalation
+        let third = state
+            .create_escalation(report("research-1", "Down again"))
+            .unwrap()
+            .entity_id;
+        assert_ne!(third, first);
+        assert_eq!(state.get_escalation(&third).unwrap().occurrence_count, 1);
+    }
+
+    fn create_blocked_thread(
+        state: &mut CoordinationState,
+        title: &str,
+        at: DateTime<Utc>,
+    ) -> String {
+        let thread_id = ThreadId::new().to_string();
+        state
+            .apply_event(Event::new(
+                thread_id.clone(),
+                EntityType::Thread,
+                EventPayload::ThreadCreated {
+                    title: title.to_string(),
+                    description: String::new(),
+                    parent_id: None,
+                },
+            ))
+            .unwrap();
+        let mut blocked = Event::new(
+            thread_id.clone(),
+            EntityType::Thread,
+            EventPayload::ThreadStateChanged {
+                from: ThreadState::Active,
+                to: ThreadState::Blocked,
+                reason: Some("waiting on data".to_string()),
+            },
+        );
+        blocked.timestamp = at;
+        state.apply_event(blocked).unwrap();
+        thread_id
+    }
+
+    #[test]
+    fn test_stuck_thread_escalates_once() {
+        let mut state = CoordinationState::new().with_escalation_config(EscalationConfig {
+            stuck_threshold_hours: 24,
+            ..Default::default()
+        });
+        let now = Utc::now();
+        let old = create_blocked_thread(&mut state, "Old", now - Duration::hours(30));
+        let recent = create_blocked_thread(&mut state, "Recent", now - Duration::hours(2));
+
+        let raised = state.check_stuck_threads(now).unwrap();
+        assert_eq!(raised.len(), 1);
+        let escalation = state.get_escalation(&raised[0]).unwrap();
+        assert_eq!(escalation.category, EscalationCategory::Stuck);
+        assert_eq!(
+            escalation.thread_id.map(|t| t.to_string()),
+            Some(old.clone())
+        );
+        assert_eq!(
+            state.get_thread(&old).unwrap().stuck_escalation_id,
+            Some(raised[0].clone())
+        );
+        assert!(state
+            .get_thread(&recent)
+            .unwrap()
+            .stuck_escalation_id
+            .is_none());
+
+        // Still blocked, but already escalated
+        assert!(state
+            .check_stuck_threads(now + Duration::hours(1))
+            .unwrap()
+            .is_empty());
+        assert_eq!(state.open_escalations().len(), 1);
+        assert_eq!(
+            state.get_escalation(&raised[0]).unwrap().occurrence_count,
+            1
+        );
+    }
+
+    #[test]
+    fn test_stuck_escalation_survives_rebuild() {
+        let mut state = CoordinationState::new();
+        let now = Utc::now();
+        let thread_id = create_blocked_thread(&mut state, "Stalled", now - Duration::hours(100));
+        let raised = state.check_stuck_threads(now).unwrap();
+        assert_eq!(raised.len(), 1);
+
+        state.rebuild().unwrap();
+        assert_eq!(
+            state.get_thread(&thread_id).unwrap().stuck_escalation_id,
+            Some(raised[0].clone())
+        );
+
+        assert!(state
+            .check_stuck_threads(now + Duration::hours(1))
+            .unwrap()
+            .is_empty());
+        assert_eq!(state.all_escalations().count(), 1);
+        assert_eq!(
+            state.get_escalation(&raised[0]).unwrap().occurrence_count,
+            1
+        );
+        let recurrences = state
+            .all_events()
+            .into_iter()
+            .filter(|e| matches!(e.payload, EventPayload::EscalationRecurred { .. }))
+            .count();
+        assert_eq!(recurrences, 0);
+
+        state
+            .apply_event(Event::new(
+                raised[0].clone(),
+                EntityType::Escalation,
+                EventPayload::EscalationResolved {
+                    resolver_id: "human".to_string(),
+                    resolution: "Data arrived".to_string(),
+                },
+            ))
+            .unwrap();
+        assert!(state
+            .get_thread(&thread_id)
+            .unwrap()
+            .stuck_escalation_id
+            .is_none());
+    }
+
+    #[test]
+    fn test_unblocking_clears_watchdog() {
+        let mut state = CoordinationState::new();
+        let now = Utc::now();
+        let thread_id = create_blocked_thread(&mut state, "Flaky", now - Duration::hours(100));
+        assert_eq!(state.check_stuck_threads(now).unwrap().len(), 1);
+
+        state
+            .apply_event(Event::new(
+                thread_id.clone(),
+                EntityType::Thread,
+                EventPayload::ThreadStateChanged {
+                    from: ThreadState::Blocked,
+                    to: ThreadState::Active,
+                    reason: None,
+                },
+            ))
+            .unwrap();
+        let thread = state.get_thread(&thread_id).unwrap();
+        assert!(thread.blocked_since.is_none());
+        assert!(thread.stuck_escalation_id.is_none());
+        assert!(state.check_stuck_threads(now).unwrap().is_empty());
+    }
+
     #[test]
     fn test_escalation_priority_ordering() {
-        use crate::escalation::{EscalationCategory, EscalationPriority};
+        use crate::escalation::EscalationPriority;
 
         let mut state = CoordinationState::new();
 
@@ -46,6 +46,16 @@ pub enum ImpelError {
     NotFound(String),
 }
 
+/// A batch of events rejected as a whole because one event was invalid
+#[derive(Error, Debug)]
+#[error("Event {index} in batch rejected: {error}")]
+pub struct BatchRejected {
+    /// Position of the offending event in the batch
+    pub index: usize,
+    /// Why the event was rejected
+    pub error: ImpelError,
+}
+
 /// Thread-specific errors
 #[derive(Error, Debug)]
 pub enum ThreadError {
@@ -108,6 +118,18 @@ pub enum EventError {
     /// Projection error
     #[error("Projection error: {0}")]
     ProjectionError(String),
+
+    /// Event carries no actor to attribute it to
+    #[error("Event {0} has no author")]
+    MissingAuthor(String),
+
+    /// No signing key is known for the claimed author
+    #[error("No signing key registered for agent {0}")]
+    UnknownSigner(String),
+
+    /// Signature is missing or does not match the claimed author
+    #[error("Invalid signature on event {event_id} from agent {actor_id}")]
+    InvalidSignature { event_id: String, actor_id: String },
 }
 
 /// Message-specific errors
@@ -2,6 +2,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 use crate::thread::ThreadId;
@@ -25,6 +26,16 @@ pub enum EscalationCategory {
 }
 
 impl EscalationCategory {
+    /// All categories, in declaration order
+    pub const ALL: [EscalationCategory; 6] = [
+        EscalationCategory::Decision,
+        EscalationCategory::Novelty,
+        EscalationCategory::Stuck,
+        EscalationCategory::Scope,
+        EscalationCategory::Quality,
+        EscalationCategory::Checkpoint,
+    ];
+
     /// Get a human-readable name for the category
     pub fn name(&self) -> &'static str {
         match self {
@@ -194,6 +205,16 @@ pub struct Escalation {
     pub options: Vec<EscalationOption>,
     /// Selected option index (if applicable)
     pub selected_option: Option<usize>,
+    /// Number of times this issue was raised, including the original
+    #[serde(default = "default_occurrence_count")]
+    pub occurrence_count: u32,
+    /// Context from duplicate reports merged into this escalation
+    #[serde(default)]
+    pub additional_context: Vec<EscalationOccurrence>,
+}
+
+fn default_occurrence_count() -> u32 {
+    1
 }
 
 impl Escalation {
@@ -221,6 +242,8 @@ impl Escalation {
             resolution: None,
             options: Vec::new(),
             selected_option: None,
+            occurrence_count: 1,
+            additional_context: Vec::new(),
         }
     }
 
@@ -287,6 +310,43 @@ impl Escalation {
         }
     }
 
+    /// Key identifying the underlying issue, for de-duplication
+    ///
+    /// Combines the category, related thread, and a hash of the normalized
+    /// title. Descriptions are left out since each reporting agent words the
+    /// details differently.
+    pub fn dedup_key(&self) -> String {
+        let normalized_title = self
+            .title
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        let digest = Sha256::digest(normalized_title.as_bytes());
+        let content_hash: String = digest[..8].iter().map(|b| format!("{:02x}", b)).collect();
+
+        format!(
+            "{}:{}:{}",
+            self.category.name(),
+            self.thread_id
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            content_hash
+        )
+    }
+
+    /// Merge a duplicate report of the same issue into this escalation
+    pub fn record_occurrence(&mut self, duplicate: Escalation) {
+        self.occurrence_count += duplicate.occurrence_count;
+        self.priority = self.priority.max(duplicate.priority);
+        self.additional_context.push(EscalationOccurrence {
+            reported_by: duplicate.created_by,
+            description: duplicate.description,
+            reported_at: duplicate.created_at,
+        });
+        self.additional_context.extend(duplicate.additional_context);
+    }
+
     /// Get the time since creation
     pub fn age(&self) -> chrono::Duration {
         Utc::now() - self.created_at
@@ -298,6 +358,17 @@ impl Escalation {
     }
 }
 
+/// A duplicate report merged into an existing escalation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscalationOccurrence {
+    /// Agent that raised the duplicate
+    pub reported_by: String,
+    /// Description given in the duplicate report
+    pub description: String,
+    /// When the duplicate was raised
+    pub reported_at: DateTime<Utc>,
+}
+
 /// An option for a Decision escalation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -365,6 +436,33 @@ mod tests {
         assert!(!escalation.status.is_open());
     }
 
+    #[test]
+    fn test_dedup_key() {
+        let thread_id = ThreadId::new();
+        let a = Escalation::new(
+            EscalationCategory::Stuck,
+            "Dataset  unavailable".to_string(),
+            "Mirror is down".to_string(),
+            "research-1".to_string(),
+        )
+        .with_thread(thread_id);
+        let b = Escalation::new(
+            EscalationCategory::Stuck,
+            "dataset unavailable".to_string(),
+            "Cannot reach the archive".to_string(),
+            "research-2".to_string(),
+        )
+        .with_thread(thread_id);
+        assert_eq!(a.dedup_key(), b.dedup_key());
+
+        let other_thread = b.clone().with_thread(ThreadId::new());
+        assert_ne!(a.dedup_key(), other_thread.dedup_key());
+
+        let mut other_category = b.clone();
+        other_category.category = EscalationCategory::Quality;
+        assert_ne!(a.dedup_key(), other_category.dedup_key());
+    }
+
     #[test]
     fn test_decision_options() {
         let options = vec![
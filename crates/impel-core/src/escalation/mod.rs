@@ -6,5 +6,6 @@
 mod category;
 
 pub use category::{
-    Escalation, EscalationCategory, EscalationOption, EscalationPriority, EscalationStatus,
+    Escalation, EscalationCategory, EscalationOccurrence, EscalationOption, EscalationPriority,
+    EscalationStatus,
 };
@@ -4,9 +4,11 @@
 //! projected from the event log, allowing for time-travel and audit trails.
 
 mod projection;
+mod replay;
 mod store;
 mod types;
 
 pub use projection::{AgentProjection, Projection, SystemProjection, ThreadProjection};
+pub use replay::{assert_replay_deterministic, shuffle_independent};
 pub use store::{EventStore, InMemoryEventStore};
 pub use types::{EntityType, Event, EventId, EventPayload};
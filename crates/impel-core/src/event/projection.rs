@@ -4,9 +4,12 @@
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
+
 use super::types::{EntityType, Event, EventPayload};
 use crate::agent::{Agent, AgentRegistry, AgentStatus};
 use crate::error::Result;
+use crate::escalation::EscalationCategory;
 use crate::thread::{Thread, ThreadId, ThreadMetadata, ThreadState};
 
 /// Trait for projecting state from events
@@ -16,12 +19,41 @@ pub trait Projection {
 
     /// Reset the projection to initial state
     fn reset(&mut self);
+
+    /// Build a projection by applying `events` in order to an empty one
+    ///
+    /// This is the canonical reducer; rebuilding and replay checks go
+    /// through it so they agree on how events fold into state.
+    fn from_events<'a>(events: impl IntoIterator<Item = &'a Event>) -> Result<Self>
+    where
+        Self: Default + Sized,
+    {
+        let mut projection = Self::default();
+        for event in events {
+            projection.apply(event)?;
+        }
+        Ok(projection)
+    }
+
+    /// Build the projection as it stood at `timestamp`
+    ///
+    /// Only events with `timestamp <= as_of` are applied, so events at
+    /// exactly that moment are included. The result is a historical
+    /// snapshot for inspection; don't apply new events to it.
+    fn as_of<'a>(events: impl IntoIterator<Item = &'a Event>, as_of: DateTime<Utc>) -> Result<Self>
+    where
+        Self: Default + Sized,
+    {
+        Self::from_events(events.into_iter().filter(|e| e.timestamp <= as_of))
+    }
 }
 
 /// Projection of thread state from events
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct ThreadProjection {
     threads: HashMap<String, Thread>,
+    /// Open stuck escalations, by escalation ID, and the thread each is for
+    stuck_escalations: HashMap<String, String>,
 }
 
 impl ThreadProjection {
@@ -29,6 +61,7 @@ impl ThreadProjection {
     pub fn new() -> Self {
         Self {
             threads: HashMap::new(),
+            stuck_escalations: HashMap::new(),
         }
     }
 
@@ -37,6 +70,11 @@ impl ThreadProjection {
         self.threads.get(id)
     }
 
+    /// Get a mutable reference to a thread by ID
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut Thread> {
+        self.threads.get_mut(id)
+    }
+
     /// Get all threads
     pub fn all(&self) -> impl Iterator<Item = &Thread> {
         self.threads.values()
@@ -68,12 +106,63 @@ impl ThreadProjection {
     ///
     /// This bypasses event sourcing and is intended for initial state loading.
     pub fn add_thread(&mut self, thread: Thread) {
+        if let Some(escalation_id) = &thread.stuck_escalation_id {
+            self.stuck_escalations
+                .insert(escalation_id.clone(), thread.id.to_string());
+        }
         self.threads.insert(thread.id.to_string(), thread);
     }
+
+    /// Track stuck escalations on the blocked threads they were raised for
+    ///
+    /// A thread's `stuck_escalation_id` is set when a stuck escalation is
+    /// created (or a duplicate report recurs) for it, and cleared when that
+    /// escalation is resolved or the thread leaves Blocked.
+    fn apply_escalation(&mut self, event: &Event) {
+        let escalation_id = &event.entity_id;
+        let thread_id = match &event.payload {
+            EventPayload::EscalationCreated {
+                category: EscalationCategory::Stuck,
+                thread_id: Some(thread_id),
+                ..
+            } => {
+                self.stuck_escalations
+                    .insert(escalation_id.clone(), thread_id.clone());
+                thread_id
+            }
+            EventPayload::EscalationRecurred { .. } => {
+                match self.stuck_escalations.get(escalation_id) {
+                    Some(thread_id) => thread_id,
+                    None => return,
+                }
+            }
+            EventPayload::EscalationResolved { .. } => {
+                if let Some(thread_id) = self.stuck_escalations.remove(escalation_id) {
+                    if let Some(thread) = self.threads.get_mut(&thread_id) {
+                        if thread.stuck_escalation_id.as_ref() == Some(escalation_id) {
+                            thread.stuck_escalation_id = None;
+                        }
+                    }
+                }
+                return;
+            }
+            _ => return,
+        };
+
+        if let Some(thread) = self.threads.get_mut(thread_id) {
+            if thread.state == ThreadState::Blocked {
+                thread.stuck_escalation_id = Some(escalation_id.clone());
+            }
+        }
+    }
 }
 
 impl Projection for ThreadProjection {
     fn apply(&mut self, event: &Event) -> Result<()> {
+        if event.entity_type == EntityType::Escalation {
+            self.apply_escalation(event);
+            return Ok(());
+        }
         if event.entity_type != EntityType::Thread {
             return Ok(());
         }
@@ -92,13 +181,15 @@ impl Projection for ThreadProjection {
                 };
 
                 let thread_id = ThreadId::parse(&event.entity_id)?;
-                let thread = Thread::with_id(thread_id, metadata);
+                let mut thread = Thread::with_id(thread_id, metadata);
+                // Derive timestamps from the event so replays are reproducible
+                thread.created_at = event.timestamp;
                 self.threads.insert(event.entity_id.clone(), thread);
             }
 
             EventPayload::ThreadStateChanged { to, .. } => {
                 if let Some(thread) = self.threads.get_mut(&event.entity_id) {
-                    thread.state = *to;
+                    thread.set_state(*to, event.timestamp);
                     thread.version += 1;
                 }
             }
@@ -156,11 +247,12 @@ impl Projection for ThreadProjection {
 
     fn reset(&mut self) {
         self.threads.clear();
+        self.stuck_escalations.clear();
     }
 }
 
 /// Projection of agent state from events
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct AgentProjection {
     registry: AgentRegistry,
 }
@@ -202,6 +294,7 @@ impl Projection for AgentProjection {
                         "IDLE" => AgentStatus::Idle,
                         "WORKING" => AgentStatus::Working,
                         "PAUSED" => AgentStatus::Paused,
+                        "OFFLINE" => AgentStatus::Offline,
                         "TERMINATED" => AgentStatus::Terminated,
                         _ => agent.status,
                     };
@@ -231,7 +324,7 @@ impl Projection for AgentProjection {
 }
 
 /// Combined projection for the entire system state
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct SystemProjection {
     pub threads: ThreadProjection,
     pub agents: AgentProjection,
@@ -252,10 +345,7 @@ impl SystemProjection {
 
     /// Rebuild state from a list of events
     pub fn rebuild<'a>(&mut self, events: impl Iterator<Item = &'a Event>) -> Result<()> {
-        self.reset();
-        for event in events {
-            self.apply(event)?;
-        }
+        *self = Self::from_events(events)?;
         Ok(())
     }
 }
@@ -352,6 +442,59 @@ mod tests {
         assert_eq!(thread.state, ThreadState::Active);
     }
 
+    #[test]
+    fn test_as_of_projection() {
+        let thread_id = ThreadId::new();
+        let start = Utc::now();
+        let at = |minutes| start + chrono::Duration::minutes(minutes);
+        let event = |minutes, payload| {
+            let mut event = Event::new(thread_id.to_string(), EntityType::Thread, payload);
+            event.timestamp = at(minutes);
+            event
+        };
+        let transition = |minutes, from, to| {
+            event(
+                minutes,
+                EventPayload::ThreadStateChanged {
+                    from,
+                    to,
+                    reason: None,
+                },
+            )
+        };
+
+        let events = vec![
+            event(
+                0,
+                EventPayload::ThreadCreated {
+                    title: "Test".to_string(),
+                    description: "".to_string(),
+                    parent_id: None,
+                },
+            ),
+            transition(1, ThreadState::Embryo, ThreadState::Active),
+            transition(5, ThreadState::Active, ThreadState::Review),
+            transition(6, ThreadState::Review, ThreadState::Complete),
+        ];
+        let id = thread_id.to_string();
+
+        let current = SystemProjection::from_events(&events).unwrap();
+        assert_eq!(
+            current.threads.get(&id).unwrap().state,
+            ThreadState::Complete
+        );
+
+        let before = SystemProjection::as_of(&events, at(3)).unwrap();
+        assert_eq!(before.threads.get(&id).unwrap().state, ThreadState::Active);
+
+        // An event exactly at the cutoff is included
+        let boundary = ThreadProjection::as_of(&events, at(5)).unwrap();
+        assert_eq!(boundary.get(&id).unwrap().state, ThreadState::Review);
+
+        let earlier = ThreadProjection::as_of(&events, at(-1)).unwrap();
+        assert!(earlier.get(&id).is_none());
+    }
+
     #[test]
     fn test_agent_projection() {
         let mut projection = AgentProjection::new();
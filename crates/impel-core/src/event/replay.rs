@@ -0,0 +1,227 @@
+//! Replay determinism checks for projections
+//!
+//! Events on different entities are independent: replaying them in any
+//! interleaving must project the same state. Events on the same entity
+//! (state transitions, claims and releases) are order-sensitive and keep
+//! their relative order. These helpers are meant for tests, including those
+//! of projections defined outside this crate.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+
+use super::projection::Projection;
+use super::types::{EntityType, Event};
+
+/// Reorder events while preserving the order of events on each entity
+///
+/// The result is a random interleaving of the per-entity event streams,
+/// chosen deterministically from `seed`.
+pub fn shuffle_independent(events: &[Event], seed: u64) -> Vec<Event> {
+    let mut order: Vec<(EntityType, &str)> = Vec::new();
+    let mut streams: HashMap<(EntityType, &str), VecDeque<&Event>> = HashMap::new();
+    for event in events {
+        let key = (event.entity_type, event.entity_id.as_str());
+        let stream = streams.entry(key).or_insert_with(|| {
+            order.push(key);
+            VecDeque::new()
+        });
+        stream.push_back(event);
+    }
+
+    // xorshift64*: no need for a real RNG, only for varied, repeatable orders
+    let mut state = seed.max(1);
+    let mut next_index = |len: usize| {
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        (state.wrapping_mul(0x2545_F491_4F6C_DD1D) % len as u64) as usize
+    };
+
+    let mut shuffled = Vec::with_capacity(events.len());
+    while !order.is_empty() {
+        let i = next_index(order.len());
+        let stream = streams.get_mut(&order[i]).expect("stream for every key");
+        shuffled.push(
+            stream
+                .pop_front()
+                .expect("streams in order are non-empty")
+                .clone(),
+        );
+        if stream.is_empty() {
+            order.swap_remove(i);
+        }
+    }
+    shuffled
+}
+
+/// Assert that a projection is independent of how entity streams interleave
+///
+/// Projects `events` in their given order, then again for `rounds` shuffles
+/// from [`shuffle_independent`], and compares `snapshot` of each result.
+/// Snapshots should leave out anything a projection takes from the wall
+/// clock rather than from events.
+///
+/// # Panics
+///
+/// If any replay fails to apply or projects a different snapshot.
+pub fn assert_replay_deterministic<P, S>(events: &[Event], rounds: u64, snapshot: impl Fn(&P) -> S)
+where
+    P: Projection + Default,
+    S: PartialEq + Debug,
+{
+    let expected = snapshot(&P::from_events(events).expect("events apply in original order"));
+
+    for seed in 1..=rounds {
+        let shuffled = shuffle_independent(events, seed);
+        let projection = P::from_events(&shuffled)
+            .unwrap_or_else(|e| panic!("replay with seed {} failed: {}", seed, e));
+        assert_eq!(
+            expected,
+            snapshot(&projection),
+            "projection depends on event interleaving (seed {})",
+            seed
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::AgentType;
+    use crate::event::{EventPayload, SystemProjection, ThreadProjection};
+    use crate::thread::{ThreadId, ThreadState};
+
+    fn thread_event(thread_id: &str, payload: EventPayload) -> Event {
+        Event::new(thread_id.to_string(), EntityType::Thread, payload)
+    }
+
+    fn created(thread_id: &str, title: &str) -> Event {
+        thread_event(
+            thread_id,
+            EventPayload::ThreadCreated {
+                title: title.to_string(),
+                description: String::new(),
+                parent_id: None,
+            },
+        )
+    }
+
+    fn transition(thread_id: &str, from: ThreadState, to: ThreadState) -> Event {
+        thread_event(
+            thread_id,
+            EventPayload::ThreadStateChanged {
+                from,
+                to,
+                reason: None,
+            },
+        )
+    }
+
+    /// (id, title, state, claimant, artifacts) of a thread
+    type ThreadRow = (String, String, ThreadState, Option<String>, Vec<String>);
+
+    /// Sorted rows for every thread
+    fn thread_snapshot(projection: &SystemProjection) -> Vec<ThreadRow> {
+        let mut threads: Vec<_> = projection
+            .threads
+            .all()
+            .map(|t| {
+                (
+                    t.id.to_string(),
+                    t.metadata.title.clone(),
+                    t.state,
+                    t.claimed_by.clone(),
+                    t.artifact_ids.clone(),
+                )
+            })
+            .collect();
+        threads.sort_by(|a, b| a.0.cmp(&b.0));
+        threads
+    }
+
+    #[test]
+    fn test_independent_entities_commute() {
+        let a = ThreadId::new().to_string();
+        let b = ThreadId::new().to_string();
+        let c = ThreadId::new().to_string();
+
+        let events = vec![
+            created(&a, "A"),
+            created(&b, "B"),
+            Event::new(
+                "research-1".to_string(),
+                EntityType::Agent,
+                EventPayload::AgentRegistered {
+                    agent_type: AgentType::Research,
+                    capabilities: vec![],
+                },
+            ),
+            transition(&a, ThreadState::Embryo, ThreadState::Active),
+            created(&c, "C"),
+            thread_event(
+                &b,
+                EventPayload::ThreadClaimed {
+                    agent_id: "research-1".to_string(),
+                },
+            ),
+            thread_event(
+                &c,
+                EventPayload::ThreadArtifactAdded {
+                    artifact_id: "fig-1".to_string(),
+                    artifact_type: "figure".to_string(),
+                },
+            ),
+            transition(&a, ThreadState::Active, ThreadState::Blocked),
+        ];
+
+        assert_replay_deterministic::<SystemProjection, _>(&events, 32, |p| {
+            let agents: Vec<_> = p.agents.registry().all().map(|a| a.id.clone()).collect();
+            (thread_snapshot(p), agents, p.is_paused)
+        });
+        assert_replay_deterministic::<ThreadProjection, _>(&events, 32, |p| {
+            let mut ids: Vec<_> = p.all().map(|t| (t.id.to_string(), t.state)).collect();
+            ids.sort_by(|a, b| a.0.cmp(&b.0));
+            ids
+        });
+    }
+
+    #[test]
+    fn test_same_entity_order_is_preserved() {
+        let a = ThreadId::new().to_string();
+        let b = ThreadId::new().to_string();
+        let events = vec![
+            created(&a, "A"),
+            transition(&a, ThreadState::Embryo, ThreadState::Active),
+            created(&b, "B"),
+            transition(&a, ThreadState::Active, ThreadState::Blocked),
+            transition(&b, ThreadState::Embryo, ThreadState::Active),
+            transition(&a, ThreadState::Blocked, ThreadState::Active),
+            transition(&a, ThreadState::Active, ThreadState::Review),
+        ];
+
+        for seed in 1..=16 {
+            let shuffled = shuffle_independent(&events, seed);
+            assert_eq!(shuffled.len(), events.len());
+            let a_ids: Vec<_> = shuffled
+                .iter()
+                .filter(|e| e.entity_id == a)
+                .map(|e| e.id)
+                .collect();
+            let expected: Vec<_> = events
+                .iter()
+                .filter(|e| e.entity_id == a)
+                .map(|e| e.id)
+                .collect();
+            assert_eq!(a_ids, expected);
+        }
+        assert_replay_deterministic::<SystemProjection, _>(&events, 16, thread_snapshot);
+
+        // Reordering transitions on one thread does change the outcome,
+        // which is why they are never shuffled
+        let mut reordered = events.clone();
+        reordered.swap(3, 6);
+        let original = SystemProjection::from_events(&events).unwrap();
+        let swapped = SystemProjection::from_events(&reordered).unwrap();
+        assert_ne!(thread_snapshot(&original), thread_snapshot(&swapped));
+    }
+}
@@ -11,6 +11,29 @@ pub trait EventStore: Send + Sync {
     /// Append an event to the store
     fn append(&mut self, event: Event) -> Result<Event>;
 
+    /// Append an event only if it is signed by its claimed author
+    ///
+    /// `keys` maps agent IDs to their signing keys. Events without an actor,
+    /// from an actor with no registered key, or whose signature does not
+    /// verify are rejected and not stored.
+    fn append_verified(&mut self, event: Event, keys: &HashMap<String, Vec<u8>>) -> Result<Event> {
+        let actor_id = event
+            .actor_id
+            .clone()
+            .ok_or_else(|| EventError::MissingAuthor(event.id.to_string()))?;
+        let key = keys
+            .get(&actor_id)
+            .ok_or_else(|| EventError::UnknownSigner(actor_id.clone()))?;
+        if !event.verify(key) {
+            return Err(EventError::InvalidSignature {
+                event_id: event.id.to_string(),
+                actor_id,
+            }
+            .into());
+        }
+        self.append(event)
+    }
+
     /// Get an event by ID
     fn get(&self, id: &EventId) -> Option<&Event>;
 
@@ -179,7 +202,8 @@ impl SqliteEventStore {
                 payload TEXT NOT NULL,
                 actor_id TEXT,
                 correlation_id TEXT,
-                causation_id TEXT
+                causation_id TEXT,
+                signature TEXT
             );
 
             CREATE INDEX IF NOT EXISTS idx_events_entity ON events(entity_id, entity_type);
@@ -187,6 +211,14 @@ impl SqliteEventStore {
             CREATE INDEX IF NOT EXISTS idx_events_correlation ON events(correlation_id);
             "#,
         )?;
+
+        // Stores created before signatures were persisted lack the column
+        if conn
+            .prepare("SELECT signature FROM events LIMIT 0")
+            .is_err()
+        {
+            conn.execute_batch("ALTER TABLE events ADD COLUMN signature TEXT")?;
+        }
         Ok(())
     }
 
@@ -199,8 +231,8 @@ impl SqliteEventStore {
 
         self.conn.execute(
             r#"
-            INSERT INTO events (id, sequence, timestamp, entity_id, entity_type, payload, actor_id, correlation_id, causation_id)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            INSERT INTO events (id, sequence, timestamp, entity_id, entity_type, payload, actor_id, correlation_id, causation_id, signature)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
             "#,
             rusqlite::params![
                 event.id.value.to_string(),
@@ -212,6 +244,7 @@ impl SqliteEventStore {
                 event.actor_id,
                 event.correlation_id,
                 event.causation_id.map(|id| id.value.to_string()),
+                event.signature,
             ],
         )?;
 
@@ -226,7 +259,7 @@ impl SqliteEventStore {
     ) -> Result<Vec<Event>> {
         let mut stmt = self.conn.prepare(
             r#"
-            SELECT id, sequence, timestamp, entity_id, entity_type, payload, actor_id, correlation_id, causation_id
+            SELECT id, sequence, timestamp, entity_id, entity_type, payload, actor_id, correlation_id, causation_id, signature
             FROM events
             WHERE entity_id = ?1 AND entity_type = ?2
             ORDER BY sequence
@@ -247,7 +280,7 @@ impl SqliteEventStore {
     pub fn events_after(&self, sequence: u64) -> Result<Vec<Event>> {
         let mut stmt = self.conn.prepare(
             r#"
-            SELECT id, sequence, timestamp, entity_id, entity_type, payload, actor_id, correlation_id, causation_id
+            SELECT id, sequence, timestamp, entity_id, entity_type, payload, actor_id, correlation_id, causation_id, signature
             FROM events
             WHERE sequence > ?1
             ORDER BY sequence
@@ -274,6 +307,7 @@ impl SqliteEventStore {
         let actor_id: Option<String> = row.get(6)?;
         let correlation_id: Option<String> = row.get(7)?;
         let causation_id_str: Option<String> = row.get(8)?;
+        let signature: Option<String> = row.get(9)?;
 
         let entity_type = match entity_type_str.as_str() {
             "thread" => EntityType::Thread,
@@ -300,6 +334,7 @@ impl SqliteEventStore {
             causation_id: causation_id_str.map(|s| EventId {
                 value: Uuid::parse_str(&s).unwrap(),
             }),
+            signature,
         })
     }
 
@@ -312,6 +347,7 @@ impl SqliteEventStore {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::ImpelError;
     use crate::event::types::EventPayload;
 
     #[test]
@@ -365,6 +401,91 @@ mod tests {
         assert_eq!(events.len(), 1);
     }
 
+    #[test]
+    fn test_append_verified() {
+        let mut store = InMemoryEventStore::new();
+        let keys = HashMap::from([("agent-1".to_string(), b"agent-1-key".to_vec())]);
+        let claim = |agent: &str| {
+            Event::new(
+                "thread-1".to_string(),
+                EntityType::Thread,
+                EventPayload::ThreadClaimed {
+                    agent_id: agent.to_string(),
+                },
+            )
+            .with_actor(agent.to_string())
+        };
+
+        let stored = store
+            .append_verified(claim("agent-1").signed(b"agent-1-key"), &keys)
+            .unwrap();
+        assert_eq!(stored.sequence, 1);
+
+        // Signed with another agent's key while claiming to be agent-1
+        let forged = claim("agent-1").signed(b"agent-2-key");
+        assert!(matches!(
+            store.append_verified(forged, &keys),
+            Err(ImpelError::Event(EventError::InvalidSignature { .. }))
+        ));
+
+        let mut tampered = claim("agent-1").signed(b"agent-1-key");
+        tampered.entity_id = "thread-2".to_string();
+        assert!(store.append_verified(tampered, &keys).is_err());
+
+        assert!(matches!(
+            store.append_verified(claim("agent-2").signed(b"agent-2-key"), &keys),
+            Err(ImpelError::Event(EventError::UnknownSigner(_)))
+        ));
+        assert_eq!(store.len(), 1);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_signature_survives_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.db");
+        let path = path.to_str().unwrap();
+
+        // A store created before signatures were persisted
+        rusqlite::Connection::open(path)
+            .unwrap()
+            .execute_batch(
+                "CREATE TABLE events (
+                    id TEXT PRIMARY KEY,
+                    sequence INTEGER NOT NULL UNIQUE,
+                    timestamp TEXT NOT NULL,
+                    entity_id TEXT NOT NULL,
+                    entity_type TEXT NOT NULL,
+                    payload TEXT NOT NULL,
+                    actor_id TEXT,
+                    correlation_id TEXT,
+                    causation_id TEXT
+                );",
+            )
+            .unwrap();
+
+        {
+            let mut store = SqliteEventStore::new(path).unwrap();
+            let event = Event::new(
+                "thread-1".to_string(),
+                EntityType::Thread,
+                EventPayload::ThreadClaimed {
+                    agent_id: "agent-1".to_string(),
+                },
+            )
+            .with_actor("agent-1".to_string())
+            .signed(b"agent-1-key");
+            store.append(event).unwrap();
+        }
+
+        let store = SqliteEventStore::new(path).unwrap();
+        let events = store.events_after(0).unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].signature.is_some());
+        assert!(events[0].verify(b"agent-1-key"));
+        assert!(!events[0].verify(b"agent-2-key"));
+    }
+
     #[test]
     fn test_sequence_numbers() {
         let mut store = InMemoryEventStore::new();
@@ -1,7 +1,9 @@
 //! Event types for event sourcing
 
 use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
 use uuid::Uuid;
 
 use crate::agent::AgentType;
@@ -62,6 +64,9 @@ pub struct Event {
     pub correlation_id: Option<String>,
     /// Causation ID (ID of the event that caused this one)
     pub causation_id: Option<EventId>,
+    /// Hex-encoded HMAC-SHA256 by the actor's key, if the event is signed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
 }
 
 impl Event {
@@ -77,6 +82,7 @@ impl Event {
             actor_id: None,
             correlation_id: None,
             causation_id: None,
+            signature: None,
         }
     }
 
@@ -97,6 +103,54 @@ impl Event {
         self.causation_id = Some(causation_id);
         self
     }
+
+    /// Sign the event with the actor's key
+    ///
+    /// Set the actor first: the signature covers everything except the
+    /// sequence number, which the store assigns on append.
+    pub fn signed(mut self, key: &[u8]) -> Self {
+        let mac = self.mac(key).finalize().into_bytes();
+        self.signature = Some(mac.iter().map(|b| format!("{:02x}", b)).collect());
+        self
+    }
+
+    /// Check the signature against `key`
+    ///
+    /// Returns false for unsigned events.
+    pub fn verify(&self, key: &[u8]) -> bool {
+        let Some(signature) = self.signature.as_deref().and_then(decode_hex) else {
+            return false;
+        };
+        self.mac(key).verify_slice(&signature).is_ok()
+    }
+
+    fn mac(&self, key: &[u8]) -> Hmac<Sha256> {
+        let signed_fields = serde_json::to_vec(&(
+            &self.id,
+            &self.timestamp,
+            &self.entity_id,
+            &self.entity_type,
+            &self.payload,
+            &self.actor_id,
+            &self.correlation_id,
+            &self.causation_id,
+        ))
+        .expect("event fields serialize to JSON");
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+        mac.update(&signed_fields);
+        mac
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
 }
 
 /// Type of entity an event affects
@@ -189,6 +243,11 @@ pub enum EventPayload {
         title: String,
         thread_id: Option<String>,
     },
+    /// A duplicate report was merged into an open escalation
+    EscalationRecurred {
+        reported_by: String,
+        occurrence_count: u32,
+    },
     EscalationAcknowledged {
         acknowledger_id: String,
     },
@@ -267,6 +326,13 @@ impl EventPayload {
             } => {
                 format!("Escalation ({:?}): {}", category, title)
             }
+            EventPayload::EscalationRecurred {
+                reported_by,
+                occurrence_count,
+            } => format!(
+                "Escalation reported again by {} ({} occurrences)",
+                reported_by, occurrence_count
+            ),
             EventPayload::EscalationAcknowledged { acknowledger_id } => {
                 format!("Escalation acknowledged by {}", acknowledger_id)
             }
@@ -322,6 +388,52 @@ mod tests {
         assert_eq!(event.actor_id, Some("agent-1".to_string()));
     }
 
+    #[test]
+    fn test_signed_event_verifies() {
+        let event = Event::new(
+            "thread-123".to_string(),
+            EntityType::Thread,
+            EventPayload::ThreadClaimed {
+                agent_id: "agent-1".to_string(),
+            },
+        )
+        .with_actor("agent-1".to_string())
+        .signed(b"agent-1-key");
+
+        assert!(event.verify(b"agent-1-key"));
+        assert!(!event.verify(b"agent-2-key"));
+
+        // Signature survives a serialization round trip
+        let json = serde_json::to_string(&event).unwrap();
+        let restored: Event = serde_json::from_str(&json).unwrap();
+        assert!(restored.verify(b"agent-1-key"));
+    }
+
+    #[test]
+    fn test_tampered_event_rejected() {
+        let mut event = Event::new(
+            "thread-123".to_string(),
+            EntityType::Thread,
+            EventPayload::ThreadClaimed {
+                agent_id: "agent-1".to_string(),
+            },
+        )
+        .with_actor("agent-1".to_string())
+        .signed(b"agent-1-key");
+
+        event.payload = EventPayload::ThreadClaimed {
+            agent_id: "agent-2".to_string(),
+        };
+        assert!(!event.verify(b"agent-1-key"));
+
+        let unsigned = Event::new(
+            "thread-123".to_string(),
+            EntityType::Thread,
+            EventPayload::SystemResumed,
+        );
+        assert!(!unsigned.verify(b"agent-1-key"));
+    }
+
     #[test]
     fn test_payload_description() {
         let payload = EventPayload::ThreadStateChanged {
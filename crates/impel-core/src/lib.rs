@@ -75,7 +75,8 @@ pub use thread::{Temperature, TemperatureCoefficients, Thread, ThreadId, ThreadS
 
 pub use persona::{
     builtin_personas, Persona, PersonaBehavior, PersonaDomain, PersonaId, PersonaModelConfig,
-    PersonaRegistry, ToolAccess, ToolPolicy, ToolPolicySet, WorkingStyle,
+    PersonaRegistry, PromptContext, ToolAccess, ToolDecision, ToolPolicy, ToolPolicySet,
+    WorkingStyle,
 };
 
 pub use schemas::register_impel_schemas;
@@ -83,8 +83,8 @@ impl Repository {
         self.conn.execute(
             r#"
             INSERT OR REPLACE INTO threads
-            (id, state, title, description, temperature, claimed_by, parent_id, created_at, updated_at, version, metadata)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            (id, state, title, description, temperature, claimed_by, parent_id, created_at, updated_at, version, metadata, stuck_escalation_id)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
             "#,
             rusqlite::params![
                 thread.id.to_string(),
@@ -98,6 +98,7 @@ impl Repository {
                 thread.updated_at.to_rfc3339(),
                 thread.version,
                 metadata_json,
+                thread.stuck_escalation_id,
             ],
         )?;
 
@@ -107,7 +108,7 @@ impl Repository {
     /// Get a thread by ID
     pub fn get_thread(&self, id: &str) -> Result<Option<Thread>> {
         let result = self.conn.query_row(
-            "SELECT id, state, title, description, temperature, claimed_by, parent_id, created_at, updated_at, version, metadata FROM threads WHERE id = ?1",
+            "SELECT id, state, title, description, temperature, claimed_by, parent_id, created_at, updated_at, version, metadata, stuck_escalation_id FROM threads WHERE id = ?1",
             [id],
             Self::row_to_thread,
         );
@@ -122,7 +123,7 @@ impl Repository {
     /// Get all threads
     pub fn get_all_threads(&self) -> Result<Vec<Thread>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, state, title, description, temperature, claimed_by, parent_id, created_at, updated_at, version, metadata FROM threads ORDER BY temperature DESC",
+            "SELECT id, state, title, description, temperature, claimed_by, parent_id, created_at, updated_at, version, metadata, stuck_escalation_id FROM threads ORDER BY temperature DESC",
         )?;
 
         let threads = stmt
@@ -135,7 +136,7 @@ impl Repository {
     /// Get threads by state
     pub fn get_threads_by_state(&self, state: &str) -> Result<Vec<Thread>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, state, title, description, temperature, claimed_by, parent_id, created_at, updated_at, version, metadata FROM threads WHERE state = ?1 ORDER BY temperature DESC",
+            "SELECT id, state, title, description, temperature, claimed_by, parent_id, created_at, updated_at, version, metadata, stuck_escalation_id FROM threads WHERE state = ?1 ORDER BY temperature DESC",
         )?;
 
         let threads = stmt
@@ -160,6 +161,7 @@ impl Repository {
         let created_at_str: String = row.get(7)?;
         let updated_at_str: String = row.get(8)?;
         let version: u64 = row.get(9)?;
+        let stuck_escalation_id: Option<String> = row.get(11)?;
 
         let state = match state_str.as_str() {
             "EMBRYO" => ThreadState::Embryo,
@@ -180,6 +182,10 @@ impl Repository {
             ..Default::default()
         };
 
+        let updated_at = DateTime::parse_from_rfc3339(&updated_at_str)
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
         Ok(Thread {
             id: ThreadId {
                 value: Uuid::parse_str(&id_str).unwrap(),
@@ -192,10 +198,11 @@ impl Repository {
             created_at: DateTime::parse_from_rfc3339(&created_at_str)
                 .unwrap()
                 .with_timezone(&chrono::Utc),
-            updated_at: DateTime::parse_from_rfc3339(&updated_at_str)
-                .unwrap()
-                .with_timezone(&chrono::Utc),
+            updated_at,
             version,
+            // Best available estimate of when a persisted thread was blocked
+            blocked_since: (state == ThreadState::Blocked).then_some(updated_at),
+            stuck_escalation_id,
         })
     }
 
@@ -285,6 +292,7 @@ impl Repository {
             "IDLE" => AgentStatus::Idle,
             "WORKING" => AgentStatus::Working,
             "PAUSED" => AgentStatus::Paused,
+            "OFFLINE" => AgentStatus::Offline,
             "TERMINATED" => AgentStatus::Terminated,
             _ => AgentStatus::Idle,
         };
@@ -307,6 +315,8 @@ impl Repository {
             last_active_at: DateTime::parse_from_rfc3339(&last_active_at_str)
                 .unwrap()
                 .with_timezone(&chrono::Utc),
+            // Heartbeats aren't persisted; give loaded agents a fresh grace period
+            last_heartbeat: chrono::Utc::now(),
             threads_completed,
             metadata,
         })
@@ -317,13 +327,15 @@ impl Repository {
     /// Save an escalation to the database
     pub fn save_escalation(&self, escalation: &Escalation) -> Result<()> {
         let options_json = serde_json::to_string(&escalation.options)?;
+        let context_json = serde_json::to_string(&escalation.additional_context)?;
 
         self.conn.execute(
             r#"
             INSERT OR REPLACE INTO escalations
             (id, category, priority, status, title, description, thread_id, created_by, created_at,
-             acknowledged_at, acknowledged_by, resolved_at, resolved_by, resolution, options, selected_option)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
+             acknowledged_at, acknowledged_by, resolved_at, resolved_by, resolution, options, selected_option,
+             occurrence_count, additional_context)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)
             "#,
             rusqlite::params![
                 escalation.id,
@@ -342,6 +354,8 @@ impl Repository {
                 escalation.resolution,
                 options_json,
                 escalation.selected_option.map(|i| i as i32),
+                escalation.occurrence_count,
+                context_json,
             ],
         )?;
 
@@ -353,7 +367,8 @@ impl Repository {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT id, category, priority, status, title, description, thread_id, created_by, created_at,
-                   acknowledged_at, acknowledged_by, resolved_at, resolved_by, resolution, options, selected_option
+                   acknowledged_at, acknowledged_by, resolved_at, resolved_by, resolution, options, selected_option,
+                   occurrence_count, additional_context
             FROM escalations
             WHERE status IN ('PENDING', 'ACKNOWLEDGED')
             ORDER BY priority DESC, created_at ASC
@@ -372,7 +387,8 @@ impl Repository {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT id, category, priority, status, title, description, thread_id, created_by, created_at,
-                   acknowledged_at, acknowledged_by, resolved_at, resolved_by, resolution, options, selected_option
+                   acknowledged_at, acknowledged_by, resolved_at, resolved_by, resolution, options, selected_option,
+                   occurrence_count, additional_context
             FROM escalations
             ORDER BY priority DESC, created_at ASC
             "#,
@@ -409,6 +425,8 @@ impl Repository {
         let resolution: Option<String> = row.get(13)?;
         let options_json: String = row.get(14)?;
         let selected_option: Option<i32> = row.get(15)?;
+        let occurrence_count: u32 = row.get(16)?;
+        let context_json: Option<String> = row.get(17)?;
 
         let category = match category_str.as_str() {
             "Decision" => EscalationCategory::Decision,
@@ -438,6 +456,9 @@ impl Repository {
 
         let options: Vec<EscalationOption> =
             serde_json::from_str(&options_json).unwrap_or_default();
+        let additional_context = context_json
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
 
         Ok(Escalation {
             id,
@@ -468,6 +489,8 @@ impl Repository {
             resolution,
             options,
             selected_option: selected_option.map(|i| i as usize),
+            occurrence_count,
+            additional_context,
         })
     }
 
@@ -553,6 +576,23 @@ mod tests {
         assert_eq!(all.len(), 1);
     }
 
+    #[test]
+    fn test_stuck_escalation_survives_reload() {
+        let repo = Repository::in_memory().unwrap();
+
+        let mut thread = Thread::new(ThreadMetadata {
+            title: "Stuck Thread".to_string(),
+            ..Default::default()
+        });
+        thread.activate().unwrap();
+        thread.block().unwrap();
+        thread.stuck_escalation_id = Some("esc-1".to_string());
+        repo.save_thread(&thread).unwrap();
+
+        let loaded = repo.get_thread(&thread.id.to_string()).unwrap().unwrap();
+        assert_eq!(loaded.stuck_escalation_id.as_deref(), Some("esc-1"));
+    }
+
     #[test]
     fn test_agent_crud() {
         use crate::agent::{Agent, AgentType};
@@ -585,4 +625,37 @@ mod tests {
         let missing = repo.get_system_state("nonexistent").unwrap();
         assert!(missing.is_none());
     }
+
+    #[test]
+    fn test_merged_escalation_roundtrip() {
+        use crate::escalation::{Escalation, EscalationCategory};
+
+        let repo = Repository::in_memory().unwrap();
+        let report = |agent: &str, details: &str| {
+            Escalation::new(
+                EscalationCategory::Stuck,
+                "Dataset unavailable".to_string(),
+                details.to_string(),
+                agent.to_string(),
+            )
+        };
+        let mut escalation = report("research-1", "Mirror is down");
+        escalation.record_occurrence(report("research-2", "Archive times out"));
+        escalation.record_occurrence(report("research-3", "Still down"));
+        repo.save_escalation(&escalation).unwrap();
+
+        let loaded = repo.get_open_escalations().unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].occurrence_count, 3);
+        let reporters: Vec<&str> = loaded[0]
+            .additional_context
+            .iter()
+            .map(|o| o.reported_by.as_str())
+            .collect();
+        assert_eq!(reporters, vec!["research-2", "research-3"]);
+        assert_eq!(
+            loaded[0].additional_context[0].description,
+            "Archive times out"
+        );
+    }
 }
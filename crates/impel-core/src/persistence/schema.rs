@@ -1,7 +1,7 @@
 //! SQLite schema for impel state storage
 
 /// Schema version for migrations
-pub const SCHEMA_VERSION: u32 = 1;
+pub const SCHEMA_VERSION: u32 = 4;
 
 /// SQLite schema definition
 pub struct Schema;
@@ -26,7 +26,8 @@ CREATE TABLE IF NOT EXISTS events (
     payload TEXT NOT NULL,
     actor_id TEXT,
     correlation_id TEXT,
-    causation_id TEXT
+    causation_id TEXT,
+    signature TEXT
 );
 
 CREATE INDEX IF NOT EXISTS idx_events_sequence ON events(sequence);
@@ -47,7 +48,8 @@ CREATE TABLE IF NOT EXISTS threads (
     created_at TEXT NOT NULL,
     updated_at TEXT NOT NULL,
     version INTEGER NOT NULL DEFAULT 0,
-    metadata TEXT
+    metadata TEXT,
+    stuck_escalation_id TEXT
 );
 
 CREATE INDEX IF NOT EXISTS idx_threads_state ON threads(state);
@@ -114,6 +116,8 @@ CREATE TABLE IF NOT EXISTS escalations (
     resolution TEXT,
     options TEXT,
     selected_option INTEGER,
+    occurrence_count INTEGER NOT NULL DEFAULT 1,
+    additional_context TEXT,
     FOREIGN KEY (thread_id) REFERENCES threads(id)
 );
 
@@ -160,7 +164,12 @@ CREATE TABLE IF NOT EXISTS system_state (
     pub fn migration(from_version: u32, to_version: u32) -> Option<&'static str> {
         match (from_version, to_version) {
             // Add migrations here as the schema evolves
-            // (0, 1) => Some("ALTER TABLE ..."),
+            (1, 2) => Some("ALTER TABLE events ADD COLUMN signature TEXT;"),
+            (2, 3) => Some(
+                "ALTER TABLE escalations ADD COLUMN occurrence_count INTEGER NOT NULL DEFAULT 1;
+                 ALTER TABLE escalations ADD COLUMN additional_context TEXT;",
+            ),
+            (3, 4) => Some("ALTER TABLE threads ADD COLUMN stuck_escalation_id TEXT;"),
             _ => None,
         }
     }
@@ -5,7 +5,7 @@
 //! - Role descriptions and behavioral traits
 //! - Model configuration (provider, temperature, token limits)
 //! - Tool access policies (which tools, read/write permissions)
-//! - Domain-specific prompting
+//! - Domain-specific prompting, with thread context filled into the system prompt
 //!
 //! Personas can be:
 //! - Builtin (compiled into impel-core)
@@ -16,13 +16,15 @@
 
 mod builtin;
 mod persona;
+mod prompt;
 mod registry;
 mod toml_loader;
 
 pub use builtin::builtin_personas;
 pub use persona::{
-    Persona, PersonaBehavior, PersonaDomain, PersonaId, PersonaModelConfig, ToolAccess, ToolPolicy,
-    ToolPolicySet, WorkingStyle,
+    ModelOverride, ModelOverrideError, Persona, PersonaBehavior, PersonaDomain, PersonaId,
+    PersonaModelConfig, ToolAccess, ToolDecision, ToolPolicy, ToolPolicySet, WorkingStyle,
 };
+pub use prompt::{unknown_placeholders, PromptContext, PROMPT_PLACEHOLDERS};
 pub use registry::PersonaRegistry;
 pub use toml_loader::{load_persona_from_toml, PersonaLoadError};
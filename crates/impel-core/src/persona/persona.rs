@@ -13,6 +13,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::agent::AgentType;
 
+use super::prompt::{unknown_placeholders, PromptContext};
+
 /// Unique identifier for a persona
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PersonaId(pub String);
@@ -82,6 +84,10 @@ pub struct Persona {
     /// Whether this persona is builtin (vs user-defined or project-specific)
     pub builtin: bool,
 
+    /// Base persona this one inherits unset fields from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<PersonaId>,
+
     /// Source path if loaded from file (None for builtin)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_path: Option<String>,
@@ -106,6 +112,7 @@ impl Persona {
             model: PersonaModelConfig::default(),
             tools: ToolPolicySet::default(),
             builtin: false,
+            extends: None,
             source_path: None,
         }
     }
@@ -155,6 +162,24 @@ impl Persona {
     pub fn can_write_with(&self, tool: &str) -> bool {
         self.tools.can_write(tool)
     }
+
+    /// The system prompt with `{{placeholder}}`s filled from thread context
+    ///
+    /// Placeholders the context doesn't know are left as written; see
+    /// [`unknown_prompt_placeholders`](Self::unknown_prompt_placeholders).
+    pub fn render_system_prompt(&self, context: &PromptContext) -> String {
+        context.render(&self.system_prompt)
+    }
+
+    /// Placeholders in the system prompt that rendering can't fill
+    pub fn unknown_prompt_placeholders(&self) -> Vec<String> {
+        unknown_placeholders(&self.system_prompt)
+    }
+
+    /// Dry-run a tool call: would `access` to `tool` be allowed, and why
+    pub fn check_tool(&self, tool: &str, access: ToolAccess) -> ToolDecision {
+        self.tools.check(tool, access)
+    }
 }
 
 /// Behavioral traits that shape how a persona approaches tasks
@@ -293,6 +318,125 @@ impl PersonaModelConfig {
         self.max_tokens = Some(tokens);
         self
     }
+
+    /// Check whether `model` is served by `provider`
+    ///
+    /// Hosted providers are checked by model family; any non-empty model name
+    /// is accepted for local providers.
+    pub fn is_supported_model(provider: &str, model: &str) -> bool {
+        match provider {
+            "anthropic" => model.starts_with("claude-"),
+            "openai" => ["gpt-", "o1", "o3", "o4"]
+                .iter()
+                .any(|family| model.starts_with(family)),
+            "ollama" => !model.trim().is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Highest sampling temperature `provider` accepts, if known
+    ///
+    /// Anthropic takes 0.0..=1.0; OpenAI and Ollama take 0.0..=2.0. Other
+    /// providers are left for the provider itself to check.
+    pub fn max_temperature(provider: &str) -> Option<f64> {
+        match provider {
+            "anthropic" => Some(1.0),
+            "openai" | "ollama" => Some(2.0),
+            _ => None,
+        }
+    }
+
+    /// The configuration with `overrides` applied on top
+    ///
+    /// `self` is left unchanged. Fails if an overridden provider or model is
+    /// not supported or a sampling parameter is out of range. The persona's
+    /// own provider and model are not rechecked, so sampling-only overrides
+    /// work for any provider.
+    pub fn with_overrides(
+        &self,
+        overrides: &ModelOverride,
+    ) -> Result<PersonaModelConfig, ModelOverrideError> {
+        let mut config = self.clone();
+        if let Some(provider) = &overrides.provider {
+            config.provider = provider.clone();
+        }
+        if let Some(model) = &overrides.model {
+            config.model = model.clone();
+        }
+        if let Some(temperature) = overrides.temperature {
+            config.temperature = temperature;
+        }
+        if overrides.max_tokens.is_some() {
+            config.max_tokens = overrides.max_tokens;
+        }
+        if overrides.top_p.is_some() {
+            config.top_p = overrides.top_p;
+        }
+
+        let switches_model = overrides.provider.is_some() || overrides.model.is_some();
+        if switches_model && !Self::is_supported_model(&config.provider, &config.model) {
+            return Err(ModelOverrideError::UnsupportedModel {
+                provider: config.provider,
+                model: config.model,
+            });
+        }
+        if let Some(max) = Self::max_temperature(&config.provider) {
+            if !(0.0..=max).contains(&config.temperature) {
+                return Err(ModelOverrideError::InvalidTemperature {
+                    provider: config.provider,
+                    temperature: config.temperature,
+                    max,
+                });
+            }
+        }
+        if let Some(top_p) = config.top_p {
+            if !(top_p > 0.0 && top_p <= 1.0) {
+                return Err(ModelOverrideError::InvalidTopP(top_p));
+            }
+        }
+        if config.max_tokens == Some(0) {
+            return Err(ModelOverrideError::InvalidMaxTokens);
+        }
+        Ok(config)
+    }
+}
+
+/// Per-request changes to a persona's model configuration
+///
+/// Unset fields keep the persona's value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ModelOverride {
+    #[serde(default)]
+    pub provider: Option<String>,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub temperature: Option<f64>,
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    #[serde(default)]
+    pub top_p: Option<f64>,
+}
+
+/// Errors from applying a [`ModelOverride`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ModelOverrideError {
+    #[error("Model {model} is not supported by provider {provider}")]
+    UnsupportedModel { provider: String, model: String },
+
+    #[error("Temperature {temperature} is outside 0.0..={max:.1} for provider {provider}")]
+    InvalidTemperature {
+        provider: String,
+        temperature: f64,
+        max: f64,
+    },
+
+    #[error("top_p {0} is outside (0.0, 1.0]")]
+    InvalidTopP(f64),
+
+    #[error("max_tokens must be positive")]
+    InvalidMaxTokens,
 }
 
 /// Access level for a tool
@@ -322,6 +466,29 @@ impl ToolAccess {
     pub fn can_execute(&self) -> bool {
         matches!(self, ToolAccess::Full)
     }
+
+    /// Whether this level includes everything `requested` needs
+    pub fn permits(&self, requested: ToolAccess) -> bool {
+        self.rank() >= requested.rank()
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            ToolAccess::None => 0,
+            ToolAccess::Read => 1,
+            ToolAccess::ReadWrite => 2,
+            ToolAccess::Full => 3,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ToolAccess::None => "none",
+            ToolAccess::Read => "read",
+            ToolAccess::ReadWrite => "read_write",
+            ToolAccess::Full => "full",
+        }
+    }
 }
 
 /// Policy for a specific tool
@@ -357,6 +524,58 @@ impl ToolPolicy {
         self.scope = scope;
         self
     }
+
+    /// Whether this policy covers `tool`
+    ///
+    /// A trailing `*` makes the policy a prefix pattern: `fs/*` covers
+    /// `fs/read` and `fs/write`, and `*` covers every tool.
+    pub fn matches(&self, tool: &str) -> bool {
+        match self.tool.strip_suffix('*') {
+            Some(prefix) => tool.starts_with(prefix),
+            None => self.tool == tool,
+        }
+    }
+
+    fn is_pattern(&self) -> bool {
+        self.tool.ends_with('*')
+    }
+}
+
+/// Outcome of [`ToolPolicySet::check`]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ToolDecision {
+    Allow {
+        /// Policy that granted access (None when the default applied)
+        policy: Option<ToolPolicy>,
+        reason: String,
+    },
+    Deny {
+        /// Policy that refused access (None when the default applied)
+        policy: Option<ToolPolicy>,
+        reason: String,
+    },
+}
+
+impl ToolDecision {
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, ToolDecision::Allow { .. })
+    }
+
+    /// The policy that decided, if any
+    pub fn policy(&self) -> Option<&ToolPolicy> {
+        match self {
+            ToolDecision::Allow { policy, .. } | ToolDecision::Deny { policy, .. } => {
+                policy.as_ref()
+            }
+        }
+    }
+
+    pub fn reason(&self) -> &str {
+        match self {
+            ToolDecision::Allow { reason, .. } | ToolDecision::Deny { reason, .. } => reason,
+        }
+    }
 }
 
 /// Collection of tool policies for a persona
@@ -390,8 +609,52 @@ impl ToolPolicySet {
     }
 
     /// Get policy for a specific tool
+    ///
+    /// An exact policy wins over patterns; among patterns the longest one
+    /// (most specific) wins.
     pub fn get_policy(&self, tool: &str) -> Option<&ToolPolicy> {
-        self.policies.iter().find(|p| p.tool == tool)
+        self.policies
+            .iter()
+            .find(|p| !p.is_pattern() && p.tool == tool)
+            .or_else(|| {
+                self.policies
+                    .iter()
+                    .filter(|p| p.is_pattern() && p.matches(tool))
+                    .max_by_key(|p| p.tool.len())
+            })
+    }
+
+    /// Decide whether `access` to `tool` is allowed, without performing it
+    ///
+    /// The matching policy decides; tools without one fall back to the
+    /// default access level.
+    pub fn check(&self, tool: &str, access: ToolAccess) -> ToolDecision {
+        let (granted, policy, source) = match self.get_policy(tool) {
+            Some(policy) => (
+                policy.access,
+                Some(policy.clone()),
+                format!("policy '{}'", policy.tool),
+            ),
+            None => (self.default_access, None, "default access".to_string()),
+        };
+
+        if granted.permits(access) {
+            ToolDecision::Allow {
+                policy,
+                reason: format!("{} grants {} access to {}", source, granted.name(), tool),
+            }
+        } else {
+            ToolDecision::Deny {
+                policy,
+                reason: format!(
+                    "{} grants {} access to {}, but {} was requested",
+                    source,
+                    granted.name(),
+                    tool,
+                    access.name()
+                ),
+            }
+        }
     }
 
     /// Check if tool can be accessed
@@ -447,6 +710,55 @@ mod tests {
         assert!(!tools.can_access("bash"));
     }
 
+    #[test]
+    fn test_check_tool_read_only() {
+        let persona = Persona::new("reader", "Reader", AgentType::Research, "Reads only")
+            .with_tools(
+                ToolPolicySet::new()
+                    .with_policy(ToolPolicy::new("imbib", ToolAccess::Read))
+                    .with_default(ToolAccess::None),
+            );
+
+        let read = persona.check_tool("imbib", ToolAccess::Read);
+        assert!(read.is_allowed());
+        assert_eq!(read.policy().unwrap().tool, "imbib");
+
+        let write = persona.check_tool("imbib", ToolAccess::ReadWrite);
+        assert!(!write.is_allowed());
+        assert_eq!(write.policy().unwrap().tool, "imbib");
+        assert!(write.reason().contains("read_write was requested"));
+
+        let unlisted = persona.check_tool("bash", ToolAccess::Read);
+        assert!(!unlisted.is_allowed());
+        assert!(unlisted.policy().is_none());
+        assert!(unlisted.reason().starts_with("default access"));
+    }
+
+    #[test]
+    fn test_check_tool_wildcard() {
+        let tools = ToolPolicySet::new()
+            .with_policy(ToolPolicy::new("fs/*", ToolAccess::Read))
+            .with_policy(ToolPolicy::new("fs/tmp/*", ToolAccess::Full))
+            .with_policy(ToolPolicy::new("fs/secrets", ToolAccess::None))
+            .with_default(ToolAccess::Read);
+
+        let decision = tools.check("fs/read", ToolAccess::Read);
+        assert!(decision.is_allowed());
+        assert_eq!(decision.policy().unwrap().tool, "fs/*");
+        assert!(!tools.check("fs/write", ToolAccess::ReadWrite).is_allowed());
+
+        // More specific patterns and exact names take precedence
+        let tmp = tools.check("fs/tmp/scratch", ToolAccess::Full);
+        assert!(tmp.is_allowed());
+        assert_eq!(tmp.policy().unwrap().tool, "fs/tmp/*");
+        assert!(!tools.check("fs/secrets", ToolAccess::Read).is_allowed());
+
+        // "fs/*" doesn't cover "fsck"; the default applies
+        assert!(tools.check("fsck", ToolAccess::Read).policy().is_none());
+        assert!(tools.can_access("fs/anything"));
+        assert!(!tools.can_write("fs/anything"));
+    }
+
     #[test]
     fn test_model_config() {
         let config = PersonaModelConfig::anthropic("claude-sonnet-4-20250514")
@@ -458,4 +770,85 @@ mod tests {
         assert!((config.temperature - 0.3).abs() < f64::EPSILON);
         assert_eq!(config.max_tokens, Some(4096));
     }
+
+    #[test]
+    fn test_model_overrides() {
+        let base = PersonaModelConfig::anthropic("claude-sonnet-4-20250514").with_temperature(0.3);
+
+        let effective = base
+            .with_overrides(&ModelOverride {
+                temperature: Some(0.9),
+                max_tokens: Some(1024),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!((effective.temperature - 0.9).abs() < f64::EPSILON);
+        assert_eq!(effective.max_tokens, Some(1024));
+        assert_eq!(effective.model, base.model);
+        assert!((base.temperature - 0.3).abs() < f64::EPSILON);
+
+        let unsupported = base.with_overrides(&ModelOverride {
+            model: Some("gpt-4o".to_string()),
+            ..Default::default()
+        });
+        assert!(matches!(
+            unsupported,
+            Err(ModelOverrideError::UnsupportedModel { .. })
+        ));
+
+        let switched = base
+            .with_overrides(&ModelOverride {
+                provider: Some("openai".to_string()),
+                model: Some("gpt-4o".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(switched.provider, "openai");
+
+        assert_eq!(
+            base.with_overrides(&ModelOverride {
+                temperature: Some(1.5),
+                ..Default::default()
+            })
+            .unwrap_err(),
+            ModelOverrideError::InvalidTemperature {
+                provider: "anthropic".to_string(),
+                temperature: 1.5,
+                max: 1.0,
+            }
+        );
+
+        let hot = switched
+            .with_overrides(&ModelOverride {
+                temperature: Some(1.5),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!((hot.temperature - 1.5).abs() < f64::EPSILON);
+        assert!(matches!(
+            switched.with_overrides(&ModelOverride {
+                temperature: Some(2.5),
+                ..Default::default()
+            }),
+            Err(ModelOverrideError::InvalidTemperature { .. })
+        ));
+    }
+
+    #[test]
+    fn test_temperature_override_for_other_provider() {
+        let base = PersonaModelConfig {
+            provider: "mistral".to_string(),
+            model: "mistral-large-latest".to_string(),
+            ..Default::default()
+        };
+
+        let effective = base
+            .with_overrides(&ModelOverride {
+                temperature: Some(1.2),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!((effective.temperature - 1.2).abs() < f64::EPSILON);
+        assert_eq!(effective.provider, "mistral");
+    }
 }
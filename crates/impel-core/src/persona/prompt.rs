@@ -0,0 +1,156 @@
+//! System prompt templating
+//!
+//! A persona's system prompt may contain `{{placeholder}}` markers that are
+//! filled from the thread an agent is working on:
+//!
+//! - `{{thread_title}}` — title of the current thread
+//! - `{{thread_description}}` — its description
+//! - `{{deliverable}}` — name of the deliverable the thread feeds
+//! - `{{recent_events}}` — recent thread events, one `- ` line each
+//!
+//! Unknown placeholders are left in the text as written.
+
+use crate::event::Event;
+use crate::project::Deliverable;
+use crate::thread::Thread;
+
+/// Placeholder names understood by [`PromptContext`]
+pub const PROMPT_PLACEHOLDERS: &[&str] = &[
+    "thread_title",
+    "thread_description",
+    "deliverable",
+    "recent_events",
+];
+
+/// Runtime thread context for rendering a persona's system prompt
+#[derive(Debug, Clone, Default)]
+pub struct PromptContext {
+    pub thread_title: String,
+    pub thread_description: String,
+    pub deliverable: Option<String>,
+    pub recent_events: Vec<String>,
+}
+
+impl PromptContext {
+    /// Context for a thread, without deliverable or events
+    pub fn from_thread(thread: &Thread) -> Self {
+        Self {
+            thread_title: thread.metadata.title.clone(),
+            thread_description: thread.metadata.description.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Builder: set the deliverable
+    pub fn with_deliverable(mut self, deliverable: &Deliverable) -> Self {
+        self.deliverable = Some(deliverable.name.clone());
+        self
+    }
+
+    /// Builder: describe recent events, oldest first
+    pub fn with_events(mut self, events: &[Event]) -> Self {
+        self.recent_events = events.iter().map(|e| e.payload.description()).collect();
+        self
+    }
+
+    fn value(&self, placeholder: &str) -> Option<String> {
+        match placeholder {
+            "thread_title" => Some(self.thread_title.clone()),
+            "thread_description" => Some(self.thread_description.clone()),
+            "deliverable" => Some(self.deliverable.clone().unwrap_or_default()),
+            "recent_events" => Some(
+                self.recent_events
+                    .iter()
+                    .map(|e| format!("- {}", e))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Fill the placeholders in `template`
+    pub fn render(&self, template: &str) -> String {
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some((before, name, after)) = next_placeholder(rest) {
+            out.push_str(before);
+            match self.value(name.trim()) {
+                Some(value) => out.push_str(&value),
+                None => {
+                    out.push_str("{{");
+                    out.push_str(name);
+                    out.push_str("}}");
+                }
+            }
+            rest = after;
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// Placeholder names in `template` that [`PromptContext`] can't fill
+pub fn unknown_placeholders(template: &str) -> Vec<String> {
+    let mut unknown = Vec::new();
+    let mut rest = template;
+    while let Some((_, name, after)) = next_placeholder(rest) {
+        let name = name.trim();
+        if !PROMPT_PLACEHOLDERS.contains(&name) && !unknown.iter().any(|u| u == name) {
+            unknown.push(name.to_string());
+        }
+        rest = after;
+    }
+    unknown
+}
+
+/// Split off the first `{{name}}`: (text before, name, text after)
+fn next_placeholder(text: &str) -> Option<(&str, &str, &str)> {
+    let start = text.find("{{")?;
+    let len = text[start + 2..].find("}}")?;
+    let name = &text[start + 2..start + 2 + len];
+    Some((&text[..start], name, &text[start + 4 + len..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::AgentType;
+    use crate::persona::Persona;
+
+    #[test]
+    fn test_render_fills_placeholders() {
+        let persona = Persona::new("scout", "Scout", AgentType::Research, "Explorer")
+            .with_system_prompt(
+                "You are working on \"{{thread_title}}\" for {{ deliverable }}.\n\
+                 Keep {{house_style}} in mind.",
+            );
+        let context = PromptContext {
+            thread_title: "Dark matter halos".to_string(),
+            deliverable: Some("Review paper".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            persona.render_system_prompt(&context),
+            "You are working on \"Dark matter halos\" for Review paper.\n\
+             Keep {{house_style}} in mind."
+        );
+        assert_eq!(persona.unknown_prompt_placeholders(), vec!["house_style"]);
+    }
+
+    #[test]
+    fn test_render_recent_events() {
+        let context = PromptContext {
+            recent_events: vec![
+                "Thread created: A".to_string(),
+                "Thread claimed by b".to_string(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            context.render("Recent:\n{{recent_events}}\n{{unclosed"),
+            "Recent:\n- Thread created: A\n- Thread claimed by b\n{{unclosed"
+        );
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! The registry provides:
 //! - Loading and resolution of personas (project > user > builtin)
+//! - Inheritance between personas (`extends`)
 //! - Lookup by ID
 //! - Listing and filtering
 
@@ -9,7 +10,7 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use super::builtin::builtin_personas;
-use super::toml_loader::{load_persona_from_toml, PersonaLoadError};
+use super::toml_loader::{load_persona_overlay, PersonaLoadError, PersonaOverlay};
 use super::{Persona, PersonaId};
 use crate::agent::AgentType;
 
@@ -35,11 +36,23 @@ impl PersonaSource {
     }
 }
 
+/// A persona as registered by one source
+#[derive(Debug)]
+enum Definition {
+    /// Complete persona
+    Persona(Persona),
+    /// File definition that extends another, merged on resolution
+    Overlay(PersonaOverlay),
+}
+
 /// Registry for managing available personas
 #[derive(Debug, Default)]
 pub struct PersonaRegistry {
     personas: HashMap<PersonaId, Persona>,
     sources: HashMap<PersonaId, PersonaSource>,
+    /// Every definition by source priority and ID, including shadowed ones,
+    /// so that a persona can extend the one it overrides
+    definitions: HashMap<(u8, PersonaId), Definition>,
 }
 
 impl PersonaRegistry {
@@ -60,7 +73,9 @@ impl PersonaRegistry {
     /// Load personas from standard locations
     ///
     /// Loads in order: builtin, user (~/.impel/personas/), project (.impel/personas/)
-    /// Later sources override earlier ones for the same ID.
+    /// Later sources override earlier ones for the same ID. Inheritance is
+    /// resolved once everything is loaded, so a base is looked up with the
+    /// same precedence.
     pub fn load_standard(project_root: Option<&Path>) -> Result<Self, PersonaLoadError> {
         let mut registry = Self::with_builtins();
 
@@ -80,12 +95,16 @@ impl PersonaRegistry {
             }
         }
 
+        registry.resolve_inheritance()?;
         Ok(registry)
     }
 
     /// Load personas from a directory
     ///
     /// Expects structure: {dir}/{persona_name}/identity.toml
+    ///
+    /// Personas that `extends` another are not merged with their base until
+    /// [`resolve_inheritance`](Self::resolve_inheritance) is called.
     pub fn load_from_directory(
         &mut self,
         dir: &Path,
@@ -102,8 +121,8 @@ impl PersonaRegistry {
             if path.is_dir() {
                 let identity_path = path.join("identity.toml");
                 if identity_path.exists() {
-                    let persona = load_persona_from_toml(&identity_path)?;
-                    self.register(persona, source.clone());
+                    let overlay = load_persona_overlay(&identity_path)?;
+                    self.register_overlay(overlay, source.clone())?;
                 }
             }
         }
@@ -116,6 +135,44 @@ impl PersonaRegistry {
     /// If a persona with the same ID already exists, it will be replaced
     /// only if the new source has equal or higher priority.
     pub fn register(&mut self, persona: Persona, source: PersonaSource) {
+        self.definitions.insert(
+            (source.priority(), persona.id.clone()),
+            Definition::Persona(persona.clone()),
+        );
+        self.insert(persona, source);
+    }
+
+    /// Register a persona loaded from file
+    ///
+    /// A persona that extends another is held back until
+    /// [`resolve_inheritance`](Self::resolve_inheritance) merges it.
+    fn register_overlay(
+        &mut self,
+        overlay: PersonaOverlay,
+        source: PersonaSource,
+    ) -> Result<(), PersonaLoadError> {
+        let id = overlay.id();
+        if overlay.extends().is_none() {
+            self.register(overlay.apply(None)?, source);
+            return Ok(());
+        }
+
+        let priority = source.priority();
+        let shadowed = matches!(
+            self.sources.get(&id),
+            Some(existing) if priority < existing.priority()
+        );
+        if !shadowed {
+            self.personas.remove(&id);
+            self.sources.insert(id.clone(), source);
+        }
+        self.definitions
+            .insert((priority, id), Definition::Overlay(overlay));
+        Ok(())
+    }
+
+    /// Insert unless a higher-priority source already provides the ID
+    fn insert(&mut self, persona: Persona, source: PersonaSource) {
         let id = persona.id.clone();
 
         // Check if we should replace
@@ -129,6 +186,82 @@ impl PersonaRegistry {
         self.sources.insert(id, source);
     }
 
+    /// Merge every persona that `extends` another with its resolved base
+    ///
+    /// Bases are looked up among the currently registered personas, so a
+    /// project persona shadows a user or builtin one of the same ID. A persona
+    /// that extends its own ID builds on the lower-priority definition it
+    /// overrides. Fails on an unknown base or an inheritance cycle, leaving
+    /// the registry as it was.
+    pub fn resolve_inheritance(&mut self) -> Result<(), PersonaLoadError> {
+        let mut resolved = HashMap::new();
+        let mut merged = HashMap::new();
+        for (id, source) in &self.sources {
+            let key = (source.priority(), id.clone());
+            if let Some(Definition::Overlay(_)) = self.definitions.get(&key) {
+                let persona = self.resolve(&key, &mut Vec::new(), &mut resolved)?;
+                merged.insert(id.clone(), persona);
+            }
+        }
+        self.personas.extend(merged);
+        Ok(())
+    }
+
+    fn resolve(
+        &self,
+        key: &(u8, PersonaId),
+        chain: &mut Vec<(u8, PersonaId)>,
+        resolved: &mut HashMap<(u8, PersonaId), Persona>,
+    ) -> Result<Persona, PersonaLoadError> {
+        if let Some(persona) = resolved.get(key) {
+            return Ok(persona.clone());
+        }
+        let overlay = match &self.definitions[key] {
+            Definition::Persona(persona) => return Ok(persona.clone()),
+            Definition::Overlay(overlay) => overlay,
+        };
+
+        if chain.contains(key) {
+            let cycle: Vec<&str> = chain
+                .iter()
+                .skip_while(|link| *link != key)
+                .chain(std::iter::once(key))
+                .map(|(_, id)| id.as_str())
+                .collect();
+            return Err(PersonaLoadError::InheritanceCycle(cycle.join(" -> ")));
+        }
+
+        let (priority, id) = key;
+        let base_id = overlay.extends().unwrap_or_else(|| id.clone());
+        let below = if base_id == *id { *priority } else { u8::MAX };
+        let base_key = match self.definition_below(&base_id, below) {
+            Some(base_key) => base_key,
+            None => {
+                return Err(PersonaLoadError::UnknownBase {
+                    persona: id.to_string(),
+                    base: base_id.to_string(),
+                })
+            }
+        };
+
+        chain.push(key.clone());
+        let base = self.resolve(&base_key, chain, resolved)?;
+        chain.pop();
+
+        let persona = overlay.apply(Some(&base))?;
+        resolved.insert(key.clone(), persona.clone());
+        Ok(persona)
+    }
+
+    /// Key of the highest-priority definition of `id` below `priority`
+    fn definition_below(&self, id: &PersonaId, priority: u8) -> Option<(u8, PersonaId)> {
+        self.definitions
+            .keys()
+            .filter(|(p, other)| other == id && *p < priority)
+            .max_by_key(|(p, _)| *p)
+            .cloned()
+    }
+
     /// Get a persona by ID
     pub fn get(&self, id: &PersonaId) -> Option<&Persona> {
         self.personas.get(id)
@@ -211,6 +344,7 @@ impl PersonaRegistry {
     /// Remove a persona (mainly for testing)
     pub fn remove(&mut self, id: &PersonaId) -> Option<Persona> {
         self.sources.remove(id);
+        self.definitions.retain(|(_, other), _| other != id);
         self.personas.remove(id)
     }
 }
@@ -272,6 +406,188 @@ mod tests {
         );
     }
 
+    fn write_persona(dir: &Path, name: &str, toml: &str) {
+        let persona_dir = dir.join(name);
+        std::fs::create_dir_all(&persona_dir).unwrap();
+        std::fs::write(persona_dir.join("identity.toml"), toml).unwrap();
+    }
+
+    #[test]
+    fn test_extends_overrides_only_given_fields() {
+        let user = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        write_persona(
+            user.path(),
+            "base",
+            r#"
+[identity]
+id = "base"
+name = "Base"
+archetype = "research"
+role_description = "Shared team defaults"
+system_prompt = "Cite everything."
+
+[model]
+temperature = 0.7
+max_tokens = 4096
+
+[[tools.policies]]
+tool = "imbib"
+access = "read_write"
+
+[[tools.policies]]
+tool = "bash"
+access = "none"
+
+[tools]
+default_access = "read"
+"#,
+        );
+        write_persona(
+            project.path(),
+            "cool",
+            r#"
+[identity]
+id = "cool"
+extends = "base"
+
+[model]
+temperature = 0.2
+"#,
+        );
+
+        let mut registry = PersonaRegistry::with_builtins();
+        registry
+            .load_from_directory(user.path(), PersonaSource::User)
+            .unwrap();
+        registry
+            .load_from_directory(project.path(), PersonaSource::Project)
+            .unwrap();
+        registry.resolve_inheritance().unwrap();
+
+        let child = registry.get_by_str("cool").unwrap();
+        assert_eq!(child.extends, Some(PersonaId::new("base")));
+        assert_eq!(child.name, "Base");
+        assert_eq!(child.system_prompt, "Cite everything.");
+        assert!((child.model.temperature - 0.2).abs() < f64::EPSILON);
+        assert_eq!(child.model.max_tokens, Some(4096));
+        assert!(child.tools.can_write("imbib"));
+        assert!(!child.tools.can_access("bash"));
+        assert!(child.tools.can_access("imprint"));
+        assert_eq!(registry.source(&child.id), Some(&PersonaSource::Project));
+
+        // Builtin bases resolve too
+        write_persona(
+            project.path(),
+            "quiet-scout",
+            "[identity]\nid = \"quiet-scout\"\nextends = \"scout\"\n[behavior]\nverbosity = 0.1\n",
+        );
+        registry
+            .load_from_directory(project.path(), PersonaSource::Project)
+            .unwrap();
+        registry.resolve_inheritance().unwrap();
+        let scout = registry.get_by_str("scout").unwrap().clone();
+        let quiet = registry.get_by_str("quiet-scout").unwrap();
+        assert_eq!(quiet.archetype, scout.archetype);
+        assert_eq!(quiet.tools.policies.len(), scout.tools.policies.len());
+        assert!((quiet.behavior.verbosity - 0.1).abs() < f64::EPSILON);
+        assert!(!quiet.builtin);
+    }
+
+    #[test]
+    fn test_extends_cycle_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        for (id, base) in [("a", "b"), ("b", "c"), ("c", "a")] {
+            write_persona(
+                dir.path(),
+                id,
+                &format!("[identity]\nid = \"{id}\"\nextends = \"{base}\"\n"),
+            );
+        }
+
+        let mut registry = PersonaRegistry::new();
+        registry
+            .load_from_directory(dir.path(), PersonaSource::Project)
+            .unwrap();
+        let err = registry.resolve_inheritance().unwrap_err();
+        match err {
+            PersonaLoadError::InheritanceCycle(cycle) => {
+                assert!(["a -> b -> c -> a", "b -> c -> a -> b", "c -> a -> b -> c"]
+                    .contains(&cycle.as_str()));
+            }
+            other => panic!("expected cycle, got {other:?}"),
+        }
+
+        let mut registry = PersonaRegistry::new();
+        write_persona(
+            dir.path(),
+            "a",
+            "[identity]\nid = \"a\"\nextends = \"missing\"\n",
+        );
+        std::fs::remove_dir_all(dir.path().join("b")).unwrap();
+        std::fs::remove_dir_all(dir.path().join("c")).unwrap();
+        registry
+            .load_from_directory(dir.path(), PersonaSource::Project)
+            .unwrap();
+        assert!(matches!(
+            registry.resolve_inheritance(),
+            Err(PersonaLoadError::UnknownBase { .. })
+        ));
+    }
+
+    #[test]
+    fn test_extends_same_id_builds_on_lower_priority() {
+        let user = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        write_persona(
+            user.path(),
+            "scout",
+            "[identity]\nid = \"scout\"\nextends = \"scout\"\n[behavior]\nverbosity = 0.1\n",
+        );
+        write_persona(
+            project.path(),
+            "scout",
+            "[identity]\nid = \"scout\"\nextends = \"scout\"\n[model]\ntemperature = 0.3\n",
+        );
+
+        let builtin = PersonaRegistry::with_builtins()
+            .get_by_str("scout")
+            .unwrap()
+            .clone();
+        let mut registry = PersonaRegistry::with_builtins();
+        registry
+            .load_from_directory(user.path(), PersonaSource::User)
+            .unwrap();
+        registry.resolve_inheritance().unwrap();
+
+        let scout = registry.get_by_str("scout").unwrap();
+        assert_eq!(scout.name, builtin.name);
+        assert_eq!(scout.system_prompt, builtin.system_prompt);
+        assert!((scout.behavior.verbosity - 0.1).abs() < f64::EPSILON);
+        assert_eq!(registry.source(&scout.id), Some(&PersonaSource::User));
+
+        // A project override stacks on the user one, not on itself
+        registry
+            .load_from_directory(project.path(), PersonaSource::Project)
+            .unwrap();
+        registry.resolve_inheritance().unwrap();
+        let scout = registry.get_by_str("scout").unwrap();
+        assert!((scout.behavior.verbosity - 0.1).abs() < f64::EPSILON);
+        assert!((scout.model.temperature - 0.3).abs() < f64::EPSILON);
+        assert_eq!(scout.tools.policies.len(), builtin.tools.policies.len());
+        assert_eq!(registry.source(&scout.id), Some(&PersonaSource::Project));
+
+        // Without a lower-priority definition there is nothing to extend
+        let mut registry = PersonaRegistry::new();
+        registry
+            .load_from_directory(user.path(), PersonaSource::User)
+            .unwrap();
+        assert!(matches!(
+            registry.resolve_inheritance(),
+            Err(PersonaLoadError::UnknownBase { .. })
+        ));
+    }
+
     #[test]
     fn test_by_archetype() {
         let registry = PersonaRegistry::with_builtins();
@@ -42,6 +42,19 @@
 //! [tools]
 //! default_access = "read"
 //! ```
+//!
+//! A persona can build on another with `extends`, giving only the fields it
+//! changes. Tool policies are merged by tool name; other fields replace the
+//! base's value:
+//!
+//! ```toml
+//! [identity]
+//! id = "cautious-scout"
+//! extends = "scout"
+//!
+//! [model]
+//! temperature = 0.2
+//! ```
 
 use std::collections::HashMap;
 use std::path::Path;
@@ -75,97 +88,58 @@ pub enum PersonaLoadError {
 
     #[error("Invalid access level: {0}")]
     InvalidAccess(String),
+
+    #[error("Persona {persona} extends unknown persona {base}")]
+    UnknownBase { persona: String, base: String },
+
+    #[error("Persona inheritance cycle: {0}")]
+    InheritanceCycle(String),
 }
 
 /// TOML representation of a persona identity
+///
+/// `name`, `archetype`, and `role_description` are required unless the
+/// persona `extends` another one.
 #[derive(Debug, Deserialize)]
 struct TomlIdentity {
     id: String,
-    name: String,
-    archetype: String,
-    role_description: String,
-    #[serde(default)]
-    system_prompt: String,
+    extends: Option<String>,
+    name: Option<String>,
+    archetype: Option<String>,
+    role_description: Option<String>,
+    system_prompt: Option<String>,
 }
 
 /// TOML representation of behavior
 #[derive(Debug, Deserialize, Default)]
 struct TomlBehavior {
-    #[serde(default = "default_verbosity")]
-    verbosity: f64,
-    #[serde(default = "default_risk")]
-    risk_tolerance: f64,
-    #[serde(default = "default_citation")]
-    citation_density: f64,
-    #[serde(default = "default_escalation")]
-    escalation_tendency: f64,
-    #[serde(default)]
+    verbosity: Option<f64>,
+    risk_tolerance: Option<f64>,
+    citation_density: Option<f64>,
+    escalation_tendency: Option<f64>,
     working_style: Option<String>,
-    #[serde(default)]
-    notes: Vec<String>,
-}
-
-fn default_verbosity() -> f64 {
-    0.5
-}
-fn default_risk() -> f64 {
-    0.3
-}
-fn default_citation() -> f64 {
-    0.5
-}
-fn default_escalation() -> f64 {
-    0.5
+    notes: Option<Vec<String>>,
 }
 
 /// TOML representation of domain
 #[derive(Debug, Deserialize, Default)]
 struct TomlDomain {
-    #[serde(default)]
-    primary_domains: Vec<String>,
-    #[serde(default)]
-    methodologies: Vec<String>,
-    #[serde(default)]
-    data_sources: Vec<String>,
-    #[serde(default)]
-    terminology: HashMap<String, String>,
+    primary_domains: Option<Vec<String>>,
+    methodologies: Option<Vec<String>>,
+    data_sources: Option<Vec<String>>,
+    terminology: Option<HashMap<String, String>>,
 }
 
 /// TOML representation of model config
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Default)]
 struct TomlModel {
-    #[serde(default = "default_provider")]
-    provider: String,
-    #[serde(default = "default_model")]
-    model: String,
-    #[serde(default = "default_temperature")]
-    temperature: f64,
+    provider: Option<String>,
+    model: Option<String>,
+    temperature: Option<f64>,
     max_tokens: Option<u32>,
     top_p: Option<f64>,
 }
 
-fn default_provider() -> String {
-    "anthropic".to_string()
-}
-fn default_model() -> String {
-    "claude-sonnet-4-20250514".to_string()
-}
-fn default_temperature() -> f64 {
-    0.7
-}
-
-impl Default for TomlModel {
-    fn default() -> Self {
-        Self {
-            provider: default_provider(),
-            model: default_model(),
-            temperature: default_temperature(),
-            max_tokens: None,
-            top_p: None,
-        }
-    }
-}
-
 /// TOML representation of a tool policy
 #[derive(Debug, Deserialize)]
 struct TomlToolPolicy {
@@ -199,34 +173,97 @@ struct TomlPersona {
     tools: TomlTools,
 }
 
-/// Load a persona from a TOML file
-pub fn load_persona_from_toml(path: &Path) -> Result<Persona, PersonaLoadError> {
+/// A persona as written in its TOML file, before inheritance is resolved
+///
+/// Only the fields present in the file are set; everything else comes from
+/// the base persona (or the defaults, when there is no base).
+#[derive(Debug)]
+pub(super) struct PersonaOverlay {
+    toml: TomlPersona,
+    source_path: String,
+}
+
+impl PersonaOverlay {
+    pub(super) fn id(&self) -> PersonaId {
+        PersonaId::new(self.toml.identity.id.clone())
+    }
+
+    /// The persona this one extends, if any
+    pub(super) fn extends(&self) -> Option<PersonaId> {
+        self.toml.identity.extends.clone().map(PersonaId::new)
+    }
+
+    /// Build the persona by applying this file's fields on top of `base`
+    pub(super) fn apply(&self, base: Option<&Persona>) -> Result<Persona, PersonaLoadError> {
+        let identity = &self.toml.identity;
+        let mut persona = match (base, &identity.extends) {
+            (Some(base), _) => base.clone(),
+            (None, Some(extends)) => {
+                return Err(PersonaLoadError::UnknownBase {
+                    persona: identity.id.clone(),
+                    base: extends.clone(),
+                })
+            }
+            (None, None) => Persona::new(
+                identity.id.clone(),
+                required(&identity.name, "identity.name")?,
+                parse_archetype(&required(&identity.archetype, "identity.archetype")?)?,
+                required(&identity.role_description, "identity.role_description")?,
+            ),
+        };
+
+        persona.id = self.id();
+        persona.extends = self.extends();
+        persona.builtin = false;
+        persona.source_path = Some(self.source_path.clone());
+        if let Some(name) = &identity.name {
+            persona.name = name.clone();
+        }
+        if let Some(archetype) = &identity.archetype {
+            persona.archetype = parse_archetype(archetype)?;
+        }
+        if let Some(role_description) = &identity.role_description {
+            persona.role_description = role_description.clone();
+        }
+        if let Some(system_prompt) = &identity.system_prompt {
+            persona.system_prompt = system_prompt.clone();
+        }
+
+        apply_behavior(&mut persona.behavior, &self.toml.behavior)?;
+        apply_domain(&mut persona.domain, &self.toml.domain);
+        apply_model(&mut persona.model, &self.toml.model);
+        apply_tools(&mut persona.tools, &self.toml.tools)?;
+
+        Ok(persona)
+    }
+}
+
+/// Parse a persona TOML file without resolving inheritance
+pub(super) fn load_persona_overlay(path: &Path) -> Result<PersonaOverlay, PersonaLoadError> {
     let content = std::fs::read_to_string(path).map_err(|e| PersonaLoadError::Io(e.to_string()))?;
 
     let toml: TomlPersona =
         toml::from_str(&content).map_err(|e| PersonaLoadError::Parse(e.to_string()))?;
 
-    let archetype = parse_archetype(&toml.identity.archetype)?;
-    let behavior = parse_behavior(toml.behavior)?;
-    let domain = parse_domain(toml.domain);
-    let model = parse_model(toml.model);
-    let tools = parse_tools(toml.tools)?;
-
-    let persona = Persona {
-        id: PersonaId::new(toml.identity.id),
-        name: toml.identity.name,
-        archetype,
-        role_description: toml.identity.role_description,
-        system_prompt: toml.identity.system_prompt,
-        behavior,
-        domain,
-        model,
-        tools,
-        builtin: false,
-        source_path: Some(path.to_string_lossy().to_string()),
-    };
-
-    Ok(persona)
+    Ok(PersonaOverlay {
+        toml,
+        source_path: path.to_string_lossy().to_string(),
+    })
+}
+
+/// Load a persona from a TOML file
+///
+/// Fails with [`PersonaLoadError::UnknownBase`] for a persona that `extends`
+/// another; load those through [`PersonaRegistry`](super::PersonaRegistry),
+/// which resolves the base.
+pub fn load_persona_from_toml(path: &Path) -> Result<Persona, PersonaLoadError> {
+    load_persona_overlay(path)?.apply(None)
+}
+
+fn required(value: &Option<String>, field: &str) -> Result<String, PersonaLoadError> {
+    value
+        .clone()
+        .ok_or_else(|| PersonaLoadError::MissingField(field.to_string()))
 }
 
 fn parse_archetype(s: &str) -> Result<AgentType, PersonaLoadError> {
@@ -261,61 +298,87 @@ fn parse_access(s: &str) -> Result<ToolAccess, PersonaLoadError> {
     }
 }
 
-fn parse_behavior(toml: TomlBehavior) -> Result<PersonaBehavior, PersonaLoadError> {
-    let working_style = match toml.working_style {
-        Some(s) => parse_working_style(&s)?,
-        None => WorkingStyle::default(),
-    };
-
-    Ok(PersonaBehavior {
-        verbosity: toml.verbosity,
-        risk_tolerance: toml.risk_tolerance,
-        citation_density: toml.citation_density,
-        escalation_tendency: toml.escalation_tendency,
-        working_style,
-        notes: toml.notes,
-    })
+fn apply_behavior(
+    behavior: &mut PersonaBehavior,
+    toml: &TomlBehavior,
+) -> Result<(), PersonaLoadError> {
+    if let Some(verbosity) = toml.verbosity {
+        behavior.verbosity = verbosity;
+    }
+    if let Some(risk_tolerance) = toml.risk_tolerance {
+        behavior.risk_tolerance = risk_tolerance;
+    }
+    if let Some(citation_density) = toml.citation_density {
+        behavior.citation_density = citation_density;
+    }
+    if let Some(escalation_tendency) = toml.escalation_tendency {
+        behavior.escalation_tendency = escalation_tendency;
+    }
+    if let Some(style) = &toml.working_style {
+        behavior.working_style = parse_working_style(style)?;
+    }
+    if let Some(notes) = &toml.notes {
+        behavior.notes = notes.clone();
+    }
+    Ok(())
 }
 
-fn parse_domain(toml: TomlDomain) -> PersonaDomain {
-    PersonaDomain {
-        primary_domains: toml.primary_domains,
-        methodologies: toml.methodologies,
-        data_sources: toml.data_sources,
-        terminology: toml.terminology,
+fn apply_domain(domain: &mut PersonaDomain, toml: &TomlDomain) {
+    if let Some(primary_domains) = &toml.primary_domains {
+        domain.primary_domains = primary_domains.clone();
+    }
+    if let Some(methodologies) = &toml.methodologies {
+        domain.methodologies = methodologies.clone();
+    }
+    if let Some(data_sources) = &toml.data_sources {
+        domain.data_sources = data_sources.clone();
+    }
+    if let Some(terminology) = &toml.terminology {
+        domain.terminology = terminology.clone();
     }
 }
 
-fn parse_model(toml: TomlModel) -> PersonaModelConfig {
-    PersonaModelConfig {
-        provider: toml.provider,
-        model: toml.model,
-        temperature: toml.temperature,
-        max_tokens: toml.max_tokens,
-        top_p: toml.top_p,
+fn apply_model(model: &mut PersonaModelConfig, toml: &TomlModel) {
+    if let Some(provider) = &toml.provider {
+        model.provider = provider.clone();
+    }
+    if let Some(name) = &toml.model {
+        model.model = name.clone();
+    }
+    if let Some(temperature) = toml.temperature {
+        model.temperature = temperature;
+    }
+    if toml.max_tokens.is_some() {
+        model.max_tokens = toml.max_tokens;
+    }
+    if toml.top_p.is_some() {
+        model.top_p = toml.top_p;
     }
 }
 
-fn parse_tools(toml: TomlTools) -> Result<ToolPolicySet, PersonaLoadError> {
-    let mut policies = Vec::new();
-
-    for p in toml.policies {
+/// Apply tool policies: a policy replaces the inherited one for the same tool
+fn apply_tools(tools: &mut ToolPolicySet, toml: &TomlTools) -> Result<(), PersonaLoadError> {
+    for p in &toml.policies {
         let access = parse_access(&p.access)?;
-        let mut policy = ToolPolicy::new(p.tool, access);
-        policy.scope = p.scope;
-        policy.notes = p.notes;
-        policies.push(policy);
+        let mut policy = ToolPolicy::new(p.tool.clone(), access);
+        policy.scope = p.scope.clone();
+        policy.notes = p.notes.clone();
+
+        match tools
+            .policies
+            .iter_mut()
+            .find(|existing| existing.tool == policy.tool)
+        {
+            Some(existing) => *existing = policy,
+            None => tools.policies.push(policy),
+        }
     }
 
-    let default_access = match toml.default_access {
-        Some(s) => parse_access(&s)?,
-        None => ToolAccess::None,
-    };
+    if let Some(s) = &toml.default_access {
+        tools.default_access = parse_access(s)?;
+    }
 
-    Ok(ToolPolicySet {
-        policies,
-        default_access,
-    })
+    Ok(())
 }
 
 #[cfg(test)]
@@ -5,6 +5,8 @@
 //!
 //! Program -> Projects -> Threads -> Events
 
+use std::collections::{HashMap, HashSet, VecDeque};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -308,6 +310,56 @@ impl ProgramRegistry {
         self.get_program(program_id)
             .map(|program| program.calculate_stats(&self.projects))
     }
+
+    /// All projects the given project depends on, directly or transitively
+    ///
+    /// Each project is listed once, nearest first. Cycles in the relation
+    /// graph are tolerated.
+    pub fn dependencies_of(&self, project_id: &ProjectId) -> Vec<ProjectId> {
+        let mut edges: HashMap<ProjectId, Vec<ProjectId>> = HashMap::new();
+        for project in &self.projects {
+            edges
+                .entry(project.id)
+                .or_default()
+                .extend(project.direct_dependencies());
+        }
+        transitive_closure(&edges, *project_id)
+    }
+
+    /// All projects that depend on the given project, directly or transitively
+    ///
+    /// These are the projects affected when this one is delayed. Each project
+    /// is listed once, nearest first. Cycles in the relation graph are tolerated.
+    pub fn dependents_of(&self, project_id: &ProjectId) -> Vec<ProjectId> {
+        let mut edges: HashMap<ProjectId, Vec<ProjectId>> = HashMap::new();
+        for project in &self.projects {
+            for dependency in project.direct_dependencies() {
+                edges.entry(dependency).or_default().push(project.id);
+            }
+        }
+        transitive_closure(&edges, *project_id)
+    }
+}
+
+/// Breadth-first walk of `edges` from `start`, excluding `start` itself
+fn transitive_closure(
+    edges: &HashMap<ProjectId, Vec<ProjectId>>,
+    start: ProjectId,
+) -> Vec<ProjectId> {
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    let mut reached = Vec::new();
+
+    while let Some(current) = queue.pop_front() {
+        for &next in edges.get(&current).into_iter().flatten() {
+            if visited.insert(next) {
+                reached.push(next);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    reached
 }
 
 #[cfg(test)]
@@ -359,6 +411,92 @@ mod tests {
         assert_eq!(orphans.len(), 1);
     }
 
+    #[test]
+    fn test_transitive_dependents_diamond() {
+        use crate::project::ProjectRelation;
+
+        // base <- left, right <- top, with top also feeding back into base
+        let mut registry = ProgramRegistry::new();
+        let mut base = Project::new("Base".to_string(), "".to_string());
+        let mut left = Project::new("Left".to_string(), "".to_string());
+        let mut right = Project::new("Right".to_string(), "".to_string());
+        let mut top = Project::new("Top".to_string(), "".to_string());
+        let (base_id, left_id, right_id, top_id) = (base.id, left.id, right.id, top.id);
+
+        left.add_relation(
+            base_id,
+            ProjectRelation::FollowOn {
+                predecessor: base_id,
+                inherits: vec![],
+            },
+        );
+        right.add_relation(
+            base_id,
+            ProjectRelation::Dependency {
+                provides: vec!["dataset".to_string()],
+            },
+        );
+        top.add_relation(
+            left_id,
+            ProjectRelation::Synthesis {
+                sources: vec![left_id, right_id],
+            },
+        );
+        top.add_relation(
+            base_id,
+            ProjectRelation::Sibling {
+                shared_scope: "methods".to_string(),
+            },
+        );
+        // Cycle: base depends on top's output
+        base.add_relation(top_id, ProjectRelation::Dependency { provides: vec![] });
+
+        for project in [base, left, right, top] {
+            registry.add_project(project);
+        }
+
+        let dependents = registry.dependents_of(&left_id);
+        assert_eq!(dependents.len(), 3);
+        assert_eq!(dependents[0], top_id);
+        assert!(dependents.contains(&base_id));
+        assert!(dependents.contains(&right_id));
+
+        let dependencies = registry.dependencies_of(&top_id);
+        assert_eq!(dependencies.len(), 3);
+        assert_eq!(&dependencies[..2], &[left_id, right_id]);
+        assert_eq!(dependencies[2], base_id);
+    }
+
+    #[test]
+    fn test_dependents_without_cycle() {
+        use crate::project::ProjectRelation;
+
+        let mut registry = ProgramRegistry::new();
+        let base = Project::new("Base".to_string(), "".to_string());
+        let mut left = Project::new("Left".to_string(), "".to_string());
+        let mut right = Project::new("Right".to_string(), "".to_string());
+        let mut top = Project::new("Top".to_string(), "".to_string());
+        let (base_id, left_id, right_id, top_id) = (base.id, left.id, right.id, top.id);
+
+        left.add_relation(base_id, ProjectRelation::Dependency { provides: vec![] });
+        right.add_relation(base_id, ProjectRelation::Dependency { provides: vec![] });
+        top.add_relation(left_id, ProjectRelation::Dependency { provides: vec![] });
+        top.add_relation(right_id, ProjectRelation::Dependency { provides: vec![] });
+
+        for project in [base, left, right, top] {
+            registry.add_project(project);
+        }
+
+        // Top is reachable through both branches but listed once
+        let dependents = registry.dependents_of(&base_id);
+        assert_eq!(dependents.len(), 3);
+        assert_eq!(dependents.iter().filter(|&&id| id == top_id).count(), 1);
+        assert_eq!(dependents[2], top_id);
+
+        assert_eq!(registry.dependencies_of(&base_id), vec![]);
+        assert!(registry.dependents_of(&top_id).is_empty());
+    }
+
     #[test]
     fn test_program_stats() {
         let mut registry = ProgramRegistry::new();
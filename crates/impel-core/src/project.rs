@@ -276,6 +276,30 @@ impl Project {
         self.updated_at = Utc::now();
     }
 
+    /// Projects this one directly depends on
+    ///
+    /// Follow-on, synthesis, and dependency relations point at the projects
+    /// whose work this one builds on; sibling relations carry no dependency.
+    pub fn direct_dependencies(&self) -> Vec<ProjectId> {
+        let mut dependencies = Vec::new();
+        for (other, relation) in &self.relations {
+            let related: Vec<ProjectId> = match relation {
+                ProjectRelation::FollowOn { predecessor, .. } => vec![*other, *predecessor],
+                ProjectRelation::Synthesis { sources } => std::iter::once(*other)
+                    .chain(sources.iter().copied())
+                    .collect(),
+                ProjectRelation::Dependency { .. } => vec![*other],
+                ProjectRelation::Sibling { .. } => vec![],
+            };
+            for id in related {
+                if id != self.id && !dependencies.contains(&id) {
+                    dependencies.push(id);
+                }
+            }
+        }
+        dependencies
+    }
+
     /// Transition to a new status
     pub fn set_status(&mut self, status: ProjectStatus) {
         self.status = status;
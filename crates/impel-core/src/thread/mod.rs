@@ -6,6 +6,7 @@
 mod state;
 mod temperature;
 mod thread;
+mod timeline;
 
 pub use state::ThreadState;
 pub use temperature::{Temperature, TemperatureCoefficients};
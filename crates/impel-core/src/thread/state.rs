@@ -29,6 +29,16 @@ pub enum ThreadState {
 }
 
 impl ThreadState {
+    /// All states, in lifecycle order
+    pub const ALL: [ThreadState; 6] = [
+        ThreadState::Embryo,
+        ThreadState::Active,
+        ThreadState::Blocked,
+        ThreadState::Review,
+        ThreadState::Complete,
+        ThreadState::Killed,
+    ];
+
     /// Check if a state transition is valid
     pub fn can_transition_to(&self, target: &ThreadState) -> bool {
         match (self, target) {
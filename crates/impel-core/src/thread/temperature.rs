@@ -54,6 +54,9 @@ pub struct Temperature {
     human_boost: f64,
     /// Breakthrough signal strength
     breakthrough_signal: f64,
+    /// Recent activity level (decays with the temperature)
+    #[serde(default)]
+    recent_activity: f64,
 }
 
 /// Half-life for temperature decay (24 hours)
@@ -68,6 +71,7 @@ impl Temperature {
             last_updated: Utc::now(),
             human_boost: 0.0,
             breakthrough_signal: 0.0,
+            recent_activity: 0.0,
         }
     }
 
@@ -79,6 +83,7 @@ impl Temperature {
             last_updated: Utc::now(),
             human_boost: 0.0,
             breakthrough_signal: 0.0,
+            recent_activity: 0.0,
         }
     }
 
@@ -99,6 +104,7 @@ impl Temperature {
         self.value *= decay_factor;
         self.human_boost *= decay_factor;
         self.breakthrough_signal *= decay_factor;
+        self.recent_activity *= decay_factor;
         self.last_updated = Utc::now();
     }
 
@@ -108,6 +114,7 @@ impl Temperature {
         activity_weight: f64,
         coefficients: &TemperatureCoefficients,
     ) {
+        self.recent_activity = (self.recent_activity + activity_weight).clamp(0.0, 1.0);
         self.value += coefficients.alpha * activity_weight;
         self.value = self.value.clamp(0.0, 1.0);
         self.last_updated = Utc::now();
@@ -138,22 +145,33 @@ impl Temperature {
         self.value = self.base_priority;
         self.human_boost = 0.0;
         self.breakthrough_signal = 0.0;
+        self.recent_activity = 0.0;
         self.last_updated = Utc::now();
     }
 
     /// Recalculate temperature using all components
     pub fn recalculate(&mut self, recent_activity: f64, coefficients: &TemperatureCoefficients) {
-        let elapsed = Utc::now() - self.last_updated;
-        let hours_since_progress = elapsed.num_hours() as f64;
+        self.recent_activity = recent_activity.clamp(0.0, 1.0);
+        self.value = self.effective_value(coefficients);
+        self.last_updated = Utc::now();
+    }
 
-        self.value = self.base_priority
-            + coefficients.alpha * recent_activity
+    /// Evaluate the temperature formula with the given coefficients
+    ///
+    /// Unlike [`value`](Self::value), which accumulates updates under whatever
+    /// coefficients were in effect at the time, this recomputes the value from
+    /// the tracked components. Agents with different coefficients therefore see
+    /// the same thread at different temperatures.
+    pub fn effective_value(&self, coefficients: &TemperatureCoefficients) -> f64 {
+        let hours_since_progress = self.time_since_update().num_hours() as f64;
+
+        let value = self.base_priority
+            + coefficients.alpha * self.recent_activity
             + coefficients.beta * self.breakthrough_signal
             - coefficients.gamma * (hours_since_progress / DECAY_HALF_LIFE_HOURS as f64)
             + coefficients.delta * self.human_boost;
 
-        self.value = self.value.clamp(0.0, 1.0);
-        self.last_updated = Utc::now();
+        value.clamp(0.0, 1.0)
     }
 
     /// Get the time since last update
@@ -231,6 +249,20 @@ mod tests {
         assert!(temp.value() > 0.3);
     }
 
+    #[test]
+    fn test_effective_value_depends_on_coefficients() {
+        let mut temp = Temperature::with_priority(0.3);
+        let coefficients = TemperatureCoefficients::default();
+        temp.record_breakthrough(1.0, &coefficients);
+        assert!((temp.effective_value(&coefficients) - 0.6).abs() < 1e-9);
+
+        let breakthrough_seeking = TemperatureCoefficients {
+            beta: 0.6,
+            ..coefficients
+        };
+        assert!((temp.effective_value(&breakthrough_seeking) - 0.9).abs() < 1e-9);
+    }
+
     #[test]
     fn test_hot_warm_cold() {
         let hot = Temperature::new(0.8);
@@ -85,6 +85,12 @@ pub struct Thread {
     pub updated_at: DateTime<Utc>,
     /// Event sequence number for optimistic concurrency
     pub version: u64,
+    /// When the thread last entered the Blocked state (if currently blocked)
+    #[serde(default)]
+    pub blocked_since: Option<DateTime<Utc>>,
+    /// Escalation raised by the stuck-thread watchdog for the current block
+    #[serde(default)]
+    pub stuck_escalation_id: Option<String>,
 }
 
 impl Thread {
@@ -101,6 +107,8 @@ impl Thread {
             created_at: now,
             updated_at: now,
             version: 0,
+            blocked_since: None,
+            stuck_escalation_id: None,
         }
     }
 
@@ -117,6 +125,8 @@ impl Thread {
             created_at: now,
             updated_at: now,
             version: 0,
+            blocked_since: None,
+            stuck_escalation_id: None,
         }
     }
 
@@ -129,12 +139,28 @@ impl Thread {
             }
             .into());
         }
-        self.state = new_state;
-        self.updated_at = Utc::now();
+        self.set_state(new_state, Utc::now());
         self.version += 1;
         Ok(())
     }
 
+    /// Record a state change that happened at `at`, without validation
+    ///
+    /// Tracks when the thread became blocked; leaving Blocked clears the
+    /// stuck-thread watchdog.
+    pub fn set_state(&mut self, new_state: ThreadState, at: DateTime<Utc>) {
+        if new_state == ThreadState::Blocked {
+            if self.state != ThreadState::Blocked {
+                self.blocked_since = Some(at);
+            }
+        } else {
+            self.blocked_since = None;
+            self.stuck_escalation_id = None;
+        }
+        self.state = new_state;
+        self.updated_at = at;
+    }
+
     /// Activate the thread (from Embryo state)
     pub fn activate(&mut self) -> Result<()> {
         self.transition_to(ThreadState::Active)
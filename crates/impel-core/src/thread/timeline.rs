@@ -0,0 +1,289 @@
+//! Markdown export of a thread's event history
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use super::Thread;
+use crate::event::{Event, EventPayload};
+
+impl Thread {
+    /// Render the thread's events as a Markdown timeline for human review
+    ///
+    /// `events` may be the whole log: only events about this thread are
+    /// included, plus escalations raised for it and their follow-ups and
+    /// messages sent about it. Events are ordered by timestamp, then
+    /// sequence, and each becomes a section with its time, actor, and a
+    /// description.
+    pub fn export_timeline_markdown(&self, events: &[Event]) -> String {
+        let id = self.id.to_string();
+        // Escalations raised for this thread, collected first since their
+        // follow-ups may come before them in `events`
+        let escalation_ids: HashSet<&str> = events
+            .iter()
+            .filter(|event| {
+                matches!(
+                    &event.payload,
+                    EventPayload::EscalationCreated { thread_id, .. }
+                        if thread_id.as_deref() == Some(id.as_str())
+                )
+            })
+            .map(|event| event.entity_id.as_str())
+            .collect();
+
+        let mut relevant: Vec<&Event> = Vec::new();
+        for event in events {
+            let related = match &event.payload {
+                EventPayload::EscalationCreated { thread_id, .. } => {
+                    thread_id.as_deref() == Some(id.as_str())
+                }
+                EventPayload::EscalationRecurred { .. }
+                | EventPayload::EscalationAcknowledged { .. }
+                | EventPayload::EscalationResolved { .. } => {
+                    escalation_ids.contains(event.entity_id.as_str())
+                }
+                EventPayload::MessageSent { thread_id, .. } => {
+                    thread_id.as_deref() == Some(id.as_str())
+                }
+                _ => event.entity_id == id,
+            };
+            if related {
+                relevant.push(event);
+            }
+        }
+        relevant.sort_by_key(|e| (e.timestamp, e.sequence));
+
+        let mut out = String::new();
+        let _ = writeln!(out, "# Timeline: {}", self.metadata.title);
+        let _ = writeln!(out);
+        let _ = writeln!(out, "- **Thread:** `{}`", id);
+        let _ = writeln!(out, "- **State:** {}", self.state);
+        let _ = writeln!(out, "- **Events:** {}", relevant.len());
+
+        if relevant.is_empty() {
+            let _ = writeln!(out);
+            let _ = writeln!(out, "_No events recorded._");
+            return out;
+        }
+
+        for event in relevant {
+            let _ = writeln!(out);
+            let _ = writeln!(
+                out,
+                "## {} — {}",
+                event.timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
+                heading(&event.payload)
+            );
+            let _ = writeln!(out);
+            let _ = writeln!(
+                out,
+                "**Actor:** {}",
+                event.actor_id.as_deref().unwrap_or("system")
+            );
+            let _ = writeln!(out);
+            let _ = writeln!(out, "{}", detail(&event.payload));
+        }
+        out
+    }
+}
+
+/// Section heading for an event kind
+fn heading(payload: &EventPayload) -> &'static str {
+    match payload {
+        EventPayload::ThreadCreated { .. } => "Created",
+        EventPayload::ThreadStateChanged { .. } => "State change",
+        EventPayload::ThreadClaimed { .. } => "Claimed",
+        EventPayload::ThreadReleased { .. } => "Released",
+        EventPayload::ThreadTemperatureChanged { .. } => "Temperature change",
+        EventPayload::ThreadMerged { .. } => "Merged",
+        EventPayload::ThreadArtifactAdded { .. } => "Artifact added",
+        EventPayload::EscalationCreated { .. } => "Escalation raised",
+        EventPayload::EscalationRecurred { .. } => "Escalation reported again",
+        EventPayload::EscalationAcknowledged { .. } => "Escalation acknowledged",
+        EventPayload::EscalationResolved { .. } => "Escalation resolved",
+        EventPayload::MessageSent { .. } => "Message",
+        _ => "Event",
+    }
+}
+
+/// Description with the details a reviewer needs beyond the one-liner
+fn detail(payload: &EventPayload) -> String {
+    match payload {
+        EventPayload::ThreadStateChanged {
+            from,
+            to,
+            reason: Some(reason),
+        } => format!("{} → {}: {}", from, to, reason),
+        EventPayload::ThreadStateChanged { from, to, .. } => format!("{} → {}", from, to),
+        EventPayload::ThreadTemperatureChanged {
+            old_value,
+            new_value,
+            reason,
+        } => format!("{:.2} → {:.2}: {}", old_value, new_value, reason),
+        EventPayload::ThreadArtifactAdded {
+            artifact_id,
+            artifact_type,
+        } => format!("{} `{}`", artifact_type, artifact_id),
+        EventPayload::EscalationResolved {
+            resolver_id,
+            resolution,
+        } => format!("Resolved by {}: {}", resolver_id, resolution),
+        other => other.description(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::escalation::EscalationCategory;
+    use crate::event::EntityType;
+    use crate::thread::{ThreadMetadata, ThreadState};
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_timeline_sections_in_order() {
+        let thread = Thread::new(ThreadMetadata {
+            title: "Survey halo finders".to_string(),
+            ..Default::default()
+        });
+        let id = thread.id.to_string();
+        let start = Utc::now();
+        let event = |minutes, payload| {
+            let mut event = Event::new(id.clone(), EntityType::Thread, payload)
+                .with_actor("research-1".to_string());
+            event.timestamp = start + Duration::minutes(minutes);
+            event
+        };
+
+        // Deliberately out of order, with an unrelated thread's event mixed in
+        let mut unrelated = event(
+            2,
+            EventPayload::ThreadClaimed {
+                agent_id: "other".to_string(),
+            },
+        );
+        unrelated.entity_id = "someone-else".to_string();
+        let events = vec![
+            event(
+                30,
+                EventPayload::ThreadStateChanged {
+                    from: ThreadState::Review,
+                    to: ThreadState::Complete,
+                    reason: Some("accepted".to_string()),
+                },
+            ),
+            event(
+                10,
+                EventPayload::ThreadArtifactAdded {
+                    artifact_id: "notes.md".to_string(),
+                    artifact_type: "document".to_string(),
+                },
+            ),
+            event(
+                0,
+                EventPayload::ThreadClaimed {
+                    agent_id: "research-1".to_string(),
+                },
+            ),
+            unrelated,
+            event(
+                20,
+                EventPayload::ThreadArtifactAdded {
+                    artifact_id: "table.csv".to_string(),
+                    artifact_type: "dataset".to_string(),
+                },
+            ),
+        ];
+
+        let markdown = thread.export_timeline_markdown(&events);
+        assert!(markdown.starts_with("# Timeline: Survey halo finders\n"));
+        assert!(markdown.contains("- **Events:** 4\n"));
+        assert!(!markdown.contains("other"));
+
+        let sections: Vec<&str> = markdown
+            .lines()
+            .filter_map(|l| l.strip_prefix("## "))
+            .map(|l| l.split(" — ").nth(1).unwrap())
+            .collect();
+        assert_eq!(
+            sections,
+            vec![
+                "Claimed",
+                "Artifact added",
+                "Artifact added",
+                "State change"
+            ]
+        );
+
+        let notes = markdown.find("document `notes.md`").unwrap();
+        let table = markdown.find("dataset `table.csv`").unwrap();
+        assert!(notes < table);
+        assert!(markdown.contains("REVIEW → COMPLETE: accepted"));
+        assert!(markdown.contains("**Actor:** research-1"));
+    }
+
+    #[test]
+    fn test_escalation_follow_ups_before_creation() {
+        let thread = Thread::new(ThreadMetadata {
+            title: "Survey halo finders".to_string(),
+            ..Default::default()
+        });
+        let start = Utc::now();
+        let escalation = |minutes, payload| {
+            let mut event = Event::new("esc-1".to_string(), EntityType::Escalation, payload);
+            event.timestamp = start + Duration::minutes(minutes);
+            event
+        };
+
+        // A log read back out of order: follow-ups come before the creation
+        let events = vec![
+            escalation(
+                20,
+                EventPayload::EscalationResolved {
+                    resolver_id: "human".to_string(),
+                    resolution: "use ROCKSTAR".to_string(),
+                },
+            ),
+            escalation(
+                10,
+                EventPayload::EscalationAcknowledged {
+                    acknowledger_id: "human".to_string(),
+                },
+            ),
+            escalation(
+                0,
+                EventPayload::EscalationCreated {
+                    category: EscalationCategory::Decision,
+                    title: "Which halo finder?".to_string(),
+                    thread_id: Some(thread.id.to_string()),
+                },
+            ),
+        ];
+
+        let markdown = thread.export_timeline_markdown(&events);
+        assert!(markdown.contains("- **Events:** 3\n"));
+        let sections: Vec<&str> = markdown
+            .lines()
+            .filter_map(|l| l.strip_prefix("## "))
+            .map(|l| l.split(" — ").nth(1).unwrap())
+            .collect();
+        assert_eq!(
+            sections,
+            vec![
+                "Escalation raised",
+                "Escalation acknowledged",
+                "Escalation resolved"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_timeline() {
+        let thread = Thread::new(ThreadMetadata {
+            title: "Quiet".to_string(),
+            ..Default::default()
+        });
+        let markdown = thread.export_timeline_markdown(&[]);
+        assert!(markdown.contains("- **Events:** 0"));
+        assert!(markdown.ends_with("_No events recorded._\n"));
+    }
+}
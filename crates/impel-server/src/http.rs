@@ -8,22 +8,24 @@ use axum::{
     Json,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 use impel_core::coordination::Command;
+use impel_core::error::{AgentError, ImpelError};
 use impel_core::event::{EntityType, Event, EventPayload};
-use impel_core::persona::ToolAccess;
+use impel_core::persona::{ModelOverride, PersonaModelConfig, ToolAccess};
 use impel_core::thread::{ThreadId, ThreadState};
 
 use crate::AppState;
 
 /// Response for available threads
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AvailableThreadsResponse {
     pub threads: Vec<ThreadSummary>,
 }
 
 /// Summary of a thread for listing
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ThreadSummary {
     pub id: String,
     pub title: String,
@@ -33,6 +35,14 @@ pub struct ThreadSummary {
 }
 
 /// Get available threads (unclaimed, claimable)
+#[utoipa::path(
+    get,
+    path = "/threads/available",
+    tag = "threads",
+    responses(
+        (status = 200, description = "Unclaimed, claimable threads", body = AvailableThreadsResponse),
+    )
+)]
 pub async fn get_available_threads(
     State(state): State<Arc<AppState>>,
 ) -> Json<AvailableThreadsResponse> {
@@ -73,12 +83,24 @@ pub async fn get_thread(
 }
 
 /// Request to claim a thread
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct ClaimRequest {
     pub agent_id: String,
 }
 
 /// Claim a thread for an agent
+#[utoipa::path(
+    post,
+    path = "/threads/{id}/claim",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    request_body = ClaimRequest,
+    responses(
+        (status = 200, description = "Thread claimed", body = Object),
+        (status = 400, description = "Invalid thread ID"),
+        (status = 409, description = "Thread not claimable or already claimed"),
+    )
+)]
 pub async fn claim_thread(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -103,38 +125,61 @@ pub async fn claim_thread(
 }
 
 /// Request to submit an event
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct SubmitEventRequest {
     pub entity_id: String,
     pub entity_type: String,
+    /// Externally tagged `EventPayload`, e.g. `{"ThreadClaimed": {"agent_id": "..."}}`
+    #[schema(value_type = Object)]
     pub payload: serde_json::Value,
     pub actor_id: Option<String>,
 }
 
+impl SubmitEventRequest {
+    /// Build the event described by this request
+    fn into_event(self) -> Result<Event, String> {
+        let entity_type = match self.entity_type.as_str() {
+            "thread" => EntityType::Thread,
+            "agent" => EntityType::Agent,
+            "message" => EntityType::Message,
+            "escalation" => EntityType::Escalation,
+            "artifact" => EntityType::Artifact,
+            "system" => EntityType::System,
+            _ => return Err("Invalid entity type".to_string()),
+        };
+
+        // Parse payload into EventPayload
+        // For simplicity, we'll support a subset of events via JSON
+        let payload: EventPayload =
+            serde_json::from_value(self.payload).map_err(|e| format!("Invalid payload: {}", e))?;
+
+        let mut event = Event::new(self.entity_id, entity_type, payload);
+        if let Some(actor) = self.actor_id {
+            event = event.with_actor(actor);
+        }
+        Ok(event)
+    }
+}
+
 /// Submit an event
+#[utoipa::path(
+    post,
+    path = "/events",
+    tag = "events",
+    request_body = SubmitEventRequest,
+    responses(
+        (status = 200, description = "Event recorded", body = Object),
+        (status = 400, description = "Invalid entity type or payload"),
+        (status = 500, description = "Event could not be applied"),
+    )
+)]
 pub async fn submit_event(
     State(state): State<Arc<AppState>>,
     Json(request): Json<SubmitEventRequest>,
 ) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
-    let entity_type = match request.entity_type.as_str() {
-        "thread" => EntityType::Thread,
-        "agent" => EntityType::Agent,
-        "message" => EntityType::Message,
-        "escalation" => EntityType::Escalation,
-        "artifact" => EntityType::Artifact,
-        "system" => EntityType::System,
-        _ => return Err((StatusCode::BAD_REQUEST, "Invalid entity type".to_string())),
-    };
-
-    // Parse payload into EventPayload
-    // For simplicity, we'll support a subset of events via JSON
-    let payload: EventPayload = serde_json::from_value(request.payload)
-        .map_err(|e| (StatusCode::BAD_REQUEST, format!("Invalid payload: {}", e)))?;
-
-    let mut event = Event::new(request.entity_id, entity_type, payload);
-    if let Some(actor) = request.actor_id {
-        event = event.with_actor(actor);
-    }
+    let event = request
+        .into_event()
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
 
     let mut coord = state.coordination.write().await;
     let event = coord
@@ -148,6 +193,132 @@ pub async fn submit_event(
     })))
 }
 
+/// Outcome of one event in a batch submission
+#[derive(Debug, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchEventStatus {
+    /// Event was recorded
+    Accepted,
+    /// Event was invalid; the whole batch was discarded because of it
+    Rejected,
+    /// Event was discarded because another event in the batch was rejected
+    NotApplied,
+}
+
+/// Result for one event in a batch submission
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchEventResult {
+    pub index: usize,
+    pub status: BatchEventStatus,
+    pub event_id: Option<String>,
+    pub sequence: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Response for a batch submission
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchEventsResponse {
+    pub success: bool,
+    pub applied: usize,
+    pub results: Vec<BatchEventResult>,
+}
+
+impl BatchEventsResponse {
+    /// Response for a batch discarded because event `index` was invalid
+    fn rejected(len: usize, index: usize, error: String) -> Self {
+        let mut error = Some(error);
+        let results = (0..len)
+            .map(|i| BatchEventResult {
+                index: i,
+                status: if i == index {
+                    BatchEventStatus::Rejected
+                } else {
+                    BatchEventStatus::NotApplied
+                },
+                event_id: None,
+                sequence: None,
+                error: if i == index { error.take() } else { None },
+            })
+            .collect();
+        Self {
+            success: false,
+            applied: 0,
+            results,
+        }
+    }
+}
+
+/// Submit several events at once
+///
+/// The batch is all-or-nothing. Events are applied in the order given, each
+/// validated against the state left by the events before it, so a batch can
+/// walk a thread through several transitions. If any event is malformed
+/// (`400 Bad Request`) or invalid, e.g. a state transition the thread can't
+/// make (`409 Conflict`), no event is recorded: the offending event is marked
+/// `rejected` with its error and every other event `not_applied`.
+#[utoipa::path(
+    post,
+    path = "/events/batch",
+    tag = "events",
+    request_body = Vec<SubmitEventRequest>,
+    responses(
+        (status = 200, description = "All events recorded", body = BatchEventsResponse),
+        (status = 400, description = "An event is malformed; nothing recorded", body = BatchEventsResponse),
+        (status = 409, description = "An event is invalid; nothing recorded", body = BatchEventsResponse),
+    )
+)]
+pub async fn submit_event_batch(
+    State(state): State<Arc<AppState>>,
+    Json(requests): Json<Vec<SubmitEventRequest>>,
+) -> (StatusCode, Json<BatchEventsResponse>) {
+    let len = requests.len();
+    let mut events = Vec::with_capacity(len);
+    for (index, request) in requests.into_iter().enumerate() {
+        match request.into_event() {
+            Ok(event) => events.push(event),
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(BatchEventsResponse::rejected(len, index, e)),
+                )
+            }
+        }
+    }
+
+    let mut coord = state.coordination.write().await;
+    match coord.apply_batch(events) {
+        Ok(applied) => {
+            let results = applied
+                .iter()
+                .enumerate()
+                .map(|(index, event)| BatchEventResult {
+                    index,
+                    status: BatchEventStatus::Accepted,
+                    event_id: Some(event.id.to_string()),
+                    sequence: Some(event.sequence),
+                    error: None,
+                })
+                .collect();
+            (
+                StatusCode::OK,
+                Json(BatchEventsResponse {
+                    success: true,
+                    applied: applied.len(),
+                    results,
+                }),
+            )
+        }
+        Err(rejected) => (
+            StatusCode::CONFLICT,
+            Json(BatchEventsResponse::rejected(
+                len,
+                rejected.index,
+                rejected.error.to_string(),
+            )),
+        ),
+    }
+}
+
 /// Request to get events
 #[derive(Debug, Deserialize)]
 pub struct GetEventsQuery {
@@ -156,6 +327,14 @@ pub struct GetEventsQuery {
 }
 
 /// Get events
+#[utoipa::path(
+    get,
+    path = "/events",
+    tag = "events",
+    responses(
+        (status = 200, description = "Recent events", body = Object),
+    )
+)]
 pub async fn get_events(State(state): State<Arc<AppState>>) -> Json<serde_json::Value> {
     let coord = state.coordination.read().await;
     let events: Vec<_> = coord
@@ -270,7 +449,7 @@ pub struct PersonaDomainResponse {
     pub data_sources: Vec<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PersonaModelResponse {
     pub provider: String,
     pub model: String,
@@ -346,13 +525,7 @@ pub async fn get_persona(
                     methodologies: p.domain.methodologies.clone(),
                     data_sources: p.domain.data_sources.clone(),
                 },
-                model: PersonaModelResponse {
-                    provider: p.model.provider.clone(),
-                    model: p.model.model.clone(),
-                    temperature: p.model.temperature,
-                    max_tokens: p.model.max_tokens,
-                    top_p: p.model.top_p,
-                },
+                model: PersonaModelResponse::from(&p.model),
                 tools: PersonaToolsResponse {
                     policies: p
                         .tools
@@ -374,6 +547,55 @@ pub async fn get_persona(
         .ok_or(StatusCode::NOT_FOUND)
 }
 
+impl From<&PersonaModelConfig> for PersonaModelResponse {
+    fn from(model: &PersonaModelConfig) -> Self {
+        Self {
+            provider: model.provider.clone(),
+            model: model.model.clone(),
+            temperature: model.temperature,
+            max_tokens: model.max_tokens,
+            top_p: model.top_p,
+        }
+    }
+}
+
+/// Resolve the model configuration an agent should use for a task
+///
+/// Merges the requested overrides onto the persona's model configuration
+/// and returns the result; the stored persona is not modified.
+#[utoipa::path(
+    post,
+    path = "/personas/{id}/model",
+    tag = "personas",
+    params(("id" = String, Path, description = "Persona ID")),
+    request_body(
+        content = Object,
+        description = "Fields to override (provider, model, temperature, max_tokens, top_p); unset fields keep the persona's value"
+    ),
+    responses(
+        (status = 200, description = "Effective model configuration", body = PersonaModelResponse),
+        (status = 400, description = "Unsupported model or out-of-range parameter"),
+        (status = 404, description = "Persona not found"),
+    )
+)]
+pub async fn resolve_persona_model(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(overrides): Json<ModelOverride>,
+) -> Result<Json<PersonaModelResponse>, (StatusCode, String)> {
+    let persona = state
+        .personas
+        .get_by_str(&id)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, format!("Persona not found: {}", id)))?;
+
+    let effective = persona
+        .model
+        .with_overrides(&overrides)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+
+    Ok(Json(PersonaModelResponse::from(&effective)))
+}
+
 fn access_to_string(access: ToolAccess) -> String {
     match access {
         ToolAccess::None => "none".to_string(),
@@ -388,14 +610,15 @@ fn access_to_string(access: ToolAccess) -> String {
 // ============================================================================
 
 /// Response for list threads
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ThreadsResponse {
     pub threads: Vec<ThreadSummary>,
     pub count: usize,
 }
 
 /// Query parameters for listing threads
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ListThreadsQuery {
     pub state: Option<String>,
     pub min_temperature: Option<f64>,
@@ -403,6 +626,15 @@ pub struct ListThreadsQuery {
 }
 
 /// List all threads with optional filters
+#[utoipa::path(
+    get,
+    path = "/threads",
+    tag = "threads",
+    params(ListThreadsQuery),
+    responses(
+        (status = 200, description = "Threads, hottest first", body = ThreadsResponse),
+    )
+)]
 pub async fn list_threads(
     State(state): State<Arc<AppState>>,
     axum::extract::Query(query): axum::extract::Query<ListThreadsQuery>,
@@ -452,7 +684,7 @@ pub async fn list_threads(
 }
 
 /// Full thread detail response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ThreadDetail {
     pub id: String,
     pub title: String,
@@ -468,6 +700,16 @@ pub struct ThreadDetail {
 }
 
 /// Get full thread details
+#[utoipa::path(
+    get,
+    path = "/threads/{id}",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    responses(
+        (status = 200, description = "Thread detail", body = ThreadDetail),
+        (status = 404, description = "Thread not found"),
+    )
+)]
 pub async fn get_thread_detail(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -495,7 +737,7 @@ pub async fn get_thread_detail(
 }
 
 /// Request to create a thread
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateThreadRequest {
     pub title: String,
     pub description: String,
@@ -504,6 +746,17 @@ pub struct CreateThreadRequest {
 }
 
 /// Create a new thread
+#[utoipa::path(
+    post,
+    path = "/threads",
+    tag = "threads",
+    request_body = CreateThreadRequest,
+    responses(
+        (status = 200, description = "Thread created", body = ThreadDetail),
+        (status = 400, description = "Invalid parent ID"),
+        (status = 500, description = "Thread could not be created"),
+    )
+)]
 pub async fn create_thread(
     State(state): State<Arc<AppState>>,
     Json(request): Json<CreateThreadRequest>,
@@ -549,6 +802,17 @@ pub async fn create_thread(
 }
 
 /// Activate a thread (Embryo -> Active)
+#[utoipa::path(
+    put,
+    path = "/threads/{id}/activate",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    responses(
+        (status = 200, description = "Thread activated", body = Object),
+        (status = 400, description = "Invalid thread ID"),
+        (status = 409, description = "Invalid state transition"),
+    )
+)]
 pub async fn activate_thread(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -569,12 +833,24 @@ pub async fn activate_thread(
 }
 
 /// Request to block a thread
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct BlockThreadRequest {
     pub reason: Option<String>,
 }
 
 /// Block a thread
+#[utoipa::path(
+    put,
+    path = "/threads/{id}/block",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    request_body = BlockThreadRequest,
+    responses(
+        (status = 200, description = "Thread blocked", body = Object),
+        (status = 400, description = "Invalid thread ID"),
+        (status = 409, description = "Invalid state transition"),
+    )
+)]
 pub async fn block_thread(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -599,6 +875,17 @@ pub async fn block_thread(
 }
 
 /// Unblock a thread
+#[utoipa::path(
+    put,
+    path = "/threads/{id}/unblock",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    responses(
+        (status = 200, description = "Thread unblocked", body = Object),
+        (status = 400, description = "Invalid thread ID"),
+        (status = 409, description = "Invalid state transition"),
+    )
+)]
 pub async fn unblock_thread(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -619,6 +906,17 @@ pub async fn unblock_thread(
 }
 
 /// Submit thread for review
+#[utoipa::path(
+    put,
+    path = "/threads/{id}/review",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    responses(
+        (status = 200, description = "Thread submitted for review", body = Object),
+        (status = 400, description = "Invalid thread ID"),
+        (status = 409, description = "Invalid state transition"),
+    )
+)]
 pub async fn submit_for_review(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -639,6 +937,17 @@ pub async fn submit_for_review(
 }
 
 /// Complete a thread
+#[utoipa::path(
+    put,
+    path = "/threads/{id}/complete",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    responses(
+        (status = 200, description = "Thread completed", body = Object),
+        (status = 400, description = "Invalid thread ID"),
+        (status = 409, description = "Invalid state transition"),
+    )
+)]
 pub async fn complete_thread(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -659,12 +968,24 @@ pub async fn complete_thread(
 }
 
 /// Request to kill a thread
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct KillThreadRequest {
     pub reason: Option<String>,
 }
 
 /// Kill a thread
+#[utoipa::path(
+    put,
+    path = "/threads/{id}/kill",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    request_body = KillThreadRequest,
+    responses(
+        (status = 200, description = "Thread killed", body = Object),
+        (status = 400, description = "Invalid thread ID"),
+        (status = 409, description = "Invalid state transition"),
+    )
+)]
 pub async fn kill_thread(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -689,13 +1010,26 @@ pub async fn kill_thread(
 }
 
 /// Request to set thread temperature
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct SetTemperatureRequest {
     pub temperature: f64,
     pub reason: Option<String>,
 }
 
 /// Set thread temperature
+#[utoipa::path(
+    put,
+    path = "/threads/{id}/temperature",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    request_body = SetTemperatureRequest,
+    responses(
+        (status = 200, description = "Temperature set", body = Object),
+        (status = 400, description = "Invalid thread ID"),
+        (status = 404, description = "Thread not found"),
+        (status = 409, description = "Temperature could not be set"),
+    )
+)]
 pub async fn set_thread_temperature(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -729,12 +1063,24 @@ pub async fn set_thread_temperature(
 }
 
 /// Release a thread (agent releases claim)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct ReleaseThreadRequest {
     pub agent_id: String,
 }
 
 /// Release a thread from an agent
+#[utoipa::path(
+    post,
+    path = "/threads/{id}/release",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    request_body = ReleaseThreadRequest,
+    responses(
+        (status = 200, description = "Thread released", body = Object),
+        (status = 400, description = "Invalid thread ID"),
+        (status = 409, description = "Agent does not own the thread"),
+    )
+)]
 pub async fn release_thread(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -759,6 +1105,16 @@ pub async fn release_thread(
 }
 
 /// Get events for a specific thread
+#[utoipa::path(
+    get,
+    path = "/threads/{id}/events",
+    tag = "threads",
+    params(("id" = String, Path, description = "Thread ID")),
+    responses(
+        (status = 200, description = "Events for the thread", body = Object),
+        (status = 404, description = "Thread not found"),
+    )
+)]
 pub async fn get_thread_events(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -800,7 +1156,7 @@ pub async fn get_thread_events(
 use impel_core::agent::AgentType;
 
 /// Summary of an agent for listing
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AgentSummary {
     pub id: String,
     pub agent_type: String,
@@ -810,7 +1166,7 @@ pub struct AgentSummary {
 }
 
 /// Full agent detail
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AgentDetail {
     pub id: String,
     pub agent_type: String,
@@ -818,18 +1174,27 @@ pub struct AgentDetail {
     pub current_thread: Option<String>,
     pub registered_at: String,
     pub last_active_at: String,
+    pub last_heartbeat: String,
     pub threads_completed: u64,
     pub capabilities: Vec<String>,
 }
 
 /// Response for agent list
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AgentsResponse {
     pub agents: Vec<AgentSummary>,
     pub count: usize,
 }
 
 /// List all agents
+#[utoipa::path(
+    get,
+    path = "/agents",
+    tag = "agents",
+    responses(
+        (status = 200, description = "Registered agents", body = AgentsResponse),
+    )
+)]
 pub async fn list_agents(State(state): State<Arc<AppState>>) -> Json<AgentsResponse> {
     let coord = state.coordination.read().await;
 
@@ -850,6 +1215,16 @@ pub async fn list_agents(State(state): State<Arc<AppState>>) -> Json<AgentsRespo
 }
 
 /// Get a specific agent
+#[utoipa::path(
+    get,
+    path = "/agents/{id}",
+    tag = "agents",
+    params(("id" = String, Path, description = "Agent ID")),
+    responses(
+        (status = 200, description = "Agent detail", body = AgentDetail),
+        (status = 404, description = "Agent not found"),
+    )
+)]
 pub async fn get_agent(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -867,6 +1242,7 @@ pub async fn get_agent(
                 current_thread: a.current_thread.map(|t| t.to_string()),
                 registered_at: a.registered_at.to_rfc3339(),
                 last_active_at: a.last_active_at.to_rfc3339(),
+                last_heartbeat: a.last_heartbeat.to_rfc3339(),
                 threads_completed: a.threads_completed,
                 capabilities: a
                     .agent_type
@@ -880,7 +1256,7 @@ pub async fn get_agent(
 }
 
 /// Request to register an agent
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct RegisterAgentRequest {
     pub agent_type: String,
     pub persona_id: Option<String>,
@@ -899,6 +1275,18 @@ fn parse_agent_type(s: &str) -> Option<AgentType> {
 }
 
 /// Register a new agent
+#[utoipa::path(
+    post,
+    path = "/agents",
+    tag = "agents",
+    request_body = RegisterAgentRequest,
+    responses(
+        (status = 200, description = "Agent registered", body = AgentDetail),
+        (status = 400, description = "Invalid agent type"),
+        (status = 409, description = "Agent could not be registered"),
+        (status = 500, description = "Agent missing after registration"),
+    )
+)]
 pub async fn register_agent(
     State(state): State<Arc<AppState>>,
     Json(request): Json<RegisterAgentRequest>,
@@ -942,6 +1330,7 @@ pub async fn register_agent(
         current_thread: agent.current_thread.map(|t| t.to_string()),
         registered_at: agent.registered_at.to_rfc3339(),
         last_active_at: agent.last_active_at.to_rfc3339(),
+        last_heartbeat: agent.last_heartbeat.to_rfc3339(),
         threads_completed: agent.threads_completed,
         capabilities: agent
             .agent_type
@@ -952,13 +1341,60 @@ pub async fn register_agent(
     }))
 }
 
+/// Record a heartbeat from an agent
+///
+/// Agents must call this periodically; those silent past the configured
+/// timeout are marked offline by the reaper and lose their claimed threads.
+#[utoipa::path(
+    post,
+    path = "/agents/{id}/heartbeat",
+    tag = "agents",
+    params(("id" = String, Path, description = "Agent ID")),
+    responses(
+        (status = 200, description = "Heartbeat recorded", body = Object),
+        (status = 404, description = "Agent not found"),
+        (status = 409, description = "Agent has been terminated"),
+    )
+)]
+pub async fn agent_heartbeat(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+    let mut coord = state.coordination.write().await;
+    let now = chrono::Utc::now();
+
+    let status = coord.record_heartbeat(&id, now).map_err(|e| match e {
+        ImpelError::Agent(AgentError::NotFound(_)) => (StatusCode::NOT_FOUND, e.to_string()),
+        _ => (StatusCode::CONFLICT, e.to_string()),
+    })?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "agent_id": id,
+        "status": status.to_string(),
+        "last_heartbeat": now.to_rfc3339()
+    })))
+}
+
 /// Request to terminate an agent
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct TerminateAgentRequest {
     pub reason: Option<String>,
 }
 
 /// Terminate an agent
+#[utoipa::path(
+    delete,
+    path = "/agents/{id}",
+    tag = "agents",
+    params(("id" = String, Path, description = "Agent ID")),
+    request_body = TerminateAgentRequest,
+    responses(
+        (status = 200, description = "Agent terminated", body = Object),
+        (status = 404, description = "Agent not found"),
+        (status = 500, description = "Agent could not be terminated"),
+    )
+)]
 pub async fn terminate_agent(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -999,7 +1435,7 @@ use impel_core::escalation::{
 };
 
 /// Summary of an escalation for listing
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct EscalationSummary {
     pub id: String,
     pub category: String,
@@ -1012,7 +1448,7 @@ pub struct EscalationSummary {
 }
 
 /// Full escalation detail
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct EscalationDetail {
     pub id: String,
     pub category: String,
@@ -1030,9 +1466,10 @@ pub struct EscalationDetail {
     pub resolution: Option<String>,
     pub options: Vec<EscalationOptionResponse>,
     pub selected_option: Option<usize>,
+    pub occurrence_count: u32,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct EscalationOptionResponse {
     pub label: String,
     pub description: String,
@@ -1040,19 +1477,29 @@ pub struct EscalationOptionResponse {
 }
 
 /// Response for escalation list
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct EscalationsResponse {
     pub escalations: Vec<EscalationSummary>,
     pub count: usize,
 }
 
 /// Query parameters for listing escalations
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ListEscalationsQuery {
     pub open_only: Option<bool>,
 }
 
 /// List escalations
+#[utoipa::path(
+    get,
+    path = "/escalations",
+    tag = "escalations",
+    params(ListEscalationsQuery),
+    responses(
+        (status = 200, description = "Escalations, highest priority first", body = EscalationsResponse),
+    )
+)]
 pub async fn list_escalations(
     State(state): State<Arc<AppState>>,
     axum::extract::Query(query): axum::extract::Query<ListEscalationsQuery>,
@@ -1097,6 +1544,16 @@ pub async fn list_escalations(
 }
 
 /// Get a specific escalation
+#[utoipa::path(
+    get,
+    path = "/escalations/{id}",
+    tag = "escalations",
+    params(("id" = String, Path, description = "Escalation ID")),
+    responses(
+        (status = 200, description = "Escalation detail", body = EscalationDetail),
+        (status = 404, description = "Escalation not found"),
+    )
+)]
 pub async fn get_escalation(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -1131,13 +1588,14 @@ pub async fn get_escalation(
                     })
                     .collect(),
                 selected_option: e.selected_option,
+                occurrence_count: e.occurrence_count,
             })
         })
         .ok_or(StatusCode::NOT_FOUND)
 }
 
 /// Request to create an escalation
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateEscalationRequest {
     pub category: String,
     pub title: String,
@@ -1148,7 +1606,7 @@ pub struct CreateEscalationRequest {
     pub options: Option<Vec<CreateEscalationOption>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateEscalationOption {
     pub label: String,
     pub description: String,
@@ -1178,6 +1636,17 @@ fn parse_escalation_priority(s: &str) -> Option<EscalationPriority> {
 }
 
 /// Create a new escalation
+#[utoipa::path(
+    post,
+    path = "/escalations",
+    tag = "escalations",
+    request_body = CreateEscalationRequest,
+    responses(
+        (status = 200, description = "Escalation created, or merged into an open duplicate", body = EscalationDetail),
+        (status = 400, description = "Invalid category, priority or thread ID"),
+        (status = 500, description = "Escalation could not be recorded"),
+    )
+)]
 pub async fn create_escalation(
     State(state): State<Arc<AppState>>,
     Json(request): Json<CreateEscalationRequest>,
@@ -1228,18 +1697,11 @@ pub async fn create_escalation(
             .collect();
     }
 
-    let escalation_id = escalation.id.clone();
-    coord.add_escalation(escalation);
-
-    // Record event
-    let _ = Command::CreateEscalation {
-        category,
-        title: request.title,
-        description: request.description,
-        created_by: request.created_by,
-        thread_id,
-    }
-    .execute(&mut coord);
+    // Duplicates of an open escalation are merged into it
+    let escalation_id = coord
+        .create_escalation(escalation)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .entity_id;
 
     let escalation = coord.get_escalation(&escalation_id).ok_or_else(|| {
         (
@@ -1273,16 +1735,28 @@ pub async fn create_escalation(
             })
             .collect(),
         selected_option: None,
+        occurrence_count: escalation.occurrence_count,
     }))
 }
 
 /// Request to acknowledge an escalation
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct AcknowledgeEscalationRequest {
     pub by: String,
 }
 
 /// Acknowledge an escalation
+#[utoipa::path(
+    put,
+    path = "/escalations/{id}/acknowledge",
+    tag = "escalations",
+    params(("id" = String, Path, description = "Escalation ID")),
+    request_body = AcknowledgeEscalationRequest,
+    responses(
+        (status = 200, description = "Escalation acknowledged", body = Object),
+        (status = 409, description = "Escalation could not be acknowledged"),
+    )
+)]
 pub async fn acknowledge_escalation(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -1306,7 +1780,7 @@ pub async fn acknowledge_escalation(
 }
 
 /// Request to resolve an escalation
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct ResolveEscalationRequest {
     pub by: String,
     pub resolution: String,
@@ -1314,6 +1788,18 @@ pub struct ResolveEscalationRequest {
 }
 
 /// Resolve an escalation
+#[utoipa::path(
+    put,
+    path = "/escalations/{id}/resolve",
+    tag = "escalations",
+    params(("id" = String, Path, description = "Escalation ID")),
+    request_body = ResolveEscalationRequest,
+    responses(
+        (status = 200, description = "Escalation resolved", body = Object),
+        (status = 404, description = "Escalation not found"),
+        (status = 409, description = "Escalation could not be resolved"),
+    )
+)]
 pub async fn resolve_escalation(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -1350,14 +1836,15 @@ pub async fn resolve_escalation(
 }
 
 /// Query parameters for polling escalation resolution
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct PollEscalationQuery {
     /// Timeout in seconds (default: 30, max: 120)
     pub timeout: Option<u64>,
 }
 
 /// Response for escalation poll
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct EscalationPollResponse {
     pub id: String,
     pub status: String,
@@ -1373,6 +1860,16 @@ pub struct EscalationPollResponse {
 ///
 /// This endpoint allows agents to wait for a human to resolve an escalation.
 /// The request will block until the escalation is resolved or the timeout expires.
+#[utoipa::path(
+    get,
+    path = "/escalations/{id}/poll",
+    tag = "escalations",
+    params(("id" = String, Path, description = "Escalation ID"), PollEscalationQuery),
+    responses(
+        (status = 200, description = "Escalation resolved or poll timed out", body = EscalationPollResponse),
+        (status = 404, description = "Escalation not found"),
+    )
+)]
 pub async fn poll_escalation(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -1441,7 +1938,7 @@ pub async fn poll_escalation(
 // ============================================================================
 
 /// Response for next thread endpoint
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct NextThreadResponse {
     pub thread: Option<ThreadDetail>,
     pub claimed: bool,
@@ -1452,6 +1949,18 @@ pub struct NextThreadResponse {
 ///
 /// Returns the highest-temperature available thread. If auto_claim is true,
 /// the thread will be automatically claimed for the agent.
+#[utoipa::path(
+    get,
+    path = "/agents/{id}/next-thread",
+    tag = "agents",
+    params(("id" = String, Path, description = "Agent ID"), GetNextThreadQuery),
+    responses(
+        (status = 200, description = "Hottest available thread, if any", body = NextThreadResponse),
+        (status = 404, description = "Agent not found"),
+        (status = 409, description = "Agent is terminated or already has a thread"),
+        (status = 500, description = "Thread could not be claimed"),
+    )
+)]
 pub async fn get_next_thread(
     State(state): State<Arc<AppState>>,
     Path(agent_id): Path<String>,
@@ -1569,8 +2078,138 @@ pub async fn get_next_thread(
 }
 
 /// Query parameters for get next thread
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct GetNextThreadQuery {
     /// Automatically claim the thread (default: false)
     pub auto_claim: Option<bool>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thread_event(thread_id: &str, payload: serde_json::Value) -> SubmitEventRequest {
+        SubmitEventRequest {
+            entity_id: thread_id.to_string(),
+            entity_type: "thread".to_string(),
+            payload,
+            actor_id: Some("research-1".to_string()),
+        }
+    }
+
+    fn created(thread_id: &str) -> SubmitEventRequest {
+        thread_event(
+            thread_id,
+            serde_json::json!({"ThreadCreated": {
+                "title": "Batch", "description": "", "parent_id": null
+            }}),
+        )
+    }
+
+    fn transition(thread_id: &str, from: &str, to: &str) -> SubmitEventRequest {
+        thread_event(
+            thread_id,
+            serde_json::json!({"ThreadStateChanged": {"from": from, "to": to, "reason": null}}),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_model_override_leaves_persona_unchanged() {
+        let state = Arc::new(AppState::new());
+        let stored = state.personas.get_by_str("scout").unwrap().model.clone();
+
+        let Json(effective) = resolve_persona_model(
+            State(state.clone()),
+            Path("scout".to_string()),
+            Json(ModelOverride {
+                temperature: Some(0.1),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap();
+        assert!((effective.temperature - 0.1).abs() < f64::EPSILON);
+        assert_eq!(effective.model, stored.model);
+
+        let persona = state.personas.get_by_str("scout").unwrap();
+        assert!((persona.model.temperature - stored.temperature).abs() < f64::EPSILON);
+
+        let (status, _) = resolve_persona_model(
+            State(state),
+            Path("scout".to_string()),
+            Json(ModelOverride {
+                model: Some("not-a-model".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_event_batch_applies_in_order() {
+        let state = Arc::new(AppState::new());
+        let id = ThreadId::new().to_string();
+
+        let (status, Json(response)) = submit_event_batch(
+            State(state.clone()),
+            Json(vec![
+                created(&id),
+                transition(&id, "Embryo", "Active"),
+                transition(&id, "Active", "Blocked"),
+            ]),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(response.success);
+        assert_eq!(response.applied, 3);
+        let sequences: Vec<_> = response.results.iter().map(|r| r.sequence).collect();
+        assert_eq!(sequences, vec![Some(1), Some(2), Some(3)]);
+
+        let coord = state.coordination.read().await;
+        assert_eq!(coord.get_thread(&id).unwrap().state, ThreadState::Blocked);
+    }
+
+    #[tokio::test]
+    async fn test_event_batch_rolls_back_on_invalid_transition() {
+        let state = Arc::new(AppState::new());
+        let id = ThreadId::new().to_string();
+
+        // Blocked -> Complete is not a valid transition
+        let (status, Json(response)) = submit_event_batch(
+            State(state.clone()),
+            Json(vec![
+                created(&id),
+                transition(&id, "Embryo", "Active"),
+                transition(&id, "Active", "Blocked"),
+                transition(&id, "Blocked", "Complete"),
+                transition(&id, "Blocked", "Active"),
+            ]),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(!response.success);
+        assert_eq!(response.applied, 0);
+        let statuses: Vec<_> = response.results.iter().map(|r| &r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                &BatchEventStatus::NotApplied,
+                &BatchEventStatus::NotApplied,
+                &BatchEventStatus::NotApplied,
+                &BatchEventStatus::Rejected,
+                &BatchEventStatus::NotApplied,
+            ]
+        );
+        assert!(response.results[3].error.is_some());
+
+        // Nothing from the batch was recorded
+        let coord = state.coordination.read().await;
+        assert!(coord.get_thread(&id).is_none());
+        assert_eq!(coord.current_sequence(), 0);
+    }
+}
@@ -4,6 +4,9 @@
 
 pub mod auth;
 pub mod http;
+pub mod metrics;
+pub mod openapi;
+pub mod reaper;
 pub mod socket;
 pub mod websocket;
 
@@ -18,6 +21,7 @@ use tokio::sync::RwLock;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 
+use impel_core::config::AgentConfig;
 use impel_core::coordination::CoordinationState;
 use impel_core::persona::PersonaRegistry;
 
@@ -84,6 +88,15 @@ impl AppState {
         })
     }
 
+    /// Use the given agent configuration (heartbeat timeout, claim limits)
+    pub fn with_agent_config(self, config: AgentConfig) -> Self {
+        let coordination = self.coordination.into_inner().with_agent_config(config);
+        Self {
+            coordination: RwLock::new(coordination),
+            ..self
+        }
+    }
+
     /// Save current state to persistence (if enabled)
     #[cfg(feature = "sqlite")]
     pub async fn save_state(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
@@ -131,6 +144,7 @@ pub fn create_router(state: Arc<AppState>) -> Router {
         .route("/agents", post(http::register_agent))
         .route("/agents/{id}", get(http::get_agent))
         .route("/agents/{id}", delete(http::terminate_agent))
+        .route("/agents/{id}/heartbeat", post(http::agent_heartbeat))
         .route("/agents/{id}/next-thread", get(http::get_next_thread))
         // Escalation endpoints
         .route("/escalations", get(http::list_escalations))
@@ -144,13 +158,17 @@ pub fn create_router(state: Arc<AppState>) -> Router {
         .route("/escalations/{id}/poll", get(http::poll_escalation))
         // Event endpoints
         .route("/events", post(http::submit_event))
+        .route("/events/batch", post(http::submit_event_batch))
         .route("/events", get(http::get_events))
         // Persona endpoints
         .route("/personas", get(http::list_personas))
         .route("/personas/{id}", get(http::get_persona))
+        .route("/personas/{id}/model", post(http::resolve_persona_model))
         // System endpoints
         .route("/constitution", get(http::get_constitution))
         .route("/status", get(http::get_status))
+        .route("/metrics", get(metrics::metrics_handler))
+        .route("/openapi.json", get(openapi::openapi_json))
         // WebSocket
         .route("/ws", get(websocket::ws_handler))
         // Middleware
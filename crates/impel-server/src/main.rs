@@ -3,8 +3,9 @@
 //! Standalone server for the impel agent API.
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use impel_server::{serve, AppState};
+use impel_server::{reaper, serve, AppState};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -12,6 +13,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing_subscriber::fmt::init();
 
     let state = Arc::new(AppState::new());
+    reaper::spawn_reaper(state.clone(), Duration::from_secs(30));
     let addr = std::env::var("IMPEL_ADDR").unwrap_or_else(|_| "127.0.0.1:8080".to_string());
 
     serve(&addr, state).await
@@ -0,0 +1,197 @@
+//! Prometheus metrics for the coordination state
+//!
+//! Served at `GET /metrics` in the Prometheus text exposition format. All
+//! values are computed from [`CoordinationState`] at scrape time.
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use axum::{extract::State, http::header, response::IntoResponse};
+
+use impel_core::agent::AgentStatus;
+use impel_core::coordination::CoordinationState;
+use impel_core::escalation::EscalationCategory;
+use impel_core::thread::ThreadState;
+
+use crate::AppState;
+
+/// Content type of the Prometheus text format
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Render coordination metrics in the Prometheus text format
+pub fn render_metrics(coord: &CoordinationState) -> String {
+    let mut out = String::new();
+
+    write_header(
+        &mut out,
+        "impel_threads",
+        "gauge",
+        "Number of threads by state",
+    );
+    for state in ThreadState::ALL {
+        let count = coord.threads_by_state(state).count();
+        write_sample(
+            &mut out,
+            "impel_threads",
+            Some(("state", &state.to_string())),
+            count,
+        );
+    }
+
+    write_header(
+        &mut out,
+        "impel_escalations_open",
+        "gauge",
+        "Number of open escalations by category",
+    );
+    let open = coord.open_escalations();
+    for category in EscalationCategory::ALL {
+        let count = open.iter().filter(|e| e.category == category).count();
+        write_sample(
+            &mut out,
+            "impel_escalations_open",
+            Some(("category", category.name())),
+            count,
+        );
+    }
+
+    write_header(
+        &mut out,
+        "impel_agents",
+        "gauge",
+        "Number of registered agents by status",
+    );
+    for status in AgentStatus::ALL {
+        write_sample(
+            &mut out,
+            "impel_agents",
+            Some(("status", &status.to_string())),
+            coord.agents().count_by_status(status),
+        );
+    }
+
+    write_header(
+        &mut out,
+        "impel_agents_active",
+        "gauge",
+        "Number of agents that are idle, working or paused",
+    );
+    let active = coord
+        .agents()
+        .all()
+        .filter(|a| {
+            matches!(
+                a.status,
+                AgentStatus::Idle | AgentStatus::Working | AgentStatus::Paused
+            )
+        })
+        .count();
+    write_sample(&mut out, "impel_agents_active", None, active);
+
+    write_header(
+        &mut out,
+        "impel_events_total",
+        "counter",
+        "Total events ingested",
+    );
+    write_sample(
+        &mut out,
+        "impel_events_total",
+        None,
+        coord.current_sequence(),
+    );
+
+    out
+}
+
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn write_sample(
+    out: &mut String,
+    name: &str,
+    label: Option<(&str, &str)>,
+    value: impl std::fmt::Display,
+) {
+    match label {
+        Some((key, label_value)) => {
+            let _ = writeln!(
+                out,
+                "{}{{{}=\"{}\"}} {}",
+                name,
+                key,
+                label_value.to_lowercase(),
+                value
+            );
+        }
+        None => {
+            let _ = writeln!(out, "{} {}", name, value);
+        }
+    }
+}
+
+/// Serve coordination metrics for Prometheus
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "system",
+    responses(
+        (status = 200, description = "Metrics in the Prometheus text format", body = String, content_type = "text/plain"),
+    )
+)]
+pub async fn metrics_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let coord = state.coordination.read().await;
+    (
+        [(header::CONTENT_TYPE, CONTENT_TYPE)],
+        render_metrics(&coord),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use impel_core::coordination::Command;
+
+    #[test]
+    fn test_metrics_reflect_state() {
+        let mut coord = CoordinationState::new();
+        for title in ["One", "Two", "Three"] {
+            Command::CreateThread {
+                title: title.to_string(),
+                description: String::new(),
+                parent_id: None,
+                priority: None,
+            }
+            .execute(&mut coord)
+            .unwrap();
+        }
+        let active = coord.threads().next().unwrap().id;
+        Command::ActivateThread { thread_id: active }
+            .execute(&mut coord)
+            .unwrap();
+
+        let output = render_metrics(&coord);
+        for name in [
+            "impel_threads",
+            "impel_escalations_open",
+            "impel_agents",
+            "impel_agents_active",
+            "impel_events_total",
+        ] {
+            assert!(output.contains(&format!("# TYPE {} ", name)), "{}", name);
+        }
+        assert!(output.contains("impel_threads{state=\"embryo\"} 2\n"));
+        assert!(output.contains("impel_threads{state=\"active\"} 1\n"));
+        assert!(output.contains("impel_threads{state=\"blocked\"} 0\n"));
+        assert!(output.contains("impel_escalations_open{category=\"stuck\"} 0\n"));
+        assert!(output.contains("impel_events_total 4\n"));
+
+        // Every non-comment line is `name{labels} value` with a numeric value
+        for line in output.lines().filter(|l| !l.starts_with('#')) {
+            let (_, value) = line.rsplit_once(' ').unwrap();
+            assert!(value.parse::<f64>().is_ok(), "{}", line);
+        }
+    }
+}
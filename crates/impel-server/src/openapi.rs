@@ -0,0 +1,124 @@
+//! OpenAPI description of the agent API
+//!
+//! Built from the `#[utoipa::path]` annotations on the handlers in
+//! [`crate::http`] and the schemas derived on their request and response
+//! types, so the document tracks the handlers themselves.
+
+use axum::Json;
+use utoipa::OpenApi;
+
+use crate::{http, metrics};
+
+/// OpenAPI document for the thread, agent, escalation, event, persona model
+/// and metrics endpoints
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "impel agent API"),
+    paths(
+        http::list_threads,
+        http::create_thread,
+        http::get_available_threads,
+        http::get_thread_detail,
+        http::claim_thread,
+        http::release_thread,
+        http::activate_thread,
+        http::block_thread,
+        http::unblock_thread,
+        http::submit_for_review,
+        http::complete_thread,
+        http::kill_thread,
+        http::set_thread_temperature,
+        http::get_thread_events,
+        http::list_agents,
+        http::register_agent,
+        http::get_agent,
+        http::terminate_agent,
+        http::agent_heartbeat,
+        http::get_next_thread,
+        http::list_escalations,
+        http::create_escalation,
+        http::get_escalation,
+        http::acknowledge_escalation,
+        http::resolve_escalation,
+        http::poll_escalation,
+        http::submit_event,
+        http::submit_event_batch,
+        http::get_events,
+        http::resolve_persona_model,
+        metrics::metrics_handler,
+        openapi_json,
+    ),
+    tags(
+        (name = "threads", description = "Research threads and their lifecycle"),
+        (name = "agents", description = "Agent registration and work assignment"),
+        (name = "escalations", description = "Questions and decisions for humans"),
+        (name = "events", description = "The coordination event log"),
+        (name = "personas", description = "Agent personas and their model configuration"),
+        (name = "system", description = "Monitoring and API description"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Serve the OpenAPI document
+#[utoipa::path(
+    get,
+    path = "/openapi.json",
+    tag = "system",
+    responses(
+        (status = 200, description = "This OpenAPI document", body = Object),
+    )
+)]
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_openapi_document_is_served() {
+        let app = crate::create_router(Arc::new(crate::AppState::new()));
+        let response = app
+            .oneshot(Request::get("/openapi.json").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let doc: utoipa::openapi::OpenApi = serde_json::from_slice(&bytes).unwrap();
+
+        let raw: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(raw["openapi"].as_str().unwrap().starts_with("3."));
+
+        let threads = &doc.paths.paths["/threads"];
+        let create = threads.post.as_ref().unwrap();
+        assert_eq!(create.operation_id.as_deref(), Some("create_thread"));
+        assert!(create.request_body.is_some());
+        assert!(threads.get.is_some());
+        assert!(doc.paths.paths.contains_key("/events/batch"));
+
+        let resolve = doc.paths.paths["/personas/{id}/model"]
+            .post
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            resolve.operation_id.as_deref(),
+            Some("resolve_persona_model")
+        );
+        assert!(resolve.request_body.is_some());
+        assert!(doc.paths.paths["/metrics"].get.is_some());
+        assert!(doc.paths.paths["/openapi.json"].get.is_some());
+        assert!(doc
+            .components
+            .as_ref()
+            .unwrap()
+            .schemas
+            .contains_key("PersonaModelResponse"));
+    }
+}
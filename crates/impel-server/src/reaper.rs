@@ -0,0 +1,102 @@
+//! Stale agent reaping
+//!
+//! Agents report liveness via `POST /agents/{id}/heartbeat`. An agent that
+//! dies never releases its claims, so a background task periodically marks
+//! agents silent past the configured heartbeat timeout offline and returns
+//! their threads to the available pool.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+
+use crate::AppState;
+
+/// Run one reaping pass, returning the IDs of agents marked offline
+pub async fn reap_stale_agents(state: &AppState) -> Vec<String> {
+    let mut coord = state.coordination.write().await;
+    match coord.reap_stale_agents(chrono::Utc::now()) {
+        Ok(reaped) => {
+            for agent_id in &reaped {
+                tracing::warn!("Agent {} missed heartbeats, marked offline", agent_id);
+            }
+            reaped
+        }
+        Err(e) => {
+            tracing::error!("Failed to reap stale agents: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Spawn a background task that reaps stale agents every `interval`
+pub fn spawn_reaper(state: Arc<AppState>, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            reap_stale_agents(&state).await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use impel_core::agent::{AgentStatus, AgentType};
+    use impel_core::coordination::Command;
+    use impel_core::thread::ThreadId;
+
+    #[tokio::test]
+    async fn test_reaper_releases_threads_of_stale_agent() {
+        let state = AppState::new();
+        let thread_id = {
+            let mut coord = state.coordination.write().await;
+            Command::RegisterAgent {
+                agent_id: "research-1".to_string(),
+                agent_type: AgentType::Research,
+            }
+            .execute(&mut coord)
+            .unwrap();
+            let events = Command::CreateThread {
+                title: "Orphaned".to_string(),
+                description: String::new(),
+                parent_id: None,
+                priority: None,
+            }
+            .execute(&mut coord)
+            .unwrap();
+            let thread_id = ThreadId::parse(&events[0].entity_id).unwrap();
+            Command::ActivateThread { thread_id }
+                .execute(&mut coord)
+                .unwrap();
+            Command::ClaimThread {
+                thread_id,
+                agent_id: "research-1".to_string(),
+            }
+            .execute(&mut coord)
+            .unwrap();
+            thread_id
+        };
+
+        // A live agent keeps its claim
+        assert!(reap_stale_agents(&state).await.is_empty());
+
+        // Agent goes silent past the default timeout
+        {
+            let mut coord = state.coordination.write().await;
+            let agent = coord.agents_mut().get_mut("research-1").unwrap();
+            agent.last_heartbeat -= chrono::Duration::hours(1);
+        }
+        assert_eq!(reap_stale_agents(&state).await, vec!["research-1"]);
+
+        let coord = state.coordination.read().await;
+        let thread = coord.get_thread(&thread_id.to_string()).unwrap();
+        assert!(thread.claimed_by.is_none());
+        assert!(coord.available_threads().any(|t| t.id == thread_id));
+        assert_eq!(
+            coord.agents().get("research-1").unwrap().status,
+            AgentStatus::Offline
+        );
+    }
+}
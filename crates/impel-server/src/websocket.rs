@@ -1,42 +1,288 @@
 //! WebSocket handler for real-time updates
 //!
-//! Note: WebSocket support in axum 0.8 requires the axum-extra crate.
-//! For now, this module provides a placeholder that returns the status.
+//! Protocol:
+//!
+//! 1. On connect the server sends a `status` message.
+//! 2. The client sends `{"type": "subscribe", "last_event_id": "<uuid>"}`.
+//!    `last_event_id` is the last event the client saw before disconnecting;
+//!    omit it to receive only events recorded from now on.
+//! 3. The server answers with `subscribed`, replays the events recorded after
+//!    the cursor, then streams new events live as `event` messages.
+//!
+//! At most [`MAX_REPLAY`] events are replayed. A client further behind gets
+//! the most recent ones with `truncated: true` and should resync its state
+//! over HTTP. An unknown cursor gets an `error` message and the connection
+//! is closed.
 
 use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::IntoResponse,
+};
+use serde::Deserialize;
 
-use axum::{extract::State, response::Json};
+use impel_core::event::{Event, EventId};
 
 use crate::AppState;
 
-/// WebSocket upgrade handler (placeholder - returns current status)
-///
-/// To enable full WebSocket support, add axum-extra with websocket feature.
-pub async fn ws_handler(State(state): State<Arc<AppState>>) -> Json<serde_json::Value> {
-    // Return current status as JSON for now
-    // Full WebSocket support would require axum-extra
-    let coord = state.coordination.read().await;
-    let status = serde_json::json!({
-        "type": "status",
-        "message": "WebSocket endpoint - use HTTP polling or SSE as alternative",
-        "paused": coord.is_paused(),
-        "thread_count": coord.threads().count(),
-        "sequence": coord.current_sequence()
+/// Maximum number of missed events replayed on resume
+pub const MAX_REPLAY: usize = 1000;
+
+/// How often the event log is checked for new events to stream
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// First message a client sends after connecting
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ClientMessage {
+    Subscribe { last_event_id: Option<String> },
+}
+
+/// WebSocket upgrade handler
+pub async fn ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+async fn handle_socket(mut socket: WebSocket, state: Arc<AppState>) {
+    let status = {
+        let coord = state.coordination.read().await;
+        serde_json::json!({
+            "type": "status",
+            "paused": coord.is_paused(),
+            "thread_count": coord.threads().count(),
+            "sequence": coord.current_sequence()
+        })
+    };
+    if send_json(&mut socket, &status).await.is_err() {
+        return;
+    }
+
+    let last_event_id = match socket.recv().await {
+        Some(Ok(Message::Text(text))) => match serde_json::from_str(&text) {
+            Ok(ClientMessage::Subscribe { last_event_id }) => last_event_id,
+            Err(e) => {
+                let message = format!("Invalid subscribe message: {}", e);
+                let _ = send_error(&mut socket, &message).await;
+                return;
+            }
+        },
+        _ => return,
+    };
+
+    let Some(mut cursor) = replay(&mut socket, &state, last_event_id.as_deref()).await else {
+        return;
+    };
+
+    // Stream live events until the client goes away
+    let mut ticker = tokio::time::interval(POLL_INTERVAL);
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                let events: Vec<Event> = {
+                    let coord = state.coordination.read().await;
+                    coord.events_since(cursor).into_iter().cloned().collect()
+                };
+                for event in &events {
+                    if send_json(&mut socket, &event_message(event)).await.is_err() {
+                        return;
+                    }
+                    cursor = event.sequence;
+                }
+            }
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                Some(Ok(_)) => {}
+            }
+        }
+    }
+}
+
+/// Replay events missed since `last_event_id`, returning the sequence to
+/// stream from, or `None` if the connection should be closed
+async fn replay(
+    socket: &mut WebSocket,
+    state: &AppState,
+    last_event_id: Option<&str>,
+) -> Option<u64> {
+    let (missed, current) = {
+        let coord = state.coordination.read().await;
+        let current = coord.current_sequence();
+        let missed = match last_event_id {
+            None => Some(Vec::new()),
+            Some(id) => uuid::Uuid::parse_str(id).ok().and_then(|uuid| {
+                coord
+                    .events_after_id(&EventId::from_uuid(uuid))
+                    .map(|events| events.into_iter().cloned().collect::<Vec<_>>())
+            }),
+        };
+        (missed, current)
+    };
+
+    let Some(missed) = missed else {
+        let _ = send_error(socket, "Unknown last_event_id; resync over HTTP").await;
+        return None;
+    };
+
+    let truncated = missed.len() > MAX_REPLAY;
+    let replayed = &missed[missed.len().saturating_sub(MAX_REPLAY)..];
+    let ack = serde_json::json!({
+        "type": "subscribed",
+        "replayed": replayed.len(),
+        "missed": missed.len(),
+        "truncated": truncated
     });
-    Json(status)
-}
-
-// The following would be used with axum-extra WebSocket support:
-//
-// use axum_extra::extract::ws::{Message, WebSocket, WebSocketUpgrade};
-//
-// pub async fn ws_handler(
-//     ws: WebSocketUpgrade,
-//     State(state): State<Arc<AppState>>,
-// ) -> impl IntoResponse {
-//     ws.on_upgrade(move |socket| handle_socket(socket, state))
-// }
-//
-// async fn handle_socket(mut socket: WebSocket, state: Arc<AppState>) {
-//     // ... WebSocket handling code
-// }
+    send_json(socket, &ack).await.ok()?;
+
+    for event in replayed {
+        send_json(socket, &event_message(event)).await.ok()?;
+    }
+
+    Some(replayed.last().map_or(current, |e| e.sequence))
+}
+
+fn event_message(event: &Event) -> serde_json::Value {
+    serde_json::json!({
+        "type": "event",
+        "id": event.id.to_string(),
+        "sequence": event.sequence,
+        "timestamp": event.timestamp.to_rfc3339(),
+        "entity_id": event.entity_id,
+        "entity_type": event.entity_type.to_string(),
+        "actor_id": event.actor_id,
+        "description": event.payload.description(),
+        "payload": event.payload
+    })
+}
+
+async fn send_json(socket: &mut WebSocket, value: &serde_json::Value) -> Result<(), axum::Error> {
+    socket.send(Message::Text(value.to_string().into())).await
+}
+
+async fn send_error(socket: &mut WebSocket, message: &str) -> Result<(), axum::Error> {
+    send_json(
+        socket,
+        &serde_json::json!({ "type": "error", "message": message }),
+    )
+    .await?;
+    socket.send(Message::Close(None)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::{SinkExt, StreamExt};
+    use impel_core::coordination::Command;
+    use tokio_tungstenite::tungstenite;
+
+    type Client = tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >;
+
+    async fn start_server(state: Arc<AppState>) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, crate::create_router(state))
+                .await
+                .unwrap();
+        });
+        format!("ws://{}/ws", addr)
+    }
+
+    async fn next_json(client: &mut Client) -> serde_json::Value {
+        loop {
+            let message = tokio::time::timeout(Duration::from_secs(5), client.next())
+                .await
+                .expect("message within timeout")
+                .unwrap()
+                .unwrap();
+            if let tungstenite::Message::Text(text) = message {
+                return serde_json::from_str(&text).unwrap();
+            }
+        }
+    }
+
+    async fn subscribe(url: &str, last_event_id: Option<&str>) -> (Client, serde_json::Value) {
+        let (mut client, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+        assert_eq!(next_json(&mut client).await["type"], "status");
+        let request = serde_json::json!({ "type": "subscribe", "last_event_id": last_event_id });
+        client
+            .send(tungstenite::Message::Text(request.to_string().into()))
+            .await
+            .unwrap();
+        let ack = next_json(&mut client).await;
+        assert_eq!(ack["type"], "subscribed");
+        (client, ack)
+    }
+
+    async fn create_thread(state: &AppState, title: &str) {
+        let mut coord = state.coordination.write().await;
+        Command::CreateThread {
+            title: title.to_string(),
+            description: String::new(),
+            parent_id: None,
+            priority: None,
+        }
+        .execute(&mut coord)
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replays_missed_events() {
+        let state = Arc::new(AppState::new());
+        let url = start_server(state.clone()).await;
+
+        // Live streaming before the disconnect
+        let (mut client, _) = subscribe(&url, None).await;
+        create_thread(&state, "First").await;
+        let seen = next_json(&mut client).await;
+        assert_eq!(seen["type"], "event");
+        let cursor = seen["id"].as_str().unwrap().to_string();
+        client.close(None).await.unwrap();
+
+        // Events recorded while disconnected
+        for title in ["Second", "Third", "Fourth"] {
+            create_thread(&state, title).await;
+        }
+
+        let (mut client, ack) = subscribe(&url, Some(&cursor)).await;
+        assert_eq!(ack["replayed"], 3);
+        assert_eq!(ack["truncated"], false);
+        let mut sequences = Vec::new();
+        for _ in 0..3 {
+            let event = next_json(&mut client).await;
+            sequences.push(event["sequence"].as_u64().unwrap());
+        }
+        assert_eq!(sequences, vec![2, 3, 4]);
+
+        // Then back to live streaming, without duplicates
+        create_thread(&state, "Fifth").await;
+        assert_eq!(next_json(&mut client).await["sequence"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_cursor_is_rejected() {
+        let state = Arc::new(AppState::new());
+        let url = start_server(state).await;
+
+        let (mut client, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+        next_json(&mut client).await;
+        let request = serde_json::json!({
+            "type": "subscribe",
+            "last_event_id": uuid::Uuid::new_v4().to_string()
+        });
+        client
+            .send(tungstenite::Message::Text(request.to_string().into()))
+            .await
+            .unwrap();
+        assert_eq!(next_json(&mut client).await["type"], "error");
+    }
+}
@@ -8,9 +8,14 @@
 //! - `implore://export?format=pdf&dpi=300&width=1200`
 //! - `implore://share?session=xyz&email=user@example.com`
 //! - `implore://insert-figure?imprint_document=abc&session=xyz`
+//! - `implore://export-figure?figure=fig123&imprint_document=abc&format=pdf`
 //! - `implore://link-publication?dataset=ds1&publication=pub123`
+//! - `implore://select?expr=x%20%3E%200&register=a`
+//! - `implore://view?mode=box_3d&colormap=plasma&position=3,3,3&target=0,0,0`
 
 use crate::session::FigureFormat;
+use crate::types::Vec3f;
+use crate::view::{ArtShaderConfig, Box3DConfig, Camera3D, RenderMode, Science2DConfig, ViewState};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
@@ -61,6 +66,10 @@ pub enum ImploreCommand {
     /// `implore://insert-figure?imprint_document=abc&session=xyz`
     InsertFigure(InsertFigureCommand),
 
+    /// Export a library figure into an imprint document
+    /// `implore://export-figure?figure=fig123&imprint_document=abc&format=pdf`
+    ExportFigure(ExportFigureToDocumentCommand),
+
     /// Link dataset to imbib publication
     /// `implore://link-publication?dataset=ds1&publication=pub123`
     LinkPublication(LinkPublicationCommand),
@@ -89,6 +98,14 @@ pub enum ImploreCommand {
     /// `implore://library`
     OpenLibrary,
 
+    /// Apply a selection expression
+    /// `implore://select?expr=x%20%3E%200&register=a`
+    Select(SelectCommand),
+
+    /// Restore a view (camera, colormap, render mode)
+    /// `implore://view?mode=box_3d&colormap=plasma&position=3,3,3&target=0,0,0`
+    View(ViewCommand),
+
     /// Unknown command (for forward compatibility)
     Unknown(String),
 }
@@ -186,6 +203,19 @@ pub struct InsertFigureCommand {
     pub figure_id: Option<String>,
 }
 
+/// Export a library figure into an imprint document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportFigureToDocumentCommand {
+    /// Library figure ID
+    pub figure_id: String,
+
+    /// Target imprint document ID
+    pub imprint_document_id: String,
+
+    /// Export format
+    pub format: FigureFormat,
+}
+
 /// Link dataset to imbib publication
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LinkPublicationCommand {
@@ -236,6 +266,42 @@ pub struct UnlinkFigureCommand {
     pub document_id: String,
 }
 
+/// Apply a selection expression command
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelectCommand {
+    /// Selection expression (implore-selection grammar)
+    pub expression: String,
+
+    /// Register to store the resulting selection in
+    pub register: Option<String>,
+}
+
+/// Restore a view command
+///
+/// Parameters missing from the URL take the mode's
+/// [`ViewState::defaults_for`]. Render modes carry their axis fields (and
+/// shader name for art mode); other mode settings use their defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewCommand {
+    /// Camera to restore
+    pub camera: Camera3D,
+
+    /// Colormap name
+    pub colormap: String,
+
+    /// Render mode
+    pub render_mode: RenderMode,
+}
+
+impl ViewCommand {
+    /// Apply this view to a view state, leaving unrelated settings alone
+    pub fn apply(&self, state: &mut ViewState) {
+        state.camera = self.camera.clone();
+        state.color_mapping.colormap = self.colormap.clone();
+        state.mode = self.render_mode.clone();
+    }
+}
+
 impl ImploreCommand {
     /// Parse a command from a URL string
     pub fn parse(url_str: &str) -> AutomationResult<Self> {
@@ -259,6 +325,7 @@ impl ImploreCommand {
             "export" => Self::parse_export(params),
             "share" => Self::parse_share(params),
             "insert-figure" => Self::parse_insert_figure(params),
+            "export-figure" => Self::parse_export_figure(params),
             "link-publication" => Self::parse_link_publication(params),
             "sync" => Self::parse_sync(params),
             "generate" => Self::parse_generate(params),
@@ -266,6 +333,8 @@ impl ImploreCommand {
             "sync-figure" => Self::parse_sync_figure(params),
             "unlink-figure" => Self::parse_unlink_figure(params),
             "library" => Ok(ImploreCommand::OpenLibrary),
+            "select" => Self::parse_select(params),
+            "view" => Self::parse_view(params),
             _ => Ok(ImploreCommand::Unknown(url_str.to_string())),
         }
     }
@@ -310,22 +379,8 @@ impl ImploreCommand {
             .get("format")
             .ok_or_else(|| AutomationError::MissingParameter("format".to_string()))?;
 
-        let format = match format_str.as_str() {
-            "png" => FigureFormat::Png,
-            "pdf" => FigureFormat::Pdf,
-            "svg" => FigureFormat::Svg,
-            "eps" => FigureFormat::Eps,
-            "typst" | "typ" => FigureFormat::Typst,
-            _ => {
-                return Err(AutomationError::InvalidValue(format!(
-                    "Unknown format: {}",
-                    format_str
-                )))
-            }
-        };
-
         Ok(ImploreCommand::Export(ExportFigureCommand {
-            format,
+            format: parse_figure_format(format_str)?,
             dpi: params.get("dpi").and_then(|s| s.parse().ok()),
             width: params.get("width").and_then(|s| s.parse().ok()),
             height: params.get("height").and_then(|s| s.parse().ok()),
@@ -366,6 +421,30 @@ impl ImploreCommand {
         }))
     }
 
+    fn parse_export_figure(params: HashMap<String, String>) -> AutomationResult<Self> {
+        let figure_id = params
+            .get("figure")
+            .ok_or_else(|| AutomationError::MissingParameter("figure".to_string()))?
+            .clone();
+
+        let imprint_document_id = params
+            .get("imprint_document")
+            .ok_or_else(|| AutomationError::MissingParameter("imprint_document".to_string()))?
+            .clone();
+
+        let format_str = params
+            .get("format")
+            .ok_or_else(|| AutomationError::MissingParameter("format".to_string()))?;
+
+        Ok(ImploreCommand::ExportFigure(
+            ExportFigureToDocumentCommand {
+                figure_id,
+                imprint_document_id,
+                format: parse_figure_format(format_str)?,
+            },
+        ))
+    }
+
     fn parse_link_publication(params: HashMap<String, String>) -> AutomationResult<Self> {
         let dataset_id = params
             .get("dataset")
@@ -446,6 +525,143 @@ impl ImploreCommand {
             document_id,
         }))
     }
+
+    fn parse_select(params: HashMap<String, String>) -> AutomationResult<Self> {
+        let expression = params
+            .get("expr")
+            .ok_or_else(|| AutomationError::MissingParameter("expr".to_string()))?
+            .clone();
+
+        Ok(ImploreCommand::Select(SelectCommand {
+            expression,
+            register: params.get("register").cloned(),
+        }))
+    }
+
+    fn parse_view(params: HashMap<String, String>) -> AutomationResult<Self> {
+        let render_mode = match params.get("mode").map(String::as_str) {
+            None => RenderMode::default(),
+            Some("science_2d") => {
+                let mut config = Science2DConfig::default();
+                if let Some(x) = params.get("x") {
+                    config.x_field = x.clone();
+                }
+                if let Some(y) = params.get("y") {
+                    config.y_field = y.clone();
+                }
+                RenderMode::Science2D(config)
+            }
+            Some("box_3d") => {
+                let mut config = Box3DConfig::default();
+                if let Some(x) = params.get("x") {
+                    config.x_field = x.clone();
+                }
+                if let Some(y) = params.get("y") {
+                    config.y_field = y.clone();
+                }
+                if let Some(z) = params.get("z") {
+                    config.z_field = z.clone();
+                }
+                RenderMode::Box3D(config)
+            }
+            Some("art_shader") => {
+                let mut config = ArtShaderConfig::default();
+                if let Some(shader) = params.get("shader") {
+                    config.shader_name = shader.clone();
+                }
+                RenderMode::ArtShader(config)
+            }
+            Some(other) => {
+                return Err(AutomationError::InvalidValue(format!(
+                    "Unknown mode: {}",
+                    other
+                )))
+            }
+        };
+
+        let defaults = ViewState::defaults_for(render_mode.clone());
+
+        let mut camera = defaults.camera;
+        if let Some(v) = params.get("position") {
+            camera.position = parse_vec3(v)?;
+        }
+        if let Some(v) = params.get("target") {
+            camera.target = parse_vec3(v)?;
+        }
+        if let Some(v) = params.get("up") {
+            camera.up = parse_vec3(v)?;
+        }
+        if let Some(v) = params.get("fov") {
+            camera.fov = parse_f32("fov", v)?;
+        }
+        if let Some(v) = params.get("near") {
+            camera.near = parse_f32("near", v)?;
+        }
+        if let Some(v) = params.get("far") {
+            camera.far = parse_f32("far", v)?;
+        }
+        if let Some(v) = params.get("ortho_scale") {
+            camera.ortho_scale = parse_f32("ortho_scale", v)?;
+        }
+        if let Some(v) = params.get("projection") {
+            camera.orthographic = match v.as_str() {
+                "orthographic" => true,
+                "perspective" => false,
+                _ => {
+                    return Err(AutomationError::InvalidValue(format!(
+                        "Unknown projection: {}",
+                        v
+                    )))
+                }
+            };
+        }
+
+        Ok(ImploreCommand::View(ViewCommand {
+            camera,
+            colormap: params
+                .get("colormap")
+                .cloned()
+                .unwrap_or(defaults.color_mapping.colormap),
+            render_mode,
+        }))
+    }
+}
+
+/// Parse a figure format name (`typst` and `typ` are both accepted)
+fn parse_figure_format(value: &str) -> AutomationResult<FigureFormat> {
+    match value {
+        "png" => Ok(FigureFormat::Png),
+        "pdf" => Ok(FigureFormat::Pdf),
+        "svg" => Ok(FigureFormat::Svg),
+        "eps" => Ok(FigureFormat::Eps),
+        "typst" | "typ" => Ok(FigureFormat::Typst),
+        _ => Err(AutomationError::InvalidValue(format!(
+            "Unknown format: {}",
+            value
+        ))),
+    }
+}
+
+fn parse_f32(name: &str, value: &str) -> AutomationResult<f32> {
+    value
+        .parse()
+        .map_err(|_| AutomationError::InvalidValue(format!("{}: {}", name, value)))
+}
+
+/// Parse `x,y,z` into a vector
+fn parse_vec3(value: &str) -> AutomationResult<Vec3f> {
+    let parts: Vec<&str> = value.split(',').collect();
+    match parts.as_slice() {
+        [x, y, z] => Ok(Vec3f::new(
+            parse_f32("x", x.trim())?,
+            parse_f32("y", y.trim())?,
+            parse_f32("z", z.trim())?,
+        )),
+        _ => Err(AutomationError::InvalidValue(format!(
+            "Expected x,y,z: {}",
+            value
+        ))),
+    }
 }
 
 // URL builders for constructing URLs programmatically
@@ -505,6 +721,20 @@ pub fn build_insert_figure_url(
     url
 }
 
+/// Build an export-figure URL
+pub fn build_export_figure_url(
+    figure_id: &str,
+    imprint_document_id: &str,
+    format: FigureFormat,
+) -> String {
+    format!(
+        "implore://export-figure?figure={}&imprint_document={}&format={}",
+        urlencoding::encode(figure_id),
+        urlencoding::encode(imprint_document_id),
+        format.extension()
+    )
+}
+
 /// Build a link-publication URL
 pub fn build_link_publication_url(dataset_id: &str, publication_id: &str) -> String {
     format!(
@@ -558,6 +788,55 @@ pub fn build_open_library_url() -> String {
     "implore://library".to_string()
 }
 
+/// Build a select URL
+pub fn build_select_url(expression: &str, register: Option<&str>) -> String {
+    let mut url = format!("implore://select?expr={}", urlencoding::encode(expression));
+    if let Some(r) = register {
+        url.push_str(&format!("&register={}", urlencoding::encode(r)));
+    }
+    url
+}
+
+/// Build a view URL capturing the camera, colormap, and render mode
+pub fn build_view_url(camera: &Camera3D, colormap: &str, render_mode: &RenderMode) -> String {
+    let vec3 = |v: &Vec3f| format!("{},{},{}", v.x, v.y, v.z);
+
+    let mut url = match render_mode {
+        RenderMode::Science2D(config) => format!(
+            "implore://view?mode=science_2d&x={}&y={}",
+            urlencoding::encode(&config.x_field),
+            urlencoding::encode(&config.y_field)
+        ),
+        RenderMode::Box3D(config) => format!(
+            "implore://view?mode=box_3d&x={}&y={}&z={}",
+            urlencoding::encode(&config.x_field),
+            urlencoding::encode(&config.y_field),
+            urlencoding::encode(&config.z_field)
+        ),
+        RenderMode::ArtShader(config) => format!(
+            "implore://view?mode=art_shader&shader={}",
+            urlencoding::encode(&config.shader_name)
+        ),
+    };
+    url.push_str(&format!(
+        "&colormap={}&position={}&target={}&up={}&fov={}&near={}&far={}&projection={}&ortho_scale={}",
+        urlencoding::encode(colormap),
+        vec3(&camera.position),
+        vec3(&camera.target),
+        vec3(&camera.up),
+        camera.fov,
+        camera.near,
+        camera.far,
+        if camera.orthographic {
+            "orthographic"
+        } else {
+            "perspective"
+        },
+        camera.ortho_scale
+    ));
+    url
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -634,6 +913,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_roundtrip_export_figure() {
+        for format in [
+            FigureFormat::Png,
+            FigureFormat::Pdf,
+            FigureFormat::Svg,
+            FigureFormat::Eps,
+            FigureFormat::Typst,
+        ] {
+            let url = build_export_figure_url("fig 1", "doc/2", format);
+            match ImploreCommand::parse(&url).unwrap() {
+                ImploreCommand::ExportFigure(export) => {
+                    assert_eq!(export.figure_id, "fig 1");
+                    assert_eq!(export.imprint_document_id, "doc/2");
+                    assert_eq!(export.format, format);
+                }
+                _ => panic!("Roundtrip failed for export-figure"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_export_figure_unknown_format() {
+        let url = "implore://export-figure?figure=fig1&imprint_document=doc1&format=gif";
+        let result = ImploreCommand::parse(url);
+        assert!(matches!(result, Err(AutomationError::InvalidValue(_))));
+
+        let url = "implore://export-figure?figure=fig1&imprint_document=doc1";
+        let result = ImploreCommand::parse(url);
+        assert!(matches!(result, Err(AutomationError::MissingParameter(_))));
+    }
+
     #[test]
     fn test_build_open_url() {
         let url = build_open_url("/data/test.hdf5", Some("/group/dataset"), None);
@@ -649,6 +960,69 @@ mod tests {
         assert!(url.contains("dpi=300"));
     }
 
+    #[test]
+    fn test_roundtrip_select() {
+        let url = build_select_url("x > 0 & @a", Some("b"));
+        assert!(url.starts_with("implore://select?expr=x%20%3E%200"));
+
+        match ImploreCommand::parse(&url).unwrap() {
+            ImploreCommand::Select(select) => {
+                assert_eq!(select.expression, "x > 0 & @a");
+                assert_eq!(select.register, Some("b".to_string()));
+            }
+            _ => panic!("Roundtrip failed for select"),
+        }
+
+        let url = build_select_url("all", None);
+        match ImploreCommand::parse(&url).unwrap() {
+            ImploreCommand::Select(select) => assert_eq!(select.register, None),
+            _ => panic!("Roundtrip failed for select"),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_view() {
+        let mut state = ViewState::default();
+        state.camera.orbit(0.3, -0.2);
+        state.camera.orthographic = true;
+        state.color_mapping.colormap = "plasma".to_string();
+        state.mode = RenderMode::Box3D(Box3DConfig {
+            x_field: "pos x".to_string(),
+            ..Default::default()
+        });
+
+        let url = state.to_url();
+        match ImploreCommand::parse(&url).unwrap() {
+            ImploreCommand::View(view) => {
+                assert_eq!(view.camera, state.camera);
+                assert_eq!(view.colormap, "plasma");
+                assert_eq!(view.render_mode, state.mode);
+
+                let mut restored = ViewState::default();
+                view.apply(&mut restored);
+                assert_eq!(restored.to_url(), url);
+            }
+            _ => panic!("Roundtrip failed for view"),
+        }
+    }
+
+    #[test]
+    fn test_parse_view_defaults() {
+        let cmd = ImploreCommand::parse("implore://view?colormap=magma").unwrap();
+        let defaults = ViewState::default();
+        match cmd {
+            ImploreCommand::View(view) => {
+                assert_eq!(view.colormap, "magma");
+                assert_eq!(view.camera, defaults.camera);
+                assert_eq!(view.render_mode, defaults.mode);
+            }
+            _ => panic!("Expected View command"),
+        }
+
+        let result = ImploreCommand::parse("implore://view?position=1,2");
+        assert!(matches!(result, Err(AutomationError::InvalidValue(_))));
+    }
+
     #[test]
     fn test_unknown_command() {
         let url = "implore://unknown-command?foo=bar";
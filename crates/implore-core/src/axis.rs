@@ -26,14 +26,136 @@ impl AxisPosition {
     }
 }
 
-/// Scale type for axis
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub enum ScaleType {
+/// Axis scale type
+///
+/// Also reads the names used by the old `ScaleType` and view `AxisScale`
+/// enums (`"Log10"`, and `"SymLog"` without a threshold), so saved views
+/// keep loading.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "StoredAxisScale")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AxisScale {
+    #[default]
+    Linear,
+    /// Base-10 logarithm; only positive values are representable
+    Log,
+    /// Base-2 logarithm
+    Log2,
+    /// Symmetric log: linear within `[-linthresh, linthresh]`, logarithmic
+    /// beyond, so data spanning zero and many decades stays readable
+    SymLog { linthresh: f64 },
+}
+
+/// Former name of [`AxisScale`]
+#[deprecated(note = "use AxisScale")]
+pub type ScaleType = AxisScale;
+
+/// Serialized forms of [`AxisScale`], current and legacy
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredAxisScale {
+    Named(NamedAxisScale),
+    SymLog {
+        #[serde(rename = "SymLog")]
+        symlog: SymLogThreshold,
+    },
+}
+
+#[derive(Deserialize)]
+enum NamedAxisScale {
     Linear,
-    Log10,
+    #[serde(alias = "Log10")]
+    Log,
+    Log2,
+    /// Saved before symlog had a threshold
     SymLog,
 }
 
+#[derive(Deserialize)]
+struct SymLogThreshold {
+    linthresh: f64,
+}
+
+impl From<StoredAxisScale> for AxisScale {
+    fn from(stored: StoredAxisScale) -> Self {
+        match stored {
+            StoredAxisScale::Named(NamedAxisScale::Linear) => AxisScale::Linear,
+            StoredAxisScale::Named(NamedAxisScale::Log) => AxisScale::Log,
+            StoredAxisScale::Named(NamedAxisScale::Log2) => AxisScale::Log2,
+            StoredAxisScale::Named(NamedAxisScale::SymLog) => AxisScale::symlog(1.0),
+            StoredAxisScale::SymLog { symlog } => AxisScale::symlog(symlog.linthresh),
+        }
+    }
+}
+
+impl AxisScale {
+    /// Create a symmetric log scale; non-positive thresholds fall back to 1.0
+    pub fn symlog(linthresh: f64) -> Self {
+        let linthresh = if linthresh > 0.0 { linthresh } else { 1.0 };
+        AxisScale::SymLog { linthresh }
+    }
+
+    /// Whether this is a pure logarithmic scale (positive values only)
+    pub fn is_log(&self) -> bool {
+        matches!(self, AxisScale::Log | AxisScale::Log2)
+    }
+
+    /// Transform a value according to this scale
+    ///
+    /// Log scales return NaN for non-positive values.
+    pub fn transform(&self, value: f64) -> f64 {
+        match self {
+            AxisScale::Linear => value,
+            AxisScale::Log if value > 0.0 => value.log10(),
+            AxisScale::Log2 if value > 0.0 => value.log2(),
+            AxisScale::Log | AxisScale::Log2 => f64::NAN,
+            AxisScale::SymLog { linthresh } => {
+                if value.abs() <= *linthresh {
+                    value / linthresh
+                } else {
+                    value.signum() * (1.0 + (value.abs() / linthresh).log10())
+                }
+            }
+        }
+    }
+
+    /// Inverse transform
+    pub fn inverse(&self, value: f64) -> f64 {
+        match self {
+            AxisScale::Linear => value,
+            AxisScale::Log => 10.0_f64.powf(value),
+            AxisScale::Log2 => 2.0_f64.powf(value),
+            AxisScale::SymLog { linthresh } => {
+                if value.abs() <= 1.0 {
+                    value * linthresh
+                } else {
+                    value.signum() * linthresh * 10.0_f64.powf(value.abs() - 1.0)
+                }
+            }
+        }
+    }
+
+    /// Map a data value to normalized coordinates (0.0 at `min`, 1.0 at `max`)
+    ///
+    /// Returns 0.5 for an empty range and NaN for values the scale cannot
+    /// represent (e.g. non-positive values on a log scale).
+    pub fn normalize(&self, value: f64, min: f64, max: f64) -> f64 {
+        let t_min = self.transform(min);
+        let range = self.transform(max) - t_min;
+        if range == 0.0 {
+            return 0.5;
+        }
+        (self.transform(value) - t_min) / range
+    }
+
+    /// Map normalized coordinates back to a data value
+    pub fn denormalize(&self, t: f64, min: f64, max: f64) -> f64 {
+        let t_min = self.transform(min);
+        let t_max = self.transform(max);
+        self.inverse(t_min + t * (t_max - t_min))
+    }
+}
+
 /// Configuration for an axis
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AxisConfig {
@@ -41,7 +163,7 @@ pub struct AxisConfig {
     pub position: AxisPosition,
 
     /// Scale type
-    pub scale: ScaleType,
+    pub scale: AxisScale,
 
     /// Data range
     pub min: f64,
@@ -80,7 +202,7 @@ impl AxisConfig {
     pub fn new(position: AxisPosition, min: f64, max: f64) -> Self {
         Self {
             position,
-            scale: ScaleType::Linear,
+            scale: AxisScale::Linear,
             min,
             max,
             label: None,
@@ -109,7 +231,13 @@ impl AxisConfig {
 
     /// Set logarithmic scale
     pub fn with_log_scale(mut self) -> Self {
-        self.scale = ScaleType::Log10;
+        self.scale = AxisScale::Log;
+        self
+    }
+
+    /// Set symmetric log scale with the given linear threshold
+    pub fn with_symlog_scale(mut self, linthresh: f64) -> Self {
+        self.scale = AxisScale::symlog(linthresh);
         self
     }
 
@@ -125,6 +253,16 @@ impl AxisConfig {
         self
     }
 
+    /// Map a data value to normalized axis coordinates using the axis scale
+    pub fn normalize(&self, value: f64) -> f64 {
+        self.scale.normalize(value, self.min, self.max)
+    }
+
+    /// Map normalized axis coordinates back to a data value
+    pub fn denormalize(&self, t: f64) -> f64 {
+        self.scale.denormalize(t, self.min, self.max)
+    }
+
     /// Get the full label with unit
     pub fn full_label(&self) -> Option<String> {
         match (&self.label, &self.unit) {
@@ -161,9 +299,10 @@ pub struct TickMark {
 /// Calculate tick marks for an axis
 pub fn calculate_ticks(config: &AxisConfig) -> Vec<TickMark> {
     match config.scale {
-        ScaleType::Linear => calculate_linear_ticks(config),
-        ScaleType::Log10 => calculate_log_ticks(config),
-        ScaleType::SymLog => calculate_symlog_ticks(config),
+        AxisScale::Linear => calculate_linear_ticks(config),
+        AxisScale::Log => calculate_log_ticks(config),
+        AxisScale::Log2 => calculate_log2_ticks(config),
+        AxisScale::SymLog { linthresh } => calculate_symlog_ticks(config, linthresh),
     }
 }
 
@@ -174,19 +313,7 @@ fn calculate_linear_ticks(config: &AxisConfig) -> Vec<TickMark> {
     }
 
     // Calculate nice tick spacing
-    let rough_step = range / 5.0;
-    let magnitude = 10.0_f64.powf(rough_step.abs().log10().floor());
-    let residual = rough_step / magnitude;
-
-    let nice_step = if residual <= 1.5 {
-        1.0 * magnitude
-    } else if residual <= 3.0 {
-        2.0 * magnitude
-    } else if residual <= 7.0 {
-        5.0 * magnitude
-    } else {
-        10.0 * magnitude
-    };
+    let nice_step = nice_step(range / 5.0);
 
     // Generate major ticks
     let start = (config.min / nice_step).ceil() * nice_step;
@@ -233,6 +360,22 @@ fn calculate_linear_ticks(config: &AxisConfig) -> Vec<TickMark> {
     ticks
 }
 
+/// Round a rough tick spacing to a "nice" 1, 2, or 5 times a power of ten
+pub(crate) fn nice_step(rough_step: f64) -> f64 {
+    let magnitude = 10.0_f64.powf(rough_step.abs().log10().floor());
+    let residual = rough_step / magnitude;
+
+    if residual <= 1.5 {
+        1.0 * magnitude
+    } else if residual <= 3.0 {
+        2.0 * magnitude
+    } else if residual <= 7.0 {
+        5.0 * magnitude
+    } else {
+        10.0 * magnitude
+    }
+}
+
 fn calculate_log_ticks(config: &AxisConfig) -> Vec<TickMark> {
     if config.min <= 0.0 || config.max <= 0.0 {
         return vec![];
@@ -280,14 +423,83 @@ fn calculate_log_ticks(config: &AxisConfig) -> Vec<TickMark> {
     ticks
 }
 
-fn calculate_symlog_ticks(config: &AxisConfig) -> Vec<TickMark> {
+fn calculate_log2_ticks(config: &AxisConfig) -> Vec<TickMark> {
+    if config.min <= 0.0 || config.max <= 0.0 {
+        return vec![];
+    }
+
+    let start = config.min.log2().ceil() as i32;
+    let end = config.max.log2().floor() as i32;
+
+    (start..=end)
+        .map(|exponent| {
+            let value = 2.0_f64.powi(exponent);
+            TickMark {
+                value,
+                normalized: config.normalize(value),
+                is_major: true,
+                label: Some(format!("2{}", superscript(exponent))),
+            }
+        })
+        .collect()
+}
+
+fn calculate_symlog_ticks(config: &AxisConfig, linthresh: f64) -> Vec<TickMark> {
     // Symmetric log scale (linear near zero, log away from zero)
-    // For simplicity, we fall back to linear for now
-    calculate_linear_ticks(config)
+    if config.max <= config.min {
+        return vec![];
+    }
+
+    let mut ticks = Vec::new();
+    let mut push = |value: f64, is_major: bool, label: Option<String>| {
+        if value >= config.min && value <= config.max {
+            ticks.push(TickMark {
+                value,
+                normalized: config.normalize(value),
+                is_major,
+                label,
+            });
+        }
+    };
+
+    // Linear region: nice steps across [-linthresh, linthresh]
+    let step = nice_step(linthresh);
+    let n = (linthresh / step + 1e-9).floor() as i64;
+    for k in -n..=n {
+        let value = k as f64 * step;
+        push(
+            value,
+            true,
+            Some(format_number(value, config.format.as_deref())),
+        );
+    }
+
+    // Log regions: decades beyond the threshold on both sides of zero
+    let max_abs = config.min.abs().max(config.max.abs());
+    if max_abs > linthresh {
+        let first_decade = (linthresh.log10() + 1e-9).floor() as i32 + 1;
+        let last_decade = max_abs.log10().floor() as i32;
+        for decade in first_decade..=last_decade {
+            let value = 10.0_f64.powi(decade);
+            let label = format!("10{}", superscript(decade));
+            push(value, true, Some(label.clone()));
+            push(-value, true, Some(format!("-{}", label)));
+
+            if config.minor_ticks > 0 {
+                for minor in [2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0] {
+                    push(value * minor, false, None);
+                    push(-value * minor, false, None);
+                }
+            }
+        }
+    }
+
+    ticks.sort_by(|a, b| a.value.partial_cmp(&b.value).unwrap());
+    ticks
 }
 
 /// Format a number for display
-fn format_number(value: f64, format: Option<&str>) -> String {
+pub(crate) fn format_number(value: f64, format: Option<&str>) -> String {
     match format {
         Some(fmt) if fmt.ends_with('e') || fmt.ends_with('E') => {
             format!("{:e}", value)
@@ -319,7 +531,7 @@ fn format_number(value: f64, format: Option<&str>) -> String {
 }
 
 /// Convert an integer to superscript Unicode characters
-fn superscript(n: i32) -> String {
+pub(crate) fn superscript(n: i32) -> String {
     const SUPERSCRIPTS: &[char] = &['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
 
     if n == 0 {
@@ -388,8 +600,8 @@ impl AxisLayout {
 
     /// Transform data coordinates to pixel coordinates
     pub fn data_to_pixel(&self, x: f64, y: f64) -> (f32, f32) {
-        let x_norm = (x - self.x_axis.min) / (self.x_axis.max - self.x_axis.min);
-        let y_norm = (y - self.y_axis.min) / (self.y_axis.max - self.y_axis.min);
+        let x_norm = self.x_axis.normalize(x);
+        let y_norm = self.y_axis.normalize(y);
 
         let px = self.plot_area[0] + x_norm as f32 * self.plot_area[2];
         let py = self.plot_area[1] + (1.0 - y_norm as f32) * self.plot_area[3]; // Y is flipped
@@ -402,8 +614,8 @@ impl AxisLayout {
         let x_norm = (px - self.plot_area[0]) / self.plot_area[2];
         let y_norm = 1.0 - (py - self.plot_area[1]) / self.plot_area[3]; // Y is flipped
 
-        let x = self.x_axis.min + x_norm as f64 * (self.x_axis.max - self.x_axis.min);
-        let y = self.y_axis.min + y_norm as f64 * (self.y_axis.max - self.y_axis.min);
+        let x = self.x_axis.denormalize(x_norm as f64);
+        let y = self.y_axis.denormalize(y_norm as f64);
 
         (x, y)
     }
@@ -426,13 +638,98 @@ mod tests {
     #[test]
     fn test_calculate_log_ticks() {
         let mut config = AxisConfig::new(AxisPosition::Bottom, 1.0, 1000.0);
-        config.scale = ScaleType::Log10;
+        config.scale = AxisScale::Log;
 
         let ticks = calculate_ticks(&config);
         let major_ticks: Vec<_> = ticks.iter().filter(|t| t.is_major).collect();
 
         // Should have ticks at 10^0, 10^1, 10^2, 10^3
         assert_eq!(major_ticks.len(), 4);
+
+        // Evenly spaced across the three decades
+        for (i, tick) in major_ticks.iter().enumerate() {
+            assert!((tick.normalized - i as f64 / 3.0).abs() < 1e-10);
+        }
+        assert_eq!(major_ticks[2].label.as_deref(), Some("10²"));
+    }
+
+    #[test]
+    fn test_symlog_transform_straddles_zero() {
+        let scale = AxisScale::symlog(1.0);
+
+        assert_eq!(scale.transform(0.0), 0.0);
+        assert!((scale.transform(0.5) - 0.5).abs() < 1e-12);
+        assert!((scale.transform(100.0) - 3.0).abs() < 1e-12);
+        assert!((scale.transform(-100.0) + 3.0).abs() < 1e-12);
+
+        for value in [-1000.0, -3.5, -1.0, -0.25, 0.0, 0.25, 1.0, 3.5, 1000.0] {
+            assert!((scale.inverse(scale.transform(value)) - value).abs() < 1e-9);
+        }
+
+        // Symmetric range puts zero in the middle
+        assert!((scale.normalize(0.0, -1000.0, 1000.0) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_calculate_symlog_ticks() {
+        let config = AxisConfig::new(AxisPosition::Bottom, -1000.0, 1000.0).with_symlog_scale(1.0);
+        let ticks = calculate_ticks(&config);
+        let majors: Vec<f64> = ticks
+            .iter()
+            .filter(|t| t.is_major)
+            .map(|t| t.value)
+            .collect();
+
+        assert_eq!(
+            majors,
+            vec![-1000.0, -100.0, -10.0, -1.0, 0.0, 1.0, 10.0, 100.0, 1000.0]
+        );
+
+        let zero = ticks.iter().find(|t| t.value == 0.0).unwrap();
+        assert!((zero.normalized - 0.5).abs() < 1e-12);
+        let ten = ticks.iter().find(|t| t.value == 10.0).unwrap();
+        let minus_ten = ticks.iter().find(|t| t.value == -10.0).unwrap();
+        assert!((ten.normalized + minus_ten.normalized - 1.0).abs() < 1e-12);
+        assert_eq!(minus_ten.label.as_deref(), Some("-10¹"));
+    }
+
+    #[test]
+    fn test_axis_layout_log_transform() {
+        let x_axis = AxisConfig::new(AxisPosition::Bottom, 1.0, 1000.0).with_log_scale();
+        let y_axis = AxisConfig::new(AxisPosition::Left, 0.0, 1.0);
+        let layout = AxisLayout::new(400.0, 300.0, x_axis, y_axis);
+
+        // 10 sits a third of the way across a three-decade axis
+        let (px, _) = layout.data_to_pixel(10.0, 0.5);
+        let third = layout.plot_area[0] + layout.plot_area[2] / 3.0;
+        assert!((px - third).abs() < 1e-3);
+
+        let (x, _) = layout.pixel_to_data(px, 150.0);
+        assert!((x - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_axis_scale_reads_saved_names() {
+        let scales: Vec<AxisScale> = serde_json::from_str(
+            r#"["Linear", "Log", "Log10", "Log2", "SymLog", {"SymLog": {"linthresh": 0.5}}]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            scales,
+            vec![
+                AxisScale::Linear,
+                AxisScale::Log,
+                AxisScale::Log,
+                AxisScale::Log2,
+                AxisScale::symlog(1.0),
+                AxisScale::symlog(0.5),
+            ]
+        );
+
+        for scale in scales {
+            let json = serde_json::to_string(&scale).unwrap();
+            assert_eq!(serde_json::from_str::<AxisScale>(&json).unwrap(), scale);
+        }
     }
 
     #[test]
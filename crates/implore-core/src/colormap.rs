@@ -2,12 +2,17 @@
 //!
 //! Provides a variety of perceptually uniform colormaps suitable for
 //! scientific data visualization, including:
-//! - Sequential: viridis, plasma, inferno, magma
+//! - Sequential: viridis, cividis, plasma, inferno, magma
 //! - Diverging: coolwarm, seismic
-//! - Categorical: for discrete data
+//! - Categorical: Okabe-Ito, for discrete data
 //!
-//! All colormaps support interpolation and can be reversed.
+//! Viridis, cividis, and Okabe-Ito remain distinguishable under the common
+//! forms of color vision deficiency.
+//!
+//! All colormaps support interpolation and can be reversed, and can produce
+//! colorbar legend ticks on linear or logarithmic scales.
 
+use crate::axis::{format_number, nice_step, superscript};
 use serde::{Deserialize, Serialize};
 
 /// A color in RGBA format (0.0 to 1.0)
@@ -86,6 +91,8 @@ pub struct Colormap {
     stops: Vec<(f32, Color)>,
     /// Whether the colormap is reversed
     reversed: bool,
+    /// Whether the colormap is a set of discrete swatches (no interpolation)
+    discrete: bool,
 }
 
 impl Colormap {
@@ -102,6 +109,31 @@ impl Colormap {
             name: name.into(),
             stops,
             reversed: false,
+            discrete: false,
+        }
+    }
+
+    /// Create a qualitative colormap of discrete swatches for categorical data
+    ///
+    /// Sampling picks the swatch covering `t` rather than interpolating.
+    pub fn qualitative(name: impl Into<String>, colors: Vec<Color>) -> Self {
+        Self {
+            discrete: true,
+            ..Self::from_colors(name, colors)
+        }
+    }
+
+    /// Look up a built-in colormap by name (case-insensitive)
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "viridis" => Some(viridis()),
+            "cividis" => Some(cividis()),
+            "plasma" => Some(plasma()),
+            "inferno" => Some(inferno()),
+            "magma" => Some(magma()),
+            "coolwarm" => Some(coolwarm()),
+            "okabe-ito" | "okabe_ito" | "okabeito" => Some(okabe_ito()),
+            _ => None,
         }
     }
 
@@ -111,7 +143,31 @@ impl Colormap {
             name: name.into(),
             stops,
             reversed: false,
+            discrete: false,
+        }
+    }
+
+    /// Whether this is a qualitative colormap of discrete swatches
+    pub fn is_discrete(&self) -> bool {
+        self.discrete
+    }
+
+    /// The colormap's swatches, in order
+    pub fn swatches(&self) -> Vec<Color> {
+        let mut colors: Vec<Color> = self.stops.iter().map(|(_, c)| *c).collect();
+        if self.reversed {
+            colors.reverse();
+        }
+        colors
+    }
+
+    /// Color for a category index, cycling through the swatches
+    pub fn swatch(&self, index: usize) -> Color {
+        let colors = self.swatches();
+        if colors.is_empty() {
+            return Color::rgb(0.0, 0.0, 0.0);
         }
+        colors[index % colors.len()]
     }
 
     /// Reverse the colormap
@@ -135,6 +191,12 @@ impl Colormap {
             return self.stops[0].1;
         }
 
+        if self.discrete {
+            let n = self.stops.len();
+            let index = ((t * n as f32) as usize).min(n - 1);
+            return self.stops[index].1;
+        }
+
         // Find the two stops to interpolate between
         for i in 0..self.stops.len() - 1 {
             let (t0, c0) = &self.stops[i];
@@ -173,6 +235,99 @@ impl Colormap {
     }
 }
 
+/// A labelled tick on a colorbar legend
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LegendTick {
+    /// Tick value in data units
+    pub value: f64,
+    /// Position along the colorbar (0.0 at data_min, 1.0 at data_max)
+    pub position: f32,
+    /// Colormap color at this position
+    pub color: Color,
+    /// Display label
+    pub label: String,
+}
+
+impl Colormap {
+    /// Generate colorbar ticks for a linear color scale.
+    ///
+    /// Tick values are "nice" (1, 2, or 5 times a power of ten) and aim for
+    /// roughly `n_ticks` ticks inside `[data_min, data_max]`. Returns no ticks
+    /// for an empty or non-finite range.
+    pub fn legend(&self, data_min: f64, data_max: f64, n_ticks: usize) -> Vec<LegendTick> {
+        let range = data_max - data_min;
+        if !range.is_finite() || range <= 0.0 {
+            return vec![];
+        }
+
+        let step = nice_step(range / (n_ticks.max(2) - 1) as f64);
+        let first = (data_min / step - 1e-9).ceil() as i64;
+        let last = (data_max / step + 1e-9).floor() as i64;
+
+        (first..=last)
+            .map(|k| {
+                // Multiply rather than accumulate to keep values round
+                let value = k as f64 * step;
+                let value = if value.abs() < step * 1e-9 {
+                    0.0
+                } else {
+                    value
+                };
+                let position = ((value - data_min) / range).clamp(0.0, 1.0) as f32;
+                LegendTick {
+                    value,
+                    position,
+                    color: self.sample(position),
+                    label: format_number(value, None),
+                }
+            })
+            .collect()
+    }
+
+    /// Generate colorbar ticks for a logarithmic color scale.
+    ///
+    /// Ticks fall on powers of ten, skipping decades evenly when the range
+    /// spans more than `n_ticks` of them. Ranges narrower than a decade fall
+    /// back to 1-2-5 mantissas. Returns no ticks unless both bounds are
+    /// positive.
+    pub fn log_legend(&self, data_min: f64, data_max: f64, n_ticks: usize) -> Vec<LegendTick> {
+        if !(data_min > 0.0 && data_max > data_min && data_max.is_finite()) {
+            return vec![];
+        }
+
+        let log_min = data_min.log10();
+        let log_max = data_max.log10();
+        let log_range = log_max - log_min;
+        let tick = |value: f64, label: String| {
+            let position = ((value.log10() - log_min) / log_range).clamp(0.0, 1.0) as f32;
+            LegendTick {
+                value,
+                position,
+                color: self.sample(position),
+                label,
+            }
+        };
+
+        let first_decade = (log_min - 1e-9).ceil() as i32;
+        let last_decade = (log_max + 1e-9).floor() as i32;
+        let decades = (last_decade - first_decade + 1).max(0) as usize;
+
+        if decades >= 2 {
+            let stride = decades.div_ceil(n_ticks.max(2)).max(1);
+            return (first_decade..=last_decade)
+                .step_by(stride)
+                .map(|d| tick(10.0_f64.powi(d), format!("10{}", superscript(d))))
+                .collect();
+        }
+
+        (log_min.floor() as i32..=log_max.ceil() as i32)
+            .flat_map(|d| [1.0, 2.0, 5.0].map(|m| m * 10.0_f64.powi(d)))
+            .filter(|v| *v >= data_min * (1.0 - 1e-9) && *v <= data_max * (1.0 + 1e-9))
+            .map(|v| tick(v, format_number(v, None)))
+            .collect()
+    }
+}
+
 // MARK: - Built-in Colormaps
 
 /// Get the viridis colormap (perceptually uniform, colorblind-safe)
@@ -196,6 +351,26 @@ pub fn viridis() -> Colormap {
     )
 }
 
+/// Get the cividis colormap (perceptually uniform, optimized for
+/// deuteranopia and protanopia)
+pub fn cividis() -> Colormap {
+    Colormap::from_colors(
+        "cividis",
+        vec![
+            Color::rgb(0.000, 0.133, 0.306),
+            Color::rgb(0.071, 0.208, 0.439),
+            Color::rgb(0.231, 0.286, 0.424),
+            Color::rgb(0.341, 0.365, 0.427),
+            Color::rgb(0.439, 0.443, 0.451),
+            Color::rgb(0.541, 0.525, 0.471),
+            Color::rgb(0.647, 0.612, 0.455),
+            Color::rgb(0.765, 0.702, 0.412),
+            Color::rgb(0.882, 0.800, 0.333),
+            Color::rgb(0.996, 0.910, 0.220),
+        ],
+    )
+}
+
 /// Get the plasma colormap
 pub fn plasma() -> Colormap {
     Colormap::from_colors(
@@ -264,9 +439,34 @@ pub fn coolwarm() -> Colormap {
     )
 }
 
+/// Get the Okabe-Ito qualitative palette (colorblind-safe categorical colors)
+pub fn okabe_ito() -> Colormap {
+    Colormap::qualitative(
+        "okabe-ito",
+        vec![
+            Color::rgb(0.000, 0.000, 0.000), // black
+            Color::rgb(0.902, 0.624, 0.000), // orange
+            Color::rgb(0.337, 0.706, 0.914), // sky blue
+            Color::rgb(0.000, 0.620, 0.451), // bluish green
+            Color::rgb(0.941, 0.894, 0.259), // yellow
+            Color::rgb(0.000, 0.447, 0.698), // blue
+            Color::rgb(0.835, 0.369, 0.000), // vermillion
+            Color::rgb(0.800, 0.475, 0.655), // reddish purple
+        ],
+    )
+}
+
 /// Get a list of all built-in colormap names
 pub fn builtin_colormap_names() -> Vec<&'static str> {
-    vec!["viridis", "plasma", "inferno", "magma", "coolwarm"]
+    vec![
+        "viridis",
+        "cividis",
+        "plasma",
+        "inferno",
+        "magma",
+        "coolwarm",
+        "okabe-ito",
+    ]
 }
 
 /// FFI-safe version of `builtin_colormap_names` returning owned Strings.
@@ -280,14 +480,7 @@ pub fn available_colormaps() -> Vec<String> {
 
 /// Get a built-in colormap by name
 pub fn get_colormap(name: &str) -> Option<Colormap> {
-    match name.to_lowercase().as_str() {
-        "viridis" => Some(viridis()),
-        "plasma" => Some(plasma()),
-        "inferno" => Some(inferno()),
-        "magma" => Some(magma()),
-        "coolwarm" => Some(coolwarm()),
-        _ => None,
-    }
+    Colormap::by_name(name)
 }
 
 /// Colormap configuration for a visualization
@@ -357,6 +550,24 @@ impl ColormapConfig {
     }
 }
 
+impl ColormapConfig {
+    /// Generate colorbar ticks for this configuration's colormap and range,
+    /// using a log legend when `log_scale` is set.
+    ///
+    /// Unknown colormap names fall back to viridis.
+    pub fn legend(&self, n_ticks: usize) -> Vec<LegendTick> {
+        let mut colormap = get_colormap(&self.name).unwrap_or_else(viridis);
+        if self.reversed {
+            colormap = colormap.reversed();
+        }
+        if self.log_scale {
+            colormap.log_legend(self.min_value, self.max_value, n_ticks)
+        } else {
+            colormap.legend(self.min_value, self.max_value, n_ticks)
+        }
+    }
+}
+
 impl Default for ColormapConfig {
     fn default() -> Self {
         Self::new("viridis", 0.0, 1.0)
@@ -413,6 +624,44 @@ mod tests {
         assert!((c0.b - c1_rev.b).abs() < 0.01);
     }
 
+    fn assert_close(a: Color, b: Color) {
+        assert!(
+            (a.r - b.r).abs() < 0.01 && (a.g - b.g).abs() < 0.01 && (a.b - b.b).abs() < 0.01,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn test_by_name_viridis_endpoints() {
+        let cmap = Colormap::by_name("viridis").unwrap();
+        assert!(!cmap.is_discrete());
+        assert_close(cmap.sample(0.0), Color::from_hex("#440154").unwrap());
+        assert_close(cmap.sample(1.0), Color::from_hex("#FDE725").unwrap());
+
+        assert!(Colormap::by_name("Cividis").is_some());
+        assert!(Colormap::by_name("no-such-map").is_none());
+        for name in builtin_colormap_names() {
+            assert!(Colormap::by_name(name).is_some(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_qualitative_swatches() {
+        let cmap = Colormap::by_name("okabe-ito").unwrap();
+        assert!(cmap.is_discrete());
+
+        let swatches = cmap.swatches();
+        assert_eq!(swatches.len(), 8);
+        assert_close(cmap.swatch(1), Color::from_hex("#E69F00").unwrap());
+        assert_eq!(cmap.swatch(9), cmap.swatch(1));
+
+        // Sampling snaps to a swatch instead of blending neighbours
+        assert_eq!(cmap.sample(0.14), swatches[1]);
+        assert_eq!(cmap.sample(1.0), swatches[7]);
+    }
+
     #[test]
     fn test_generate_lut() {
         let cmap = viridis();
@@ -430,6 +679,48 @@ mod tests {
         assert!((config.normalize(100.0) - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_legend_nice_ticks() {
+        let ticks = viridis().legend(0.0, 100.0, 6);
+
+        let values: Vec<f64> = ticks.iter().map(|t| t.value).collect();
+        assert_eq!(values, vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+        let labels: Vec<&str> = ticks.iter().map(|t| t.label.as_str()).collect();
+        assert_eq!(labels, vec!["0", "20", "40", "60", "80", "100"]);
+
+        assert_eq!(ticks[0].position, 0.0);
+        assert!((ticks[2].position - 0.4).abs() < 1e-6);
+        assert_eq!(ticks[5].color, viridis().sample(1.0));
+
+        // Awkward ranges still land on round numbers
+        for tick in viridis().legend(-0.37, 2.91, 5) {
+            assert!((tick.value * 2.0 - (tick.value * 2.0).round()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_log_legend_spans_decades() {
+        let ticks = viridis().log_legend(1.0, 1.0e4, 5);
+
+        let values: Vec<f64> = ticks.iter().map(|t| t.value).collect();
+        assert_eq!(values, vec![1.0, 10.0, 100.0, 1000.0, 10000.0]);
+        assert_eq!(ticks[1].label, "10¹");
+        for (i, tick) in ticks.iter().enumerate() {
+            assert!((tick.position - i as f32 * 0.25).abs() < 1e-6);
+        }
+
+        // Too many decades: every other one
+        let sparse = viridis().log_legend(1.0e-3, 1.0e6, 5);
+        assert!(sparse.len() <= 5);
+        assert_eq!(sparse[0].value, 1.0e-3);
+
+        // Log legend chosen from the config
+        let mut config = ColormapConfig::new("viridis", 1.0, 1000.0);
+        config.log_scale = true;
+        assert_eq!(config.legend(5).len(), 4);
+        assert!(viridis().log_legend(0.0, 10.0, 5).is_empty());
+    }
+
     #[test]
     fn test_colormap_config_log_scale() {
         let mut config = ColormapConfig::new("viridis", 1.0, 1000.0);
@@ -5,6 +5,7 @@
 //! - Schema describing field names and types
 //! - Metadata (units, descriptions, etc.)
 //! - Provenance linking to academic publications
+//! - Lineage: the chain of loads, selections, and transforms that produced it
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -24,6 +25,12 @@ pub enum DatasetError {
 
     #[error("Parse error: {0}")]
     ParseError(String),
+
+    #[error("Selection mask has {actual} entries but the dataset has {expected} records")]
+    MaskLengthMismatch { expected: u64, actual: usize },
+
+    #[error("Selection '{0}' did not record its rows and cannot be replayed")]
+    SelectionNotReplayable(String),
 }
 
 /// Result type for dataset operations
@@ -54,14 +61,19 @@ pub struct Dataset {
 
 impl Dataset {
     /// Create a new dataset with the given source
+    ///
+    /// Records a source-load step as the start of the dataset's lineage.
     pub fn new(name: impl Into<String>, source: DatasetSource) -> Self {
+        let mut provenance = DataProvenance::default();
+        provenance.steps.push(ProvenanceStep::source_load(&source));
+
         Self {
             id: uuid::Uuid::new_v4().to_string(),
             name: name.into(),
             source,
             schema: DataSchema::default(),
             metadata: DatasetMetadata::default(),
-            provenance: DataProvenance::default(),
+            provenance,
         }
     }
 
@@ -100,6 +112,15 @@ impl Dataset {
         };
 
         dataset.provenance.created_at = Some(now);
+        if let DatasetSource::Generated {
+            generator_id,
+            params_json,
+            ..
+        } = &dataset.source
+        {
+            let step = ProvenanceStep::generator(generator_id.clone(), params_json.clone());
+            dataset.provenance.steps.push(step);
+        }
 
         dataset
     }
@@ -123,6 +144,102 @@ impl Dataset {
     pub fn is_generated(&self) -> bool {
         self.source.is_generated()
     }
+
+    /// Create a child dataset by applying a transformation step
+    ///
+    /// The child gets a new id and inherits the parent's source, schema,
+    /// metadata, and provenance, with `step` appended to the lineage and
+    /// linked back to this dataset. Callers update the child's schema to
+    /// reflect the transformation.
+    pub fn derive(&self, mut step: ProvenanceStep) -> Dataset {
+        step.input_dataset_id = Some(self.id.clone());
+
+        let mut child = self.clone();
+        child.id = uuid::Uuid::new_v4().to_string();
+        child.metadata.modified_at = Some(chrono::Utc::now().timestamp_millis());
+        child.provenance.steps.push(step);
+        child
+    }
+
+    /// Create a child dataset holding only the records where `mask` is true
+    ///
+    /// The schema carries over with the record count reduced, and a
+    /// selection step recording the kept row indices is appended to the
+    /// lineage, so the subset can be reloaded from the parent's source with
+    /// [`Dataset::source_rows`]. Use [`Dataset::subset_where`] to record the
+    /// selection expression that produced the mask.
+    pub fn subset(&self, mask: &[bool]) -> DatasetResult<Dataset> {
+        self.subset_where(mask, "row mask")
+    }
+
+    /// Like [`Dataset::subset`], recording `expression` as the selection
+    pub fn subset_where(
+        &self,
+        mask: &[bool],
+        expression: impl Into<String>,
+    ) -> DatasetResult<Dataset> {
+        if mask.len() as u64 != self.num_records() {
+            return Err(DatasetError::MaskLengthMismatch {
+                expected: self.num_records(),
+                actual: mask.len(),
+            });
+        }
+
+        let row_indices: Vec<u64> = mask
+            .iter()
+            .enumerate()
+            .filter(|(_, &keep)| keep)
+            .map(|(i, _)| i as u64)
+            .collect();
+        let selected_count = row_indices.len() as u64;
+        let mut child = self.derive(ProvenanceStep::selection_rows(expression, row_indices));
+        child.schema.num_records = selected_count;
+        Ok(child)
+    }
+
+    /// Rows of the source this dataset holds, oldest selection first
+    ///
+    /// Replays the selection steps in the lineage, mapping each step's row
+    /// indices back through the selections before it. Returns `None` when
+    /// no selection has been applied, i.e. the dataset holds every source row.
+    pub fn source_rows(&self) -> DatasetResult<Option<Vec<u64>>> {
+        let mut rows: Option<Vec<u64>> = None;
+        for step in &self.provenance.steps {
+            let ProvenanceAction::Selection {
+                expression,
+                row_indices,
+                ..
+            } = &step.action
+            else {
+                continue;
+            };
+            let Some(indices) = row_indices else {
+                return Err(DatasetError::SelectionNotReplayable(expression.clone()));
+            };
+            rows = Some(match rows {
+                None => indices.clone(),
+                Some(parent) => indices.iter().map(|&i| parent[i as usize]).collect(),
+            });
+        }
+        Ok(rows)
+    }
+
+    /// Human-readable lineage, one numbered step per line
+    pub fn lineage_summary(&self) -> String {
+        self.provenance
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| format!("{}. {} ({})", i + 1, step.summary(), step.timestamp))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Human-readable lineage summary for a dataset (FFI)
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn dataset_lineage_summary(dataset: Dataset) -> String {
+    dataset.lineage_summary()
 }
 
 /// Source location and format for a dataset
@@ -351,6 +468,10 @@ pub struct DataProvenance {
 
     /// Notes about data origin
     pub notes: Option<String>,
+
+    /// Transformations that produced this dataset, oldest first
+    #[serde(default)]
+    pub steps: Vec<ProvenanceStep>,
 }
 
 impl DataProvenance {
@@ -370,6 +491,129 @@ impl DataProvenance {
     }
 }
 
+/// What a provenance step did
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ProvenanceAction {
+    /// Data loaded from a file or in-memory source
+    SourceLoad { format: String, location: String },
+
+    /// A selection applied to the parent dataset
+    Selection {
+        /// Selection description (e.g. a query or region)
+        expression: String,
+        /// Number of records kept
+        selected_count: u64,
+        /// Indices of the kept records in the input dataset, if recorded
+        #[serde(default)]
+        row_indices: Option<Vec<u64>>,
+    },
+
+    /// Data produced by a generator plugin
+    Generator {
+        generator_id: String,
+        params_json: String,
+    },
+
+    /// Any other transformation (binning, resampling, ...)
+    Transform { name: String, params_json: String },
+}
+
+/// One step in a dataset's lineage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ProvenanceStep {
+    /// What was done
+    pub action: ProvenanceAction,
+
+    /// When it was done (RFC 3339)
+    pub timestamp: String,
+
+    /// Dataset this step was applied to (None for the initial load)
+    pub input_dataset_id: Option<String>,
+}
+
+impl ProvenanceStep {
+    /// Create a step timestamped now
+    pub fn new(action: ProvenanceAction) -> Self {
+        Self {
+            action,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            input_dataset_id: None,
+        }
+    }
+
+    /// Loading data from a source
+    pub fn source_load(source: &DatasetSource) -> Self {
+        let location = match source {
+            DatasetSource::Hdf5 { path, dataset_path } => format!("{}:{}", path, dataset_path),
+            DatasetSource::Fits { path, extension } => format!("{}[{}]", path, extension),
+            DatasetSource::Csv { path, .. } | DatasetSource::Parquet { path } => path.clone(),
+            DatasetSource::InMemory { format } => format.clone(),
+            DatasetSource::Generated { generator_id, .. } => generator_id.clone(),
+        };
+        Self::new(ProvenanceAction::SourceLoad {
+            format: source.format_name().to_string(),
+            location,
+        })
+    }
+
+    /// Applying a selection that kept `selected_count` records
+    pub fn selection(expression: impl Into<String>, selected_count: u64) -> Self {
+        Self::new(ProvenanceAction::Selection {
+            expression: expression.into(),
+            selected_count,
+            row_indices: None,
+        })
+    }
+
+    /// Applying a selection that kept the records at `row_indices`
+    pub fn selection_rows(expression: impl Into<String>, row_indices: Vec<u64>) -> Self {
+        Self::new(ProvenanceAction::Selection {
+            expression: expression.into(),
+            selected_count: row_indices.len() as u64,
+            row_indices: Some(row_indices),
+        })
+    }
+
+    /// Generating data with a plugin
+    pub fn generator(generator_id: impl Into<String>, params_json: impl Into<String>) -> Self {
+        Self::new(ProvenanceAction::Generator {
+            generator_id: generator_id.into(),
+            params_json: params_json.into(),
+        })
+    }
+
+    /// Any other named transformation
+    pub fn transform(name: impl Into<String>, params_json: impl Into<String>) -> Self {
+        Self::new(ProvenanceAction::Transform {
+            name: name.into(),
+            params_json: params_json.into(),
+        })
+    }
+
+    /// One-line description of the step
+    pub fn summary(&self) -> String {
+        match &self.action {
+            ProvenanceAction::SourceLoad { format, location } => {
+                format!("Loaded {} from {}", format, location)
+            }
+            ProvenanceAction::Selection {
+                expression,
+                selected_count,
+                ..
+            } => format!("Selected {} records where {}", selected_count, expression),
+            ProvenanceAction::Generator {
+                generator_id,
+                params_json,
+            } => format!("Generated with {} {}", generator_id, params_json),
+            ProvenanceAction::Transform { name, params_json } => {
+                format!("Applied {} {}", name, params_json)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -408,6 +652,134 @@ mod tests {
         assert_eq!(DataType::String.byte_size(), None);
     }
 
+    #[test]
+    fn test_load_then_select_lineage() {
+        let source = DatasetSource::Csv {
+            path: "/data/halos.csv".to_string(),
+            delimiter: None,
+        };
+        let loaded = Dataset::new("Halos", source);
+        assert_eq!(loaded.provenance.steps.len(), 1);
+
+        let selected = loaded.derive(ProvenanceStep::selection("mass > 1e12", 42));
+
+        assert_ne!(selected.id, loaded.id);
+        assert_eq!(loaded.provenance.steps.len(), 1);
+        let steps = &selected.provenance.steps;
+        assert_eq!(steps.len(), 2);
+        assert_eq!(
+            steps[0].action,
+            ProvenanceAction::SourceLoad {
+                format: "CSV".to_string(),
+                location: "/data/halos.csv".to_string(),
+            }
+        );
+        assert_eq!(steps[0].input_dataset_id, None);
+        assert_eq!(
+            steps[1].input_dataset_id.as_deref(),
+            Some(loaded.id.as_str())
+        );
+        assert!(chrono::DateTime::parse_from_rfc3339(&steps[1].timestamp).is_ok());
+
+        let summary = selected.lineage_summary();
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("1. Loaded CSV from /data/halos.csv"));
+        assert!(lines[1].starts_with("2. Selected 42 records where mass > 1e12"));
+    }
+
+    #[test]
+    fn test_subset_keeps_selected_rows() {
+        let mut dataset = Dataset::new(
+            "Halos",
+            DatasetSource::InMemory {
+                format: "table".to_string(),
+            },
+        );
+        dataset.schema = DataSchema::new(
+            vec![
+                FieldDescriptor::new("mass", DataType::Float64).with_unit("solar masses"),
+                FieldDescriptor::new("radius", DataType::Float32),
+            ],
+            10,
+        );
+
+        let mut mask = vec![false; 10];
+        for i in [1, 4, 7] {
+            mask[i] = true;
+        }
+        let subset = dataset.subset_where(&mask, "mass > 1e12").unwrap();
+
+        assert_eq!(subset.num_records(), 3);
+        assert_eq!(subset.field_names(), vec!["mass", "radius"]);
+        assert_eq!(
+            subset.field("mass").unwrap().unit.as_deref(),
+            Some("solar masses")
+        );
+        assert_eq!(dataset.num_records(), 10);
+        assert_eq!(
+            subset.provenance.steps.last().unwrap().action,
+            ProvenanceAction::Selection {
+                expression: "mass > 1e12".to_string(),
+                selected_count: 3,
+                row_indices: Some(vec![1, 4, 7]),
+            }
+        );
+
+        let err = dataset.subset(&[true; 4]).unwrap_err();
+        assert!(matches!(
+            err,
+            DatasetError::MaskLengthMismatch {
+                expected: 10,
+                actual: 4
+            }
+        ));
+    }
+
+    #[test]
+    fn test_subset_rows_survive_reload() {
+        let mut dataset = Dataset::new(
+            "Halos",
+            DatasetSource::Parquet {
+                path: "/data/halos.parquet".to_string(),
+            },
+        );
+        dataset.schema = DataSchema::new(vec![FieldDescriptor::new("mass", DataType::Float64)], 6);
+        assert_eq!(dataset.source_rows().unwrap(), None);
+
+        let massive = dataset
+            .subset_where(&[false, true, true, false, true, true], "mass > 1e12")
+            .unwrap();
+        let inner = massive
+            .subset_where(&[true, false, false, true], "radius < 2")
+            .unwrap();
+
+        let json = serde_json::to_string(&inner).unwrap();
+        let reloaded: Dataset = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded.num_records(), 2);
+        assert_eq!(reloaded.source_rows().unwrap(), Some(vec![1, 5]));
+
+        let described = dataset.derive(ProvenanceStep::selection("mass > 1e12", 4));
+        assert!(matches!(
+            described.source_rows(),
+            Err(DatasetError::SelectionNotReplayable(_))
+        ));
+    }
+
+    #[test]
+    fn test_generated_dataset_lineage() {
+        let dataset = Dataset::from_generator(
+            "noise.perlin",
+            "Perlin Noise",
+            r#"{"octaves":4}"#,
+            DataSchema::default(),
+        );
+        assert_eq!(dataset.provenance.steps.len(), 1);
+        assert!(dataset
+            .lineage_summary()
+            .contains("Generated with noise.perlin"));
+    }
+
     #[test]
     fn test_provenance() {
         let mut provenance = DataProvenance::default();
@@ -0,0 +1,454 @@
+//! Point downsampling for interactive rendering
+//!
+//! A billion-point dataset can't show per-point detail on screen, so the
+//! renderer draws a representative subset of at most `target_count` points.
+//! Downsampling only the points inside the current view brings detail back
+//! as the user zooms in: fewer points are visible, so a larger share of them
+//! (eventually all of them) is drawn.
+//!
+//! Point positions come from one to three numeric columns of a dataset; axes
+//! without a column are zero.
+
+use std::collections::HashMap;
+
+use implore_io::{DataReader, IoError, IoResult};
+use serde::{Deserialize, Serialize};
+
+use crate::spatial::rtree::BoundingBox;
+
+/// Seed for random downsampling, fixed so the subset is stable across frames
+const RANDOM_SEED: u64 = 0x5EED_D0A7;
+
+/// How points are chosen when downsampling
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DownsampleStrategy {
+    /// Uniform random subset (deterministic, so it doesn't flicker)
+    Random,
+    /// Every n-th point in dataset order
+    Stride,
+    /// Bucket points into a grid, keep one point per occupied cell, and
+    /// spend the remaining budget in proportion to each cell's population
+    #[default]
+    DensityPreserving,
+}
+
+/// Choose at most `target_count` records of `dataset` to render
+///
+/// `columns` names the position columns (x, then optionally y and z).
+/// Returns record indices in ascending order. If there are no more records
+/// than the target, every index is returned.
+pub fn downsample(
+    dataset: &dyn DataReader,
+    columns: &[&str],
+    target_count: usize,
+    strategy: DownsampleStrategy,
+) -> IoResult<Vec<usize>> {
+    let points = read_points(dataset, columns)?;
+    Ok(downsample_points(&points, target_count, strategy))
+}
+
+/// Choose at most `target_count` of the records inside `view` to render
+///
+/// Call again whenever the view changes; zooming in shrinks the set of
+/// visible points and so raises the share that gets drawn.
+pub fn downsample_in_view(
+    dataset: &dyn DataReader,
+    columns: &[&str],
+    view: &BoundingBox,
+    target_count: usize,
+    strategy: DownsampleStrategy,
+) -> IoResult<Vec<usize>> {
+    let points = read_points(dataset, columns)?;
+    Ok(select(&points, view, target_count, strategy))
+}
+
+/// Read point positions from the named columns of `dataset`
+fn read_points(dataset: &dyn DataReader, columns: &[&str]) -> IoResult<Vec<[f64; 3]>> {
+    if columns.is_empty() || columns.len() > 3 {
+        return Err(IoError::InvalidFormat(format!(
+            "Expected 1 to 3 position columns, got {}",
+            columns.len()
+        )));
+    }
+
+    let mut points = Vec::new();
+    for (axis, &name) in columns.iter().enumerate() {
+        let column = dataset.read_column(name)?;
+        let values = column.to_f64().ok_or_else(|| IoError::TypeMismatch {
+            expected: "numeric".to_string(),
+            actual: format!("{:?}", column.dtype()),
+        })?;
+        if axis == 0 {
+            points = vec![[0.0; 3]; values.len()];
+        } else if values.len() != points.len() {
+            return Err(IoError::InvalidFormat(format!(
+                "Column {} has {} records, expected {}",
+                name,
+                values.len(),
+                points.len()
+            )));
+        }
+        for (point, value) in points.iter_mut().zip(values) {
+            point[axis] = value;
+        }
+    }
+    Ok(points)
+}
+
+/// Downsample points over their own bounding box
+fn downsample_points(
+    points: &[[f64; 3]],
+    target_count: usize,
+    strategy: DownsampleStrategy,
+) -> Vec<usize> {
+    let mut bounds = BoundingBox::empty();
+    for &point in points {
+        bounds.expand_to_include(point);
+    }
+    select(points, &bounds, target_count, strategy)
+}
+
+/// Downsample the points inside `bounds`
+fn select(
+    points: &[[f64; 3]],
+    bounds: &BoundingBox,
+    target_count: usize,
+    strategy: DownsampleStrategy,
+) -> Vec<usize> {
+    let visible = || {
+        points
+            .iter()
+            .enumerate()
+            .filter(|(_, point)| bounds.contains_point(**point))
+    };
+
+    let count = visible().count();
+    if count <= target_count {
+        return visible().map(|(i, _)| i).collect();
+    }
+    if target_count == 0 {
+        return Vec::new();
+    }
+
+    match strategy {
+        DownsampleStrategy::Random => {
+            // Selection sampling: keep each point with probability
+            // (still needed) / (still to see), which yields exactly the target
+            let mut rng = SimpleRng::new(RANDOM_SEED);
+            let mut needed = target_count;
+            let mut remaining = count;
+            let mut selected = Vec::with_capacity(target_count);
+            for (i, _) in visible() {
+                if (rng.next_f64() * remaining as f64) < needed as f64 {
+                    selected.push(i);
+                    needed -= 1;
+                    if needed == 0 {
+                        break;
+                    }
+                }
+                remaining -= 1;
+            }
+            selected
+        }
+        DownsampleStrategy::Stride => visible()
+            .enumerate()
+            .filter(|(j, _)| evenly_spaced(*j, target_count, count))
+            .map(|(_, (i, _))| i)
+            .collect(),
+        DownsampleStrategy::DensityPreserving => {
+            let grid = Grid::new(bounds, target_count);
+
+            let mut populations: HashMap<[usize; 3], usize> = HashMap::new();
+            for (_, point) in visible() {
+                *populations.entry(grid.cell(point)).or_default() += 1;
+            }
+
+            // One point per occupied cell, the rest shared out by population.
+            // The grid has at most `target_count` cells and there are more
+            // points than that, so `surplus` is never zero.
+            let occupied = populations.len();
+            let extra = target_count - occupied;
+            let surplus = count - occupied;
+            let quotas: HashMap<[usize; 3], (usize, usize)> = populations
+                .into_iter()
+                .map(|(cell, population)| {
+                    let share = extra * (population - 1) / surplus;
+                    (cell, (1 + share, population))
+                })
+                .collect();
+
+            let mut seen: HashMap<[usize; 3], usize> = HashMap::new();
+            let mut selected = Vec::new();
+            for (i, point) in visible() {
+                let cell = grid.cell(point);
+                let (quota, population) = quotas[&cell];
+                let j = seen.entry(cell).or_default();
+                if evenly_spaced(*j, quota, population) {
+                    selected.push(i);
+                }
+                *j += 1;
+            }
+            selected
+        }
+    }
+}
+
+/// Whether item `j` of `n` is one of `k` evenly spaced picks (always
+/// including the first)
+fn evenly_spaced(j: usize, k: usize, n: usize) -> bool {
+    (j * k).div_ceil(n) < ((j + 1) * k).div_ceil(n)
+}
+
+/// Uniform grid over a bounding box with about `target_cells` cells
+struct Grid {
+    min: [f64; 3],
+    extent: [f64; 3],
+    cells: [usize; 3],
+}
+
+impl Grid {
+    fn new(bounds: &BoundingBox, target_cells: usize) -> Self {
+        let mut extent = [0.0; 3];
+        for (i, e) in extent.iter_mut().enumerate() {
+            *e = bounds.max[i] - bounds.min[i];
+        }
+
+        // Split only the axes the points actually spread along
+        let dims = extent.iter().filter(|&&e| e > 0.0).count().max(1);
+        let per_axis = ((target_cells as f64).powf(1.0 / dims as f64).floor() as usize).max(1);
+        let mut cells = [1; 3];
+        for (i, c) in cells.iter_mut().enumerate() {
+            if extent[i] > 0.0 {
+                *c = per_axis;
+            }
+        }
+
+        Self {
+            min: bounds.min,
+            extent,
+            cells,
+        }
+    }
+
+    fn cell(&self, point: &[f64; 3]) -> [usize; 3] {
+        let mut cell = [0; 3];
+        for i in 0..3 {
+            if self.extent[i] > 0.0 {
+                let t = (point[i] - self.min[i]) / self.extent[i];
+                cell[i] = ((t * self.cells[i] as f64) as usize).min(self.cells[i] - 1);
+            }
+        }
+        cell
+    }
+}
+
+/// Simple RNG for reproducible sampling
+struct SimpleRng {
+    state: u64,
+}
+
+impl SimpleRng {
+    fn new(seed: u64) -> Self {
+        Self {
+            state: seed.wrapping_add(0x9E3779B97F4A7C15),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() as f64) / (u64::MAX as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use implore_io::{DataColumn, DataSchema, DataSlice};
+    use std::collections::HashSet;
+
+    fn grid_points(n: usize) -> Vec<[f64; 3]> {
+        (0..n * n)
+            .map(|i| [(i % n) as f64, (i / n) as f64, 0.0])
+            .collect()
+    }
+
+    /// In-memory dataset with x/y columns on an n x n grid
+    struct GridDataset {
+        columns: HashMap<String, DataColumn>,
+        metadata: HashMap<String, String>,
+    }
+
+    impl GridDataset {
+        fn new(n: usize) -> Self {
+            let points = grid_points(n);
+            let mut columns = HashMap::new();
+            columns.insert(
+                "x".to_string(),
+                DataColumn::Float64(points.iter().map(|p| p[0]).collect()),
+            );
+            columns.insert(
+                "y".to_string(),
+                DataColumn::Int64(points.iter().map(|p| p[1] as i64).collect()),
+            );
+            columns.insert(
+                "label".to_string(),
+                DataColumn::String(vec![String::new(); points.len()]),
+            );
+            Self {
+                columns,
+                metadata: HashMap::new(),
+            }
+        }
+    }
+
+    impl DataReader for GridDataset {
+        fn read_schema(&self) -> IoResult<DataSchema> {
+            Ok(DataSchema::default())
+        }
+
+        fn read_column(&self, name: &str) -> IoResult<DataColumn> {
+            self.columns
+                .get(name)
+                .cloned()
+                .ok_or_else(|| IoError::ColumnNotFound(name.to_string()))
+        }
+
+        fn read_range(&self, start: usize, _end: usize) -> IoResult<DataSlice> {
+            Ok(DataSlice::new(start))
+        }
+
+        fn metadata(&self) -> &HashMap<String, String> {
+            &self.metadata
+        }
+
+        fn format_name(&self) -> &'static str {
+            "memory"
+        }
+    }
+
+    #[test]
+    fn test_random_hits_target() {
+        let points = grid_points(100);
+        let indices = downsample_points(&points, 1000, DownsampleStrategy::Random);
+        assert_eq!(indices.len(), 1000);
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+
+        // Deterministic across calls
+        assert_eq!(
+            indices,
+            downsample_points(&points, 1000, DownsampleStrategy::Random)
+        );
+    }
+
+    #[test]
+    fn test_stride_hits_target() {
+        let points = grid_points(10);
+        let indices = downsample_points(&points, 25, DownsampleStrategy::Stride);
+        assert_eq!(indices.len(), 25);
+        assert_eq!(indices[0], 0);
+        assert_eq!(indices[1], 4);
+    }
+
+    #[test]
+    fn test_small_dataset_is_untouched() {
+        let points = grid_points(3);
+        for strategy in [
+            DownsampleStrategy::Random,
+            DownsampleStrategy::Stride,
+            DownsampleStrategy::DensityPreserving,
+        ] {
+            assert_eq!(
+                downsample_points(&points, 100, strategy),
+                (0..9).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_density_preserving_keeps_every_occupied_cell() {
+        // A dense cluster plus a handful of isolated outliers
+        let mut points: Vec<[f64; 3]> = (0..10_000)
+            .map(|i| [(i % 100) as f64 * 0.001, (i / 100) as f64 * 0.001, 0.0])
+            .collect();
+        for k in 0..5 {
+            points.push([10.0 + k as f64 * 20.0, 100.0 - k as f64 * 20.0, 0.0]);
+        }
+
+        let target = 100;
+        let indices = downsample_points(&points, target, DownsampleStrategy::DensityPreserving);
+        assert!(indices.len() <= target);
+
+        let mut bounds = BoundingBox::empty();
+        for &point in &points {
+            bounds.expand_to_include(point);
+        }
+        let grid = Grid::new(&bounds, target);
+        let occupied: HashSet<[usize; 3]> = points.iter().map(|p| grid.cell(p)).collect();
+        let kept: HashSet<[usize; 3]> = indices.iter().map(|&i| grid.cell(&points[i])).collect();
+        assert_eq!(kept, occupied);
+
+        // Every outlier survives; random sampling would likely lose them
+        for i in 10_000..10_005 {
+            assert!(indices.contains(&i));
+        }
+    }
+
+    #[test]
+    fn test_zooming_in_restores_detail() {
+        let dataset = GridDataset::new(100);
+        let points = grid_points(100);
+        let target = 500;
+
+        let overview = downsample(
+            &dataset,
+            &["x", "y"],
+            target,
+            DownsampleStrategy::DensityPreserving,
+        )
+        .unwrap();
+        let zoomed_view = BoundingBox::new([0.0, 0.0, 0.0], [9.0, 9.0, 0.0]);
+        let zoomed = downsample_in_view(
+            &dataset,
+            &["x", "y"],
+            &zoomed_view,
+            target,
+            DownsampleStrategy::DensityPreserving,
+        )
+        .unwrap();
+
+        let in_view = |i: &&usize| zoomed_view.contains_point(points[**i]);
+        assert!(overview.iter().filter(in_view).count() < 100);
+        assert_eq!(zoomed.len(), 100);
+    }
+
+    #[test]
+    fn test_position_columns_must_be_numeric() {
+        let dataset = GridDataset::new(3);
+        let strategy = DownsampleStrategy::Stride;
+
+        // A single column places the points on the x axis
+        assert_eq!(
+            downsample(&dataset, &["x"], 100, strategy).unwrap(),
+            (0..9).collect::<Vec<_>>()
+        );
+        assert!(matches!(
+            downsample(&dataset, &["x", "missing"], 100, strategy),
+            Err(IoError::ColumnNotFound(_))
+        ));
+        assert!(matches!(
+            downsample(&dataset, &["x", "label"], 100, strategy),
+            Err(IoError::TypeMismatch { .. })
+        ));
+        assert!(matches!(
+            downsample(&dataset, &[], 100, strategy),
+            Err(IoError::InvalidFormat(_))
+        ));
+    }
+}
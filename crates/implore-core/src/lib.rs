@@ -9,6 +9,7 @@
 //! - **ViewState**: Current visualization state (camera, colormap, selection)
 //! - **RenderMode**: Science 2D, Box 3D, or Art shader modes
 //! - **Session**: Collaborative visualization session management
+//! - **Recording**: Record view changes in a session and play them back
 //! - **Automation**: URL scheme handling for implore:// commands
 //! - **Plugin**: Data generation system with built-in noise, fractal, and statistical generators
 //!
@@ -25,12 +26,14 @@ pub mod axis;
 pub mod camera;
 pub mod colormap;
 pub mod dataset;
+pub mod downsample;
 pub mod error;
 pub mod export;
 pub mod input;
 pub mod library;
 pub mod plot;
 pub mod plugin;
+pub mod recording;
 pub mod render;
 pub mod rg;
 pub mod schemas;
@@ -44,10 +47,11 @@ pub use automation::*;
 pub use axis::*;
 pub use camera::*;
 pub use colormap::{
-    available_colormaps, builtin_colormap_names, coolwarm, get_colormap, inferno, magma, plasma,
-    viridis, Color, Colormap, ColormapConfig,
+    available_colormaps, builtin_colormap_names, cividis, coolwarm, get_colormap, inferno, magma,
+    okabe_ito, plasma, viridis, Color, Colormap, ColormapConfig, LegendTick,
 };
 pub use dataset::*;
+pub use downsample::{downsample, downsample_in_view, DownsampleStrategy};
 pub use library::{
     FigureFolder, FigureLibrary, ImprintLink, LibraryError, LibraryFigure, load_library_json,
     save_library_json,
@@ -58,6 +62,7 @@ pub use plugin::{
     GeneratorRegistryHandle, MetadataEntry, ParameterConstraints, ParameterSpec, ParameterType,
     ParameterValue,
 };
+pub use recording::*;
 pub use schemas::register_implore_schemas;
 pub use session::*;
 pub use spatial::*;
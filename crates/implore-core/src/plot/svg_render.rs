@@ -9,7 +9,7 @@
 //! - Title text
 
 use super::types::*;
-use crate::axis::{AxisConfig, AxisPosition, ScaleType, calculate_ticks};
+use crate::axis::{AxisConfig, AxisPosition, AxisScale, calculate_ticks};
 
 /// Render a `PlotSpec` to a standalone SVG string.
 pub fn render_svg(spec: &PlotSpec) -> String {
@@ -31,9 +31,11 @@ pub fn render_svg(spec: &PlotSpec) -> String {
     let (x_min, x_max, y_min, y_max) = compute_bounds(spec);
 
     // Build axis configs
+    let x_scale = spec.x_axis.scale;
+    let y_scale = spec.y_axis.scale;
     let x_axis_config = AxisConfig {
         position: AxisPosition::Bottom,
-        scale: if spec.x_axis.log_scale { ScaleType::Log10 } else { ScaleType::Linear },
+        scale: x_scale,
         min: x_min,
         max: x_max,
         label: spec.x_axis.label.clone(),
@@ -49,7 +51,7 @@ pub fn render_svg(spec: &PlotSpec) -> String {
 
     let y_axis_config = AxisConfig {
         position: AxisPosition::Left,
-        scale: if spec.y_axis.log_scale { ScaleType::Log10 } else { ScaleType::Linear },
+        scale: y_scale,
         min: y_min,
         max: y_max,
         label: spec.y_axis.label.clone(),
@@ -70,8 +72,8 @@ pub fn render_svg(spec: &PlotSpec) -> String {
 
     // Coordinate transform closures
     let to_px = |x: f64, y: f64| -> (f64, f64) {
-        let xn = normalize(x, x_min, x_max, spec.x_axis.log_scale);
-        let yn = normalize(y, y_min, y_max, spec.y_axis.log_scale);
+        let xn = normalize(x, x_min, x_max, x_scale);
+        let yn = normalize(y, y_min, y_max, y_scale);
         let px = plot_x + xn * plot_w;
         let py = plot_y + (1.0 - yn) * plot_h;
         (px, py)
@@ -129,7 +131,7 @@ pub fn render_svg(spec: &PlotSpec) -> String {
                 render_step(&mut svg, series, &css_color, &to_px);
             }
             SeriesStyle::Bar => {
-                render_bars(&mut svg, series, &css_color, &to_px, plot_y + plot_h, y_min, y_max, spec.y_axis.log_scale);
+                render_bars(&mut svg, series, &css_color, &to_px, plot_y + plot_h, y_min, y_max, y_scale);
             }
         }
     }
@@ -165,21 +167,9 @@ pub fn render_svg(spec: &PlotSpec) -> String {
 
 // ── Coordinate normalization ────────────────────────────────────────
 
-fn normalize(v: f64, min: f64, max: f64, log_scale: bool) -> f64 {
-    if log_scale {
-        if v <= 0.0 || min <= 0.0 || max <= 0.0 {
-            return 0.0;
-        }
-        let log_min = min.log10();
-        let log_max = max.log10();
-        let range = log_max - log_min;
-        if range == 0.0 { return 0.5; }
-        (v.log10() - log_min) / range
-    } else {
-        let range = max - min;
-        if range == 0.0 { return 0.5; }
-        (v - min) / range
-    }
+fn normalize(v: f64, min: f64, max: f64, scale: AxisScale) -> f64 {
+    let t = scale.normalize(v, min, max);
+    if t.is_finite() { t } else { 0.0 }
 }
 
 // ── Bounds computation ──────────────────────────────────────────────
@@ -303,7 +293,7 @@ fn render_bars(
     baseline_py: f64,
     y_min: f64,
     _y_max: f64,
-    log_scale_y: bool,
+    y_scale: AxisScale,
 ) {
     let n = series.x.len().min(series.y.len());
     if n < 2 { return; }
@@ -316,7 +306,7 @@ fn render_bars(
         1.0
     };
 
-    let baseline_y = if log_scale_y { y_min.max(1e-10) } else { 0.0_f64.max(y_min) };
+    let baseline_y = if y_scale.is_log() { y_min.max(1e-10) } else { 0.0_f64.max(y_min) };
 
     for i in 0..n {
         let (x, y) = (series.x[i], series.y[i]);
@@ -825,11 +815,34 @@ mod tests {
             .line(vec![1.0, 10.0, 100.0], vec![1.0, 10.0, 100.0], "log-log");
         let svg = render_svg(&spec);
         assert!(svg.contains("polyline"));
+        assert!(spec.x_axis.log_scale());
+    }
+
+    #[test]
+    fn test_legacy_log_scale_axis() {
+        let axis: PlotAxis = serde_json::from_str(
+            r#"{"label": null, "min": null, "max": null, "log_scale": true, "format": null}"#,
+        )
+        .unwrap();
+        assert_eq!(axis.scale, AxisScale::Log);
+        assert!(axis.log_scale());
     }
 
     #[test]
     fn test_normalize() {
-        assert!((normalize(5.0, 0.0, 10.0, false) - 0.5).abs() < 1e-10);
-        assert!((normalize(10.0, 1.0, 100.0, true) - 0.5).abs() < 1e-10);
+        assert!((normalize(5.0, 0.0, 10.0, AxisScale::Linear) - 0.5).abs() < 1e-10);
+        assert!((normalize(10.0, 1.0, 100.0, AxisScale::Log) - 0.5).abs() < 1e-10);
+        assert_eq!(normalize(-1.0, 1.0, 100.0, AxisScale::Log), 0.0);
+        assert!((normalize(0.0, -100.0, 100.0, AxisScale::symlog(1.0)) - 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_symlog_scale() {
+        let spec = PlotSpec::new()
+            .with_symlog_y(1.0)
+            .line(vec![0.0, 1.0, 2.0], vec![-100.0, 0.0, 100.0], "signed");
+        let svg = render_svg(&spec);
+        assert!(svg.contains("polyline"));
+        assert!(svg.contains("10²"));
     }
 }
@@ -5,6 +5,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::axis::AxisScale;
+
 // ── Series style ────────────────────────────────────────────────────
 
 /// How to render a data series.
@@ -188,12 +190,52 @@ impl PlotSeries {
 
 /// Axis configuration within a PlotSpec.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "StoredPlotAxis")]
 pub struct PlotAxis {
     pub label: Option<String>,
     pub min: Option<f64>,
     pub max: Option<f64>,
-    pub log_scale: bool,
     pub format: Option<String>,
+    /// Axis scale.
+    pub scale: AxisScale,
+}
+
+impl PlotAxis {
+    /// Whether the axis is logarithmic.
+    pub fn log_scale(&self) -> bool {
+        self.scale.is_log()
+    }
+}
+
+/// Serialized form of [`PlotAxis`], which may still carry the `log_scale`
+/// flag from before axes had a scale.
+#[derive(Deserialize)]
+struct StoredPlotAxis {
+    label: Option<String>,
+    min: Option<f64>,
+    max: Option<f64>,
+    #[serde(default)]
+    log_scale: bool,
+    format: Option<String>,
+    #[serde(default)]
+    scale: AxisScale,
+}
+
+impl From<StoredPlotAxis> for PlotAxis {
+    fn from(stored: StoredPlotAxis) -> Self {
+        let scale = if stored.log_scale && stored.scale == AxisScale::Linear {
+            AxisScale::Log
+        } else {
+            stored.scale
+        };
+        Self {
+            label: stored.label,
+            min: stored.min,
+            max: stored.max,
+            format: stored.format,
+            scale,
+        }
+    }
 }
 
 // ── PlotSpec ────────────────────────────────────────────────────────
@@ -263,13 +305,25 @@ impl PlotSpec {
 
     /// Enable log scale on X axis.
     pub fn with_log_x(mut self) -> Self {
-        self.x_axis.log_scale = true;
+        self.x_axis.scale = AxisScale::Log;
         self
     }
 
     /// Enable log scale on Y axis.
     pub fn with_log_y(mut self) -> Self {
-        self.y_axis.log_scale = true;
+        self.y_axis.scale = AxisScale::Log;
+        self
+    }
+
+    /// Enable symmetric log scale on X axis.
+    pub fn with_symlog_x(mut self, linthresh: f64) -> Self {
+        self.x_axis.scale = AxisScale::symlog(linthresh);
+        self
+    }
+
+    /// Enable symmetric log scale on Y axis.
+    pub fn with_symlog_y(mut self, linthresh: f64) -> Self {
+        self.y_axis.scale = AxisScale::symlog(linthresh);
         self
     }
 
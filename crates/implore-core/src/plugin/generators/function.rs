@@ -4,6 +4,8 @@
 
 use std::collections::HashMap;
 
+use implore_selection::{parse_math, MathExpr};
+
 use crate::plugin::{
     BoundingBox, DataGenerator, DataSchema, DataType, FieldDescriptor, GeneratedData,
     GeneratorCategory, GeneratorError, GeneratorMetadata, GeneratorParams, ParameterConstraints,
@@ -315,6 +317,136 @@ impl DataGenerator for SineCosine {
     }
 }
 
+/// Parametric curve generator ((x(t), y(t)) from typed expressions)
+pub struct ParametricCurve {
+    metadata: GeneratorMetadata,
+}
+
+impl ParametricCurve {
+    pub fn new() -> Self {
+        Self {
+            metadata: GeneratorMetadata {
+                id: "function-parametric".to_string(),
+                name: "Parametric Curve".to_string(),
+                category: GeneratorCategory::Function,
+                description: "Plot a curve (x(t), y(t)) from expressions in t".to_string(),
+                icon: "point.bottomleft.forward.to.point.topright.scurvepath".to_string(),
+                parameters: vec![
+                    ParameterSpec::string("x_expr", "x(t)", "cos(t)")
+                        .with_description("Expression for x in terms of t"),
+                    ParameterSpec::string("y_expr", "y(t)", "sin(t)")
+                        .with_description("Expression for y in terms of t"),
+                    ParameterSpec::float("t_min", "t Min", 0.0),
+                    ParameterSpec::float("t_max", "t Max", std::f64::consts::TAU)
+                        .with_description("Default is 2π"),
+                    ParameterSpec::int("samples", "Samples", 1000)
+                        .with_constraints(ParameterConstraints::range(2.0, 1000000.0)),
+                ],
+                output_dimensions: 2,
+                supports_animation: false,
+            },
+        }
+    }
+
+    /// Parse an expression parameter, checking it only uses `t`
+    fn parse(name: &str, source: &str) -> Result<MathExpr, GeneratorError> {
+        let expr = parse_math(source)
+            .map_err(|e| GeneratorError::ExpressionError(format!("{}: {}", name, e)))?;
+        if let Some(unknown) = expr.variables().into_iter().find(|v| v != "t") {
+            return Err(GeneratorError::ExpressionError(format!(
+                "{}: unknown variable '{}'",
+                name, unknown
+            )));
+        }
+        Ok(expr)
+    }
+}
+
+impl Default for ParametricCurve {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataGenerator for ParametricCurve {
+    fn metadata(&self) -> &GeneratorMetadata {
+        &self.metadata
+    }
+
+    fn schema(&self) -> DataSchema {
+        DataSchema::new(vec![
+            FieldDescriptor::new("t", DataType::Float64).with_description("Curve parameter"),
+            FieldDescriptor::new("x", DataType::Float64).with_description("x(t)"),
+            FieldDescriptor::new("y", DataType::Float64).with_description("y(t)"),
+        ])
+    }
+
+    fn generate(&self, params: &GeneratorParams) -> Result<GeneratedData, GeneratorError> {
+        let x_source = params.get_string_or("x_expr", "cos(t)");
+        let y_source = params.get_string_or("y_expr", "sin(t)");
+        let t_min = params.get_float_or("t_min", 0.0);
+        let t_max = params.get_float_or("t_max", std::f64::consts::TAU);
+        let samples = params.get_int_or("samples", 1000);
+
+        if samples < 2 {
+            return Err(GeneratorError::InvalidParameter {
+                name: "samples".to_string(),
+                reason: "at least 2 samples are needed".to_string(),
+            });
+        }
+        let samples = samples as usize;
+
+        let x_expr = Self::parse("x_expr", x_source)?;
+        let y_expr = Self::parse("y_expr", y_source)?;
+
+        let mut t_data = Vec::with_capacity(samples);
+        let mut x_data = Vec::with_capacity(samples);
+        let mut y_data = Vec::with_capacity(samples);
+
+        let dt = (t_max - t_min) / (samples - 1) as f64;
+        let mut vars = HashMap::new();
+        let mut min = [f64::MAX, f64::MAX];
+        let mut max = [f64::MIN, f64::MIN];
+
+        for i in 0..samples {
+            let t = t_min + i as f64 * dt;
+            vars.insert("t".to_string(), t);
+            let x = x_expr
+                .evaluate(&vars)
+                .map_err(|e| GeneratorError::ExpressionError(format!("x_expr: {}", e)))?;
+            let y = y_expr
+                .evaluate(&vars)
+                .map_err(|e| GeneratorError::ExpressionError(format!("y_expr: {}", e)))?;
+
+            t_data.push(t);
+            x_data.push(x);
+            y_data.push(y);
+
+            if x.is_finite() && y.is_finite() {
+                min = [min[0].min(x), min[1].min(y)];
+                max = [max[0].max(x), max[1].max(y)];
+            }
+        }
+
+        let mut columns = HashMap::new();
+        columns.insert("t".to_string(), t_data);
+        columns.insert("x".to_string(), x_data);
+        columns.insert("y".to_string(), y_data);
+
+        let mut data = GeneratedData::new(columns)
+            .with_metadata("generator", "function-parametric")
+            .with_metadata("x_expr", x_source.to_string())
+            .with_metadata("y_expr", y_source.to_string());
+        if min[0] <= max[0] {
+            data = data.with_bounds(BoundingBox::new(
+                [min[0], min[1], 0.0],
+                [max[0], max[1], 0.0],
+            ));
+        }
+        Ok(data)
+    }
+}
+
 // Simple RNG
 struct SimpleRng {
     state: u64,
@@ -404,6 +536,52 @@ mod tests {
         assert_eq!(data.point_count, 100);
     }
 
+    #[test]
+    fn test_parametric_circle() {
+        let gen = ParametricCurve::new();
+        let mut params = GeneratorParams::new();
+        params.set_string("x_expr", "cos(t)");
+        params.set_string("y_expr", "sin(t)");
+        params.set_float("t_min", 0.0);
+        params.set_float("t_max", 2.0 * std::f64::consts::PI);
+        params.set_int("samples", 200);
+
+        let data = gen.generate(&params).unwrap();
+        assert_eq!(data.point_count, 200);
+
+        let x = data.get_column("x").unwrap();
+        let y = data.get_column("y").unwrap();
+        for i in 0..data.point_count {
+            let r = (x[i] * x[i] + y[i] * y[i]).sqrt();
+            assert!(
+                (r - 1.0).abs() < 1e-12,
+                "Point {} off unit circle: r = {}",
+                i,
+                r
+            );
+        }
+        assert!((x[0] - 1.0).abs() < 1e-12 && (x[199] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_parametric_expression_errors() {
+        let gen = ParametricCurve::new();
+
+        let mut params = GeneratorParams::new();
+        params.set_string("x_expr", "cos(t");
+        assert!(matches!(
+            gen.generate(&params),
+            Err(GeneratorError::ExpressionError(_))
+        ));
+
+        let mut params = GeneratorParams::new();
+        params.set_string("y_expr", "sin(s)");
+        assert!(matches!(
+            gen.generate(&params),
+            Err(GeneratorError::ExpressionError(_))
+        ));
+    }
+
     #[test]
     fn test_function_gradient() {
         let gen = FunctionPlotter2D::new();
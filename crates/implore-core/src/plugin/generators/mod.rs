@@ -5,7 +5,8 @@
 //! - **Noise**: Perlin, Simplex, Worley, Power Spectrum noise
 //! - **Fractals**: Mandelbrot, Julia sets
 //! - **Statistical**: Gaussian clusters, uniform random
-//! - **Functions**: 2D function plotter, sine/cosine, dual function plotter
+//! - **Functions**: 2D function plotter, sine/cosine, dual function plotter,
+//!   parametric curves
 
 mod dual_function;
 mod fractal;
@@ -16,7 +17,7 @@ mod statistical;
 
 pub use dual_function::DualFunction;
 pub use fractal::{JuliaSet, MandelbrotSet};
-pub use function::{FunctionPlotter2D, SineCosine};
+pub use function::{FunctionPlotter2D, ParametricCurve, SineCosine};
 pub use noise::{PerlinNoise2D, SimplexNoise2D, WorleyNoise2D};
 pub use power_spectrum::PowerSpectrumNoise;
 pub use statistical::{GaussianClusters, UniformRandom};
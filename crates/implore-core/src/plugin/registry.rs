@@ -61,6 +61,7 @@ impl GeneratorRegistry {
         self.register(Box::new(generators::FunctionPlotter2D::new()));
         self.register(Box::new(generators::SineCosine::new()));
         self.register(Box::new(generators::DualFunction::new()));
+        self.register(Box::new(generators::ParametricCurve::new()));
     }
 
     /// Register a generator with the registry.
@@ -0,0 +1,365 @@
+//! Session recording and playback
+//!
+//! A [`SessionRecorder`] logs the view changes made during a session as
+//! timestamped deltas: only the top-level [`ViewState`] fields that changed
+//! are stored, as JSON values. The resulting [`SessionRecording`] serializes
+//! to JSON so it can be saved and shared, and a [`SessionPlayer`] replays it
+//! with play/pause/seek.
+//!
+//! ```ignore
+//! let mut recorder = SessionRecorder::start(&session);
+//! recorder.record(&session.view_state)?;   // after each view change
+//! let json = recorder.finish().to_json()?;
+//!
+//! let mut player = SessionPlayer::new(SessionRecording::from_json(&json)?)?;
+//! player.play();
+//! player.advance(16);                       // once per frame
+//! player.apply_to(&mut session);
+//! ```
+
+use crate::session::VisualizationSession;
+use crate::view::ViewState;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::time::Instant;
+
+/// Top-level view state fields that changed, with their new values
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ViewStateDelta {
+    /// Field name to new value
+    pub fields: Map<String, Value>,
+}
+
+impl ViewStateDelta {
+    /// Fields of `to` that differ from `from`
+    pub fn between(from: &ViewState, to: &ViewState) -> Result<Self, serde_json::Error> {
+        Ok(Self::between_values(
+            &serde_json::to_value(from)?,
+            &serde_json::to_value(to)?,
+        ))
+    }
+
+    fn between_values(from: &Value, to: &Value) -> Self {
+        let mut fields = Map::new();
+        if let (Some(from), Some(to)) = (from.as_object(), to.as_object()) {
+            for (key, value) in to {
+                if from.get(key) != Some(value) {
+                    fields.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        Self { fields }
+    }
+
+    /// Whether nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Apply the changed fields to a view state
+    pub fn apply(&self, state: &ViewState) -> Result<ViewState, serde_json::Error> {
+        let mut value = serde_json::to_value(state)?;
+        if let Some(object) = value.as_object_mut() {
+            for (key, field) in &self.fields {
+                object.insert(key.clone(), field.clone());
+            }
+        }
+        serde_json::from_value(value)
+    }
+}
+
+/// A view change at a point in the recording
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedChange {
+    /// Milliseconds since the recording started
+    pub offset_ms: u64,
+
+    /// What changed
+    pub delta: ViewStateDelta,
+}
+
+/// A recorded sequence of view changes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecording {
+    /// Session the recording was made in
+    pub session_id: String,
+
+    /// Dataset that was being viewed
+    pub dataset_id: Option<String>,
+
+    /// View state when recording started
+    pub initial_state: ViewState,
+
+    /// View changes in time order
+    pub changes: Vec<RecordedChange>,
+
+    /// When recording started
+    pub recorded_at: String,
+}
+
+impl SessionRecording {
+    /// Length of the recording in milliseconds
+    pub fn duration_ms(&self) -> u64 {
+        self.changes.last().map(|c| c.offset_ms).unwrap_or(0)
+    }
+
+    /// Serialize for saving or sharing
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Load a saved recording
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Records view changes during a session
+pub struct SessionRecorder {
+    recording: SessionRecording,
+    last_state: Value,
+    started: Instant,
+}
+
+impl SessionRecorder {
+    /// Start recording from the session's current view
+    pub fn start(session: &VisualizationSession) -> Self {
+        Self {
+            last_state: serde_json::to_value(&session.view_state).unwrap_or(Value::Null),
+            recording: SessionRecording {
+                session_id: session.id.clone(),
+                dataset_id: session.dataset_id.clone(),
+                initial_state: session.view_state.clone(),
+                changes: Vec::new(),
+                recorded_at: chrono::Utc::now().to_rfc3339(),
+            },
+            started: Instant::now(),
+        }
+    }
+
+    /// Record the view as it is now
+    ///
+    /// Returns false if nothing changed since the last recorded view.
+    pub fn record(&mut self, state: &ViewState) -> Result<bool, serde_json::Error> {
+        let offset_ms = self.started.elapsed().as_millis() as u64;
+        self.record_at(offset_ms, state)
+    }
+
+    /// Record the view at an explicit offset from the start
+    ///
+    /// Offsets earlier than the previous change are moved up to it so the
+    /// recording stays in time order.
+    pub fn record_at(
+        &mut self,
+        offset_ms: u64,
+        state: &ViewState,
+    ) -> Result<bool, serde_json::Error> {
+        let value = serde_json::to_value(state)?;
+        let delta = ViewStateDelta::between_values(&self.last_state, &value);
+        if delta.is_empty() {
+            return Ok(false);
+        }
+        self.recording.changes.push(RecordedChange {
+            offset_ms: offset_ms.max(self.recording.duration_ms()),
+            delta,
+        });
+        self.last_state = value;
+        Ok(true)
+    }
+
+    /// Number of changes recorded so far
+    pub fn change_count(&self) -> usize {
+        self.recording.changes.len()
+    }
+
+    /// Stop recording
+    pub fn finish(self) -> SessionRecording {
+        self.recording
+    }
+}
+
+/// Replays a recording with play/pause/seek
+pub struct SessionPlayer {
+    recording: SessionRecording,
+    /// View after each change, so seeking doesn't re-apply deltas
+    states: Vec<ViewState>,
+    position_ms: u64,
+    playing: bool,
+}
+
+impl SessionPlayer {
+    /// Prepare a recording for playback, positioned at the start
+    ///
+    /// Fails if a recorded change doesn't apply to the view state (for
+    /// example, a recording from an incompatible version).
+    pub fn new(recording: SessionRecording) -> Result<Self, serde_json::Error> {
+        let mut states: Vec<ViewState> = Vec::with_capacity(recording.changes.len());
+        for change in &recording.changes {
+            let previous = states.last().unwrap_or(&recording.initial_state);
+            let next = change.delta.apply(previous)?;
+            states.push(next);
+        }
+        Ok(Self {
+            recording,
+            states,
+            position_ms: 0,
+            playing: false,
+        })
+    }
+
+    /// The recording being played
+    pub fn recording(&self) -> &SessionRecording {
+        &self.recording
+    }
+
+    /// Resume playback
+    pub fn play(&mut self) {
+        if !self.is_finished() {
+            self.playing = true;
+        }
+    }
+
+    /// Pause playback
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Whether playback is running
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Whether the position is at the end of the recording
+    pub fn is_finished(&self) -> bool {
+        self.position_ms >= self.recording.duration_ms()
+    }
+
+    /// Current position in milliseconds
+    pub fn position_ms(&self) -> u64 {
+        self.position_ms
+    }
+
+    /// Move to a position, clamped to the recording
+    pub fn seek(&mut self, position_ms: u64) {
+        self.position_ms = position_ms.min(self.recording.duration_ms());
+        if self.is_finished() {
+            self.playing = false;
+        }
+    }
+
+    /// Advance a playing recording by `elapsed_ms`
+    ///
+    /// Returns true if the view changed. Playback stops at the end.
+    pub fn advance(&mut self, elapsed_ms: u64) -> bool {
+        if !self.playing {
+            return false;
+        }
+        let before = self.applied_changes();
+        self.seek(self.position_ms.saturating_add(elapsed_ms));
+        self.applied_changes() != before
+    }
+
+    /// View state at the current position
+    pub fn state(&self) -> &ViewState {
+        match self.applied_changes() {
+            0 => &self.recording.initial_state,
+            n => &self.states[n - 1],
+        }
+    }
+
+    /// Show the current view in a session
+    pub fn apply_to(&self, session: &mut VisualizationSession) {
+        session.view_state = self.state().clone();
+        session.touch();
+    }
+
+    /// Number of changes at or before the current position
+    fn applied_changes(&self) -> usize {
+        self.recording
+            .changes
+            .partition_point(|c| c.offset_ms <= self.position_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::{Box3DConfig, RenderMode};
+
+    fn as_json(state: &ViewState) -> Value {
+        serde_json::to_value(state).unwrap()
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let mut session = VisualizationSession::new("Lecture");
+        session.dataset_id = Some("halos".to_string());
+        let mut recorder = SessionRecorder::start(&session);
+
+        let mut view = session.view_state.clone();
+        view.color_mapping.colormap = "plasma".to_string();
+        assert!(recorder.record_at(1000, &view).unwrap());
+
+        view.mode = RenderMode::Box3D(Box3DConfig::default());
+        view.camera.orbit(0.4, 0.1);
+        assert!(recorder.record_at(2500, &view).unwrap());
+
+        // Re-recording an unchanged view adds nothing
+        assert!(!recorder.record_at(3000, &view).unwrap());
+
+        view.point_size = 4.0;
+        view.show_grid = !view.show_grid;
+        assert!(recorder.record_at(4000, &view).unwrap());
+        assert_eq!(recorder.change_count(), 3);
+
+        let recording = recorder.finish();
+        assert_eq!(recording.changes[0].delta.fields.len(), 1);
+        assert_eq!(recording.duration_ms(), 4000);
+
+        // Share via JSON and replay into a fresh session
+        let json = recording.to_json().unwrap();
+        let mut player = SessionPlayer::new(SessionRecording::from_json(&json).unwrap()).unwrap();
+        assert_eq!(as_json(player.state()), as_json(&session.view_state));
+
+        player.play();
+        assert!(player.advance(1200));
+        assert_eq!(player.state().color_mapping.colormap, "plasma");
+        assert!(!player.advance(100));
+
+        player.pause();
+        assert!(!player.advance(5000));
+        assert_eq!(player.position_ms(), 1300);
+
+        player.play();
+        while player.is_playing() {
+            player.advance(16);
+        }
+        assert!(player.is_finished());
+
+        let mut replay = VisualizationSession::new("Replay");
+        player.apply_to(&mut replay);
+        assert_eq!(as_json(&replay.view_state), as_json(&view));
+    }
+
+    #[test]
+    fn test_seek() {
+        let session = VisualizationSession::new("Seek");
+        let mut recorder = SessionRecorder::start(&session);
+        let mut view = session.view_state.clone();
+        for (i, colormap) in ["magma", "cividis", "inferno"].iter().enumerate() {
+            view.color_mapping.colormap = colormap.to_string();
+            recorder.record_at(1000 * (i as u64 + 1), &view).unwrap();
+        }
+        let mut player = SessionPlayer::new(recorder.finish()).unwrap();
+
+        player.seek(2000);
+        assert_eq!(player.state().color_mapping.colormap, "cividis");
+        player.seek(1999);
+        assert_eq!(player.state().color_mapping.colormap, "magma");
+        player.seek(0);
+        assert_eq!(as_json(player.state()), as_json(&session.view_state));
+        player.seek(u64::MAX);
+        assert_eq!(player.position_ms(), 3000);
+        assert_eq!(player.state().color_mapping.colormap, "inferno");
+    }
+}
@@ -242,6 +242,10 @@ pub struct RenderConfig {
     pub point_size: f32,
     /// Point size range (min, max)
     pub point_size_range: (f32, f32),
+    /// Field whose values set per-point size (None = uniform `point_size`)
+    pub size_field: Option<String>,
+    /// Whether to map size field values logarithmically
+    pub size_log_scale: bool,
     /// Background color [r, g, b, a]
     pub background_color: [f32; 4],
     /// Selection highlight color
@@ -259,12 +263,49 @@ impl Default for RenderConfig {
             colormap: ColormapConfig::default(),
             point_size: 4.0,
             point_size_range: (1.0, 20.0),
+            size_field: None,
+            size_log_scale: false,
             background_color: [0.05, 0.05, 0.08, 1.0], // Dark blue-gray
             selection_color: [1.0, 0.8, 0.0, 1.0],     // Gold
         }
     }
 }
 
+impl RenderConfig {
+    /// Per-point sizes in pixels for the values of `size_field`
+    ///
+    /// Values are mapped from their finite range onto `point_size_range`,
+    /// linearly or by log10 with `size_log_scale`. NaN and infinite values
+    /// (and non-positive ones on a log scale) get the minimum size, as does
+    /// every point when all values are equal.
+    pub fn point_sizes(&self, values: &[f64]) -> Vec<f32> {
+        let (min_size, max_size) = self.point_size_range;
+        let transform = |v: f64| {
+            let t = if self.size_log_scale { v.log10() } else { v };
+            t.is_finite().then_some(t)
+        };
+
+        let (lo, hi) = values
+            .iter()
+            .filter_map(|&v| transform(v))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), t| {
+                (lo.min(t), hi.max(t))
+            });
+        let span = hi - lo;
+
+        values
+            .iter()
+            .map(|&v| match transform(v) {
+                Some(t) if span > 0.0 => {
+                    let frac = ((t - lo) / span) as f32;
+                    (min_size + frac * (max_size - min_size)).clamp(min_size, max_size)
+                }
+                _ => min_size,
+            })
+            .collect()
+    }
+}
+
 /// 3D wireframe box for bounding visualization
 #[derive(Clone, Debug)]
 pub struct WireframeBox {
@@ -616,6 +657,25 @@ mod tests {
         assert!(config.show_statistics);
     }
 
+    #[test]
+    fn test_point_sizes_linear_and_log() {
+        let mut config = RenderConfig {
+            point_size_range: (2.0, 10.0),
+            size_field: Some("mass".to_string()),
+            ..Default::default()
+        };
+
+        let sizes = config.point_sizes(&[0.0, 25.0, 50.0, 100.0, f64::NAN]);
+        assert_eq!(sizes, vec![2.0, 4.0, 6.0, 10.0, 2.0]);
+
+        config.size_log_scale = true;
+        let sizes = config.point_sizes(&[1.0, 10.0, 100.0, 1000.0, 10000.0, -1.0]);
+        assert_eq!(sizes, vec![2.0, 4.0, 6.0, 8.0, 10.0, 2.0]);
+
+        // Constant values have no range to map
+        assert_eq!(config.point_sizes(&[5.0, 5.0]), vec![2.0, 2.0]);
+    }
+
     #[test]
     fn test_wireframe_box_vertices() {
         let bbox = WireframeBox::unit();
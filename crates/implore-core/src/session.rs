@@ -116,7 +116,7 @@ impl VisualizationSession {
     }
 
     /// Update the modification timestamp
-    fn touch(&mut self) {
+    pub(crate) fn touch(&mut self) {
         self.modified_at = chrono::Utc::now().to_rfc3339();
     }
 }
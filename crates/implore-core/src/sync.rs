@@ -7,7 +7,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
+use crate::automation::ExportFigureToDocumentCommand;
 use crate::library::{FigureLibrary, ImprintLink, LibraryFigure};
+use crate::session::FigureFormat;
 
 /// Notification sent when a figure is modified
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -213,6 +215,59 @@ impl FigureSyncService {
         }
     }
 
+    /// Handle an `implore://export-figure` request
+    ///
+    /// `render` exports the figure in the requested format. The export is
+    /// returned for delivery to imprint and the outcome is recorded in the
+    /// recent results; a linked document is marked as synced. Returns `None`
+    /// if the figure isn't in the library or the export fails.
+    pub fn export_figure<F>(
+        &mut self,
+        library: &mut FigureLibrary,
+        command: &ExportFigureToDocumentCommand,
+        render: F,
+    ) -> Option<FigureExportData>
+    where
+        F: FnOnce(&LibraryFigure, FigureFormat) -> Result<FigureExportData, String>,
+    {
+        let document_id = command.imprint_document_id.clone();
+        let Some(figure) = library.get_figure_mut(&command.figure_id) else {
+            self.add_result(SyncResult::Failed {
+                document_id,
+                figure_label: command.figure_id.clone(),
+                error: "Figure not found".to_string(),
+            });
+            return None;
+        };
+
+        let figure_label = figure
+            .imprint_links
+            .iter()
+            .find(|l| l.document_id == document_id)
+            .map(|l| l.figure_label.clone())
+            .unwrap_or_else(|| figure.title.clone());
+
+        match render(figure, command.format) {
+            Ok(export) => {
+                figure.mark_synced(&document_id);
+                self.add_result(SyncResult::Success {
+                    document_id,
+                    figure_label,
+                    synced_at: chrono::Utc::now().to_rfc3339(),
+                });
+                Some(export)
+            }
+            Err(error) => {
+                self.add_result(SyncResult::Failed {
+                    document_id,
+                    figure_label,
+                    error,
+                });
+                None
+            }
+        }
+    }
+
     /// Handle figure being unlinked from a document
     ///
     /// Called when imprint notifies that a figure reference was removed.
@@ -485,6 +540,53 @@ mod tests {
         assert!(url.contains(&format!("figure_id={}", urlencoding::encode(&figure.id))));
     }
 
+    #[test]
+    fn test_export_figure_from_url() {
+        use crate::automation::{build_export_figure_url, ImploreCommand};
+
+        let mut service = FigureSyncService::new();
+        let mut library = create_test_library();
+        let figure_id = library.figures[0].id.clone();
+
+        let url = build_export_figure_url(&figure_id, "doc-1", FigureFormat::Svg);
+        let command = match ImploreCommand::parse(&url).unwrap() {
+            ImploreCommand::ExportFigure(command) => command,
+            other => panic!("Expected ExportFigure command, got {:?}", other),
+        };
+
+        let export = service
+            .export_figure(&mut library, &command, |figure, format| {
+                Ok(FigureExportData {
+                    format: format.extension().to_string(),
+                    data: figure.title.as_bytes().to_vec(),
+                    width: 640,
+                    height: 480,
+                    dpi: 72,
+                })
+            })
+            .unwrap();
+
+        assert_eq!(export.format, "svg");
+        assert_eq!(export.data, b"Test Figure");
+        assert!(matches!(
+            service.recent_results().back(),
+            Some(SyncResult::Success { document_id, figure_label, .. })
+                if document_id == "doc-1" && figure_label == "fig:test"
+        ));
+        let figure = library.get_figure(&figure_id).unwrap();
+        assert!(figure.imprint_links[0].last_synced.is_some());
+
+        // Unknown figures are reported, not exported
+        let missing = ExportFigureToDocumentCommand {
+            figure_id: "missing".to_string(),
+            ..command
+        };
+        assert!(service
+            .export_figure(&mut library, &missing, |_, _| unreachable!())
+            .is_none());
+        assert_eq!(service.stats().total_failed, 1);
+    }
+
     #[test]
     fn test_stats_after_processing() {
         let mut service = FigureSyncService::new();
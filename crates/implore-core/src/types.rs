@@ -7,7 +7,7 @@
 use serde::{Deserialize, Serialize};
 
 /// A 2D vector of f32 values
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Vec2f {
     pub x: f32,
@@ -40,7 +40,7 @@ impl From<Vec2f> for [f32; 2] {
 }
 
 /// A 3D vector of f32 values
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Vec3f {
     pub x: f32,
@@ -103,7 +103,7 @@ impl From<Vec3f> for [f32; 3] {
 }
 
 /// A 4D vector of f32 values
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Vec4f {
     pub x: f32,
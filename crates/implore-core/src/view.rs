@@ -7,6 +7,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::axis::AxisScale;
 use crate::types::{ColorRgb, Vec2f, Vec3d, Vec3f, Vec4f};
 
 /// Current state of the visualization view
@@ -43,34 +44,99 @@ pub struct ViewState {
 
 impl Default for ViewState {
     fn default() -> Self {
+        Self::defaults_for(RenderMode::default())
+    }
+}
+
+impl ViewState {
+    /// Default view for a render mode
+    ///
+    /// Science2D looks straight down the z axis with an orthographic camera;
+    /// Box3D and ArtShader use perspective cameras, and ArtShader drops the
+    /// axes and grid for a black backdrop.
+    pub fn defaults_for(mode: RenderMode) -> Self {
+        let (camera, colormap, show_axes, show_grid, background_color) = match mode {
+            RenderMode::Science2D(_) => (
+                Camera3D {
+                    position: Vec3f::new(0.0, 0.0, 10.0),
+                    orthographic: true,
+                    ..Default::default()
+                },
+                "viridis",
+                true,
+                true,
+                ColorRgb::dark_gray(),
+            ),
+            RenderMode::Box3D(_) => (
+                Camera3D::default(),
+                "viridis",
+                true,
+                false,
+                ColorRgb::dark_gray(),
+            ),
+            RenderMode::ArtShader(_) => (
+                Camera3D::look_at_origin(5.0),
+                "inferno",
+                false,
+                false,
+                ColorRgb::black(),
+            ),
+        };
+
         Self {
-            mode: RenderMode::default(),
-            camera: Camera3D::default(),
-            color_mapping: ColorMapping::default(),
+            mode,
+            camera,
+            color_mapping: ColorMapping {
+                colormap: colormap.to_string(),
+                ..Default::default()
+            },
             point_size: 2.0,
             selection_bounds: None,
             visible_layers: vec!["default".to_string()],
-            show_axes: true,
-            show_grid: true,
-            background_color: ColorRgb::dark_gray(),
+            show_axes,
+            show_grid,
+            background_color,
         }
     }
-}
 
-impl ViewState {
+    /// Switch render mode, adopting the new mode's camera, axes, grid, and
+    /// background while keeping the selection, layers, point size, and color
+    /// mapping.
+    ///
+    /// The colormap follows the new mode's default only if it was still the
+    /// old mode's default, so a colormap the user picked survives the switch.
+    pub fn switch_mode(&mut self, new_mode: RenderMode) {
+        let old_defaults = Self::defaults_for(self.mode.clone());
+        let new_defaults = Self::defaults_for(new_mode);
+
+        if self.color_mapping.colormap == old_defaults.color_mapping.colormap {
+            self.color_mapping.colormap = new_defaults.color_mapping.colormap;
+        }
+        self.mode = new_defaults.mode;
+        self.camera = new_defaults.camera;
+        self.show_axes = new_defaults.show_axes;
+        self.show_grid = new_defaults.show_grid;
+        self.background_color = new_defaults.background_color;
+    }
+
     /// Cycle to the next render mode
     pub fn cycle_mode(&mut self) {
-        self.mode = self.mode.cycle();
+        self.switch_mode(self.mode.cycle());
     }
 
     /// Reset to default view
     pub fn reset(&mut self) {
         *self = Self::default();
     }
+
+    /// `implore://view` URL that reopens this camera, colormap, and mode
+    pub fn to_url(&self) -> String {
+        crate::automation::build_view_url(&self.camera, &self.color_mapping.colormap, &self.mode)
+    }
 }
 
 /// Rendering mode for the visualization
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum RenderMode {
     /// 2D statistical plots with axes and marginals
@@ -120,7 +186,7 @@ impl RenderMode {
 }
 
 /// Configuration for 2D scientific plots
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Science2DConfig {
     /// Field to map to X axis
@@ -135,10 +201,10 @@ pub struct Science2DConfig {
     /// Whether to show Y marginal (ECDF)
     pub show_y_marginal: bool,
 
-    /// Axis scale for X (linear or log)
+    /// Axis scale for X (linear, log, or symlog)
     pub x_scale: AxisScale,
 
-    /// Axis scale for Y (linear or log)
+    /// Axis scale for Y (linear, log, or symlog)
     pub y_scale: AxisScale,
 
     /// Whether to show grid lines
@@ -160,7 +226,7 @@ impl Default for Science2DConfig {
 }
 
 /// Configuration for 3D box view
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Box3DConfig {
     /// Field to map to X axis
@@ -200,7 +266,7 @@ impl Default for Box3DConfig {
 }
 
 /// Configuration for art shader mode
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct ArtShaderConfig {
     /// Shader name or identifier
@@ -228,7 +294,7 @@ impl Default for ArtShaderConfig {
 }
 
 /// A shader parameter with name and value
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct ShaderParameter {
     pub name: String,
@@ -236,7 +302,7 @@ pub struct ShaderParameter {
 }
 
 /// Value types for shader parameters
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum ShaderValue {
     Float(f32),
@@ -248,7 +314,7 @@ pub enum ShaderValue {
 }
 
 /// Post-processing effects
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum PostEffect {
     /// Bloom effect with intensity
@@ -264,43 +330,8 @@ pub enum PostEffect {
     ColorGrade { lut_name: String },
 }
 
-/// Axis scale type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
-pub enum AxisScale {
-    Linear,
-    Log10,
-    Log2,
-    SymLog,
-}
-
-impl AxisScale {
-    /// Transform a value according to this scale
-    pub fn transform(&self, value: f64) -> f64 {
-        match self {
-            AxisScale::Linear => value,
-            AxisScale::Log10 => value.log10(),
-            AxisScale::Log2 => value.log2(),
-            AxisScale::SymLog => {
-                // Symmetric log: sign(x) * log10(1 + |x|)
-                value.signum() * (1.0 + value.abs()).log10()
-            }
-        }
-    }
-
-    /// Inverse transform
-    pub fn inverse(&self, value: f64) -> f64 {
-        match self {
-            AxisScale::Linear => value,
-            AxisScale::Log10 => 10.0_f64.powf(value),
-            AxisScale::Log2 => 2.0_f64.powf(value),
-            AxisScale::SymLog => value.signum() * (10.0_f64.powf(value.abs()) - 1.0),
-        }
-    }
-}
-
 /// 3D camera with position, target, and projection
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Camera3D {
     /// Camera position in world space
@@ -558,6 +589,46 @@ mod tests {
         assert!(matches!(mode, RenderMode::Science2D(_)));
     }
 
+    #[test]
+    fn test_switch_mode_preserves_selection() {
+        let mut state = ViewState::default();
+        assert!(state.camera.orthographic);
+
+        let bounds = SelectionBounds::from_arrays([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        state.selection_bounds = Some(bounds.clone());
+        state.point_size = 4.0;
+
+        state.switch_mode(RenderMode::Box3D(Box3DConfig::default()));
+        assert!(matches!(state.mode, RenderMode::Box3D(_)));
+        assert!(!state.camera.orthographic);
+        assert_eq!(
+            state.camera,
+            ViewState::defaults_for(state.mode.clone()).camera
+        );
+        assert_eq!(
+            state.selection_bounds.as_ref().map(|b| b.center().x),
+            Some(bounds.center().x)
+        );
+        assert_eq!(state.point_size, 4.0);
+
+        state.switch_mode(RenderMode::Science2D(Science2DConfig::default()));
+        assert_eq!(state.camera, ViewState::default().camera);
+        assert!(state.selection_bounds.is_some());
+    }
+
+    #[test]
+    fn test_switch_mode_keeps_custom_colormap() {
+        let mut state = ViewState::default();
+        state.cycle_mode();
+        state.cycle_mode();
+        assert!(matches!(state.mode, RenderMode::ArtShader(_)));
+        assert_eq!(state.color_mapping.colormap, "inferno");
+
+        state.color_mapping.colormap = "cividis".to_string();
+        state.cycle_mode();
+        assert_eq!(state.color_mapping.colormap, "cividis");
+    }
+
     #[test]
     fn test_camera_orbit() {
         let mut camera = Camera3D::look_at_origin(5.0);
@@ -584,7 +655,7 @@ mod tests {
     #[test]
     fn test_axis_scale_transform() {
         assert!((AxisScale::Linear.transform(10.0) - 10.0).abs() < 1e-10);
-        assert!((AxisScale::Log10.transform(100.0) - 2.0).abs() < 1e-10);
+        assert!((AxisScale::Log.transform(100.0) - 2.0).abs() < 1e-10);
         assert!((AxisScale::Log2.transform(8.0) - 3.0).abs() < 1e-10);
     }
 
@@ -2,7 +2,7 @@
 
 use crate::reader::{DataReader, IoError, IoResult};
 use crate::schema::{ColumnDescriptor, ColumnType, DataColumn, DataSchema, DataSlice};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
@@ -13,6 +13,7 @@ pub struct CsvReader {
     schema: DataSchema,
     metadata: HashMap<String, String>,
     delimiter: u8,
+    has_header: bool,
 }
 
 impl CsvReader {
@@ -21,6 +22,13 @@ impl CsvReader {
         Self::open_with_options(path, b',', true)
     }
 
+    /// Open a CSV file whose first row is data rather than a header
+    ///
+    /// Columns are named `column_1`, `column_2`, ...
+    pub fn open_without_header(path: &str) -> IoResult<Self> {
+        Self::open_with_options(path, b',', false)
+    }
+
     /// Open a CSV file with options
     pub fn open_with_options(path: &str, delimiter: u8, has_header: bool) -> IoResult<Self> {
         if !Path::new(path).exists() {
@@ -39,15 +47,25 @@ impl CsvReader {
         let mut metadata = HashMap::new();
         metadata.insert("format".to_string(), "CSV".to_string());
         metadata.insert("delimiter".to_string(), (delimiter as char).to_string());
+        metadata.insert("has_header".to_string(), has_header.to_string());
 
         Ok(Self {
             path: path.to_string(),
             schema,
             metadata,
             delimiter,
+            has_header,
         })
     }
 
+    fn reader(&self) -> IoResult<csv::Reader<BufReader<File>>> {
+        let file = File::open(&self.path).map_err(|e| IoError::OpenFailed(e.to_string()))?;
+        Ok(csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(self.has_header)
+            .from_reader(BufReader::new(file)))
+    }
+
     fn infer_schema(
         reader: &mut csv::Reader<BufReader<File>>,
         has_header: bool,
@@ -60,16 +78,19 @@ impl CsvReader {
                 .map(|s| s.to_string())
                 .collect::<Vec<_>>()
         } else {
-            // Generate column names
-            (0..10).map(|i| format!("col_{}", i)).collect()
+            Vec::new()
         };
 
-        // Read a few rows to infer types
+        // Read a few rows to infer types; without a header the width comes
+        // from the data itself
         let mut sample_values: Vec<Vec<String>> = vec![Vec::new(); headers.len()];
         let mut num_records = 0;
 
         for result in reader.records() {
             let record = result.map_err(|e| IoError::InvalidFormat(e.to_string()))?;
+            if !has_header && record.len() > sample_values.len() {
+                sample_values.resize(record.len(), Vec::new());
+            }
             for (i, value) in record.iter().enumerate() {
                 if i < sample_values.len() {
                     sample_values[i].push(value.to_string());
@@ -90,7 +111,8 @@ impl CsvReader {
         }
 
         // Infer types
-        let columns: Vec<ColumnDescriptor> = headers
+        let width = headers.len().max(sample_values.len());
+        let columns: Vec<ColumnDescriptor> = sanitize_column_names(&headers, width)
             .into_iter()
             .enumerate()
             .map(|(i, name)| {
@@ -99,7 +121,11 @@ impl CsvReader {
                 } else {
                     ColumnType::String
                 };
-                ColumnDescriptor::new(name, dtype)
+                let column = ColumnDescriptor::new(name.clone(), dtype);
+                match headers.get(i) {
+                    Some(original) if *original != name => column.with_original_name(original),
+                    _ => column,
+                }
             })
             .collect();
 
@@ -120,12 +146,7 @@ impl DataReader for CsvReader {
 
         let col_desc = &self.schema.columns[col_index];
 
-        let file = File::open(&self.path).map_err(|e| IoError::OpenFailed(e.to_string()))?;
-        let mut reader = csv::ReaderBuilder::new()
-            .delimiter(self.delimiter)
-            .has_headers(true)
-            .from_reader(BufReader::new(file));
-
+        let mut reader = self.reader()?;
         let values: Vec<String> = reader
             .records()
             .filter_map(|r| r.ok())
@@ -136,11 +157,7 @@ impl DataReader for CsvReader {
     }
 
     fn read_range(&self, start: usize, end: usize) -> IoResult<DataSlice> {
-        let file = File::open(&self.path).map_err(|e| IoError::OpenFailed(e.to_string()))?;
-        let mut reader = csv::ReaderBuilder::new()
-            .delimiter(self.delimiter)
-            .has_headers(true)
-            .from_reader(BufReader::new(file));
+        let mut reader = self.reader()?;
 
         let mut columns: Vec<Vec<String>> = vec![Vec::new(); self.schema.num_columns()];
 
@@ -182,6 +199,31 @@ impl DataReader for CsvReader {
     }
 }
 
+/// Build unique, non-empty column names from raw CSV headers.
+///
+/// Empty headers become `column_N` (1-based position) and repeats get a
+/// numeric suffix: `value`, `value_2`, `value_3`. Columns beyond the header
+/// row (or all columns, with no header) are named like empty headers.
+fn sanitize_column_names(headers: &[String], width: usize) -> Vec<String> {
+    let mut used = HashSet::new();
+    (0..width)
+        .map(|i| {
+            let base = match headers.get(i).map(|h| h.trim()) {
+                Some(h) if !h.is_empty() => h.to_string(),
+                _ => format!("column_{}", i + 1),
+            };
+            let mut name = base.clone();
+            let mut n = 2;
+            while used.contains(&name) {
+                name = format!("{}_{}", base, n);
+                n += 1;
+            }
+            used.insert(name.clone());
+            name
+        })
+        .collect()
+}
+
 /// Infer column type from sample values
 fn infer_type(values: &[String]) -> ColumnType {
     if values.is_empty() {
@@ -255,6 +297,75 @@ fn parse_column(values: &[String], dtype: ColumnType) -> DataColumn {
 mod tests {
     use super::*;
 
+    use std::io::Write;
+
+    fn write_csv(name: &str, contents: &str) -> String {
+        let path =
+            std::env::temp_dir().join(format!("implore_csv_{}_{}.csv", name, std::process::id()));
+        let mut file = File::create(&path).unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_sanitize_column_names() {
+        let headers: Vec<String> = ["value", "", "value", " value ", "value_2"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            sanitize_column_names(&headers, 6),
+            vec![
+                "value",
+                "column_2",
+                "value_2",
+                "value_3",
+                "value_2_2",
+                "column_6"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_value_columns() {
+        let path = write_csv("dup", "time,value,value\n0,1.5,10\n1,2.5,20\n");
+        let reader = CsvReader::open(&path).unwrap();
+        let schema = reader.read_schema().unwrap();
+
+        assert_eq!(schema.column_names(), vec!["time", "value", "value_2"]);
+        assert_eq!(schema.columns[1].original_name, None);
+        assert_eq!(schema.columns[2].original_name.as_deref(), Some("value"));
+        assert_eq!(schema.columns[2].dtype, ColumnType::Int64);
+
+        match reader.read_column("value_2").unwrap() {
+            DataColumn::Int64(values) => assert_eq!(values, vec![10, 20]),
+            other => panic!("unexpected column {:?}", other),
+        }
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_headerless_file() {
+        let path = write_csv("noheader", "1,a\n2,b\n3,c\n");
+        let reader = CsvReader::open_without_header(&path).unwrap();
+        let schema = reader.read_schema().unwrap();
+
+        assert_eq!(schema.column_names(), vec!["column_1", "column_2"]);
+        assert_eq!(schema.num_records, 3);
+
+        // The first row is data, not a header
+        match reader.read_column("column_1").unwrap() {
+            DataColumn::Int64(values) => assert_eq!(values, vec![1, 2, 3]),
+            other => panic!("unexpected column {:?}", other),
+        }
+        let slice = reader.read_range(0, 1).unwrap();
+        match slice.column("column_2").unwrap() {
+            DataColumn::String(values) => assert_eq!(values, &vec!["a".to_string()]),
+            other => panic!("unexpected column {:?}", other),
+        }
+        std::fs::remove_file(path).ok();
+    }
+
     #[test]
     fn test_infer_type_int() {
         let values = vec!["1".to_string(), "2".to_string(), "3".to_string()];
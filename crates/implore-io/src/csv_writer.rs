@@ -0,0 +1,266 @@
+//! CSV file writer with configurable formatting
+//!
+//! Fields containing the delimiter, a quote, or a line break are quoted per
+//! RFC 4180, so files written here read back with [`CsvReader`] unchanged.
+//!
+//! [`CsvReader`]: crate::csv_reader::CsvReader
+
+use crate::reader::{IoError, IoResult};
+use crate::schema::{DataColumn, DataSchema, DataSlice};
+use crate::writer::DataWriter;
+
+/// Formatting options for CSV output
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvWriteOptions {
+    /// Field delimiter
+    pub delimiter: u8,
+
+    /// Digits after the decimal point for float columns; `None` writes the
+    /// shortest representation that reads back exactly
+    pub float_precision: Option<usize>,
+
+    /// Whether to write a header row of column names
+    pub include_header: bool,
+
+    /// Text written for missing (NaN) float values
+    pub na_repr: String,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            float_precision: None,
+            include_header: true,
+            na_repr: String::new(),
+        }
+    }
+}
+
+impl CsvWriteOptions {
+    /// Set the field delimiter
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set the number of digits after the decimal point for floats
+    pub fn with_float_precision(mut self, precision: usize) -> Self {
+        self.float_precision = Some(precision);
+        self
+    }
+
+    /// Set whether to write a header row
+    pub fn with_header(mut self, include_header: bool) -> Self {
+        self.include_header = include_header;
+        self
+    }
+
+    /// Set the text written for missing values
+    pub fn with_na_repr(mut self, na_repr: impl Into<String>) -> Self {
+        self.na_repr = na_repr.into();
+        self
+    }
+}
+
+/// CSV file writer
+pub struct CsvWriter {
+    path: String,
+    options: CsvWriteOptions,
+}
+
+impl CsvWriter {
+    /// Create a writer for a new CSV file with default options
+    pub fn create(path: &str) -> IoResult<Self> {
+        Self::create_with_options(path, CsvWriteOptions::default())
+    }
+
+    /// Create a writer for a new CSV file with options
+    ///
+    /// The file is created (or truncated) when the dataset is written.
+    pub fn create_with_options(path: &str, options: CsvWriteOptions) -> IoResult<Self> {
+        Ok(Self {
+            path: path.to_string(),
+            options,
+        })
+    }
+
+    /// The formatting options in use
+    pub fn options(&self) -> &CsvWriteOptions {
+        &self.options
+    }
+
+    fn format_float<T: std::fmt::Display>(&self, value: T, is_nan: bool) -> String {
+        if is_nan {
+            return self.options.na_repr.clone();
+        }
+        match self.options.float_precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        }
+    }
+
+    fn format_value(&self, column: &DataColumn, row: usize) -> String {
+        match column {
+            DataColumn::Float32(v) => self.format_float(v[row], v[row].is_nan()),
+            DataColumn::Float64(v) => self.format_float(v[row], v[row].is_nan()),
+            DataColumn::Int32(v) => v[row].to_string(),
+            DataColumn::Int64(v) => v[row].to_string(),
+            DataColumn::Bool(v) => v[row].to_string(),
+            DataColumn::String(v) => v[row].clone(),
+        }
+    }
+}
+
+impl DataWriter for CsvWriter {
+    fn write(&mut self, schema: &DataSchema, data: &DataSlice) -> IoResult<()> {
+        let columns = schema
+            .columns
+            .iter()
+            .map(|desc| {
+                let column = data
+                    .column(&desc.name)
+                    .ok_or_else(|| IoError::ColumnNotFound(desc.name.clone()))?;
+                if column.len() != data.num_rows {
+                    return Err(IoError::OutOfBounds {
+                        index: column.len(),
+                        size: data.num_rows,
+                    });
+                }
+                Ok(column)
+            })
+            .collect::<IoResult<Vec<_>>>()?;
+
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(self.options.delimiter)
+            .quote_style(csv::QuoteStyle::Necessary)
+            .from_path(&self.path)
+            .map_err(|e| IoError::Io(e.to_string()))?;
+
+        if self.options.include_header {
+            writer
+                .write_record(schema.column_names())
+                .map_err(|e| IoError::WriteFailed(e.to_string()))?;
+        }
+
+        let mut record = Vec::with_capacity(columns.len());
+        for row in 0..data.num_rows {
+            record.clear();
+            record.extend(columns.iter().map(|c| self.format_value(c, row)));
+            writer
+                .write_record(&record)
+                .map_err(|e| IoError::WriteFailed(e.to_string()))?;
+        }
+
+        writer
+            .flush()
+            .map_err(|e| IoError::WriteFailed(e.to_string()))
+    }
+
+    fn path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+
+    fn format_name(&self) -> &'static str {
+        "CSV"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csv_reader::CsvReader;
+    use crate::reader::DataReader;
+    use crate::schema::{ColumnDescriptor, ColumnType};
+
+    fn temp_path(name: &str, extension: &str) -> String {
+        std::env::temp_dir()
+            .join(format!(
+                "implore_csv_writer_{}_{}.{}",
+                name,
+                std::process::id(),
+                extension
+            ))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    fn catalog() -> (DataSchema, DataSlice) {
+        let schema = DataSchema::new(
+            vec![
+                ColumnDescriptor::new("name", ColumnType::String),
+                ColumnDescriptor::new("redshift", ColumnType::Float64),
+                ColumnDescriptor::new("count", ColumnType::Int64),
+                ColumnDescriptor::new("resolved", ColumnType::Bool),
+            ],
+            3,
+        );
+        let mut data = DataSlice::new(0);
+        data.add_column(
+            "name",
+            DataColumn::String(vec![
+                "Abell 1689".to_string(),
+                "Coma, Perseus".to_string(),
+                "the \"Bullet\"\ncluster".to_string(),
+            ]),
+        );
+        data.add_column("redshift", DataColumn::Float64(vec![0.183, 0.0231, 0.296]));
+        data.add_column("count", DataColumn::Int64(vec![12, -3, 400]));
+        data.add_column("resolved", DataColumn::Bool(vec![true, false, true]));
+        (schema, data)
+    }
+
+    #[test]
+    fn test_round_trip_with_quoted_fields() {
+        let (schema, data) = catalog();
+        let path = temp_path("round_trip", "csv");
+        CsvWriter::create(&path)
+            .unwrap()
+            .write(&schema, &data)
+            .unwrap();
+
+        let reader = CsvReader::open(&path).unwrap();
+        let read_schema = reader.read_schema().unwrap();
+        assert_eq!(read_schema.num_records, 3);
+        assert_eq!(read_schema.column_names(), schema.column_names());
+        for name in schema.column_names() {
+            assert_eq!(
+                reader.read_column(name).unwrap(),
+                *data.column(name).unwrap(),
+                "column {}",
+                name
+            );
+        }
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_formatting_options() {
+        let (schema, mut data) = catalog();
+        data.add_column(
+            "redshift",
+            DataColumn::Float64(vec![0.183, f64::NAN, 0.296]),
+        );
+        let options = CsvWriteOptions::default()
+            .with_delimiter(b'\t')
+            .with_float_precision(2)
+            .with_header(false)
+            .with_na_repr("NA");
+        let path = temp_path("options", "tsv");
+        CsvWriter::create_with_options(&path, options)
+            .unwrap()
+            .write(&schema, &data)
+            .unwrap();
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            text,
+            "Abell 1689\t0.18\t12\ttrue\n\
+             Coma, Perseus\tNA\t-3\tfalse\n\
+             \"the \"\"Bullet\"\"\ncluster\"\t0.30\t400\ttrue\n"
+        );
+
+        std::fs::remove_file(&path).ok();
+    }
+}
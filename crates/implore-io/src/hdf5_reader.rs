@@ -5,10 +5,11 @@
 //! - Reading datasets by path within the file
 //! - Automatic type detection and conversion
 //! - Chunked reading for memory efficiency
-//! - Attribute extraction for metadata
+//! - Attribute extraction for metadata, including `units` and `long_name`
+//!   attributes as column units and descriptions
 
 #[cfg(feature = "hdf5")]
-use hdf5::{types::TypeDescriptor, Dataset as Hdf5Dataset, File as Hdf5File};
+use hdf5::{types::TypeDescriptor, Dataset as Hdf5Dataset, File as Hdf5File, Location};
 
 use crate::reader::{DataReader, IoError, IoResult};
 use crate::schema::{ColumnDescriptor, ColumnType, DataColumn, DataSchema, DataSlice};
@@ -45,15 +46,7 @@ impl Hdf5Reader {
         metadata.insert("dataset_path".to_string(), dataset_path.to_string());
 
         // Extract file-level attributes
-        if let Ok(attrs) = file.attr_names() {
-            for attr_name in attrs {
-                if let Ok(attr) = file.attr(&attr_name) {
-                    if let Ok(value) = attr.read_scalar::<hdf5::types::VarLenUnicode>() {
-                        metadata.insert(attr_name.clone(), value.to_string());
-                    }
-                }
-            }
-        }
+        metadata.extend(read_attributes(&file));
 
         Ok(Self {
             path: path.to_string(),
@@ -97,7 +90,7 @@ impl Hdf5Reader {
 
     fn infer_schema(
         dataset: &Hdf5Dataset,
-        _file: &Hdf5File,
+        file: &Hdf5File,
         dataset_path: &str,
     ) -> IoResult<DataSchema> {
         let shape = dataset.shape();
@@ -135,20 +128,60 @@ impl Hdf5Reader {
             }
         };
 
-        // Try to get column names from attributes
+        // Units and descriptions: one value for every column, or one per
+        // column of a 2D dataset
+        let num_cols = columns.len();
+        let per_column = |names: &[&str]| -> Vec<Option<String>> {
+            let values = names
+                .iter()
+                .find_map(|name| read_attribute_values(dataset, name))
+                .unwrap_or_default();
+            (0..num_cols)
+                .map(|i| match values.len() {
+                    1 => values.first().cloned(),
+                    n if n == num_cols => values.get(i).cloned(),
+                    _ => None,
+                })
+                .collect()
+        };
+        let units = per_column(&["units", "unit"]);
+        let descriptions = per_column(&["long_name", "description"]);
+
+        let columns = columns
+            .into_iter()
+            .zip(units.into_iter().zip(descriptions))
+            .map(|(col, (unit, description))| {
+                let col = match unit {
+                    Some(unit) if !unit.is_empty() => col.with_unit(unit),
+                    _ => col,
+                };
+                match description {
+                    Some(desc) if !desc.is_empty() => col.with_description(desc),
+                    _ => col,
+                }
+            })
+            .collect();
+
         let mut schema = DataSchema::new(columns, num_records);
 
-        // Add dataset attributes to metadata
-        if let Ok(attr_names) = dataset.attr_names() {
-            for attr_name in attr_names {
-                if let Ok(attr) = dataset.attr(&attr_name) {
-                    if let Ok(value) = attr.read_scalar::<hdf5::types::VarLenUnicode>() {
-                        schema.metadata.insert(attr_name, value.to_string());
-                    }
-                }
+        // Attributes of enclosing groups, outermost first so nearer groups win
+        let mut group_path = String::new();
+        let parents: Vec<&str> = dataset_path
+            .trim_matches('/')
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect();
+        for segment in parents.iter().take(parents.len().saturating_sub(1)) {
+            group_path.push('/');
+            group_path.push_str(segment);
+            if let Ok(group) = file.group(&group_path) {
+                schema.metadata.extend(read_attributes(&group));
             }
         }
 
+        // Dataset attributes override group attributes
+        schema.metadata.extend(read_attributes(dataset));
+
         Ok(schema)
     }
 
@@ -281,6 +314,51 @@ impl Hdf5Reader {
     }
 }
 
+/// Read every attribute of a file, group, or dataset as display strings
+///
+/// Array attributes are joined with ", ". Attributes of unsupported types
+/// (compound, enum, references) are skipped.
+#[cfg(feature = "hdf5")]
+fn read_attributes(location: &Location) -> HashMap<String, String> {
+    location
+        .attr_names()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|name| {
+            let values = read_attribute_values(location, &name)?;
+            Some((name, values.join(", ")))
+        })
+        .collect()
+}
+
+/// Read a string or numeric attribute as one string per element
+#[cfg(feature = "hdf5")]
+fn read_attribute_values(location: &Location, name: &str) -> Option<Vec<String>> {
+    use hdf5::types::{FixedAscii, FixedUnicode, VarLenAscii, VarLenUnicode};
+
+    fn strings<T: ToString>(values: Vec<T>) -> Vec<String> {
+        values
+            .iter()
+            .map(|v| v.to_string().trim_end_matches('\0').trim().to_string())
+            .collect()
+    }
+
+    let attr = location.attr(name).ok()?;
+    let descriptor = attr.dtype().ok()?.to_descriptor().ok()?;
+    let values = match descriptor {
+        TypeDescriptor::VarLenUnicode => strings(attr.read_raw::<VarLenUnicode>().ok()?),
+        TypeDescriptor::VarLenAscii => strings(attr.read_raw::<VarLenAscii>().ok()?),
+        TypeDescriptor::FixedAscii(_) => strings(attr.read_raw::<FixedAscii<255>>().ok()?),
+        TypeDescriptor::FixedUnicode(_) => strings(attr.read_raw::<FixedUnicode<255>>().ok()?),
+        TypeDescriptor::Float(_) => strings(attr.read_raw::<f64>().ok()?),
+        TypeDescriptor::Integer(_) => strings(attr.read_raw::<i64>().ok()?),
+        TypeDescriptor::Unsigned(_) => strings(attr.read_raw::<u64>().ok()?),
+        TypeDescriptor::Boolean => strings(attr.read_raw::<bool>().ok()?),
+        _ => return None,
+    };
+    Some(values)
+}
+
 #[cfg(feature = "hdf5")]
 impl DataReader for Hdf5Reader {
     fn read_schema(&self) -> IoResult<DataSchema> {
@@ -384,8 +462,7 @@ impl Hdf5Reader {
 mod tests {
     use super::*;
 
-    // Tests would require actual HDF5 files
-    // These are placeholder tests that would run with test fixtures
+    use hdf5::types::VarLenUnicode;
 
     #[test]
     fn test_hdf5_type_conversion() {
@@ -395,4 +472,66 @@ mod tests {
             ColumnType::Float64
         );
     }
+
+    /// Write a small fixture: /simulation (redshift = 0.5, code = "gadget")
+    /// containing a 1D `temperature` dataset with `units` and `long_name`.
+    fn write_fixture(path: &std::path::Path) -> hdf5::Result<()> {
+        let text = |s: &str| s.parse::<VarLenUnicode>().unwrap();
+
+        let file = Hdf5File::create(path)?;
+        let group = file.create_group("simulation")?;
+        group
+            .new_attr::<f64>()
+            .shape(())
+            .create("redshift")?
+            .write_scalar(&0.5)?;
+        group
+            .new_attr::<VarLenUnicode>()
+            .shape(())
+            .create("code")?
+            .write_scalar(&text("gadget"))?;
+
+        let dataset = group
+            .new_dataset_builder()
+            .with_data(&[250.0f64, 260.0, 270.0])
+            .create("temperature")?;
+        dataset
+            .new_attr::<VarLenUnicode>()
+            .shape(())
+            .create("units")?
+            .write_scalar(&text("K"))?;
+        dataset
+            .new_attr::<VarLenUnicode>()
+            .shape(())
+            .create("long_name")?
+            .write_scalar(&text("Gas temperature"))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_units_attribute_in_schema() {
+        let path = std::env::temp_dir().join(format!("implore_units_{}.h5", std::process::id()));
+        write_fixture(&path).unwrap();
+
+        let reader = Hdf5Reader::open(path.to_str().unwrap(), "/simulation/temperature").unwrap();
+        let schema = reader.read_schema().unwrap();
+
+        let column = &schema.columns[0];
+        assert_eq!(column.name, "temperature");
+        assert_eq!(column.unit.as_deref(), Some("K"));
+        assert_eq!(column.description.as_deref(), Some("Gas temperature"));
+
+        // Group-level attributes, string and numeric, become dataset metadata
+        assert_eq!(
+            schema.metadata.get("code").map(String::as_str),
+            Some("gadget")
+        );
+        assert_eq!(
+            schema.metadata.get("redshift").map(String::as_str),
+            Some("0.5")
+        );
+        assert_eq!(schema.metadata.get("units").map(String::as_str), Some("K"));
+
+        std::fs::remove_file(path).ok();
+    }
 }
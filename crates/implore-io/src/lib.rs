@@ -4,20 +4,28 @@
 //!
 //! - **HDF5**: Hierarchical Data Format (primary scientific format)
 //! - **FITS**: Flexible Image Transport System (astronomy)
-//! - **CSV**: Comma-separated values with type inference
-//! - **Parquet**: Apache Parquet columnar format
+//! - **CSV**: Comma-separated values with type inference (read and write)
+//! - **Parquet**: Apache Parquet columnar format (read and write)
+//!
+//! The `wcs` module converts FITS image pixels to world coordinates.
 //!
 //! # Design
 //!
 //! All readers implement the `DataReader` trait for uniform access.
 //! Data is accessed lazily with memory-mapped I/O where possible.
+//! Writers implement the `DataWriter` trait.
 
 pub mod reader;
 pub mod schema;
+pub mod wcs;
+pub mod writer;
 
 #[cfg(feature = "csv")]
 pub mod csv_reader;
 
+#[cfg(feature = "csv")]
+pub mod csv_writer;
+
 #[cfg(feature = "hdf5")]
 pub mod hdf5_reader;
 
@@ -27,11 +35,16 @@ pub mod fits_reader;
 #[cfg(feature = "parquet")]
 pub mod parquet_reader;
 
+#[cfg(feature = "parquet")]
+pub mod parquet_writer;
+
 #[cfg(feature = "npz")]
 pub mod npz_reader;
 
 pub use reader::*;
 pub use schema::*;
+pub use wcs::*;
+pub use writer::*;
 
 // Setup UniFFI when the feature is enabled
 #[cfg(feature = "uniffi")]
@@ -1,35 +1,241 @@
 //! Apache Parquet file reader
 //!
-//! This module provides support for reading Parquet columnar format files.
-//! Currently a placeholder - Parquet support is planned for a future release.
+//! Flat files of boolean, 32/64-bit integer, float, double, and UTF-8 string
+//! columns are supported. Units and descriptions written by
+//! [`ParquetWriter`](crate::parquet_writer::ParquetWriter) are read back
+//! from the file's key-value metadata.
 
-use crate::reader::{DataReader, ReaderError};
-use crate::schema::DataSchema;
+use crate::parquet_writer::{DESCRIPTION_KEY_PREFIX, UNIT_KEY_PREFIX};
+use crate::reader::{DataReader, IoError, IoResult};
+use crate::schema::{ColumnDescriptor, ColumnType, DataColumn, DataSchema, DataSlice};
+use parquet::basic::{ConvertedType, LogicalType, Type as PhysicalType};
+use parquet::errors::ParquetError;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::record::Field;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
 
-/// Parquet file reader (placeholder implementation)
+/// Parquet file reader
 pub struct ParquetReader {
-    _path: String,
+    path: String,
+    schema: DataSchema,
+    metadata: HashMap<String, String>,
 }
 
 impl ParquetReader {
-    /// Create a new Parquet reader for the given file path
-    pub fn new(path: impl Into<String>) -> Result<Self, ReaderError> {
-        Err(ReaderError::UnsupportedFormat(
-            "Parquet support is not yet implemented".to_string(),
-        ))
+    /// Open a Parquet file
+    pub fn open(path: &str) -> IoResult<Self> {
+        if !Path::new(path).exists() {
+            return Err(IoError::FileNotFound(path.to_string()));
+        }
+
+        let reader = Self::file_reader(path)?;
+        let file_metadata = reader.metadata().file_metadata();
+
+        let mut metadata = HashMap::new();
+        let mut units = HashMap::new();
+        let mut descriptions = HashMap::new();
+        for entry in file_metadata.key_value_metadata().into_iter().flatten() {
+            let Some(value) = entry.value.clone() else {
+                continue;
+            };
+            if let Some(column) = entry.key.strip_prefix(UNIT_KEY_PREFIX) {
+                units.insert(column.to_string(), value);
+            } else if let Some(column) = entry.key.strip_prefix(DESCRIPTION_KEY_PREFIX) {
+                descriptions.insert(column.to_string(), value);
+            } else {
+                metadata.insert(entry.key.clone(), value);
+            }
+        }
+
+        let columns = file_metadata
+            .schema_descr()
+            .columns()
+            .iter()
+            .map(|column| {
+                let name = column.name().to_string();
+                let dtype = column_type(
+                    column.physical_type(),
+                    column.logical_type(),
+                    column.converted_type(),
+                );
+                let mut descriptor = ColumnDescriptor::new(name.clone(), dtype)
+                    .with_nullable(column.max_def_level() > 0);
+                if let Some(unit) = units.remove(&name) {
+                    descriptor = descriptor.with_unit(unit);
+                }
+                if let Some(description) = descriptions.remove(&name) {
+                    descriptor = descriptor.with_description(description);
+                }
+                descriptor
+            })
+            .collect();
+
+        let mut schema = DataSchema::new(columns, file_metadata.num_rows().max(0) as usize);
+        schema.metadata = metadata.clone();
+
+        metadata.insert("format".to_string(), "Parquet".to_string());
+        metadata.insert(
+            "row_groups".to_string(),
+            reader.metadata().num_row_groups().to_string(),
+        );
+
+        Ok(Self {
+            path: path.to_string(),
+            schema,
+            metadata,
+        })
+    }
+
+    fn file_reader(path: &str) -> IoResult<SerializedFileReader<File>> {
+        let file = File::open(path).map_err(|e| IoError::OpenFailed(e.to_string()))?;
+        SerializedFileReader::new(file).map_err(|e| IoError::InvalidFormat(e.to_string()))
+    }
+
+    fn empty_column(dtype: ColumnType, capacity: usize) -> IoResult<DataColumn> {
+        Ok(match dtype {
+            ColumnType::Float32 => DataColumn::Float32(Vec::with_capacity(capacity)),
+            ColumnType::Float64 => DataColumn::Float64(Vec::with_capacity(capacity)),
+            ColumnType::Int32 => DataColumn::Int32(Vec::with_capacity(capacity)),
+            ColumnType::Int64 => DataColumn::Int64(Vec::with_capacity(capacity)),
+            ColumnType::Bool => DataColumn::Bool(Vec::with_capacity(capacity)),
+            ColumnType::String => DataColumn::String(Vec::with_capacity(capacity)),
+            ColumnType::Unknown => {
+                return Err(IoError::UnsupportedFormat(
+                    "Parquet column type not supported".to_string(),
+                ))
+            }
+        })
     }
 }
 
 impl DataReader for ParquetReader {
-    fn schema(&self) -> Result<DataSchema, ReaderError> {
-        Err(ReaderError::UnsupportedFormat(
-            "Parquet support is not yet implemented".to_string(),
-        ))
+    fn read_schema(&self) -> IoResult<DataSchema> {
+        Ok(self.schema.clone())
+    }
+
+    fn read_column(&self, name: &str) -> IoResult<DataColumn> {
+        let col_index = self
+            .schema
+            .column_index(name)
+            .ok_or_else(|| IoError::ColumnNotFound(name.to_string()))?;
+        let mut column = Self::empty_column(
+            self.schema.columns[col_index].dtype,
+            self.schema.num_records,
+        )?;
+
+        let reader = Self::file_reader(&self.path)?;
+        for row in reader.get_row_iter(None).map_err(read_error)? {
+            let row = row.map_err(read_error)?;
+            let (_, field) = row
+                .get_column_iter()
+                .nth(col_index)
+                .ok_or_else(|| IoError::ColumnNotFound(name.to_string()))?;
+            push_field(&mut column, field)?;
+        }
+
+        Ok(column)
+    }
+
+    fn read_range(&self, start: usize, end: usize) -> IoResult<DataSlice> {
+        let end = end.min(self.schema.num_records);
+        let count = end.saturating_sub(start);
+
+        let mut columns = self
+            .schema
+            .columns
+            .iter()
+            .map(|c| Self::empty_column(c.dtype, count))
+            .collect::<IoResult<Vec<_>>>()?;
+
+        let reader = Self::file_reader(&self.path)?;
+        let rows = reader.get_row_iter(None).map_err(read_error)?;
+        for row in rows.skip(start).take(count) {
+            let row = row.map_err(read_error)?;
+            for (column, (_, field)) in columns.iter_mut().zip(row.get_column_iter()) {
+                push_field(column, field)?;
+            }
+        }
+
+        let mut slice = DataSlice::new(start);
+        for (col_desc, data) in self.schema.columns.iter().zip(columns) {
+            slice.add_column(&col_desc.name, data);
+        }
+
+        Ok(slice)
+    }
+
+    fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    fn path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+
+    fn format_name(&self) -> &'static str {
+        "Parquet"
     }
 
-    fn read_column(&self, _name: &str) -> Result<Vec<f64>, ReaderError> {
-        Err(ReaderError::UnsupportedFormat(
-            "Parquet support is not yet implemented".to_string(),
-        ))
+    fn estimated_memory_bytes(&self) -> Option<usize> {
+        self.schema
+            .columns
+            .iter()
+            .map(|c| c.dtype.byte_size())
+            .sum::<Option<usize>>()
+            .map(|row_bytes| row_bytes * self.schema.num_records)
     }
 }
+
+/// Map a Parquet leaf column to a column type
+fn column_type(
+    physical: PhysicalType,
+    logical: Option<LogicalType>,
+    converted: ConvertedType,
+) -> ColumnType {
+    match physical {
+        PhysicalType::BOOLEAN => ColumnType::Bool,
+        PhysicalType::INT32 => ColumnType::Int32,
+        PhysicalType::INT64 => ColumnType::Int64,
+        PhysicalType::FLOAT => ColumnType::Float32,
+        PhysicalType::DOUBLE => ColumnType::Float64,
+        PhysicalType::BYTE_ARRAY
+            if matches!(logical, Some(LogicalType::String)) || converted == ConvertedType::UTF8 =>
+        {
+            ColumnType::String
+        }
+        _ => ColumnType::Unknown,
+    }
+}
+
+/// Append a record field to a column
+///
+/// Nulls become NaN, zero, false, or an empty string, as for empty CSV cells.
+fn push_field(column: &mut DataColumn, field: &Field) -> IoResult<()> {
+    match (column, field) {
+        (DataColumn::Float32(v), Field::Float(x)) => v.push(*x),
+        (DataColumn::Float32(v), Field::Null) => v.push(f32::NAN),
+        (DataColumn::Float64(v), Field::Double(x)) => v.push(*x),
+        (DataColumn::Float64(v), Field::Null) => v.push(f64::NAN),
+        (DataColumn::Int32(v), Field::Int(x)) => v.push(*x),
+        (DataColumn::Int32(v), Field::Null) => v.push(0),
+        (DataColumn::Int64(v), Field::Long(x)) => v.push(*x),
+        (DataColumn::Int64(v), Field::Null) => v.push(0),
+        (DataColumn::Bool(v), Field::Bool(x)) => v.push(*x),
+        (DataColumn::Bool(v), Field::Null) => v.push(false),
+        (DataColumn::String(v), Field::Str(x)) => v.push(x.clone()),
+        (DataColumn::String(v), Field::Null) => v.push(String::new()),
+        (column, field) => {
+            return Err(IoError::TypeMismatch {
+                expected: format!("{:?}", column.dtype()),
+                actual: field.to_string(),
+            })
+        }
+    }
+    Ok(())
+}
+
+fn read_error(e: ParquetError) -> IoError {
+    IoError::ReadFailed(e.to_string())
+}
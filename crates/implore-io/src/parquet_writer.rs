@@ -0,0 +1,344 @@
+//! Apache Parquet file writer
+//!
+//! Each schema column becomes a required Parquet column of the matching
+//! physical type (strings as UTF-8 byte arrays). Column units and
+//! descriptions, which Parquet has no place for, are stored in the file's
+//! key-value metadata under `implore.unit.<column>` and
+//! `implore.description.<column>`, alongside the schema's own metadata.
+//!
+//! Rows are written in row groups of at most
+//! [`DEFAULT_ROW_GROUP_SIZE`] rows, so large datasets are compressed and
+//! flushed a chunk at a time.
+
+use crate::reader::{IoError, IoResult};
+use crate::schema::{ColumnType, DataColumn, DataSchema, DataSlice};
+use crate::writer::DataWriter;
+use parquet::basic::{LogicalType, Repetition, Type as PhysicalType};
+use parquet::data_type::{
+    BoolType, ByteArray, ByteArrayType, DoubleType, FloatType, Int32Type, Int64Type,
+};
+use parquet::errors::ParquetError;
+use parquet::file::metadata::KeyValue;
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::{SerializedColumnWriter, SerializedFileWriter};
+use parquet::schema::types::Type;
+use std::fs::File;
+use std::sync::Arc;
+
+/// Key-value metadata prefix for column units
+pub(crate) const UNIT_KEY_PREFIX: &str = "implore.unit.";
+
+/// Key-value metadata prefix for column descriptions
+pub(crate) const DESCRIPTION_KEY_PREFIX: &str = "implore.description.";
+
+/// Rows per row group unless set with [`ParquetWriter::with_row_group_size`]
+pub const DEFAULT_ROW_GROUP_SIZE: usize = 64 * 1024;
+
+/// Parquet file writer
+pub struct ParquetWriter {
+    path: String,
+    row_group_size: usize,
+}
+
+impl ParquetWriter {
+    /// Create a writer for a new Parquet file
+    ///
+    /// The file is created (or truncated) when the dataset is written.
+    pub fn create(path: &str) -> IoResult<Self> {
+        Ok(Self {
+            path: path.to_string(),
+            row_group_size: DEFAULT_ROW_GROUP_SIZE,
+        })
+    }
+
+    /// Set the maximum number of rows per row group
+    pub fn with_row_group_size(mut self, rows: usize) -> Self {
+        self.row_group_size = rows.max(1);
+        self
+    }
+
+    /// Columns of `data` in schema order, checked against the schema
+    fn ordered_columns<'a>(
+        schema: &DataSchema,
+        data: &'a DataSlice,
+    ) -> IoResult<Vec<&'a DataColumn>> {
+        schema
+            .columns
+            .iter()
+            .map(|desc| {
+                let column = data
+                    .column(&desc.name)
+                    .ok_or_else(|| IoError::ColumnNotFound(desc.name.clone()))?;
+                if column.dtype() != desc.dtype {
+                    return Err(IoError::TypeMismatch {
+                        expected: format!("{:?}", desc.dtype),
+                        actual: format!("{:?}", column.dtype()),
+                    });
+                }
+                if column.len() != data.num_rows {
+                    return Err(IoError::OutOfBounds {
+                        index: column.len(),
+                        size: data.num_rows,
+                    });
+                }
+                Ok(column)
+            })
+            .collect()
+    }
+
+    fn parquet_schema(schema: &DataSchema) -> IoResult<Type> {
+        let fields = schema
+            .columns
+            .iter()
+            .map(|desc| {
+                let (physical, logical) = match desc.dtype {
+                    ColumnType::Float32 => (PhysicalType::FLOAT, None),
+                    ColumnType::Float64 => (PhysicalType::DOUBLE, None),
+                    ColumnType::Int32 => (PhysicalType::INT32, None),
+                    ColumnType::Int64 => (PhysicalType::INT64, None),
+                    ColumnType::Bool => (PhysicalType::BOOLEAN, None),
+                    ColumnType::String => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+                    ColumnType::Unknown => {
+                        return Err(IoError::UnsupportedFormat(format!(
+                            "Column '{}' has no Parquet type",
+                            desc.name
+                        )))
+                    }
+                };
+                Type::primitive_type_builder(&desc.name, physical)
+                    .with_repetition(Repetition::REQUIRED)
+                    .with_logical_type(logical)
+                    .build()
+                    .map(Arc::new)
+                    .map_err(write_error)
+            })
+            .collect::<IoResult<Vec<_>>>()?;
+
+        Type::group_type_builder("schema")
+            .with_fields(fields)
+            .build()
+            .map_err(write_error)
+    }
+
+    fn key_value_metadata(schema: &DataSchema) -> Vec<KeyValue> {
+        let mut entries: Vec<KeyValue> = schema
+            .metadata
+            .iter()
+            .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+            .collect();
+        for desc in &schema.columns {
+            if let Some(unit) = &desc.unit {
+                entries.push(KeyValue::new(
+                    format!("{}{}", UNIT_KEY_PREFIX, desc.name),
+                    unit.clone(),
+                ));
+            }
+            if let Some(description) = &desc.description {
+                entries.push(KeyValue::new(
+                    format!("{}{}", DESCRIPTION_KEY_PREFIX, desc.name),
+                    description.clone(),
+                ));
+            }
+        }
+        entries
+    }
+}
+
+impl DataWriter for ParquetWriter {
+    fn write(&mut self, schema: &DataSchema, data: &DataSlice) -> IoResult<()> {
+        let columns = Self::ordered_columns(schema, data)?;
+        let parquet_schema = Self::parquet_schema(schema)?;
+        let properties = WriterProperties::builder()
+            .set_key_value_metadata(Some(Self::key_value_metadata(schema)))
+            .build();
+
+        let file = File::create(&self.path).map_err(|e| IoError::Io(e.to_string()))?;
+        let mut writer =
+            SerializedFileWriter::new(file, Arc::new(parquet_schema), Arc::new(properties))
+                .map_err(write_error)?;
+
+        let mut start = 0;
+        while start < data.num_rows {
+            let end = (start + self.row_group_size).min(data.num_rows);
+            let mut row_group = writer.next_row_group().map_err(write_error)?;
+            for column in &columns {
+                let mut column_writer = row_group
+                    .next_column()
+                    .map_err(write_error)?
+                    .ok_or_else(|| IoError::WriteFailed("Parquet schema ended early".into()))?;
+                write_chunk(&mut column_writer, column, start, end).map_err(write_error)?;
+                column_writer.close().map_err(write_error)?;
+            }
+            row_group.close().map_err(write_error)?;
+            start = end;
+        }
+
+        writer.close().map_err(write_error)?;
+        Ok(())
+    }
+
+    fn path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+
+    fn format_name(&self) -> &'static str {
+        "Parquet"
+    }
+}
+
+/// Write rows `start..end` of a column into the current row group
+fn write_chunk(
+    writer: &mut SerializedColumnWriter<'_>,
+    column: &DataColumn,
+    start: usize,
+    end: usize,
+) -> Result<(), ParquetError> {
+    let written = match column {
+        DataColumn::Float32(v) => {
+            writer
+                .typed::<FloatType>()
+                .write_batch(&v[start..end], None, None)
+        }
+        DataColumn::Float64(v) => {
+            writer
+                .typed::<DoubleType>()
+                .write_batch(&v[start..end], None, None)
+        }
+        DataColumn::Int32(v) => writer
+            .typed::<Int32Type>()
+            .write_batch(&v[start..end], None, None),
+        DataColumn::Int64(v) => writer
+            .typed::<Int64Type>()
+            .write_batch(&v[start..end], None, None),
+        DataColumn::Bool(v) => writer
+            .typed::<BoolType>()
+            .write_batch(&v[start..end], None, None),
+        DataColumn::String(v) => {
+            let values: Vec<ByteArray> = v[start..end]
+                .iter()
+                .map(|s| ByteArray::from(s.as_str()))
+                .collect();
+            writer
+                .typed::<ByteArrayType>()
+                .write_batch(&values, None, None)
+        }
+    };
+    written.map(|_| ())
+}
+
+fn write_error(e: ParquetError) -> IoError {
+    IoError::WriteFailed(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parquet_reader::ParquetReader;
+    use crate::reader::DataReader;
+    use crate::schema::ColumnDescriptor;
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!(
+                "implore_parquet_{}_{}.parquet",
+                name,
+                std::process::id()
+            ))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let n = 10;
+        let mut schema = DataSchema::new(
+            vec![
+                ColumnDescriptor::new("mass", ColumnType::Float64)
+                    .with_unit("Msun/h")
+                    .with_description("Virial mass"),
+                ColumnDescriptor::new("radius", ColumnType::Float32).with_unit("kpc/h"),
+                ColumnDescriptor::new("id", ColumnType::Int64),
+                ColumnDescriptor::new("level", ColumnType::Int32),
+                ColumnDescriptor::new("is_central", ColumnType::Bool),
+                ColumnDescriptor::new("label", ColumnType::String),
+            ],
+            n,
+        );
+        schema
+            .metadata
+            .insert("simulation".to_string(), "L500".to_string());
+
+        let mut data = DataSlice::new(0);
+        data.add_column(
+            "mass",
+            DataColumn::Float64((0..n).map(|i| 1e12 * (i + 1) as f64).collect()),
+        );
+        data.add_column(
+            "radius",
+            DataColumn::Float32((0..n).map(|i| 0.5 * i as f32).collect()),
+        );
+        data.add_column(
+            "id",
+            DataColumn::Int64((0..n as i64).map(|i| i << 40).collect()),
+        );
+        data.add_column(
+            "level",
+            DataColumn::Int32((0..n as i32).map(|i| i % 3).collect()),
+        );
+        data.add_column(
+            "is_central",
+            DataColumn::Bool((0..n).map(|i| i % 2 == 0).collect()),
+        );
+        data.add_column(
+            "label",
+            DataColumn::String((0..n).map(|i| format!("halo-{}", i)).collect()),
+        );
+
+        // Small row groups so the data is written in several chunks
+        let path = temp_path("round_trip");
+        let mut writer = ParquetWriter::create(&path).unwrap().with_row_group_size(3);
+        writer.write(&schema, &data).unwrap();
+
+        let reader = ParquetReader::open(&path).unwrap();
+        assert_eq!(reader.metadata().get("row_groups").unwrap(), "4");
+
+        let read_schema = reader.read_schema().unwrap();
+        assert_eq!(read_schema.num_records, n);
+        assert_eq!(read_schema.column_names(), schema.column_names());
+        assert_eq!(read_schema.metadata.get("simulation").unwrap(), "L500");
+        for (read, written) in read_schema.columns.iter().zip(&schema.columns) {
+            assert_eq!(read.dtype, written.dtype);
+            assert_eq!(read.unit, written.unit);
+            assert_eq!(read.description, written.description);
+        }
+
+        for name in schema.column_names() {
+            assert_eq!(
+                reader.read_column(name).unwrap(),
+                *data.column(name).unwrap(),
+                "column {}",
+                name
+            );
+        }
+
+        let slice = reader.read_range(2, 7).unwrap();
+        assert_eq!(slice.num_rows, 5);
+        assert_eq!(
+            slice.column("label").unwrap(),
+            &DataColumn::String((2..7).map(|i| format!("halo-{}", i)).collect())
+        );
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_missing_column_rejected() {
+        let schema = DataSchema::new(vec![ColumnDescriptor::new("x", ColumnType::Float64)], 0);
+        let path = temp_path("missing");
+        let mut writer = ParquetWriter::create(&path).unwrap();
+        assert!(matches!(
+            writer.write(&schema, &DataSlice::new(0)),
+            Err(IoError::ColumnNotFound(_))
+        ));
+    }
+}
@@ -37,6 +37,9 @@ pub enum IoError {
     #[error("Read failed: {0}")]
     ReadFailed(String),
 
+    #[error("Write failed: {0}")]
+    WriteFailed(String),
+
     #[error("I/O error: {0}")]
     Io(String),
 }
@@ -64,6 +64,11 @@ pub struct ColumnDescriptor {
 
     /// Whether the column can contain nulls
     pub nullable: bool,
+
+    /// Name in the source file, when the column had to be renamed
+    /// (e.g. a duplicate or empty CSV header)
+    #[serde(default)]
+    pub original_name: Option<String>,
 }
 
 impl ColumnDescriptor {
@@ -75,6 +80,7 @@ impl ColumnDescriptor {
             unit: None,
             description: None,
             nullable: true,
+            original_name: None,
         }
     }
 
@@ -95,6 +101,12 @@ impl ColumnDescriptor {
         self.nullable = nullable;
         self
     }
+
+    /// Set the name the column had in the source file
+    pub fn with_original_name(mut self, name: impl Into<String>) -> Self {
+        self.original_name = Some(name.into());
+        self
+    }
 }
 
 /// Column data type
@@ -132,7 +144,7 @@ impl ColumnType {
 }
 
 /// A column of data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum DataColumn {
     Float32(Vec<f32>),
     Float64(Vec<f64>),
@@ -0,0 +1,468 @@
+//! FITS world coordinate system (WCS) transforms
+//!
+//! Converts between pixel and world coordinates using the standard WCS
+//! header keywords of a 2D FITS image:
+//!
+//! - `CTYPEi`: axis type and projection, e.g. `RA---TAN` / `DEC--TAN`
+//! - `CRPIXi`: reference pixel (1-based, FITS convention)
+//! - `CRVALi`: world coordinates of the reference pixel (degrees)
+//! - `CDi_j`: linear transform from pixel offsets to intermediate world
+//!   coordinates, or alternatively `CDELTi` with `PCi_j` or `CROTA2`
+//!
+//! The gnomonic (`TAN`) projection is supported for celestial axes, and
+//! axes without a projection code (e.g. `LINEAR`, `WAVE`) map linearly.
+//! Other projections are rejected with [`WcsError::UnsupportedProjection`].
+//!
+//! Pixel coordinates follow the FITS convention: the center of the first
+//! pixel is (1, 1).
+
+use crate::reader::{IoError, IoResult};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use thiserror::Error;
+
+/// FITS header block size in bytes
+const BLOCK_SIZE: usize = 2880;
+
+/// FITS header card size in bytes
+const CARD_SIZE: usize = 80;
+
+/// Errors building or applying a WCS
+#[derive(Debug, Error, PartialEq)]
+pub enum WcsError {
+    #[error("Missing WCS keyword: {0}")]
+    MissingKeyword(String),
+
+    #[error("Invalid value for {keyword}: {value}")]
+    InvalidValue { keyword: String, value: String },
+
+    #[error("Unsupported projection: {0} (only TAN and linear axes are supported)")]
+    UnsupportedProjection(String),
+
+    #[error("WCS transform matrix is singular")]
+    SingularMatrix,
+
+    #[error("World coordinate ({0}, {1}) is not visible in the projection")]
+    OutOfProjection(f64, f64),
+}
+
+/// Result type for WCS operations
+pub type WcsResult<T> = Result<T, WcsError>;
+
+/// Projection between the sky and the image plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Projection {
+    /// Gnomonic (tangent plane) projection of celestial axes
+    Tan,
+    /// Linear axes with no projection
+    Linear,
+}
+
+/// A 2D world coordinate system
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wcs {
+    /// Axis types (`CTYPE1`, `CTYPE2`)
+    pub ctype: [String; 2],
+    /// Reference pixel (`CRPIX1`, `CRPIX2`)
+    pub crpix: [f64; 2],
+    /// World coordinates at the reference pixel (`CRVAL1`, `CRVAL2`)
+    pub crval: [f64; 2],
+    /// Pixel offset to intermediate world coordinate matrix
+    pub cd: [[f64; 2]; 2],
+    /// Projection of the axes
+    pub projection: Projection,
+}
+
+impl Wcs {
+    /// Build a WCS from header keywords
+    ///
+    /// `header` maps keyword names to their values as written in the
+    /// header, as returned by [`parse_fits_header`].
+    pub fn from_header(header: &HashMap<String, String>) -> WcsResult<Self> {
+        let ctype = [
+            string_keyword(header, "CTYPE1").unwrap_or_default(),
+            string_keyword(header, "CTYPE2").unwrap_or_default(),
+        ];
+        let projection = projection(&ctype)?;
+
+        let crpix = [
+            required_number(header, "CRPIX1")?,
+            required_number(header, "CRPIX2")?,
+        ];
+        let crval = [
+            required_number(header, "CRVAL1")?,
+            required_number(header, "CRVAL2")?,
+        ];
+        let cd = transform_matrix(header)?;
+
+        let det = cd[0][0] * cd[1][1] - cd[0][1] * cd[1][0];
+        if det == 0.0 || !det.is_finite() {
+            return Err(WcsError::SingularMatrix);
+        }
+
+        Ok(Self {
+            ctype,
+            crpix,
+            crval,
+            cd,
+            projection,
+        })
+    }
+
+    /// World coordinates (degrees for celestial axes) of a pixel
+    pub fn pixel_to_world(&self, x: f64, y: f64) -> (f64, f64) {
+        let u = x - self.crpix[0];
+        let v = y - self.crpix[1];
+        let xi = self.cd[0][0] * u + self.cd[0][1] * v;
+        let eta = self.cd[1][0] * u + self.cd[1][1] * v;
+
+        match self.projection {
+            Projection::Linear => (self.crval[0] + xi, self.crval[1] + eta),
+            Projection::Tan => {
+                let (xi, eta) = (xi.to_radians(), eta.to_radians());
+                let ra0 = self.crval[0].to_radians();
+                let dec0 = self.crval[1].to_radians();
+
+                let denom = dec0.cos() - eta * dec0.sin();
+                let ra = ra0 + xi.atan2(denom);
+                let dec = (eta * dec0.cos() + dec0.sin()).atan2(xi.hypot(denom));
+                (ra.to_degrees().rem_euclid(360.0), dec.to_degrees())
+            }
+        }
+    }
+
+    /// Pixel coordinates of a world position
+    ///
+    /// Fails for TAN positions 90° or more from the reference point, which
+    /// the tangent plane can't represent.
+    pub fn world_to_pixel(&self, lon: f64, lat: f64) -> WcsResult<(f64, f64)> {
+        let (xi, eta) = match self.projection {
+            Projection::Linear => (lon - self.crval[0], lat - self.crval[1]),
+            Projection::Tan => {
+                let ra0 = self.crval[0].to_radians();
+                let dec0 = self.crval[1].to_radians();
+                let (ra, dec) = (lon.to_radians(), lat.to_radians());
+                let dra = ra - ra0;
+
+                let cos_c = dec.sin() * dec0.sin() + dec.cos() * dec0.cos() * dra.cos();
+                if cos_c <= 0.0 {
+                    return Err(WcsError::OutOfProjection(lon, lat));
+                }
+                let xi = dec.cos() * dra.sin() / cos_c;
+                let eta = (dec.sin() * dec0.cos() - dec.cos() * dec0.sin() * dra.cos()) / cos_c;
+                (xi.to_degrees(), eta.to_degrees())
+            }
+        };
+
+        let [[a, b], [c, d]] = self.cd;
+        let det = a * d - b * c;
+        let u = (d * xi - b * eta) / det;
+        let v = (a * eta - c * xi) / det;
+        Ok((u + self.crpix[0], v + self.crpix[1]))
+    }
+}
+
+/// Projection shared by both axes, from the code after the axis name
+fn projection(ctype: &[String; 2]) -> WcsResult<Projection> {
+    let code = |ctype: &str| -> Option<String> {
+        // "RA---TAN": axis name padded with '-' to 4 characters, '-', code
+        if ctype.len() < 8 || ctype.as_bytes()[4] != b'-' {
+            return None;
+        }
+        let code = ctype.get(5..)?.trim_matches('-').trim();
+        if code.is_empty() {
+            None
+        } else {
+            Some(code.to_uppercase())
+        }
+    };
+
+    match (code(&ctype[0]), code(&ctype[1])) {
+        (None, None) => Ok(Projection::Linear),
+        (Some(a), Some(b)) if a == "TAN" && b == "TAN" => {
+            let lon = ctype[0].to_uppercase();
+            if lon.starts_with("DEC") || lon.ends_with("LAT-TAN") {
+                // Latitude on the first axis would need the axes swapped
+                return Err(WcsError::UnsupportedProjection(format!(
+                    "{} / {} (latitude on axis 1)",
+                    ctype[0], ctype[1]
+                )));
+            }
+            Ok(Projection::Tan)
+        }
+        (Some(a), Some(b)) if a != b => Err(WcsError::UnsupportedProjection(format!(
+            "{} / {} (mixed projections)",
+            ctype[0], ctype[1]
+        ))),
+        (Some(code), _) | (_, Some(code)) => Err(WcsError::UnsupportedProjection(code)),
+    }
+}
+
+/// The CD matrix, or one built from CDELT with PC or CROTA2
+fn transform_matrix(header: &HashMap<String, String>) -> WcsResult<[[f64; 2]; 2]> {
+    let cd_keys = [["CD1_1", "CD1_2"], ["CD2_1", "CD2_2"]];
+    if cd_keys.iter().flatten().any(|k| header.contains_key(*k)) {
+        let mut cd = [[0.0; 2]; 2];
+        for (i, row) in cd_keys.iter().enumerate() {
+            for (j, key) in row.iter().enumerate() {
+                cd[i][j] = number(header, key)?.unwrap_or(0.0);
+            }
+        }
+        return Ok(cd);
+    }
+
+    let cdelt = match (number(header, "CDELT1")?, number(header, "CDELT2")?) {
+        (Some(c1), Some(c2)) => [c1, c2],
+        (None, _) => return Err(WcsError::MissingKeyword("CD1_1 or CDELT1".to_string())),
+        (_, None) => return Err(WcsError::MissingKeyword("CD2_2 or CDELT2".to_string())),
+    };
+
+    let pc_keys = [["PC1_1", "PC1_2"], ["PC2_1", "PC2_2"]];
+    let pc = if pc_keys.iter().flatten().any(|k| header.contains_key(*k)) {
+        let mut pc = [[0.0; 2]; 2];
+        for (i, row) in pc_keys.iter().enumerate() {
+            for (j, key) in row.iter().enumerate() {
+                pc[i][j] = number(header, key)?.unwrap_or(if i == j { 1.0 } else { 0.0 });
+            }
+        }
+        pc
+    } else {
+        let rho = number(header, "CROTA2")?.unwrap_or(0.0).to_radians();
+        let ratio = cdelt[1] / cdelt[0];
+        [
+            [rho.cos(), -rho.sin() * ratio],
+            [rho.sin() / ratio, rho.cos()],
+        ]
+    };
+
+    Ok([
+        [cdelt[0] * pc[0][0], cdelt[0] * pc[0][1]],
+        [cdelt[1] * pc[1][0], cdelt[1] * pc[1][1]],
+    ])
+}
+
+fn string_keyword(header: &HashMap<String, String>, key: &str) -> Option<String> {
+    header.get(key).map(|v| v.trim().to_string())
+}
+
+/// A numeric keyword, if present (FITS allows `D` exponents)
+fn number(header: &HashMap<String, String>, key: &str) -> WcsResult<Option<f64>> {
+    let Some(value) = header.get(key) else {
+        return Ok(None);
+    };
+    value
+        .trim()
+        .replace(['D', 'd'], "E")
+        .parse()
+        .map(Some)
+        .map_err(|_| WcsError::InvalidValue {
+            keyword: key.to_string(),
+            value: value.clone(),
+        })
+}
+
+fn required_number(header: &HashMap<String, String>, key: &str) -> WcsResult<f64> {
+    number(header, key)?.ok_or_else(|| WcsError::MissingKeyword(key.to_string()))
+}
+
+/// Parse FITS header cards into keyword → value
+///
+/// Reads 80-character cards up to `END`. String values are unquoted and
+/// comments after `/` are dropped; cards without a value (COMMENT,
+/// HISTORY, blank) are skipped.
+pub fn parse_fits_header(header: &[u8]) -> HashMap<String, String> {
+    let mut keywords = HashMap::new();
+    for card in header.chunks(CARD_SIZE) {
+        let card = String::from_utf8_lossy(card).into_owned();
+        let key = card.get(..8).unwrap_or(&card).trim();
+        if key == "END" {
+            break;
+        }
+        let Some(value) = card.get(8..).and_then(|rest| rest.strip_prefix("= ")) else {
+            continue;
+        };
+        keywords.insert(key.to_string(), card_value(value));
+    }
+    keywords
+}
+
+/// Value of a card without its comment, unquoted if it's a string
+fn card_value(field: &str) -> String {
+    let field = field.trim_start();
+    if let Some(quoted) = field.strip_prefix('\'') {
+        // '' is an escaped quote inside a string
+        let mut value = String::new();
+        let mut chars = quoted.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                if chars.peek() != Some(&'\'') {
+                    break;
+                }
+                chars.next();
+            }
+            value.push(c);
+        }
+        return value.trim_end().to_string();
+    }
+    field.split('/').next().unwrap_or("").trim().to_string()
+}
+
+/// Read the primary header of a FITS file
+pub fn read_fits_header(path: &str) -> IoResult<HashMap<String, String>> {
+    let mut file = File::open(path).map_err(|e| IoError::OpenFailed(e.to_string()))?;
+    let mut header = Vec::new();
+    let mut block = vec![0u8; BLOCK_SIZE];
+    loop {
+        file.read_exact(&mut block)
+            .map_err(|e| IoError::InvalidFormat(format!("Truncated FITS header: {}", e)))?;
+        if header.is_empty() && !block.starts_with(b"SIMPLE  =") {
+            return Err(IoError::InvalidFormat("Not a FITS file".to_string()));
+        }
+        header.extend_from_slice(&block);
+        let has_end = block
+            .chunks(CARD_SIZE)
+            .any(|card| card.starts_with(b"END") && card[3..].iter().all(|&b| b == b' '));
+        if has_end {
+            return Ok(parse_fits_header(&header));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Header of a 1024×1024 image, 10″ pixels, centered on RA 150°, Dec 2°
+    fn tan_fixture() -> Vec<u8> {
+        let cards = [
+            "SIMPLE  =                    T / conforms to FITS standard",
+            "BITPIX  =                  -32",
+            "NAXIS   =                    2",
+            "NAXIS1  =                 1024",
+            "NAXIS2  =                 1024",
+            "CTYPE1  = 'RA---TAN'           / gnomonic projection",
+            "CTYPE2  = 'DEC--TAN'",
+            "CRPIX1  =                512.5",
+            "CRPIX2  =                512.5",
+            "CRVAL1  =                150.0 / [deg]",
+            "CRVAL2  =                  2.0 / [deg]",
+            "CD1_1   = -2.7777777777778D-03",
+            "CD1_2   =                  0.0",
+            "CD2_1   =                  0.0",
+            "CD2_2   =  2.7777777777778E-03",
+            "COMMENT test fixture",
+            "END",
+        ];
+        let mut header: Vec<u8> = cards
+            .iter()
+            .flat_map(|card| format!("{:<80}", card).into_bytes())
+            .collect();
+        header.resize(BLOCK_SIZE, b' ');
+        header
+    }
+
+    fn assert_close(actual: (f64, f64), expected: (f64, f64)) {
+        assert!(
+            (actual.0 - expected.0).abs() < 1e-9 && (actual.1 - expected.1).abs() < 1e-9,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_tan_reference_pixel() {
+        let header = parse_fits_header(&tan_fixture());
+        assert_eq!(header["CTYPE1"], "RA---TAN");
+        assert_eq!(header["CRVAL1"], "150.0");
+
+        let wcs = Wcs::from_header(&header).unwrap();
+        assert_eq!(wcs.projection, Projection::Tan);
+        assert_close(wcs.pixel_to_world(512.5, 512.5), (150.0, 2.0));
+
+        // Corner pixel, computed independently via native spherical coordinates
+        let corner = wcs.pixel_to_world(1.0, 1.0);
+        assert_close(corner, (151.42017838936152, 0.5792798233926395));
+        assert_close(wcs.world_to_pixel(corner.0, corner.1).unwrap(), (1.0, 1.0));
+
+        assert_eq!(
+            wcs.world_to_pixel(330.0, -2.0),
+            Err(WcsError::OutOfProjection(330.0, -2.0))
+        );
+    }
+
+    #[test]
+    fn test_read_fits_header() {
+        let path = std::env::temp_dir().join(format!("implore-wcs-{}.fits", std::process::id()));
+        std::fs::write(&path, tan_fixture()).unwrap();
+        let header = read_fits_header(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        let wcs = Wcs::from_header(&header.unwrap()).unwrap();
+        assert_eq!(wcs.crpix, [512.5, 512.5]);
+    }
+
+    #[test]
+    fn test_cdelt_with_rotation() {
+        let mut header = parse_fits_header(&tan_fixture());
+        for key in ["CD1_1", "CD1_2", "CD2_1", "CD2_2"] {
+            header.remove(key);
+        }
+        header.insert("CDELT1".to_string(), "-2.7777777777778E-03".to_string());
+        header.insert("CDELT2".to_string(), "2.7777777777778E-03".to_string());
+        header.insert("CROTA2".to_string(), "30.0".to_string());
+
+        let wcs = Wcs::from_header(&header).unwrap();
+        assert_close(wcs.pixel_to_world(512.5, 512.5), (150.0, 2.0));
+        let world = wcs.pixel_to_world(100.0, 800.0);
+        assert_close(
+            wcs.world_to_pixel(world.0, world.1).unwrap(),
+            (100.0, 800.0),
+        );
+    }
+
+    #[test]
+    fn test_wcs_errors() {
+        let mut header = parse_fits_header(&tan_fixture());
+        header.remove("CRVAL2");
+        assert_eq!(
+            Wcs::from_header(&header),
+            Err(WcsError::MissingKeyword("CRVAL2".to_string()))
+        );
+
+        let mut header = parse_fits_header(&tan_fixture());
+        header.insert("CTYPE1".to_string(), "RA---SIN".to_string());
+        header.insert("CTYPE2".to_string(), "DEC--SIN".to_string());
+        assert_eq!(
+            Wcs::from_header(&header),
+            Err(WcsError::UnsupportedProjection("SIN".to_string()))
+        );
+
+        let mut header = parse_fits_header(&tan_fixture());
+        header.insert("CD1_1".to_string(), "0.0".to_string());
+        assert_eq!(Wcs::from_header(&header), Err(WcsError::SingularMatrix));
+    }
+
+    #[test]
+    fn test_linear_axes() {
+        let header: HashMap<String, String> = [
+            ("CTYPE1", "WAVE"),
+            ("CTYPE2", "LINEAR"),
+            ("CRPIX1", "1"),
+            ("CRPIX2", "1"),
+            ("CRVAL1", "500.0"),
+            ("CRVAL2", "0.0"),
+            ("CDELT1", "0.5"),
+            ("CDELT2", "2.0"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        let wcs = Wcs::from_header(&header).unwrap();
+        assert_eq!(wcs.projection, Projection::Linear);
+        assert_eq!(wcs.pixel_to_world(11.0, 3.0), (505.0, 4.0));
+        assert_eq!(wcs.world_to_pixel(505.0, 4.0).unwrap(), (11.0, 3.0));
+    }
+}
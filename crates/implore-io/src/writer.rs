@@ -0,0 +1,22 @@
+//! Writer trait for exporting datasets
+
+use crate::reader::IoResult;
+use crate::schema::{DataSchema, DataSlice};
+
+/// Trait for writing scientific data to various formats
+///
+/// A dataset is written as its schema plus a slice holding every column.
+/// Column names, types, and units come from the schema; the slice must
+/// contain a column of matching type for each schema column.
+pub trait DataWriter {
+    /// Write the dataset and finish the file
+    fn write(&mut self, schema: &DataSchema, data: &DataSlice) -> IoResult<()>;
+
+    /// Get the file path (if applicable)
+    fn path(&self) -> Option<&str> {
+        None
+    }
+
+    /// Get the format name
+    fn format_name(&self) -> &'static str;
+}
@@ -225,10 +225,11 @@ pub enum GeometricPrimitive {
     /// Axis-aligned bounding box
     Box { min: [f64; 3], max: [f64; 3] },
 
-    /// Infinite plane defined by point and normal
+    /// Half-space on the side of the plane the normal points into
     Plane { point: [f64; 3], normal: [f64; 3] },
 
-    /// Cylinder along an axis
+    /// Finite cylinder centered on `center`, extending `height / 2` each way
+    /// along `axis` (which need not be normalized)
     Cylinder {
         center: [f64; 3],
         axis: [f64; 3],
@@ -256,6 +257,16 @@ impl GeometricPrimitive {
         Self::Plane { point, normal }
     }
 
+    /// Create a cylinder
+    pub fn cylinder(center: [f64; 3], axis: [f64; 3], radius: f64, height: f64) -> Self {
+        Self::Cylinder {
+            center,
+            axis,
+            radius,
+            height,
+        }
+    }
+
     /// Test if a point is inside the primitive
     pub fn contains(&self, point: &[f64; 3]) -> bool {
         match self {
@@ -278,29 +289,24 @@ impl GeometricPrimitive {
                 radius,
                 height,
             } => {
-                // Project point onto axis
+                let axis_len = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+                if axis_len == 0.0 {
+                    return false;
+                }
+
+                // Signed distance along the axis from the center
                 let v = [
                     point[0] - center[0],
                     point[1] - center[1],
                     point[2] - center[2],
                 ];
-                let axis_len2 = axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2];
-                let t = (v[0] * axis[0] + v[1] * axis[1] + v[2] * axis[2]) / axis_len2;
-
-                // Check height
-                if t < 0.0 || t > *height {
+                let along = (v[0] * axis[0] + v[1] * axis[1] + v[2] * axis[2]) / axis_len;
+                if along.abs() > height / 2.0 {
                     return false;
                 }
 
-                // Check radius
-                let closest = [
-                    center[0] + t * axis[0],
-                    center[1] + t * axis[1],
-                    center[2] + t * axis[2],
-                ];
-                let d2 = (point[0] - closest[0]).powi(2)
-                    + (point[1] - closest[1]).powi(2)
-                    + (point[2] - closest[2]).powi(2);
+                // Squared distance from the axis
+                let d2 = v[0] * v[0] + v[1] * v[1] + v[2] * v[2] - along * along;
                 d2 <= radius * radius
             }
             Self::Polygon { vertices } => {
@@ -396,6 +402,14 @@ mod tests {
         assert!(!aabb.contains(&[-0.5, 0.5, 0.5]));
     }
 
+    #[test]
+    fn test_cylinder_contains() {
+        let cylinder = GeometricPrimitive::cylinder([0.0, 0.0, 0.0], [0.0, 0.0, 2.0], 1.0, 4.0);
+        assert!(cylinder.contains(&[0.5, 0.5, -1.9]));
+        assert!(!cylinder.contains(&[0.0, 0.0, 2.1]));
+        assert!(!cylinder.contains(&[1.0, 1.0, 0.0]));
+    }
+
     #[test]
     fn test_polygon_contains() {
         let polygon = GeometricPrimitive::Polygon {
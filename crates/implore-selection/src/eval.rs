@@ -28,6 +28,13 @@ pub enum EvalError {
 /// Result type for evaluation
 pub type EvalResult<T> = Result<T, EvalError>;
 
+/// Reserved field name bound to the current animation time
+///
+/// `t` never reads from the dataset; it resolves to the value passed to
+/// [`Evaluator::with_time`], so one parsed expression can be re-evaluated
+/// each frame.
+pub const TIME_FIELD: &str = "t";
+
 /// Context for evaluation - provides field values
 pub trait EvalContext {
     /// Get the value of a field at a point index
@@ -88,6 +95,7 @@ impl FieldStats {
 pub struct Evaluator<'a, C: EvalContext> {
     context: &'a C,
     registers: HashMap<String, Vec<bool>>,
+    time: Option<f64>,
 }
 
 impl<'a, C: EvalContext> Evaluator<'a, C> {
@@ -96,9 +104,21 @@ impl<'a, C: EvalContext> Evaluator<'a, C> {
         Self {
             context,
             registers: HashMap::new(),
+            time: None,
         }
     }
 
+    /// Builder method to bind the animation time `t`
+    pub fn with_time(mut self, t: f64) -> Self {
+        self.time = Some(t);
+        self
+    }
+
+    /// Bind the animation time `t` for the next evaluation
+    pub fn set_time(&mut self, t: f64) {
+        self.time = Some(t);
+    }
+
     /// Store a selection in a register
     pub fn store_register(&mut self, name: impl Into<String>, mask: Vec<bool>) {
         self.registers.insert(name.into(), mask);
@@ -160,6 +180,10 @@ impl<'a, C: EvalContext> Evaluator<'a, C> {
         match value {
             Value::Number(n) => Ok(*n),
 
+            Value::Field(name) if name == TIME_FIELD => self
+                .time
+                .ok_or_else(|| EvalError::FieldNotFound(name.clone())),
+
             Value::Field(name) => self
                 .context
                 .field_value(name, index)
@@ -348,6 +372,90 @@ impl<'a, C: EvalContext> Evaluator<'a, C> {
     }
 }
 
+/// Statically check an expression against a dataset's field names
+///
+/// Reports every unknown field, unknown function, string used as a number,
+/// and statistical function not applied to a field, so a UI can flag them all
+/// before evaluation. Registers and geometric primitives aren't checked, and
+/// the reserved [`TIME_FIELD`] is always accepted.
+pub fn validate_against_schema(
+    expr: &SelectionExpr,
+    schema: &[String],
+) -> Result<(), Vec<EvalError>> {
+    let mut errors = Vec::new();
+    validate_expr(expr, schema, &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn validate_expr(expr: &SelectionExpr, schema: &[String], errors: &mut Vec<EvalError>) {
+    match expr {
+        SelectionExpr::And(a, b) | SelectionExpr::Or(a, b) => {
+            validate_expr(a, schema, errors);
+            validate_expr(b, schema, errors);
+        }
+        SelectionExpr::Not(a) => validate_expr(a, schema, errors),
+        SelectionExpr::Comparison(cmp) => {
+            validate_value(&cmp.lhs, schema, errors);
+            validate_value(&cmp.rhs, schema, errors);
+        }
+        SelectionExpr::Statistical(
+            StatisticalFilter::ZScore { field, .. }
+            | StatisticalFilter::Percentile { field, .. }
+            | StatisticalFilter::RobustOutlier { field, .. },
+        ) => validate_field(field, schema, errors),
+        SelectionExpr::Geometric(_)
+        | SelectionExpr::Register(_)
+        | SelectionExpr::All
+        | SelectionExpr::None => {}
+    }
+}
+
+fn validate_field(field: &str, schema: &[String], errors: &mut Vec<EvalError>) {
+    if field != TIME_FIELD && !schema.iter().any(|name| name == field) {
+        errors.push(EvalError::FieldNotFound(field.to_string()));
+    }
+}
+
+fn validate_value(value: &Value, schema: &[String], errors: &mut Vec<EvalError>) {
+    match value {
+        Value::Number(_) => {}
+        Value::Field(name) => validate_field(name, schema, errors),
+        Value::String(s) => errors.push(EvalError::TypeError(format!(
+            "Cannot use string \"{}\" in numeric comparison",
+            s
+        ))),
+        Value::Function(func) => match func.name.as_str() {
+            "zscore" | "robust_zscore" => match func.args.first() {
+                Some(Value::Field(field)) => validate_field(field, schema, errors),
+                _ => errors.push(EvalError::InvalidArguments(format!(
+                    "{} requires a field argument",
+                    func.name
+                ))),
+            },
+            "percentile" => match (func.args.first(), func.args.get(1)) {
+                (Some(Value::Field(field)), Some(Value::Number(_))) => {
+                    validate_field(field, schema, errors)
+                }
+                _ => errors.push(EvalError::InvalidArguments(
+                    "percentile requires (field, p) arguments".to_string(),
+                )),
+            },
+            "abs" | "sqrt" | "log10" => match func.args.first() {
+                Some(arg) => validate_value(arg, schema, errors),
+                None => errors.push(EvalError::InvalidArguments(format!(
+                    "{} requires an argument",
+                    func.name
+                ))),
+            },
+            _ => errors.push(EvalError::UnknownFunction(func.name.clone())),
+        },
+    }
+}
+
 /// Count the number of selected points
 pub fn count_selected(mask: &[bool]) -> usize {
     mask.iter().filter(|&&b| b).count()
@@ -379,6 +487,7 @@ pub fn or_masks(a: &[bool], b: &[bool]) -> Vec<bool> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::parse_selection;
 
     struct TestContext {
         x: Vec<f64>,
@@ -468,6 +577,48 @@ mod tests {
         assert_eq!(mask, vec![true, true, false]);
     }
 
+    #[test]
+    fn test_evaluate_cylinder() {
+        let ctx = TestContext {
+            x: vec![0.0, 0.9, 1.1, 0.0, 0.5],
+            y: vec![0.0, 0.0, 0.0, 0.0, 0.5],
+            z: vec![0.0, 1.0, 0.0, 1.5, -0.9],
+        };
+
+        let eval = Evaluator::new(&ctx);
+
+        // Unit-radius cylinder along z spanning z in [-1, 1]
+        let expr = parse_selection("cylinder([0,0,0], [0,0,3], 1.0, 2.0)").unwrap();
+        let mask = eval.evaluate(&expr).unwrap();
+
+        // on axis, rim at the top cap, outside radius, above the top, inside
+        assert_eq!(mask, vec![true, true, false, false, true]);
+    }
+
+    #[test]
+    fn test_evaluate_plane() {
+        let ctx = TestContext {
+            x: vec![0.0, 1.0, 2.0, 3.0],
+            y: vec![2.0, 0.0, 5.0, -2.0],
+            z: vec![0.0; 4],
+        };
+
+        let eval = Evaluator::new(&ctx);
+
+        // Keep the side of x + y = 2 that the normal (1, 1, 0) points into
+        let expr = parse_selection("plane([1,1,0], [1,1,0])").unwrap();
+        let mask = eval.evaluate(&expr).unwrap();
+
+        // (0, 2) lies on the plane and counts as inside
+        assert_eq!(mask, vec![true, false, true, false]);
+
+        let flipped = parse_selection("plane([1,1,0], [-1,-1,0])").unwrap();
+        assert_eq!(
+            eval.evaluate(&flipped).unwrap(),
+            vec![true, true, false, true]
+        );
+    }
+
     #[test]
     fn test_count_selected() {
         let mask = vec![true, false, true, false, true];
@@ -479,4 +630,67 @@ mod tests {
         let mask = vec![true, false, true, false, true];
         assert_eq!(selected_indices(&mask), vec![0, 2, 4]);
     }
+
+    #[test]
+    fn test_evaluate_time_per_frame() {
+        let ctx = TestContext {
+            x: vec![0.1, 0.3, 0.5, 0.7, 0.9],
+            y: vec![0.0; 5],
+            z: vec![0.0; 5],
+        };
+        let expr = parse_selection("x < t").unwrap();
+
+        let mut eval = Evaluator::new(&ctx).with_time(0.4);
+        assert_eq!(
+            eval.evaluate(&expr).unwrap(),
+            vec![true, true, false, false, false]
+        );
+
+        eval.set_time(0.8);
+        assert_eq!(
+            eval.evaluate(&expr).unwrap(),
+            vec![true, true, true, true, false]
+        );
+
+        let unbound = Evaluator::new(&ctx);
+        assert!(matches!(
+            unbound.evaluate(&expr),
+            Err(EvalError::FieldNotFound(ref f)) if f == "t"
+        ));
+    }
+
+    fn schema(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_validate_against_schema_valid() {
+        let expr =
+            parse_selection("x > 0 && abs(y) < 2 && sphere([0,0,0], 1.0) || zscore(mass) < 3")
+                .unwrap();
+        assert!(validate_against_schema(&expr, &schema(&["x", "y", "mass"])).is_ok());
+    }
+
+    #[test]
+    fn test_validate_against_schema_collects_errors() {
+        let expr = parse_selection("x > 0 && density < 3 || !(zscore(temp) < 2)").unwrap();
+        let errors = validate_against_schema(&expr, &schema(&["x", "y"])).unwrap_err();
+
+        let missing: Vec<String> = errors
+            .iter()
+            .map(|e| match e {
+                EvalError::FieldNotFound(field) => field.clone(),
+                other => panic!("unexpected error: {}", other),
+            })
+            .collect();
+        assert_eq!(missing, vec!["density", "temp"]);
+
+        let call = SelectionExpr::Comparison(Comparison::new(
+            Value::Function(FunctionCall::new("zscore", vec![Value::Number(1.0)])),
+            ComparisonOp::Lt,
+            Value::Number(3.0),
+        ));
+        let errors = validate_against_schema(&call, &schema(&["x"])).unwrap_err();
+        assert!(matches!(errors[..], [EvalError::InvalidArguments(_)]));
+    }
 }
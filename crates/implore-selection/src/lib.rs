@@ -10,6 +10,9 @@
 //! - **Statistical filters**: `zscore(density) < 3`
 //! - **Set operations**: `(A || B) && !C`
 //! - **Named registers**: `"a` to store, `@a` to recall
+//! - **Arithmetic**: `cos(t) * (1 + 0.5 * sin(3*t))`, see [`math`]
+//! - **Animation time**: `x < t` — `t` is reserved for the per-frame time the
+//!   caller binds with [`Evaluator::with_time`], never a dataset field
 //!
 //! # Examples
 //!
@@ -23,10 +26,12 @@
 
 pub mod ast;
 pub mod eval;
+pub mod math;
 pub mod parser;
 
 pub use ast::*;
 pub use eval::*;
+pub use math::{parse_math, BinaryOp, MathExpr};
 pub use parser::*;
 
 // Setup UniFFI when the feature is enabled
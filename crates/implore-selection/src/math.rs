@@ -0,0 +1,317 @@
+//! Arithmetic expressions over named variables
+//!
+//! Used wherever a formula is typed in rather than picked from a list, e.g.
+//! parametric curves `x = cos(t)`, `y = sin(2*t)`.
+//!
+//! Grammar:
+//! ```text
+//! sum     := product (('+' | '-') product)*
+//! product := unary (('*' | '/') unary)*
+//! unary   := ('-' | '+') unary | power
+//! power   := primary ('^' unary)?
+//! primary := number | function | variable | '(' sum ')'
+//! function:= ident '(' sum (',' sum)* ')'
+//! ```
+//!
+//! `^` is right-associative and binds tighter than unary minus, so `-t^2`
+//! is `-(t^2)`. The constants `pi`, `tau`, and `e` are always defined, and
+//! `log` is the natural logarithm (`log10` for base 10).
+
+use crate::eval::{EvalError, EvalResult};
+use crate::parser::{identifier, parse_number, ws, ParseError};
+use nom::{
+    branch::alt,
+    character::complete::{char, one_of},
+    combinator::map,
+    multi::{many0, separated_list1},
+    sequence::{delimited, pair, preceded},
+    IResult,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// An arithmetic expression
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MathExpr {
+    /// A numeric literal
+    Number(f64),
+    /// A variable or constant reference
+    Variable(String),
+    /// Negation
+    Neg(Box<MathExpr>),
+    /// A binary operation
+    Binary(BinaryOp, Box<MathExpr>, Box<MathExpr>),
+    /// A function call
+    Call(String, Vec<MathExpr>),
+}
+
+/// Binary arithmetic operators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+impl BinaryOp {
+    /// Apply the operator
+    pub fn apply(&self, lhs: f64, rhs: f64) -> f64 {
+        match self {
+            BinaryOp::Add => lhs + rhs,
+            BinaryOp::Sub => lhs - rhs,
+            BinaryOp::Mul => lhs * rhs,
+            BinaryOp::Div => lhs / rhs,
+            BinaryOp::Pow => lhs.powf(rhs),
+        }
+    }
+}
+
+impl MathExpr {
+    /// Evaluate the expression with the given variable values
+    pub fn evaluate(&self, variables: &HashMap<String, f64>) -> EvalResult<f64> {
+        match self {
+            MathExpr::Number(n) => Ok(*n),
+            MathExpr::Variable(name) => variables
+                .get(name)
+                .copied()
+                .or_else(|| constant(name))
+                .ok_or_else(|| EvalError::FieldNotFound(name.clone())),
+            MathExpr::Neg(inner) => Ok(-inner.evaluate(variables)?),
+            MathExpr::Binary(op, lhs, rhs) => {
+                Ok(op.apply(lhs.evaluate(variables)?, rhs.evaluate(variables)?))
+            }
+            MathExpr::Call(name, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.evaluate(variables))
+                    .collect::<EvalResult<Vec<f64>>>()?;
+                call(name, &args)
+            }
+        }
+    }
+
+    /// Names of the variables referenced, excluding built-in constants
+    pub fn variables(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.collect_variables(&mut names);
+        names
+    }
+
+    fn collect_variables(&self, names: &mut Vec<String>) {
+        match self {
+            MathExpr::Number(_) => {}
+            MathExpr::Variable(name) => {
+                if constant(name).is_none() && !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+            MathExpr::Neg(inner) => inner.collect_variables(names),
+            MathExpr::Binary(_, lhs, rhs) => {
+                lhs.collect_variables(names);
+                rhs.collect_variables(names);
+            }
+            MathExpr::Call(_, args) => {
+                for arg in args {
+                    arg.collect_variables(names);
+                }
+            }
+        }
+    }
+}
+
+/// Value of a built-in constant
+fn constant(name: &str) -> Option<f64> {
+    match name {
+        "pi" => Some(std::f64::consts::PI),
+        "tau" => Some(std::f64::consts::TAU),
+        "e" => Some(std::f64::consts::E),
+        _ => None,
+    }
+}
+
+/// Evaluate a built-in function
+fn call(name: &str, args: &[f64]) -> EvalResult<f64> {
+    let unary = |f: fn(f64) -> f64| match args {
+        [x] => Ok(f(*x)),
+        _ => Err(EvalError::InvalidArguments(format!(
+            "{} takes 1 argument, got {}",
+            name,
+            args.len()
+        ))),
+    };
+    let binary = |f: fn(f64, f64) -> f64| match args {
+        [x, y] => Ok(f(*x, *y)),
+        _ => Err(EvalError::InvalidArguments(format!(
+            "{} takes 2 arguments, got {}",
+            name,
+            args.len()
+        ))),
+    };
+
+    match name {
+        "sin" => unary(f64::sin),
+        "cos" => unary(f64::cos),
+        "tan" => unary(f64::tan),
+        "asin" => unary(f64::asin),
+        "acos" => unary(f64::acos),
+        "atan" => unary(f64::atan),
+        "sinh" => unary(f64::sinh),
+        "cosh" => unary(f64::cosh),
+        "tanh" => unary(f64::tanh),
+        "exp" => unary(f64::exp),
+        "ln" | "log" => unary(f64::ln),
+        "log10" => unary(f64::log10),
+        "sqrt" => unary(f64::sqrt),
+        "abs" => unary(f64::abs),
+        "floor" => unary(f64::floor),
+        "ceil" => unary(f64::ceil),
+        "atan2" => binary(f64::atan2),
+        "pow" => binary(f64::powf),
+        "min" => binary(f64::min),
+        "max" => binary(f64::max),
+        _ => Err(EvalError::UnknownFunction(name.to_string())),
+    }
+}
+
+/// Parse an arithmetic expression from a string
+pub fn parse_math(input: &str) -> Result<MathExpr, ParseError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParseError::UnexpectedEnd);
+    }
+
+    match sum(input) {
+        Ok(("", result)) => Ok(result),
+        Ok((remaining, _)) => Err(ParseError::Parse(format!(
+            "Unexpected characters at end: '{}'",
+            remaining
+        ))),
+        Err(e) => Err(ParseError::Parse(format!("Parse error: {:?}", e))),
+    }
+}
+
+/// Fold a chain of left-associative operations
+fn fold_binary(first: MathExpr, rest: Vec<(BinaryOp, MathExpr)>) -> MathExpr {
+    rest.into_iter().fold(first, |lhs, (op, rhs)| {
+        MathExpr::Binary(op, Box::new(lhs), Box::new(rhs))
+    })
+}
+
+/// Parse addition and subtraction
+fn sum(input: &str) -> IResult<&str, MathExpr> {
+    let (input, first) = product(input)?;
+    let op = map(ws(one_of("+-")), |c| match c {
+        '+' => BinaryOp::Add,
+        _ => BinaryOp::Sub,
+    });
+    let (input, rest) = many0(pair(op, product))(input)?;
+    Ok((input, fold_binary(first, rest)))
+}
+
+/// Parse multiplication and division
+fn product(input: &str) -> IResult<&str, MathExpr> {
+    let (input, first) = unary(input)?;
+    let op = map(ws(one_of("*/")), |c| match c {
+        '*' => BinaryOp::Mul,
+        _ => BinaryOp::Div,
+    });
+    let (input, rest) = many0(pair(op, unary))(input)?;
+    Ok((input, fold_binary(first, rest)))
+}
+
+/// Parse unary signs
+fn unary(input: &str) -> IResult<&str, MathExpr> {
+    alt((
+        map(preceded(ws(char('-')), unary), |e| {
+            MathExpr::Neg(Box::new(e))
+        }),
+        preceded(ws(char('+')), unary),
+        power,
+    ))(input)
+}
+
+/// Parse exponentiation (right-associative)
+fn power(input: &str) -> IResult<&str, MathExpr> {
+    let (input, base) = primary(input)?;
+    match preceded(ws(char('^')), unary)(input) {
+        Ok((input, exponent)) => Ok((
+            input,
+            MathExpr::Binary(BinaryOp::Pow, Box::new(base), Box::new(exponent)),
+        )),
+        Err(nom::Err::Error(_)) => Ok((input, base)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parse a number, function call, variable, or parenthesized expression
+fn primary(input: &str) -> IResult<&str, MathExpr> {
+    ws(alt((
+        function_call,
+        map(identifier, |s| MathExpr::Variable(s.to_string())),
+        map(parse_number, MathExpr::Number),
+        delimited(char('('), sum, ws(char(')'))),
+    )))(input)
+}
+
+/// Parse a function call
+fn function_call(input: &str) -> IResult<&str, MathExpr> {
+    let (input, name) = identifier(input)?;
+    let (input, args) = delimited(
+        ws(char('(')),
+        separated_list1(ws(char(',')), sum),
+        ws(char(')')),
+    )(input)?;
+    Ok((input, MathExpr::Call(name.to_string(), args)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(input: &str, t: f64) -> f64 {
+        let vars = HashMap::from([("t".to_string(), t)]);
+        parse_math(input).unwrap().evaluate(&vars).unwrap()
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(eval("1 + 2 * 3", 0.0), 7.0);
+        assert_eq!(eval("(1 + 2) * 3", 0.0), 9.0);
+        assert_eq!(eval("10 - 4 - 3", 0.0), 3.0);
+        assert_eq!(eval("2 ^ 3 ^ 2", 0.0), 512.0);
+        assert_eq!(eval("-t^2", 3.0), -9.0);
+        assert_eq!(eval("2*-t", 3.0), -6.0);
+    }
+
+    #[test]
+    fn test_functions_and_constants() {
+        assert!((eval("cos(t)^2 + sin(t)^2", 0.7) - 1.0).abs() < 1e-12);
+        assert!((eval("atan2(1, 1) * 4", 0.0) - std::f64::consts::PI).abs() < 1e-12);
+        assert_eq!(eval("1.5e2 + t", 1.0), 151.0);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse_math("").is_err());
+        assert!(parse_math("1 +").is_err());
+        assert!(parse_math("sin(t").is_err());
+
+        let vars = HashMap::new();
+        let expr = parse_math("foo(t)").unwrap();
+        assert!(matches!(
+            expr.evaluate(&vars),
+            Err(EvalError::FieldNotFound(_))
+        ));
+        let expr = parse_math("foo(1)").unwrap();
+        assert!(matches!(
+            expr.evaluate(&vars),
+            Err(EvalError::UnknownFunction(_))
+        ));
+        assert_eq!(
+            parse_math("a * sin(b) + pi").unwrap().variables(),
+            ["a", "b"]
+        );
+    }
+}
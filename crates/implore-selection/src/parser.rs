@@ -10,8 +10,11 @@
 //! comparison:= value op value
 //! op        := '<' | '<=' | '>' | '>=' | '==' | '!='
 //! value     := field | number | string | function
+//! field     := ident                  ('t' is reserved for animation time)
 //! function  := ident '(' args ')'
-//! geometric := 'sphere' '(' point ',' number ')' | 'box' '(' point ',' point ')' | ...
+//! geometric := 'sphere' '(' point ',' number ')' | 'box' '(' point ',' point ')'
+//!            | 'cylinder' '(' point ',' point ',' number ',' number ')'
+//!            | 'plane' '(' point ',' point ')' | 'polygon' '(' point2 (',' point2)* ')'
 //! ```
 
 use crate::ast::*;
@@ -19,10 +22,10 @@ use nom::{
     branch::alt,
     bytes::complete::{tag, take_while, take_while1},
     character::complete::{char, multispace0},
-    combinator::{map, recognize, value},
+    combinator::{cut, map, recognize, value},
     multi::{many0, separated_list0},
     number::complete::double,
-    sequence::{delimited, pair, preceded},
+    sequence::{delimited, pair, preceded, terminated},
     IResult,
 };
 use thiserror::Error;
@@ -58,7 +61,7 @@ pub fn parse_selection(input: &str) -> Result<SelectionExpr, ParseError> {
 }
 
 /// Parse whitespace
-fn ws<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+pub(crate) fn ws<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
 where
     F: FnMut(&'a str) -> IResult<&'a str, O>,
 {
@@ -150,12 +153,12 @@ fn parse_value(input: &str) -> IResult<&str, Value> {
 }
 
 /// Parse a number (including scientific notation)
-fn parse_number(input: &str) -> IResult<&str, f64> {
+pub(crate) fn parse_number(input: &str) -> IResult<&str, f64> {
     double(input)
 }
 
 /// Parse an identifier (starts with letter or underscore, followed by alphanumeric or underscore)
-fn identifier(input: &str) -> IResult<&str, &str> {
+pub(crate) fn identifier(input: &str) -> IResult<&str, &str> {
     recognize(pair(
         take_while1(|c: char| c.is_alphabetic() || c == '_'),
         take_while(|c: char| c.is_alphanumeric() || c == '_'),
@@ -185,7 +188,13 @@ fn function_call(input: &str) -> IResult<&str, FunctionCall> {
 
 /// Parse a geometric primitive
 fn geometric_primitive(input: &str) -> IResult<&str, GeometricPrimitive> {
-    alt((parse_sphere, parse_box, parse_polygon))(input)
+    alt((
+        parse_sphere,
+        parse_box,
+        parse_cylinder,
+        parse_plane,
+        parse_polygon,
+    ))(input)
 }
 
 /// Parse a sphere: sphere([x,y,z], r)
@@ -214,6 +223,91 @@ fn parse_box(input: &str) -> IResult<&str, GeometricPrimitive> {
     Ok((input, GeometricPrimitive::aabb(min, max)))
 }
 
+/// Parse a cylinder: cylinder([cx,cy,cz], [ax,ay,az], radius, height)
+fn parse_cylinder(input: &str) -> IResult<&str, GeometricPrimitive> {
+    use PrimitiveArg::{Number, Vector};
+
+    let (input, args) = primitive_args("cylinder")(input)?;
+    match args.as_slice() {
+        [Vector(center), Vector(axis), Number(radius), Number(height)] => {
+            let center = vector3(center, input)?;
+            let axis = vector3(axis, input)?;
+            Ok((
+                input,
+                GeometricPrimitive::cylinder(center, axis, *radius, *height),
+            ))
+        }
+        _ => Err(invalid_arguments(input)),
+    }
+}
+
+/// Parse a half-space: plane([px,py,pz], [nx,ny,nz])
+fn parse_plane(input: &str) -> IResult<&str, GeometricPrimitive> {
+    let (input, args) = primitive_args("plane")(input)?;
+    match args.as_slice() {
+        [PrimitiveArg::Vector(point), PrimitiveArg::Vector(normal)] => {
+            let point = vector3(point, input)?;
+            let normal = vector3(normal, input)?;
+            Ok((input, GeometricPrimitive::plane(point, normal)))
+        }
+        _ => Err(invalid_arguments(input)),
+    }
+}
+
+/// An argument to a geometric primitive, before its shape is checked
+enum PrimitiveArg {
+    Number(f64),
+    Vector(Vec<f64>),
+}
+
+/// Parse `name(arg, ...)` where each arg is a number or `[n, ...]` vector
+///
+/// Once the name and opening parenthesis match, later failures are fatal so
+/// a malformed primitive reports an error instead of being retried as a
+/// comparison.
+fn primitive_args<'a>(
+    name: &'static str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<PrimitiveArg>> {
+    move |input| {
+        let (input, _) = tag(name)(input)?;
+        let (input, _) = multispace0(input)?;
+        let (input, _) = char('(')(input)?;
+        cut(terminated(
+            separated_list0(
+                ws(char(',')),
+                ws(alt((
+                    map(
+                        delimited(
+                            char('['),
+                            separated_list0(ws(char(',')), ws(parse_number)),
+                            char(']'),
+                        ),
+                        PrimitiveArg::Vector,
+                    ),
+                    map(parse_number, PrimitiveArg::Number),
+                ))),
+            ),
+            char(')'),
+        ))(input)
+    }
+}
+
+/// Check that a vector argument has exactly three components
+fn vector3<'a>(
+    v: &[f64],
+    input: &'a str,
+) -> Result<[f64; 3], nom::Err<nom::error::Error<&'a str>>> {
+    match v {
+        [x, y, z] => Ok([*x, *y, *z]),
+        _ => Err(invalid_arguments(input)),
+    }
+}
+
+/// Fatal error for a primitive with the wrong number or shape of arguments
+fn invalid_arguments(input: &str) -> nom::Err<nom::error::Error<&str>> {
+    nom::Err::Failure(nom::error::Error::new(input, nom::error::ErrorKind::Verify))
+}
+
 /// Parse a polygon: polygon([x1,y1], [x2,y2], ...)
 fn parse_polygon(input: &str) -> IResult<&str, GeometricPrimitive> {
     let (input, _) = tag("polygon")(input)?;
@@ -304,6 +398,33 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_cylinder_and_plane() {
+        let result = parse_selection("cylinder([0, 0, 0], [0, 0, 1], 0.5, 2)").unwrap();
+        assert_eq!(
+            result,
+            SelectionExpr::Geometric(GeometricPrimitive::cylinder(
+                [0.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0],
+                0.5,
+                2.0
+            ))
+        );
+
+        let result = parse_selection("plane([0,0,0], [1,0,0]) && x < 5").unwrap();
+        assert!(matches!(result, SelectionExpr::And(_, _)));
+
+        for bad in [
+            "cylinder([0,0,0], [0,0,1], 0.5)",
+            "cylinder([0,0], [0,0,1], 0.5, 2)",
+            "plane([0,0,0])",
+            "plane([0,0,0], [1,0,0,0])",
+            "plane([0,0,0], 1)",
+        ] {
+            assert!(parse_selection(bad).is_err(), "{} should not parse", bad);
+        }
+    }
+
     #[test]
     fn test_parse_complex() {
         let result = parse_selection("(x > 0 && y < 10) || sphere([0,0,0], 5)").unwrap();
@@ -322,6 +443,20 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_time_field() {
+        let result = parse_selection("x < t && t >= 0.5").unwrap();
+        match result {
+            SelectionExpr::And(lhs, _) => match *lhs {
+                SelectionExpr::Comparison(c) => {
+                    assert!(matches!(c.rhs, Value::Field(ref f) if f == crate::eval::TIME_FIELD));
+                }
+                _ => panic!("Expected comparison"),
+            },
+            _ => panic!("Expected and"),
+        }
+    }
+
     #[test]
     fn test_parse_function() {
         let result = parse_selection("zscore(mass) < 3").unwrap();
@@ -104,6 +104,21 @@ impl Ecdf {
         }
     }
 
+    /// Get the quantile, interpolating linearly between order statistics
+    ///
+    /// Unlike [`Ecdf::quantile`], the result varies smoothly with `p`, which
+    /// makes quantiles of samples of different sizes comparable.
+    pub fn interpolated_quantile(&self, p: f64) -> Option<f64> {
+        if self.values.is_empty() || !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+
+        let h = p * (self.values.len() - 1) as f64;
+        let lo = h.floor() as usize;
+        let hi = h.ceil() as usize;
+        Some(self.values[lo] + (h - lo as f64) * (self.values[hi] - self.values[lo]))
+    }
+
     /// Get common quantiles (min, 25%, median, 75%, max)
     pub fn five_number_summary(&self) -> Option<FiveNumberSummary> {
         if self.values.is_empty() {
@@ -185,6 +200,101 @@ impl Ecdf {
     }
 }
 
+/// Paired quantiles of two samples for a quantile-quantile (Q-Q) plot
+///
+/// Evaluates both samples' quantile functions at `n` evenly spaced
+/// probabilities `(i + 0.5) / n` and returns `(quantile_a, quantile_b)`
+/// pairs. Points on the line y = x mean the distributions match. The samples
+/// may differ in size; quantiles are interpolated between order statistics.
+pub fn qq_points(a: &[f64], b: &[f64], n: usize) -> Vec<(f64, f64)> {
+    let ecdf_a = Ecdf::from_data(a);
+    let ecdf_b = Ecdf::from_data(b);
+    if ecdf_a.values.is_empty() || ecdf_b.values.is_empty() {
+        return Vec::new();
+    }
+
+    (0..n)
+        .filter_map(|i| {
+            let p = (i as f64 + 0.5) / n as f64;
+            Some((
+                ecdf_a.interpolated_quantile(p)?,
+                ecdf_b.interpolated_quantile(p)?,
+            ))
+        })
+        .collect()
+}
+
+/// Result of a two-sample Kolmogorov-Smirnov test
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct KsResult {
+    /// D statistic: the largest vertical gap between the two ECDFs (0 to 1)
+    pub statistic: f64,
+    /// Approximate p-value under the null hypothesis that both samples come
+    /// from the same distribution (asymptotic; less accurate for tiny samples)
+    pub p_value: f64,
+}
+
+/// Two-sample Kolmogorov-Smirnov test
+///
+/// Compares the ECDFs of samples `a` and `b` (which may differ in size)
+/// rather than testing one sample against a reference distribution. The D
+/// statistic is found in a single pass over both sorted samples, so the cost
+/// is O(n log n) for the sort. Non-finite values are ignored; if either
+/// sample is empty both fields are NaN.
+pub fn ks_two_sample(a: &[f64], b: &[f64]) -> KsResult {
+    let a = Ecdf::from_data(a).values;
+    let b = Ecdf::from_data(b).values;
+    if a.is_empty() || b.is_empty() {
+        return KsResult {
+            statistic: f64::NAN,
+            p_value: f64::NAN,
+        };
+    }
+
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let (mut i, mut j) = (0, 0);
+    let mut d: f64 = 0.0;
+    while i < a.len() && j < b.len() {
+        // Step past every copy of the next value in both samples so ties
+        // don't open a spurious gap
+        let x = a[i].min(b[j]);
+        while i < a.len() && a[i] == x {
+            i += 1;
+        }
+        while j < b.len() && b[j] == x {
+            j += 1;
+        }
+        d = d.max((i as f64 / n_a - j as f64 / n_b).abs());
+    }
+
+    let en = (n_a * n_b / (n_a + n_b)).sqrt();
+    KsResult {
+        statistic: d,
+        p_value: kolmogorov_survival((en + 0.12 + 0.11 / en) * d),
+    }
+}
+
+/// Survival function of the Kolmogorov distribution, Q(λ) = P(K > λ)
+fn kolmogorov_survival(lambda: f64) -> f64 {
+    // The alternating series converges too slowly near zero, where Q is 1
+    if lambda < 0.2 {
+        return 1.0;
+    }
+
+    let mut sum = 0.0;
+    let mut sign = 1.0;
+    for k in 1..=100 {
+        let k = k as f64;
+        let term = sign * (-2.0 * k * k * lambda * lambda).exp();
+        sum += term;
+        if term.abs() < 1e-12 {
+            break;
+        }
+        sign = -sign;
+    }
+    (2.0 * sum).clamp(0.0, 1.0)
+}
+
 /// Five number summary statistics
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct FiveNumberSummary {
@@ -274,6 +384,57 @@ mod tests {
         assert!((ecdf.evaluate(2.0) - 5.0 / 6.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_qq_points_same_distribution() {
+        // Every third value vs. the full sample: same distribution, different sizes
+        let full: Vec<f64> = (0..3000).map(|i| ((i * 7919) % 3000) as f64).collect();
+        let subsample: Vec<f64> = full.iter().copied().step_by(3).collect();
+
+        let points = qq_points(&full, &subsample, 50);
+        assert_eq!(points.len(), 50);
+        for (qa, qb) in points {
+            assert!(
+                (qa - qb).abs() < 5.0,
+                "({}, {}) is off the y = x line",
+                qa,
+                qb
+            );
+        }
+    }
+
+    #[test]
+    fn test_qq_points_shifted() {
+        let a: Vec<f64> = (0..100).map(|x| x as f64).collect();
+        let b: Vec<f64> = a.iter().map(|x| x + 10.0).collect();
+
+        for (qa, qb) in qq_points(&a, &b, 10) {
+            assert!((qb - qa - 10.0).abs() < 1e-10);
+        }
+        assert!(qq_points(&a, &[], 10).is_empty());
+    }
+
+    #[test]
+    fn test_ks_identical_distributions() {
+        let a: Vec<f64> = (0..1000).map(|i| ((i * 7919) % 1000) as f64).collect();
+        let b: Vec<f64> = a.iter().copied().step_by(2).collect();
+
+        let result = ks_two_sample(&a, &b);
+        assert!(result.statistic < 0.01);
+        assert!(result.p_value > 0.9);
+    }
+
+    #[test]
+    fn test_ks_shifted_distributions() {
+        let a: Vec<f64> = (0..200).map(|x| x as f64).collect();
+        let b: Vec<f64> = a.iter().map(|x| x + 100.0).collect();
+
+        let result = ks_two_sample(&a, &b);
+        assert!((result.statistic - 0.5).abs() < 1e-10);
+        assert!(result.p_value < 1e-6);
+
+        assert!(ks_two_sample(&a, &[]).statistic.is_nan());
+    }
+
     #[test]
     fn test_ecdf_plot_points() {
         let data = vec![1.0, 2.0, 3.0];
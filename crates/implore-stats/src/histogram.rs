@@ -0,0 +1,299 @@
+//! Incremental histograms
+//!
+//! ECDFs are preferred for display, but some exports need binned counts.
+//! A [`Histogram`] has fixed, equal-width bins over a range and can be
+//! filled incrementally: fill one per chunk or thread and [`merge`] them.
+//!
+//! Bins are half-open, `[lo, hi)`, except the last, which also includes the
+//! upper end of the range. Values outside the range are counted in underflow
+//! and overflow bins; NaNs are counted as missing.
+//!
+//! [`merge`]: Histogram::merge
+
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use thiserror::Error;
+
+/// Errors from combining histograms
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum HistogramError {
+    #[error("Histograms have different binning: {0} bins over {1:?} vs {2} bins over {3:?}")]
+    IncompatibleBinning(usize, (f64, f64), usize, (f64, f64)),
+}
+
+/// Equal-width histogram with under/overflow counts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Histogram {
+    /// Lower end of the first bin
+    min: f64,
+    /// Upper end of the last bin
+    max: f64,
+    /// Count in each bin
+    counts: Vec<u64>,
+    /// Values below `min`
+    underflow: u64,
+    /// Values above `max`
+    overflow: u64,
+    /// NaN values
+    missing: u64,
+}
+
+impl Histogram {
+    /// Create an empty histogram with `n_bins` equal bins over `range`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_bins` is zero or the range is empty or not finite.
+    pub fn new(range: Range<f64>, n_bins: usize) -> Self {
+        assert!(n_bins > 0, "histogram needs at least one bin");
+        assert!(
+            range.start.is_finite() && range.end.is_finite() && range.start < range.end,
+            "histogram range must be finite and non-empty, got {:?}",
+            range
+        );
+        Self {
+            min: range.start,
+            max: range.end,
+            counts: vec![0; n_bins],
+            underflow: 0,
+            overflow: 0,
+            missing: 0,
+        }
+    }
+
+    /// Build a histogram from data in one pass
+    pub fn from_data(data: &[f64], range: Range<f64>, n_bins: usize) -> Self {
+        let mut histogram = Self::new(range, n_bins);
+        histogram.add_slice(data);
+        histogram
+    }
+
+    /// Add a value
+    pub fn add(&mut self, x: f64) {
+        if x.is_nan() {
+            self.missing += 1;
+        } else if x < self.min {
+            self.underflow += 1;
+        } else if x > self.max {
+            self.overflow += 1;
+        } else {
+            let bin = self.bin_for(x);
+            self.counts[bin] += 1;
+        }
+    }
+
+    /// Add every value in a slice
+    pub fn add_slice(&mut self, data: &[f64]) {
+        for &x in data {
+            self.add(x);
+        }
+    }
+
+    /// Add the counts of another histogram with the same binning
+    pub fn merge(&mut self, other: &Histogram) -> Result<(), HistogramError> {
+        if self.n_bins() != other.n_bins() || self.min != other.min || self.max != other.max {
+            return Err(HistogramError::IncompatibleBinning(
+                self.n_bins(),
+                (self.min, self.max),
+                other.n_bins(),
+                (other.min, other.max),
+            ));
+        }
+        for (count, added) in self.counts.iter_mut().zip(&other.counts) {
+            *count += added;
+        }
+        self.underflow += other.underflow;
+        self.overflow += other.overflow;
+        self.missing += other.missing;
+        Ok(())
+    }
+
+    /// Bin a value falls in, or `None` if it is outside the range or NaN
+    pub fn bin_index(&self, x: f64) -> Option<usize> {
+        if x >= self.min && x <= self.max {
+            Some(self.bin_for(x))
+        } else {
+            None
+        }
+    }
+
+    /// Bin for an in-range value, consistent with [`edges`](Self::edges)
+    fn bin_for(&self, x: f64) -> usize {
+        let n = self.n_bins();
+        let guess = ((x - self.min) / (self.max - self.min) * n as f64) as usize;
+        let mut bin = guess.min(n - 1);
+        // Rounding in the division can land one bin off near an edge
+        if bin > 0 && x < self.edge(bin) {
+            bin -= 1;
+        } else if bin + 1 < n && x >= self.edge(bin + 1) {
+            bin += 1;
+        }
+        bin
+    }
+
+    /// Position of the `i`th bin edge (0 is `min`, `n_bins` is `max`)
+    fn edge(&self, i: usize) -> f64 {
+        if i == self.n_bins() {
+            return self.max;
+        }
+        self.min + (self.max - self.min) * i as f64 / self.n_bins() as f64
+    }
+
+    /// Number of bins
+    pub fn n_bins(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Range covered by the bins
+    pub fn range(&self) -> Range<f64> {
+        self.min..self.max
+    }
+
+    /// Width of each bin
+    pub fn bin_width(&self) -> f64 {
+        (self.max - self.min) / self.n_bins() as f64
+    }
+
+    /// Bin edges, `n_bins + 1` values from `min` to `max`
+    pub fn edges(&self) -> Vec<f64> {
+        (0..=self.n_bins()).map(|i| self.edge(i)).collect()
+    }
+
+    /// Bin centers
+    pub fn centers(&self) -> Vec<f64> {
+        (0..self.n_bins())
+            .map(|i| 0.5 * (self.edge(i) + self.edge(i + 1)))
+            .collect()
+    }
+
+    /// Count in each bin
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Number of values below the range
+    pub fn underflow(&self) -> u64 {
+        self.underflow
+    }
+
+    /// Number of values above the range
+    pub fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// Number of NaN values
+    pub fn missing(&self) -> u64 {
+        self.missing
+    }
+
+    /// Number of values inside the range
+    pub fn in_range(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Number of values added, including out-of-range and NaN values
+    pub fn total(&self) -> u64 {
+        self.in_range() + self.underflow + self.overflow + self.missing
+    }
+
+    /// Probability density in each bin
+    ///
+    /// Normalized over the in-range values, so the density integrates to 1
+    /// over the range. All zeros if no values are in range.
+    pub fn density(&self) -> Vec<f64> {
+        let in_range = self.in_range();
+        if in_range == 0 {
+            return vec![0.0; self.n_bins()];
+        }
+        let norm = in_range as f64 * self.bin_width();
+        self.counts.iter().map(|&c| c as f64 / norm).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bin_boundaries() {
+        let mut hist = Histogram::new(0.0..1.0, 10);
+
+        // Lower edges belong to their bin, the range end to the last bin
+        assert_eq!(hist.bin_index(0.0), Some(0));
+        assert_eq!(hist.bin_index(0.1), Some(1));
+        assert_eq!(hist.bin_index(0.3), Some(3));
+        assert_eq!(hist.bin_index(0.7), Some(7));
+        assert_eq!(hist.bin_index(0.29999999), Some(2));
+        assert_eq!(hist.bin_index(1.0), Some(9));
+
+        // Every edge maps to the bin it starts
+        for (i, &edge) in hist.edges()[..10].iter().enumerate() {
+            assert_eq!(hist.bin_index(edge), Some(i), "edge {}", edge);
+        }
+
+        hist.add_slice(&[0.0, 0.05, 0.1, 0.95, 1.0]);
+        assert_eq!(hist.counts(), &[2, 1, 0, 0, 0, 0, 0, 0, 0, 2]);
+        assert_eq!(hist.edges().len(), 11);
+        assert!((hist.centers()[0] - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_overflow_and_missing() {
+        let hist = Histogram::from_data(
+            &[
+                -5.0,
+                -0.001,
+                2.0,
+                10.0,
+                10.5,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NAN,
+            ],
+            0.0..10.0,
+            5,
+        );
+
+        assert_eq!(hist.underflow(), 3);
+        assert_eq!(hist.overflow(), 2);
+        assert_eq!(hist.missing(), 1);
+        assert_eq!(hist.counts(), &[0, 1, 0, 0, 1]);
+        assert_eq!(hist.in_range(), 2);
+        assert_eq!(hist.total(), 8);
+        assert_eq!(hist.bin_index(-5.0), None);
+    }
+
+    #[test]
+    fn test_merge_matches_single_pass() {
+        let data: Vec<f64> = (0..1000).map(|i| (i as f64 * 0.37).sin() * 3.0).collect();
+        let whole = Histogram::from_data(&data, -2.0..2.0, 16);
+
+        let mut merged = Histogram::new(-2.0..2.0, 16);
+        for chunk in data.chunks(128) {
+            let part = Histogram::from_data(chunk, -2.0..2.0, 16);
+            merged.merge(&part).unwrap();
+        }
+        assert_eq!(merged, whole);
+        assert_eq!(merged.total(), 1000);
+
+        let other = Histogram::new(-2.0..2.0, 8);
+        assert!(merged.merge(&other).is_err());
+        assert_eq!(merged, whole);
+    }
+
+    #[test]
+    fn test_density_integrates_to_one() {
+        let mut hist = Histogram::new(0.0..4.0, 8);
+        hist.add_slice(&[0.1, 0.2, 1.3, 3.9, 3.95, 7.0]);
+
+        let density = hist.density();
+        let integral: f64 = density.iter().map(|d| d * hist.bin_width()).sum();
+        assert!((integral - 1.0).abs() < 1e-12);
+        assert!((density[0] - 2.0 / (5.0 * 0.5)).abs() < 1e-12);
+
+        assert!(Histogram::new(0.0..1.0, 4)
+            .density()
+            .iter()
+            .all(|&d| d == 0.0));
+    }
+}
@@ -5,6 +5,10 @@
 //! - **ECDF**: Empirical Cumulative Distribution Function
 //! - **PCDF**: Probability-integral-transform CDF (uniform [0,1] output)
 //! - **FastCDF**: O(log² n) 2D joint CDF queries using range trees
+//! - **Comparison**: Q-Q points and the two-sample Kolmogorov-Smirnov test
+//! - **Normalization**: invertible z-score, min-max, and robust scaling
+//! - **Online statistics**: single-pass, mergeable mean and variance
+//! - **Histogram**: mergeable binned counts, for exports that need them
 //!
 //! # Design Philosophy
 //!
@@ -16,11 +20,13 @@
 
 pub mod ecdf;
 pub mod fast_cdf;
+pub mod histogram;
 pub mod pcdf;
 pub mod summary;
 
 pub use ecdf::*;
 pub use fast_cdf::*;
+pub use histogram::*;
 pub use pcdf::*;
 pub use summary::*;
 
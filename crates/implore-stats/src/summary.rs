@@ -4,7 +4,9 @@
 //! - Mean, variance, standard deviation
 //! - Min, max, range
 //! - Robust statistics (median, MAD)
+//! - Invertible normalization (z-score, min-max, median/IQR)
 
+use crate::ecdf::Ecdf;
 use serde::{Deserialize, Serialize};
 
 /// Summary statistics for a numeric dataset
@@ -149,6 +151,233 @@ pub fn robust_zscore_batch(data: &[f64]) -> Vec<f64> {
     data.iter().map(|&x| stats.robust_zscore(x)).collect()
 }
 
+/// Single-pass mean, variance, and range using Welford's algorithm
+///
+/// Accumulates statistics without holding the data, e.g. over chunked
+/// column reads. Accumulators filled separately (per chunk or thread) can be
+/// combined with [`merge`](Self::merge). Like [`SummaryStats`], non-finite
+/// values are counted as missing and otherwise ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OnlineStats {
+    count: usize,
+    missing: usize,
+    mean: f64,
+    /// Sum of squared deviations from the mean
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for OnlineStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OnlineStats {
+    /// Create an empty accumulator
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            missing: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Accumulate statistics over a slice
+    pub fn from_data(data: &[f64]) -> Self {
+        let mut stats = Self::new();
+        stats.extend(data);
+        stats
+    }
+
+    /// Add a value
+    pub fn push(&mut self, x: f64) {
+        if !x.is_finite() {
+            self.missing += 1;
+            return;
+        }
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+    }
+
+    /// Add every value in a slice
+    pub fn extend(&mut self, data: &[f64]) {
+        for &x in data {
+            self.push(x);
+        }
+    }
+
+    /// Combine with statistics accumulated over other data
+    ///
+    /// Uses the pairwise update of Chan et al., which stays accurate when the
+    /// two parts have very different sizes or means.
+    pub fn merge(&mut self, other: &OnlineStats) {
+        self.missing += other.missing;
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = Self {
+                missing: self.missing,
+                ..*other
+            };
+            return;
+        }
+        let n_a = self.count as f64;
+        let n_b = other.count as f64;
+        let n = n_a + n_b;
+        let delta = other.mean - self.mean;
+        self.mean += delta * n_b / n;
+        self.m2 += other.m2 + delta * delta * n_a * n_b / n;
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Number of finite values
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Number of missing/NaN values
+    pub fn missing(&self) -> usize {
+        self.missing
+    }
+
+    /// Mean, or NaN if empty
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            f64::NAN
+        } else {
+            self.mean
+        }
+    }
+
+    /// Population variance (divides by n, as [`SummaryStats`]), or NaN if empty
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            f64::NAN
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+
+    /// Sample variance (divides by n - 1), or NaN with fewer than two values
+    pub fn sample_variance(&self) -> f64 {
+        if self.count < 2 {
+            f64::NAN
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+
+    /// Population standard deviation
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Minimum value, or NaN if empty
+    pub fn min(&self) -> f64 {
+        if self.count == 0 {
+            f64::NAN
+        } else {
+            self.min
+        }
+    }
+
+    /// Maximum value, or NaN if empty
+    pub fn max(&self) -> f64 {
+        if self.count == 0 {
+            f64::NAN
+        } else {
+            self.max
+        }
+    }
+}
+
+/// Parameters of an affine normalization: `y = (x - offset) / scale`
+///
+/// Returned by [`zscore`], [`minmax_scale`], and [`robust_scale`] so that
+/// scaled values can be mapped back to the original units.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Scaling {
+    /// Value subtracted before scaling (mean, min, or median)
+    pub offset: f64,
+    /// Divisor applied after the offset (std dev, range, or IQR)
+    pub scale: f64,
+}
+
+impl Scaling {
+    /// Create a scaling, falling back to a unit scale when `scale` is zero
+    /// or not finite (e.g. a constant column) so nothing divides by zero
+    fn new(offset: f64, scale: f64) -> Self {
+        let offset = if offset.is_finite() { offset } else { 0.0 };
+        let scale = if scale.is_finite() && scale != 0.0 {
+            scale
+        } else {
+            1.0
+        };
+        Self { offset, scale }
+    }
+
+    /// Scale a single value
+    pub fn apply(&self, x: f64) -> f64 {
+        (x - self.offset) / self.scale
+    }
+
+    /// Map a scaled value back to the original units
+    pub fn invert(&self, y: f64) -> f64 {
+        y * self.scale + self.offset
+    }
+
+    /// Map a slice of scaled values back to the original units
+    pub fn invert_all(&self, values: &[f64]) -> Vec<f64> {
+        values.iter().map(|&y| self.invert(y)).collect()
+    }
+
+    fn apply_all(&self, data: &[f64]) -> Vec<f64> {
+        data.iter().map(|&x| self.apply(x)).collect()
+    }
+}
+
+/// Standardize data to zero mean and unit standard deviation
+///
+/// A constant column maps to all zeros. Non-finite values pass through.
+pub fn zscore(data: &[f64]) -> (Vec<f64>, Scaling) {
+    let stats = SummaryStats::from_data(data);
+    let scaling = Scaling::new(stats.mean, stats.std_dev);
+    (scaling.apply_all(data), scaling)
+}
+
+/// Rescale data linearly onto [0, 1]
+///
+/// A constant column maps to all zeros. Non-finite values pass through.
+pub fn minmax_scale(data: &[f64]) -> (Vec<f64>, Scaling) {
+    let stats = SummaryStats::from_data(data);
+    let scaling = Scaling::new(stats.min, stats.range());
+    (scaling.apply_all(data), scaling)
+}
+
+/// Center on the median and divide by the interquartile range
+///
+/// Less sensitive to outliers than [`zscore`]. A column with zero IQR is
+/// only centered. Non-finite values pass through.
+pub fn robust_scale(data: &[f64]) -> (Vec<f64>, Scaling) {
+    let scaling = match Ecdf::from_data(data).five_number_summary() {
+        Some(summary) => Scaling::new(summary.median, summary.q3 - summary.q1),
+        None => Scaling::new(0.0, 1.0),
+    };
+    (scaling.apply_all(data), scaling)
+}
+
 /// Winsorize data at a given percentile
 ///
 /// Replaces values below the lower percentile with that percentile's value,
@@ -240,6 +469,107 @@ mod tests {
         assert!(winsorized.iter().all(|&x| x <= 5.0 || x == 100.0)); // Simple check
     }
 
+    #[test]
+    fn test_zscore_transform() {
+        let data = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let (scaled, scaling) = zscore(&data);
+
+        let stats = SummaryStats::from_data(&scaled);
+        assert!(stats.mean.abs() < 1e-10);
+        assert!((stats.std_dev - 1.0).abs() < 1e-10);
+
+        let restored = scaling.invert_all(&scaled);
+        for (a, b) in data.iter().zip(&restored) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_minmax_scale() {
+        let data = vec![-3.0, 0.0, 1.5, 7.0];
+        let (scaled, scaling) = minmax_scale(&data);
+
+        assert!(scaled.iter().all(|&y| (0.0..=1.0).contains(&y)));
+        assert_eq!(scaled[0], 0.0);
+        assert_eq!(scaled[3], 1.0);
+        assert!((scaling.invert(0.3) - 0.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_robust_scale() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 100.0];
+        let (scaled, scaling) = robust_scale(&data);
+
+        assert_eq!(scaling.offset, 3.0);
+        assert_eq!(scaling.scale, 3.0);
+        assert!(scaled[5] > 30.0);
+    }
+
+    #[test]
+    fn test_constant_column_scaling() {
+        let data = vec![4.0; 5];
+        for (scaled, scaling) in [zscore(&data), minmax_scale(&data), robust_scale(&data)] {
+            assert!(scaled.iter().all(|&y| y == 0.0));
+            assert_eq!(scaling.invert(0.0), 4.0);
+        }
+
+        let (scaled, _) = zscore(&[1.0, f64::NAN, 3.0]);
+        assert!(scaled[1].is_nan());
+    }
+
+    #[test]
+    fn test_online_stats_over_chunks() {
+        // Large offset makes a naive sum-of-squares variance lose precision
+        let data: Vec<f64> = (0..10_000)
+            .map(|i| 1e9 + (i as f64 * 0.61).sin() * 5.0 + (i % 7) as f64)
+            .collect();
+        let batch = SummaryStats::from_data(&data);
+
+        let mut online = OnlineStats::new();
+        for chunk in data.chunks(1024) {
+            online.merge(&OnlineStats::from_data(chunk));
+        }
+
+        assert_eq!(online.count(), batch.count);
+        assert_eq!(online.min(), batch.min);
+        assert_eq!(online.max(), batch.max);
+        assert!((online.mean() - batch.mean).abs() < 1e-6);
+        assert!((online.variance() - batch.variance).abs() / batch.variance < 1e-9);
+
+        let single_pass = OnlineStats::from_data(&data);
+        assert!((single_pass.variance() - batch.variance).abs() / batch.variance < 1e-9);
+    }
+
+    #[test]
+    fn test_online_stats_merge_associative() {
+        let a = OnlineStats::from_data(&[1.0, 2.0, 3.0]);
+        let b = OnlineStats::from_data(&[100.0, f64::NAN]);
+        let c = OnlineStats::from_data(&[-4.0, 0.5, 7.25, 9.0]);
+
+        let mut left = a;
+        left.merge(&b);
+        left.merge(&c);
+
+        let mut bc = b;
+        bc.merge(&c);
+        let mut right = a;
+        right.merge(&bc);
+
+        assert_eq!(left.count(), 8);
+        assert_eq!(left.missing(), 1);
+        assert_eq!(left.min(), right.min());
+        assert_eq!(left.max(), right.max());
+        assert!((left.mean() - right.mean()).abs() < 1e-12);
+        assert!((left.variance() - right.variance()).abs() < 1e-9);
+
+        // Merging into or from an empty accumulator changes nothing
+        let mut empty = OnlineStats::new();
+        empty.merge(&left);
+        empty.merge(&OnlineStats::new());
+        assert_eq!(empty, left);
+        assert!(OnlineStats::new().mean().is_nan());
+    }
+
     #[test]
     fn test_zscore_batch() {
         let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
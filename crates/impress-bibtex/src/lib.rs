@@ -54,7 +54,7 @@ pub fn format_entries(entries: Vec<BibTeXEntry>) -> String {
     im_bibtex::format_entries(entries.into_iter().map(Into::into).collect())
 }
 
-// ── LaTeX decoding ───────────────────────────────────────────────────────────
+// ── LaTeX decoding/encoding ──────────────────────────────────────────────────
 
 /// Decode LaTeX special characters to Unicode
 pub fn decode_latex(input: String) -> String {
@@ -67,6 +67,17 @@ pub fn decode_latex_ffi(input: String) -> String {
     decode_latex(input)
 }
 
+/// Encode Unicode characters as LaTeX (inverse of `decode_latex`)
+pub fn encode_latex(input: String) -> String {
+    im_bibtex::encode_latex(input)
+}
+
+#[cfg(feature = "uniffi")]
+#[uniffi::export]
+pub fn encode_latex_ffi(input: String) -> String {
+    encode_latex(input)
+}
+
 // ── Journal macros ───────────────────────────────────────────────────────────
 
 /// Expand a journal macro to its full name
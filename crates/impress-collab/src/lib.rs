@@ -12,6 +12,11 @@ pub mod presence;
 pub mod viewport;
 
 pub use invitation::{Invitation, InvitationStatus, SecureLink};
-pub use permissions::Permissions;
-pub use presence::{CursorPosition, PresenceInfo, PresenceStatus};
-pub use viewport::{BoundingBox3D, Camera3DState, Point2D, ViewportMode, ViewportState};
+pub use permissions::{Capability, Permissions};
+pub use presence::{
+    resolve_follow_target, start_following, CursorPosition, FollowError, PresenceInfo,
+    PresenceStatus, UserId,
+};
+pub use viewport::{
+    BoundingBox3D, Camera3DState, Point2D, PresenceThrottle, ViewportMode, ViewportState,
+};
@@ -23,6 +23,64 @@ bitflags! {
         const SHARE = 0b00001000;
         /// Full administrative access to the resource
         const ADMIN = 0b00010000;
+        /// Permission to read resource metadata (title, authors, fields)
+        const READ_METADATA = 0b00100000;
+        /// Permission to add or remove tags
+        const EDIT_TAGS = 0b01000000;
+        /// Permission to create and edit annotations
+        const EDIT_ANNOTATIONS = 0b10000000;
+        /// Permission to delete the resource
+        const DELETE = 0b1_00000000;
+        /// Permission to invite new collaborators
+        const INVITE = 0b10_00000000;
+        /// Permission to change other collaborators' permissions
+        const MANAGE_PERMISSIONS = 0b100_00000000;
+    }
+}
+
+/// A single fine-grained capability that can be checked against [`Permissions`].
+///
+/// Capabilities describe concrete actions ("edit tags", "delete") rather than
+/// broad roles, so a collaboration can grant e.g. tag editing without granting
+/// deletion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Capability {
+    /// Read the resource's metadata
+    ReadMetadata,
+    /// Add or remove tags
+    EditTags,
+    /// Create and edit annotations
+    EditAnnotations,
+    /// Delete the resource
+    Delete,
+    /// Invite new collaborators
+    Invite,
+    /// Change other collaborators' permissions
+    ManagePermissions,
+}
+
+impl Capability {
+    /// All capabilities, in declaration order.
+    pub const ALL: [Capability; 6] = [
+        Capability::ReadMetadata,
+        Capability::EditTags,
+        Capability::EditAnnotations,
+        Capability::Delete,
+        Capability::Invite,
+        Capability::ManagePermissions,
+    ];
+
+    /// The permission flag backing this capability.
+    pub const fn flag(self) -> Permissions {
+        match self {
+            Capability::ReadMetadata => Permissions::READ_METADATA,
+            Capability::EditTags => Permissions::EDIT_TAGS,
+            Capability::EditAnnotations => Permissions::EDIT_ANNOTATIONS,
+            Capability::Delete => Permissions::DELETE,
+            Capability::Invite => Permissions::INVITE,
+            Capability::ManagePermissions => Permissions::MANAGE_PERMISSIONS,
+        }
     }
 }
 
@@ -47,19 +105,49 @@ impl<'de> Deserialize<'de> for Permissions {
 }
 
 impl Permissions {
-    /// Viewer role: can only view the resource
-    pub const VIEWER: Permissions = Permissions::VIEW;
+    /// Viewer role: can view the resource and read its metadata
+    pub const VIEWER: Permissions = Permissions::VIEW.union(Permissions::READ_METADATA);
+
+    /// Reviewer role: can view, comment on, and annotate the resource
+    pub const REVIEWER: Permissions = Permissions::VIEWER
+        .union(Permissions::COMMENT)
+        .union(Permissions::EDIT_ANNOTATIONS);
+
+    /// Commenter role: alias for [`Permissions::REVIEWER`]
+    pub const COMMENTER: Permissions = Permissions::REVIEWER;
 
-    /// Reviewer role: can view and comment on the resource
-    pub const REVIEWER: Permissions = Permissions::VIEW.union(Permissions::COMMENT);
+    /// Editor role: can view, comment, annotate, edit content, and edit tags
+    pub const EDITOR: Permissions = Permissions::REVIEWER
+        .union(Permissions::EDIT)
+        .union(Permissions::EDIT_TAGS);
 
-    /// Editor role: can view, comment, and edit the resource
-    pub const EDITOR: Permissions = Permissions::REVIEWER.union(Permissions::EDIT);
+    /// Owner role: every permission, including deletion and administration
+    pub const OWNER: Permissions = Permissions::all();
 
-    /// Owner role: full access including sharing and administration
-    pub const OWNER: Permissions = Permissions::EDITOR
-        .union(Permissions::SHARE)
-        .union(Permissions::ADMIN);
+    /// Build a permission set from a list of capabilities.
+    pub fn from_capabilities(capabilities: &[Capability]) -> Self {
+        capabilities
+            .iter()
+            .fold(Permissions::empty(), |acc, cap| acc.union(cap.flag()))
+    }
+
+    /// Return a copy of this permission set with `capability` granted.
+    pub fn with_capability(self, capability: Capability) -> Self {
+        self.union(capability.flag())
+    }
+
+    /// Return a copy of this permission set with `capability` revoked.
+    pub fn without_capability(self, capability: Capability) -> Self {
+        self.difference(capability.flag())
+    }
+
+    /// Check if this permission set allows a specific capability.
+    ///
+    /// Admin access implies every capability.
+    #[inline]
+    pub fn allows(&self, capability: Capability) -> bool {
+        self.is_admin() || self.contains(capability.flag())
+    }
 
     /// Check if this permission set allows viewing
     #[inline]
@@ -150,6 +238,52 @@ mod tests {
         assert!(perms.can_edit());
     }
 
+    #[test]
+    fn test_commenter_can_annotate_but_not_delete() {
+        let perms = Permissions::COMMENTER;
+        assert!(perms.allows(Capability::ReadMetadata));
+        assert!(perms.allows(Capability::EditAnnotations));
+        assert!(!perms.allows(Capability::EditTags));
+        assert!(!perms.allows(Capability::Delete));
+        assert!(!perms.allows(Capability::ManagePermissions));
+    }
+
+    #[test]
+    fn test_owner_allows_every_capability() {
+        for cap in Capability::ALL {
+            assert!(
+                Permissions::OWNER.allows(cap),
+                "owner should allow {:?}",
+                cap
+            );
+        }
+    }
+
+    #[test]
+    fn test_admin_implies_all_capabilities() {
+        let perms = Permissions::ADMIN;
+        for cap in Capability::ALL {
+            assert!(perms.allows(cap));
+        }
+    }
+
+    #[test]
+    fn test_custom_capability_composition() {
+        // "Can edit tags but not delete the publication"
+        let perms =
+            Permissions::from_capabilities(&[Capability::ReadMetadata, Capability::EditTags]);
+        assert!(perms.allows(Capability::EditTags));
+        assert!(!perms.allows(Capability::Delete));
+
+        let editor_no_tags = Permissions::EDITOR.without_capability(Capability::EditTags);
+        assert!(!editor_no_tags.allows(Capability::EditTags));
+        assert!(editor_no_tags.can_edit());
+
+        let editor_with_delete = Permissions::EDITOR.with_capability(Capability::Delete);
+        assert!(editor_with_delete.allows(Capability::Delete));
+        assert!(!editor_with_delete.is_admin());
+    }
+
     #[test]
     fn test_permission_serialization() {
         let perms = Permissions::EDITOR;
@@ -1,10 +1,36 @@
 //! Real-time presence awareness for collaborative editing.
 //!
 //! Provides structures for tracking user presence in shared resources,
-//! including cursor positions and activity status.
+//! including cursor positions, activity status, and "follow" relationships
+//! where one participant's viewport mirrors another's.
 
+use crate::viewport::ViewportState;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Identifier of a user participating in a shared resource.
+pub type UserId = String;
+
+/// Errors that can occur when resolving follow relationships.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FollowError {
+    /// The follower is not present in the resource
+    #[error("user {0} is not present")]
+    UnknownUser(UserId),
+
+    /// The user being followed is not present or has gone offline
+    #[error("leader {0} is not present")]
+    LeaderUnavailable(UserId),
+
+    /// A user attempted to follow themselves
+    #[error("user {0} cannot follow themselves")]
+    SelfFollow(UserId),
+
+    /// The follow relationship would form a cycle
+    #[error("follow cycle detected: {}", .0.join(" -> "))]
+    Cycle(Vec<UserId>),
+}
 
 /// Status indicating a user's current presence state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -145,6 +171,14 @@ pub struct PresenceInfo {
 
     /// Client/device information
     pub client_info: Option<String>,
+
+    /// Current viewport (if the client shares one)
+    #[serde(default)]
+    pub viewport: Option<ViewportState>,
+
+    /// User whose viewport this user is following
+    #[serde(default)]
+    pub following: Option<UserId>,
 }
 
 impl PresenceInfo {
@@ -167,6 +201,8 @@ impl PresenceInfo {
             joined_at: now,
             last_active_at: now,
             client_info: None,
+            viewport: None,
+            following: None,
         }
     }
 
@@ -193,6 +229,30 @@ impl PresenceInfo {
         self.cursor = None;
     }
 
+    /// Update the shared viewport.
+    pub fn update_viewport(&mut self, viewport: ViewportState) {
+        self.viewport = Some(viewport);
+        self.touch();
+    }
+
+    /// Start following another user's viewport.
+    ///
+    /// This does not check for cycles; use [`start_following`] when the other
+    /// participants are known.
+    pub fn follow(&mut self, leader: impl Into<UserId>) {
+        self.following = Some(leader.into());
+    }
+
+    /// Stop following another user.
+    pub fn unfollow(&mut self) {
+        self.following = None;
+    }
+
+    /// Check if this user is following someone.
+    pub fn is_following(&self) -> bool {
+        self.following.is_some()
+    }
+
     /// Update the presence status.
     pub fn update_status(&mut self, status: PresenceStatus) {
         self.status = status;
@@ -223,6 +283,7 @@ impl PresenceInfo {
     pub fn mark_offline(&mut self) {
         self.status = PresenceStatus::Offline;
         self.cursor = None;
+        self.following = None;
     }
 
     /// Get the duration since the user joined.
@@ -241,6 +302,84 @@ impl PresenceInfo {
     }
 }
 
+fn find_presence<'a>(presences: &'a [PresenceInfo], user_id: &str) -> Option<&'a PresenceInfo> {
+    presences.iter().find(|p| p.user_id == user_id)
+}
+
+/// Walk the follow chain starting at `follower`, returning the ultimate leader.
+///
+/// Returns `Ok(None)` if `follower` is not following anyone. A chain such as
+/// A follows B follows C resolves to C, so every follower mirrors the same
+/// viewport.
+fn resolve_leader<'a>(
+    presences: &'a [PresenceInfo],
+    follower: &str,
+) -> Result<Option<&'a PresenceInfo>, FollowError> {
+    let start = find_presence(presences, follower)
+        .ok_or_else(|| FollowError::UnknownUser(follower.to_string()))?;
+
+    let mut chain = vec![start.user_id.clone()];
+    let mut current = start;
+    while let Some(next_id) = &current.following {
+        if let Some(pos) = chain.iter().position(|id| id == next_id) {
+            let mut cycle = chain.split_off(pos);
+            cycle.push(next_id.clone());
+            return Err(FollowError::Cycle(cycle));
+        }
+        current = find_presence(presences, next_id)
+            .filter(|p| p.status.is_online())
+            .ok_or_else(|| FollowError::LeaderUnavailable(next_id.clone()))?;
+        chain.push(current.user_id.clone());
+    }
+
+    if current.user_id == start.user_id {
+        Ok(None)
+    } else {
+        Ok(Some(current))
+    }
+}
+
+/// Resolve the viewport that `follower` should apply.
+///
+/// Follows the chain of `following` relationships to the ultimate leader and
+/// returns that leader's current viewport. Returns `Ok(None)` if the follower
+/// is not following anyone or the leader has not shared a viewport yet.
+pub fn resolve_follow_target<'a>(
+    presences: &'a [PresenceInfo],
+    follower: &str,
+) -> Result<Option<&'a ViewportState>, FollowError> {
+    Ok(resolve_leader(presences, follower)?.and_then(|leader| leader.viewport.as_ref()))
+}
+
+/// Make `follower` follow `leader`, rejecting self-follows and cycles.
+///
+/// On error the existing follow relationships are left unchanged.
+pub fn start_following(
+    presences: &mut [PresenceInfo],
+    follower: &str,
+    leader: &str,
+) -> Result<(), FollowError> {
+    if follower == leader {
+        return Err(FollowError::SelfFollow(follower.to_string()));
+    }
+    let follower_idx = presences
+        .iter()
+        .position(|p| p.user_id == follower)
+        .ok_or_else(|| FollowError::UnknownUser(follower.to_string()))?;
+    if !find_presence(presences, leader).is_some_and(|p| p.status.is_online()) {
+        return Err(FollowError::LeaderUnavailable(leader.to_string()));
+    }
+
+    let previous = presences[follower_idx]
+        .following
+        .replace(leader.to_string());
+    if let Err(err) = resolve_leader(presences, follower) {
+        presences[follower_idx].following = previous;
+        return Err(err);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -328,4 +467,102 @@ mod tests {
         assert_eq!(cursor.line, Some(5));
         assert_eq!(cursor.column, Some(10));
     }
+
+    fn participant(user_id: &str) -> PresenceInfo {
+        PresenceInfo::new(
+            format!("session-{}", user_id),
+            user_id.to_string(),
+            user_id.to_string(),
+            "dataset-1".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_follower_receives_leader_viewport() {
+        use crate::viewport::Camera3DState;
+
+        let mut leader = participant("alice");
+        let camera = Camera3DState::new([1.0, 2.0, 3.0], [0.0, 0.0, 0.0]);
+        leader.update_viewport(ViewportState::box_3d(camera));
+        let mut presences = vec![leader, participant("bob")];
+
+        assert_eq!(resolve_follow_target(&presences, "bob"), Ok(None));
+
+        start_following(&mut presences, "bob", "alice").unwrap();
+        let target = resolve_follow_target(&presences, "bob").unwrap().unwrap();
+        assert_eq!(target.camera_3d, Some(camera));
+
+        // Applying the leader's viewport mirrors it on the follower
+        let applied = target.clone();
+        presences[1].update_viewport(applied);
+        assert_eq!(
+            presences[1].viewport.as_ref().unwrap().camera_3d,
+            Some(camera)
+        );
+    }
+
+    #[test]
+    fn test_follow_chain_resolves_to_ultimate_leader() {
+        let mut presences = vec![
+            participant("alice"),
+            participant("bob"),
+            participant("carol"),
+        ];
+        presences[0].update_viewport(ViewportState::reference(7, None));
+
+        start_following(&mut presences, "bob", "alice").unwrap();
+        start_following(&mut presences, "carol", "bob").unwrap();
+
+        let target = resolve_follow_target(&presences, "carol").unwrap().unwrap();
+        assert_eq!(target.visible_page, Some(7));
+    }
+
+    #[test]
+    fn test_follow_cycle_rejected() {
+        let mut presences = vec![
+            participant("alice"),
+            participant("bob"),
+            participant("carol"),
+        ];
+
+        start_following(&mut presences, "alice", "bob").unwrap();
+        start_following(&mut presences, "bob", "carol").unwrap();
+
+        let err = start_following(&mut presences, "carol", "alice").unwrap_err();
+        assert!(matches!(err, FollowError::Cycle(_)));
+        assert!(presences[2].following.is_none());
+
+        assert_eq!(
+            start_following(&mut presences, "alice", "alice"),
+            Err(FollowError::SelfFollow("alice".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_follow_cycle_detected_on_resolve() {
+        let mut presences = vec![participant("alice"), participant("bob")];
+        presences[0].follow("bob");
+        presences[1].follow("alice");
+
+        assert_eq!(
+            resolve_follow_target(&presences, "alice"),
+            Err(FollowError::Cycle(vec![
+                "alice".to_string(),
+                "bob".to_string(),
+                "alice".to_string()
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_follow_offline_leader_unavailable() {
+        let mut presences = vec![participant("alice"), participant("bob")];
+        start_following(&mut presences, "bob", "alice").unwrap();
+        presences[0].mark_offline();
+
+        assert_eq!(
+            resolve_follow_target(&presences, "bob"),
+            Err(FollowError::LeaderUnavailable("alice".to_string()))
+        );
+    }
 }
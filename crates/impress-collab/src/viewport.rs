@@ -4,6 +4,8 @@
 //! (implore) and document views (imprint, imbib) for cross-app
 //! presence tracking.
 
+use std::time::{Duration, Instant};
+
 use serde::{Deserialize, Serialize};
 
 /// A user's viewport state within a collaborative resource.
@@ -12,7 +14,7 @@ use serde::{Deserialize, Serialize};
 /// - Document editing (imprint): text cursor position
 /// - PDF viewing (imbib): page and viewport position
 /// - 2D/3D visualization (implore): camera state
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ViewportState {
     /// The mode/context of this viewport
     pub mode: ViewportMode,
@@ -292,6 +294,38 @@ impl Camera3DState {
 
         pos_diff < tolerance && target_diff < tolerance
     }
+
+    /// Interpolate between two camera states.
+    ///
+    /// `t` is clamped to `[0, 1]`; `t = 0` yields `from` and `t = 1` yields `to`.
+    /// Position, target, field of view, and clipping planes are interpolated
+    /// linearly; the up vector is interpolated and re-normalized. Receivers use
+    /// this to tween a remote camera between sparse presence updates.
+    pub fn interpolate(from: &Camera3DState, to: &Camera3DState, t: f32) -> Camera3DState {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        let lerp3 =
+            |a: [f32; 3], b: [f32; 3]| [lerp(a[0], b[0]), lerp(a[1], b[1]), lerp(a[2], b[2])];
+
+        let up = lerp3(from.up, to.up);
+        let up_len = (up[0] * up[0] + up[1] * up[1] + up[2] * up[2]).sqrt();
+        let up = if up_len > f32::EPSILON {
+            [up[0] / up_len, up[1] / up_len, up[2] / up_len]
+        } else if t < 0.5 {
+            from.up
+        } else {
+            to.up
+        };
+
+        Camera3DState {
+            position: lerp3(from.position, to.position),
+            target: lerp3(from.target, to.target),
+            up,
+            fov: lerp(from.fov, to.fov),
+            near: lerp(from.near, to.near),
+            far: lerp(from.far, to.far),
+        }
+    }
 }
 
 impl Default for Camera3DState {
@@ -300,6 +334,93 @@ impl Default for Camera3DState {
     }
 }
 
+/// Rate limiter that coalesces rapid presence updates to a maximum frequency.
+///
+/// Senders [`push`](Self::push) every new state (e.g. a camera on every frame);
+/// only states spaced at least `1 / max_hz` apart are returned for broadcast,
+/// and intermediate states are coalesced into a single pending value. When
+/// motion stops, [`poll`](Self::poll) releases the pending state once the
+/// interval has elapsed, or [`flush`](Self::flush) releases it immediately,
+/// so the final resting state is always delivered.
+#[derive(Debug, Clone)]
+pub struct PresenceThrottle<T> {
+    min_interval: Duration,
+    last_sent_at: Option<Instant>,
+    pending: Option<T>,
+}
+
+impl<T: Clone> PresenceThrottle<T> {
+    /// Create a throttle limited to `max_hz` updates per second.
+    ///
+    /// A non-positive or non-finite rate disables throttling.
+    pub fn new(max_hz: f64) -> Self {
+        let min_interval = if max_hz.is_finite() && max_hz > 0.0 {
+            Duration::from_secs_f64(1.0 / max_hz)
+        } else {
+            Duration::ZERO
+        };
+        Self {
+            min_interval,
+            last_sent_at: None,
+            pending: None,
+        }
+    }
+
+    /// The minimum spacing between broadcast updates.
+    pub fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    /// Offer a new state observed at `now`.
+    ///
+    /// Returns the state to broadcast if the rate limit allows it; otherwise
+    /// the state replaces any previously pending value and `None` is returned.
+    pub fn push(&mut self, state: T, now: Instant) -> Option<T> {
+        if self.is_ready(now) {
+            self.pending = None;
+            self.last_sent_at = Some(now);
+            Some(state)
+        } else {
+            self.pending = Some(state);
+            None
+        }
+    }
+
+    /// Release the pending state if the rate limit interval has elapsed.
+    ///
+    /// Call this periodically (e.g. from a timer) so the last state is sent
+    /// even when no further updates arrive.
+    pub fn poll(&mut self, now: Instant) -> Option<T> {
+        if self.pending.is_some() && self.is_ready(now) {
+            self.last_sent_at = Some(now);
+            self.pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// Release the pending state immediately, ignoring the rate limit.
+    ///
+    /// Use this when motion has definitively stopped (e.g. mouse up).
+    pub fn flush(&mut self, now: Instant) -> Option<T> {
+        let state = self.pending.take()?;
+        self.last_sent_at = Some(now);
+        Some(state)
+    }
+
+    /// Whether a coalesced state is waiting to be sent.
+    pub fn has_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    fn is_ready(&self, now: Instant) -> bool {
+        match self.last_sent_at {
+            Some(last) => now.saturating_duration_since(last) >= self.min_interval,
+            None => true,
+        }
+    }
+}
+
 /// A 2D point for cursor positions and viewport tracking
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Point2D {
@@ -456,6 +577,78 @@ mod tests {
         assert!(!cam1.is_similar(&cam3, 0.5));
     }
 
+    #[test]
+    fn test_camera_3d_interpolate_midpoint() {
+        let from = Camera3DState::new([0.0, 0.0, 10.0], [0.0, 0.0, 0.0]).with_fov(40.0);
+        let to = Camera3DState::new([10.0, 4.0, 10.0], [2.0, 0.0, 0.0]).with_fov(60.0);
+        let mid = Camera3DState::interpolate(&from, &to, 0.5);
+
+        assert_eq!(mid.position, [5.0, 2.0, 10.0]);
+        assert_eq!(mid.target, [1.0, 0.0, 0.0]);
+        assert!((mid.fov - 50.0).abs() < 1e-5);
+        assert_eq!(mid.up, [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_camera_3d_interpolate_endpoints_and_clamping() {
+        let from = Camera3DState::new([0.0, 0.0, 10.0], [0.0, 0.0, 0.0]);
+        let to = Camera3DState::with_z_up([10.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+
+        assert_eq!(Camera3DState::interpolate(&from, &to, 0.0), from);
+        assert_eq!(Camera3DState::interpolate(&from, &to, 1.0), to);
+        assert_eq!(Camera3DState::interpolate(&from, &to, -1.0), from);
+        assert_eq!(Camera3DState::interpolate(&from, &to, 2.0), to);
+
+        let mid = Camera3DState::interpolate(&from, &to, 0.5);
+        let up_len = (mid.up[0].powi(2) + mid.up[1].powi(2) + mid.up[2].powi(2)).sqrt();
+        assert!((up_len - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_throttle_collapses_rapid_updates() {
+        let mut throttle = PresenceThrottle::new(10.0);
+        let start = Instant::now();
+
+        // 60 frames over one second at ~60 Hz
+        let sent = (0..60)
+            .filter_map(|i| {
+                let now = start + Duration::from_millis(i * 1000 / 60);
+                throttle.push(Camera3DState::new([i as f32, 0.0, 10.0], [0.0; 3]), now)
+            })
+            .count();
+
+        assert!(sent <= 10, "sent {} updates, expected at most 10", sent);
+        assert!(sent >= 9);
+        assert!(throttle.has_pending());
+    }
+
+    #[test]
+    fn test_throttle_flushes_final_state() {
+        let mut throttle = PresenceThrottle::new(10.0);
+        let start = Instant::now();
+        let first = Camera3DState::new([0.0, 0.0, 10.0], [0.0; 3]);
+        let last = Camera3DState::new([3.0, 0.0, 10.0], [0.0; 3]);
+
+        assert_eq!(throttle.push(first, start), Some(first));
+        assert_eq!(throttle.push(last, start + Duration::from_millis(20)), None);
+
+        // Motion stopped: polling before the interval elapses holds the state...
+        assert_eq!(throttle.poll(start + Duration::from_millis(50)), None);
+        // ...and releases it once the interval has passed.
+        assert_eq!(
+            throttle.poll(start + Duration::from_millis(100)),
+            Some(last)
+        );
+        assert!(!throttle.has_pending());
+
+        throttle.push(first, start + Duration::from_millis(110));
+        assert_eq!(
+            throttle.flush(start + Duration::from_millis(111)),
+            Some(first)
+        );
+        assert_eq!(throttle.flush(start + Duration::from_millis(112)), None);
+    }
+
     #[test]
     fn test_point_2d() {
         let p1 = Point2D::new(0.0, 0.0);
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::item::{Item, ItemId};
@@ -16,6 +18,146 @@ pub enum ItemEvent {
         operation_id: ItemId,
         target_id: ItemId,
     },
+    /// App-defined domain event, projected by an [`EventRegistry`].
+    Custom(CustomEvent),
+}
+
+impl ItemEvent {
+    /// The change notification for this event, if it touched a stored item.
+    pub fn change(&self) -> Option<StoreChange> {
+        match self {
+            ItemEvent::Created(item) => Some(StoreChange::Created(item.id)),
+            ItemEvent::Updated { id, .. } => Some(StoreChange::Updated(*id)),
+            ItemEvent::Deleted(id) => Some(StoreChange::Deleted(*id)),
+            ItemEvent::OperationApplied { target_id, .. } => Some(StoreChange::Updated(*target_id)),
+            ItemEvent::Custom(_) => None,
+        }
+    }
+}
+
+/// Lightweight change notification for reactive UIs: which item changed and how.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StoreChange {
+    Created(ItemId),
+    Updated(ItemId),
+    Deleted(ItemId),
+}
+
+/// A change read from the store's event log, with its position in the log.
+///
+/// Consumers acknowledge `seq` once they have handled the change, so they
+/// can resume after it on the next start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoggedChange {
+    pub seq: u64,
+    pub change: StoreChange,
+}
+
+/// A domain event defined outside impress-core.
+///
+/// `kind` is namespaced by the emitting app (e.g. `imbib/library-synced`);
+/// `payload` is opaque to the store.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomEvent {
+    pub kind: String,
+    pub payload: serde_json::Value,
+}
+
+impl CustomEvent {
+    pub fn new(kind: impl Into<String>, payload: serde_json::Value) -> Self {
+        Self {
+            kind: kind.into(),
+            payload,
+        }
+    }
+}
+
+/// Error from the event registry.
+#[derive(Debug, thiserror::Error)]
+pub enum EventError {
+    #[error("Event kind already registered: {0}")]
+    AlreadyRegistered(String),
+
+    #[error("Projector for '{kind}' failed: {message}")]
+    Projection { kind: String, message: String },
+}
+
+type Projector<S> = Box<dyn Fn(&mut S, &serde_json::Value) -> Result<(), String> + Send + Sync>;
+
+/// Registry of projectors for custom event kinds.
+///
+/// Each app registers a projector per kind it owns; replaying an event log
+/// folds those events into the app's state `S`. Built-in item events are
+/// skipped, since the store has already applied them. The SQLite store logs
+/// custom events and replays them through a registry on load
+/// (`SqliteItemStore::replay_custom_events`).
+pub struct EventRegistry<S> {
+    projectors: HashMap<String, Projector<S>>,
+}
+
+impl<S> EventRegistry<S> {
+    pub fn new() -> Self {
+        Self {
+            projectors: HashMap::new(),
+        }
+    }
+
+    /// Register a projector for a kind. Returns error if the kind already has one.
+    pub fn register<F>(&mut self, kind: impl Into<String>, projector: F) -> Result<(), EventError>
+    where
+        F: Fn(&mut S, &serde_json::Value) -> Result<(), String> + Send + Sync + 'static,
+    {
+        let kind = kind.into();
+        if self.projectors.contains_key(&kind) {
+            return Err(EventError::AlreadyRegistered(kind));
+        }
+        self.projectors.insert(kind, Box::new(projector));
+        Ok(())
+    }
+
+    /// Whether a projector is registered for this kind.
+    pub fn is_registered(&self, kind: &str) -> bool {
+        self.projectors.contains_key(kind)
+    }
+
+    /// Project a single custom event. Returns `Ok(false)` if its kind is unknown.
+    pub fn project(&self, state: &mut S, event: &CustomEvent) -> Result<bool, EventError> {
+        let Some(projector) = self.projectors.get(&event.kind) else {
+            return Ok(false);
+        };
+        projector(state, &event.payload).map_err(|message| EventError::Projection {
+            kind: event.kind.clone(),
+            message,
+        })?;
+        Ok(true)
+    }
+
+    /// Replay an event log into `state`, in order.
+    ///
+    /// Custom events with no registered projector are returned unchanged so
+    /// the caller can keep them (e.g. written by a newer app version) rather
+    /// than losing them on the next save.
+    pub fn replay<'a>(
+        &self,
+        state: &mut S,
+        events: impl IntoIterator<Item = &'a ItemEvent>,
+    ) -> Result<Vec<CustomEvent>, EventError> {
+        let mut unknown = Vec::new();
+        for event in events {
+            if let ItemEvent::Custom(custom) = event {
+                if !self.project(state, custom)? {
+                    unknown.push(custom.clone());
+                }
+            }
+        }
+        Ok(unknown)
+    }
+}
+
+impl<S> Default for EventRegistry<S> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
@@ -35,6 +177,10 @@ mod tests {
                 operation_id: Uuid::new_v4(),
                 target_id: Uuid::new_v4(),
             },
+            ItemEvent::Custom(CustomEvent::new(
+                "imbib/library-synced",
+                serde_json::json!({ "count": 3 }),
+            )),
         ];
         for e in &events {
             let json = serde_json::to_string(e).unwrap();
@@ -42,4 +188,56 @@ mod tests {
             assert_eq!(*e, back);
         }
     }
+
+    #[derive(Default)]
+    struct ReadingStats {
+        pages: u64,
+        sessions: u32,
+    }
+
+    #[test]
+    fn registry_projects_custom_kinds_and_keeps_unknown() {
+        let mut registry = EventRegistry::<ReadingStats>::new();
+        registry
+            .register("imbib/reading-session", |stats, payload| {
+                let pages = payload["pages"].as_u64().ok_or("missing pages")?;
+                stats.pages += pages;
+                stats.sessions += 1;
+                Ok(())
+            })
+            .unwrap();
+        assert!(matches!(
+            registry.register("imbib/reading-session", |_, _| Ok(())),
+            Err(EventError::AlreadyRegistered(_))
+        ));
+
+        let unknown = CustomEvent::new("imprint/compiled", serde_json::json!({ "ok": true }));
+        let log = vec![
+            ItemEvent::Custom(CustomEvent::new(
+                "imbib/reading-session",
+                serde_json::json!({ "pages": 12 }),
+            )),
+            ItemEvent::Deleted(Uuid::new_v4()),
+            ItemEvent::Custom(unknown.clone()),
+            ItemEvent::Custom(CustomEvent::new(
+                "imbib/reading-session",
+                serde_json::json!({ "pages": 5 }),
+            )),
+        ];
+
+        let mut stats = ReadingStats::default();
+        let preserved = registry.replay(&mut stats, &log).unwrap();
+        assert_eq!(stats.pages, 17);
+        assert_eq!(stats.sessions, 2);
+        assert_eq!(preserved, vec![unknown]);
+
+        let bad = ItemEvent::Custom(CustomEvent::new(
+            "imbib/reading-session",
+            serde_json::json!({}),
+        ));
+        assert!(matches!(
+            registry.replay(&mut stats, [&bad]),
+            Err(EventError::Projection { .. })
+        ));
+    }
 }
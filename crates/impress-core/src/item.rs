@@ -212,6 +212,10 @@ pub struct Item {
 
     // Causal ordering & collaboration
     pub logical_clock: u64,
+    /// Optimistic-concurrency revision, bumped by the store on every change.
+    /// Unrelated to `version`, which is a free-form content version label.
+    #[serde(default)]
+    pub revision: u64,
     pub origin: Option<String>,
     pub canonical_id: Option<String>,
 
@@ -276,6 +280,7 @@ mod tests {
             author: "user@example.com".into(),
             author_kind: ActorKind::Human,
             logical_clock: 42,
+            revision: 7,
             origin: Some("store-abc".into()),
             canonical_id: None,
             tags: vec!["methods/sims/hydro".into()],
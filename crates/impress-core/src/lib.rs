@@ -6,6 +6,7 @@ pub mod reference;
 pub mod registry;
 pub mod schema;
 pub mod schemas;
+pub mod search;
 pub mod store;
 
 #[cfg(feature = "sqlite")]
@@ -20,6 +21,7 @@ pub use query::*;
 pub use reference::*;
 pub use registry::*;
 pub use schema::*;
+pub use search::*;
 pub use store::*;
 
 #[cfg(feature = "sqlite")]
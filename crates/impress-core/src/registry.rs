@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
-use crate::item::{Item, Value};
-use crate::schema::{FieldType, Schema, SchemaRef};
+use crate::item::Item;
+use crate::schema::{validate_fields, Schema, SchemaRef};
 
 /// Error from the schema registry.
 #[derive(Debug, thiserror::Error)]
@@ -80,32 +80,14 @@ impl SchemaRegistry {
 
         // Collect all fields including inherited ones
         let all_fields = self.collect_fields(schema);
-        let mut errors = Vec::new();
-
-        for field_def in &all_fields {
-            match item.payload.get(&field_def.name) {
-                None => {
-                    if field_def.required {
-                        errors.push(ValidationError {
-                            field: field_def.name.clone(),
-                            message: "required field missing".into(),
-                        });
-                    }
-                }
-                Some(value) => {
-                    if !matches!(value, Value::Null) && !type_matches(&field_def.field_type, value) {
-                        errors.push(ValidationError {
-                            field: field_def.name.clone(),
-                            message: format!(
-                                "expected {:?}, got {}",
-                                field_def.field_type,
-                                value_type_name(value)
-                            ),
-                        });
-                    }
-                }
-            }
-        }
+
+        let errors: Vec<ValidationError> = validate_fields(&all_fields, item)
+            .into_iter()
+            .map(|violation| ValidationError {
+                field: violation.field().to_string(),
+                message: violation.detail(),
+            })
+            .collect();
 
         if errors.is_empty() {
             Ok(())
@@ -149,35 +131,6 @@ impl Default for SchemaRegistry {
     }
 }
 
-/// Check if a Value matches the expected FieldType.
-fn type_matches(expected: &FieldType, value: &Value) -> bool {
-    match (expected, value) {
-        (FieldType::String, Value::String(_)) => true,
-        (FieldType::Int, Value::Int(_)) => true,
-        (FieldType::Float, Value::Float(_)) => true,
-        (FieldType::Float, Value::Int(_)) => true, // Allow int where float expected
-        (FieldType::Bool, Value::Bool(_)) => true,
-        (FieldType::DateTime, Value::String(_)) => true, // DateTime stored as ISO string
-        (FieldType::DateTime, Value::Int(_)) => true,    // or as unix timestamp
-        (FieldType::StringArray, Value::Array(_)) => true,
-        (FieldType::Object, Value::Object(_)) => true,
-        _ => false,
-    }
-}
-
-/// Human-readable name for a Value variant.
-fn value_type_name(value: &Value) -> &'static str {
-    match value {
-        Value::Null => "null",
-        Value::Bool(_) => "bool",
-        Value::Int(_) => "int",
-        Value::Float(_) => "float",
-        Value::String(_) => "string",
-        Value::Array(_) => "array",
-        Value::Object(_) => "object",
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +198,7 @@ mod tests {
             author: "user".into(),
             author_kind: ActorKind::Human,
             logical_clock: 0,
+            revision: 0,
             origin: None,
             canonical_id: None,
             tags: vec![],
@@ -387,6 +341,7 @@ mod tests {
             author: "user".into(),
             author_kind: ActorKind::Human,
             logical_clock: 0,
+            revision: 0,
             origin: None,
             canonical_id: None,
             tags: vec![],
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::item::{Item, Value};
 use crate::reference::EdgeType;
 
 /// Reference to a registered schema (e.g., "bibliography-entry", "chat-message").
@@ -24,6 +25,8 @@ pub enum FieldType {
     DateTime,
     StringArray,
     Object,
+    /// A string restricted to one of the listed values.
+    Enum(Vec<String>),
 }
 
 /// Schema definition — describes what fields an item type has.
@@ -37,9 +40,139 @@ pub struct Schema {
     pub inherits: Option<SchemaRef>,
 }
 
+impl Schema {
+    /// Validate an item's payload against this schema's own fields.
+    ///
+    /// Reports every violation rather than stopping at the first. Inherited
+    /// fields aren't visible here; use `SchemaRegistry::validate` to include
+    /// them.
+    pub fn validate(&self, item: &Item) -> Result<(), Vec<SchemaViolation>> {
+        let violations = validate_fields(&self.fields, item);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// A way in which an item fails to conform to its schema.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("field '{}': {}", self.field(), self.detail())]
+pub enum SchemaViolation {
+    MissingField {
+        field: String,
+    },
+
+    TypeMismatch {
+        field: String,
+        expected: FieldType,
+        found: &'static str,
+    },
+
+    NotInEnum {
+        field: String,
+        value: String,
+        allowed: Vec<String>,
+    },
+}
+
+impl SchemaViolation {
+    /// Name of the offending field.
+    pub fn field(&self) -> &str {
+        match self {
+            SchemaViolation::MissingField { field }
+            | SchemaViolation::TypeMismatch { field, .. }
+            | SchemaViolation::NotInEnum { field, .. } => field,
+        }
+    }
+
+    /// What is wrong with the field, without its name.
+    pub fn detail(&self) -> String {
+        match self {
+            SchemaViolation::MissingField { .. } => "required field missing".into(),
+            SchemaViolation::TypeMismatch {
+                expected, found, ..
+            } => format!("expected {:?}, got {}", expected, found),
+            SchemaViolation::NotInEnum { value, allowed, .. } => {
+                format!("'{}' is not one of {:?}", value, allowed)
+            }
+        }
+    }
+}
+
+/// Check an item's payload against a list of field definitions.
+///
+/// Null values count as present and skip type checks, so optional fields
+/// may be explicitly cleared.
+pub(crate) fn validate_fields(fields: &[FieldDef], item: &Item) -> Vec<SchemaViolation> {
+    let mut violations = Vec::new();
+    for def in fields {
+        match item.payload.get(&def.name) {
+            None if def.required => violations.push(SchemaViolation::MissingField {
+                field: def.name.clone(),
+            }),
+            None | Some(Value::Null) => {}
+            Some(value) if !type_matches(&def.field_type, value) => {
+                violations.push(SchemaViolation::TypeMismatch {
+                    field: def.name.clone(),
+                    expected: def.field_type.clone(),
+                    found: value_type_name(value),
+                })
+            }
+            Some(Value::String(s)) => {
+                if let FieldType::Enum(allowed) = &def.field_type {
+                    if !allowed.contains(s) {
+                        violations.push(SchemaViolation::NotInEnum {
+                            field: def.name.clone(),
+                            value: s.clone(),
+                            allowed: allowed.clone(),
+                        });
+                    }
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    violations
+}
+
+/// Check if a Value matches the expected FieldType.
+fn type_matches(expected: &FieldType, value: &Value) -> bool {
+    match (expected, value) {
+        (FieldType::String, Value::String(_)) => true,
+        (FieldType::Int, Value::Int(_)) => true,
+        (FieldType::Float, Value::Float(_)) => true,
+        (FieldType::Float, Value::Int(_)) => true, // Allow int where float expected
+        (FieldType::Bool, Value::Bool(_)) => true,
+        (FieldType::DateTime, Value::String(_)) => true, // DateTime stored as ISO string
+        (FieldType::DateTime, Value::Int(_)) => true,    // or as unix timestamp
+        (FieldType::StringArray, Value::Array(_)) => true,
+        (FieldType::Object, Value::Object(_)) => true,
+        (FieldType::Enum(_), Value::String(_)) => true,
+        _ => false,
+    }
+}
+
+/// Human-readable name for a Value variant.
+fn value_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Int(_) => "int",
+        Value::Float(_) => "float",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::item::ActorKind;
+    use chrono::Utc;
+    use std::collections::BTreeMap;
 
     #[test]
     fn schema_serde_round_trip() {
@@ -108,4 +241,103 @@ mod tests {
         assert_eq!(child, back);
         assert_eq!(back.inherits, Some("research-item".into()));
     }
+
+    fn task_schema() -> Schema {
+        Schema {
+            id: "task".into(),
+            name: "Task".into(),
+            version: "1.0.0".into(),
+            fields: vec![
+                FieldDef {
+                    name: "title".into(),
+                    field_type: FieldType::String,
+                    required: true,
+                    description: None,
+                },
+                FieldDef {
+                    name: "estimate".into(),
+                    field_type: FieldType::Float,
+                    required: false,
+                    description: None,
+                },
+                FieldDef {
+                    name: "state".into(),
+                    field_type: FieldType::Enum(vec!["open".into(), "done".into()]),
+                    required: true,
+                    description: None,
+                },
+            ],
+            expected_edges: vec![],
+            inherits: None,
+        }
+    }
+
+    fn task_item(payload: &[(&str, Value)]) -> Item {
+        Item {
+            id: uuid::Uuid::new_v4(),
+            schema: "task".into(),
+            payload: payload
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect::<BTreeMap<_, _>>(),
+            created: Utc::now(),
+            modified: Utc::now(),
+            author: "test".into(),
+            author_kind: ActorKind::Human,
+            logical_clock: 0,
+            revision: 0,
+            origin: None,
+            canonical_id: None,
+            tags: vec![],
+            flag: None,
+            is_read: false,
+            is_starred: false,
+            priority: Default::default(),
+            visibility: Default::default(),
+            message_type: None,
+            produced_by: None,
+            version: None,
+            batch_id: None,
+            references: vec![],
+            parent: None,
+        }
+    }
+
+    #[test]
+    fn validate_valid_item() {
+        let item = task_item(&[
+            ("title", Value::String("Write intro".into())),
+            ("estimate", Value::Int(2)),
+            ("state", Value::String("open".into())),
+        ]);
+        assert!(task_schema().validate(&item).is_ok());
+    }
+
+    #[test]
+    fn validate_collects_missing_and_mismatched_fields() {
+        let item = task_item(&[
+            ("estimate", Value::String("two hours".into())),
+            ("state", Value::String("blocked".into())),
+        ]);
+        let violations = task_schema().validate(&item).unwrap_err();
+        assert_eq!(
+            violations,
+            vec![
+                SchemaViolation::MissingField {
+                    field: "title".into()
+                },
+                SchemaViolation::TypeMismatch {
+                    field: "estimate".into(),
+                    expected: FieldType::Float,
+                    found: "string",
+                },
+                SchemaViolation::NotInEnum {
+                    field: "state".into(),
+                    value: "blocked".into(),
+                    allowed: vec!["open".into(), "done".into()],
+                },
+            ]
+        );
+        assert_eq!(violations[1].field(), "estimate");
+    }
 }
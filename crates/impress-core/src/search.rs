@@ -0,0 +1,397 @@
+//! Federated search across apps.
+//!
+//! Each app keeps its own search (imbib over references, impart over mail,
+//! the item store over items). A [`FederatedSearch`] fans one query out to
+//! every registered [`SearchProvider`] in parallel and merges the ranked
+//! lists into one, tagging each hit with the provider it came from.
+//!
+//! Provider scores aren't comparable with each other (BM25 in one app, a
+//! title match in another), so lists are merged by rank with reciprocal rank
+//! fusion. Hits that share an [`identity`](SearchHit::identity), such as a
+//! DOI or Message-ID, are combined into one result, which ranks higher for
+//! being found in several places.
+//!
+//! A provider that errors or doesn't answer within the timeout is reported
+//! in [`FederatedResults::errors`]; the other providers' results are still
+//! returned.
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Constant in the reciprocal rank fusion score `1 / (k + rank)`.
+///
+/// Larger values flatten the difference between the top ranks; 60 is the
+/// usual choice.
+const RRF_K: f64 = 60.0;
+
+/// Error from a search provider.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SearchError {
+    #[error("Search failed: {0}")]
+    Failed(String),
+
+    #[error("Search timed out after {0:?}")]
+    TimedOut(Duration),
+}
+
+/// One result from a search provider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    /// Provider-local ID (citation key, message ID, item UUID, ...).
+    pub id: String,
+    /// Identity shared across apps, such as a DOI, used to merge duplicates.
+    pub identity: Option<String>,
+    /// Display title.
+    pub title: String,
+    /// Provider's own relevance score. Only used for ordering within the
+    /// provider's list.
+    pub score: f64,
+}
+
+impl SearchHit {
+    pub fn new(id: impl Into<String>, title: impl Into<String>, score: f64) -> Self {
+        Self {
+            id: id.into(),
+            identity: None,
+            title: title.into(),
+            score,
+        }
+    }
+
+    /// Set the cross-app identity used for deduplication.
+    pub fn with_identity(mut self, identity: impl Into<String>) -> Self {
+        self.identity = Some(identity.into());
+        self
+    }
+}
+
+/// A searchable source, usually one app's own index.
+pub trait SearchProvider: Send + Sync {
+    /// Source tag attached to this provider's hits (e.g. "imbib").
+    fn name(&self) -> &str;
+
+    /// Search for up to `limit` hits, best first.
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchHit>, SearchError>;
+}
+
+/// Where a merged result was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceHit {
+    /// Provider name.
+    pub source: String,
+    /// 1-based rank within that provider's results.
+    pub rank: usize,
+    pub hit: SearchHit,
+}
+
+/// A result merged across providers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FederatedHit {
+    /// Title from the best-ranked source.
+    pub title: String,
+    /// Shared identity, if any source provided one.
+    pub identity: Option<String>,
+    /// Fused score; higher is better.
+    pub score: f64,
+    /// Every provider that returned this result, best-ranked first.
+    pub sources: Vec<SourceHit>,
+}
+
+/// Merged results and any providers that didn't contribute.
+#[derive(Debug, Clone, Default)]
+pub struct FederatedResults {
+    pub hits: Vec<FederatedHit>,
+    /// Providers that failed or timed out, by name.
+    pub errors: Vec<(String, SearchError)>,
+}
+
+/// Fans a query out to registered providers and merges their results.
+pub struct FederatedSearch {
+    providers: Vec<Arc<dyn SearchProvider>>,
+    timeout: Duration,
+    limit: usize,
+}
+
+impl Default for FederatedSearch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FederatedSearch {
+    pub fn new() -> Self {
+        Self {
+            providers: Vec::new(),
+            timeout: Duration::from_secs(2),
+            limit: 50,
+        }
+    }
+
+    /// Set how long to wait for providers before returning without them.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the maximum number of merged results (also asked of each provider).
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Register a provider.
+    pub fn register(&mut self, provider: impl SearchProvider + 'static) {
+        self.providers.push(Arc::new(provider));
+    }
+
+    /// Names of the registered providers, in registration order.
+    pub fn provider_names(&self) -> Vec<&str> {
+        self.providers.iter().map(|p| p.name()).collect()
+    }
+
+    /// Run a query against every provider and merge the results.
+    ///
+    /// Providers run on their own threads. A provider still running at the
+    /// timeout is reported as [`SearchError::TimedOut`] and its thread is
+    /// left to finish in the background; its results are discarded.
+    pub fn search(&self, query: &str) -> FederatedResults {
+        let (sender, receiver) = mpsc::channel();
+        for (index, provider) in self.providers.iter().enumerate() {
+            let provider = Arc::clone(provider);
+            let sender = sender.clone();
+            let query = query.to_string();
+            let limit = self.limit;
+            std::thread::spawn(move || {
+                // The receiver is gone if the search already timed out
+                let _ = sender.send((index, provider.search(&query, limit)));
+            });
+        }
+        drop(sender);
+
+        let mut responses: Vec<Option<Result<Vec<SearchHit>, SearchError>>> =
+            vec![None; self.providers.len()];
+        let deadline = Instant::now() + self.timeout;
+        let mut pending = self.providers.len();
+        while pending > 0 {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match receiver.recv_timeout(remaining) {
+                Ok((index, response)) => {
+                    responses[index] = Some(response);
+                    pending -= 1;
+                }
+                Err(_) => break,
+            }
+        }
+
+        let mut results = FederatedResults::default();
+        let mut lists = Vec::new();
+        for (provider, response) in self.providers.iter().zip(responses) {
+            let name = provider.name().to_string();
+            match response {
+                Some(Ok(hits)) => lists.push((name, hits)),
+                Some(Err(e)) => results.errors.push((name, e)),
+                None => results
+                    .errors
+                    .push((name, SearchError::TimedOut(self.timeout))),
+            }
+        }
+        results.hits = fuse(lists, self.limit);
+        results
+    }
+}
+
+/// Merge ranked lists with reciprocal rank fusion, combining hits that share
+/// an identity.
+fn fuse(lists: Vec<(String, Vec<SearchHit>)>, limit: usize) -> Vec<FederatedHit> {
+    let mut merged: Vec<FederatedHit> = Vec::new();
+    let mut by_key: HashMap<String, usize> = HashMap::new();
+
+    for (source, mut hits) in lists {
+        // Rank by the provider's score in case it didn't sort
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        for (i, hit) in hits.into_iter().enumerate() {
+            let rank = i + 1;
+            let key = match &hit.identity {
+                Some(identity) => format!("identity:{}", identity),
+                None => format!("{}:{}", source, hit.id),
+            };
+            let index = *by_key.entry(key).or_insert_with(|| {
+                merged.push(FederatedHit {
+                    title: hit.title.clone(),
+                    identity: hit.identity.clone(),
+                    score: 0.0,
+                    sources: Vec::new(),
+                });
+                merged.len() - 1
+            });
+            let entry = &mut merged[index];
+            // A provider listing the same identity twice only counts once
+            if entry.sources.iter().any(|s| s.source == source) {
+                continue;
+            }
+            entry.score += 1.0 / (RRF_K + rank as f64);
+            entry.sources.push(SourceHit {
+                source: source.clone(),
+                rank,
+                hit,
+            });
+        }
+    }
+
+    for entry in &mut merged {
+        entry.sources.sort_by_key(|s| s.rank);
+        entry.title = entry.sources[0].hit.title.clone();
+    }
+    merged.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.title.cmp(&b.title))
+    });
+    merged.truncate(limit);
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockProvider {
+        name: &'static str,
+        hits: Vec<SearchHit>,
+        delay: Duration,
+    }
+
+    impl MockProvider {
+        fn new(name: &'static str, hits: Vec<SearchHit>) -> Self {
+            Self {
+                name,
+                hits,
+                delay: Duration::ZERO,
+            }
+        }
+    }
+
+    impl SearchProvider for MockProvider {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn search(&self, _query: &str, limit: usize) -> Result<Vec<SearchHit>, SearchError> {
+            std::thread::sleep(self.delay);
+            Ok(self.hits.iter().take(limit).cloned().collect())
+        }
+    }
+
+    struct FailingProvider;
+
+    impl SearchProvider for FailingProvider {
+        fn name(&self) -> &str {
+            "broken"
+        }
+
+        fn search(&self, _query: &str, _limit: usize) -> Result<Vec<SearchHit>, SearchError> {
+            Err(SearchError::Failed("index missing".into()))
+        }
+    }
+
+    fn references() -> MockProvider {
+        MockProvider::new(
+            "imbib",
+            vec![
+                SearchHit::new("Smith2023", "Dark matter halos", 12.0)
+                    .with_identity("10.1000/halos"),
+                SearchHit::new("Jones2021", "Halo occupation", 9.5),
+                SearchHit::new("Lee2019", "Cluster lensing", 3.1),
+            ],
+        )
+    }
+
+    fn mail() -> MockProvider {
+        MockProvider::new(
+            "impart",
+            vec![
+                SearchHit::new("<abc@lists>", "Re: halo catalog", 0.9),
+                SearchHit::new("<def@lists>", "Paper: Dark matter halos", 0.7)
+                    .with_identity("10.1000/halos"),
+            ],
+        )
+    }
+
+    #[test]
+    fn merges_and_ranks_across_providers() {
+        let mut search = FederatedSearch::new();
+        search.register(references());
+        search.register(mail());
+        assert_eq!(search.provider_names(), vec!["imbib", "impart"]);
+
+        let results = search.search("halo");
+        assert!(results.errors.is_empty());
+
+        // The paper found by both apps merges into one result and ranks first
+        let titles: Vec<&str> = results.hits.iter().map(|h| h.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Dark matter halos",
+                "Re: halo catalog",
+                "Halo occupation",
+                "Cluster lensing",
+            ]
+        );
+
+        let top = &results.hits[0];
+        assert_eq!(top.identity.as_deref(), Some("10.1000/halos"));
+        let sources: Vec<(&str, usize)> = top
+            .sources
+            .iter()
+            .map(|s| (s.source.as_str(), s.rank))
+            .collect();
+        assert_eq!(sources, vec![("imbib", 1), ("impart", 2)]);
+        assert_eq!(results.hits[1].sources[0].source, "impart");
+        assert_eq!(results.hits[1].sources[0].hit.id, "<abc@lists>");
+    }
+
+    #[test]
+    fn limit_applies_to_merged_results() {
+        let mut search = FederatedSearch::new().with_limit(2);
+        search.register(references());
+        search.register(mail());
+
+        let results = search.search("halo");
+        assert_eq!(results.hits.len(), 2);
+        assert_eq!(results.hits[0].title, "Dark matter halos");
+    }
+
+    #[test]
+    fn slow_or_failing_providers_do_not_block_others() {
+        let mut slow = mail();
+        slow.delay = Duration::from_secs(5);
+
+        let mut search = FederatedSearch::new().with_timeout(Duration::from_millis(100));
+        search.register(references());
+        search.register(slow);
+        search.register(FailingProvider);
+
+        let started = Instant::now();
+        let results = search.search("halo");
+        assert!(started.elapsed() < Duration::from_secs(2));
+
+        assert_eq!(results.hits.len(), 3);
+        assert!(results.hits.iter().all(|h| h.sources[0].source == "imbib"));
+        assert_eq!(
+            results.errors,
+            vec![
+                (
+                    "impart".to_string(),
+                    SearchError::TimedOut(Duration::from_millis(100))
+                ),
+                (
+                    "broken".to_string(),
+                    SearchError::Failed("index missing".into())
+                ),
+            ]
+        );
+    }
+}
@@ -8,7 +8,7 @@ use chrono::{TimeZone, Utc};
 use rusqlite::{params, Connection, OptionalExtension};
 use uuid::Uuid;
 
-use crate::event::ItemEvent;
+use crate::event::{CustomEvent, EventRegistry, ItemEvent, LoggedChange, StoreChange};
 use crate::item::{ActorKind, FlagState, Item, ItemId, Priority, Value, Visibility};
 use crate::operation::{
     build_operation_payload, inverse_of, undo_description, EffectiveState, OperationIntent,
@@ -16,6 +16,7 @@ use crate::operation::{
 };
 use crate::query::ItemQuery;
 use crate::reference::{EdgeType, TypedReference};
+use crate::registry::SchemaRegistry;
 use crate::sql_query::compile_query;
 use crate::store::{FieldMutation, ItemStore, StoreError};
 
@@ -133,12 +134,19 @@ impl Default for StoreConfig {
 pub struct SqliteItemStore {
     conn: Mutex<Connection>,
     readers: Option<ReaderPool>,
-    event_tx: Sender<ItemEvent>,
-    event_rx: Mutex<Option<Receiver<ItemEvent>>>,
+    subscribers: Mutex<Vec<Subscriber>>,
     default_author: String,
     default_author_kind: ActorKind,
     origin_id: String,
     tag_namespace: String,
+    schemas: Option<SchemaRegistry>,
+}
+
+/// A live subscription; dropped from the list once its receiver hangs up.
+enum Subscriber {
+    Events(Sender<ItemEvent>),
+    Changes(Sender<StoreChange>),
+    Feed(Sender<LoggedChange>),
 }
 
 /// The full SELECT column list for the items table.
@@ -146,7 +154,7 @@ const ITEM_COLUMNS: &str =
     "id, schema_ref, payload, created, modified, author, author_kind,
      is_read, is_starred, flag_color, flag_style, flag_length, parent_id,
      logical_clock, origin, canonical_id, priority, visibility,
-     message_type, produced_by, version, batch_id, op_target_id";
+     message_type, produced_by, version, batch_id, op_target_id, revision";
 
 impl SqliteItemStore {
     /// Open (or create) a database at the given path with default config.
@@ -188,6 +196,7 @@ impl SqliteItemStore {
         Self::init_schema(&conn)?;
         Self::migrate_schema(&conn)?;
         Self::init_tombstones(&conn)?;
+        Self::init_event_log(&conn)?;
 
         // Increase prepared statement cache from default 16 to 64.
         // With dynamic SQL from compile_query(), 16 slots evict useful statements.
@@ -196,16 +205,44 @@ impl SqliteItemStore {
         // Initialize or read store metadata
         let origin_id = Self::init_store_metadata(&conn, &config)?;
 
-        let (tx, rx) = mpsc::channel();
         Ok(Self {
             conn: Mutex::new(conn),
             readers: None,
-            event_tx: tx,
-            event_rx: Mutex::new(Some(rx)),
+            subscribers: Mutex::new(Vec::new()),
             default_author: config.author,
             default_author_kind: config.author_kind,
             origin_id,
             tag_namespace: config.tag_namespace,
+            schemas: None,
+        })
+    }
+
+    /// Validate writes against the schemas in `registry`.
+    ///
+    /// Inserts and payload operations on items whose schema is registered
+    /// are rejected with `StoreError::Validation` listing every violation.
+    /// Items with unregistered schemas are written unchecked.
+    pub fn with_schema_validation(mut self, registry: SchemaRegistry) -> Self {
+        self.schemas = Some(registry);
+        self
+    }
+
+    /// Reject an item that violates its registered schema, if validation is on.
+    fn check_schema(&self, item: &Item) -> Result<(), StoreError> {
+        let Some(registry) = &self.schemas else {
+            return Ok(());
+        };
+        if registry.get(&item.schema).is_none() {
+            return Ok(());
+        }
+        registry.validate(item).map_err(|errors| {
+            StoreError::Validation(
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )
         })
     }
 
@@ -289,7 +326,8 @@ impl SqliteItemStore {
                 version TEXT,
                 batch_id TEXT,
                 op_target_id TEXT REFERENCES items(id) ON DELETE CASCADE,
-                retention TEXT NOT NULL DEFAULT 'durable'
+                retention TEXT NOT NULL DEFAULT 'durable',
+                revision INTEGER NOT NULL DEFAULT 0
             );
 
             CREATE TABLE IF NOT EXISTS item_tags (
@@ -372,6 +410,7 @@ impl SqliteItemStore {
             "ALTER TABLE items ADD COLUMN batch_id TEXT",
             "ALTER TABLE items ADD COLUMN op_target_id TEXT REFERENCES items(id) ON DELETE CASCADE",
             "ALTER TABLE items ADD COLUMN retention TEXT NOT NULL DEFAULT 'durable'",
+            "ALTER TABLE items ADD COLUMN revision INTEGER NOT NULL DEFAULT 0",
         ];
         for sql in &migrations {
             let _ = conn.execute(sql, []);
@@ -543,11 +582,194 @@ impl SqliteItemStore {
         Ok(new_val)
     }
 
-    fn emit(&self, event: ItemEvent) {
-        let _ = self.event_tx.send(event);
+    /// Deliver an event, logged at `seq`, to every live subscriber.
+    ///
+    /// Write paths log their events inside the write's transaction and call
+    /// this after commit while still holding the writer lock, so every
+    /// subscriber sees events in log order.
+    fn emit(&self, seq: u64, event: ItemEvent) {
+        let Ok(mut subscribers) = self.subscribers.lock() else {
+            return;
+        };
+        subscribers.retain(|subscriber| match subscriber {
+            Subscriber::Events(tx) => tx.send(event.clone()).is_ok(),
+            Subscriber::Changes(tx) => match event.change() {
+                Some(change) => tx.send(change).is_ok(),
+                None => true,
+            },
+            Subscriber::Feed(tx) => match event.change() {
+                Some(change) => tx.send(LoggedChange { seq, change }).is_ok(),
+                None => true,
+            },
+        });
+    }
+
+    fn add_subscriber(&self, subscriber: Subscriber) -> Result<(), StoreError> {
+        self.subscribers
+            .lock()
+            .map_err(|e| StoreError::Storage(e.to_string()))?
+            .push(subscriber);
+        Ok(())
+    }
+
+    /// Subscribe to created/updated/deleted notifications for all items.
+    ///
+    /// Each subscriber receives every change committed after it subscribed,
+    /// in commit order. Batch writes notify once per affected item operation.
+    pub fn subscribe_changes(&self) -> Result<Receiver<StoreChange>, StoreError> {
+        let (tx, rx) = mpsc::channel();
+        self.add_subscriber(Subscriber::Changes(tx))?;
+        Ok(rx)
+    }
+
+    /// Subscribe to the change feed as `consumer`, resuming from its cursor.
+    ///
+    /// The receiver first gets every change logged after the consumer's last
+    /// [`ack_changes`](Self::ack_changes), then live changes as they commit,
+    /// with no gap or overlap between the two. The cursor is persisted, so a
+    /// consumer that acknowledges what it handled picks up where it left off
+    /// after a restart.
+    pub fn subscribe_feed(&self, consumer: &str) -> Result<Receiver<LoggedChange>, StoreError> {
+        // Hold the writer so no change commits between the backlog read and
+        // the subscription
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| StoreError::Storage(e.to_string()))?;
+        let cursor = Self::read_feed_cursor(&conn, consumer)?;
+        let (tx, rx) = mpsc::channel();
+        for change in Self::read_changes_since(&conn, cursor)? {
+            // The receiver can't have hung up yet; it is returned below
+            let _ = tx.send(change);
+        }
+        self.add_subscriber(Subscriber::Feed(tx))?;
+        Ok(rx)
+    }
+
+    /// Changes logged after `seq`, oldest first.
+    pub fn changes_since(&self, seq: u64) -> Result<Vec<LoggedChange>, StoreError> {
+        self.with_read(|conn| Self::read_changes_since(conn, seq))
+    }
+
+    /// The last sequence number `consumer` acknowledged, or 0 if it never has.
+    pub fn feed_cursor(&self, consumer: &str) -> Result<u64, StoreError> {
+        self.with_read(|conn| Self::read_feed_cursor(conn, consumer))
+    }
+
+    /// Persist that `consumer` has handled every change up to and including
+    /// `seq`.
+    pub fn ack_changes(&self, consumer: &str, seq: u64) -> Result<(), StoreError> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| StoreError::Storage(e.to_string()))?;
+        conn.execute(
+            "INSERT OR REPLACE INTO store_metadata (key, value) VALUES (?1, ?2)",
+            params![format!("feed_cursor:{}", consumer), seq.to_string()],
+        )
+        .map_err(|e| StoreError::Storage(format!("ack_changes: {}", e)))?;
+        Ok(())
+    }
+
+    fn read_feed_cursor(conn: &Connection, consumer: &str) -> Result<u64, StoreError> {
+        let value: Option<String> = conn
+            .query_row(
+                "SELECT value FROM store_metadata WHERE key = ?1",
+                params![format!("feed_cursor:{}", consumer)],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| StoreError::Storage(format!("read feed cursor: {}", e)))?;
+        Ok(value.and_then(|v| v.parse().ok()).unwrap_or(0))
+    }
+
+    fn read_changes_since(conn: &Connection, seq: u64) -> Result<Vec<LoggedChange>, StoreError> {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT seq, event FROM event_log
+                 WHERE seq > ?1 AND kind != 'custom' ORDER BY seq ASC",
+            )
+            .map_err(|e| StoreError::Storage(format!("prepare changes_since: {}", e)))?;
+        let rows = stmt
+            .query_map(params![seq as i64], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|e| StoreError::Storage(format!("query changes_since: {}", e)))?;
+        let mut changes = Vec::new();
+        for row in rows {
+            let (seq, json) = row.map_err(|e| StoreError::Storage(format!("row: {}", e)))?;
+            let change: StoreChange = serde_json::from_str(&json)
+                .map_err(|e| StoreError::Storage(format!("decode change: {}", e)))?;
+            changes.push(LoggedChange {
+                seq: seq as u64,
+                change,
+            });
+        }
+        Ok(changes)
+    }
+
+    /// Record an app-defined event in the event log and emit it to subscribers.
+    ///
+    /// Logged events survive restarts; rebuild app state from them with
+    /// [`replay_custom_events`](Self::replay_custom_events). Returns the
+    /// event's sequence number in the log.
+    pub fn emit_custom(&self, event: CustomEvent) -> Result<u64, StoreError> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| StoreError::Storage(e.to_string()))?;
+        let event = ItemEvent::Custom(event);
+        let seq = Self::log_event(&conn, &event)?;
+        self.emit(seq, event);
+        Ok(seq)
+    }
+
+    /// Custom events in the event log, oldest first.
+    pub fn custom_events(&self) -> Result<Vec<CustomEvent>, StoreError> {
+        self.with_read(|conn| {
+            let mut stmt = conn
+                .prepare("SELECT event FROM event_log WHERE kind = 'custom' ORDER BY seq ASC")
+                .map_err(|e| StoreError::Storage(format!("prepare custom_events: {}", e)))?;
+            let rows = stmt
+                .query_map([], |row| row.get::<_, String>(0))
+                .map_err(|e| StoreError::Storage(format!("query custom_events: {}", e)))?;
+            let mut events = Vec::new();
+            for row in rows {
+                let json = row.map_err(|e| StoreError::Storage(format!("row: {}", e)))?;
+                let event: ItemEvent = serde_json::from_str(&json)
+                    .map_err(|e| StoreError::Storage(format!("decode event: {}", e)))?;
+                if let ItemEvent::Custom(custom) = event {
+                    events.push(custom);
+                }
+            }
+            Ok(events)
+        })
+    }
+
+    /// Replay the logged custom events into app state, oldest first.
+    ///
+    /// Apps call this on load to rebuild the state their projectors maintain.
+    /// Events of kinds with no registered projector are returned, as by
+    /// [`EventRegistry::replay`].
+    pub fn replay_custom_events<S>(
+        &self,
+        registry: &EventRegistry<S>,
+        state: &mut S,
+    ) -> Result<Vec<CustomEvent>, StoreError> {
+        let events: Vec<ItemEvent> = self
+            .custom_events()?
+            .into_iter()
+            .map(ItemEvent::Custom)
+            .collect();
+        registry
+            .replay(state, &events)
+            .map_err(|e| StoreError::Storage(format!("replay: {}", e)))
     }
 
     /// Insert a single item into the database.
+    ///
+    /// Statements go through the connection's prepared-statement cache, so
+    /// `insert_batch` compiles them once rather than once per item.
     fn insert_item(conn: &Connection, item: &Item, origin_id: &str) -> Result<(), StoreError> {
         let payload_json =
             serde_json::to_string(&item.payload).map_err(|e| StoreError::Storage(e.to_string()))?;
@@ -557,14 +779,16 @@ impl SqliteItemStore {
         let produced_by = item.produced_by.map(|p| p.to_string());
         let origin = item.origin.as_deref().unwrap_or(origin_id);
 
-        conn.execute(
+        conn.prepare_cached(
             "INSERT INTO items (id, schema_ref, payload, created, modified, author, author_kind,
               is_read, is_starred, flag_color, flag_style, flag_length, parent_id,
               logical_clock, origin, canonical_id, priority, visibility,
-              message_type, produced_by, version, batch_id, op_target_id)
+              message_type, produced_by, version, batch_id, op_target_id, revision)
              VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13,
-                     ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, NULL)",
-            params![
+                     ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, NULL, ?23)",
+        )
+        .and_then(|mut stmt| {
+            stmt.execute(params![
                 item.id.to_string(),
                 item.schema,
                 payload_json,
@@ -587,8 +811,9 @@ impl SqliteItemStore {
                 produced_by,
                 item.version,
                 item.batch_id,
-            ],
-        )
+                item.revision as i64,
+            ])
+        })
         .map_err(|e| {
             if let rusqlite::Error::SqliteFailure(ref err, _) = e {
                 if err.code == rusqlite::ErrorCode::ConstraintViolation {
@@ -599,12 +824,16 @@ impl SqliteItemStore {
         })?;
 
         // Insert tags
-        for tag in &item.tags {
-            conn.execute(
-                "INSERT OR IGNORE INTO item_tags (item_id, tag_path) VALUES (?1, ?2)",
-                params![item.id.to_string(), tag],
-            )
-            .map_err(|e| StoreError::Storage(format!("insert tag: {}", e)))?;
+        if !item.tags.is_empty() {
+            let mut stmt = conn
+                .prepare_cached(
+                    "INSERT OR IGNORE INTO item_tags (item_id, tag_path) VALUES (?1, ?2)",
+                )
+                .map_err(|e| StoreError::Storage(format!("prepare tag: {}", e)))?;
+            for tag in &item.tags {
+                stmt.execute(params![item.id.to_string(), tag])
+                    .map_err(|e| StoreError::Storage(format!("insert tag: {}", e)))?;
+            }
         }
 
         // Insert references
@@ -615,10 +844,12 @@ impl SqliteItemStore {
                 .metadata
                 .as_ref()
                 .map(|m| serde_json::to_string(m).unwrap_or_default());
-            conn.execute(
+            conn.prepare_cached(
                 "INSERT OR IGNORE INTO item_references (source_id, target_id, edge_type, metadata) VALUES (?1, ?2, ?3, ?4)",
-                params![item.id.to_string(), r.target.to_string(), edge_str, meta_str],
             )
+            .and_then(|mut stmt| {
+                stmt.execute(params![item.id.to_string(), r.target.to_string(), edge_str, meta_str])
+            })
             .map_err(|e| StoreError::Storage(format!("insert ref: {}", e)))?;
         }
 
@@ -688,6 +919,33 @@ impl SqliteItemStore {
             .lock()
             .map_err(|e| StoreError::Storage(e.to_string()))?;
 
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| StoreError::Storage(format!("begin tx: {}", e)))?;
+
+        let op_id = self.apply_operation_on(&tx, &spec)?;
+        Self::bump_revision(&tx, &spec.target_id.to_string())?;
+        let event = ItemEvent::OperationApplied {
+            operation_id: op_id,
+            target_id: spec.target_id,
+        };
+        let seq = Self::log_event(&tx, &event)?;
+
+        tx.commit()
+            .map_err(|e| StoreError::Storage(format!("commit operation: {}", e)))?;
+        self.emit(seq, event);
+
+        Ok(op_id)
+    }
+
+    /// Record and materialize one operation on an already-locked connection.
+    ///
+    /// Does not bump the target's revision or emit events; callers do both.
+    fn apply_operation_on(
+        &self,
+        conn: &Connection,
+        spec: &OperationSpec,
+    ) -> Result<ItemId, StoreError> {
         let target_str = spec.target_id.to_string();
 
         // Verify target exists
@@ -729,11 +987,31 @@ impl SqliteItemStore {
             }
         }
 
+        // Validate the payload the operation would produce
+        if self.schemas.is_some() {
+            if let Some(payload_fields) = payload_changes(&spec.op_type) {
+                let mut target = conn
+                    .query_row(
+                        &format!("SELECT {} FROM items WHERE id = ?1", ITEM_COLUMNS),
+                        params![&target_str],
+                        |row| Ok(Self::row_to_item_partial(row)),
+                    )
+                    .map_err(|e| StoreError::Storage(format!("read target: {}", e)))??;
+                for (field, value) in payload_fields {
+                    match value {
+                        Some(value) => target.payload.insert(field.clone(), value.clone()),
+                        None => target.payload.remove(field),
+                    };
+                }
+                self.check_schema(&target)?;
+            }
+        }
+
         // Capture previous value before materializing change
-        let prev = Self::capture_previous_value(&conn, &target_str, &spec.op_type)?;
+        let prev = Self::capture_previous_value(conn, &target_str, &spec.op_type)?;
 
         // Get next logical clock
-        let clock = Self::next_clock(&conn)?;
+        let clock = Self::next_clock(conn)?;
 
         // Build operation item (with prev for undo)
         let op_id = Uuid::new_v4();
@@ -754,6 +1032,7 @@ impl SqliteItemStore {
             author: spec.author.clone(),
             author_kind: spec.author_kind,
             logical_clock: clock,
+            revision: 0,
             origin: Some(self.origin_id.clone()),
             canonical_id: None,
             tags: vec![],
@@ -771,21 +1050,25 @@ impl SqliteItemStore {
         };
 
         // Insert operation item with op_target_id
-        Self::insert_operation_item(&conn, &op_item, spec.target_id, &self.origin_id, spec.retention)?;
+        Self::insert_operation_item(conn, &op_item, spec.target_id, &self.origin_id, spec.retention)?;
 
         // Materialize the change on the target
         let now = Utc::now().timestamp_millis();
-        Self::materialize_operation(&conn, &spec.target_id.to_string(), &spec.op_type, now)?;
-
-        drop(conn);
-        self.emit(ItemEvent::OperationApplied {
-            operation_id: op_id,
-            target_id: spec.target_id,
-        });
+        Self::materialize_operation(conn, &target_str, &spec.op_type, now)?;
 
         Ok(op_id)
     }
 
+    /// Advance an item's optimistic-concurrency revision.
+    fn bump_revision(conn: &Connection, target_id_str: &str) -> Result<(), StoreError> {
+        conn.execute(
+            "UPDATE items SET revision = revision + 1 WHERE id = ?1",
+            params![target_id_str],
+        )
+        .map_err(|e| StoreError::Storage(format!("bump revision: {}", e)))?;
+        Ok(())
+    }
+
     /// Apply a batch of operations sharing a batch_id.
     pub fn apply_operation_batch(
         &self,
@@ -801,6 +1084,7 @@ impl SqliteItemStore {
 
         let batch_id = Uuid::new_v4().to_string();
         let mut op_ids = Vec::with_capacity(specs.len());
+        let mut events = Vec::with_capacity(specs.len());
 
         for mut spec in specs {
             spec.batch_id = Some(batch_id.clone());
@@ -842,6 +1126,7 @@ impl SqliteItemStore {
                 author: spec.author.clone(),
                 author_kind: spec.author_kind,
                 logical_clock: clock,
+                revision: 0,
                 origin: Some(self.origin_id.clone()),
                 canonical_id: None,
                 tags: vec![],
@@ -862,21 +1147,22 @@ impl SqliteItemStore {
 
             let now = Utc::now().timestamp_millis();
             Self::materialize_operation(&tx, &target_str, &spec.op_type, now)?;
+            Self::bump_revision(&tx, &target_str)?;
 
+            let event = ItemEvent::OperationApplied {
+                operation_id: op_id,
+                target_id: spec.target_id,
+            };
+            events.push((Self::log_event(&tx, &event)?, event));
             op_ids.push(op_id);
         }
 
         tx.commit()
             .map_err(|e| StoreError::Storage(format!("commit batch: {}", e)))?;
 
-        // Emit events after commit
-        drop(conn);
-        for op_id in &op_ids {
-            // We don't have target_ids here easily; emit a generic event
-            self.emit(ItemEvent::OperationApplied {
-                operation_id: *op_id,
-                target_id: Uuid::nil(), // batch events — listeners should re-query
-            });
+        // Emit events after commit, before releasing the writer
+        for (seq, event) in events {
+            self.emit(seq, event);
         }
 
         Ok(op_ids)
@@ -1781,18 +2067,20 @@ impl SqliteItemStore {
             || note.is_some()
             || body.is_some()
         {
-            conn.execute(
+            conn.prepare_cached(
                 "INSERT INTO items_fts (item_id, title, author_text, abstract_text, note, body)
                  VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-                params![
+            )
+            .and_then(|mut stmt| {
+                stmt.execute(params![
                     id_str,
                     title.unwrap_or_default(),
                     author_text.unwrap_or_default(),
                     abstract_text.unwrap_or_default(),
                     note.unwrap_or_default(),
                     body.unwrap_or_default(),
-                ],
-            )
+                ])
+            })
             .map_err(|e| StoreError::Storage(format!("update_fts: {}", e)))?;
         }
 
@@ -1886,6 +2174,9 @@ impl SqliteItemStore {
             .get(21)
             .map_err(|e| StoreError::Storage(format!("row batch_id: {}", e)))?;
         // column 22 = op_target_id (not stored on Item struct, used for queries only)
+        let revision: i64 = row
+            .get(23)
+            .map_err(|e| StoreError::Storage(format!("row revision: {}", e)))?;
 
         let created = Utc
             .timestamp_millis_opt(created_ms)
@@ -1922,6 +2213,7 @@ impl SqliteItemStore {
             author,
             author_kind,
             logical_clock: logical_clock as u64,
+            revision: revision as u64,
             origin,
             canonical_id,
             tags,
@@ -2063,6 +2355,9 @@ impl SqliteItemStore {
         let batch_id: Option<String> = row
             .get(21)
             .map_err(|e| StoreError::Storage(format!("row batch_id: {}", e)))?;
+        let revision: i64 = row
+            .get(23)
+            .map_err(|e| StoreError::Storage(format!("row revision: {}", e)))?;
 
         let created = Utc
             .timestamp_millis_opt(created_ms)
@@ -2092,6 +2387,7 @@ impl SqliteItemStore {
             author,
             author_kind,
             logical_clock: logical_clock as u64,
+            revision: revision as u64,
             origin,
             canonical_id,
             tags: vec![],
@@ -2370,6 +2666,50 @@ impl SqliteItemStore {
         }
     }
 
+    // --- Event log ---
+
+    /// Initialize the event log table (called from init_with_connection).
+    fn init_event_log(conn: &Connection) -> Result<(), StoreError> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS event_log (
+                seq INTEGER PRIMARY KEY AUTOINCREMENT,
+                kind TEXT NOT NULL,
+                event TEXT NOT NULL,
+                recorded_at INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_event_log_kind ON event_log(kind, seq);",
+        )
+        .map_err(|e| StoreError::Storage(format!("init_event_log: {}", e)))?;
+        Ok(())
+    }
+
+    /// Append an event to the log on an already-locked connection, returning
+    /// its sequence number.
+    ///
+    /// Item events are logged as the [`StoreChange`] they produce, so bulk
+    /// imports don't copy every item into the log; custom events are logged
+    /// whole for replay.
+    fn log_event(conn: &Connection, event: &ItemEvent) -> Result<u64, StoreError> {
+        let (kind, json) = match event.change() {
+            Some(change) => {
+                let kind = match change {
+                    StoreChange::Created(_) => "created",
+                    StoreChange::Updated(_) => "updated",
+                    StoreChange::Deleted(_) => "deleted",
+                };
+                (kind, serde_json::to_string(&change))
+            }
+            None => ("custom", serde_json::to_string(event)),
+        };
+        let json = json.map_err(|e| StoreError::Storage(format!("encode event: {}", e)))?;
+        conn.prepare_cached(
+            "INSERT INTO event_log (kind, event, recorded_at) VALUES (?1, ?2, ?3)",
+        )
+        .and_then(|mut stmt| stmt.execute(params![kind, json, Utc::now().timestamp_millis()]))
+        .map_err(|e| StoreError::Storage(format!("log_event: {}", e)))?;
+        Ok(conn.last_insert_rowid() as u64)
+    }
+
     // --- Tombstones for sync delete tracking ---
 
     /// Initialize tombstones table (called from init_schema/migrate_schema).
@@ -2516,18 +2856,28 @@ impl SqliteItemStore {
 
 impl ItemStore for SqliteItemStore {
     fn insert(&self, item: Item) -> Result<ItemId, StoreError> {
+        self.check_schema(&item)?;
         let conn = self
             .conn
             .lock()
             .map_err(|e| StoreError::Storage(e.to_string()))?;
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| StoreError::Storage(format!("begin tx: {}", e)))?;
         let id = item.id;
-        Self::insert_item(&conn, &item, &self.origin_id)?;
-        drop(conn);
-        self.emit(ItemEvent::Created(Box::new(item)));
+        Self::insert_item(&tx, &item, &self.origin_id)?;
+        let event = ItemEvent::Created(Box::new(item));
+        let seq = Self::log_event(&tx, &event)?;
+        tx.commit()
+            .map_err(|e| StoreError::Storage(format!("commit: {}", e)))?;
+        self.emit(seq, event);
         Ok(id)
     }
 
     fn insert_batch(&self, items: Vec<Item>) -> Result<Vec<ItemId>, StoreError> {
+        for item in &items {
+            self.check_schema(item)?;
+        }
         let conn = self
             .conn
             .lock()
@@ -2537,17 +2887,19 @@ impl ItemStore for SqliteItemStore {
             .map_err(|e| StoreError::Storage(format!("begin tx: {}", e)))?;
 
         let mut ids = Vec::with_capacity(items.len());
-        for item in &items {
-            Self::insert_item(&tx, item, &self.origin_id)?;
+        let mut events = Vec::with_capacity(items.len());
+        for item in items {
+            Self::insert_item(&tx, &item, &self.origin_id)?;
             ids.push(item.id);
+            let event = ItemEvent::Created(Box::new(item));
+            events.push((Self::log_event(&tx, &event)?, event));
         }
 
         tx.commit()
             .map_err(|e| StoreError::Storage(format!("commit: {}", e)))?;
 
-        drop(conn);
-        for item in items {
-            self.emit(ItemEvent::Created(Box::new(item)));
+        for (seq, event) in events {
+            self.emit(seq, event);
         }
         Ok(ids)
     }
@@ -2598,25 +2950,103 @@ impl ItemStore for SqliteItemStore {
         Ok(())
     }
 
+    /// The revision check and bump happen in a single conditional `UPDATE`
+    /// inside the same transaction as the mutations, so a concurrent writer
+    /// can't slip in between. On success the item's revision is
+    /// `expected_revision + 1`, which is returned. A stale revision fails with
+    /// `StoreError::RevisionConflict` and nothing is applied.
+    fn update_if_revision(
+        &self,
+        id: ItemId,
+        mutations: Vec<FieldMutation>,
+        expected_revision: u64,
+    ) -> Result<u64, StoreError> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| StoreError::Storage(e.to_string()))?;
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| StoreError::Storage(format!("begin tx: {}", e)))?;
+        let id_str = id.to_string();
+
+        let rows = tx
+            .execute(
+                "UPDATE items SET revision = revision + 1 WHERE id = ?1 AND revision = ?2",
+                params![&id_str, expected_revision as i64],
+            )
+            .map_err(|e| StoreError::Storage(format!("check revision: {}", e)))?;
+        if rows == 0 {
+            let actual: Option<i64> = tx
+                .query_row(
+                    "SELECT revision FROM items WHERE id = ?1",
+                    params![&id_str],
+                    |row| row.get(0),
+                )
+                .optional()
+                .map_err(|e| StoreError::Storage(format!("read revision: {}", e)))?;
+            return Err(match actual {
+                Some(actual) => StoreError::RevisionConflict {
+                    id,
+                    expected: expected_revision,
+                    actual: actual as u64,
+                },
+                None => StoreError::NotFound(id),
+            });
+        }
+
+        let batch_id = (mutations.len() > 1).then(|| Uuid::new_v4().to_string());
+        let mut events = Vec::with_capacity(mutations.len());
+        for mutation in mutations {
+            let spec = OperationSpec {
+                target_id: id,
+                op_type: mutation.into(),
+                intent: OperationIntent::Routine,
+                reason: None,
+                batch_id: batch_id.clone(),
+                author: self.default_author.clone(),
+                author_kind: self.default_author_kind,
+                retention: RetentionTier::Durable,
+            };
+            let event = ItemEvent::OperationApplied {
+                operation_id: self.apply_operation_on(&tx, &spec)?,
+                target_id: id,
+            };
+            events.push((Self::log_event(&tx, &event)?, event));
+        }
+
+        tx.commit()
+            .map_err(|e| StoreError::Storage(format!("commit versioned update: {}", e)))?;
+
+        for (seq, event) in events {
+            self.emit(seq, event);
+        }
+
+        Ok(expected_revision + 1)
+    }
+
     fn delete(&self, id: ItemId) -> Result<(), StoreError> {
         let conn = self
             .conn
             .lock()
             .map_err(|e| StoreError::Storage(e.to_string()))?;
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| StoreError::Storage(format!("begin tx: {}", e)))?;
         let id_str = id.to_string();
 
         // Delete FTS entry first
-        Self::delete_fts(&conn, &id_str)?;
+        Self::delete_fts(&tx, &id_str)?;
 
         // Delete any operations targeting this item first (they reference it via FK)
-        conn.execute(
+        tx.execute(
             "DELETE FROM items WHERE op_target_id = ?1",
             params![&id_str],
         )
         .map_err(|e| StoreError::Storage(format!("delete ops: {}", e)))?;
 
         // Foreign key CASCADE handles item_tags and item_references
-        let rows = conn
+        let rows = tx
             .execute("DELETE FROM items WHERE id = ?1", params![&id_str])
             .map_err(|e| StoreError::Storage(format!("delete: {}", e)))?;
 
@@ -2624,8 +3054,11 @@ impl ItemStore for SqliteItemStore {
             return Err(StoreError::NotFound(id));
         }
 
-        drop(conn);
-        self.emit(ItemEvent::Deleted(id));
+        let event = ItemEvent::Deleted(id);
+        let seq = Self::log_event(&tx, &event)?;
+        tx.commit()
+            .map_err(|e| StoreError::Storage(format!("commit delete: {}", e)))?;
+        self.emit(seq, event);
         Ok(())
     }
 
@@ -2727,14 +3160,8 @@ impl ItemStore for SqliteItemStore {
     }
 
     fn subscribe(&self, _q: ItemQuery) -> Result<Receiver<ItemEvent>, StoreError> {
-        let rx = self
-            .event_rx
-            .lock()
-            .map_err(|e| StoreError::Storage(e.to_string()))?
-            .take()
-            .ok_or_else(|| {
-                StoreError::Storage("subscribe: receiver already taken".to_string())
-            })?;
+        let (tx, rx) = mpsc::channel();
+        self.add_subscriber(Subscriber::Events(tx))?;
         Ok(rx)
     }
 }
@@ -2890,6 +3317,18 @@ fn extract_string_field(payload: &BTreeMap<String, Value>, field: &str) -> Optio
     }
 }
 
+/// Payload fields an operation sets (`Some`) or removes (`None`), if it touches the payload.
+fn payload_changes(op: &OperationType) -> Option<Vec<(&String, Option<&Value>)>> {
+    match op {
+        OperationType::SetPayload(field, value) => Some(vec![(field, Some(value))]),
+        OperationType::RemovePayload(field) => Some(vec![(field, None)]),
+        OperationType::PatchPayload(fields) => {
+            Some(fields.iter().map(|(field, value)| (field, Some(value))).collect())
+        }
+        _ => None,
+    }
+}
+
 impl SqliteItemStore {
     /// Fetch recent undoable operations, grouped by batch_id.
     /// Returns one entry per batch (or per unbatched operation).
@@ -3043,6 +3482,7 @@ mod tests {
             author: "test@example.com".into(),
             author_kind: ActorKind::Human,
             logical_clock: 0,
+            revision: 0,
             origin: None,
             canonical_id: None,
             tags: vec![],
@@ -3096,6 +3536,40 @@ mod tests {
         assert_eq!(count, 10);
     }
 
+    #[test]
+    fn batch_insert_is_atomic() {
+        let store = SqliteItemStore::open_in_memory().unwrap();
+        let items: Vec<Item> = (0..1000)
+            .map(|i| make_item("test", &format!("Batch item {}", i)))
+            .collect();
+        let first = items[0].clone();
+        let ids = store.insert_batch(items).unwrap();
+        assert_eq!(ids.len(), 1000);
+        assert_eq!(store.count(&ItemQuery::default()).unwrap(), 1000);
+
+        // The duplicate in the middle fails the whole batch
+        let mut batch: Vec<Item> = (0..10)
+            .map(|i| make_item("test", &format!("Doomed item {}", i)))
+            .collect();
+        batch.insert(5, first.clone());
+        let err = store.insert_batch(batch.clone()).unwrap_err();
+        assert!(matches!(err, StoreError::AlreadyExists(id) if id == first.id));
+
+        assert_eq!(store.count(&ItemQuery::default()).unwrap(), 1000);
+        assert!(store.get(batch[0].id).unwrap().is_none());
+        let fts_rows: i64 = store
+            .conn
+            .lock()
+            .unwrap()
+            .query_row(
+                "SELECT COUNT(*) FROM items_fts WHERE title LIKE 'Doomed%'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(fts_rows, 0);
+    }
+
     #[test]
     fn query_by_schema() {
         let store = SqliteItemStore::open_in_memory().unwrap();
@@ -3343,6 +3817,142 @@ mod tests {
         assert_eq!(tag_count, 0);
     }
 
+    #[test]
+    fn update_if_revision_applies_at_current_revision() {
+        let store = SqliteItemStore::open_in_memory().unwrap();
+        let id = store.insert(make_item("test", "Versioned")).unwrap();
+        assert_eq!(store.get(id).unwrap().unwrap().revision, 0);
+
+        let rev = store
+            .update_if_revision(
+                id,
+                vec![
+                    FieldMutation::SetRead(true),
+                    FieldMutation::SetPayload("title".into(), Value::String("Renamed".into())),
+                ],
+                0,
+            )
+            .unwrap();
+        assert_eq!(rev, 1);
+
+        let got = store.get(id).unwrap().unwrap();
+        assert_eq!(got.revision, 1);
+        assert!(got.is_read);
+        assert_eq!(got.payload.get("title"), Some(&Value::String("Renamed".into())));
+
+        // Unconditional updates advance the revision too
+        store.update(id, vec![FieldMutation::SetStarred(true)]).unwrap();
+        assert_eq!(store.get(id).unwrap().unwrap().revision, 2);
+    }
+
+    #[test]
+    fn update_if_revision_rejects_stale_revision() {
+        let store = SqliteItemStore::open_in_memory().unwrap();
+        let id = store.insert(make_item("test", "Contended")).unwrap();
+
+        // Two writers read revision 0; the first one wins
+        store
+            .update_if_revision(id, vec![FieldMutation::SetRead(true)], 0)
+            .unwrap();
+        let err = store
+            .update_if_revision(id, vec![FieldMutation::SetStarred(true)], 0)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            StoreError::RevisionConflict { expected: 0, actual: 1, .. }
+        ));
+
+        let got = store.get(id).unwrap().unwrap();
+        assert_eq!(got.revision, 1);
+        assert!(!got.is_starred);
+        assert_eq!(store.operations_for(id, None).unwrap().len(), 1);
+
+        assert!(matches!(
+            store.update_if_revision(Uuid::new_v4(), vec![], 0),
+            Err(StoreError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn change_feed_reaches_every_subscriber_in_order() {
+        let store = SqliteItemStore::open_in_memory().unwrap();
+        let first = store.subscribe_changes().unwrap();
+        let second = store.subscribe_changes().unwrap();
+
+        let id = store.insert(make_item("test", "Watched")).unwrap();
+        store
+            .update(id, vec![FieldMutation::SetRead(true)])
+            .unwrap();
+        store
+            .emit_custom(CustomEvent::new("test/ignored", serde_json::json!({})))
+            .unwrap();
+        store.delete(id).unwrap();
+
+        let expected = vec![
+            StoreChange::Created(id),
+            StoreChange::Updated(id),
+            StoreChange::Deleted(id),
+        ];
+        for rx in [first, second] {
+            assert_eq!(rx.try_iter().collect::<Vec<_>>(), expected);
+        }
+
+        // A hung-up subscriber doesn't stop delivery to the others
+        let dropped = store.subscribe_changes().unwrap();
+        let live = store.subscribe_changes().unwrap();
+        drop(dropped);
+        let other = store.insert(make_item("test", "Other")).unwrap();
+        assert_eq!(live.try_recv().unwrap(), StoreChange::Created(other));
+    }
+
+    #[test]
+    fn schema_validation_on_write() {
+        use crate::schema::{FieldDef, FieldType, Schema};
+
+        let mut registry = SchemaRegistry::new();
+        registry
+            .register(Schema {
+                id: "note".into(),
+                name: "Note".into(),
+                version: "1.0.0".into(),
+                fields: vec![FieldDef {
+                    name: "title".into(),
+                    field_type: FieldType::String,
+                    required: true,
+                    description: None,
+                }],
+                expected_edges: vec![],
+                inherits: None,
+            })
+            .unwrap();
+        let store = SqliteItemStore::open_in_memory()
+            .unwrap()
+            .with_schema_validation(registry);
+
+        let id = store.insert(make_item("note", "Valid")).unwrap();
+
+        let mut untitled = make_item("note", "Untitled");
+        untitled.payload.remove("title");
+        let err = store.insert(untitled.clone()).unwrap_err();
+        assert!(matches!(err, StoreError::Validation(ref msg) if msg.contains("title")));
+        assert!(store.get(untitled.id).unwrap().is_none());
+
+        let err = store
+            .update(id, vec![FieldMutation::SetPayload("title".into(), Value::Int(3))])
+            .unwrap_err();
+        assert!(matches!(err, StoreError::Validation(ref msg) if msg.contains("expected String")));
+        assert!(store
+            .update(id, vec![FieldMutation::RemovePayload("title".into())])
+            .is_err());
+        let got = store.get(id).unwrap().unwrap();
+        assert_eq!(got.payload.get("title"), Some(&Value::String("Valid".into())));
+
+        // Unregistered schemas aren't checked
+        let mut loose = make_item("scratch", "Anything");
+        loose.payload.remove("title");
+        assert!(store.insert(loose).is_ok());
+    }
+
     #[test]
     fn event_emission() {
         let store = SqliteItemStore::open_in_memory().unwrap();
@@ -4297,6 +4907,97 @@ mod tests {
         p
     }
 
+    #[test]
+    fn custom_events_replay_after_reopen() {
+        let path = tmp_db_path("custom_events");
+        {
+            let store = SqliteItemStore::open(&path).unwrap();
+            for count in [3, 2] {
+                store
+                    .emit_custom(CustomEvent::new(
+                        "imbib/library-synced",
+                        serde_json::json!({ "count": count }),
+                    ))
+                    .unwrap();
+            }
+            store
+                .emit_custom(CustomEvent::new(
+                    "imprint/newer-kind",
+                    serde_json::json!({ "x": 1 }),
+                ))
+                .unwrap();
+        }
+
+        let store = SqliteItemStore::open(&path).unwrap();
+        let mut registry: EventRegistry<u64> = EventRegistry::new();
+        registry
+            .register("imbib/library-synced", |synced, payload| {
+                *synced += payload["count"].as_u64().ok_or("missing count")?;
+                Ok(())
+            })
+            .unwrap();
+
+        let mut synced = 0;
+        let unknown = store.replay_custom_events(&registry, &mut synced).unwrap();
+        assert_eq!(synced, 5);
+        assert_eq!(
+            unknown,
+            vec![CustomEvent::new(
+                "imprint/newer-kind",
+                serde_json::json!({ "x": 1 })
+            )]
+        );
+
+        drop(store);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn change_feed_resumes_from_persisted_cursor() {
+        let path = tmp_db_path("change_feed");
+        let (id, other) = {
+            let store = SqliteItemStore::open(&path).unwrap();
+            let id = store.insert(make_item("test", "Watched")).unwrap();
+            store
+                .update(id, vec![FieldMutation::SetRead(true)])
+                .unwrap();
+
+            // Changes from before the subscription are replayed from the log
+            let feed = store.subscribe_feed("sidebar").unwrap();
+            let other = store.insert(make_item("test", "Other")).unwrap();
+            let seen: Vec<LoggedChange> = feed.try_iter().collect();
+            let changes: Vec<StoreChange> = seen.iter().map(|c| c.change).collect();
+            assert_eq!(
+                changes,
+                vec![
+                    StoreChange::Created(id),
+                    StoreChange::Updated(id),
+                    StoreChange::Created(other),
+                ]
+            );
+            assert!(seen.windows(2).all(|w| w[0].seq < w[1].seq));
+
+            // Acknowledge only the first two, then change more after that
+            store.ack_changes("sidebar", seen[1].seq).unwrap();
+            store.delete(id).unwrap();
+            (id, other)
+        };
+
+        let store = SqliteItemStore::open(&path).unwrap();
+        let feed = store.subscribe_feed("sidebar").unwrap();
+        assert_eq!(
+            feed.try_iter().map(|c| c.change).collect::<Vec<_>>(),
+            vec![StoreChange::Created(other), StoreChange::Deleted(id)]
+        );
+
+        // Consumers keep separate cursors
+        assert_eq!(store.feed_cursor("search-index").unwrap(), 0);
+        assert_eq!(store.changes_since(0).unwrap().len(), 4);
+
+        drop(store);
+        let _ = std::fs::remove_file(&path);
+    }
+
     /// File-backed stores must get a reader pool that is distinct from the
     /// writer connection, so a slow read does not contend with fast reads
     /// or with writes.
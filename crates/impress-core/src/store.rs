@@ -28,6 +28,8 @@ pub trait ItemStore: Send + Sync {
     fn insert(&self, item: Item) -> Result<ItemId, StoreError>;
 
     /// Insert multiple items atomically.
+    ///
+    /// Either every item is inserted or, if any insert fails, none are.
     fn insert_batch(&self, items: Vec<Item>) -> Result<Vec<ItemId>, StoreError>;
 
     /// Get an item by ID.
@@ -36,6 +38,18 @@ pub trait ItemStore: Send + Sync {
     /// Apply mutations to an existing item.
     fn update(&self, id: ItemId, mutations: Vec<FieldMutation>) -> Result<(), StoreError>;
 
+    /// Apply mutations only if the item is still at `expected_revision`,
+    /// returning the item's new revision.
+    ///
+    /// Fails with `StoreError::RevisionConflict`, applying nothing, if another
+    /// writer has changed the item since that revision was read.
+    fn update_if_revision(
+        &self,
+        id: ItemId,
+        mutations: Vec<FieldMutation>,
+        expected_revision: u64,
+    ) -> Result<u64, StoreError>;
+
     /// Delete an item by ID.
     fn delete(&self, id: ItemId) -> Result<(), StoreError>;
 
@@ -67,6 +81,13 @@ pub enum StoreError {
     #[error("Item already exists: {0}")]
     AlreadyExists(ItemId),
 
+    #[error("Revision conflict on {id}: expected {expected}, found {actual}")]
+    RevisionConflict {
+        id: ItemId,
+        expected: u64,
+        actual: u64,
+    },
+
     #[error("Schema not found: {0}")]
     SchemaNotFound(SchemaRef),
 
@@ -68,6 +68,224 @@ impl Author {
         }
         name
     }
+
+    /// Canonical identity key: normalized family name plus first initial.
+    ///
+    /// "J. Smith", "John Smith", and "Smith, John Q." all map to `"smith, j"`.
+    /// Authors without a given name map to just the family name.
+    pub fn canonical_key(&self) -> String {
+        let family = normalize_author_name(self.family_name.clone());
+        match self.given_tokens().first().and_then(|t| t.chars().next()) {
+            Some(initial) => format!("{}, {}", family, initial),
+            None => family,
+        }
+    }
+
+    /// Normalized family name plus the full normalized given name.
+    ///
+    /// Unlike [`canonical_key`](Self::canonical_key) this tells "John Smith"
+    /// (`"smith, john"`) from "Jane Smith" (`"smith, jane"`); "J. Q. Smith"
+    /// maps to `"smith, j q"`.
+    pub fn full_name_key(&self) -> String {
+        let family = normalize_author_name(self.family_name.clone());
+        let given = self.given_tokens();
+        if given.is_empty() {
+            family
+        } else {
+            format!("{}, {}", family, given.join(" "))
+        }
+    }
+
+    /// ORCID in canonical form (bare identifier, uppercase), if present.
+    pub fn normalized_orcid(&self) -> Option<String> {
+        let orcid = self.orcid.as_deref()?.trim();
+        let bare = orcid
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_start_matches("orcid.org/")
+            .trim();
+        if bare.is_empty() {
+            None
+        } else {
+            Some(bare.to_uppercase())
+        }
+    }
+
+    /// Name similarity score in `[0, 1]`, ignoring ORCID.
+    ///
+    /// Scoring is deterministic:
+    /// - different normalized family names: `0.0`
+    /// - matching full first names: `1.0`
+    /// - one side is an initial matching the other's first letter: `0.8`
+    /// - either side has no given name: `0.5`
+    /// - different full first names with the same initial: `0.2`
+    /// - different initials: `0.0`
+    pub fn name_similarity(&self, other: &Author) -> f32 {
+        let family = normalize_author_name(self.family_name.clone());
+        let other_family = normalize_author_name(other.family_name.clone());
+        if family != other_family {
+            return 0.0;
+        }
+
+        let ours = self.given_tokens();
+        let theirs = other.given_tokens();
+        let (Some(a), Some(b)) = (ours.first(), theirs.first()) else {
+            return 0.5;
+        };
+
+        if a.chars().next() != b.chars().next() {
+            0.0
+        } else if a == b {
+            1.0
+        } else if a.chars().count() == 1 || b.chars().count() == 1 {
+            0.8
+        } else {
+            0.2
+        }
+    }
+
+    /// Whether two author records plausibly refer to the same person.
+    ///
+    /// Matching ORCIDs are a definitive match and differing ORCIDs a definitive
+    /// mismatch. Otherwise the [`name_similarity`](Self::name_similarity) score
+    /// must reach `threshold`.
+    pub fn same_person(&self, other: &Author, threshold: f32) -> bool {
+        match (self.normalized_orcid(), other.normalized_orcid()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.name_similarity(other) >= threshold,
+        }
+    }
+
+    /// Normalized given-name tokens ("J. Q." -> ["j", "q"]).
+    fn given_tokens(&self) -> Vec<String> {
+        let Some(given) = &self.given_name else {
+            return Vec::new();
+        };
+        normalize_author_name(given.replace(['.', '-'], " "))
+            .split_whitespace()
+            .map(|t| {
+                t.chars()
+                    .filter(|c| c.is_ascii_alphanumeric())
+                    .collect::<String>()
+            })
+            .filter(|t| !t.is_empty())
+            .collect()
+    }
+
+    /// Whether the given name consists only of initials (or is missing).
+    fn has_only_initials(&self) -> bool {
+        self.given_tokens().iter().all(|t| t.chars().count() == 1)
+    }
+}
+
+/// Default similarity threshold for [`Author::same_person`].
+///
+/// Accepts an initial matching a full first name ("J. Smith" / "John Smith")
+/// but rejects differing full first names ("John Smith" / "Jane Smith").
+pub const DEFAULT_AUTHOR_MATCH_THRESHOLD: f32 = 0.75;
+
+/// Group author records that refer to the same person.
+///
+/// Records sharing an ORCID always group together. Records without an ORCID
+/// are grouped under their [`Author::canonical_key`] and split where full first
+/// names conflict; if a canonical key has exactly one ORCID among its records,
+/// an ORCID-less record with that key joins that ORCID's group when it is
+/// [`same_person`](Author::same_person) as every record with that key and
+/// ORCID.
+/// Initial-only names join the
+/// largest compatible group (earliest on ties). Groups are returned in order of
+/// first appearance, and each group lists indices into `authors`.
+pub fn group_authors(authors: &[Author]) -> Vec<Vec<usize>> {
+    use std::collections::HashMap;
+
+    // ORCIDs seen per canonical key, to let ORCID-less records adopt one
+    let mut orcids_by_key: HashMap<String, HashSet<String>> = HashMap::new();
+    let mut orcid_members: HashMap<String, Vec<usize>> = HashMap::new();
+    for (idx, author) in authors.iter().enumerate() {
+        if let Some(orcid) = author.normalized_orcid() {
+            orcids_by_key
+                .entry(author.canonical_key())
+                .or_default()
+                .insert(orcid.clone());
+            orcid_members.entry(orcid).or_default().push(idx);
+        }
+    }
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut orcid_groups: HashMap<String, usize> = HashMap::new();
+    let mut name_groups: HashMap<String, Vec<usize>> = HashMap::new();
+    let mut deferred = Vec::new();
+
+    for (idx, author) in authors.iter().enumerate() {
+        let key = author.canonical_key();
+        let orcid = author.normalized_orcid().or_else(|| {
+            orcids_by_key
+                .get(&key)
+                .filter(|set| set.len() == 1)
+                .and_then(|set| set.iter().next().cloned())
+                .filter(|orcid| {
+                    orcid_members[orcid]
+                        .iter()
+                        .map(|&m| &authors[m])
+                        .filter(|m| m.canonical_key() == key)
+                        .all(|m| m.same_person(author, DEFAULT_AUTHOR_MATCH_THRESHOLD))
+                })
+        });
+
+        if let Some(orcid) = orcid {
+            let group = *orcid_groups.entry(orcid).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(idx);
+        } else if author.has_only_initials() {
+            deferred.push(idx);
+        } else {
+            let candidates = name_groups.entry(key).or_default();
+            let existing = candidates.iter().copied().find(|&g| {
+                groups[g]
+                    .iter()
+                    .all(|&m| authors[m].same_person(author, DEFAULT_AUTHOR_MATCH_THRESHOLD))
+            });
+            match existing {
+                Some(g) => groups[g].push(idx),
+                None => {
+                    groups.push(vec![idx]);
+                    candidates.push(groups.len() - 1);
+                }
+            }
+        }
+    }
+
+    for idx in deferred {
+        let author = &authors[idx];
+        let candidates = name_groups.entry(author.canonical_key()).or_default();
+        let best = candidates
+            .iter()
+            .copied()
+            .filter(|&g| {
+                groups[g]
+                    .iter()
+                    .all(|&m| authors[m].same_person(author, DEFAULT_AUTHOR_MATCH_THRESHOLD))
+            })
+            .fold(None, |best: Option<usize>, g| match best {
+                Some(b) if groups[b].len() >= groups[g].len() => Some(b),
+                _ => Some(g),
+            });
+        match best {
+            Some(g) => groups[g].push(idx),
+            None => {
+                groups.push(vec![idx]);
+                candidates.push(groups.len() - 1);
+            }
+        }
+    }
+
+    groups.sort_by_key(|g| g.iter().copied().min());
+    for group in &mut groups {
+        group.sort_unstable();
+    }
+    groups
 }
 
 // ===== Author parsing utilities =====
@@ -384,6 +602,80 @@ mod tests {
         assert_eq!(with_suffix.display_name(), "Martin Luther King, Jr.");
     }
 
+    #[test]
+    fn test_canonical_key() {
+        let initial = Author::new("Smith".to_string()).with_given_name("J.");
+        let full = Author::new("Smith".to_string()).with_given_name("John");
+        let accented = Author::new("Müller".to_string()).with_given_name("Hans");
+        assert_eq!(initial.canonical_key(), "smith, j");
+        assert_eq!(full.canonical_key(), "smith, j");
+        assert_eq!(accented.canonical_key(), "muller, h");
+        assert_eq!(Author::new("Plato".to_string()).canonical_key(), "plato");
+
+        assert_eq!(full.full_name_key(), "smith, john");
+        assert_eq!(
+            Author::new("Smith".to_string())
+                .with_given_name("J. Q.")
+                .full_name_key(),
+            "smith, j q"
+        );
+        assert_eq!(Author::new("Plato".to_string()).full_name_key(), "plato");
+    }
+
+    #[test]
+    fn test_same_person() {
+        let j = Author::new("Smith".to_string()).with_given_name("J.");
+        let john = Author::new("Smith".to_string()).with_given_name("John");
+        let jane = Author::new("Smith".to_string()).with_given_name("Jane");
+
+        assert!(j.same_person(&john, DEFAULT_AUTHOR_MATCH_THRESHOLD));
+        assert!(!john.same_person(&jane, DEFAULT_AUTHOR_MATCH_THRESHOLD));
+
+        // ORCID equality is definitive in both directions
+        let orcid = "0000-0002-1825-0097";
+        let john_orcid = john.clone().with_orcid(orcid);
+        let jane_orcid = jane
+            .clone()
+            .with_orcid(format!("https://orcid.org/{}", orcid));
+        assert!(john_orcid.same_person(&jane_orcid, DEFAULT_AUTHOR_MATCH_THRESHOLD));
+        let other = j.clone().with_orcid("0000-0001-5109-3700");
+        assert!(!john_orcid.same_person(&other, DEFAULT_AUTHOR_MATCH_THRESHOLD));
+    }
+
+    #[test]
+    fn test_group_authors_by_canonical_key() {
+        let authors = parse_author_string("J. Smith and John Smith and Jane Smith".to_string());
+        let groups = group_authors(&authors);
+        assert_eq!(groups, vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_group_authors_orcid_precedence() {
+        let orcid = "0000-0002-1825-0097";
+        let authors = vec![
+            Author::new("Smith".to_string())
+                .with_given_name("John")
+                .with_orcid(orcid),
+            Author::new("Smith".to_string()).with_given_name("J."),
+            Author::new("Smyth".to_string())
+                .with_given_name("John")
+                .with_orcid(orcid),
+        ];
+        assert_eq!(group_authors(&authors), vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn test_group_authors_orcid_not_adopted_across_first_names() {
+        let authors = vec![
+            Author::new("Smith".to_string()).with_given_name("Jane"),
+            Author::new("Smith".to_string())
+                .with_given_name("John")
+                .with_orcid("0000-0002-1825-0097"),
+            Author::new("Smith".to_string()).with_given_name("J."),
+        ];
+        assert_eq!(group_authors(&authors), vec![vec![0], vec![1, 2]]);
+    }
+
     #[test]
     fn test_extract_first_author_last_name() {
         assert_eq!(
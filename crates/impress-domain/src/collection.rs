@@ -1,8 +1,122 @@
 //! Collection representation for grouping publications
 
+use super::{normalize_author_name, Publication};
 use serde::{Deserialize, Serialize};
 
+/// How the conditions of a [`SmartRule`] combine
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum SmartMatch {
+    /// Every condition must match
+    #[default]
+    All,
+    /// At least one condition must match
+    Any,
+}
+
+/// A single predicate evaluated against a publication
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SmartCondition {
+    /// Has the tag, or a descendant of it ("methods" matches "methods/sims")
+    HasTag { tag: String },
+    /// Does not have the tag or any descendant of it
+    LacksTag { tag: String },
+    /// Year within the inclusive range; open ends are unbounded
+    YearRange { min: Option<i32>, max: Option<i32> },
+    /// Some author's name contains the text (case- and accent-insensitive)
+    AuthorContains { name: String },
+    /// Flagged with the given color (e.g. "red")
+    FlagColor { color: String },
+    /// Has any flag
+    Flagged,
+    /// Has no flag
+    Unflagged,
+}
+
+impl SmartCondition {
+    /// Evaluate this condition against a publication
+    pub fn matches(&self, publication: &Publication) -> bool {
+        match self {
+            SmartCondition::HasTag { tag } => has_tag(publication, tag),
+            SmartCondition::LacksTag { tag } => !has_tag(publication, tag),
+            SmartCondition::YearRange { min, max } => publication.year.is_some_and(|year| {
+                min.is_none_or(|min| year >= min) && max.is_none_or(|max| year <= max)
+            }),
+            SmartCondition::AuthorContains { name } => {
+                let needle = normalize_author_name(name.clone());
+                !needle.is_empty()
+                    && publication
+                        .authors
+                        .iter()
+                        .any(|a| normalize_author_name(a.display_name()).contains(&needle))
+            }
+            SmartCondition::FlagColor { color } => publication
+                .flag_color
+                .as_deref()
+                .is_some_and(|c| c.eq_ignore_ascii_case(color)),
+            SmartCondition::Flagged => publication.flag_color.is_some(),
+            SmartCondition::Unflagged => publication.flag_color.is_none(),
+        }
+    }
+}
+
+fn has_tag(publication: &Publication, tag: &str) -> bool {
+    publication.tags.iter().any(|t| {
+        t.eq_ignore_ascii_case(tag)
+            || (t.len() > tag.len()
+                && t.is_char_boundary(tag.len())
+                && t[..tag.len()].eq_ignore_ascii_case(tag)
+                && t[tag.len()..].starts_with('/'))
+    })
+}
+
+/// A saved-search rule that dynamically selects publications
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SmartRule {
+    pub match_mode: SmartMatch,
+    pub conditions: Vec<SmartCondition>,
+}
+
+impl SmartRule {
+    /// Rule matching publications that satisfy every condition
+    pub fn all(conditions: Vec<SmartCondition>) -> Self {
+        Self {
+            match_mode: SmartMatch::All,
+            conditions,
+        }
+    }
+
+    /// Rule matching publications that satisfy at least one condition
+    pub fn any(conditions: Vec<SmartCondition>) -> Self {
+        Self {
+            match_mode: SmartMatch::Any,
+            conditions,
+        }
+    }
+
+    /// Evaluate the rule against a publication
+    ///
+    /// A rule with no conditions matches nothing, so an empty smart collection
+    /// stays empty rather than swallowing the whole library.
+    pub fn matches(&self, publication: &Publication) -> bool {
+        if self.conditions.is_empty() {
+            return false;
+        }
+        match self.match_mode {
+            SmartMatch::All => self.conditions.iter().all(|c| c.matches(publication)),
+            SmartMatch::Any => self.conditions.iter().any(|c| c.matches(publication)),
+        }
+    }
+}
+
 /// A collection (folder) for organizing publications
+///
+/// Membership is the union of explicit members (`member_ids`, or publications
+/// listing this collection in their `collections`) and, if set, publications
+/// matching `smart_rule`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Collection {
@@ -11,6 +125,10 @@ pub struct Collection {
     pub parent_id: Option<String>,
     pub is_smart: bool,
     pub smart_query: Option<String>,
+    #[serde(default)]
+    pub smart_rule: Option<SmartRule>,
+    #[serde(default)]
+    pub member_ids: Vec<String>,
     pub created_at: Option<String>,
 }
 
@@ -23,6 +141,8 @@ impl Collection {
             parent_id: None,
             is_smart: false,
             smart_query: None,
+            smart_rule: None,
+            member_ids: Vec::new(),
             created_at: None,
         }
     }
@@ -35,15 +155,56 @@ impl Collection {
             parent_id: None,
             is_smart: true,
             smart_query: Some(query),
+            smart_rule: None,
+            member_ids: Vec::new(),
             created_at: None,
         }
     }
 
+    /// Create a new smart collection with a structured rule
+    pub fn new_with_rule(name: String, rule: SmartRule) -> Self {
+        Self::new(name).with_rule(rule)
+    }
+
     /// Create a subcollection under a parent
     pub fn with_parent(mut self, parent_id: String) -> Self {
         self.parent_id = Some(parent_id);
         self
     }
+
+    /// Attach a smart rule, keeping any explicit members
+    pub fn with_rule(mut self, rule: SmartRule) -> Self {
+        self.smart_rule = Some(rule);
+        self.is_smart = true;
+        self
+    }
+
+    /// Add an explicit member by publication ID
+    pub fn add_member(&mut self, publication_id: String) {
+        if !self.member_ids.contains(&publication_id) {
+            self.member_ids.push(publication_id);
+        }
+    }
+
+    /// Remove an explicit member by publication ID
+    pub fn remove_member(&mut self, publication_id: &str) {
+        self.member_ids.retain(|id| id != publication_id);
+    }
+
+    /// Check whether a publication belongs to this collection
+    pub fn matches(&self, publication: &Publication) -> bool {
+        self.member_ids.contains(&publication.id)
+            || publication.collections.contains(&self.id)
+            || self
+                .smart_rule
+                .as_ref()
+                .is_some_and(|rule| rule.matches(publication))
+    }
+
+    /// Filter publications down to this collection's members
+    pub fn members<'a>(&self, publications: &'a [Publication]) -> Vec<&'a Publication> {
+        publications.iter().filter(|p| self.matches(p)).collect()
+    }
 }
 
 #[cfg(test)]
@@ -72,4 +233,93 @@ mod tests {
         let child = Collection::new("Project A".to_string()).with_parent(parent.id.clone());
         assert_eq!(child.parent_id, Some(parent.id));
     }
+
+    fn publication(cite_key: &str, year: Option<i32>, tags: &[&str]) -> Publication {
+        let mut pub_ = Publication::new(
+            cite_key.to_string(),
+            "article".to_string(),
+            format!("Paper {}", cite_key),
+        );
+        pub_.year = year;
+        pub_.tags = tags.iter().map(|t| t.to_string()).collect();
+        pub_
+    }
+
+    #[test]
+    fn test_smart_rule_year_range() {
+        let col = Collection::new_with_rule(
+            "2020s".to_string(),
+            SmartRule::all(vec![SmartCondition::YearRange {
+                min: Some(2020),
+                max: Some(2029),
+            }]),
+        );
+
+        assert!(col.matches(&publication("a", Some(2020), &[])));
+        assert!(col.matches(&publication("b", Some(2029), &[])));
+        assert!(!col.matches(&publication("c", Some(2019), &[])));
+        assert!(!col.matches(&publication("d", None, &[])));
+    }
+
+    #[test]
+    fn test_smart_rule_tags() {
+        let col = Collection::new_with_rule(
+            "Simulations".to_string(),
+            SmartRule::all(vec![
+                SmartCondition::HasTag {
+                    tag: "methods".to_string(),
+                },
+                SmartCondition::LacksTag {
+                    tag: "retracted".to_string(),
+                },
+            ]),
+        );
+
+        assert!(col.matches(&publication("a", None, &["methods"])));
+        assert!(col.matches(&publication("b", None, &["Methods/sims"])));
+        assert!(!col.matches(&publication("c", None, &["methodsology"])));
+        assert!(!col.matches(&publication("d", None, &["methods", "retracted"])));
+    }
+
+    #[test]
+    fn test_smart_rule_author_and_flags() {
+        let mut pub_ = publication("a", Some(2021), &[]);
+        pub_.authors
+            .push(crate::Author::new("Müller".to_string()).with_given_name("Hans"));
+        pub_.flag_color = Some("red".to_string());
+
+        let rule = SmartRule::any(vec![
+            SmartCondition::AuthorContains {
+                name: "muller".to_string(),
+            },
+            SmartCondition::Unflagged,
+        ]);
+        assert!(rule.matches(&pub_));
+        assert!(SmartCondition::FlagColor {
+            color: "Red".to_string()
+        }
+        .matches(&pub_));
+        assert!(!SmartRule::all(Vec::new()).matches(&pub_));
+    }
+
+    #[test]
+    fn test_static_and_smart_members_coexist() {
+        let mut col = Collection::new("Reading".to_string()).with_rule(SmartRule::all(vec![
+            SmartCondition::HasTag {
+                tag: "to-read".to_string(),
+            },
+        ]));
+        let pinned = publication("pinned", None, &[]);
+        let tagged = publication("tagged", None, &["to-read"]);
+        let other = publication("other", None, &[]);
+        col.add_member(pinned.id.clone());
+
+        let library = vec![pinned, tagged, other];
+        let members: Vec<&str> = col
+            .members(&library)
+            .iter()
+            .map(|p| p.cite_key.as_str())
+            .collect();
+        assert_eq!(members, vec!["pinned", "tagged"]);
+    }
 }
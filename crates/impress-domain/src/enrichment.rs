@@ -3,7 +3,9 @@
 //! Types for publication enrichment from external sources like Semantic Scholar,
 //! OpenAlex, and ADS.
 
+use crate::author::{group_authors, Author};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Open access availability status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
@@ -92,6 +94,56 @@ pub struct AuthorStats {
     pub affiliations: Vec<String>,
 }
 
+impl AuthorStats {
+    /// Aggregate author occurrences (one per authored paper) into per-person stats.
+    ///
+    /// Occurrences are grouped with [`group_authors`], so "J. Smith" and
+    /// "John Smith" count as one person while ORCID takes precedence over names.
+    /// Each entry's `author_id` is the ORCID when known, otherwise the
+    /// [full name key](Author::full_name_key) of the most complete name in the
+    /// group, so "John Smith" and "Jane Smith" stay apart. Groups that still
+    /// share a key get a `#2`, `#3`, ... suffix. `name` is the most complete
+    /// display name in the group.
+    pub fn aggregate(authors: &[Author]) -> Vec<AuthorStats> {
+        let mut seen_ids: HashMap<String, usize> = HashMap::new();
+        group_authors(authors)
+            .into_iter()
+            .map(|group| {
+                let members: Vec<&Author> = group.iter().map(|&i| &authors[i]).collect();
+                let fullest = members
+                    .iter()
+                    .copied()
+                    .max_by_key(|a| a.display_name().len())
+                    .unwrap_or(members[0]);
+                let mut author_id = members
+                    .iter()
+                    .find_map(|a| a.normalized_orcid())
+                    .unwrap_or_else(|| fullest.full_name_key());
+                let count = seen_ids.entry(author_id.clone()).or_insert(0);
+                *count += 1;
+                if *count > 1 {
+                    author_id = format!("{}#{}", author_id, count);
+                }
+                let name = fullest.display_name();
+                let mut affiliations: Vec<String> = Vec::new();
+                for affiliation in members.iter().filter_map(|a| a.affiliation.clone()) {
+                    if !affiliations.contains(&affiliation) {
+                        affiliations.push(affiliation);
+                    }
+                }
+                AuthorStats {
+                    author_id,
+                    name,
+                    h_index: None,
+                    citation_count: None,
+                    paper_count: Some(members.len() as i32),
+                    affiliations,
+                }
+            })
+            .collect()
+    }
+}
+
 /// Enrichment data for a publication
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -189,6 +241,62 @@ mod tests {
         assert!(EnrichmentPriority::RecentlyViewed < EnrichmentPriority::LibraryPaper);
     }
 
+    #[test]
+    fn test_author_stats_aggregate_groups_name_variants() {
+        let authors = vec![
+            Author::new("Smith".to_string()).with_given_name("J."),
+            Author::new("Smith".to_string()).with_given_name("John"),
+            Author::new("Smith".to_string()).with_given_name("Jane"),
+        ];
+        let stats = AuthorStats::aggregate(&authors);
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].author_id, "smith, john");
+        assert_eq!(stats[0].name, "John Smith");
+        assert_eq!(stats[0].paper_count, Some(2));
+        assert_eq!(stats[1].author_id, "smith, jane");
+        assert_eq!(stats[1].name, "Jane Smith");
+        assert_eq!(stats[1].paper_count, Some(1));
+    }
+
+    #[test]
+    fn test_author_stats_same_initial_authors_stay_apart() {
+        let affiliated = |given: &str, affiliation: &str| {
+            let mut author = Author::new("Smith".to_string()).with_given_name(given);
+            author.affiliation = Some(affiliation.to_string());
+            author
+        };
+        let authors = vec![
+            affiliated("John", "MIT"),
+            affiliated("Jane", "Caltech"),
+            Author::new("Smith".to_string()).with_given_name("Jane"),
+        ];
+        let stats = AuthorStats::aggregate(&authors);
+
+        let ids: Vec<&str> = stats.iter().map(|s| s.author_id.as_str()).collect();
+        assert_eq!(ids, vec!["smith, john", "smith, jane"]);
+        assert_eq!(stats[0].paper_count, Some(1));
+        assert_eq!(stats[0].affiliations, vec!["MIT"]);
+        assert_eq!(stats[1].paper_count, Some(2));
+        assert_eq!(stats[1].affiliations, vec!["Caltech"]);
+    }
+
+    #[test]
+    fn test_author_stats_aggregate_uses_orcid() {
+        let orcid = "0000-0002-1825-0097";
+        let authors = vec![
+            Author::new("Smith".to_string())
+                .with_given_name("Jane")
+                .with_orcid(orcid),
+            Author::new("Smith".to_string()).with_given_name("Jane"),
+        ];
+        let stats = AuthorStats::aggregate(&authors);
+
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].author_id, orcid);
+        assert_eq!(stats[0].paper_count, Some(2));
+    }
+
     #[test]
     fn test_enrichment_capability_display_names() {
         assert_eq!(
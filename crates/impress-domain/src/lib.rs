@@ -5,6 +5,7 @@
 //! - Author: Researcher with name, ORCID, affiliation
 //! - Identifiers: DOI, arXiv, ISBN, etc.
 //! - Annotation: PDF highlights, notes, drawings
+//! - Manuscript: Authored drafts and the publications they became
 //! - LinkedFile: PDF and attachment references
 //! - Collection, Tag, Library: Organization structures
 //! - Enrichment: Citation counts, open access status
@@ -18,6 +19,7 @@ pub mod figure;
 pub mod identifiers;
 pub mod library;
 pub mod linked_file;
+pub mod manuscript;
 pub mod publication;
 pub mod search_result;
 pub mod tag;
@@ -32,6 +34,7 @@ pub use figure::*;
 pub use identifiers::*;
 pub use library::*;
 pub use linked_file::*;
+pub use manuscript::*;
 pub use publication::*;
 pub use search_result::*;
 pub use tag::*;
@@ -1,6 +1,7 @@
 //! Linked file representation for PDFs and other attachments
 
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 /// Type of file storage
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -26,6 +27,9 @@ pub struct LinkedFile {
     pub file_size: Option<i64>,
     pub checksum: Option<String>,
     pub added_at: Option<String>,
+    /// Set when the file lives outside the library root and is stored absolute
+    #[serde(default)]
+    pub is_external: bool,
 }
 
 impl LinkedFile {
@@ -41,6 +45,7 @@ impl LinkedFile {
             file_size: None,
             checksum: None,
             added_at: None,
+            is_external: false,
         }
     }
 
@@ -56,6 +61,7 @@ impl LinkedFile {
             file_size: None,
             checksum: None,
             added_at: None,
+            is_external: false,
         }
     }
 
@@ -67,6 +73,85 @@ impl LinkedFile {
             .unwrap_or(false)
             || self.filename.to_lowercase().ends_with(".pdf")
     }
+
+    /// Whether the file is stored on a locally mounted filesystem
+    pub fn is_filesystem_backed(&self) -> bool {
+        matches!(
+            self.storage_type,
+            FileStorageType::Local | FileStorageType::ICloud
+        )
+    }
+
+    /// Absolute path stored on this file, if any (plain path or `file://` URL)
+    fn stored_absolute_path(&self) -> Option<PathBuf> {
+        if let Some(rel) = &self.relative_path {
+            let path = Path::new(rel);
+            if path.is_absolute() {
+                return Some(path.to_path_buf());
+            }
+        }
+        let url = self.absolute_url.as_deref()?;
+        let path = url.strip_prefix("file://").unwrap_or(url);
+        let path = Path::new(path);
+        path.is_absolute().then(|| path.to_path_buf())
+    }
+
+    /// Resolve the file's absolute path given the current library root.
+    ///
+    /// Returns `None` for remote storage (WebDAV, S3, URL), which has no local
+    /// path. Relative paths are joined onto `root`; absolute paths are
+    /// returned as stored.
+    pub fn resolve_path(&self, root: &Path) -> Option<PathBuf> {
+        if !self.is_filesystem_backed() {
+            return None;
+        }
+        if let Some(path) = self.stored_absolute_path() {
+            return Some(path);
+        }
+        self.relative_path.as_ref().map(|rel| root.join(rel))
+    }
+
+    /// Rebase this file from `old_root` onto `new_root` after a library move.
+    ///
+    /// Files under `old_root` are stored relative, so they resolve against
+    /// `new_root` afterwards. Files outside `old_root` keep their absolute path
+    /// and are flagged `is_external`. Remote files are returned unchanged.
+    pub fn rebase(&self, old_root: &Path, new_root: &Path) -> LinkedFile {
+        let mut rebased = self.clone();
+        if !self.is_filesystem_backed() {
+            return rebased;
+        }
+
+        let Some(absolute) = self.resolve_path(old_root) else {
+            return rebased;
+        };
+
+        match absolute.strip_prefix(old_root) {
+            Ok(relative) => {
+                rebased.relative_path = Some(relative.to_string_lossy().into_owned());
+                if self.stored_absolute_path().is_some() {
+                    rebased.absolute_url = None;
+                }
+                rebased.is_external = false;
+            }
+            Err(_) => {
+                // Outside the library: keep absolute, unless it already lives
+                // under the new root (e.g. the file was moved with the library)
+                match absolute.strip_prefix(new_root) {
+                    Ok(relative) => {
+                        rebased.relative_path = Some(relative.to_string_lossy().into_owned());
+                        rebased.absolute_url = None;
+                        rebased.is_external = false;
+                    }
+                    Err(_) => {
+                        rebased.relative_path = Some(absolute.to_string_lossy().into_owned());
+                        rebased.is_external = true;
+                    }
+                }
+            }
+        }
+        rebased
+    }
 }
 
 #[cfg(test)]
@@ -104,4 +189,60 @@ mod tests {
         not_pdf.mime_type = Some("text/csv".to_string());
         assert!(!not_pdf.is_pdf());
     }
+
+    #[test]
+    fn test_rebase_file_under_root() {
+        let old_root = Path::new("/Users/me/Library/Papers");
+        let new_root = Path::new("/Volumes/Archive/Papers");
+        let file = LinkedFile::new_local(
+            "paper.pdf".to_string(),
+            "/Users/me/Library/Papers/2024/paper.pdf".to_string(),
+        );
+
+        let rebased = file.rebase(old_root, new_root);
+        assert_eq!(rebased.relative_path, Some("2024/paper.pdf".to_string()));
+        assert!(!rebased.is_external);
+        assert_eq!(
+            rebased.resolve_path(new_root),
+            Some(PathBuf::from("/Volumes/Archive/Papers/2024/paper.pdf"))
+        );
+
+        // Already-relative paths stay relative and follow the new root
+        let relative = LinkedFile::new_local("a.pdf".to_string(), "a.pdf".to_string());
+        let rebased = relative.rebase(old_root, new_root);
+        assert_eq!(rebased.relative_path, Some("a.pdf".to_string()));
+        assert_eq!(
+            rebased.resolve_path(new_root),
+            Some(PathBuf::from("/Volumes/Archive/Papers/a.pdf"))
+        );
+    }
+
+    #[test]
+    fn test_rebase_external_file_untouched() {
+        let old_root = Path::new("/Users/me/Library/Papers");
+        let new_root = Path::new("/Volumes/Archive/Papers");
+        let file = LinkedFile::new_local(
+            "notes.pdf".to_string(),
+            "/Users/me/Desktop/notes.pdf".to_string(),
+        );
+
+        let rebased = file.rebase(old_root, new_root);
+        assert_eq!(rebased.relative_path, file.relative_path);
+        assert!(rebased.is_external);
+        assert_eq!(
+            rebased.resolve_path(new_root),
+            Some(PathBuf::from("/Users/me/Desktop/notes.pdf"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_remote_file() {
+        let file = LinkedFile::new_url(
+            "paper.pdf".to_string(),
+            "https://arxiv.org/pdf/1234".to_string(),
+        );
+        assert_eq!(file.resolve_path(Path::new("/tmp")), None);
+        let rebased = file.rebase(Path::new("/a"), Path::new("/b"));
+        assert_eq!(rebased, file);
+    }
 }
@@ -0,0 +1,268 @@
+//! Manuscript representation and manuscript–publication linkage
+//!
+//! A manuscript is an authored document tracked in imprint. Once it appears in
+//! print, it is linked to the library publication it became: the manuscript's
+//! `published_as` names the publication (by id, cite key, or DOI) and the
+//! publication's `manuscript_id` points back at the manuscript.
+
+use super::Publication;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Lifecycle state of a manuscript
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[serde(rename_all = "kebab-case")]
+pub enum ManuscriptStatus {
+    #[default]
+    Draft,
+    InternalReview,
+    Submitted,
+    InRevision,
+    Published,
+    Archived,
+}
+
+/// A manuscript (paper draft, thesis chapter, etc.) authored in imprint
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Manuscript {
+    pub id: String,
+    pub title: String,
+    pub status: ManuscriptStatus,
+    pub authors: Vec<String>,
+    pub journal_target: Option<String>,
+    /// Publication this manuscript became (cite key or DOI)
+    pub published_as: Option<String>,
+    pub created_at: Option<String>,  // ISO 8601
+    pub modified_at: Option<String>, // ISO 8601
+}
+
+impl Manuscript {
+    /// Create a new draft manuscript
+    pub fn new(title: String) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            title,
+            status: ManuscriptStatus::Draft,
+            authors: Vec::new(),
+            journal_target: None,
+            published_as: None,
+            created_at: None,
+            modified_at: None,
+        }
+    }
+}
+
+/// Errors from establishing or validating a manuscript–publication link
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum ManuscriptLinkError {
+    /// No publication matches the given id, cite key, or DOI
+    #[error("no publication matches '{0}'")]
+    PublicationNotFound(String),
+
+    /// The publication is already linked to a different manuscript
+    #[error("publication '{publication}' is already linked to manuscript '{manuscript_id}'")]
+    AlreadyLinked {
+        publication: String,
+        manuscript_id: String,
+    },
+
+    /// The manuscript names a publication that does not point back at it
+    #[error(
+        "manuscript '{manuscript_id}' is published as '{publication}', which does not link back"
+    )]
+    MissingBackLink {
+        manuscript_id: String,
+        publication: String,
+    },
+
+    /// The publication names a manuscript that does not point back at it
+    #[error(
+        "publication '{publication}' claims manuscript '{manuscript_id}', which does not link back"
+    )]
+    MissingForwardLink {
+        publication: String,
+        manuscript_id: String,
+    },
+}
+
+impl Publication {
+    /// Check whether `reference` names this publication by id, cite key, or DOI.
+    ///
+    /// DOI comparison is case-insensitive, as DOIs are.
+    pub fn matches_reference(&self, reference: &str) -> bool {
+        let reference = reference.trim();
+        !reference.is_empty()
+            && (self.id == reference
+                || self.cite_key == reference
+                || self
+                    .identifiers
+                    .doi
+                    .as_deref()
+                    .is_some_and(|doi| doi.eq_ignore_ascii_case(reference)))
+    }
+}
+
+/// Link a manuscript to the publication it became.
+///
+/// `publication_ref` is the publication's id, cite key, or DOI and must match
+/// one of `publications`; linking to an unknown publication is rejected. On
+/// success both sides are updated and the manuscript is marked published.
+/// Re-linking an already-linked pair is a no-op.
+pub fn link_manuscript(
+    manuscript: &mut Manuscript,
+    publications: &mut [Publication],
+    publication_ref: &str,
+) -> Result<(), ManuscriptLinkError> {
+    let publication = publications
+        .iter_mut()
+        .find(|p| p.matches_reference(publication_ref))
+        .ok_or_else(|| ManuscriptLinkError::PublicationNotFound(publication_ref.to_string()))?;
+
+    if let Some(existing) = &publication.manuscript_id {
+        if existing != &manuscript.id {
+            return Err(ManuscriptLinkError::AlreadyLinked {
+                publication: publication.cite_key.clone(),
+                manuscript_id: existing.clone(),
+            });
+        }
+    }
+
+    publication.manuscript_id = Some(manuscript.id.clone());
+    manuscript.published_as = Some(
+        publication
+            .identifiers
+            .doi
+            .clone()
+            .unwrap_or_else(|| publication.cite_key.clone()),
+    );
+    manuscript.status = ManuscriptStatus::Published;
+    Ok(())
+}
+
+/// Check that a manuscript's link is consistent on both sides.
+///
+/// Succeeds if the manuscript is unlinked and no publication claims it, or if
+/// `published_as` resolves to a publication whose `manuscript_id` is this
+/// manuscript. Reports the first one-sided link found otherwise.
+pub fn validate_manuscript_link(
+    manuscript: &Manuscript,
+    publications: &[Publication],
+) -> Result<(), ManuscriptLinkError> {
+    if let Some(reference) = &manuscript.published_as {
+        let publication = publications
+            .iter()
+            .find(|p| p.matches_reference(reference))
+            .ok_or_else(|| ManuscriptLinkError::PublicationNotFound(reference.clone()))?;
+        if publication.manuscript_id.as_deref() != Some(manuscript.id.as_str()) {
+            return Err(ManuscriptLinkError::MissingBackLink {
+                manuscript_id: manuscript.id.clone(),
+                publication: reference.clone(),
+            });
+        }
+    }
+
+    let linked_to_other = |p: &&Publication| {
+        manuscript
+            .published_as
+            .as_deref()
+            .is_none_or(|reference| !p.matches_reference(reference))
+    };
+    if let Some(publication) = publications
+        .iter()
+        .filter(|p| p.manuscript_id.as_deref() == Some(manuscript.id.as_str()))
+        .find(linked_to_other)
+    {
+        return Err(ManuscriptLinkError::MissingForwardLink {
+            publication: publication.cite_key.clone(),
+            manuscript_id: manuscript.id.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_publications() -> Vec<Publication> {
+        let mut published = Publication::new(
+            "smith2024dark".to_string(),
+            "article".to_string(),
+            "Dark Matter Halos".to_string(),
+        );
+        published.identifiers.doi = Some("10.1234/dm.2024".to_string());
+        let other = Publication::new(
+            "doe2023".to_string(),
+            "article".to_string(),
+            "Something Else".to_string(),
+        );
+        vec![published, other]
+    }
+
+    #[test]
+    fn test_link_manuscript_by_doi() {
+        let mut manuscript = Manuscript::new("Dark Matter Halos (draft)".to_string());
+        let mut publications = sample_publications();
+
+        link_manuscript(&mut manuscript, &mut publications, "10.1234/DM.2024").unwrap();
+
+        assert_eq!(manuscript.published_as.as_deref(), Some("10.1234/dm.2024"));
+        assert_eq!(manuscript.status, ManuscriptStatus::Published);
+        assert_eq!(publications[0].manuscript_id, Some(manuscript.id.clone()));
+        assert!(publications[1].manuscript_id.is_none());
+        assert_eq!(validate_manuscript_link(&manuscript, &publications), Ok(()));
+    }
+
+    #[test]
+    fn test_link_manuscript_unknown_publication() {
+        let mut manuscript = Manuscript::new("Orphan".to_string());
+        let mut publications = sample_publications();
+
+        assert_eq!(
+            link_manuscript(&mut manuscript, &mut publications, "nobody1999"),
+            Err(ManuscriptLinkError::PublicationNotFound(
+                "nobody1999".to_string()
+            ))
+        );
+        assert!(manuscript.published_as.is_none());
+        assert_eq!(manuscript.status, ManuscriptStatus::Draft);
+    }
+
+    #[test]
+    fn test_link_manuscript_already_linked() {
+        let mut first = Manuscript::new("First".to_string());
+        let mut second = Manuscript::new("Second".to_string());
+        let mut publications = sample_publications();
+
+        link_manuscript(&mut first, &mut publications, "smith2024dark").unwrap();
+        let err = link_manuscript(&mut second, &mut publications, "smith2024dark").unwrap_err();
+        assert!(matches!(err, ManuscriptLinkError::AlreadyLinked { .. }));
+    }
+
+    #[test]
+    fn test_detect_one_sided_links() {
+        let mut publications = sample_publications();
+
+        // Manuscript side only
+        let mut manuscript = Manuscript::new("Draft".to_string());
+        manuscript.published_as = Some("smith2024dark".to_string());
+        assert!(matches!(
+            validate_manuscript_link(&manuscript, &publications),
+            Err(ManuscriptLinkError::MissingBackLink { .. })
+        ));
+
+        // Publication side only
+        manuscript.published_as = None;
+        publications[1].manuscript_id = Some(manuscript.id.clone());
+        assert_eq!(
+            validate_manuscript_link(&manuscript, &publications),
+            Err(ManuscriptLinkError::MissingForwardLink {
+                publication: "doe2023".to_string(),
+                manuscript_id: manuscript.id.clone(),
+            })
+        );
+    }
+}
@@ -55,6 +55,10 @@ pub struct Publication {
     pub tags: Vec<String>,
     pub collections: Vec<String>,
     pub library_id: Option<String>,
+    pub flag_color: Option<String>, // Workflow flag; not exported to BibTeX
+
+    // Manuscript this publication originated from (see `manuscript` module)
+    pub manuscript_id: Option<String>,
 
     // Metadata
     pub created_at: Option<String>,  // ISO 8601
@@ -111,6 +115,8 @@ impl Publication {
             tags: Vec::new(),
             collections: Vec::new(),
             library_id: None,
+            flag_color: None,
+            manuscript_id: None,
             created_at: None,
             modified_at: None,
             source_id: None,
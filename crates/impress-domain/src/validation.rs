@@ -142,6 +142,81 @@ pub fn is_valid_ffi(publication: &Publication) -> bool {
     is_valid(publication)
 }
 
+// ===== Completeness scoring =====
+
+/// Fields contributing to [`Publication::completeness_score`] and their weights.
+///
+/// Weights sum to 1.0 and are listed in descending order of impact, so the
+/// order here is also the order gaps are reported in. "venue" is satisfied by
+/// any of journal, booktitle, publisher, school, or institution.
+pub const COMPLETENESS_WEIGHTS: [(&str, f32); 6] = [
+    ("title", 0.25),
+    ("authors", 0.20),
+    ("year", 0.15),
+    ("doi", 0.15),
+    ("abstract", 0.15),
+    ("venue", 0.10),
+];
+
+fn has_text(value: &Option<String>) -> bool {
+    value.as_deref().is_some_and(|v| !v.trim().is_empty())
+}
+
+impl Publication {
+    fn has_completeness_field(&self, field: &str) -> bool {
+        match field {
+            "title" => !self.title.trim().is_empty(),
+            "authors" => !self.authors.is_empty(),
+            "year" => self.year.is_some(),
+            "doi" => has_text(&self.identifiers.doi),
+            "abstract" => has_text(&self.abstract_text),
+            "venue" => {
+                has_text(&self.journal)
+                    || has_text(&self.booktitle)
+                    || has_text(&self.publisher)
+                    || has_text(&self.school)
+                    || has_text(&self.institution)
+            }
+            _ => false,
+        }
+    }
+
+    /// Score how complete this publication's metadata is, from 0.0 to 1.0.
+    ///
+    /// The score is the sum of [`COMPLETENESS_WEIGHTS`] for each present field
+    /// (blank strings count as missing). It depends only on the publication's
+    /// fields, so sorting a library by it is stable and reproducible.
+    pub fn completeness_score(&self) -> f32 {
+        COMPLETENESS_WEIGHTS
+            .iter()
+            .filter(|(field, _)| self.has_completeness_field(field))
+            .map(|(_, weight)| weight)
+            .sum::<f32>()
+            .min(1.0)
+    }
+
+    /// List missing scored fields, most impactful first.
+    pub fn missing_high_value_fields(&self) -> Vec<String> {
+        COMPLETENESS_WEIGHTS
+            .iter()
+            .filter(|(field, _)| !self.has_completeness_field(field))
+            .map(|(field, _)| field.to_string())
+            .collect()
+    }
+}
+
+#[cfg(feature = "uniffi")]
+#[uniffi::export]
+pub fn publication_completeness_score(publication: &Publication) -> f32 {
+    publication.completeness_score()
+}
+
+#[cfg(feature = "uniffi")]
+#[uniffi::export]
+pub fn publication_missing_high_value_fields(publication: &Publication) -> Vec<String> {
+    publication.missing_high_value_fields()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,4 +242,44 @@ mod tests {
         let invalid = Publication::new(String::new(), String::new(), String::new());
         assert!(!is_valid(&invalid));
     }
+
+    #[test]
+    fn test_completeness_weights_sum_to_one() {
+        let total: f32 = COMPLETENESS_WEIGHTS.iter().map(|(_, w)| w).sum();
+        assert!((total - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_completeness_full_publication() {
+        let mut pub_ = Publication::new(
+            "einstein1905".to_string(),
+            "article".to_string(),
+            "On the Electrodynamics of Moving Bodies".to_string(),
+        );
+        pub_.authors
+            .push(crate::Author::new("Einstein".to_string()).with_given_name("Albert"));
+        pub_.year = Some(1905);
+        pub_.identifiers.doi = Some("10.1002/andp.19053221004".to_string());
+        pub_.abstract_text = Some("We consider the electrodynamics...".to_string());
+        pub_.journal = Some("Annalen der Physik".to_string());
+
+        assert!((pub_.completeness_score() - 1.0).abs() < 1e-6);
+        assert!(pub_.missing_high_value_fields().is_empty());
+    }
+
+    #[test]
+    fn test_completeness_title_only() {
+        let mut pub_ = Publication::new(
+            "bare".to_string(),
+            "misc".to_string(),
+            "Just a Title".to_string(),
+        );
+        pub_.abstract_text = Some("   ".to_string());
+
+        assert!((pub_.completeness_score() - 0.25).abs() < 1e-6);
+        assert_eq!(
+            pub_.missing_high_value_fields(),
+            vec!["authors", "year", "doi", "abstract", "venue"]
+        );
+    }
 }
@@ -65,6 +65,15 @@ impl FlagStyle {
             _ => None,
         }
     }
+
+    /// Display name for UI.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Solid => "Solid",
+            Self::Dashed => "Dashed",
+            Self::Dotted => "Dotted",
+        }
+    }
 }
 
 /// Flag stripe length as fraction of row height.
@@ -88,6 +97,15 @@ impl FlagLength {
         }
     }
 
+    /// Display name for UI.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Full => "Full",
+            Self::Half => "Half",
+            Self::Quarter => "Quarter",
+        }
+    }
+
     /// Fraction of row height (0.0 to 1.0).
     pub fn fraction(&self) -> f64 {
         match self {
@@ -70,8 +70,8 @@ fn main() {
                 }
             };
 
-            match impress_flags::parse_flag_command(shorthand) {
-                Some(flag) => {
+            match impress_flags::parse_flag(shorthand) {
+                Ok(flag) => {
                     // Flag, FlagColor, FlagStyle, FlagLength all derive Serialize
                     match serde_json::to_string_pretty(&flag) {
                         Ok(json) => println!("{}", json),
@@ -81,12 +81,11 @@ fn main() {
                         }
                     }
                 }
-                None => {
+                Err(e) => {
                     eprintln!(
-                        "error: invalid flag shorthand: {:?}",
-                        shorthand
+                        "error: invalid flag shorthand {:?}: {}",
+                        shorthand, e
                     );
-                    eprintln!("color must be one of: r (red), a (amber), b (blue), g (gray)");
                     std::process::exit(1);
                 }
             }
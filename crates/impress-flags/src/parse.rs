@@ -4,65 +4,119 @@
 
 use crate::{Flag, FlagColor, FlagLength, FlagStyle};
 
-/// Parse a flag command string into a Flag.
+/// Longest valid shorthand: color, style, and length.
+const MAX_SHORTHAND_LEN: usize = 3;
+
+/// Why a flag shorthand failed to parse.
+///
+/// Positions are 0-based character offsets into the trimmed input.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(feature = "native", derive(uniffi::Error))]
+pub enum FlagParseError {
+    #[error("empty flag shorthand")]
+    Empty,
+
+    #[error("invalid color '{found}' at position {position}: expected r, a, b, or g")]
+    InvalidColor { found: String, position: u32 },
+
+    #[error("invalid style '{found}' at position {position}: expected s, -, or .")]
+    InvalidStyle { found: String, position: u32 },
+
+    #[error("invalid length '{found}' at position {position}: expected f, h, or q")]
+    InvalidLength { found: String, position: u32 },
+
+    #[error("flag shorthand too long: {length} characters (at most 3)")]
+    TooLong { length: u32 },
+}
+
+/// Parse a flag shorthand, reporting exactly which part is invalid.
 ///
 /// Grammar: `<color>[<style>][<length>]`
 /// - color: r/a/b/g (required)
 /// - style: s/-/. (optional, default: solid)
 /// - length: f/h/q (optional, default: full)
 ///
-/// Case insensitive.
+/// Style and length may appear in either order. Case insensitive.
 ///
 /// # Examples
 /// ```
-/// use impress_flags::parse_flag_command;
-/// assert!(parse_flag_command("r").is_some());
-/// assert!(parse_flag_command("a-h").is_some());
-/// assert!(parse_flag_command("xyz").is_none());
+/// use impress_flags::{parse_flag, FlagParseError};
+/// assert!(parse_flag("a-h").is_ok());
+/// assert_eq!(
+///     parse_flag("z-x"),
+///     Err(FlagParseError::InvalidColor { found: "z".into(), position: 0 })
+/// );
 /// ```
 #[cfg_attr(feature = "native", uniffi::export)]
-pub fn parse_flag_command(input: &str) -> Option<Flag> {
-    let input = input.trim();
-    if input.is_empty() {
-        return None;
+pub fn parse_flag(input: &str) -> Result<Flag, FlagParseError> {
+    let chars: Vec<char> = input.trim().chars().collect();
+    if chars.is_empty() {
+        return Err(FlagParseError::Empty);
+    }
+    if chars.len() > MAX_SHORTHAND_LEN {
+        return Err(FlagParseError::TooLong {
+            length: chars.len() as u32,
+        });
     }
-
-    let chars: Vec<char> = input.chars().collect();
 
     // First character: color (required)
-    let color = FlagColor::from_char(chars[0])?;
+    let color = FlagColor::from_char(chars[0]).ok_or(FlagParseError::InvalidColor {
+        found: chars[0].to_string(),
+        position: 0,
+    })?;
 
     // Remaining characters: style and length (optional, order flexible)
-    let mut style = FlagStyle::Solid;
-    let mut length = FlagLength::Full;
-    let mut style_set = false;
-    let mut length_set = false;
+    let mut style = None;
+    let mut length = None;
 
-    for &c in &chars[1..] {
-        if !style_set {
+    for (i, &c) in chars.iter().enumerate().skip(1) {
+        if style.is_none() {
             if let Some(s) = FlagStyle::from_char(c) {
-                style = s;
-                style_set = true;
+                style = Some(s);
                 continue;
             }
         }
-        if !length_set {
+        if length.is_none() {
             if let Some(l) = FlagLength::from_char(c) {
-                length = l;
-                length_set = true;
+                length = Some(l);
                 continue;
             }
         }
-        // Unknown character - ignore
+
+        // Blame the slot still waiting to be filled, style first
+        let found = c.to_string();
+        let position = i as u32;
+        return Err(if style.is_none() {
+            FlagParseError::InvalidStyle { found, position }
+        } else {
+            FlagParseError::InvalidLength { found, position }
+        });
     }
 
-    Some(Flag {
+    Ok(Flag {
         color,
-        style,
-        length,
+        style: style.unwrap_or_default(),
+        length: length.unwrap_or_default(),
     })
 }
 
+/// Parse a flag command string into a Flag.
+///
+/// Same grammar as [`parse_flag`], for callers that don't need to know why
+/// the input was rejected.
+///
+/// # Examples
+/// ```
+/// use impress_flags::parse_flag_command;
+/// assert!(parse_flag_command("r").is_some());
+/// assert!(parse_flag_command("a-h").is_some());
+/// assert!(parse_flag_command("xyz").is_none());
+/// ```
+#[cfg_attr(feature = "native", uniffi::export)]
+pub fn parse_flag_command(input: &str) -> Option<Flag> {
+    parse_flag(input).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,6 +163,68 @@ mod tests {
         assert_eq!(flag.color, FlagColor::Gray);
     }
 
+    #[test]
+    fn parse_length_before_style() {
+        let flag = parse_flag_command("rh.").unwrap();
+        assert_eq!(flag.style, FlagStyle::Dotted);
+        assert_eq!(flag.length, FlagLength::Half);
+    }
+
+    #[test]
+    fn error_invalid_color() {
+        assert_eq!(
+            parse_flag("z-x"),
+            Err(FlagParseError::InvalidColor {
+                found: "z".into(),
+                position: 0
+            })
+        );
+        assert_eq!(parse_flag("   "), Err(FlagParseError::Empty));
+    }
+
+    #[test]
+    fn error_invalid_style() {
+        assert_eq!(
+            parse_flag("ax"),
+            Err(FlagParseError::InvalidStyle {
+                found: "x".into(),
+                position: 1
+            })
+        );
+        // Length given first, so the second slot can only be a style
+        assert_eq!(
+            parse_flag("ahq"),
+            Err(FlagParseError::InvalidStyle {
+                found: "q".into(),
+                position: 2
+            })
+        );
+    }
+
+    #[test]
+    fn error_invalid_length() {
+        assert_eq!(
+            parse_flag("b.x"),
+            Err(FlagParseError::InvalidLength {
+                found: "x".into(),
+                position: 2
+            })
+        );
+        assert_eq!(
+            parse_flag(" a-- "),
+            Err(FlagParseError::InvalidLength {
+                found: "-".into(),
+                position: 2
+            })
+        );
+    }
+
+    #[test]
+    fn error_too_long() {
+        assert_eq!(parse_flag("a-hq"), Err(FlagParseError::TooLong { length: 4 }));
+        assert!(parse_flag_command("a-hq").is_none());
+    }
+
     #[test]
     fn parse_gray_solid_quarter() {
         let flag = parse_flag_command("gsq").unwrap();
@@ -1,7 +1,12 @@
 //! Flag query types for filtering publications by flag state.
+//!
+//! Besides the single-token `flag:red` form used in filter strings, the
+//! command bar accepts clause expressions such as
+//! `color:red,amber style:!dotted`, parsed by [`parse_flag_expression`].
 
-use crate::FlagColor;
+use crate::{Flag, FlagColor, FlagLength, FlagStyle};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// A query for filtering publications by flag state.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -15,6 +20,50 @@ pub enum FlagQuery {
     HasColor(FlagColor),
     /// Does not have a specific flag color
     NotColor(FlagColor),
+    /// Matches every clause (from a clause expression)
+    All(Vec<FlagClause>),
+}
+
+/// One `key:values` clause of a flag expression.
+///
+/// A clause matches when the flag has one of the listed values; a negated
+/// clause (`key:!values`) matches when it has none of them. Unflagged items
+/// only match negated clauses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "native", derive(uniffi::Enum))]
+pub enum FlagClause {
+    Color {
+        colors: Vec<FlagColor>,
+        negated: bool,
+    },
+    Style {
+        styles: Vec<FlagStyle>,
+        negated: bool,
+    },
+    Length {
+        lengths: Vec<FlagLength>,
+        negated: bool,
+    },
+}
+
+/// Errors from parsing a flag clause expression.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(feature = "native", derive(uniffi::Error))]
+pub enum FlagQueryError {
+    #[error("empty flag expression")]
+    Empty,
+
+    #[error("expected key:value, found '{clause}'")]
+    MalformedClause { clause: String },
+
+    #[error("unknown key '{key}': expected color, style, or length")]
+    UnknownKey { key: String },
+
+    #[error("missing value for '{key}'")]
+    MissingValue { key: String },
+
+    #[error("invalid {key} '{value}'")]
+    InvalidValue { key: String, value: String },
 }
 
 /// Parse a flag query from a filter string.
@@ -50,6 +99,116 @@ pub fn parse_flag_query(input: &str) -> Option<FlagQuery> {
     None
 }
 
+/// Parse a command-bar flag expression.
+///
+/// Syntax: whitespace-separated `key:values` clauses, ANDed together.
+/// - keys: `color`, `style`, `length`
+/// - values: comma-separated names or shorthands (`red,a`, `dotted`, `h`)
+/// - a leading `!` negates the clause: `style:!dotted`
+///
+/// A lone `flag:` token (see [`parse_flag_query`]) is also accepted, so the
+/// canonical string of any [`FlagQuery`] parses back to the same query.
+#[cfg_attr(feature = "native", uniffi::export)]
+pub fn parse_flag_expression(input: &str) -> Result<FlagQuery, FlagQueryError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(FlagQueryError::Empty);
+    }
+    if let Some(query) = parse_flag_query(input) {
+        return Ok(query);
+    }
+
+    input
+        .split_whitespace()
+        .map(parse_clause)
+        .collect::<Result<Vec<_>, _>>()
+        .map(FlagQuery::All)
+}
+
+fn parse_clause(clause: &str) -> Result<FlagClause, FlagQueryError> {
+    let (key, values) = clause
+        .split_once(':')
+        .ok_or_else(|| FlagQueryError::MalformedClause {
+            clause: clause.to_string(),
+        })?;
+    let key = key.to_lowercase();
+    let (negated, values) = match values.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, values),
+    };
+    let values: Vec<String> = values
+        .split(',')
+        .map(|v| v.trim().to_lowercase())
+        .filter(|v| !v.is_empty())
+        .collect();
+
+    match key.as_str() {
+        "color" => Ok(FlagClause::Color {
+            colors: parse_values(&key, &values, parse_color_name)?,
+            negated,
+        }),
+        "style" => Ok(FlagClause::Style {
+            styles: parse_values(&key, &values, parse_style_name)?,
+            negated,
+        }),
+        "length" => Ok(FlagClause::Length {
+            lengths: parse_values(&key, &values, parse_length_name)?,
+            negated,
+        }),
+        _ => Err(FlagQueryError::UnknownKey { key }),
+    }
+}
+
+/// Parse a clause's values, dropping duplicates but keeping their order.
+fn parse_values<T: PartialEq>(
+    key: &str,
+    values: &[String],
+    parse: fn(&str) -> Option<T>,
+) -> Result<Vec<T>, FlagQueryError> {
+    if values.is_empty() {
+        return Err(FlagQueryError::MissingValue {
+            key: key.to_string(),
+        });
+    }
+    let mut parsed = Vec::new();
+    for value in values {
+        let v = parse(value).ok_or_else(|| FlagQueryError::InvalidValue {
+            key: key.to_string(),
+            value: value.clone(),
+        })?;
+        if !parsed.contains(&v) {
+            parsed.push(v);
+        }
+    }
+    Ok(parsed)
+}
+
+fn parse_style_name(name: &str) -> Option<FlagStyle> {
+    match name {
+        "solid" => Some(FlagStyle::Solid),
+        "dashed" => Some(FlagStyle::Dashed),
+        "dotted" => Some(FlagStyle::Dotted),
+        _ => single_char(name).and_then(FlagStyle::from_char),
+    }
+}
+
+fn parse_length_name(name: &str) -> Option<FlagLength> {
+    match name {
+        "full" => Some(FlagLength::Full),
+        "half" => Some(FlagLength::Half),
+        "quarter" => Some(FlagLength::Quarter),
+        _ => single_char(name).and_then(FlagLength::from_char),
+    }
+}
+
+fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
 fn parse_color_name(name: &str) -> Option<FlagColor> {
     match name {
         "red" | "r" => Some(FlagColor::Red),
@@ -68,10 +227,88 @@ impl FlagQuery {
             FlagQuery::NoFlag => flag.is_none(),
             FlagQuery::HasColor(c) => flag == Some(c),
             FlagQuery::NotColor(c) => flag != Some(c),
+            FlagQuery::All(clauses) => clauses.iter().all(|clause| match clause {
+                FlagClause::Color { colors, negated } => {
+                    flag.is_some_and(|c| colors.contains(c)) != *negated
+                }
+                // Style and length aren't known from the color alone, but an
+                // unflagged item has none to match
+                FlagClause::Style { .. } | FlagClause::Length { .. } => {
+                    flag.is_some() || clause.matches(None)
+                }
+            }),
+        }
+    }
+
+    /// Test whether a full flag (color, style, and length) matches this query.
+    pub fn matches_flag(&self, flag: Option<&Flag>) -> bool {
+        match self {
+            FlagQuery::All(clauses) => clauses.iter().all(|clause| clause.matches(flag)),
+            _ => self.matches(flag.map(|f| &f.color)),
+        }
+    }
+}
+
+impl FlagClause {
+    /// Test whether a flag satisfies this clause.
+    pub fn matches(&self, flag: Option<&Flag>) -> bool {
+        let listed = match (self, flag) {
+            (_, None) => false,
+            (FlagClause::Color { colors, .. }, Some(f)) => colors.contains(&f.color),
+            (FlagClause::Style { styles, .. }, Some(f)) => styles.contains(&f.style),
+            (FlagClause::Length { lengths, .. }, Some(f)) => lengths.contains(&f.length),
+        };
+        listed != self.negated()
+    }
+
+    fn negated(&self) -> bool {
+        match self {
+            FlagClause::Color { negated, .. }
+            | FlagClause::Style { negated, .. }
+            | FlagClause::Length { negated, .. } => *negated,
+        }
+    }
+}
+
+/// Canonical string form, which parses back via [`parse_flag_expression`].
+impl fmt::Display for FlagQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlagQuery::AnyFlag => write!(f, "flag:*"),
+            FlagQuery::NoFlag => write!(f, "-flag:*"),
+            FlagQuery::HasColor(c) => write!(f, "flag:{}", c.display_name().to_lowercase()),
+            FlagQuery::NotColor(c) => write!(f, "-flag:{}", c.display_name().to_lowercase()),
+            FlagQuery::All(clauses) => {
+                for (i, clause) in clauses.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{}", clause)?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
+impl fmt::Display for FlagClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (key, names): (&str, Vec<&str>) = match self {
+            FlagClause::Color { colors, .. } => {
+                ("color", colors.iter().map(|c| c.display_name()).collect())
+            }
+            FlagClause::Style { styles, .. } => {
+                ("style", styles.iter().map(|s| s.display_name()).collect())
+            }
+            FlagClause::Length { lengths, .. } => {
+                ("length", lengths.iter().map(|l| l.display_name()).collect())
+            }
+        };
+        let bang = if self.negated() { "!" } else { "" };
+        write!(f, "{}:{}{}", key, bang, names.join(",").to_lowercase())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,6 +345,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_multi_clause_expression() {
+        let query = parse_flag_expression("color:red,a style:!dotted length:H").unwrap();
+        assert_eq!(
+            query,
+            FlagQuery::All(vec![
+                FlagClause::Color {
+                    colors: vec![FlagColor::Red, FlagColor::Amber],
+                    negated: false,
+                },
+                FlagClause::Style {
+                    styles: vec![FlagStyle::Dotted],
+                    negated: true,
+                },
+                FlagClause::Length {
+                    lengths: vec![FlagLength::Half],
+                    negated: false,
+                },
+            ])
+        );
+
+        let flag = |color, style, length| Flag {
+            color,
+            style,
+            length,
+        };
+        assert!(query.matches_flag(Some(&flag(
+            FlagColor::Amber,
+            FlagStyle::Dashed,
+            FlagLength::Half
+        ))));
+        assert!(!query.matches_flag(Some(&flag(
+            FlagColor::Amber,
+            FlagStyle::Dotted,
+            FlagLength::Half
+        ))));
+        assert!(!query.matches_flag(Some(&flag(
+            FlagColor::Blue,
+            FlagStyle::Solid,
+            FlagLength::Half
+        ))));
+        assert!(!query.matches_flag(None));
+        assert!(query.matches(Some(&FlagColor::Red)));
+        assert!(!query.matches(Some(&FlagColor::Gray)));
+    }
+
+    #[test]
+    fn expression_round_trip() {
+        let query = parse_flag_expression("  Color:red,amber,red   style:!dotted,- ").unwrap();
+        let canonical = query.to_string();
+        assert_eq!(canonical, "color:red,amber style:!dotted,dashed");
+        assert_eq!(parse_flag_expression(&canonical).unwrap(), query);
+
+        for simple in [
+            FlagQuery::AnyFlag,
+            FlagQuery::NoFlag,
+            FlagQuery::HasColor(FlagColor::Blue),
+            FlagQuery::NotColor(FlagColor::Gray),
+        ] {
+            assert_eq!(parse_flag_expression(&simple.to_string()).unwrap(), simple);
+        }
+    }
+
+    #[test]
+    fn expression_errors() {
+        assert_eq!(parse_flag_expression(" "), Err(FlagQueryError::Empty));
+        assert_eq!(
+            parse_flag_expression("color:red hue:blue"),
+            Err(FlagQueryError::UnknownKey { key: "hue".into() })
+        );
+        assert_eq!(
+            parse_flag_expression("style:wavy"),
+            Err(FlagQueryError::InvalidValue {
+                key: "style".into(),
+                value: "wavy".into()
+            })
+        );
+        assert_eq!(
+            parse_flag_expression("length:!"),
+            Err(FlagQueryError::MissingValue {
+                key: "length".into()
+            })
+        );
+        assert_eq!(
+            parse_flag_expression("red"),
+            Err(FlagQueryError::MalformedClause {
+                clause: "red".into()
+            })
+        );
+    }
+
     #[test]
     fn query_matches() {
         let red = FlagColor::Red;
@@ -122,4 +450,19 @@ mod tests {
         assert!(FlagQuery::NotColor(FlagColor::Red).matches(Some(&blue)));
         assert!(FlagQuery::NotColor(FlagColor::Red).matches(None));
     }
+
+    #[test]
+    fn style_and_length_clauses_need_a_flag() {
+        let style = parse_flag_expression("style:dashed").unwrap();
+        assert!(!style.matches(None));
+        assert!(style.matches(Some(&FlagColor::Red)));
+
+        let length = parse_flag_expression("length:H").unwrap();
+        assert!(!length.matches(None));
+
+        // A negated clause still matches unflagged items, as in matches_flag
+        let not_dotted = parse_flag_expression("style:!dotted").unwrap();
+        assert!(not_dotted.matches(None));
+        assert!(not_dotted.matches_flag(None));
+    }
 }
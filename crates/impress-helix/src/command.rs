@@ -50,6 +50,12 @@ pub enum HelixCommand {
     DocumentStart,
     /// Move to end of document.
     DocumentEnd,
+    /// Move to the first non-blank character of a line (1-based).
+    GotoLine { line: usize },
+    /// Move to the first non-blank character of the last line.
+    GotoLastLine,
+    /// Move to the line at a percentage of the document.
+    GotoPercent { percent: u8 },
 
     // Character finding
     /// Find character forward on line.
@@ -115,6 +121,18 @@ pub enum HelixCommand {
     /// Replace character under cursor.
     ReplaceCharacter { char: char },
 
+    // Surround
+    /// Wrap the selection in a delimiter pair (e.g., `ms(`, `ms$`).
+    SurroundAdd(char),
+    /// Replace the enclosing delimiter pair (e.g., `mr"'`).
+    SurroundChange(char, char),
+    /// Remove the enclosing delimiter pair (e.g., `md(`).
+    SurroundDelete(char),
+
+    // Registers
+    /// Select the register used by the next yank, delete, or paste.
+    SelectRegister { register: char },
+
     // Operator + Motion combinations
     /// Delete with motion (e.g., dw, d$, dd).
     DeleteMotion(Motion),
@@ -161,6 +179,9 @@ impl HelixCommand {
                 | HelixCommand::LineFirstNonBlank
                 | HelixCommand::DocumentStart
                 | HelixCommand::DocumentEnd
+                | HelixCommand::GotoLine { .. }
+                | HelixCommand::GotoLastLine
+                | HelixCommand::GotoPercent { .. }
                 | HelixCommand::FindCharacter { .. }
                 | HelixCommand::FindCharacterBackward { .. }
                 | HelixCommand::TillCharacter { .. }
@@ -186,6 +207,9 @@ impl HelixCommand {
                 | HelixCommand::Indent
                 | HelixCommand::Dedent
                 | HelixCommand::ReplaceCharacter { .. }
+                | HelixCommand::SurroundAdd(_)
+                | HelixCommand::SurroundChange(_, _)
+                | HelixCommand::SurroundDelete(_)
                 | HelixCommand::DeleteMotion(_)
                 | HelixCommand::ChangeMotion(_)
                 | HelixCommand::YankMotion(_)
@@ -217,6 +241,9 @@ impl HelixCommand {
             HelixCommand::LineFirstNonBlank => "First non-blank",
             HelixCommand::DocumentStart => "Document start",
             HelixCommand::DocumentEnd => "Document end",
+            HelixCommand::GotoLine { .. } => "Goto line",
+            HelixCommand::GotoLastLine => "Goto last line",
+            HelixCommand::GotoPercent { .. } => "Goto percent",
             HelixCommand::FindCharacter { .. } => "Find char",
             HelixCommand::FindCharacterBackward { .. } => "Find char backward",
             HelixCommand::TillCharacter { .. } => "Till char",
@@ -243,6 +270,10 @@ impl HelixCommand {
             HelixCommand::Indent => "Indent",
             HelixCommand::Dedent => "Dedent",
             HelixCommand::ReplaceCharacter { .. } => "Replace char",
+            HelixCommand::SurroundAdd(_) => "Surround add",
+            HelixCommand::SurroundChange(_, _) => "Surround change",
+            HelixCommand::SurroundDelete(_) => "Surround delete",
+            HelixCommand::SelectRegister { .. } => "Select register",
             HelixCommand::DeleteMotion(_) => "Delete motion",
             HelixCommand::ChangeMotion(_) => "Change motion",
             HelixCommand::YankMotion(_) => "Yank motion",
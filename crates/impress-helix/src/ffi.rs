@@ -395,6 +395,8 @@ pub enum FfiMotion {
     DocumentStart,
     DocumentEnd,
     GotoLine { line: u32 },
+    GotoPercent { percent: u8 },
+    LastLine,
     ParagraphForward { count: u32 },
     ParagraphBackward { count: u32 },
     FindChar { char: String, count: u32 },
@@ -434,6 +436,8 @@ impl From<FfiMotion> for crate::motion::Motion {
             FfiMotion::DocumentStart => crate::motion::Motion::DocumentStart,
             FfiMotion::DocumentEnd => crate::motion::Motion::DocumentEnd,
             FfiMotion::GotoLine { line } => crate::motion::Motion::GotoLine(line as usize),
+            FfiMotion::GotoPercent { percent } => crate::motion::Motion::GotoPercent(percent),
+            FfiMotion::LastLine => crate::motion::Motion::LastLine,
             FfiMotion::ParagraphForward { count } => {
                 crate::motion::Motion::ParagraphForward(count as usize)
             }
@@ -494,6 +498,9 @@ pub enum FfiHelixCommand {
     // Document movement
     DocumentStart,
     DocumentEnd,
+    GotoLine { line: u32 },
+    GotoLastLine,
+    GotoPercent { percent: u8 },
 
     // Character finding
     FindCharacter { char: String, count: u32 },
@@ -533,6 +540,14 @@ pub enum FfiHelixCommand {
     Dedent,
     ReplaceCharacter { char: String },
 
+    // Surround
+    SurroundAdd { char: String },
+    SurroundChange { from: String, to: String },
+    SurroundDelete { char: String },
+
+    // Registers
+    SelectRegister { register: String },
+
     // Operator + Motion combinations
     DeleteMotion { motion: FfiMotion },
     ChangeMotion { motion: FfiMotion },
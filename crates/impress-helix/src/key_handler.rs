@@ -53,6 +53,16 @@ pub enum PendingCharacterOperation {
     TillBackward,
     /// Replace character (r).
     Replace,
+    /// Select register for the next yank, delete, or paste (").
+    SelectRegister,
+    /// Surround add (ms).
+    SurroundAdd,
+    /// Surround delete (md).
+    SurroundDelete,
+    /// Surround change, awaiting the pair to replace (mr).
+    SurroundChangeFrom,
+    /// Surround change, awaiting the replacement pair.
+    SurroundChangeTo(char),
 }
 
 /// Key modifiers.
@@ -89,6 +99,8 @@ pub struct HelixKeyHandler {
     pending_key: Option<char>,
     /// Count prefix for commands (e.g., "3" in "3j").
     count_prefix: Option<usize>,
+    /// Count typed after an operator (e.g., "5" in "d5G").
+    operator_count: Option<usize>,
     /// Pending character operation (f, t, r awaiting a character).
     pending_char_op: Option<PendingCharacterOperation>,
     /// Last find operation for ; and , repeats.
@@ -105,6 +117,7 @@ impl HelixKeyHandler {
         Self {
             pending_key: None,
             count_prefix: None,
+            operator_count: None,
             pending_char_op: None,
             last_find_op: None,
             pending_operator: None,
@@ -116,6 +129,7 @@ impl HelixKeyHandler {
     pub fn reset(&mut self) {
         self.pending_key = None;
         self.count_prefix = None;
+        self.operator_count = None;
         self.pending_char_op = None;
         self.pending_operator = None;
         self.pending_text_object_modifier = None;
@@ -193,7 +207,16 @@ impl HelixKeyHandler {
 
         // Handle pending operator (d, c, y) waiting for motion or text object
         if let Some(operator) = self.pending_operator {
-            let count = self.count_prefix.take().unwrap_or(1);
+            // Counts before and after the operator multiply ("2d3w" = "d6w")
+            if key.is_ascii_digit() && (self.operator_count.is_some() || key != '0') {
+                let digit = key.to_digit(10).unwrap() as usize;
+                self.operator_count = Some(self.operator_count.unwrap_or(0) * 10 + digit);
+                return HelixKeyResult::Pending;
+            }
+            let count = match (self.count_prefix.take(), self.operator_count.take()) {
+                (None, None) => None,
+                (before, after) => Some(before.unwrap_or(1) * after.unwrap_or(1)),
+            };
             return self.handle_pending_operator(operator, key, count, modifiers);
         }
 
@@ -213,13 +236,26 @@ impl HelixKeyHandler {
         }
 
         // Get count and reset
-        let count = self.count_prefix.take().unwrap_or(1);
+        let explicit_count = self.count_prefix.take();
+        let count = explicit_count.unwrap_or(1);
 
         // Handle pending key sequences (e.g., "gg")
         if let Some(pending) = self.pending_key.take() {
             return self.handle_pending_key(pending, key, count, modifiers);
         }
 
+        // <n>G and <n>% jump to a line or a percentage of the document
+        if let Some(n) = explicit_count {
+            match key {
+                'G' => return HelixKeyResult::Command(HelixCommand::GotoLine { line: n }),
+                '%' => {
+                    let percent = n.min(100) as u8;
+                    return HelixKeyResult::Command(HelixCommand::GotoPercent { percent });
+                }
+                _ => {}
+            }
+        }
+
         // Normal/Select mode key handling
         self.handle_normal_key(key, count, modifiers)
     }
@@ -229,9 +265,11 @@ impl HelixKeyHandler {
         &mut self,
         operator: PendingOperator,
         key: char,
-        count: usize,
+        explicit_count: Option<usize>,
         _modifiers: &KeyModifiers,
     ) -> HelixKeyResult {
+        let count = explicit_count.unwrap_or(1);
+
         // Check for doubled operator (dd, cc, yy)
         let is_same_operator = matches!(
             (operator, key),
@@ -260,8 +298,13 @@ impl HelixKeyHandler {
             _ => {}
         }
 
-        // Try to parse as motion
-        if let Some(motion) = self.key_to_motion(key, count) {
+        // d<n>G and d<n>% target a line, like <n>G and <n>% without an operator
+        let motion = match (key, explicit_count) {
+            ('G', Some(n)) => Some(Motion::GotoLine(n)),
+            ('%', Some(n)) => Some(Motion::GotoPercent(n.min(100) as u8)),
+            _ => self.key_to_motion(key, count),
+        };
+        if let Some(motion) = motion {
             self.pending_operator = None;
             return self.create_motion_command(operator, motion);
         }
@@ -320,7 +363,7 @@ impl HelixKeyHandler {
             '^' => Some(Motion::LineFirstNonBlank),
 
             // Document movement
-            'G' => Some(Motion::DocumentEnd),
+            'G' => Some(Motion::LastLine),
 
             // Paragraph movement
             '{' => Some(Motion::ParagraphBackward(count)),
@@ -368,8 +411,14 @@ impl HelixKeyHandler {
         op: PendingCharacterOperation,
         char: char,
     ) -> HelixKeyResult {
-        // Store for ; and , repeats (except replace)
-        if op != PendingCharacterOperation::Replace {
+        // Store find/till for ; and , repeats
+        if matches!(
+            op,
+            PendingCharacterOperation::FindForward
+                | PendingCharacterOperation::FindBackward
+                | PendingCharacterOperation::TillForward
+                | PendingCharacterOperation::TillBackward
+        ) {
             self.last_find_op = Some((char, op));
         }
 
@@ -387,6 +436,18 @@ impl HelixKeyHandler {
                 HelixCommand::TillCharacterBackward { char, count: 1 }
             }
             PendingCharacterOperation::Replace => HelixCommand::ReplaceCharacter { char },
+            PendingCharacterOperation::SelectRegister => {
+                HelixCommand::SelectRegister { register: char }
+            }
+            PendingCharacterOperation::SurroundAdd => HelixCommand::SurroundAdd(char),
+            PendingCharacterOperation::SurroundDelete => HelixCommand::SurroundDelete(char),
+            PendingCharacterOperation::SurroundChangeFrom => {
+                self.pending_char_op = Some(PendingCharacterOperation::SurroundChangeTo(char));
+                return HelixKeyResult::AwaitingCharacter;
+            }
+            PendingCharacterOperation::SurroundChangeTo(from) => {
+                HelixCommand::SurroundChange(from, char)
+            }
         };
 
         HelixKeyResult::Command(command)
@@ -404,6 +465,19 @@ impl HelixKeyHandler {
             ('g', 'g') => HelixKeyResult::Command(HelixCommand::DocumentStart),
             // ge - go to end of previous word
             ('g', 'e') => HelixKeyResult::Command(HelixCommand::WordEnd { count }),
+            // ms, md, mr - surround add, delete, change
+            ('m', 's') => {
+                self.pending_char_op = Some(PendingCharacterOperation::SurroundAdd);
+                HelixKeyResult::AwaitingCharacter
+            }
+            ('m', 'd') => {
+                self.pending_char_op = Some(PendingCharacterOperation::SurroundDelete);
+                HelixKeyResult::AwaitingCharacter
+            }
+            ('m', 'r') => {
+                self.pending_char_op = Some(PendingCharacterOperation::SurroundChangeFrom);
+                HelixKeyResult::AwaitingCharacter
+            }
             // Unknown g sequence
             ('g', _) => HelixKeyResult::Consumed,
             _ => HelixKeyResult::Consumed,
@@ -447,12 +521,18 @@ impl HelixKeyHandler {
             '^' => HelixKeyResult::Command(HelixCommand::LineFirstNonBlank),
 
             // Document movement
-            'G' => HelixKeyResult::Command(HelixCommand::DocumentEnd),
+            'G' => HelixKeyResult::Command(HelixCommand::GotoLastLine),
             'g' => {
                 self.pending_key = Some('g');
                 HelixKeyResult::Pending
             }
 
+            // Surround (ms, md, mr)
+            'm' => {
+                self.pending_key = Some('m');
+                HelixKeyResult::Pending
+            }
+
             // Character finding
             'f' => {
                 self.pending_char_op = Some(PendingCharacterOperation::FindForward);
@@ -510,6 +590,12 @@ impl HelixKeyHandler {
                 HelixKeyResult::Pending
             }
 
+            // Register selection
+            '"' => {
+                self.pending_char_op = Some(PendingCharacterOperation::SelectRegister);
+                HelixKeyResult::AwaitingCharacter
+            }
+
             // Immediate editing commands
             'p' => HelixKeyResult::Command(HelixCommand::PasteAfter),
             'P' => HelixKeyResult::Command(HelixCommand::PasteBefore),
@@ -611,6 +697,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_goto_line_and_percent() {
+        let mut handler = HelixKeyHandler::new();
+        let mods = KeyModifiers::default();
+
+        assert_eq!(
+            handler.handle_key('G', HelixMode::Normal, &mods),
+            HelixKeyResult::Command(HelixCommand::GotoLastLine)
+        );
+
+        handler.handle_key('5', HelixMode::Normal, &mods);
+        assert_eq!(
+            handler.handle_key('G', HelixMode::Normal, &mods),
+            HelixKeyResult::Command(HelixCommand::GotoLine { line: 5 })
+        );
+
+        handler.handle_key('5', HelixMode::Normal, &mods);
+        handler.handle_key('0', HelixMode::Normal, &mods);
+        assert_eq!(
+            handler.handle_key('%', HelixMode::Normal, &mods),
+            HelixKeyResult::Command(HelixCommand::GotoPercent { percent: 50 })
+        );
+
+        // Without a count, % still selects the whole document
+        assert_eq!(
+            handler.handle_key('%', HelixMode::Normal, &mods),
+            HelixKeyResult::Command(HelixCommand::SelectAll)
+        );
+    }
+
+    #[test]
+    fn test_surround_keys() {
+        let mut handler = HelixKeyHandler::new();
+        let mods = KeyModifiers::default();
+
+        handler.handle_key('m', HelixMode::Normal, &mods);
+        handler.handle_key('s', HelixMode::Normal, &mods);
+        assert_eq!(
+            handler.handle_key('$', HelixMode::Normal, &mods),
+            HelixKeyResult::Command(HelixCommand::SurroundAdd('$'))
+        );
+
+        handler.handle_key('m', HelixMode::Normal, &mods);
+        handler.handle_key('r', HelixMode::Normal, &mods);
+        assert_eq!(
+            handler.handle_key('"', HelixMode::Normal, &mods),
+            HelixKeyResult::AwaitingCharacter
+        );
+        assert_eq!(
+            handler.handle_key('\'', HelixMode::Normal, &mods),
+            HelixKeyResult::Command(HelixCommand::SurroundChange('"', '\''))
+        );
+        assert_eq!(handler.last_find_op(), None);
+    }
+
     #[test]
     fn test_find_character() {
         let mut handler = HelixKeyHandler::new();
@@ -645,6 +786,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_delete_to_line() {
+        let mut handler = HelixKeyHandler::new();
+        let mods = KeyModifiers::default();
+        let mut keys = |keys: &str| {
+            keys.chars()
+                .map(|key| handler.handle_key(key, HelixMode::Normal, &mods))
+                .last()
+                .unwrap()
+        };
+
+        assert_eq!(
+            keys("dG"),
+            HelixKeyResult::Command(HelixCommand::DeleteMotion(Motion::LastLine))
+        );
+        assert_eq!(
+            keys("d12G"),
+            HelixKeyResult::Command(HelixCommand::DeleteMotion(Motion::GotoLine(12)))
+        );
+        assert_eq!(
+            keys("y50%"),
+            HelixKeyResult::Command(HelixCommand::YankMotion(Motion::GotoPercent(50)))
+        );
+        assert_eq!(
+            keys("2d3w"),
+            HelixKeyResult::Command(HelixCommand::DeleteMotion(Motion::WordForward(6)))
+        );
+        assert_eq!(
+            keys("d0"),
+            HelixKeyResult::Command(HelixCommand::DeleteMotion(Motion::LineStart))
+        );
+    }
+
     #[test]
     fn test_delete_line_dd() {
         let mut handler = HelixKeyHandler::new();
@@ -9,6 +9,7 @@
 //! - **Modal editing**: Normal, Insert, and Select modes
 //! - **Operator + Motion**: Vim-style compositions like `dw`, `c$`, `y2j`
 //! - **Text Objects**: Inner/Around text objects like `diw`, `ci"`, `da(`
+//! - **Surround**: Add, change, and delete delimiter pairs like `ms(`, `mr"'`, `md[`
 //! - **Space-Mode**: Helix-style application command menu
 //! - **Trie-based Keymap**: Efficient multi-key sequences with which-key support
 //!
@@ -49,8 +50,10 @@ mod key_handler;
 pub mod keymap;
 mod mode;
 pub mod motion;
+pub mod register;
 pub mod space;
 mod state;
+pub mod surround;
 pub mod text_engine;
 pub mod text_object;
 
@@ -65,6 +68,7 @@ pub use key_handler::{
 pub use keymap::{KeyEvent, KeyTrie, KeyTrieNode, Keymap, KeymapResult, MappableCommand};
 pub use mode::HelixMode;
 pub use motion::Motion;
+pub use register::{Register, Registers};
 pub use space::{build_space_mode_keymap, SpaceCommand};
 pub use state::{HelixState, KeyHandleResult};
 pub use text_engine::HelixTextEngine;
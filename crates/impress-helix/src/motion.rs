@@ -50,6 +50,10 @@ pub enum Motion {
     DocumentEnd,
     /// Move to a specific line number.
     GotoLine(usize),
+    /// Move to the line at a percentage (0-100) of the document.
+    GotoPercent(u8),
+    /// Move to the last line of the document.
+    LastLine,
 
     // Paragraph movements
     /// Move forward by count paragraphs.
@@ -92,6 +96,8 @@ impl Motion {
                 | Motion::ParagraphForward(_)
                 | Motion::ParagraphBackward(_)
                 | Motion::GotoLine(_)
+                | Motion::GotoPercent(_)
+                | Motion::LastLine
         )
     }
 
@@ -132,6 +138,7 @@ impl Motion {
             | Motion::TillChar(_, n)
             | Motion::TillCharBackward(_, n)
             | Motion::GotoLine(n) => Some(*n),
+            Motion::GotoPercent(percent) => Some(*percent as usize),
             _ => None,
         }
     }
@@ -156,6 +163,7 @@ impl Motion {
             Motion::TillChar(c, _) => Motion::TillChar(c, count),
             Motion::TillCharBackward(c, _) => Motion::TillCharBackward(c, count),
             Motion::GotoLine(_) => Motion::GotoLine(count),
+            Motion::GotoPercent(_) => Motion::GotoPercent(count.min(100) as u8),
             // These don't have counts
             other => other,
         }
@@ -178,6 +186,8 @@ mod tests {
         assert!(Motion::Up(1).is_linewise());
         assert!(Motion::Down(5).is_linewise());
         assert!(Motion::ParagraphForward(1).is_linewise());
+        assert!(Motion::GotoPercent(50).is_linewise());
+        assert!(Motion::LastLine.is_linewise());
         assert!(!Motion::WordForward(1).is_linewise());
         assert!(!Motion::FindChar('a', 1).is_linewise());
     }
@@ -198,6 +208,10 @@ mod tests {
             Motion::FindChar('a', 1).with_count(3),
             Motion::FindChar('a', 3)
         );
+        assert_eq!(
+            Motion::GotoPercent(1).with_count(250),
+            Motion::GotoPercent(100)
+        );
         // Motions without counts remain unchanged
         assert_eq!(Motion::LineStart.with_count(5), Motion::LineStart);
     }
@@ -0,0 +1,170 @@
+//! Registers for yank, delete, and paste.
+//!
+//! Follows the Helix/Vim conventions:
+//!
+//! - `"` is the unnamed register, used when no register is selected
+//! - `0` holds the last yank
+//! - `1`-`9` hold the last deletes, shifting down on each new delete
+//! - `a`-`z` are named registers; `A`-`Z` append to them
+//! - `_` is the black hole register, which discards writes
+
+use std::collections::HashMap;
+
+/// The unnamed register.
+pub const UNNAMED_REGISTER: char = '"';
+
+/// The register holding the last yank.
+pub const YANK_REGISTER: char = '0';
+
+/// The black hole register.
+pub const BLACK_HOLE_REGISTER: char = '_';
+
+/// Contents of a single register.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Register {
+    /// The stored text.
+    pub text: String,
+    /// Whether the text was captured linewise.
+    pub linewise: bool,
+}
+
+/// Store of all registers, keyed by name.
+#[derive(Debug, Clone, Default)]
+pub struct Registers {
+    values: HashMap<char, Register>,
+}
+
+impl Registers {
+    /// Create an empty register store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `name` is a register that can be selected with `"`.
+    pub fn is_valid_name(name: char) -> bool {
+        name == UNNAMED_REGISTER || name == BLACK_HOLE_REGISTER || name.is_ascii_alphanumeric()
+    }
+
+    /// Get a register's contents. Uppercase names read their lowercase register.
+    pub fn get(&self, name: char) -> Option<&Register> {
+        self.values.get(&name.to_ascii_lowercase())
+    }
+
+    /// Get the unnamed register's contents.
+    pub fn unnamed(&self) -> Option<&Register> {
+        self.get(UNNAMED_REGISTER)
+    }
+
+    /// Record a yank into `name`, or into `0` when no register is selected.
+    ///
+    /// The unnamed register always mirrors the result.
+    pub fn yank(&mut self, name: Option<char>, text: &str, linewise: bool) {
+        match name {
+            Some(BLACK_HOLE_REGISTER) => {}
+            None | Some(UNNAMED_REGISTER) => {
+                self.store(YANK_REGISTER, text, linewise);
+                self.store(UNNAMED_REGISTER, text, linewise);
+            }
+            Some(name) => self.write_named(name, text, linewise),
+        }
+    }
+
+    /// Record a delete into `name`, or rotate it into `1`-`9` when no
+    /// register is selected.
+    ///
+    /// The unnamed register always mirrors the result.
+    pub fn delete(&mut self, name: Option<char>, text: &str, linewise: bool) {
+        match name {
+            Some(BLACK_HOLE_REGISTER) => {}
+            None | Some(UNNAMED_REGISTER) => {
+                for n in (1..9).rev() {
+                    let from = char::from(b'0' + n);
+                    if let Some(register) = self.values.remove(&from) {
+                        self.values.insert(char::from(b'0' + n + 1), register);
+                    }
+                }
+                self.store('1', text, linewise);
+                self.store(UNNAMED_REGISTER, text, linewise);
+            }
+            Some(name) => self.write_named(name, text, linewise),
+        }
+    }
+
+    /// Write an explicitly selected register; uppercase appends.
+    fn write_named(&mut self, name: char, text: &str, linewise: bool) {
+        let lower = name.to_ascii_lowercase();
+        if name.is_ascii_uppercase() {
+            let register = self.values.entry(lower).or_default();
+            register.text.push_str(text);
+            register.linewise |= linewise;
+        } else {
+            self.store(lower, text, linewise);
+        }
+
+        let register = self.values[&lower].clone();
+        self.values.insert(UNNAMED_REGISTER, register);
+    }
+
+    fn store(&mut self, name: char, text: &str, linewise: bool) {
+        self.values.insert(
+            name,
+            Register {
+                text: text.to_string(),
+                linewise,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(registers: &Registers, name: char) -> Option<&str> {
+        registers.get(name).map(|r| r.text.as_str())
+    }
+
+    #[test]
+    fn test_yank_to_named_register() {
+        let mut registers = Registers::new();
+        registers.yank(Some('a'), "hello", false);
+
+        assert_eq!(text(&registers, 'a'), Some("hello"));
+        assert_eq!(text(&registers, UNNAMED_REGISTER), Some("hello"));
+        assert_eq!(text(&registers, YANK_REGISTER), None);
+
+        registers.yank(Some('A'), " world", false);
+        assert_eq!(text(&registers, 'a'), Some("hello world"));
+    }
+
+    #[test]
+    fn test_delete_rotation() {
+        let mut registers = Registers::new();
+        registers.delete(None, "one", false);
+        registers.delete(None, "two", false);
+        registers.yank(None, "kept", false);
+        registers.delete(None, "three", true);
+
+        assert_eq!(text(&registers, '1'), Some("three"));
+        assert_eq!(text(&registers, '2'), Some("two"));
+        assert_eq!(text(&registers, '3'), Some("one"));
+        assert_eq!(text(&registers, YANK_REGISTER), Some("kept"));
+        assert_eq!(text(&registers, UNNAMED_REGISTER), Some("three"));
+        assert!(registers.get('1').unwrap().linewise);
+
+        for i in 0..10 {
+            registers.delete(None, &i.to_string(), false);
+        }
+        assert_eq!(text(&registers, '9'), Some("1"));
+    }
+
+    #[test]
+    fn test_black_hole() {
+        let mut registers = Registers::new();
+        registers.yank(None, "kept", false);
+        registers.delete(Some(BLACK_HOLE_REGISTER), "gone", false);
+
+        assert_eq!(text(&registers, UNNAMED_REGISTER), Some("kept"));
+        assert_eq!(text(&registers, '1'), None);
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::keymap::{KeyEvent, Keymap, KeymapResult, MappableCommand};
 use crate::motion::Motion;
+use crate::register::Registers;
 use crate::space::{build_space_mode_keymap, SpaceCommand};
 use crate::{
     HelixCommand, HelixKeyHandler, HelixKeyResult, HelixMode, HelixTextEngine, KeyModifiers,
@@ -37,10 +38,10 @@ pub struct HelixState {
     last_repeatable_command: Option<HelixCommand>,
     /// Text inserted after last insert-mode-entering command (for repeat).
     last_inserted_text: String,
-    /// Clipboard/register content.
-    register_content: String,
-    /// Whether register content is linewise.
-    register_linewise: bool,
+    /// Yank/delete registers.
+    registers: Registers,
+    /// Register selected with `"` for the next yank, delete, or paste.
+    selected_register: Option<char>,
     /// Whether space-mode is active.
     is_space_mode: bool,
     /// Space-mode keymap for trie-based lookup.
@@ -63,8 +64,8 @@ impl HelixState {
             search_query: String::new(),
             last_repeatable_command: None,
             last_inserted_text: String::new(),
-            register_content: String::new(),
-            register_linewise: false,
+            registers: Registers::new(),
+            selected_register: None,
             is_space_mode: false,
             space_keymap,
             space_mode_available_keys: Vec::new(),
@@ -127,9 +128,19 @@ impl HelixState {
         self.key_handler.is_awaiting_character()
     }
 
-    /// Get the register content.
+    /// Get the unnamed register content.
     pub fn register_content(&self) -> &str {
-        &self.register_content
+        self.registers.unnamed().map_or("", |r| r.text.as_str())
+    }
+
+    /// Get all registers.
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    /// Get the register selected for the next yank, delete, or paste.
+    pub fn selected_register(&self) -> Option<char> {
+        self.selected_register
     }
 
     /// Whether space-mode is active.
@@ -168,15 +179,25 @@ impl HelixState {
         self.key_handler.is_awaiting_motion()
     }
 
-    /// Yank text into the register.
+    /// Yank text into the selected register (or `0` and the unnamed register).
     pub fn yank(&mut self, text: &str, linewise: bool) {
-        self.register_content = text.to_string();
-        self.register_linewise = linewise;
+        self.registers.yank(self.selected_register, text, linewise);
     }
 
-    /// Paste from the register.
+    /// Record deleted text in the selected register (or `1`-`9` and the
+    /// unnamed register).
+    pub fn record_delete(&mut self, text: &str, linewise: bool) {
+        self.registers
+            .delete(self.selected_register, text, linewise);
+    }
+
+    /// Paste from the selected register, falling back to the unnamed register.
     pub fn paste(&self) -> (&str, bool) {
-        (&self.register_content, self.register_linewise)
+        let register = match self.selected_register {
+            Some(name) => self.registers.get(name),
+            None => self.registers.unnamed(),
+        };
+        register.map_or(("", false), |r| (r.text.as_str(), r.linewise))
     }
 
     /// Record inserted text for repeat functionality.
@@ -193,6 +214,7 @@ impl HelixState {
     pub fn reset(&mut self) {
         self.mode = HelixMode::Normal;
         self.key_handler.reset();
+        self.selected_register = None;
         self.is_searching = false;
         self.search_query.clear();
         self.is_space_mode = false;
@@ -349,7 +371,25 @@ impl HelixState {
     }
 
     /// Execute a command on the optional text engine.
+    ///
+    /// A selected register applies to the next command only.
     pub fn execute_command<E: HelixTextEngine>(
+        &mut self,
+        command: &HelixCommand,
+        text_engine: Option<&mut E>,
+    ) {
+        if let HelixCommand::SelectRegister { register } = command {
+            if Registers::is_valid_name(*register) {
+                self.selected_register = Some(*register);
+            }
+            return;
+        }
+
+        self.run_command(command, text_engine);
+        self.selected_register = None;
+    }
+
+    fn run_command<E: HelixTextEngine>(
         &mut self,
         command: &HelixCommand,
         mut text_engine: Option<&mut E>,
@@ -382,7 +422,7 @@ impl HelixState {
             }
             HelixCommand::Change => {
                 if let Some(ref mut engine) = text_engine {
-                    engine.delete();
+                    self.delete_selection(*engine);
                 }
                 self.set_mode(HelixMode::Insert);
                 self.last_repeatable_command = Some(HelixCommand::Change);
@@ -390,7 +430,7 @@ impl HelixState {
             }
             HelixCommand::Substitute => {
                 if let Some(ref mut engine) = text_engine {
-                    engine.delete();
+                    self.delete_selection(*engine);
                 }
                 self.set_mode(HelixMode::Insert);
                 self.last_repeatable_command = Some(HelixCommand::Substitute);
@@ -460,7 +500,7 @@ impl HelixState {
                         PendingCharacterOperation::TillBackward => {
                             HelixCommand::TillCharacterBackward { char, count: 1 }
                         }
-                        PendingCharacterOperation::Replace => return,
+                        _ => return,
                     };
                     self.execute_on_engine(&repeat_cmd, text_engine, extend_selection);
                 }
@@ -481,7 +521,7 @@ impl HelixState {
                         PendingCharacterOperation::TillBackward => {
                             HelixCommand::TillCharacter { char, count: 1 }
                         }
-                        PendingCharacterOperation::Replace => return,
+                        _ => return,
                     };
                     self.execute_on_engine(&reverse_cmd, text_engine, extend_selection);
                 }
@@ -523,6 +563,11 @@ impl HelixState {
             }
             HelixCommand::DocumentStart => engine.move_to_document_start(extend_selection),
             HelixCommand::DocumentEnd => engine.move_to_document_end(extend_selection),
+            HelixCommand::GotoLine { line } => engine.goto_line(*line, extend_selection),
+            HelixCommand::GotoLastLine => engine.goto_last_line(extend_selection),
+            HelixCommand::GotoPercent { percent } => {
+                engine.goto_percent(*percent, extend_selection)
+            }
 
             // Character finding
             HelixCommand::FindCharacter { char, count } => {
@@ -555,17 +600,17 @@ impl HelixState {
             HelixCommand::SelectAll => engine.select_all(),
 
             // Editing
-            HelixCommand::Delete => engine.delete(),
+            HelixCommand::Delete => self.delete_selection(engine),
             HelixCommand::Yank => {
                 let (text, linewise) = engine.yank();
                 self.yank(&text, linewise);
             }
             HelixCommand::PasteAfter => {
-                let content = self.register_content.clone();
+                let content = self.paste().0.to_string();
                 engine.paste_after(&content);
             }
             HelixCommand::PasteBefore => {
-                let content = self.register_content.clone();
+                let content = self.paste().0.to_string();
                 engine.paste_before(&content);
             }
 
@@ -576,6 +621,11 @@ impl HelixState {
             HelixCommand::Dedent => engine.dedent(),
             HelixCommand::ReplaceCharacter { char } => engine.replace_character(*char),
 
+            // Surround
+            HelixCommand::SurroundAdd(char) => engine.surround_add(*char),
+            HelixCommand::SurroundChange(from, to) => engine.surround_change(*from, *to),
+            HelixCommand::SurroundDelete(char) => engine.surround_delete(*char),
+
             // Undo/Redo
             HelixCommand::Undo => engine.undo(),
             HelixCommand::Redo => engine.redo(),
@@ -601,13 +651,13 @@ impl HelixState {
             HelixCommand::DeleteTextObject(text_object, modifier) => {
                 if let Some((start, end)) = engine.text_object_range(*text_object, *modifier) {
                     engine.set_selection(start, end);
-                    engine.delete();
+                    self.delete_selection(engine);
                 }
             }
             HelixCommand::ChangeTextObject(text_object, modifier) => {
                 if let Some((start, end)) = engine.text_object_range(*text_object, *modifier) {
                     engine.set_selection(start, end);
-                    engine.delete();
+                    self.delete_selection(engine);
                     self.set_mode(HelixMode::Insert);
                 }
             }
@@ -623,12 +673,31 @@ impl HelixState {
         }
     }
 
+    /// Delete the selection, or the character under the cursor, recording it
+    /// in the registers.
+    fn delete_selection<E: HelixTextEngine>(&mut self, engine: &mut E) {
+        let (start, end) = engine.selection();
+        let text = engine.text();
+        let deleted = if start != end {
+            &text[start..end]
+        } else {
+            let pos = engine.cursor_position();
+            let len = text[pos..].chars().next().map_or(0, char::len_utf8);
+            &text[pos..pos + len]
+        };
+        if !deleted.is_empty() {
+            let deleted = deleted.to_string();
+            self.record_delete(&deleted, false);
+        }
+        engine.delete();
+    }
+
     /// Execute delete with motion.
     fn execute_motion_delete<E: HelixTextEngine>(&mut self, engine: &mut E, motion: &Motion) {
         if let Some((start, end)) = engine.motion_range(motion) {
             let is_linewise = motion.is_linewise();
             let text = engine.text()[start..end].to_string();
-            self.yank(&text, is_linewise);
+            self.record_delete(&text, is_linewise);
             engine.set_selection(start, end);
             engine.delete();
         }
@@ -639,7 +708,7 @@ impl HelixState {
         if let Some((start, end)) = engine.motion_range(motion) {
             let is_linewise = motion.is_linewise();
             let text = engine.text()[start..end].to_string();
-            self.yank(&text, is_linewise);
+            self.record_delete(&text, is_linewise);
             engine.set_selection(start, end);
             engine.delete();
             self.set_mode(HelixMode::Insert);
@@ -693,6 +762,7 @@ impl Default for HelixState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::text_engine::tests::TestTextEngine;
 
     #[test]
     fn test_initial_state() {
@@ -750,4 +820,81 @@ mod tests {
         assert_eq!(text, "world\n");
         assert!(linewise);
     }
+
+    fn type_keys(state: &mut HelixState, engine: &mut TestTextEngine, keys: &str) {
+        let mods = KeyModifiers::default();
+        for key in keys.chars() {
+            state.handle_key(key, &mods, Some(&mut *engine));
+        }
+    }
+
+    #[test]
+    fn test_named_register_yank_and_paste() {
+        let mut state = HelixState::new();
+        let mut engine = TestTextEngine::new("one\ntwo\n");
+
+        type_keys(&mut state, &mut engine, "\"ayy");
+        assert_eq!(state.registers().get('a').unwrap().text, "one\n");
+        assert_eq!(state.selected_register(), None);
+
+        // A later yank to the unnamed register leaves `a` alone
+        type_keys(&mut state, &mut engine, "jyy");
+        assert_eq!(state.register_content(), "two\n");
+
+        type_keys(&mut state, &mut engine, "\"aP");
+        assert_eq!(engine.text(), "one\none\ntwo\n");
+    }
+
+    #[test]
+    fn test_goto_line_keys() {
+        let mut state = HelixState::new();
+        let text: String = (1..=10).map(|i| format!("  line {}\n", i)).collect();
+        let mut engine = TestTextEngine::new(&text);
+        let line_at_cursor = |engine: &TestTextEngine| {
+            let rest = &engine.text()[engine.cursor_position()..];
+            rest[..rest.find('\n').unwrap()].to_string()
+        };
+
+        type_keys(&mut state, &mut engine, "G");
+        assert_eq!(line_at_cursor(&engine), "line 10");
+
+        type_keys(&mut state, &mut engine, "5G");
+        assert_eq!(line_at_cursor(&engine), "line 5");
+
+        type_keys(&mut state, &mut engine, "gg50%");
+        assert_eq!(line_at_cursor(&engine), "line 5");
+
+        type_keys(&mut state, &mut engine, "99G");
+        assert_eq!(line_at_cursor(&engine), "line 10");
+    }
+
+    #[test]
+    fn test_delete_to_line_keys() {
+        let mut state = HelixState::new();
+        let text: String = (1..=6).map(|i| format!("line {}\n", i)).collect();
+        let mut engine = TestTextEngine::new(&text);
+
+        type_keys(&mut state, &mut engine, "jd4G");
+        assert_eq!(engine.text(), "line 1\nline 5\nline 6\n");
+
+        type_keys(&mut state, &mut engine, "dG");
+        assert_eq!(engine.text(), "line 1\n");
+    }
+
+    #[test]
+    fn test_deletes_rotate_numbered_registers() {
+        let mut state = HelixState::new();
+        let mut engine = TestTextEngine::new("one\ntwo\nthree\n");
+
+        type_keys(&mut state, &mut engine, "yydddd");
+        let registers = state.registers();
+        assert_eq!(registers.get('1').unwrap().text, "two\n");
+        assert_eq!(registers.get('2').unwrap().text, "one\n");
+        assert_eq!(registers.get('0').unwrap().text, "one\n");
+        assert_eq!(engine.text(), "three\n");
+
+        type_keys(&mut state, &mut engine, "\"_dd");
+        assert_eq!(state.register_content(), "two\n");
+        assert_eq!(engine.text(), "");
+    }
 }
@@ -0,0 +1,199 @@
+//! Surround operations (add, change, delete delimiter pairs).
+//!
+//! Typing either half of a bracket pair selects the whole pair, so `(` and
+//! `)` both mean `(...)`. Any other character pairs with itself, which covers
+//! quotes and math delimiters like `$...$`.
+
+use crate::text_engine::HelixTextEngine;
+
+/// A text edit: replace the byte range `start..end` with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    /// Start of the replaced range.
+    pub start: usize,
+    /// End of the replaced range.
+    pub end: usize,
+    /// Replacement text.
+    pub text: String,
+}
+
+impl Edit {
+    fn insert(at: usize, c: char) -> Self {
+        Self {
+            start: at,
+            end: at,
+            text: c.to_string(),
+        }
+    }
+
+    fn replace(at: usize, old: char, new: Option<char>) -> Self {
+        Self {
+            start: at,
+            end: at + old.len_utf8(),
+            text: new.map(String::from).unwrap_or_default(),
+        }
+    }
+}
+
+/// The opening and closing delimiters for a surround character.
+pub fn surround_pair(c: char) -> (char, char) {
+    match c {
+        '(' | ')' => ('(', ')'),
+        '[' | ']' => ('[', ']'),
+        '{' | '}' => ('{', '}'),
+        '<' | '>' => ('<', '>'),
+        _ => (c, c),
+    }
+}
+
+/// Find the delimiter pair for `c` enclosing the range `start..end`.
+///
+/// Returns the byte positions of the opening and closing delimiters.
+/// Nested bracket pairs are skipped; symmetric delimiters match the nearest
+/// occurrence on each side.
+pub fn find_surrounding(text: &str, start: usize, end: usize, c: char) -> Option<(usize, usize)> {
+    let (open, close) = surround_pair(c);
+
+    if open == close {
+        let open_pos = text[..start].rfind(open)?;
+        let close_pos = end + text[end..].find(close)?;
+        return Some((open_pos, close_pos));
+    }
+
+    let mut depth = 0;
+    let mut open_pos = None;
+    for (i, ch) in text[..start].char_indices().rev() {
+        if ch == close {
+            depth += 1;
+        } else if ch == open {
+            if depth == 0 {
+                open_pos = Some(i);
+                break;
+            }
+            depth -= 1;
+        }
+    }
+
+    let mut depth = 0;
+    let mut close_pos = None;
+    for (i, ch) in text[end..].char_indices() {
+        if ch == open {
+            depth += 1;
+        } else if ch == close {
+            if depth == 0 {
+                close_pos = Some(end + i);
+                break;
+            }
+            depth -= 1;
+        }
+    }
+
+    Some((open_pos?, close_pos?))
+}
+
+/// Edits wrapping each range in the pair for `c`.
+///
+/// An empty range wraps the character at that position.
+pub fn add_edits(text: &str, ranges: &[(usize, usize)], c: char) -> Vec<Edit> {
+    let (open, close) = surround_pair(c);
+    let mut edits = Vec::new();
+    for &(start, end) in ranges {
+        let end = if start == end {
+            start + text[start..].chars().next().map_or(0, char::len_utf8)
+        } else {
+            end
+        };
+        edits.push(Edit::insert(start, open));
+        edits.push(Edit::insert(end, close));
+    }
+    edits
+}
+
+/// Edits replacing the pair for `from` enclosing each range with the pair
+/// for `to`, or removing it when `to` is `None`.
+///
+/// Ranges inside the same pair produce a single change.
+pub fn change_edits(
+    text: &str,
+    ranges: &[(usize, usize)],
+    from: char,
+    to: Option<char>,
+) -> Vec<Edit> {
+    let (old_open, old_close) = surround_pair(from);
+    let (new_open, new_close) = match to.map(surround_pair) {
+        Some((open, close)) => (Some(open), Some(close)),
+        None => (None, None),
+    };
+
+    let mut pairs: Vec<(usize, usize)> = ranges
+        .iter()
+        .filter_map(|&(start, end)| find_surrounding(text, start, end, from))
+        .collect();
+    pairs.sort_unstable();
+    pairs.dedup();
+
+    let mut edits = Vec::new();
+    for (open_pos, close_pos) in pairs {
+        edits.push(Edit::replace(open_pos, old_open, new_open));
+        edits.push(Edit::replace(close_pos, old_close, new_close));
+    }
+    edits
+}
+
+/// Apply non-overlapping edits to an engine.
+///
+/// Edits are applied back to front so earlier offsets stay valid; edits at
+/// the same position end up in the order given.
+pub fn apply_edits<E: HelixTextEngine + ?Sized>(engine: &mut E, mut edits: Vec<Edit>) {
+    edits.sort_by_key(|edit| edit.start);
+    for edit in edits.iter().rev() {
+        engine.set_selection(edit.start, edit.end);
+        engine.replace_selection(&edit.text);
+    }
+}
+
+/// Replace or remove the pairs for `from` enclosing each of the engine's
+/// selections, keeping the cursor on the same character.
+pub(crate) fn replace_surrounding<E: HelixTextEngine + ?Sized>(
+    engine: &mut E,
+    from: char,
+    to: Option<char>,
+) {
+    let ranges = engine.selections();
+    let cursor = engine.cursor_position();
+    let edits = change_edits(engine.text(), &ranges, from, to);
+    if edits.is_empty() {
+        return;
+    }
+
+    let shift: isize = edits
+        .iter()
+        .filter(|edit| edit.end <= cursor)
+        .map(|edit| edit.text.len() as isize - (edit.end - edit.start) as isize)
+        .sum();
+    apply_edits(engine, edits);
+    engine.set_cursor_position(cursor.saturating_add_signed(shift));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_surrounding_skips_nested_pairs() {
+        let text = "f(a, (b), c)";
+        let c = text.find('c').unwrap();
+        assert_eq!(find_surrounding(text, c, c, ')'), Some((1, 11)));
+
+        let b = text.find('b').unwrap();
+        assert_eq!(find_surrounding(text, b, b, '('), Some((5, 7)));
+        assert_eq!(find_surrounding(text, 0, 0, '('), None);
+    }
+
+    #[test]
+    fn test_find_surrounding_symmetric() {
+        let text = "let $x^2$ be";
+        let x = text.find('x').unwrap();
+        assert_eq!(find_surrounding(text, x, x + 1, '$'), Some((4, 8)));
+    }
+}
@@ -4,6 +4,7 @@
 //! for Helix commands to operate on.
 
 use crate::motion::Motion;
+use crate::surround;
 use crate::text_object::{TextObject, TextObjectModifier};
 
 /// A trait for text engines that can be controlled by Helix commands.
@@ -32,6 +33,13 @@ pub trait HelixTextEngine {
     /// Set the selection range.
     fn set_selection(&mut self, start: usize, end: usize);
 
+    /// Get all selection ranges, for engines with multiple cursors.
+    ///
+    /// Defaults to the single selection.
+    fn selections(&self) -> Vec<(usize, usize)> {
+        vec![self.selection()]
+    }
+
     // =========================================================================
     // Text modification
     // =========================================================================
@@ -385,6 +393,30 @@ pub trait HelixTextEngine {
         self.set_cursor_position(len);
     }
 
+    /// Move to the first non-blank character of a line (1-based), clamped to
+    /// the document.
+    fn goto_line(&mut self, line: usize, extend_selection: bool) {
+        let text = self.text();
+        let new_pos = first_non_blank(text, line_start(text, line));
+        if extend_selection {
+            let (start, _) = self.selection();
+            self.set_selection(start, new_pos);
+        }
+        self.set_cursor_position(new_pos);
+    }
+
+    /// Move to the first non-blank character of the last line.
+    fn goto_last_line(&mut self, extend_selection: bool) {
+        let line = line_count(self.text());
+        self.goto_line(line, extend_selection);
+    }
+
+    /// Move to the line at a percentage (0-100) of the document.
+    fn goto_percent(&mut self, percent: u8, extend_selection: bool) {
+        let line = percent_line(self.text(), percent);
+        self.goto_line(line, extend_selection);
+    }
+
     // =========================================================================
     // Character finding
     // =========================================================================
@@ -681,6 +713,32 @@ pub trait HelixTextEngine {
         }
     }
 
+    // =========================================================================
+    // Surround
+    // =========================================================================
+
+    /// Wrap each selection (or the character under each cursor) in the pair
+    /// for `char`.
+    fn surround_add(&mut self, char: char) {
+        let ranges = self.selections();
+        let edits = surround::add_edits(self.text(), &ranges, char);
+        surround::apply_edits(self, edits);
+        if let Some(start) = ranges.iter().map(|(start, _)| *start).min() {
+            self.set_cursor_position(start);
+        }
+    }
+
+    /// Replace the pair for `from` enclosing each selection with the pair
+    /// for `to`.
+    fn surround_change(&mut self, from: char, to: char) {
+        surround::replace_surrounding(self, from, Some(to));
+    }
+
+    /// Remove the pair for `char` enclosing each selection.
+    fn surround_delete(&mut self, char: char) {
+        surround::replace_surrounding(self, char, None);
+    }
+
     // =========================================================================
     // Clipboard operations
     // =========================================================================
@@ -958,21 +1016,11 @@ pub trait HelixTextEngine {
             }
             Motion::DocumentStart => Some((0, pos)),
             Motion::DocumentEnd => Some((pos, text.len())),
-            Motion::GotoLine(line_num) => {
-                let mut line_start = 0;
-                for _ in 1..*line_num {
-                    if let Some(idx) = text[line_start..].find('\n') {
-                        line_start = line_start + idx + 1;
-                    } else {
-                        break;
-                    }
-                }
-                let line_end = text[line_start..]
-                    .find('\n')
-                    .map(|i| line_start + i + 1)
-                    .unwrap_or(text.len());
-                Some((line_start, line_end))
+            Motion::GotoLine(line) => Some(line_span(text, pos, *line)),
+            Motion::GotoPercent(percent) => {
+                Some(line_span(text, pos, percent_line(text, *percent)))
             }
+            Motion::LastLine => Some(line_span(text, pos, line_count(text))),
             Motion::Line => {
                 // Current line including newline
                 let line_start = text[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0);
@@ -1438,6 +1486,47 @@ pub trait HelixTextEngine {
     fn redo(&mut self);
 }
 
+/// Number of lines in `text`. A trailing newline does not start a new line.
+fn line_count(text: &str) -> usize {
+    text.lines().count().max(1)
+}
+
+/// Byte offset of the start of a line (1-based), clamped to the document.
+fn line_start(text: &str, line: usize) -> usize {
+    let line = line.clamp(1, line_count(text));
+    text.split_inclusive('\n')
+        .take(line - 1)
+        .map(str::len)
+        .sum()
+}
+
+/// Position of the first non-blank character on the line starting at
+/// `line_start`, or the line end if the line is blank.
+fn first_non_blank(text: &str, line_start: usize) -> usize {
+    text[line_start..]
+        .find(|c: char| c == '\n' || !c.is_whitespace())
+        .map_or(text.len(), |i| line_start + i)
+}
+
+/// Line (1-based) at `percent` of the way through the document.
+fn percent_line(text: &str, percent: u8) -> usize {
+    let lines = line_count(text);
+    (lines * percent.min(100) as usize).div_ceil(100).max(1)
+}
+
+/// Range covering every line from the one containing `pos` to `line`
+/// (1-based), inclusive.
+fn line_span(text: &str, pos: usize, line: usize) -> (usize, usize) {
+    let current = text[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let target = line_start(text, line);
+    let (first, last) = (current.min(target), current.max(target));
+    let end = text[last..]
+        .find('\n')
+        .map(|i| last + i + 1)
+        .unwrap_or(text.len());
+    (first, end)
+}
+
 /// A null text engine that does nothing.
 ///
 /// Used when you want to track editor state without an actual text buffer.
@@ -1472,11 +1561,11 @@ impl HelixTextEngine for NullTextEngine {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
     /// Simple in-memory text engine for testing.
-    struct TestTextEngine {
+    pub(crate) struct TestTextEngine {
         text: String,
         cursor: usize,
         selection: (usize, usize),
@@ -1485,7 +1574,7 @@ mod tests {
     }
 
     impl TestTextEngine {
-        fn new(text: &str) -> Self {
+        pub(crate) fn new(text: &str) -> Self {
             Self {
                 text: text.to_string(),
                 cursor: 0,
@@ -1647,4 +1736,135 @@ mod tests {
         engine.search_previous("foo", false);
         assert_eq!(engine.cursor_position(), 0); // First "foo"
     }
+
+    #[test]
+    fn test_goto_line() {
+        let mut engine = TestTextEngine::new("one\n  two\nthree\n    four\n");
+
+        engine.goto_line(2, false);
+        assert_eq!(engine.cursor_position(), 6); // "two", past the indent
+
+        engine.goto_line(99, false);
+        assert_eq!(engine.cursor_position(), 20); // clamped to "four"
+
+        engine.goto_line(0, false);
+        assert_eq!(engine.cursor_position(), 0);
+
+        engine.goto_last_line(false);
+        assert_eq!(engine.cursor_position(), 20);
+    }
+
+    #[test]
+    fn test_goto_percent() {
+        let text: String = (1..=10).map(|i| format!("line{}\n", i)).collect();
+        let mut engine = TestTextEngine::new(&text);
+
+        engine.goto_percent(50, false);
+        assert_eq!(&engine.text()[engine.cursor_position()..][..6], "line5\n");
+
+        engine.goto_percent(0, false);
+        assert_eq!(engine.cursor_position(), 0);
+
+        engine.goto_percent(100, false);
+        assert!(engine.text()[engine.cursor_position()..].starts_with("line10"));
+    }
+
+    #[test]
+    fn test_goto_line_motion_range() {
+        let mut engine = TestTextEngine::new("a\nb\nc\nd");
+        engine.set_cursor_position(6); // "d"
+
+        assert_eq!(engine.motion_range(&Motion::GotoLine(2)), Some((2, 7)));
+        assert_eq!(engine.motion_range(&Motion::GotoPercent(100)), Some((6, 7)));
+    }
+
+    #[test]
+    fn test_surround_add_word() {
+        let mut engine = TestTextEngine::new("see section two");
+        engine.set_selection(4, 11);
+
+        engine.surround_add('(');
+        assert_eq!(engine.text(), "see (section) two");
+
+        engine.set_cursor_position(0);
+        engine.surround_add('$');
+        assert_eq!(engine.text(), "$s$ee (section) two");
+    }
+
+    #[test]
+    fn test_surround_change_quotes() {
+        let mut engine = TestTextEngine::new("say \"hello\" now");
+        engine.set_cursor_position(6);
+
+        engine.surround_change('"', '\'');
+        assert_eq!(engine.text(), "say 'hello' now");
+
+        engine.surround_change('\'', '[');
+        assert_eq!(engine.text(), "say [hello] now");
+    }
+
+    #[test]
+    fn test_surround_delete_brackets() {
+        let mut engine = TestTextEngine::new("f([a], b)");
+        engine.set_cursor_position(3);
+
+        engine.surround_delete(']');
+        assert_eq!(engine.text(), "f(a, b)");
+
+        engine.surround_delete('(');
+        assert_eq!(engine.text(), "fa, b");
+
+        // No enclosing pair leaves the text alone
+        engine.surround_delete('{');
+        assert_eq!(engine.text(), "fa, b");
+    }
+
+    #[test]
+    fn test_surround_multiple_selections() {
+        struct MultiCursor(TestTextEngine, Vec<(usize, usize)>);
+
+        impl HelixTextEngine for MultiCursor {
+            fn text(&self) -> &str {
+                self.0.text()
+            }
+            fn cursor_position(&self) -> usize {
+                self.0.cursor_position()
+            }
+            fn set_cursor_position(&mut self, position: usize) {
+                self.0.set_cursor_position(position)
+            }
+            fn selection(&self) -> (usize, usize) {
+                self.0.selection()
+            }
+            fn set_selection(&mut self, start: usize, end: usize) {
+                self.0.set_selection(start, end)
+            }
+            fn selections(&self) -> Vec<(usize, usize)> {
+                self.1.clone()
+            }
+            fn insert_text(&mut self, text: &str) {
+                self.0.insert_text(text)
+            }
+            fn delete(&mut self) {
+                self.0.delete()
+            }
+            fn replace_selection(&mut self, text: &str) {
+                self.0.replace_selection(text)
+            }
+            fn undo(&mut self) {
+                self.0.undo()
+            }
+            fn redo(&mut self) {
+                self.0.redo()
+            }
+        }
+
+        let mut engine = MultiCursor(TestTextEngine::new("x+y"), vec![(0, 1), (2, 3)]);
+        engine.surround_add('$');
+        assert_eq!(engine.text(), "$x$+$y$");
+
+        engine.1 = vec![(1, 1), (5, 5)];
+        engine.surround_change('$', '(');
+        assert_eq!(engine.text(), "(x)+(y)");
+    }
 }
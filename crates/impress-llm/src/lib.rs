@@ -17,12 +17,15 @@
 //!
 //! - `uniffi`: Enable FFI bindings for Swift/Kotlin (required for iOS/macOS)
 //! - `native`: Full native build with UniFFI
+//! - `async`: Non-blocking `complete_async` for native Rust callers
 //!
 //! # Architecture
 //!
-//! The crate is designed to be called from Swift through UniFFI. All operations
+//! The crate is designed to be called from Swift through UniFFI. All FFI operations
 //! are blocking (synchronous) and should be called from a background thread.
 //! The Swift layer wraps these calls in `Task.detached` for async behavior.
+//! Rust callers already running inside tokio can enable the `async` feature
+//! and await `complete_async` instead.
 //!
 //! # Memory Management
 //!
@@ -69,6 +72,26 @@ pub fn get_provider(provider_id: String) -> Option<ProviderInfo> {
         .find(|p| p.id == provider_id)
 }
 
+/// Check that a provider is reachable and accepts an API key
+///
+/// This is a blocking network call. Use it to validate a key when the user
+/// enters it, rather than waiting for the first completion to fail.
+///
+/// # Arguments
+/// * `provider_id` - The provider to check
+/// * `api_key` - The API key to validate
+///
+/// # Returns
+/// Reachability, authentication, and the models available to the key
+#[cfg(feature = "uniffi")]
+#[uniffi::export]
+pub fn check_provider_health(
+    provider_id: String,
+    api_key: String,
+) -> Result<ProviderHealth, LLMError> {
+    provider::check_provider(&provider_id, &api_key)
+}
+
 // ============================================================================
 // UniFFI Exports - Completion
 // ============================================================================
@@ -151,6 +174,26 @@ pub fn complete_with_context(
     provider::complete_with_memory(&handle, &request)
 }
 
+/// Compact a memory scope by summarizing older messages
+///
+/// Everything but the most recent `keep_recent` messages is replaced with a
+/// single "conversation so far" system message generated by the model.
+/// This is a blocking operation and should be called from a background thread.
+///
+/// # Arguments
+/// * `handle` - The memory scope handle
+/// * `request` - Provider, model, and API key to summarize with (messages are ignored)
+/// * `keep_recent` - Number of recent messages to keep verbatim
+#[cfg(feature = "uniffi")]
+#[uniffi::export]
+pub fn summarize_memory_scope(
+    handle: MemoryHandle,
+    request: LLMRequest,
+    keep_recent: u32,
+) -> Result<(), LLMError> {
+    provider::summarize_memory(&handle, &request, keep_recent as usize)
+}
+
 /// Export memory state for persistence
 ///
 /// Use this to save memory to disk or Core Data.
@@ -7,7 +7,11 @@ use llm::builder::{LLMBackend, LLMBuilder};
 use llm::chat::ChatMessage;
 use llm::error::LLMError as LLMLibError;
 use std::collections::HashMap;
+use std::fmt::Write;
+use std::future::Future;
+use std::ops::RangeInclusive;
 use std::sync::Mutex;
+use std::time::Duration;
 use tokio::runtime::Runtime;
 use uuid::Uuid;
 
@@ -299,16 +303,98 @@ fn get_backend(provider: &str) -> Result<LLMBackend, LLMError> {
     }
 }
 
+/// A source of chat completions
+///
+/// [`LlmBackend`] is the real implementation; tests substitute a backend
+/// that returns canned responses. The future is `Send` so completions can be
+/// spawned on a multi-threaded runtime.
+pub trait CompletionBackend {
+    /// Complete a conversation
+    fn complete(
+        &self,
+        request: &LLMRequest,
+    ) -> impl Future<Output = Result<LLMResponse, LLMError>> + Send;
+}
+
+/// Completion backend that calls the provider through graniet/llm
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LlmBackend;
+
+impl CompletionBackend for LlmBackend {
+    fn complete(
+        &self,
+        request: &LLMRequest,
+    ) -> impl Future<Output = Result<LLMResponse, LLMError>> + Send {
+        chat_with_llm(request)
+    }
+}
+
 /// Execute a completion request (blocking wrapper around async)
 pub fn complete_sync(request: &LLMRequest) -> Result<LLMResponse, LLMError> {
     let runtime = get_runtime();
-    runtime.block_on(complete_async(request))
+    runtime.block_on(LlmBackend.complete(request))
+}
+
+/// Execute a completion request without blocking
+///
+/// For native Rust callers that already run inside a tokio runtime (e.g.
+/// impel-server). Swift goes through the blocking `complete` export instead.
+#[cfg(feature = "async")]
+pub async fn complete_async(request: &LLMRequest) -> Result<LLMResponse, LLMError> {
+    complete_async_with(&LlmBackend, request).await
+}
+
+/// Execute a completion request without blocking, through `backend`
+///
+/// Lets tests await [`complete_async`] against a mock backend.
+#[cfg(feature = "async")]
+pub async fn complete_async_with<B: CompletionBackend>(
+    backend: &B,
+    request: &LLMRequest,
+) -> Result<LLMResponse, LLMError> {
+    backend.complete(request).await
+}
+
+/// How requests reach a provider
+enum Route {
+    /// The provider's OpenAI-compatible chat completions endpoint
+    ChatCompletions(&'static str),
+    /// graniet/llm, for providers without such an endpoint
+    Llm(LLMBackend),
 }
 
-/// Execute a completion request (async)
-async fn complete_async(request: &LLMRequest) -> Result<LLMResponse, LLMError> {
-    let backend = get_backend(&request.provider)?;
+/// Pick the route for a provider
+///
+/// Depends only on the provider, never on the request's parameters, so a
+/// conversation is sent the same way (system prompts included) whether or
+/// not it sets stop sequences, penalties, or tools.
+fn route(provider: &str) -> Result<Route, LLMError> {
+    let backend = get_backend(provider)?;
+    Ok(match chat_completions_url(provider) {
+        Some(url) => Route::ChatCompletions(url),
+        None => Route::Llm(backend),
+    })
+}
+
+/// Execute a completion request against the provider
+async fn chat_with_llm(request: &LLMRequest) -> Result<LLMResponse, LLMError> {
+    let warnings = check_request_parameters(request)?;
+    match route(&request.provider)? {
+        Route::ChatCompletions(url) => chat_completions(url, request).await,
+        Route::Llm(backend) => chat_with_backend(backend, request, warnings).await,
+    }
+}
 
+/// Complete through graniet/llm
+///
+/// Its chat messages have no system role, so system prompts are prepended to
+/// the next user message, and stop sequences, penalties, and tools can't be
+/// sent (`warnings` lists the ones the request set).
+async fn chat_with_backend(
+    backend: LLMBackend,
+    request: &LLMRequest,
+    warnings: Vec<String>,
+) -> Result<LLMResponse, LLMError> {
     // Build the LLM client
     let mut builder = LLMBuilder::new()
         .backend(backend)
@@ -355,6 +441,14 @@ async fn complete_async(request: &LLMRequest) -> Result<LLMResponse, LLMError> {
             LLMRole::Assistant => {
                 chat_messages.push(ChatMessage::assistant().content(&msg.content).build());
             }
+            LLMRole::Tool => {
+                // Only reached for providers without tool support
+                chat_messages.push(
+                    ChatMessage::user()
+                        .content(format!("Tool result: {}", msg.content))
+                        .build(),
+                );
+            }
         }
     }
 
@@ -366,32 +460,10 @@ async fn complete_async(request: &LLMRequest) -> Result<LLMResponse, LLMError> {
     }
 
     // Execute the chat
-    let response = llm.chat(&chat_messages).await.map_err(|e: LLMLibError| {
-        let err_str = e.to_string().to_lowercase();
-        if err_str.contains("rate limit") || err_str.contains("429") {
-            LLMError::RateLimited {
-                retry_after_seconds: Some(60),
-            }
-        } else if err_str.contains("unauthorized")
-            || err_str.contains("401")
-            || err_str.contains("invalid api key")
-            || err_str.contains("invalid_api_key")
-        {
-            LLMError::InvalidApiKey
-        } else if err_str.contains("network") || err_str.contains("connection") {
-            LLMError::NetworkError {
-                message: e.to_string(),
-            }
-        } else if err_str.contains("context") && err_str.contains("length") {
-            LLMError::ContextLengthExceeded {
-                message: e.to_string(),
-            }
-        } else {
-            LLMError::ApiError {
-                message: e.to_string(),
-            }
-        }
-    })?;
+    let response = llm
+        .chat(&chat_messages)
+        .await
+        .map_err(|e: LLMLibError| api_error(None, e.to_string(), None))?;
 
     // Extract the text content
     let content = response.text().unwrap_or_default().to_string();
@@ -404,9 +476,401 @@ async fn complete_async(request: &LLMRequest) -> Result<LLMResponse, LLMError> {
         tokens_used,
         finish_reason: "stop".to_string(),
         model: request.model.clone(),
+        warnings,
+        tool_calls: vec![],
+    })
+}
+
+// ============================================================================
+// Chat Completions Endpoint
+// ============================================================================
+
+/// OpenAI-compatible chat completions endpoint
+///
+/// Every provider here except Phind has one, and all requests to those
+/// providers go through it: it takes stop sequences, penalties, tools, and
+/// system messages in one format.
+fn chat_completions_url(provider: &str) -> Option<&'static str> {
+    match provider {
+        "groq" => Some("https://api.groq.com/openai/v1/chat/completions"),
+        "mistral" => Some("https://api.mistral.ai/v1/chat/completions"),
+        "cohere" => Some("https://api.cohere.ai/compatibility/v1/chat/completions"),
+        "deepseek" => Some("https://api.deepseek.com/chat/completions"),
+        "xai" => Some("https://api.x.ai/v1/chat/completions"),
+        "huggingface" => Some("https://router.huggingface.co/v1/chat/completions"),
+        _ => None,
+    }
+}
+
+/// Accepted range of frequency and presence penalties
+fn penalty_range(provider: &str) -> RangeInclusive<f32> {
+    match provider {
+        "cohere" => 0.0..=1.0,
+        _ => -2.0..=2.0,
+    }
+}
+
+/// Validate stop sequences, penalties, and tools for the request's provider
+///
+/// Out-of-range penalties are rejected. For providers that don't support
+/// these parameters, returns a warning for each one set; the request is
+/// sent without them, so a request with tools gets a plain text response.
+fn check_request_parameters(request: &LLMRequest) -> Result<Vec<String>, LLMError> {
+    let range = penalty_range(&request.provider);
+    let penalties = [
+        ("frequency_penalty", request.frequency_penalty),
+        ("presence_penalty", request.presence_penalty),
+    ];
+    for (name, value) in penalties {
+        if let Some(value) = value {
+            if !range.contains(&value) {
+                return Err(LLMError::InvalidRequest {
+                    message: format!(
+                        "{} must be between {} and {} for {}, got {}",
+                        name,
+                        range.start(),
+                        range.end(),
+                        request.provider,
+                        value
+                    ),
+                });
+            }
+        }
+    }
+
+    if chat_completions_url(&request.provider).is_some() {
+        return Ok(vec![]);
+    }
+
+    let mut warnings = Vec::new();
+    if !request.stop.is_empty() {
+        warnings.push(format!("{} ignores stop sequences", request.provider));
+    }
+    for (name, value) in penalties {
+        if value.is_some() {
+            warnings.push(format!("{} ignores {}", request.provider, name));
+        }
+    }
+    if !request.tools.is_empty() {
+        warnings.push(format!("{} does not support tools", request.provider));
+    }
+    Ok(warnings)
+}
+
+/// Tool definition in the OpenAI function-calling format
+fn tool_payload(tool: &ToolDefinition) -> Result<serde_json::Value, LLMError> {
+    let parameters: serde_json::Value =
+        serde_json::from_str(&tool.parameters).map_err(|e| LLMError::InvalidRequest {
+            message: format!("Tool '{}' parameters are not valid JSON: {}", tool.name, e),
+        })?;
+    if !parameters.is_object() {
+        return Err(LLMError::InvalidRequest {
+            message: format!(
+                "Tool '{}' parameters must be a JSON Schema object",
+                tool.name
+            ),
+        });
+    }
+    Ok(serde_json::json!({
+        "type": "function",
+        "function": {
+            "name": tool.name,
+            "description": tool.description,
+            "parameters": parameters,
+        },
+    }))
+}
+
+/// Parse the tool calls in a chat completion message
+///
+/// Arguments are normally a JSON string, but some providers send an object;
+/// either way they are returned as a JSON string.
+fn parse_tool_calls(message: &serde_json::Value) -> Vec<ToolCall> {
+    let Some(calls) = message["tool_calls"].as_array() else {
+        return vec![];
+    };
+    calls
+        .iter()
+        .filter_map(|call| {
+            let function = &call["function"];
+            let name = function["name"].as_str()?;
+            let arguments = match &function["arguments"] {
+                serde_json::Value::String(arguments) => arguments.clone(),
+                serde_json::Value::Null => "{}".to_string(),
+                arguments => arguments.to_string(),
+            };
+            Some(ToolCall {
+                id: call["id"].as_str().unwrap_or_default().to_string(),
+                name: name.to_string(),
+                arguments,
+            })
+        })
+        .collect()
+}
+
+/// A conversation message in the OpenAI chat format
+fn message_payload(msg: &LLMMessage) -> serde_json::Value {
+    let role = match msg.role {
+        LLMRole::System => "system",
+        LLMRole::User => "user",
+        LLMRole::Assistant => "assistant",
+        LLMRole::Tool => "tool",
+    };
+    let mut payload = serde_json::json!({ "role": role, "content": msg.content });
+    let fields = payload.as_object_mut().unwrap();
+    if let Some(id) = &msg.tool_call_id {
+        fields.insert("tool_call_id".into(), id.clone().into());
+    }
+    if !msg.tool_calls.is_empty() {
+        let calls: Vec<serde_json::Value> = msg
+            .tool_calls
+            .iter()
+            .map(|call| {
+                serde_json::json!({
+                    "id": call.id,
+                    "type": "function",
+                    "function": { "name": call.name, "arguments": call.arguments },
+                })
+            })
+            .collect();
+        fields.insert("tool_calls".into(), calls.into());
+        // Tool-calling turns usually have no text
+        if msg.content.is_empty() {
+            fields.insert("content".into(), serde_json::Value::Null);
+        }
+    }
+    payload
+}
+
+/// Request body for an OpenAI-compatible chat completion
+fn chat_completions_payload(request: &LLMRequest) -> Result<serde_json::Value, LLMError> {
+    let messages: Vec<serde_json::Value> = request.messages.iter().map(message_payload).collect();
+
+    let mut payload = serde_json::json!({
+        "model": request.model,
+        "messages": messages,
+    });
+    let fields = payload.as_object_mut().unwrap();
+    if let Some(max_tokens) = request.max_tokens {
+        fields.insert("max_tokens".into(), max_tokens.into());
+    }
+    if let Some(temperature) = request.temperature {
+        fields.insert("temperature".into(), temperature.into());
+    }
+    if let Some(top_p) = request.top_p {
+        fields.insert("top_p".into(), top_p.into());
+    }
+    if !request.stop.is_empty() {
+        fields.insert("stop".into(), request.stop.clone().into());
+    }
+    if let Some(penalty) = request.frequency_penalty {
+        fields.insert("frequency_penalty".into(), penalty.into());
+    }
+    if let Some(penalty) = request.presence_penalty {
+        fields.insert("presence_penalty".into(), penalty.into());
+    }
+    if !request.tools.is_empty() {
+        let tools = request
+            .tools
+            .iter()
+            .map(tool_payload)
+            .collect::<Result<Vec<_>, _>>()?;
+        fields.insert("tools".into(), tools.into());
+    }
+    Ok(payload)
+}
+
+/// Classify a failed completion
+///
+/// Uses the HTTP status when there is one; graniet/llm errors only carry a
+/// message, so those are classified by its text.
+fn api_error(status: Option<u16>, message: String, retry_after: Option<u32>) -> LLMError {
+    let text = message.to_lowercase();
+    let (rate_limited, unauthorized) = match status {
+        Some(status) => (status == 429, status == 401 || status == 403),
+        None => (
+            text.contains("rate limit") || text.contains("429"),
+            text.contains("unauthorized")
+                || text.contains("401")
+                || text.contains("invalid api key")
+                || text.contains("invalid_api_key"),
+        ),
+    };
+
+    if rate_limited {
+        LLMError::RateLimited {
+            retry_after_seconds: retry_after.or(Some(60)),
+        }
+    } else if unauthorized {
+        LLMError::InvalidApiKey
+    } else if text.contains("context") && text.contains("length") {
+        LLMError::ContextLengthExceeded { message }
+    } else if status.is_none() && (text.contains("network") || text.contains("connection")) {
+        LLMError::NetworkError { message }
+    } else {
+        LLMError::ApiError { message }
+    }
+}
+
+/// Complete through an OpenAI-compatible chat completions endpoint
+async fn chat_completions(url: &str, request: &LLMRequest) -> Result<LLMResponse, LLMError> {
+    let payload = chat_completions_payload(request)?;
+    let response = reqwest::Client::new()
+        .post(url)
+        .bearer_auth(&request.api_key)
+        .json(&payload)
+        .timeout(Duration::from_secs(120))
+        .send()
+        .await
+        .map_err(|e| LLMError::NetworkError {
+            message: e.to_string(),
+        })?;
+
+    let status = response.status();
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok());
+    let body = response.text().await.map_err(|e| LLMError::NetworkError {
+        message: e.to_string(),
+    })?;
+
+    if !status.is_success() {
+        return Err(api_error(
+            Some(status.as_u16()),
+            format!("{}: {}", status, body),
+            retry_after,
+        ));
+    }
+
+    let json: serde_json::Value = serde_json::from_str(&body).map_err(|e| LLMError::ApiError {
+        message: format!("Invalid response: {}", e),
+    })?;
+    let choice = &json["choices"][0];
+
+    Ok(LLMResponse {
+        content: choice["message"]["content"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        tokens_used: json["usage"]["total_tokens"].as_u64().map(|t| t as u32),
+        finish_reason: choice["finish_reason"]
+            .as_str()
+            .unwrap_or("stop")
+            .to_string(),
+        model: json["model"].as_str().unwrap_or(&request.model).to_string(),
+        warnings: vec![],
+        tool_calls: parse_tool_calls(&choice["message"]),
     })
 }
 
+// ============================================================================
+// Health Check
+// ============================================================================
+
+/// Endpoint used to verify an API key
+///
+/// These are model-listing endpoints where the provider has one, so a
+/// successful probe also tells us which models the key can use.
+fn health_check_url(provider: &str) -> Option<&'static str> {
+    match provider {
+        "groq" => Some("https://api.groq.com/openai/v1/models"),
+        "mistral" => Some("https://api.mistral.ai/v1/models"),
+        "cohere" => Some("https://api.cohere.com/v1/models"),
+        "deepseek" => Some("https://api.deepseek.com/models"),
+        "xai" => Some("https://api.x.ai/v1/models"),
+        "huggingface" => Some("https://huggingface.co/api/whoami-v2"),
+        _ => None,
+    }
+}
+
+/// Check that a provider is reachable and accepts the API key
+///
+/// Makes a single authenticated request to the provider's model listing
+/// endpoint rather than spending tokens on a completion. Network failures
+/// and rejected keys are reported in the returned health, not as errors.
+pub fn check_provider(provider: &str, api_key: &str) -> Result<ProviderHealth, LLMError> {
+    get_backend(provider)?;
+    let url = health_check_url(provider).ok_or_else(|| LLMError::InvalidRequest {
+        message: format!("{} does not support health checks", provider),
+    })?;
+
+    let runtime = get_runtime();
+    Ok(runtime.block_on(probe_provider(provider, url, api_key)))
+}
+
+/// Request `url` with the API key and interpret the response
+async fn probe_provider(provider: &str, url: &str, api_key: &str) -> ProviderHealth {
+    let mut health = ProviderHealth {
+        provider: provider.to_string(),
+        reachable: false,
+        authenticated: false,
+        available_models: vec![],
+        message: None,
+    };
+
+    let response = match reqwest::Client::new()
+        .get(url)
+        .bearer_auth(api_key)
+        .timeout(Duration::from_secs(10))
+        .send()
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            health.message = Some(format!("Network error: {}", e));
+            return health;
+        }
+    };
+
+    health.reachable = true;
+    let status = response.status();
+    match status.as_u16() {
+        200..=299 => {
+            health.authenticated = true;
+            let body = response.text().await.unwrap_or_default();
+            health.available_models = parse_model_ids(&body);
+            if health.available_models.is_empty() {
+                // Endpoints that only validate the key (e.g. HuggingFace) don't
+                // list models; fall back to the registry.
+                health.available_models = get_models(provider).into_iter().map(|m| m.id).collect();
+            }
+        }
+        401 | 403 => health.message = Some(LLMError::InvalidApiKey.to_string()),
+        _ => health.message = Some(format!("Unexpected status {}", status)),
+    }
+
+    health
+}
+
+/// Extract model identifiers from a model listing response
+///
+/// Handles the OpenAI-style `{"data": [{"id": ...}]}` shape and Cohere's
+/// `{"models": [{"name": ...}]}`.
+fn parse_model_ids(body: &str) -> Vec<String> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(body) else {
+        return vec![];
+    };
+
+    let entries = json
+        .get("data")
+        .or_else(|| json.get("models"))
+        .and_then(|v| v.as_array());
+
+    entries
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            entry
+                .get("id")
+                .or_else(|| entry.get("name"))
+                .and_then(|v| v.as_str())
+                .map(String::from)
+        })
+        .collect()
+}
+
 // ============================================================================
 // Memory Management
 // ============================================================================
@@ -533,9 +997,23 @@ pub fn clear_memory(handle: &MemoryHandle) -> Result<(), LLMError> {
 }
 
 /// Complete with memory context
+///
+/// The new turn (the request's last message, or every tool result at the
+/// end of the request) and the assistant's reply, tool calls included, are
+/// added to memory, so a tool round-trip can continue by sending just the
+/// tool results.
 pub fn complete_with_memory(
     handle: &MemoryHandle,
     request: &LLMRequest,
+) -> Result<LLMResponse, LLMError> {
+    complete_with_memory_with(&LlmBackend, handle, request)
+}
+
+/// [`complete_with_memory`] against an arbitrary completion backend
+pub fn complete_with_memory_with<B: CompletionBackend>(
+    backend: &B,
+    handle: &MemoryHandle,
+    request: &LLMRequest,
 ) -> Result<LLMResponse, LLMError> {
     // Get existing messages from memory
     let mut messages = get_memory_messages(handle)?;
@@ -550,23 +1028,123 @@ pub fn complete_with_memory(
     };
 
     // Execute completion
-    let response = complete_sync(&full_request)?;
+    let response = get_runtime().block_on(backend.complete(&full_request))?;
 
-    // Add the user's last message and assistant response to memory
-    if let Some(last_user_msg) = request.messages.last() {
-        add_to_memory(handle, last_user_msg.clone())?;
+    // Every call the model made needs its result, so keep all trailing
+    // tool results rather than just the last one
+    let tool_results = request
+        .messages
+        .iter()
+        .rev()
+        .take_while(|msg| msg.role == LLMRole::Tool)
+        .count();
+    let new_turn = request.messages.len() - tool_results.max(1).min(request.messages.len());
+    for msg in &request.messages[new_turn..] {
+        add_to_memory(handle, msg.clone())?;
     }
-    add_to_memory(
-        handle,
-        LLMMessage {
-            role: LLMRole::Assistant,
-            content: response.content.clone(),
-        },
-    )?;
+    add_to_memory(handle, response.to_message())?;
 
     Ok(response)
 }
 
+/// Prefix of the system message that replaces summarized history
+const SUMMARY_PREFIX: &str = "Conversation so far:";
+
+/// Replace older memory with an LLM-generated summary
+///
+/// All but the most recent `keep_recent` messages are sent to the model
+/// described by `request` (its messages are ignored) and replaced with a
+/// single system message holding the summary. Does nothing if the scope
+/// holds no more than `keep_recent` messages.
+pub fn summarize_memory(
+    handle: &MemoryHandle,
+    request: &LLMRequest,
+    keep_recent: usize,
+) -> Result<(), LLMError> {
+    summarize_memory_with(&LlmBackend, handle, request, keep_recent)
+}
+
+/// [`summarize_memory`] against an arbitrary completion backend
+pub fn summarize_memory_with<B: CompletionBackend>(
+    backend: &B,
+    handle: &MemoryHandle,
+    request: &LLMRequest,
+    keep_recent: usize,
+) -> Result<(), LLMError> {
+    let messages = get_memory_messages(handle)?;
+    if messages.len() <= keep_recent {
+        return Ok(());
+    }
+    let summarized = messages.len() - keep_recent;
+
+    let transcript = messages[..summarized]
+        .iter()
+        .map(|msg| {
+            let speaker = match msg.role {
+                LLMRole::System => "System",
+                LLMRole::User => "User",
+                LLMRole::Assistant => "Assistant",
+                LLMRole::Tool => "Tool result",
+            };
+            let mut line = format!("{}: {}", speaker, msg.content);
+            for call in &msg.tool_calls {
+                let _ = write!(line, "\n(called {} with {})", call.name, call.arguments);
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let summary_request = LLMRequest {
+        messages: vec![
+            LLMMessage::new(
+                LLMRole::System,
+                "Summarize the following conversation concisely. Keep facts, \
+                 decisions, and open questions; omit pleasantries.",
+            ),
+            LLMMessage::new(LLMRole::User, transcript),
+        ],
+        // A tool call or early stop would come back as an empty or cut-off
+        // summary
+        stop: vec![],
+        tools: vec![],
+        ..request.clone()
+    };
+
+    let response = get_runtime().block_on(backend.complete(&summary_request))?;
+    let summary = response.content.trim();
+    if summary.is_empty() {
+        return Err(LLMError::ApiError {
+            message: "Model returned an empty summary".to_string(),
+        });
+    }
+
+    let mut store_guard = MemoryStore::get_or_init();
+    let store = store_guard.as_mut().unwrap();
+
+    let memory = store
+        .memories
+        .get_mut(&handle.id)
+        .ok_or_else(|| LLMError::Unknown {
+            message: format!("Memory scope not found: {}", handle.id),
+        })?;
+
+    // Messages may have been added (or the scope cleared) while the summary
+    // was generated, so only replace what was actually summarized.
+    if memory.messages.len() >= summarized {
+        memory.messages.splice(
+            ..summarized,
+            [LLMMessage::new(
+                LLMRole::System,
+                format!("{} {}", SUMMARY_PREFIX, summary),
+            )],
+        );
+        memory.last_updated = chrono::Utc::now().timestamp();
+    }
+
+    Ok(())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -600,14 +1178,584 @@ mod tests {
         assert!(get_backend("unknown").is_err());
     }
 
+    /// Answers with canned text, or with a tool call when offered tools
+    struct CannedBackend(&'static str);
+
+    impl CompletionBackend for CannedBackend {
+        async fn complete(&self, request: &LLMRequest) -> Result<LLMResponse, LLMError> {
+            let tool_calls: Vec<ToolCall> = request
+                .tools
+                .iter()
+                .take(1)
+                .map(|tool| ToolCall {
+                    id: "call_1".to_string(),
+                    name: tool.name.clone(),
+                    arguments: "{}".to_string(),
+                })
+                .collect();
+            Ok(LLMResponse {
+                content: if tool_calls.is_empty() {
+                    self.0.to_string()
+                } else {
+                    String::new()
+                },
+                tokens_used: Some(request.messages.len() as u32),
+                finish_reason: "stop".to_string(),
+                model: request.model.clone(),
+                warnings: vec![],
+                tool_calls,
+            })
+        }
+    }
+
+    fn sample_request() -> LLMRequest {
+        LLMRequest {
+            provider: "groq".to_string(),
+            model: "llama-3.3-70b-versatile".to_string(),
+            messages: vec![LLMMessage::new(LLMRole::User, "Hello")],
+            max_tokens: None,
+            temperature: None,
+            top_p: None,
+            stop: vec![],
+            frequency_penalty: None,
+            presence_penalty: None,
+            tools: vec![],
+            api_key: String::new(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_complete_async_spawned() {
+        // Spawning requires the completion future to be Send
+        let response = tokio::spawn(async {
+            complete_async_with(&CannedBackend("Hi there"), &sample_request()).await
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(response.content, "Hi there");
+        assert_eq!(response.model, "llama-3.3-70b-versatile");
+        assert_eq!(response.tokens_used, Some(1));
+
+        let request = LLMRequest {
+            provider: "nope".to_string(),
+            ..sample_request()
+        };
+        assert!(matches!(
+            tokio::spawn(async move { complete_async(&request).await })
+                .await
+                .unwrap(),
+            Err(LLMError::ProviderNotFound { .. })
+        ));
+    }
+
+    /// Serve a single HTTP response on a local port and return its URL
+    fn mock_endpoint(status_line: &'static str, body: &'static str) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v1/models", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 4096];
+            let _ = stream.read(&mut request);
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status_line,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        url
+    }
+
+    #[test]
+    fn test_probe_provider_authenticated() {
+        let url = mock_endpoint(
+            "200 OK",
+            r#"{"object": "list", "data": [{"id": "llama-3.3-70b-versatile"}, {"id": "gemma2-9b-it"}]}"#,
+        );
+        let health = get_runtime().block_on(probe_provider("groq", &url, "good-key"));
+
+        assert!(health.reachable);
+        assert!(health.authenticated);
+        assert_eq!(
+            health.available_models,
+            vec!["llama-3.3-70b-versatile", "gemma2-9b-it"]
+        );
+        assert!(health.message.is_none());
+    }
+
+    #[test]
+    fn test_probe_provider_invalid_key() {
+        let url = mock_endpoint(
+            "401 Unauthorized",
+            r#"{"error": {"message": "Invalid API Key"}}"#,
+        );
+        let health = get_runtime().block_on(probe_provider("groq", &url, "bad-key"));
+
+        assert!(health.reachable);
+        assert!(!health.authenticated);
+        assert!(health.available_models.is_empty());
+        assert_eq!(health.message.as_deref(), Some("Invalid API key"));
+    }
+
+    #[test]
+    fn test_probe_provider_unreachable() {
+        // Bind then drop a listener so nothing is listening on the port
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let url = format!("http://127.0.0.1:{}/v1/models", port);
+        let health = get_runtime().block_on(probe_provider("groq", &url, "key"));
+
+        assert!(!health.reachable);
+        assert!(!health.authenticated);
+        assert!(health.message.unwrap().starts_with("Network error"));
+    }
+
+    #[test]
+    fn test_check_provider_unknown() {
+        assert!(matches!(
+            check_provider("nope", "key"),
+            Err(LLMError::ProviderNotFound { .. })
+        ));
+        assert!(matches!(
+            check_provider("phind", "key"),
+            Err(LLMError::InvalidRequest { .. })
+        ));
+    }
+
+    /// Serve a single chat completion on a local port
+    ///
+    /// Returns the URL and a channel that receives the request body.
+    fn capture_endpoint(body: &'static str) -> (String, std::sync::mpsc::Receiver<String>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!(
+            "http://{}/v1/chat/completions",
+            listener.local_addr().unwrap()
+        );
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut request_body = vec![0u8; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            sender
+                .send(String::from_utf8(request_body).unwrap())
+                .unwrap();
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+        });
+        (url, receiver)
+    }
+
+    #[test]
+    fn test_stop_sequence_forwarded() {
+        let (url, request_body) = capture_endpoint(
+            r#"{"model": "llama-3.3-70b-versatile", "choices": [{"message": {"role": "assistant", "content": "1. Alpha"}, "finish_reason": "stop"}], "usage": {"total_tokens": 12}}"#,
+        );
+        let request = LLMRequest {
+            stop: vec!["\n2.".to_string()],
+            frequency_penalty: Some(0.5),
+            ..sample_request()
+        };
+        let response = get_runtime()
+            .block_on(chat_completions(&url, &request))
+            .unwrap();
+        assert_eq!(response.content, "1. Alpha");
+        assert_eq!(response.tokens_used, Some(12));
+        assert!(response.warnings.is_empty());
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&request_body.recv().unwrap()).unwrap();
+        assert_eq!(payload["stop"], serde_json::json!(["\n2."]));
+        assert_eq!(payload["frequency_penalty"], serde_json::json!(0.5));
+        assert!(payload.get("presence_penalty").is_none());
+        assert_eq!(payload["messages"][0]["role"], "user");
+    }
+
+    #[test]
+    fn test_routing_ignores_request_parameters() {
+        // Every provider with a chat completions endpoint uses it for all
+        // requests, so setting stop or tools can't change how a
+        // conversation is sent
+        for provider in get_providers() {
+            let expected = chat_completions_url(&provider.id);
+            match route(&provider.id) {
+                Ok(Route::ChatCompletions(url)) => assert_eq!(Some(url), expected),
+                Ok(Route::Llm(_)) => assert_eq!(expected, None, "{}", provider.id),
+                Err(e) => panic!("{}: {}", provider.id, e),
+            }
+        }
+        assert!(matches!(route("groq"), Ok(Route::ChatCompletions(_))));
+        assert!(matches!(route("phind"), Ok(Route::Llm(_))));
+        assert!(matches!(
+            route("nope"),
+            Err(LLMError::ProviderNotFound { .. })
+        ));
+
+        let request = LLMRequest {
+            provider: "nope".to_string(),
+            stop: vec!["END".to_string()],
+            ..sample_request()
+        };
+        assert!(matches!(
+            get_runtime().block_on(chat_with_llm(&request)),
+            Err(LLMError::ProviderNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_system_prompt_sent_as_system_role() {
+        let body = r#"{"choices": [{"message": {"content": "Hi"}, "finish_reason": "stop"}]}"#;
+        let conversation = vec![
+            LLMMessage::new(LLMRole::System, "Be brief."),
+            LLMMessage::new(LLMRole::User, "Hello"),
+        ];
+        for stop in [vec![], vec!["END".to_string()]] {
+            let (url, request_body) = capture_endpoint(body);
+            let request = LLMRequest {
+                messages: conversation.clone(),
+                stop,
+                ..sample_request()
+            };
+            get_runtime()
+                .block_on(chat_completions(&url, &request))
+                .unwrap();
+
+            let payload: serde_json::Value =
+                serde_json::from_str(&request_body.recv().unwrap()).unwrap();
+            assert_eq!(
+                payload["messages"],
+                serde_json::json!([
+                    {"role": "system", "content": "Be brief."},
+                    {"role": "user", "content": "Hello"},
+                ])
+            );
+        }
+    }
+
+    #[test]
+    fn test_api_errors_classified() {
+        assert!(matches!(
+            api_error(Some(429), "429 Too Many Requests: {}".into(), Some(7)),
+            LLMError::RateLimited {
+                retry_after_seconds: Some(7)
+            }
+        ));
+        assert!(matches!(
+            api_error(Some(401), "401 Unauthorized".into(), None),
+            LLMError::InvalidApiKey
+        ));
+        assert!(matches!(
+            api_error(
+                Some(400),
+                r#"400 Bad Request: {"error": {"code": "context_length_exceeded"}}"#.into(),
+                None
+            ),
+            LLMError::ContextLengthExceeded { .. }
+        ));
+        // The status decides, not words in the body
+        assert!(matches!(
+            api_error(Some(500), "500: upstream connection reset".into(), None),
+            LLMError::ApiError { .. }
+        ));
+
+        // graniet/llm errors are classified by their text
+        assert!(matches!(
+            api_error(None, "HTTP 429: rate limit reached".into(), None),
+            LLMError::RateLimited {
+                retry_after_seconds: Some(60)
+            }
+        ));
+        assert!(matches!(
+            api_error(None, "Invalid API key provided".into(), None),
+            LLMError::InvalidApiKey
+        ));
+        assert!(matches!(
+            api_error(None, "connection refused".into(), None),
+            LLMError::NetworkError { .. }
+        ));
+    }
+
+    #[test]
+    fn test_generation_controls_validated() {
+        let request = LLMRequest {
+            presence_penalty: Some(2.5),
+            ..sample_request()
+        };
+        assert!(matches!(
+            check_request_parameters(&request),
+            Err(LLMError::InvalidRequest { .. })
+        ));
+
+        // Cohere only accepts non-negative penalties up to 1
+        let request = LLMRequest {
+            provider: "cohere".to_string(),
+            frequency_penalty: Some(-0.5),
+            ..sample_request()
+        };
+        assert!(check_request_parameters(&request).is_err());
+
+        let request = LLMRequest {
+            frequency_penalty: Some(f32::NAN),
+            ..sample_request()
+        };
+        assert!(check_request_parameters(&request).is_err());
+
+        // Phind has no way to pass them, so they're dropped with a warning
+        let request = LLMRequest {
+            provider: "phind".to_string(),
+            stop: vec!["END".to_string()],
+            presence_penalty: Some(1.0),
+            ..sample_request()
+        };
+        assert_eq!(
+            check_request_parameters(&request).unwrap(),
+            vec![
+                "phind ignores stop sequences",
+                "phind ignores presence_penalty"
+            ]
+        );
+        assert!(check_request_parameters(&sample_request())
+            .unwrap()
+            .is_empty());
+    }
+
+    fn weather_tool() -> ToolDefinition {
+        ToolDefinition {
+            name: "get_weather".to_string(),
+            description: "Current weather for a city".to_string(),
+            parameters: r#"{"type": "object", "properties": {"city": {"type": "string"}}, "required": ["city"]}"#.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_tool_call_parsed() {
+        let (url, request_body) = capture_endpoint(
+            r#"{"model": "llama-3.3-70b-versatile", "choices": [{"message": {"role": "assistant", "content": null, "tool_calls": [{"id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": "{\"city\": \"Paris\"}"}}]}, "finish_reason": "tool_calls"}]}"#,
+        );
+        let request = LLMRequest {
+            tools: vec![weather_tool()],
+            ..sample_request()
+        };
+        let response = get_runtime()
+            .block_on(chat_completions(&url, &request))
+            .unwrap();
+        assert_eq!(response.content, "");
+        assert_eq!(response.finish_reason, "tool_calls");
+        assert_eq!(
+            response.tool_calls,
+            vec![ToolCall {
+                id: "call_1".to_string(),
+                name: "get_weather".to_string(),
+                arguments: r#"{"city": "Paris"}"#.to_string(),
+            }]
+        );
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&request_body.recv().unwrap()).unwrap();
+        assert_eq!(payload["tools"][0]["type"], "function");
+        assert_eq!(payload["tools"][0]["function"]["name"], "get_weather");
+        assert_eq!(
+            payload["tools"][0]["function"]["parameters"]["required"],
+            serde_json::json!(["city"])
+        );
+    }
+
+    #[test]
+    fn test_tool_arguments_as_object() {
+        let message = serde_json::json!({
+            "tool_calls": [
+                {"id": "a", "function": {"name": "search", "arguments": {"q": "halo"}}},
+                {"id": "b", "function": {"arguments": "{}"}},
+            ]
+        });
+        let calls = parse_tool_calls(&message);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].arguments, r#"{"q":"halo"}"#);
+        assert!(parse_tool_calls(&serde_json::json!({"content": "hi"})).is_empty());
+    }
+
+    #[test]
+    fn test_tools_validated_and_ignored_without_support() {
+        let mut tool = weather_tool();
+        tool.parameters = "not json".to_string();
+        let request = LLMRequest {
+            tools: vec![tool],
+            ..sample_request()
+        };
+        assert!(matches!(
+            chat_completions_payload(&request),
+            Err(LLMError::InvalidRequest { .. })
+        ));
+
+        let request = LLMRequest {
+            provider: "phind".to_string(),
+            tools: vec![weather_tool()],
+            ..sample_request()
+        };
+        assert_eq!(
+            check_request_parameters(&request).unwrap(),
+            vec!["phind does not support tools"]
+        );
+    }
+
+    #[test]
+    fn test_tool_round_trip_payload() {
+        let call = ToolCall {
+            id: "call_1".to_string(),
+            name: "get_weather".to_string(),
+            arguments: r#"{"city": "Paris"}"#.to_string(),
+        };
+        let request = LLMRequest {
+            messages: vec![
+                LLMMessage::new(LLMRole::User, "Weather in Paris?"),
+                LLMMessage {
+                    tool_calls: vec![call],
+                    ..LLMMessage::new(LLMRole::Assistant, "")
+                },
+                LLMMessage::tool_result("call_1", r#"{"temp_c": 18}"#),
+            ],
+            tools: vec![weather_tool()],
+            ..sample_request()
+        };
+        let payload = chat_completions_payload(&request).unwrap();
+        let messages = &payload["messages"];
+        assert_eq!(messages[1]["role"], "assistant");
+        assert!(messages[1]["content"].is_null());
+        assert_eq!(messages[1]["tool_calls"][0]["id"], "call_1");
+        assert_eq!(messages[1]["tool_calls"][0]["type"], "function");
+        assert_eq!(
+            messages[1]["tool_calls"][0]["function"]["arguments"],
+            r#"{"city": "Paris"}"#
+        );
+        assert_eq!(messages[2]["role"], "tool");
+        assert_eq!(messages[2]["tool_call_id"], "call_1");
+        assert_eq!(messages[2]["content"], r#"{"temp_c": 18}"#);
+        assert!(messages[0].get("tool_call_id").is_none());
+        assert!(messages[0].get("tool_calls").is_none());
+    }
+
+    #[test]
+    fn test_memory_keeps_tool_calls() {
+        let handle = create_memory(Some("tool-scope".to_string()), MemoryScope::Document);
+        let backend = CannedBackend("It is 18°C in Paris.");
+
+        let request = LLMRequest {
+            messages: vec![LLMMessage::new(LLMRole::User, "Weather in Paris?")],
+            tools: vec![weather_tool()],
+            ..sample_request()
+        };
+        let response = complete_with_memory_with(&backend, &handle, &request).unwrap();
+        assert_eq!(response.tool_calls.len(), 1);
+
+        // Answer the call; memory supplies the assistant's tool call
+        let request = LLMRequest {
+            messages: vec![LLMMessage::tool_result(
+                &response.tool_calls[0].id,
+                r#"{"temp_c": 18}"#,
+            )],
+            ..sample_request()
+        };
+        let response = complete_with_memory_with(&backend, &handle, &request).unwrap();
+        assert_eq!(response.content, "It is 18°C in Paris.");
+        assert_eq!(response.tokens_used, Some(3));
+
+        let messages = get_memory_messages(&handle).unwrap();
+        let roles: Vec<LLMRole> = messages.iter().map(|m| m.role).collect();
+        assert_eq!(
+            roles,
+            vec![
+                LLMRole::User,
+                LLMRole::Assistant,
+                LLMRole::Tool,
+                LLMRole::Assistant
+            ]
+        );
+        assert_eq!(messages[1].tool_calls[0].name, "get_weather");
+        assert_eq!(messages[2].tool_call_id.as_deref(), Some("call_1"));
+    }
+
+    #[test]
+    fn test_summarize_memory() {
+        let handle = create_memory(Some("summarize-scope".to_string()), MemoryScope::Document);
+        for i in 0..6 {
+            let role = if i % 2 == 0 {
+                LLMRole::User
+            } else {
+                LLMRole::Assistant
+            };
+            add_to_memory(&handle, LLMMessage::new(role, format!("turn {}", i))).unwrap();
+        }
+
+        // The conversation's tools and stop sequences don't carry over into
+        // the summary request
+        let request = LLMRequest {
+            stop: vec!["\n".to_string()],
+            tools: vec![weather_tool()],
+            ..sample_request()
+        };
+        let backend = CannedBackend("We discussed turns 0 through 3.");
+        summarize_memory_with(&backend, &handle, &request, 2).unwrap();
+
+        let messages = get_memory_messages(&handle).unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0].role, LLMRole::System);
+        assert_eq!(
+            messages[0].content,
+            "Conversation so far: We discussed turns 0 through 3."
+        );
+        assert_eq!(messages[1].content, "turn 4");
+        assert_eq!(messages[2].content, "turn 5");
+
+        // Nothing left to compact
+        summarize_memory_with(&backend, &handle, &sample_request(), 3).unwrap();
+        assert_eq!(get_memory_messages(&handle).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_summarize_memory_rejects_empty_summary() {
+        let handle = create_memory(Some("summarize-empty".to_string()), MemoryScope::Document);
+        for content in ["one", "two", "three"] {
+            add_to_memory(&handle, LLMMessage::new(LLMRole::User, content)).unwrap();
+        }
+
+        let result = summarize_memory_with(&CannedBackend("  "), &handle, &sample_request(), 1);
+        assert!(matches!(result, Err(LLMError::ApiError { .. })));
+        assert_eq!(get_memory_messages(&handle).unwrap().len(), 3);
+    }
+
     #[test]
     fn test_memory_operations() {
         let handle = create_memory(Some("test-scope".to_string()), MemoryScope::Document);
 
-        let msg = LLMMessage {
-            role: LLMRole::User,
-            content: "Hello".to_string(),
-        };
+        let msg = LLMMessage::new(LLMRole::User, "Hello");
         add_to_memory(&handle, msg).unwrap();
 
         let messages = get_memory_messages(&handle).unwrap();
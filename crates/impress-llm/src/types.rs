@@ -11,6 +11,8 @@ pub enum LLMRole {
     System,
     User,
     Assistant,
+    /// Result of a tool call, sent back to the model
+    Tool,
 }
 
 /// A single message in a conversation
@@ -21,6 +23,30 @@ pub struct LLMMessage {
     pub role: LLMRole,
     /// Text content of the message
     pub content: String,
+    /// Tools the assistant called in this message (empty for none)
+    pub tool_calls: Vec<ToolCall>,
+    /// For `Tool` messages, the ID of the call this is the result of
+    pub tool_call_id: Option<String>,
+}
+
+impl LLMMessage {
+    /// A text message
+    pub fn new(role: LLMRole, content: impl Into<String>) -> Self {
+        Self {
+            role,
+            content: content.into(),
+            tool_calls: vec![],
+            tool_call_id: None,
+        }
+    }
+
+    /// The result of the tool call `tool_call_id`
+    pub fn tool_result(tool_call_id: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            tool_call_id: Some(tool_call_id.into()),
+            ..Self::new(LLMRole::Tool, content)
+        }
+    }
 }
 
 /// Request to complete a conversation
@@ -39,6 +65,14 @@ pub struct LLMRequest {
     pub temperature: Option<f32>,
     /// Top-p nucleus sampling (optional)
     pub top_p: Option<f32>,
+    /// Sequences that end generation when produced (empty for none)
+    pub stop: Vec<String>,
+    /// Penalty on tokens by how often they have appeared (optional)
+    pub frequency_penalty: Option<f32>,
+    /// Penalty on tokens that have appeared at all (optional)
+    pub presence_penalty: Option<f32>,
+    /// Functions the model may call (empty for none)
+    pub tools: Vec<ToolDefinition>,
     /// API key for the provider
     pub api_key: String,
 }
@@ -55,6 +89,47 @@ pub struct LLMResponse {
     pub finish_reason: String,
     /// Model that generated the response
     pub model: String,
+    /// Request parameters the provider ignored
+    pub warnings: Vec<String>,
+    /// Functions the model asked to call
+    pub tool_calls: Vec<ToolCall>,
+}
+
+impl LLMResponse {
+    /// The assistant message to add to the conversation, with its tool calls
+    ///
+    /// Send it back with a [`LLMMessage::tool_result`] for each call to
+    /// continue after the model has asked for tools.
+    pub fn to_message(&self) -> LLMMessage {
+        LLMMessage {
+            tool_calls: self.tool_calls.clone(),
+            ..LLMMessage::new(LLMRole::Assistant, self.content.clone())
+        }
+    }
+}
+
+/// A function the model may call
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolDefinition {
+    /// Function name
+    pub name: String,
+    /// What the function does, shown to the model
+    pub description: String,
+    /// JSON Schema of the arguments, as a JSON string
+    pub parameters: String,
+}
+
+/// A function call requested by the model
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCall {
+    /// Provider-assigned call ID
+    pub id: String,
+    /// Name of the function to call
+    pub name: String,
+    /// Arguments as a JSON string
+    pub arguments: String,
 }
 
 /// Information about a supported provider
@@ -95,6 +170,22 @@ pub struct ModelInfo {
     pub is_default: bool,
 }
 
+/// Result of probing a provider with an API key
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[derive(Debug, Clone)]
+pub struct ProviderHealth {
+    /// Provider identifier
+    pub provider: String,
+    /// Whether the provider's API answered at all
+    pub reachable: bool,
+    /// Whether the API key was accepted
+    pub authenticated: bool,
+    /// Model identifiers the key has access to
+    pub available_models: Vec<String>,
+    /// Explanation when the provider is unreachable or rejected the key
+    pub message: Option<String>,
+}
+
 /// Error types for LLM operations
 #[cfg_attr(feature = "uniffi", derive(uniffi::Error))]
 #[derive(Debug, thiserror::Error)]
@@ -45,6 +45,8 @@ pub enum SharedStoreError {
     InvalidArgument { message: String },
     #[error("Storage error: {message}")]
     Storage { message: String },
+    #[error("Conflict: {message}")]
+    Conflict { message: String },
 }
 
 impl From<StoreError> for SharedStoreError {
@@ -61,6 +63,9 @@ impl From<StoreError> for SharedStoreError {
                 message: format!("schema not found: {s}"),
             },
             StoreError::Validation(msg) => SharedStoreError::InvalidArgument { message: msg },
+            e @ StoreError::RevisionConflict { .. } => SharedStoreError::Conflict {
+                message: e.to_string(),
+            },
         }
     }
 }
@@ -338,6 +343,7 @@ fn build_item(id: ItemId, schema: String, payload: BTreeMap<String, Value>) -> I
         author: "local".into(),
         author_kind: ActorKind::Human,
         logical_clock: 0,
+        revision: 0,
         origin: None,
         canonical_id: None,
         tags: vec![],
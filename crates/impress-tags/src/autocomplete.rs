@@ -1,7 +1,10 @@
 //! Tag autocomplete engine with ranked suggestions.
 //!
-//! Ranking: recency > shallow depth > frequency > alphabetical.
+//! Ranking: recency > shallow depth > frequency > alphabetical. When a
+//! [`CoOccurrenceModel`] is attached, tags that often appear alongside the
+//! tags already applied get an additional boost.
 
+use crate::cooccurrence::CoOccurrenceModel;
 use crate::tag::Tag;
 use chrono::{DateTime, Utc};
 
@@ -19,12 +22,30 @@ pub struct Suggestion {
 /// Autocomplete engine operating on an in-memory tag list.
 pub struct AutocompleteEngine {
     tags: Vec<Tag>,
+    co_occurrence: CoOccurrenceModel,
 }
 
+/// Maximum score added for a tag that always co-occurs with an applied tag.
+const CO_OCCURRENCE_WEIGHT: f64 = 60.0;
+
 impl AutocompleteEngine {
     /// Create a new engine from a tag list.
     pub fn new(tags: Vec<Tag>) -> Self {
-        Self { tags }
+        Self {
+            tags,
+            co_occurrence: CoOccurrenceModel::default(),
+        }
+    }
+
+    /// Use a co-occurrence model for context-aware ranking.
+    pub fn with_co_occurrence(mut self, model: CoOccurrenceModel) -> Self {
+        self.co_occurrence = model;
+        self
+    }
+
+    /// Replace the co-occurrence model.
+    pub fn set_co_occurrence(&mut self, model: CoOccurrenceModel) {
+        self.co_occurrence = model;
     }
 
     /// Update the tag list.
@@ -34,8 +55,24 @@ impl AutocompleteEngine {
 
     /// Find completions matching a prefix.
     pub fn complete(&self, prefix: &str, limit: usize) -> Vec<Suggestion> {
+        self.complete_with_context(prefix, &[], limit)
+    }
+
+    /// Find completions given the tags already applied to the item.
+    ///
+    /// Matches are boosted by how strongly they co-occur with `applied`, and
+    /// applied tags themselves are never suggested. With an empty prefix,
+    /// tags related to `applied` are suggested; with an empty model this is
+    /// plain prefix matching.
+    pub fn complete_with_context(
+        &self,
+        prefix: &str,
+        applied: &[&str],
+        limit: usize,
+    ) -> Vec<Suggestion> {
         let prefix_lower = prefix.trim().to_lowercase();
-        if prefix_lower.is_empty() {
+        let related = self.co_occurrence.related(applied);
+        if prefix_lower.is_empty() && related.is_empty() {
             return Vec::new();
         }
         let now = Utc::now();
@@ -43,12 +80,18 @@ impl AutocompleteEngine {
         let mut suggestions: Vec<Suggestion> = self
             .tags
             .iter()
+            .filter(|t| !applied.contains(&t.path.as_str()))
             .filter(|t| {
-                t.path.to_lowercase().starts_with(&prefix_lower)
-                    || t.leaf.to_lowercase().starts_with(&prefix_lower)
+                if prefix_lower.is_empty() {
+                    related.contains(t.path.as_str())
+                } else {
+                    t.path.to_lowercase().starts_with(&prefix_lower)
+                        || t.leaf.to_lowercase().starts_with(&prefix_lower)
+                }
             })
             .map(|t| {
-                let score = compute_score(t, &now);
+                let score = compute_score(t, &now)
+                    + self.co_occurrence.affinity(applied, &t.path) * CO_OCCURRENCE_WEIGHT;
                 Suggestion {
                     path: t.path.clone(),
                     leaf: t.leaf.clone(),
@@ -153,6 +196,56 @@ mod tests {
         assert_eq!(results.len(), 2);
     }
 
+    #[test]
+    fn co_occurring_tag_is_suggested() {
+        let mut tags = sample_tags();
+        tags.push(Tag {
+            use_count: 1,
+            ..Tag::new("topics/agn")
+        });
+        let model = CoOccurrenceModel::from_corpus(vec![
+            vec!["methods/sims/hydro", "topics/agn"],
+            vec!["methods/sims/hydro", "topics/agn"],
+            vec!["methods/sims/hydro", "topics/agn", "topics/galaxies"],
+        ]);
+
+        // Cold start: an empty model falls back to prefix matching
+        let engine = AutocompleteEngine::new(tags.clone());
+        assert!(engine.complete_with_context("", &["methods/sims/hydro"], 10).is_empty());
+        let results = engine.complete_with_context("topics", &["methods/sims/hydro"], 10);
+        assert_eq!(results.len(), 2);
+
+        let engine = AutocompleteEngine::new(tags).with_co_occurrence(model);
+        let results = engine.complete_with_context("", &["methods/sims/hydro"], 10);
+        assert_eq!(results[0].path, "topics/agn");
+        assert_eq!(results.len(), 2);
+
+        // Applied tags are not suggested again
+        let results = engine.complete_with_context("methods", &["methods/sims/hydro"], 10);
+        assert!(results.iter().all(|s| s.path != "methods/sims/hydro"));
+    }
+
+    #[test]
+    fn co_occurrence_outranks_base_score() {
+        let tags = vec![
+            Tag {
+                use_count: 5,
+                ..Tag::new("topics/galaxies")
+            },
+            Tag::new("topics/gravitational-waves"),
+            Tag::new("methods/relativity"),
+        ];
+        let model = CoOccurrenceModel::from_corpus(vec![
+            vec!["methods/relativity", "topics/gravitational-waves"],
+            vec!["methods/relativity", "topics/gravitational-waves"],
+        ]);
+        let engine = AutocompleteEngine::new(tags).with_co_occurrence(model);
+
+        assert_eq!(engine.complete("g", 10)[0].path, "topics/galaxies");
+        let results = engine.complete_with_context("g", &["methods/relativity"], 10);
+        assert_eq!(results[0].path, "topics/gravitational-waves");
+    }
+
     #[test]
     fn empty_prefix() {
         let engine = AutocompleteEngine::new(sample_tags());
@@ -0,0 +1,120 @@
+//! Tag co-occurrence model for context-aware suggestions.
+//!
+//! Trained on the tag sets of existing publications, it answers "how often
+//! does tag B appear on items that already carry tag A?".
+
+use std::collections::{BTreeSet, HashMap};
+use serde::{Deserialize, Serialize};
+
+/// Counts of tags and tag pairs seen together on the same item.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CoOccurrenceModel {
+    /// Tag path -> number of tag sets containing it
+    tag_counts: HashMap<String, u32>,
+    /// Tag path -> co-occurring tag path -> number of sets containing both
+    pair_counts: HashMap<String, HashMap<String, u32>>,
+}
+
+impl CoOccurrenceModel {
+    /// Create an empty model.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a model from a corpus of tag sets (one per publication).
+    pub fn from_corpus<I, S, T>(corpus: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let mut model = Self::new();
+        for tags in corpus {
+            model.add_tag_set(tags);
+        }
+        model
+    }
+
+    /// Add one publication's tags to the model.
+    ///
+    /// Duplicate tags within the set are counted once.
+    pub fn add_tag_set<S, T>(&mut self, tags: S)
+    where
+        S: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let tags: BTreeSet<String> = tags
+            .into_iter()
+            .map(|t| t.as_ref().trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+
+        for tag in &tags {
+            *self.tag_counts.entry(tag.clone()).or_insert(0) += 1;
+            let pairs = self.pair_counts.entry(tag.clone()).or_default();
+            for other in tags.iter().filter(|o| *o != tag) {
+                *pairs.entry(other.clone()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Fraction of sets containing `given` that also contain `tag` (0.0–1.0).
+    pub fn strength(&self, given: &str, tag: &str) -> f64 {
+        let given_count = match self.tag_counts.get(given) {
+            Some(&count) if count > 0 => count,
+            _ => return 0.0,
+        };
+        let pair_count = self
+            .pair_counts
+            .get(given)
+            .and_then(|pairs| pairs.get(tag))
+            .copied()
+            .unwrap_or(0);
+        pair_count as f64 / given_count as f64
+    }
+
+    /// Strongest co-occurrence between `tag` and any of the applied tags.
+    pub fn affinity(&self, applied: &[&str], tag: &str) -> f64 {
+        applied
+            .iter()
+            .map(|given| self.strength(given, tag))
+            .fold(0.0, f64::max)
+    }
+
+    /// Tags that have co-occurred with any of the applied tags.
+    pub fn related<'a>(&'a self, applied: &[&str]) -> BTreeSet<&'a str> {
+        applied
+            .iter()
+            .filter_map(|given| self.pair_counts.get(*given))
+            .flat_map(|pairs| pairs.keys().map(|k| k.as_str()))
+            .collect()
+    }
+
+    /// Whether the model has seen any tags.
+    pub fn is_empty(&self) -> bool {
+        self.tag_counts.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strength_is_conditional_frequency() {
+        let model = CoOccurrenceModel::from_corpus(vec![
+            vec!["methods/sims", "topics/galaxies"],
+            vec!["methods/sims", "topics/galaxies"],
+            vec!["methods/sims", "topics/stars"],
+            vec!["topics/galaxies"],
+        ]);
+        assert!((model.strength("methods/sims", "topics/galaxies") - 2.0 / 3.0).abs() < 1e-9);
+        assert!((model.strength("topics/galaxies", "methods/sims") - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(model.strength("methods/sims", "unknown"), 0.0);
+        assert_eq!(model.strength("unknown", "methods/sims"), 0.0);
+        assert_eq!(
+            model.related(&["methods/sims"]).into_iter().collect::<Vec<_>>(),
+            vec!["topics/galaxies", "topics/stars"]
+        );
+    }
+}
@@ -3,29 +3,156 @@
 use std::collections::HashMap;
 use crate::tag::{Tag, TagColor};
 
+/// Default limit on tag depth (`methods` is depth 0, `methods/sims` depth 1).
+pub const DEFAULT_MAX_DEPTH: u32 = 10;
+
+/// Errors from restructuring a tag hierarchy.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum HierarchyError {
+    #[error("Tag not found: {0}")]
+    NotFound(String),
+
+    #[error("Tag already exists: {0}")]
+    AlreadyExists(String),
+
+    #[error(
+        "Cannot move '{path}' under '{new_parent}': it is the tag itself or one of its descendants"
+    )]
+    Cycle { path: String, new_parent: String },
+
+    #[error(
+        "Moving '{path}' would put '{deepest}' at depth {depth}, beyond the maximum of {max_depth}"
+    )]
+    MaxDepthExceeded {
+        path: String,
+        deepest: String,
+        depth: u32,
+        max_depth: u32,
+    },
+}
+
 /// An in-memory tag tree for fast hierarchy operations.
 pub struct TagHierarchy {
     tags: HashMap<String, Tag>,
     children: HashMap<String, Vec<String>>,
+    max_depth: u32,
 }
 
 impl TagHierarchy {
     /// Build a hierarchy from a flat list of tags.
     pub fn from_tags(tags: Vec<Tag>) -> Self {
-        let mut tag_map = HashMap::new();
-        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+        let mut hierarchy = Self {
+            tags: tags
+                .into_iter()
+                .map(|tag| (tag.path.clone(), tag))
+                .collect(),
+            children: HashMap::new(),
+            max_depth: DEFAULT_MAX_DEPTH,
+        };
+        hierarchy.rebuild_children();
+        hierarchy
+    }
 
-        for tag in tags {
+    /// Set the deepest depth that `move_tag` may create.
+    ///
+    /// Tags already deeper than this are left alone; only moves are checked.
+    pub fn with_max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Rebuild the parent -> children index, with children sorted by path.
+    fn rebuild_children(&mut self) {
+        self.children.clear();
+        for tag in self.tags.values() {
             if let Some(parent) = tag.parent_path() {
-                children.entry(parent.to_string()).or_default().push(tag.path.clone());
+                self.children
+                    .entry(parent.to_string())
+                    .or_default()
+                    .push(tag.path.clone());
             }
-            tag_map.insert(tag.path.clone(), tag);
         }
+        for paths in self.children.values_mut() {
+            paths.sort();
+        }
+    }
 
-        Self {
-            tags: tag_map,
-            children,
+    /// Re-parent a tag and its whole subtree, returning the tag's new path.
+    ///
+    /// `new_parent` of `None` makes the tag a root. The move is rejected if
+    /// the new parent is the tag itself or one of its descendants, if any
+    /// moved tag would end up deeper than the maximum depth, or if the new
+    /// path is already taken.
+    pub fn move_tag(
+        &mut self,
+        path: &str,
+        new_parent: Option<&str>,
+    ) -> Result<String, HierarchyError> {
+        let leaf = match self.tags.get(path) {
+            Some(tag) => tag.leaf.clone(),
+            None => return Err(HierarchyError::NotFound(path.to_string())),
+        };
+
+        let (new_path, parent_id) = match new_parent {
+            Some(parent) => {
+                if parent == path || self.tags[path].is_ancestor_of(parent) {
+                    return Err(HierarchyError::Cycle {
+                        path: path.to_string(),
+                        new_parent: parent.to_string(),
+                    });
+                }
+                let parent_tag = self
+                    .tags
+                    .get(parent)
+                    .ok_or_else(|| HierarchyError::NotFound(parent.to_string()))?;
+                (format!("{}/{}", parent, leaf), Some(parent_tag.id))
+            }
+            None => (leaf, None),
+        };
+        if new_path == path {
+            return Ok(new_path);
+        }
+        if self.tags.contains_key(&new_path) {
+            return Err(HierarchyError::AlreadyExists(new_path));
         }
+
+        // Old path -> new path for the tag and every descendant
+        let mut moves: Vec<(String, String)> = vec![(path.to_string(), new_path.clone())];
+        moves.extend(self.descendants_of(path).into_iter().map(|tag| {
+            let rest = &tag.path[path.len()..];
+            (tag.path.clone(), format!("{}{}", new_path, rest))
+        }));
+
+        // Report the first of the deepest paths, so the error is stable
+        let deepest = moves
+            .iter()
+            .map(|(_, p)| p)
+            .max_by_key(|p| (p.matches('/').count(), std::cmp::Reverse(*p)));
+        if let Some(deepest) = deepest {
+            let depth = deepest.matches('/').count() as u32;
+            if depth > self.max_depth {
+                return Err(HierarchyError::MaxDepthExceeded {
+                    path: path.to_string(),
+                    deepest: deepest.clone(),
+                    depth,
+                    max_depth: self.max_depth,
+                });
+            }
+        }
+
+        for (old, new) in moves {
+            if let Some(mut tag) = self.tags.remove(&old) {
+                tag.depth = new.matches('/').count() as u32;
+                tag.path = new;
+                if old == path {
+                    tag.parent_id = parent_id;
+                }
+                self.tags.insert(tag.path.clone(), tag);
+            }
+        }
+        self.rebuild_children();
+
+        Ok(new_path)
     }
 
     /// Get a tag by path.
@@ -46,7 +173,7 @@ impl TagHierarchy {
             .collect()
     }
 
-    /// Get direct children of a path.
+    /// Get direct children of a path, sorted by path.
     pub fn children_of(&self, path: &str) -> Vec<&Tag> {
         self.children
             .get(path)
@@ -197,6 +324,28 @@ mod tests {
         assert_eq!(children, vec!["methods/sims/hydro", "methods/sims/nbody"]);
     }
 
+    #[test]
+    fn children_are_sorted_by_path() {
+        let mut h = sample_hierarchy();
+        let children: Vec<&str> = h
+            .children_of("methods")
+            .iter()
+            .map(|t| t.path.as_str())
+            .collect();
+        assert_eq!(children, vec!["methods/obs", "methods/sims"]);
+
+        h.move_tag("topics/galaxies", Some("methods")).unwrap();
+        let children: Vec<&str> = h
+            .children_of("methods")
+            .iter()
+            .map(|t| t.path.as_str())
+            .collect();
+        assert_eq!(
+            children,
+            vec!["methods/galaxies", "methods/obs", "methods/sims"]
+        );
+    }
+
     #[test]
     fn descendants() {
         let h = sample_hierarchy();
@@ -215,6 +364,57 @@ mod tests {
         assert_eq!(ancs, vec!["methods", "methods/sims"]);
     }
 
+    #[test]
+    fn move_under_own_descendant_is_rejected() {
+        let mut h = sample_hierarchy();
+        let err = h.move_tag("methods", Some("methods/sims")).unwrap_err();
+        assert_eq!(
+            err,
+            HierarchyError::Cycle {
+                path: "methods".into(),
+                new_parent: "methods/sims".into(),
+            }
+        );
+        assert!(matches!(
+            h.move_tag("methods", Some("methods")),
+            Err(HierarchyError::Cycle { .. })
+        ));
+        assert!(h.get("methods/sims/hydro").is_some());
+    }
+
+    #[test]
+    fn move_respects_max_depth() {
+        let mut h = sample_hierarchy().with_max_depth(2);
+
+        // methods/sims/hydro would land at topics/galaxies/sims/hydro (depth 3)
+        let err = h
+            .move_tag("methods/sims", Some("topics/galaxies"))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            HierarchyError::MaxDepthExceeded { depth: 3, max_depth: 2, ref deepest, .. }
+                if deepest == "topics/galaxies/sims/hydro"
+        ));
+        assert!(h.get("methods/sims").is_some());
+
+        let new_path = h.move_tag("methods/sims", Some("topics")).unwrap();
+        assert_eq!(new_path, "topics/sims");
+        assert!(h.get("methods/sims").is_none());
+        let hydro = h.get("topics/sims/hydro").unwrap();
+        assert_eq!(hydro.depth, 2);
+        let mut children: Vec<&str> = h
+            .children_of("topics")
+            .iter()
+            .map(|t| t.path.as_str())
+            .collect();
+        children.sort();
+        assert_eq!(children, vec!["topics/galaxies", "topics/sims"]);
+        assert_eq!(
+            h.get("topics/sims").unwrap().parent_id,
+            Some(h.get("topics").unwrap().id)
+        );
+    }
+
     #[test]
     fn format_tree() {
         let h = sample_hierarchy();
@@ -12,6 +12,7 @@ pub mod hierarchy;
 pub mod parse;
 pub mod query;
 pub mod autocomplete;
+pub mod cooccurrence;
 pub mod alias;
 pub mod config;
 
@@ -20,5 +21,6 @@ pub use hierarchy::*;
 pub use parse::*;
 pub use query::*;
 pub use autocomplete::*;
+pub use cooccurrence::*;
 pub use alias::*;
 pub use config::*;
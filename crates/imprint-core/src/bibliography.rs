@@ -46,6 +46,49 @@ pub enum BibliographyError {
 /// Result type for bibliography operations
 pub type BibliographyResult<T> = Result<T, BibliographyError>;
 
+/// Order of the reference list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum BibliographyOrder {
+    /// By first citation in the document; uncited entries follow by key
+    #[default]
+    AsCited,
+    /// By first author's family name, then year and title
+    Alphabetical,
+    /// Oldest first, then alphabetically
+    ByYear,
+}
+
+/// Citation style family, which determines the usual reference order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CitationStyle {
+    /// Numbered citations, e.g. "[1]"
+    #[default]
+    Numeric,
+    /// Author–year citations, e.g. "(Smith 2023)"
+    AuthorYear,
+}
+
+impl CitationStyle {
+    /// The reference order journals expect for this style
+    pub fn default_order(&self) -> BibliographyOrder {
+        match self {
+            Self::Numeric => BibliographyOrder::AsCited,
+            Self::AuthorYear => BibliographyOrder::Alphabetical,
+        }
+    }
+}
+
+/// One formatted item of a rendered reference list
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenderedReference {
+    /// 1-based position in the list; the in-text number under `AsCited`
+    pub number: usize,
+    /// The citation key
+    pub key: String,
+    /// Formatted reference text
+    pub text: String,
+}
+
 /// A bibliography entry linking a citation key to a publication
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BibliographyEntry {
@@ -174,6 +217,63 @@ impl Bibliography {
         Ok(())
     }
 
+    /// Render the reference list in the given order
+    pub fn render_ordered(&self, order: BibliographyOrder) -> Vec<RenderedReference> {
+        let mut entries: Vec<&BibliographyEntry> = self.entries.values().collect();
+        match order {
+            BibliographyOrder::AsCited => {
+                // Cited entries first, by first position; uncited ones after, by key
+                entries.sort_by(|a, b| {
+                    let first = |e: &BibliographyEntry| {
+                        e.citation_positions.first().copied().unwrap_or(usize::MAX)
+                    };
+                    first(a).cmp(&first(b)).then_with(|| a.key.cmp(&b.key))
+                });
+            }
+            BibliographyOrder::Alphabetical => {
+                entries.sort_by_cached_key(|e| alphabetical_key(e));
+            }
+            BibliographyOrder::ByYear => {
+                entries.sort_by_cached_key(|e| {
+                    (e.publication.year.unwrap_or(i32::MAX), alphabetical_key(e))
+                });
+            }
+        }
+
+        entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, entry)| RenderedReference {
+                number: i + 1,
+                key: entry.key.clone(),
+                text: format_reference(&entry.publication),
+            })
+            .collect()
+    }
+
+    /// Render the reference list in the order the style expects
+    pub fn render(&self, style: CitationStyle) -> Vec<RenderedReference> {
+        self.render_ordered(style.default_order())
+    }
+
+    /// In-text number for a cited key under numeric styles
+    ///
+    /// Matches the numbering of `render_ordered(BibliographyOrder::AsCited)`.
+    /// Returns `None` for unknown or uncited keys.
+    pub fn citation_number(&self, key: &str) -> Option<usize> {
+        let first = *self.entries.get(key)?.citation_positions.first()?;
+        let earlier = self
+            .entries
+            .values()
+            .filter(|e| {
+                e.citation_positions
+                    .first()
+                    .is_some_and(|&p| p < first || (p == first && e.key.as_str() < key))
+            })
+            .count();
+        Some(earlier + 1)
+    }
+
     /// Get all citation keys sorted alphabetically
     pub fn keys(&self) -> Vec<&str> {
         let mut keys: Vec<_> = self.entries.keys().map(String::as_str).collect();
@@ -227,6 +327,53 @@ impl Bibliography {
     }
 }
 
+/// Sort key for alphabetical order: first author (or title), year, title, key
+fn alphabetical_key(entry: &BibliographyEntry) -> (String, i32, String, String) {
+    let publication = &entry.publication;
+    let lead = publication
+        .authors
+        .first()
+        .map(|a| a.family_name.to_lowercase())
+        .unwrap_or_else(|| publication.title.to_lowercase());
+    (
+        lead,
+        publication.year.unwrap_or(i32::MAX),
+        publication.title.to_lowercase(),
+        entry.key.clone(),
+    )
+}
+
+/// Format a publication as "Authors (Year). Title. Venue."
+fn format_reference(publication: &Publication) -> String {
+    let names: Vec<&str> = publication
+        .authors
+        .iter()
+        .map(|a| a.family_name.as_str())
+        .collect();
+    let authors = match names.as_slice() {
+        [] => "Anonymous".to_string(),
+        [one] => one.to_string(),
+        [first, second] => format!("{} and {}", first, second),
+        [rest @ .., last] => format!("{}, and {}", rest.join(", "), last),
+    };
+    let year = publication
+        .year
+        .map(|y| y.to_string())
+        .unwrap_or_else(|| "n.d.".to_string());
+
+    let mut text = format!("{} ({}). {}.", authors, year, publication.title);
+    if let Some(venue) = publication
+        .journal
+        .as_ref()
+        .or(publication.booktitle.as_ref())
+    {
+        text.push(' ');
+        text.push_str(venue);
+        text.push('.');
+    }
+    text
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -258,4 +405,60 @@ mod tests {
         let key2 = bib.add_publication(sample_publication()).unwrap();
         assert_eq!(key2, "smith2023a");
     }
+
+    fn publication(key: &str, author: &str, year: i32) -> Publication {
+        let mut pub_ = Publication::new(
+            key.to_string(),
+            "article".to_string(),
+            format!("Work by {}", author),
+        );
+        pub_.year = Some(year);
+        pub_.authors = vec![Author::new(author.to_string())];
+        pub_
+    }
+
+    fn sample_bibliography() -> Bibliography {
+        let mut bib = Bibliography::new();
+        bib.add_with_key("zwicky1933", publication("zwicky1933", "Zwicky", 1933))
+            .unwrap();
+        bib.add_with_key("hubble1929", publication("hubble1929", "Hubble", 1929))
+            .unwrap();
+        bib.add_with_key("rubin1980", publication("rubin1980", "Rubin", 1980))
+            .unwrap();
+        bib
+    }
+
+    #[test]
+    fn test_render_alphabetical() {
+        let bib = sample_bibliography();
+        let rendered = bib.render_ordered(BibliographyOrder::Alphabetical);
+        let keys: Vec<&str> = rendered.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, vec!["hubble1929", "rubin1980", "zwicky1933"]);
+        assert_eq!(rendered[0].number, 1);
+        assert_eq!(rendered[0].text, "Hubble (1929). Work by Hubble.");
+
+        let by_year = bib.render_ordered(BibliographyOrder::ByYear);
+        let keys: Vec<&str> = by_year.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, vec!["hubble1929", "zwicky1933", "rubin1980"]);
+        assert_eq!(bib.render(CitationStyle::AuthorYear), rendered);
+    }
+
+    #[test]
+    fn test_render_as_cited_numbering() {
+        let mut bib = sample_bibliography();
+        bib.cite("rubin1980", 40).unwrap();
+        bib.cite("zwicky1933", 10).unwrap();
+        bib.cite("rubin1980", 5).unwrap();
+
+        let rendered = bib.render(CitationStyle::Numeric);
+        let keys: Vec<&str> = rendered.iter().map(|r| r.key.as_str()).collect();
+        // Uncited entries go last
+        assert_eq!(keys, vec!["rubin1980", "zwicky1933", "hubble1929"]);
+
+        for reference in &rendered[..2] {
+            assert_eq!(bib.citation_number(&reference.key), Some(reference.number));
+        }
+        assert_eq!(bib.citation_number("hubble1929"), None);
+        assert_eq!(bib.citation_number("missing"), None);
+    }
 }
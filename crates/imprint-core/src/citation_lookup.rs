@@ -5,6 +5,7 @@
 //!
 //! - Conversion from `Publication` to `CitationReference` (for cross-app transfer)
 //! - A `LocalLibraryCitationProvider` for resolving citations from a local collection
+//! - An `ImbibCitationProvider` that reads an imbib library (`.bib` files) from disk
 //! - Utilities for creating citation references from search results
 //!
 //! # Architecture
@@ -33,14 +34,18 @@
 //! ```
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
 
+use impress_bibtex::BibTeXEntry;
 use impress_domain::{
-    Author, CitationMetadata, CitationReference as CrossAppCitationReference, Identifiers,
-    Publication,
+    parse_author_string, Author, CitationMetadata, CitationReference as CrossAppCitationReference,
+    Identifiers, Publication,
 };
 
 use crate::bibliography::Bibliography;
-use crate::citations::{CitationProvider, CitationReference, CitationResult};
+use crate::citations::{CitationError, CitationProvider, CitationReference, CitationResult};
 
 // Re-export the cross-app citation reference for convenience
 pub use impress_domain::CitationBatch;
@@ -297,6 +302,201 @@ impl CitationProvider for LocalLibraryCitationProvider {
     }
 }
 
+/// A citation provider backed by an imbib library on disk
+///
+/// The library path is either a `.bib` file or a directory whose `.bib`
+/// files are read together. Publications are parsed on first use and
+/// cached; the cache is rebuilt whenever a file's modification time changes
+/// (or a file is added or removed), so edits made in imbib are picked up
+/// without reopening the document.
+pub struct ImbibCitationProvider {
+    name: String,
+    path: PathBuf,
+    cache: Mutex<Option<CachedLibrary>>,
+}
+
+/// Parsed library plus the file stamps it was parsed from
+struct CachedLibrary {
+    stamps: Vec<(PathBuf, SystemTime)>,
+    library: LocalLibraryCitationProvider,
+}
+
+impl ImbibCitationProvider {
+    /// Create a provider for the library at `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            name: "imbib Library".to_string(),
+            path: path.into(),
+            cache: Mutex::new(None),
+        }
+    }
+
+    /// Builder: set the display name
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// The library path
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Resolve a cite key to its full publication metadata
+    pub fn publication(&self, cite_key: &str) -> CitationResult<Option<Publication>> {
+        self.resolve(&CitationReference::from_key(cite_key))
+    }
+
+    /// Resolve a cite key to a formatted citation (preview, metadata, and BibTeX)
+    pub fn citation(&self, cite_key: &str) -> CitationResult<Option<CrossAppCitationReference>> {
+        Ok(self
+            .publication(cite_key)?
+            .map(|pub_| publication_to_citation_reference(&pub_)))
+    }
+
+    /// Number of publications in the library
+    pub fn len(&self) -> CitationResult<usize> {
+        self.with_library(|library| Ok(library.len()))
+    }
+
+    /// Check if the library has no publications
+    pub fn is_empty(&self) -> CitationResult<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Discard the cached library so the next lookup re-reads it
+    pub fn invalidate(&self) {
+        if let Ok(mut cache) = self.cache.lock() {
+            *cache = None;
+        }
+    }
+
+    /// Run `f` against the parsed library, reloading it if the files changed
+    fn with_library<T>(
+        &self,
+        f: impl FnOnce(&LocalLibraryCitationProvider) -> CitationResult<T>,
+    ) -> CitationResult<T> {
+        let stamps = self.source_stamps()?;
+        let mut cache = self
+            .cache
+            .lock()
+            .map_err(|_| CitationError::ProviderError("library cache is poisoned".to_string()))?;
+
+        let cached = match cache.take() {
+            Some(cached) if cached.stamps == stamps => cached,
+            _ => CachedLibrary {
+                library: self.load(&stamps)?,
+                stamps,
+            },
+        };
+        let result = f(&cached.library);
+        *cache = Some(cached);
+        result
+    }
+
+    /// Library files with their modification times, sorted by path
+    fn source_stamps(&self) -> CitationResult<Vec<(PathBuf, SystemTime)>> {
+        let files = if self.path.is_dir() {
+            let entries = std::fs::read_dir(&self.path).map_err(|e| read_error(&self.path, e))?;
+            let mut files = Vec::new();
+            for entry in entries {
+                let path = entry.map_err(|e| read_error(&self.path, e))?.path();
+                let is_bib = path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("bib"));
+                if is_bib && path.is_file() {
+                    files.push(path);
+                }
+            }
+            files.sort();
+            files
+        } else {
+            vec![self.path.clone()]
+        };
+
+        files
+            .into_iter()
+            .map(|path| {
+                let modified = std::fs::metadata(&path)
+                    .and_then(|m| m.modified())
+                    .map_err(|e| read_error(&path, e))?;
+                Ok((path, modified))
+            })
+            .collect()
+    }
+
+    /// Parse the library files into an in-memory provider
+    fn load(
+        &self,
+        stamps: &[(PathBuf, SystemTime)],
+    ) -> CitationResult<LocalLibraryCitationProvider> {
+        let mut library = LocalLibraryCitationProvider::new(self.name.clone());
+        for (path, _) in stamps {
+            let content = std::fs::read_to_string(path).map_err(|e| read_error(path, e))?;
+            let parsed = impress_bibtex::parse(content).map_err(|e| {
+                CitationError::ProviderError(format!("Cannot parse {}: {}", path.display(), e))
+            })?;
+            for entry in parsed.entries {
+                library.add_publication(entry_to_publication(entry));
+            }
+        }
+        Ok(library)
+    }
+}
+
+impl CitationProvider for ImbibCitationProvider {
+    fn resolve(&self, reference: &CitationReference) -> CitationResult<Option<Publication>> {
+        self.with_library(|library| library.resolve(reference))
+    }
+
+    fn search(&self, query: &str) -> CitationResult<Vec<Publication>> {
+        self.with_library(|library| library.search(query))
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+fn read_error(path: &Path, error: std::io::Error) -> CitationError {
+    CitationError::ProviderError(format!("Cannot read {}: {}", path.display(), error))
+}
+
+/// Convert a parsed BibTeX entry into a publication
+fn entry_to_publication(entry: BibTeXEntry) -> Publication {
+    let mut pub_ = Publication::new(
+        entry.cite_key,
+        entry.entry_type.as_str().to_string(),
+        String::new(),
+    );
+
+    for field in entry.fields {
+        match field.key.to_lowercase().as_str() {
+            "author" => pub_.authors = parse_author_string(field.value),
+            "editor" => pub_.editors = parse_author_string(field.value),
+            "keywords" => {
+                pub_.keywords = field
+                    .value
+                    .split(',')
+                    .map(|k| k.trim().to_string())
+                    .filter(|k| !k.is_empty())
+                    .collect()
+            }
+            "eprint" => {
+                // New-style (2301.12345) and old-style (astro-ph/0601001) arXiv IDs
+                if field.value.contains('.') || field.value.contains('/') {
+                    pub_.identifiers.arxiv_id = Some(field.value.clone());
+                }
+                pub_.eprint = Some(field.value);
+            }
+            _ => pub_.set_field(&field.key, field.value),
+        }
+    }
+
+    pub_.raw_bibtex = entry.raw_bibtex;
+    pub_
+}
+
 /// Builder for creating cross-app citations from various inputs
 pub struct CitationBuilder {
     cite_key: String,
@@ -585,4 +785,60 @@ mod tests {
         let result = provider.resolve(&CitationReference::from_doi("10.1234/example"));
         assert!(result.unwrap().is_none());
     }
+
+    const LIBRARY_BIB: &str = r#"@article{smith2024machine,
+  author = {Smith, John and Jones, Ann},
+  title = {Machine Learning for Science},
+  journal = {Nature},
+  year = {2024},
+  doi = {10.1234/example}
+}
+"#;
+
+    #[test]
+    fn test_imbib_provider_resolves_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("library.bib");
+        std::fs::write(&path, LIBRARY_BIB).unwrap();
+        let provider = ImbibCitationProvider::new(&path);
+
+        let pub_ = provider.publication("smith2024machine").unwrap().unwrap();
+        assert_eq!(pub_.year, Some(2024));
+        assert_eq!(pub_.authors.len(), 2);
+        assert_eq!(pub_.journal.as_deref(), Some("Nature"));
+
+        let citation = provider.citation("smith2024machine").unwrap().unwrap();
+        assert_eq!(
+            citation.formatted_preview.as_deref(),
+            Some("Smith & Jones (2024)")
+        );
+
+        let by_doi = provider.resolve(&CitationReference::from_doi("10.1234/EXAMPLE"));
+        assert!(by_doi.unwrap().is_some());
+
+        assert!(provider.publication("unknown2020").unwrap().is_none());
+        assert!(provider.citation("unknown2020").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_imbib_provider_reloads_on_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("library.bib");
+        std::fs::write(&path, LIBRARY_BIB).unwrap();
+        let provider = ImbibCitationProvider::new(dir.path());
+        assert_eq!(provider.len().unwrap(), 1);
+
+        let updated = format!(
+            "{}\n@book{{jones2020deep,\n  title = {{Deep Learning}},\n  year = {{2020}}\n}}\n",
+            LIBRARY_BIB
+        );
+        std::fs::write(&path, updated).unwrap();
+        // Make the change visible even on filesystems with coarse mtimes
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + std::time::Duration::from_secs(5))
+            .unwrap();
+
+        assert_eq!(provider.len().unwrap(), 2);
+        assert!(provider.publication("jones2020deep").unwrap().is_some());
+    }
 }
@@ -4,6 +4,7 @@
 //!
 //! - **Sync**: Synchronization of document changes between peers
 //! - **Presence**: Tracking of connected users and their cursor positions
+//! - **Comments**: Review comment threads anchored to document text
 //! - **Conflict resolution**: Automatic CRDT-based conflict resolution
 //!
 //! # Architecture
@@ -27,10 +28,13 @@
 //! let messages = session.generate_sync_messages(peer_id);
 //! ```
 
-use crate::document::ImprintDocument;
+use crate::document::{DocumentError, ImprintDocument, TextAnchor};
 use automerge::sync::{Message, State as SyncState, SyncDoc};
+use automerge::transaction::Transactable;
+use automerge::{ObjType, ReadDoc, Value};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::ops::Range;
 use thiserror::Error;
 
 /// Errors that can occur during collaboration
@@ -51,6 +55,14 @@ pub enum CollaborationError {
     /// Document error
     #[error("Document error: {0}")]
     DocumentError(#[from] crate::document::DocumentError),
+
+    /// No comment with this ID
+    #[error("Unknown comment: {0}")]
+    UnknownComment(String),
+
+    /// A stored comment could not be read or written
+    #[error("Invalid comment: {0}")]
+    InvalidComment(String),
 }
 
 /// Result type for collaboration operations
@@ -126,6 +138,289 @@ impl Presence {
     }
 }
 
+/// Key of the comments map at the document root
+pub(crate) const COMMENTS_KEY: &str = "comments";
+
+/// A reply in a comment thread
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommentReply {
+    /// Who wrote the reply
+    pub author: PeerId,
+    /// Reply text
+    pub body: String,
+    /// Creation timestamp (Unix milliseconds)
+    pub created_at: i64,
+}
+
+/// A review comment attached to a range of document text
+///
+/// Comments are stored in the Automerge document, so they sync with the
+/// text, and the anchor follows edits made around it. Each comment is an
+/// Automerge map with its replies in an Automerge list, so replies and
+/// resolves made concurrently by different peers all survive a merge.
+/// Deleting all of the anchored text orphans the comment rather than
+/// removing it: [`ImprintDocument::comment_range`] then returns `None`, and
+/// `quote` still shows what it was attached to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Comment {
+    /// Unique comment ID
+    pub id: String,
+    /// Who opened the thread
+    pub author: PeerId,
+    /// Comment text
+    pub body: String,
+    /// The commented text range
+    pub anchor: TextAnchor,
+    /// The commented text at the time the comment was made
+    pub quote: String,
+    /// Whether the thread has been resolved
+    pub resolved: bool,
+    /// Replies, oldest first
+    pub replies: Vec<CommentReply>,
+    /// Creation timestamp (Unix milliseconds)
+    pub created_at: i64,
+}
+
+impl ImprintDocument {
+    /// Comment on a character range, returning the new comment's ID
+    pub fn add_comment(
+        &mut self,
+        author: impl Into<PeerId>,
+        body: impl Into<String>,
+        range: Range<usize>,
+    ) -> CollaborationResult<String> {
+        let anchor = self.anchor_range(range.clone())?;
+        let quote = self
+            .text()?
+            .chars()
+            .skip(range.start)
+            .take(range.len())
+            .collect();
+        let comment = Comment {
+            id: uuid::Uuid::new_v4().to_string(),
+            author: author.into(),
+            body: body.into(),
+            anchor,
+            quote,
+            resolved: false,
+            replies: Vec::new(),
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+        self.put_comment(&comment)?;
+        Ok(comment.id)
+    }
+
+    /// Add a reply to a comment thread
+    pub fn reply_to_comment(
+        &mut self,
+        id: &str,
+        author: impl Into<PeerId>,
+        body: impl Into<String>,
+    ) -> CollaborationResult<()> {
+        let obj = self.require_comment_object(id)?;
+        let replies = match self
+            .automerge()
+            .get(&obj, "replies")
+            .map_err(DocumentError::from)?
+        {
+            Some((Value::Object(ObjType::List), replies)) => replies,
+            _ => return Err(invalid(id, "replies")),
+        };
+        let reply = CommentReply {
+            author: author.into(),
+            body: body.into(),
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+
+        let doc = self.automerge_mut();
+        let index = doc.length(&replies);
+        let reply_obj = doc
+            .insert_object(&replies, index, ObjType::Map)
+            .map_err(DocumentError::from)?;
+        doc.put(&reply_obj, "author", reply.author)
+            .map_err(DocumentError::from)?;
+        doc.put(&reply_obj, "body", reply.body)
+            .map_err(DocumentError::from)?;
+        doc.put(&reply_obj, "created_at", reply.created_at)
+            .map_err(DocumentError::from)?;
+        Ok(())
+    }
+
+    /// Mark a comment thread as resolved
+    pub fn resolve_comment(&mut self, id: &str) -> CollaborationResult<()> {
+        let obj = self.require_comment_object(id)?;
+        self.automerge_mut()
+            .put(&obj, "resolved", true)
+            .map_err(DocumentError::from)?;
+        Ok(())
+    }
+
+    /// Get a comment by ID
+    pub fn comment(&self, id: &str) -> CollaborationResult<Option<Comment>> {
+        match self.comment_object(id)? {
+            Some(obj) => Ok(Some(self.decode_comment(id, &obj)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// All comments, oldest first
+    pub fn comments(&self) -> CollaborationResult<Vec<Comment>> {
+        let Some(map) = self.comments_map() else {
+            return Ok(Vec::new());
+        };
+        let mut comments = Vec::new();
+        for id in self.automerge().keys(&map) {
+            if let Some(comment) = self.comment(&id)? {
+                comments.push(comment);
+            }
+        }
+        comments.sort_by(|a, b| (a.created_at, &a.id).cmp(&(b.created_at, &b.id)));
+        Ok(comments)
+    }
+
+    /// Current text range of a comment, or `None` if it has been orphaned
+    pub fn comment_range(&self, comment: &Comment) -> CollaborationResult<Option<Range<usize>>> {
+        let range = self.resolve_anchor(&comment.anchor)?;
+        Ok((!range.is_empty()).then_some(range))
+    }
+
+    fn comment_object(&self, id: &str) -> CollaborationResult<Option<automerge::ObjId>> {
+        let Some(map) = self.comments_map() else {
+            return Ok(None);
+        };
+        match self
+            .automerge()
+            .get(&map, id)
+            .map_err(DocumentError::from)?
+        {
+            Some((Value::Object(ObjType::Map), obj)) => Ok(Some(obj)),
+            Some(_) => Err(CollaborationError::InvalidComment(format!(
+                "{} is not a map",
+                id
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    fn require_comment_object(&self, id: &str) -> CollaborationResult<automerge::ObjId> {
+        self.comment_object(id)?
+            .ok_or_else(|| CollaborationError::UnknownComment(id.to_string()))
+    }
+
+    fn comments_map(&self) -> Option<automerge::ObjId> {
+        match self.automerge().get(automerge::ROOT, COMMENTS_KEY) {
+            Ok(Some((Value::Object(ObjType::Map), id))) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Store a new comment as a map in the comments map
+    fn put_comment(&mut self, comment: &Comment) -> CollaborationResult<()> {
+        // The anchor never changes once written, so it is kept as one value
+        let anchor = serde_json::to_string(&comment.anchor)
+            .map_err(|e| CollaborationError::InvalidComment(e.to_string()))?;
+        let map = match self.comments_map() {
+            Some(map) => map,
+            None => self
+                .automerge_mut()
+                .put_object(automerge::ROOT, COMMENTS_KEY, ObjType::Map)
+                .map_err(DocumentError::from)?,
+        };
+
+        let doc = self.automerge_mut();
+        let obj = doc
+            .put_object(&map, comment.id.as_str(), ObjType::Map)
+            .map_err(DocumentError::from)?;
+        doc.put(&obj, "author", comment.author.as_str())
+            .map_err(DocumentError::from)?;
+        doc.put(&obj, "body", comment.body.as_str())
+            .map_err(DocumentError::from)?;
+        doc.put(&obj, "anchor", anchor)
+            .map_err(DocumentError::from)?;
+        doc.put(&obj, "quote", comment.quote.as_str())
+            .map_err(DocumentError::from)?;
+        doc.put(&obj, "resolved", comment.resolved)
+            .map_err(DocumentError::from)?;
+        doc.put(&obj, "created_at", comment.created_at)
+            .map_err(DocumentError::from)?;
+        doc.put_object(&obj, "replies", ObjType::List)
+            .map_err(DocumentError::from)?;
+        Ok(())
+    }
+
+    /// Read a comment map back into a [`Comment`]
+    fn decode_comment(&self, id: &str, obj: &automerge::ObjId) -> CollaborationResult<Comment> {
+        let doc = self.automerge();
+        let anchor = serde_json::from_str(&get_str(doc, obj, "anchor", id)?)
+            .map_err(|e| CollaborationError::InvalidComment(e.to_string()))?;
+        let resolved = match doc.get(obj, "resolved").map_err(DocumentError::from)? {
+            Some((value, _)) => value.to_bool().ok_or_else(|| invalid(id, "resolved"))?,
+            None => false,
+        };
+
+        let mut replies = Vec::new();
+        if let Some((Value::Object(ObjType::List), list)) =
+            doc.get(obj, "replies").map_err(DocumentError::from)?
+        {
+            for index in 0..doc.length(&list) {
+                if let Some((Value::Object(ObjType::Map), reply)) =
+                    doc.get(&list, index).map_err(DocumentError::from)?
+                {
+                    replies.push(CommentReply {
+                        author: get_str(doc, &reply, "author", id)?,
+                        body: get_str(doc, &reply, "body", id)?,
+                        created_at: get_i64(doc, &reply, "created_at", id)?,
+                    });
+                }
+            }
+        }
+
+        Ok(Comment {
+            id: id.to_string(),
+            author: get_str(doc, obj, "author", id)?,
+            body: get_str(doc, obj, "body", id)?,
+            anchor,
+            quote: get_str(doc, obj, "quote", id)?,
+            resolved,
+            replies,
+            created_at: get_i64(doc, obj, "created_at", id)?,
+        })
+    }
+}
+
+fn invalid(id: &str, field: &str) -> CollaborationError {
+    CollaborationError::InvalidComment(format!("{}: bad or missing {}", id, field))
+}
+
+/// Read a text field of a comment or reply map
+fn get_str(
+    doc: &impl ReadDoc,
+    obj: &automerge::ObjId,
+    field: &str,
+    id: &str,
+) -> CollaborationResult<String> {
+    match doc.get(obj, field).map_err(DocumentError::from)? {
+        Some((value, _)) => value
+            .to_str()
+            .map(str::to_string)
+            .ok_or_else(|| invalid(id, field)),
+        None => Err(invalid(id, field)),
+    }
+}
+
+/// Read an integer field of a comment or reply map
+fn get_i64(
+    doc: &impl ReadDoc,
+    obj: &automerge::ObjId,
+    field: &str,
+    id: &str,
+) -> CollaborationResult<i64> {
+    match doc.get(obj, field).map_err(DocumentError::from)? {
+        Some((value, _)) => value.to_i64().ok_or_else(|| invalid(id, field)),
+        None => Err(invalid(id, field)),
+    }
+}
+
 /// A collaborative editing session
 ///
 /// Manages sync state with multiple peers and tracks presence information.
@@ -614,4 +909,68 @@ mod tests {
             assert_eq!(bytes, bytes2);
         }
     }
+
+    #[test]
+    fn test_comment_anchor_follows_insert() {
+        let mut doc = ImprintDocument::new();
+        doc.insert_text(0, "Halos grow by accretion.").unwrap();
+        let id = doc.add_comment("alice", "Cite a source", 14..23).unwrap();
+
+        doc.insert_text(0, "Dark matter ").unwrap();
+        let comment = doc.comment(&id).unwrap().unwrap();
+        assert_eq!(comment.quote, "accretion");
+        assert_eq!(doc.comment_range(&comment).unwrap(), Some(26..35));
+
+        doc.reply_to_comment(&id, "bob", "Added one").unwrap();
+        doc.resolve_comment(&id).unwrap();
+        let comments = doc.comments().unwrap();
+        assert_eq!(comments.len(), 1);
+        assert!(comments[0].resolved);
+        assert_eq!(comments[0].replies[0].author, "bob");
+
+        assert!(matches!(
+            doc.resolve_comment("missing"),
+            Err(CollaborationError::UnknownComment(_))
+        ));
+    }
+
+    #[test]
+    fn test_concurrent_comment_replies_merge() {
+        let mut doc1 = ImprintDocument::new();
+        doc1.insert_text(0, "Halos grow by accretion.").unwrap();
+        let id = doc1.add_comment("alice", "Cite a source", 14..23).unwrap();
+        let mut doc2 = ImprintDocument::from_bytes(&doc1.to_bytes()).unwrap();
+
+        // Both peers reply while apart, and one resolves the thread
+        doc1.reply_to_comment(&id, "bob", "Added one").unwrap();
+        doc2.reply_to_comment(&id, "carol", "Which one?").unwrap();
+        doc2.resolve_comment(&id).unwrap();
+
+        doc1.merge(&mut doc2).unwrap();
+        doc2.merge(&mut doc1).unwrap();
+
+        let merged1 = doc1.comment(&id).unwrap().unwrap();
+        let merged2 = doc2.comment(&id).unwrap().unwrap();
+        assert_eq!(merged1, merged2);
+        assert!(merged1.resolved);
+        assert_eq!(merged1.body, "Cite a source");
+        let mut authors: Vec<&str> = merged1.replies.iter().map(|r| r.author.as_str()).collect();
+        authors.sort();
+        assert_eq!(authors, vec!["bob", "carol"]);
+    }
+
+    #[test]
+    fn test_comment_orphaned_when_text_deleted() {
+        let mut doc = ImprintDocument::new();
+        doc.insert_text(0, "Halos grow by accretion.").unwrap();
+        let id = doc.add_comment("alice", "Cite a source", 14..23).unwrap();
+
+        doc.delete_text(13, 10).unwrap();
+        assert_eq!(doc.text().unwrap(), "Halos grow by.");
+
+        let comment = doc.comment(&id).unwrap().unwrap();
+        assert_eq!(doc.comment_range(&comment).unwrap(), None);
+        assert_eq!(comment.body, "Cite a source");
+        assert_eq!(comment.quote, "accretion");
+    }
 }
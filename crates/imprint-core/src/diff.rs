@@ -0,0 +1,275 @@
+//! Word-level diff between document versions
+//!
+//! Produces a sequence of equal / deleted / inserted spans for a
+//! track-changes view. Text is split into words, whitespace runs, and single
+//! punctuation characters, and the token sequences are compared with the
+//! Myers algorithm. Concatenating the `Equal` and `Delete` spans gives the old
+//! text back; `Equal` and `Insert` spans give the new text.
+//!
+//! Within each changed region deletions are listed before insertions, so a
+//! replaced word reads as "~~old~~ new". A paragraph that moved shows up as
+//! a deletion at its old place and an insertion at its new one.
+
+use serde::{Deserialize, Serialize};
+
+/// Kind of change a diff span represents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum DiffOp {
+    /// Present in both versions
+    Equal,
+    /// Only in the new version
+    Insert,
+    /// Only in the old version
+    Delete,
+}
+
+/// A run of text with the same diff status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct DiffSpan {
+    /// What happened to this text
+    pub op: DiffOp,
+    /// The text itself
+    pub text: String,
+}
+
+impl DiffSpan {
+    fn new(op: DiffOp, text: impl Into<String>) -> Self {
+        Self {
+            op,
+            text: text.into(),
+        }
+    }
+}
+
+/// Word-level diff from `old` to `new`
+pub fn diff_words(old: &str, new: &str) -> Vec<DiffSpan> {
+    let a = tokenize(old);
+    let b = tokenize(new);
+
+    // Common prefix and suffix don't need the O(ND) search
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    let mut ops: Vec<(DiffOp, &str)> = Vec::with_capacity(a.len().max(b.len()));
+    ops.extend(a[..prefix].iter().map(|t| (DiffOp::Equal, *t)));
+    ops.extend(myers(
+        &a[prefix..a.len() - suffix],
+        &b[prefix..b.len() - suffix],
+    ));
+    ops.extend(a[a.len() - suffix..].iter().map(|t| (DiffOp::Equal, *t)));
+
+    coalesce(ops)
+}
+
+/// Split text into words, whitespace runs, and single other characters
+fn tokenize(text: &str) -> Vec<&str> {
+    #[derive(PartialEq)]
+    enum Class {
+        Word,
+        Space,
+        Other,
+    }
+    let class = |c: char| {
+        if c.is_alphanumeric() {
+            Class::Word
+        } else if c.is_whitespace() {
+            Class::Space
+        } else {
+            Class::Other
+        }
+    };
+
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut current: Option<Class> = None;
+    for (i, c) in text.char_indices() {
+        let next = class(c);
+        let continues = match &current {
+            Some(prev) => *prev == next && next != Class::Other,
+            None => false,
+        };
+        if !continues {
+            if current.is_some() {
+                tokens.push(&text[start..i]);
+            }
+            start = i;
+            current = Some(next);
+        }
+    }
+    if current.is_some() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+/// Myers shortest edit script between two token sequences
+fn myers<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<(DiffOp, &'a str)> {
+    let n = a.len() as isize;
+    let m = b.len() as isize;
+    let max = n + m;
+    if max == 0 {
+        return Vec::new();
+    }
+
+    // v[k + max] = furthest x reached on diagonal k
+    let mut v = vec![0isize; 2 * max as usize + 2];
+    let at = |k: isize| (k + max) as usize;
+    // Snapshot of diagonals -d..=d before each round, for backtracking
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'search: for d in 0..=max {
+        trace.push(v[at(-d)..=at(d)].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+                v[at(k + 1)]
+            } else {
+                v[at(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[at(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, snapshot) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let get = |k: isize| snapshot[(k + d) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && get(k - 1) < get(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = if d == 0 { 0 } else { get(prev_k) };
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            ops.push((DiffOp::Equal, a[x as usize - 1]));
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            if x == prev_x {
+                ops.push((DiffOp::Insert, b[y as usize - 1]));
+            } else {
+                ops.push((DiffOp::Delete, a[x as usize - 1]));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    ops.reverse();
+    ops
+}
+
+/// Merge token ops into spans, deletions before insertions in each change
+fn coalesce(ops: Vec<(DiffOp, &str)>) -> Vec<DiffSpan> {
+    let mut spans: Vec<DiffSpan> = Vec::new();
+    let mut deleted = String::new();
+    let mut inserted = String::new();
+
+    let flush = |spans: &mut Vec<DiffSpan>, deleted: &mut String, inserted: &mut String| {
+        if !deleted.is_empty() {
+            spans.push(DiffSpan::new(DiffOp::Delete, std::mem::take(deleted)));
+        }
+        if !inserted.is_empty() {
+            spans.push(DiffSpan::new(DiffOp::Insert, std::mem::take(inserted)));
+        }
+    };
+
+    for (op, token) in ops {
+        match op {
+            DiffOp::Delete => deleted.push_str(token),
+            DiffOp::Insert => inserted.push_str(token),
+            DiffOp::Equal => {
+                flush(&mut spans, &mut deleted, &mut inserted);
+                match spans.last_mut() {
+                    Some(last) if last.op == DiffOp::Equal => last.text.push_str(token),
+                    _ => spans.push(DiffSpan::new(DiffOp::Equal, token)),
+                }
+            }
+        }
+    }
+    flush(&mut spans, &mut deleted, &mut inserted);
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans(list: &[(DiffOp, &str)]) -> Vec<DiffSpan> {
+        list.iter().map(|(op, t)| DiffSpan::new(*op, *t)).collect()
+    }
+
+    #[test]
+    fn test_pure_insertion() {
+        assert_eq!(
+            diff_words("Dark matter halos.", "Dark matter halos grow slowly."),
+            spans(&[
+                (DiffOp::Equal, "Dark matter halos"),
+                (DiffOp::Insert, " grow slowly"),
+                (DiffOp::Equal, "."),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_deletion() {
+        assert_eq!(
+            diff_words(
+                "We clearly show that halos grow.",
+                "We show that halos grow."
+            ),
+            spans(&[
+                (DiffOp::Equal, "We "),
+                (DiffOp::Delete, "clearly "),
+                (DiffOp::Equal, "show that halos grow."),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_word_substitution() {
+        let diff = diff_words("the quick brown fox", "the slow brown fox");
+        assert_eq!(
+            diff,
+            spans(&[
+                (DiffOp::Equal, "the "),
+                (DiffOp::Delete, "quick"),
+                (DiffOp::Insert, "slow"),
+                (DiffOp::Equal, " brown fox"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_spans_reconstruct_both_versions() {
+        let old = "First paragraph.\n\nSecond paragraph, with detail.";
+        let new = "Second paragraph, with more detail.\n\nFirst paragraph.";
+        let diff = diff_words(old, new);
+        let rebuild = |skip: DiffOp| {
+            diff.iter()
+                .filter(|s| s.op != skip)
+                .map(|s| s.text.as_str())
+                .collect::<String>()
+        };
+        assert_eq!(rebuild(DiffOp::Insert), old);
+        assert_eq!(rebuild(DiffOp::Delete), new);
+    }
+}
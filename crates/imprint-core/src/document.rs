@@ -34,12 +34,16 @@
 //! doc.cycle_edit_mode();
 //! ```
 
+use crate::collaboration::COMMENTS_KEY;
+use crate::diff::{diff_words, DiffSpan};
+use crate::outline::{parse_outline, OutlineEntry};
 use crate::selection::{Selection, SelectionSet};
 use crate::sourcemap::{RenderPosition, SourceMap};
 use crate::transaction::Transaction;
 use automerge::transaction::Transactable;
-use automerge::{AutoCommit, ObjType, ReadDoc};
+use automerge::{AutoCommit, Cursor, ObjType, ReadDoc};
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 use thiserror::Error;
 
 /// Errors that can occur during document operations
@@ -56,6 +60,10 @@ pub enum DocumentError {
     /// Position out of bounds
     #[error("Position {0} is out of bounds (max: {1})")]
     OutOfBounds(usize, usize),
+
+    /// A stored text anchor could not be decoded
+    #[error("Invalid text anchor: {0}")]
+    InvalidAnchor(String),
 }
 
 /// Result type for document operations
@@ -177,6 +185,20 @@ impl Default for DocumentMetadata {
     }
 }
 
+/// A span of document text that follows later edits
+///
+/// Stores Automerge cursors for the first and last character of the span,
+/// so text inserted or deleted around it (locally or by a collaborator)
+/// shifts the span instead of invalidating it. Resolve it back to character
+/// offsets with [`ImprintDocument::resolve_anchor`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextAnchor {
+    /// Encoded cursor at the first character
+    start: String,
+    /// Encoded cursor at the last character
+    end: String,
+}
+
 /// A CRDT-based document for collaborative academic writing
 ///
 /// `ImprintDocument` wraps an Automerge document to provide a high-level API
@@ -207,6 +229,10 @@ impl ImprintDocument {
             .expect("failed to create content object in new document");
         doc.put_object(automerge::ROOT, "citations", ObjType::List)
             .expect("failed to create citations list in new document");
+        // Created up front so peers forked from this document share one
+        // comments map instead of each creating their own
+        doc.put_object(automerge::ROOT, COMMENTS_KEY, ObjType::Map)
+            .expect("failed to create comments map in new document");
 
         Self {
             doc,
@@ -264,6 +290,25 @@ impl ImprintDocument {
         }
     }
 
+    /// Headings of the document's Typst source, in order
+    ///
+    /// Offsets are character positions, usable with `move_cursor`.
+    pub fn outline(&self) -> Vec<OutlineEntry> {
+        self.text()
+            .map(|text| parse_outline(&text))
+            .unwrap_or_default()
+    }
+
+    /// Word-level changes from this document's text to `other`'s
+    ///
+    /// `self` is treated as the earlier draft. See [`crate::diff`] for the
+    /// span format.
+    pub fn diff(&self, other: &ImprintDocument) -> Vec<DiffSpan> {
+        let old = self.text().unwrap_or_default();
+        let new = other.text().unwrap_or_default();
+        diff_words(&old, &new)
+    }
+
     /// Insert text at the given position
     pub fn insert_text(&mut self, pos: usize, text: &str) -> DocumentResult<()> {
         if let Some(ref content_id) = self.content_id {
@@ -282,6 +327,55 @@ impl ImprintDocument {
         Ok(())
     }
 
+    /// Anchor a non-empty character range so it tracks subsequent edits
+    pub fn anchor_range(&self, range: Range<usize>) -> DocumentResult<TextAnchor> {
+        let content_id = self.content_object()?;
+        let len = self.doc.length(content_id);
+        if range.start >= range.end || range.end > len {
+            return Err(DocumentError::OutOfBounds(range.end, len));
+        }
+        let start = self.doc.get_cursor(content_id, range.start, None)?;
+        let end = self.doc.get_cursor(content_id, range.end - 1, None)?;
+        Ok(TextAnchor {
+            start: start.to_string(),
+            end: end.to_string(),
+        })
+    }
+
+    /// Current character range of an anchor
+    ///
+    /// Deleted text at either end shrinks the range; the range is empty once
+    /// all of the anchored text has been deleted.
+    pub fn resolve_anchor(&self, anchor: &TextAnchor) -> DocumentResult<Range<usize>> {
+        let content_id = self.content_object()?;
+        let (start, _) = self.cursor_position(content_id, &anchor.start)?;
+        let (end, end_alive) = self.cursor_position(content_id, &anchor.end)?;
+        // A deleted character resolves to where it would be, i.e. the index
+        // of the next surviving character
+        let end = if end_alive { end + 1 } else { end };
+        Ok(start..end.max(start))
+    }
+
+    /// Position of an encoded cursor, and whether its character still exists
+    fn cursor_position(
+        &self,
+        content_id: &automerge::ObjId,
+        encoded: &str,
+    ) -> DocumentResult<(usize, bool)> {
+        let cursor =
+            Cursor::try_from(encoded).map_err(|e| DocumentError::InvalidAnchor(e.to_string()))?;
+        let position = self.doc.get_cursor_position(content_id, &cursor, None)?;
+        let alive = position < self.doc.length(content_id)
+            && self.doc.get_cursor(content_id, position, None)?.to_string() == encoded;
+        Ok((position, alive))
+    }
+
+    fn content_object(&self) -> DocumentResult<&automerge::ObjId> {
+        self.content_id
+            .as_ref()
+            .ok_or_else(|| DocumentError::InvalidStructure("missing content text".to_string()))
+    }
+
     /// Get changes since a given set of heads (for sync)
     pub fn get_changes_since(&mut self, heads: &[automerge::ChangeHash]) -> Vec<u8> {
         self.doc.save_after(heads)
@@ -458,6 +552,7 @@ impl Default for ImprintDocument {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::diff::DiffOp;
 
     #[test]
     fn test_new_document() {
@@ -481,4 +576,52 @@ mod tests {
         let loaded = ImprintDocument::from_bytes(&bytes).unwrap();
         assert_eq!(loaded.text().unwrap(), "Test content");
     }
+
+    #[test]
+    fn test_anchor_follows_edits() {
+        let mut doc = ImprintDocument::new();
+        doc.insert_text(0, "Dark matter halos").unwrap();
+        let anchor = doc.anchor_range(5..11).unwrap();
+
+        doc.insert_text(0, "Simulated ").unwrap();
+        let range = doc.resolve_anchor(&anchor).unwrap();
+        assert_eq!(range, 15..21);
+        assert_eq!(&doc.text().unwrap()[range], "matter");
+
+        doc.delete_text(15, 6).unwrap();
+        assert!(doc.resolve_anchor(&anchor).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_diff_between_drafts() {
+        let mut draft = ImprintDocument::new();
+        draft.insert_text(0, "Halos grow slowly.").unwrap();
+        let mut revised = ImprintDocument::new();
+        revised.insert_text(0, "Halos grow quickly.").unwrap();
+
+        let diff = draft.diff(&revised);
+        let summary: Vec<(DiffOp, &str)> = diff.iter().map(|s| (s.op, s.text.as_str())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (DiffOp::Equal, "Halos grow "),
+                (DiffOp::Delete, "slowly"),
+                (DiffOp::Insert, "quickly"),
+                (DiffOp::Equal, "."),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_outline_tracks_edits() {
+        let mut doc = ImprintDocument::new();
+        doc.insert_text(0, "= Intro\nText\n== Data\n").unwrap();
+        let outline = doc.outline();
+        assert_eq!(outline.len(), 2);
+        assert_eq!(outline[1].offset, 13);
+        assert_eq!(outline[1].parent, Some(0));
+
+        doc.insert_text(8, "More ").unwrap();
+        assert_eq!(doc.outline()[1].offset, 18);
+    }
 }
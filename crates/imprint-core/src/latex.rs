@@ -457,10 +457,24 @@ const TYPST_TO_LATEX_MAPPINGS: &[(&str, &str)] = &[
     ("omega", "\\omega"),
 ];
 
+/// How converted notes are placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FootnoteMode {
+    /// Notes stay footnotes (`\footnote{}` ↔ `#footnote[]`)
+    #[default]
+    Footnote,
+    /// Notes become endnotes: numbered markers with the note text collected
+    /// in a "Notes" section (Typst) or `\endnote{}` with the endnotes package
+    /// (LaTeX)
+    Endnote,
+}
+
 /// LaTeX ↔ Typst converter.
 pub struct LatexConverter {
     /// Minimum confidence for auto-conversion
     auto_convert_threshold: Confidence,
+    /// Footnote or endnote output
+    footnote_mode: FootnoteMode,
 }
 
 impl LatexConverter {
@@ -468,6 +482,7 @@ impl LatexConverter {
     pub fn new() -> Self {
         Self {
             auto_convert_threshold: Confidence::Equivalent,
+            footnote_mode: FootnoteMode::Footnote,
         }
     }
 
@@ -477,6 +492,12 @@ impl LatexConverter {
         self
     }
 
+    /// Set whether notes are converted to footnotes or endnotes.
+    pub fn with_footnote_mode(mut self, mode: FootnoteMode) -> Self {
+        self.footnote_mode = mode;
+        self
+    }
+
     /// Check if the input looks like LaTeX.
     ///
     /// Returns true if the input contains common LaTeX patterns.
@@ -565,6 +586,14 @@ impl LatexConverter {
             if let Some((arg_end, arg)) = self.extract_braced_arg(source, command_end) {
                 let latex_text = &source[start..arg_end];
 
+                // Convert formatting inside the note; a note within a note
+                // can't be typeset, so its text stays inline
+                let arg = if mapping.pattern == r"\footnote" {
+                    inline_footnotes(&self.convert_latex(&arg).output)
+                } else {
+                    arg
+                };
+
                 // Build Typst replacement
                 let typst_text = if mapping.replacement.ends_with('*')
                     || mapping.replacement.ends_with('_')
@@ -630,7 +659,19 @@ impl LatexConverter {
     }
 
     /// Convert a LaTeX fragment to Typst.
+    ///
+    /// In [`FootnoteMode::Endnote`] the notes are numbered in place and
+    /// collected in a "Notes" section at the end of the fragment.
     pub fn latex_to_typst(&self, latex: &str) -> ConversionResult {
+        let mut result = self.convert_latex(latex);
+        if self.footnote_mode == FootnoteMode::Endnote {
+            result.output = footnotes_to_endnotes(&result.output);
+        }
+        result
+    }
+
+    /// Apply all detected conversions, leaving notes as `#footnote[]`.
+    fn convert_latex(&self, latex: &str) -> ConversionResult {
         let suggestions = self.detect_latex(latex);
 
         if suggestions.is_empty() {
@@ -674,12 +715,18 @@ impl LatexConverter {
         result.push_str("\\usepackage{amssymb}\n");
         result.push_str("\\usepackage{graphicx}\n");
         result.push_str("\\usepackage{hyperref}\n");
+        if self.footnote_mode == FootnoteMode::Endnote {
+            result.push_str("\\usepackage{endnotes}\n");
+        }
         result.push_str("\n\\begin{document}\n\n");
 
         // Convert content
         let converted = self.convert_typst_content(source)?;
         result.push_str(&converted);
 
+        if self.footnote_mode == FootnoteMode::Endnote {
+            result.push_str("\n\\theendnotes\n");
+        }
         result.push_str("\n\\end{document}\n");
 
         Ok(result)
@@ -764,19 +811,28 @@ impl LatexConverter {
 
                 // Typst functions
                 '#' => {
-                    let func: String = chars
-                        .by_ref()
-                        .take_while(|&c| c.is_alphanumeric() || c == '-')
-                        .collect();
+                    // Peek so the `[` after the name isn't consumed
+                    let mut func = String::new();
+                    while let Some(&c) = chars.peek() {
+                        if !(c.is_alphanumeric() || c == '-') {
+                            break;
+                        }
+                        func.push(c);
+                        chars.next();
+                    }
 
                     match func.as_str() {
                         "footnote" => {
-                            // Skip [ and read content until ]
+                            // Convert the bracketed body, keeping nested notes inline
                             if chars.peek() == Some(&'[') {
                                 chars.next();
-                                let content: String =
-                                    chars.by_ref().take_while(|&c| c != ']').collect();
-                                result.push_str(&format!("\\footnote{{{}}}", content));
+                                let body = inline_footnotes(&take_bracketed(&mut chars));
+                                let content = self.convert_typst_content(&body)?;
+                                let command = match self.footnote_mode {
+                                    FootnoteMode::Footnote => "footnote",
+                                    FootnoteMode::Endnote => "endnote",
+                                };
+                                result.push_str(&format!("\\{}{{{}}}", command, content));
                             }
                         }
                         "underline" => {
@@ -884,6 +940,75 @@ fn ranges_overlap(a: &Range<usize>, b: &Range<usize>) -> bool {
     a.start < b.end && b.start < a.end
 }
 
+/// Read up to the `]` matching an already-consumed `[`, returning the body.
+fn take_bracketed(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut body = String::new();
+    let mut depth = 1;
+    let mut escaped = false;
+    for c in chars.by_ref() {
+        if escaped {
+            escaped = false;
+        } else {
+            match c {
+                '\\' => escaped = true,
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        body.push(c);
+    }
+    body
+}
+
+/// Replace each top-level `#footnote[...]` in Typst source with `replace(body)`.
+fn map_footnotes(source: &str, mut replace: impl FnMut(&str) -> String) -> String {
+    const OPEN: &str = "#footnote[";
+    let mut result = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find(OPEN) {
+        let body_start = start + OPEN.len();
+        let mut chars = rest[body_start..].chars().peekable();
+        let body = take_bracketed(&mut chars);
+        // An unclosed note is left as written
+        let body_end = body_start + body.len();
+        if !rest[body_end..].starts_with(']') {
+            break;
+        }
+        result.push_str(&rest[..start]);
+        result.push_str(&replace(&body));
+        rest = &rest[body_end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Turn footnotes into parenthesized inline text.
+fn inline_footnotes(source: &str) -> String {
+    map_footnotes(source, |body| format!(" ({})", inline_footnotes(body)))
+}
+
+/// Number footnotes in place and collect their text in a "Notes" section.
+fn footnotes_to_endnotes(source: &str) -> String {
+    let mut notes = Vec::new();
+    let mut result = map_footnotes(source, |body| {
+        notes.push(body.to_string());
+        format!("#super[{}]", notes.len())
+    });
+    if !notes.is_empty() {
+        result.push_str("\n\n= Notes\n\n");
+        for note in &notes {
+            result.push_str(&format!("+ {}\n", note));
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1002,4 +1127,47 @@ mod tests {
         let result = converter.apply_suggestions(source, &suggestions);
         assert_eq!(result, "*bold*");
     }
+
+    #[test]
+    fn test_footnote_round_trip() {
+        let converter = LatexConverter::new();
+        let latex = r"Halos grow slowly.\footnote{See \textit{Smith} for [details].} Then merge.";
+        let typst = "Halos grow slowly.#footnote[See _Smith_ for [details].] Then merge.";
+
+        assert_eq!(converter.latex_to_typst(latex).output, typst);
+        assert_eq!(converter.convert_typst_content(typst).unwrap(), latex);
+    }
+
+    #[test]
+    fn test_nested_footnote_inline() {
+        let converter = LatexConverter::new();
+        assert_eq!(
+            converter
+                .latex_to_typst(r"Text.\footnote{Outer\footnote{inner} note.}")
+                .output,
+            "Text.#footnote[Outer (inner) note.]"
+        );
+        assert_eq!(
+            converter
+                .convert_typst_content("Text.#footnote[Outer#footnote[inner] note.]")
+                .unwrap(),
+            r"Text.\footnote{Outer (inner) note.}"
+        );
+    }
+
+    #[test]
+    fn test_endnote_mode() {
+        let converter = LatexConverter::new().with_footnote_mode(FootnoteMode::Endnote);
+        let result =
+            converter.latex_to_typst(r"One.\footnote{First} Two.\footnote{\textbf{Second}}");
+        assert_eq!(
+            result.output,
+            "One.#super[1] Two.#super[2]\n\n= Notes\n\n+ First\n+ *Second*\n"
+        );
+
+        let latex = converter.typst_to_latex("One.#footnote[First]").unwrap();
+        assert!(latex.contains("\\usepackage{endnotes}"));
+        assert!(latex.contains("One.\\endnote{First}"));
+        assert!(latex.contains("\\theendnotes"));
+    }
 }
@@ -4,6 +4,7 @@
 //!
 //! - **Document**: CRDT-based document representation with Automerge for conflict-free
 //!   collaborative editing
+//! - **Diff**: Word-level differences between drafts for track-changes views
 //! - **Selection**: Multi-cursor selection support (Helix-inspired)
 //! - **Transaction**: Atomic editing operations with undo/redo and CRDT transform support
 //! - **SourceMap**: Bidirectional mapping between Typst source and PDF output for direct
@@ -14,6 +15,7 @@
 //! - **Citations**: Trait-based citation provider system for flexible reference management
 //! - **Collaboration**: Real-time sync and presence tracking for multi-user editing
 //! - **Note Import**: Import annotations and highlights from PDF readers (imbib)
+//! - **Outline**: Heading outline of the Typst source for navigation
 //! - **Render**: Typst-based document rendering (requires `typst-render` feature)
 //!
 //! # Edit Modes
@@ -29,10 +31,12 @@ pub mod bibliography;
 pub mod citation_lookup;
 pub mod citations;
 pub mod collaboration;
+pub mod diff;
 pub mod document;
 pub mod latex;
 pub mod migration;
 pub mod note_import;
+pub mod outline;
 pub mod render;
 pub mod render_project;
 pub mod selection;
@@ -45,10 +49,12 @@ pub use bibliography::*;
 pub use citation_lookup::*;
 pub use citations::*;
 pub use collaboration::*;
+pub use diff::*;
 pub use document::*;
 pub use latex::*;
 pub use migration::*;
 pub use note_import::*;
+pub use outline::*;
 pub use render::*;
 pub use selection::*;
 pub use sourcemap::*;
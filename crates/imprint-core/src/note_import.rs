@@ -9,6 +9,7 @@
 //! - **Note conversion**: Convert annotations to document content
 //! - **Citation linking**: Automatically link imported notes to their source publications
 //! - **Batch import**: Import annotations from multiple PDFs at once
+//! - **Text anchoring**: Tie highlights to the manuscript passage they quote
 //! - **Multiple output formats**: Markdown and Typst output
 //!
 //! # Supported Formats
@@ -29,8 +30,10 @@
 //! let content = importer.import_as_section(&annotations, Some(&options))?;
 //! ```
 
+use crate::document::{DocumentError, ImprintDocument, TextAnchor};
 use impress_domain::{Annotation, AnnotationType, Publication};
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 use thiserror::Error;
 
 /// Errors that can occur during note import
@@ -47,6 +50,10 @@ pub enum ImportError {
     /// Publication not found for linking
     #[error("Publication not found: {0}")]
     PublicationNotFound(String),
+
+    /// Error reading the target document
+    #[error("Document error: {0}")]
+    Document(#[from] DocumentError),
 }
 
 /// Result type for import operations
@@ -190,6 +197,33 @@ impl ImportOptions {
     }
 }
 
+/// Minimum word overlap for a fuzzy quote match to be accepted
+const FUZZY_MATCH_THRESHOLD: f64 = 0.75;
+
+/// How well an anchored note's quote matched the document text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AnchorMatch {
+    /// The quote appears verbatim
+    Exact,
+    /// The quote matched after normalization or by word overlap (0.0–1.0)
+    Fuzzy { score: f64 },
+}
+
+/// Where an imported note is attached in the document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub enum NotePlacement {
+    /// Inserted as content; not tied to any passage (default)
+    #[default]
+    Inline,
+    /// Anchored to the passage its quote was found at
+    Anchored {
+        anchor: TextAnchor,
+        quality: AnchorMatch,
+    },
+    /// Quote couldn't be located; shown as an unanchored margin note
+    Margin,
+}
+
 /// An imported note ready for insertion into a document
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportedNote {
@@ -210,6 +244,14 @@ pub struct ImportedNote {
 
     /// Original annotation ID for reference
     pub annotation_id: Option<String>,
+
+    /// Text the annotation marks in the PDF, used to anchor it
+    #[serde(default)]
+    pub quote: Option<String>,
+
+    /// Where the note is attached in the document
+    #[serde(default)]
+    pub placement: NotePlacement,
 }
 
 impl ImportedNote {
@@ -229,6 +271,8 @@ impl ImportedNote {
             source_publication: None,
             citation_key: None,
             annotation_id: Some(annotation.id.clone()),
+            quote: annotation.selected_text.clone(),
+            placement: NotePlacement::Inline,
         }
     }
 
@@ -243,8 +287,22 @@ impl ImportedNote {
         note
     }
 
+    /// Current document range of an anchored note
+    pub fn anchor_range(&self, document: &ImprintDocument) -> ImportResult<Option<Range<usize>>> {
+        match &self.placement {
+            NotePlacement::Anchored { anchor, .. } => Ok(Some(document.resolve_anchor(anchor)?)),
+            _ => Ok(None),
+        }
+    }
+
     /// Format the note for document insertion
     pub fn format(&self, options: &ImportOptions) -> String {
+        if self.placement == NotePlacement::Margin
+            && options.output_format == NoteOutputFormat::Typst
+            && !self.content.is_empty()
+        {
+            return format!("#margin-note[{}]", self.escape_typst(&self.content));
+        }
         match options.output_format {
             NoteOutputFormat::Markdown => self.format_markdown(options),
             NoteOutputFormat::Typst => self.format_typst(options),
@@ -466,6 +524,51 @@ impl NoteImporter {
             .collect()
     }
 
+    /// Anchor each note's quote to the passage it matches in `document`
+    ///
+    /// Quotes are matched verbatim first, then ignoring case and whitespace
+    /// differences (PDF text extraction rarely preserves line breaks), then
+    /// by word overlap. Matched notes become [`NotePlacement::Anchored`] and
+    /// follow later edits; notes whose quote can't be found become
+    /// [`NotePlacement::Margin`] and produce a warning. Notes without a quote
+    /// are left as they are. Returns the warnings.
+    pub fn anchor_notes(
+        &self,
+        document: &ImprintDocument,
+        notes: &mut [ImportedNote],
+    ) -> ImportResult<Vec<String>> {
+        let text = document.text()?;
+        let mut warnings = Vec::new();
+
+        for note in notes.iter_mut() {
+            let quote = match note.quote.as_deref().map(str::trim) {
+                Some(quote) if !quote.is_empty() => quote,
+                _ => continue,
+            };
+            match locate_quote(&text, quote) {
+                Some((range, quality)) => {
+                    note.placement = NotePlacement::Anchored {
+                        anchor: document.anchor_range(range)?,
+                        quality,
+                    };
+                }
+                None => {
+                    let page = note
+                        .page
+                        .map(|p| format!(" (p. {})", p))
+                        .unwrap_or_default();
+                    warnings.push(format!(
+                        "Could not locate \"{}\"{} in the document; added as a margin note",
+                        quote, page
+                    ));
+                    note.placement = NotePlacement::Margin;
+                }
+            }
+        }
+
+        Ok(warnings)
+    }
+
     /// Filter annotations based on options
     fn filter_annotations<'a>(
         &self,
@@ -657,6 +760,109 @@ impl NoteImporter {
     }
 }
 
+/// Find `quote` in `text`, returning its character range and match quality
+fn locate_quote(text: &str, quote: &str) -> Option<(Range<usize>, AnchorMatch)> {
+    if let Some(byte_start) = text.find(quote) {
+        let start = text[..byte_start].chars().count();
+        return Some((start..start + quote.chars().count(), AnchorMatch::Exact));
+    }
+
+    // Ignore case and whitespace differences, mapping back to original offsets
+    let (normalized_text, offsets) = normalize(text);
+    let (normalized_quote, _) = normalize(quote);
+    let normalized_quote = normalized_quote.trim();
+    if !normalized_quote.is_empty() {
+        if let Some(byte_start) = normalized_text.find(normalized_quote) {
+            let first = normalized_text[..byte_start].chars().count();
+            let last = first + normalized_quote.chars().count() - 1;
+            return Some((
+                offsets[first]..offsets[last] + 1,
+                AnchorMatch::Fuzzy { score: 1.0 },
+            ));
+        }
+    }
+
+    fuzzy_word_match(text, quote)
+}
+
+/// Lowercase `text` and collapse whitespace runs to one space, with the
+/// original character offset of each output character
+fn normalize(text: &str) -> (String, Vec<usize>) {
+    let mut out = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len());
+    let mut in_space = false;
+    for (i, c) in text.chars().enumerate() {
+        if c.is_whitespace() {
+            if !in_space {
+                out.push(' ');
+                offsets.push(i);
+            }
+            in_space = true;
+        } else {
+            for lower in c.to_lowercase() {
+                out.push(lower);
+                offsets.push(i);
+            }
+            in_space = false;
+        }
+    }
+    (out, offsets)
+}
+
+/// Words of `text` (lowercased, alphanumeric only) with their character ranges
+fn words(text: &str) -> Vec<(String, Range<usize>)> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut start = 0;
+    for (i, c) in text.chars().enumerate() {
+        if c.is_alphanumeric() {
+            if current.is_empty() {
+                start = i;
+            }
+            current.extend(c.to_lowercase());
+        } else if !current.is_empty() {
+            words.push((std::mem::take(&mut current), start..i));
+        }
+    }
+    if !current.is_empty() {
+        words.push((current, start..text.chars().count()));
+    }
+    words
+}
+
+/// Best window of document words sharing most of the quote's words
+fn fuzzy_word_match(text: &str, quote: &str) -> Option<(Range<usize>, AnchorMatch)> {
+    let quote_words: Vec<String> = words(quote).into_iter().map(|(w, _)| w).collect();
+    let text_words = words(text);
+    let n = quote_words.len();
+    if n == 0 || text_words.len() < n {
+        return None;
+    }
+
+    let mut best: Option<(usize, f64)> = None;
+    for (start, window) in text_words.windows(n).enumerate() {
+        let mut remaining: Vec<&str> = window.iter().map(|(w, _)| w.as_str()).collect();
+        let mut shared = 0;
+        for word in &quote_words {
+            if let Some(pos) = remaining.iter().position(|w| w == word) {
+                remaining.swap_remove(pos);
+                shared += 1;
+            }
+        }
+        let score = shared as f64 / n as f64;
+        if !matches!(best, Some((_, s)) if s >= score) {
+            best = Some((start, score));
+        }
+    }
+
+    let (start, score) = best?;
+    if score < FUZZY_MATCH_THRESHOLD {
+        return None;
+    }
+    let range = text_words[start].1.start..text_words[start + n - 1].1.end;
+    Some((range, AnchorMatch::Fuzzy { score }))
+}
+
 /// Batch import result for multiple publications
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -796,6 +1002,8 @@ mod tests {
             source_publication: None,
             citation_key: Some("smith2023".to_string()),
             annotation_id: None,
+            quote: None,
+            placement: NotePlacement::Inline,
         };
 
         let options = ImportOptions::markdown();
@@ -815,6 +1023,8 @@ mod tests {
             source_publication: None,
             citation_key: Some("smith2023".to_string()),
             annotation_id: None,
+            quote: None,
+            placement: NotePlacement::Inline,
         };
 
         let options = ImportOptions::typst().with_quote_style(QuoteStyle::Block);
@@ -834,6 +1044,8 @@ mod tests {
             source_publication: None,
             citation_key: None,
             annotation_id: None,
+            quote: None,
+            placement: NotePlacement::Inline,
         };
 
         let options = ImportOptions::typst().with_quote_style(QuoteStyle::MarginNote);
@@ -852,6 +1064,8 @@ mod tests {
             source_publication: None,
             citation_key: Some("jones2020".to_string()),
             annotation_id: None,
+            quote: None,
+            placement: NotePlacement::Inline,
         };
 
         let options = ImportOptions::typst().with_quote_style(QuoteStyle::Inline);
@@ -945,6 +1159,8 @@ mod tests {
             source_publication: None,
             citation_key: None,
             annotation_id: None,
+            quote: None,
+            placement: NotePlacement::Inline,
         };
 
         let options = ImportOptions::typst();
@@ -953,4 +1169,67 @@ mod tests {
         assert!(formatted.contains("\\$"));
         assert!(formatted.contains("\\@"));
     }
+
+    #[test]
+    fn test_anchor_exact_match_follows_edits() {
+        let mut document = ImprintDocument::new();
+        document
+            .insert_text(0, "We report an Important finding about X in our data.")
+            .unwrap();
+        let importer = NoteImporter::new();
+        let mut notes = vec![ImportedNote::from_annotation(&sample_highlight())];
+
+        let warnings = importer.anchor_notes(&document, &mut notes).unwrap();
+        assert!(warnings.is_empty());
+        match &notes[0].placement {
+            NotePlacement::Anchored { quality, .. } => assert_eq!(*quality, AnchorMatch::Exact),
+            other => panic!("expected anchored note, got {:?}", other),
+        }
+        assert_eq!(notes[0].anchor_range(&document).unwrap(), Some(13..38));
+
+        document.insert_text(0, "Abstract. ").unwrap();
+        let range = notes[0].anchor_range(&document).unwrap().unwrap();
+        let text: String = document
+            .text()
+            .unwrap()
+            .chars()
+            .skip(range.start)
+            .take(range.len())
+            .collect();
+        assert_eq!(text, "Important finding about X");
+    }
+
+    #[test]
+    fn test_anchor_fuzzy_match_across_line_break() {
+        let text = "an important\n  finding about X here";
+        let (range, quality) = locate_quote(text, "Important finding about X").unwrap();
+        assert_eq!(range, 3..30);
+        assert_eq!(quality, AnchorMatch::Fuzzy { score: 1.0 });
+
+        let (_, quality) = locate_quote(text, "important finding abut X").unwrap();
+        assert_eq!(quality, AnchorMatch::Fuzzy { score: 0.75 });
+    }
+
+    #[test]
+    fn test_anchor_no_match_becomes_margin_note() {
+        let mut document = ImprintDocument::new();
+        document
+            .insert_text(0, "Nothing in this manuscript quotes the paper.")
+            .unwrap();
+        let importer = NoteImporter::new();
+        let mut notes = vec![
+            ImportedNote::from_annotation(&sample_highlight()),
+            ImportedNote::from_annotation(&sample_note()),
+        ];
+
+        let warnings = importer.anchor_notes(&document, &mut notes).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Important finding about X"));
+        assert!(warnings[0].contains("(p. 42)"));
+        assert_eq!(notes[0].placement, NotePlacement::Margin);
+        assert_eq!(notes[1].placement, NotePlacement::Inline);
+
+        let formatted = notes[0].format(&ImportOptions::typst());
+        assert_eq!(formatted, "#margin-note[Important finding about X]");
+    }
 }
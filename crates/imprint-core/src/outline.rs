@@ -0,0 +1,183 @@
+//! Document outline extraction from Typst headings
+//!
+//! Typst headings are lines starting with one or more `=` followed by a
+//! space: `= Introduction`, `== Methods`, `=== Sample selection`. The outline
+//! lists them in source order with their level, text, and character offset,
+//! which the editor uses to jump the cursor when an outline item is clicked.
+//!
+//! Lines inside raw blocks (```` ``` ````) and block comments (`/* */`) are
+//! skipped, as are `//` line comments. A trailing label (`<intro>`) is not
+//! part of the heading text.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use imprint_core::outline::parse_outline;
+//!
+//! let outline = parse_outline("= Intro\n== Background\n");
+//! assert_eq!(outline[1].parent, Some(0));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// A heading in the document outline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct OutlineEntry {
+    /// Heading level (1 for `=`, 2 for `==`, ...)
+    pub level: u32,
+    /// Heading text without markers or label
+    pub text: String,
+    /// Character offset of the heading's first `=`
+    pub offset: u64,
+    /// Zero-based line number
+    pub line: u32,
+    /// Index of the enclosing heading in the outline, if any
+    pub parent: Option<u64>,
+}
+
+/// Extract the heading outline from Typst source
+pub fn parse_outline(source: &str) -> Vec<OutlineEntry> {
+    let mut entries: Vec<OutlineEntry> = Vec::new();
+    // Indices of the open headings, shallowest first
+    let mut stack: Vec<usize> = Vec::new();
+    let mut in_raw = false;
+    let mut in_comment = false;
+    let mut line_offset = 0usize;
+
+    for (line_number, line) in source.split('\n').enumerate() {
+        let line_chars = line.chars().count();
+        let trimmed = line.trim_start();
+        let start = line_offset + line_chars - trimmed.chars().count();
+        // +1 for the newline removed by split
+        line_offset += line_chars + 1;
+
+        if in_comment {
+            in_comment = !trimmed.contains("*/");
+        } else if let Some(after) = trimmed.strip_prefix("```") {
+            // Raw text that closes on the same line doesn't open a block
+            if in_raw || !after.contains("```") {
+                in_raw = !in_raw;
+            }
+        } else if in_raw {
+            continue;
+        } else if trimmed.starts_with("/*") {
+            in_comment = !trimmed.contains("*/");
+        } else if let Some((level, text)) = parse_heading(trimmed) {
+            while let Some(&open) = stack.last() {
+                if entries[open].level >= level {
+                    stack.pop();
+                } else {
+                    break;
+                }
+            }
+            entries.push(OutlineEntry {
+                level,
+                text,
+                offset: start as u64,
+                line: line_number as u32,
+                parent: stack.last().map(|&i| i as u64),
+            });
+            stack.push(entries.len() - 1);
+        }
+    }
+
+    entries
+}
+
+/// Level and text of a heading line (leading whitespace already removed)
+fn parse_heading(line: &str) -> Option<(u32, String)> {
+    let level = line.chars().take_while(|&c| c == '=').count();
+    if level == 0 {
+        return None;
+    }
+    let rest = &line[level..];
+    // `=` must be followed by whitespace (or end the line for an empty heading)
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let mut text = rest.trim();
+    if let Some(comment) = line_comment_start(text) {
+        text = text[..comment].trim_end();
+    }
+    if text.ends_with('>') {
+        if let Some(label) = text.rfind('<') {
+            text = text[..label].trim_end();
+        }
+    }
+    Some((level as u32, text.to_string()))
+}
+
+/// Byte offset of a trailing `//` comment
+///
+/// Only `//` at the start or after whitespace counts, so URLs like
+/// `https://x.org` stay part of the heading.
+fn line_comment_start(text: &str) -> Option<usize> {
+    text.match_indices("//").map(|(i, _)| i).find(|&i| {
+        text[..i]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_headings() {
+        let source = "= Introduction\n\
+                      Some text.\n\
+                      == Background <bg>\n\
+                      === Prior work\n\
+                      == Goals\n\
+                      ```\n\
+                      = not a heading\n\
+                      ```\n\
+                      a == b\n\
+                      = Methods\n";
+        let outline = parse_outline(source);
+
+        let summary: Vec<(u32, &str, Option<u64>)> = outline
+            .iter()
+            .map(|e| (e.level, e.text.as_str(), e.parent))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, "Introduction", None),
+                (2, "Background", Some(0)),
+                (3, "Prior work", Some(1)),
+                (2, "Goals", Some(0)),
+                (1, "Methods", None),
+            ]
+        );
+
+        for entry in &outline {
+            let at: String = source.chars().skip(entry.offset as usize).take(2).collect();
+            assert!(at.starts_with('='), "offset of {:?}", entry.text);
+        }
+        assert_eq!(outline[1].offset, 26);
+        assert_eq!(outline[1].line, 2);
+        assert_eq!(outline[4].line, 9);
+    }
+
+    #[test]
+    fn test_heading_comments() {
+        assert_eq!(
+            parse_heading("= See https://x.org"),
+            Some((1, "See https://x.org".to_string()))
+        );
+        assert_eq!(
+            parse_heading("== Results // draft"),
+            Some((2, "Results".to_string()))
+        );
+        assert_eq!(
+            parse_heading("= See https://x.org // link"),
+            Some((1, "See https://x.org".to_string()))
+        );
+        assert_eq!(parse_heading("= // todo"), Some((1, String::new())));
+    }
+}
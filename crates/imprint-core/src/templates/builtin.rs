@@ -3,8 +3,8 @@
 //! This module contains all templates bundled with imprint.
 
 use super::{
-    JournalInfo, PageDefaults, PageMargins, Template, TemplateCategory, TemplateMetadata,
-    TemplateSource, TypstRequirements,
+    JournalInfo, PageDefaults, PageMargins, Template, TemplateCategory, TemplateConstraints,
+    TemplateMetadata, TemplateSource, TypstRequirements,
 };
 
 /// Get all built-in templates
@@ -82,6 +82,7 @@ fn generic_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: Generic Article
@@ -225,6 +226,7 @@ fn mnras_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: MNRAS
@@ -375,6 +377,7 @@ fn apj_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: ApJ (Astrophysical Journal)
@@ -505,6 +508,7 @@ fn apjs_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     // ApJS uses same style as ApJ
@@ -632,6 +636,7 @@ fn jcap_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: JCAP
@@ -755,6 +760,7 @@ fn aa_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: A&A
@@ -890,6 +896,7 @@ fn araa_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: ARA&A
@@ -1009,6 +1016,7 @@ fn prd_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: Physical Review D
@@ -1138,6 +1146,10 @@ fn prl_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints {
+            max_pages: Some(4),
+            ..Default::default()
+        },
     };
 
     let typst_source = r##"// imprint template: Physical Review Letters
@@ -1256,6 +1268,7 @@ fn jhep_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: JHEP
@@ -1386,6 +1399,10 @@ fn neurips_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints {
+            max_pages: Some(9),
+            ..Default::default()
+        },
     };
 
     let typst_source = r##"// imprint template: NeurIPS
@@ -1511,6 +1528,10 @@ fn icml_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints {
+            max_pages: Some(8),
+            ..Default::default()
+        },
     };
 
     let typst_source = r##"// imprint template: ICML
@@ -1642,6 +1663,7 @@ fn jcp_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: Journal of Computational Physics
@@ -1765,6 +1787,7 @@ fn nature_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: Nature
@@ -1883,6 +1906,7 @@ fn science_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: Science
@@ -2015,6 +2039,7 @@ fn pnas_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: PNAS
@@ -2153,6 +2178,7 @@ fn plos_template() -> Template {
         exports: vec!["pdf".to_string(), "latex".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: PLOS ONE
@@ -2267,6 +2293,7 @@ fn elife_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: eLife
@@ -2388,6 +2415,7 @@ fn cell_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: Cell
@@ -2510,6 +2538,7 @@ fn nejm_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: NEJM
@@ -2626,6 +2655,7 @@ fn lancet_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: The Lancet
@@ -2740,6 +2770,7 @@ fn bmj_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: BMJ
@@ -2876,6 +2907,7 @@ fn jama_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: JAMA
@@ -3006,6 +3038,7 @@ fn bioinformatics_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: Bioinformatics
@@ -3141,6 +3174,7 @@ fn nature_medicine_template() -> Template {
         exports: vec!["pdf".to_string()],
         created_at: Some("2026-01-27".to_string()),
         modified_at: Some("2026-01-27".to_string()),
+        constraints: TemplateConstraints::default(),
     };
 
     let typst_source = r##"// imprint template: Nature Medicine
@@ -37,9 +37,11 @@
 
 mod builtin;
 mod parser;
+mod validate;
 
 pub use builtin::*;
 pub use parser::*;
+pub use validate::*;
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -167,6 +169,17 @@ impl Default for PageMargins {
     }
 }
 
+/// Submission constraints checked before export
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TemplateConstraints {
+    /// Maximum number of rendered pages
+    #[serde(default, rename = "maxPages")]
+    pub max_pages: Option<u32>,
+    /// Section headings the document must contain (case-insensitive)
+    #[serde(default, rename = "requiredSections")]
+    pub required_sections: Vec<String>,
+}
+
 /// Typst version requirements
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TypstRequirements {
@@ -218,6 +231,9 @@ pub struct TemplateMetadata {
     /// Last modification date (ISO 8601)
     #[serde(default, rename = "modifiedAt")]
     pub modified_at: Option<String>,
+    /// Submission constraints (page limit, required sections)
+    #[serde(default)]
+    pub constraints: TemplateConstraints,
 }
 
 fn default_version() -> String {
@@ -619,6 +635,7 @@ mod tests {
             exports: vec![],
             created_at: None,
             modified_at: None,
+            constraints: TemplateConstraints::default(),
         };
 
         let template = Template::new(metadata, "Title: ${title}\nAuthor: ${author}".to_string());
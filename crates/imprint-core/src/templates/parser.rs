@@ -159,7 +159,9 @@ pub fn validate_template(template: &Template) -> TemplateResult<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::templates::{PageDefaults, TemplateCategory, TypstRequirements};
+    use crate::templates::{
+        PageDefaults, TemplateCategory, TemplateConstraints, TypstRequirements,
+    };
     use tempfile::TempDir;
 
     #[test]
@@ -179,6 +181,7 @@ mod tests {
             exports: vec!["pdf".to_string()],
             created_at: None,
             modified_at: None,
+            constraints: TemplateConstraints::default(),
         };
 
         let template = Template {
@@ -227,6 +230,7 @@ mod tests {
             exports: vec![],
             created_at: None,
             modified_at: None,
+            constraints: TemplateConstraints::default(),
         };
 
         let template = Template::new(metadata.clone(), "= Hello".to_string());
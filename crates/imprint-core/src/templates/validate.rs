@@ -0,0 +1,249 @@
+//! Pre-export checks of a document against a template's constraints
+//!
+//! Validation never blocks an export: it returns warnings the UI can show
+//! next to the export button, each saying what to change.
+
+use super::{PageDefaults, Template};
+use crate::document::ImprintDocument;
+use crate::outline::parse_outline;
+use crate::render::{OutputFormat, PageSize, RenderOptions, RenderOutput, TypstRenderer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Points per millimeter
+const PT_PER_MM: f64 = 72.0 / 25.4;
+
+/// A template constraint the document doesn't meet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExportWarning {
+    /// The trial render is longer than the template allows
+    PageLimitExceeded { pages: u32, max_pages: u32 },
+    /// The page limit couldn't be checked (no renderer or render failed)
+    PageLimitUnchecked { max_pages: u32, reason: String },
+    /// A section the template requires has no matching heading
+    MissingSection { section: String },
+    /// The document sets a column count the template isn't laid out for
+    ColumnMismatch { document: u8, template: u8 },
+}
+
+impl ExportWarning {
+    /// Human-readable description with a suggested fix
+    pub fn message(&self) -> String {
+        match self {
+            Self::PageLimitExceeded { pages, max_pages } => format!(
+                "Document is {} pages but the template allows {}; shorten it by {} page{} or move material to an appendix",
+                pages,
+                max_pages,
+                pages - max_pages,
+                if pages - max_pages == 1 { "" } else { "s" }
+            ),
+            Self::PageLimitUnchecked { max_pages, reason } => format!(
+                "Could not check the {}-page limit ({}); check the page count after exporting",
+                max_pages, reason
+            ),
+            Self::MissingSection { section } => {
+                format!("Add a \"{}\" section, which the template requires", section)
+            }
+            Self::ColumnMismatch { document, template } => format!(
+                "Document sets {} column{} but the template is laid out in {}; remove the column override",
+                document,
+                if *document == 1 { "" } else { "s" },
+                template
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for ExportWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl Template {
+    /// Check a document against this template's constraints before export
+    ///
+    /// Checks required sections and column overrides against the source, and
+    /// the page limit (if any) with a trial render through `renderer`. All
+    /// problems are returned as warnings; an empty list means the document
+    /// fits the template.
+    pub fn validate_document(
+        &self,
+        document: &ImprintDocument,
+        renderer: &dyn TypstRenderer,
+    ) -> Vec<ExportWarning> {
+        let text = document.text().unwrap_or_default();
+        let mut warnings = Vec::new();
+
+        let headings: Vec<String> = parse_outline(&text)
+            .into_iter()
+            .map(|entry| entry.text.to_lowercase())
+            .collect();
+        for section in &self.metadata.constraints.required_sections {
+            if !headings.contains(&section.trim().to_lowercase()) {
+                warnings.push(ExportWarning::MissingSection {
+                    section: section.clone(),
+                });
+            }
+        }
+
+        let template_columns = self.metadata.page_defaults.columns;
+        if let Some(columns) = column_override(&text) {
+            if columns != template_columns {
+                warnings.push(ExportWarning::ColumnMismatch {
+                    document: columns,
+                    template: template_columns,
+                });
+            }
+        }
+
+        if let Some(max_pages) = self.metadata.constraints.max_pages {
+            match self.trial_page_count(&text, renderer) {
+                Ok(pages) if pages > max_pages => {
+                    warnings.push(ExportWarning::PageLimitExceeded { pages, max_pages });
+                }
+                Ok(_) => {}
+                Err(reason) => {
+                    warnings.push(ExportWarning::PageLimitUnchecked { max_pages, reason });
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Render the document with this template and count the pages
+    fn trial_page_count(&self, text: &str, renderer: &dyn TypstRenderer) -> Result<u32, String> {
+        if !renderer.is_available() {
+            return Err("rendering is not available".to_string());
+        }
+        let source = format!("{}\n\n{}", self.render_preamble(&HashMap::new()), text);
+        let options = render_options(&self.metadata.page_defaults);
+        match renderer.render(&source, &options) {
+            Ok(RenderOutput::Svg(pages)) => Ok(pages.len() as u32),
+            Ok(RenderOutput::Png(pages)) => Ok(pages.len() as u32),
+            Ok(RenderOutput::Pdf(_)) => Err("renderer did not produce pages".to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Render options matching the template's page defaults, one SVG per page
+fn render_options(defaults: &PageDefaults) -> RenderOptions {
+    let page_size = match defaults.size.to_lowercase().as_str() {
+        "letter" => PageSize::Letter,
+        "a5" => PageSize::A5,
+        _ => PageSize::A4,
+    };
+    let m = &defaults.margins;
+    RenderOptions {
+        page_size,
+        ..Default::default()
+    }
+    .with_format(OutputFormat::Svg)
+    .with_margins(
+        m.top * PT_PER_MM,
+        m.right * PT_PER_MM,
+        m.bottom * PT_PER_MM,
+        m.left * PT_PER_MM,
+    )
+    .with_font_size(defaults.font_size)
+}
+
+/// Column count set by a `#set page(columns: N)` rule in the source
+fn column_override(text: &str) -> Option<u8> {
+    text.lines()
+        .map(str::trim_start)
+        .filter(|line| line.starts_with("#set page("))
+        .filter_map(|line| {
+            let value = line.split("columns:").nth(1)?.trim_start();
+            let digits: String = value.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse().ok()
+        })
+        .last()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{RenderCache, RenderError};
+    use crate::templates::TemplateRegistry;
+
+    /// Renderer that produces a fixed number of blank pages
+    struct PagesRenderer(usize);
+
+    impl TypstRenderer for PagesRenderer {
+        fn render(
+            &self,
+            _source: &str,
+            _options: &RenderOptions,
+        ) -> Result<RenderOutput, RenderError> {
+            Ok(RenderOutput::Svg(vec![String::new(); self.0]))
+        }
+
+        fn render_incremental(
+            &self,
+            source: &str,
+            options: &RenderOptions,
+            cache: Option<RenderCache>,
+        ) -> Result<(RenderOutput, RenderCache), RenderError> {
+            Ok((self.render(source, options)?, cache.unwrap_or_default()))
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn typst_version(&self) -> &'static str {
+            "test"
+        }
+    }
+
+    #[test]
+    fn test_page_limit_warning() {
+        let registry = TemplateRegistry::new();
+        let prl = registry.get("prl").unwrap();
+        let mut document = ImprintDocument::new();
+        document.insert_text(0, "= Introduction\nText.\n").unwrap();
+
+        let warnings = prl.validate_document(&document, &PagesRenderer(6));
+        assert_eq!(
+            warnings,
+            vec![ExportWarning::PageLimitExceeded {
+                pages: 6,
+                max_pages: 4
+            }]
+        );
+        assert!(warnings[0].message().contains("shorten it by 2 pages"));
+
+        assert!(prl
+            .validate_document(&document, &PagesRenderer(4))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_sections_and_columns() {
+        let mut template = TemplateRegistry::new().get("generic").unwrap().clone();
+        template.metadata.page_defaults.columns = 1;
+        template.metadata.constraints.required_sections =
+            vec!["Introduction".to_string(), "Data Availability".to_string()];
+        let mut document = ImprintDocument::new();
+        document
+            .insert_text(0, "#set page(columns: 2)\n= introduction\n")
+            .unwrap();
+
+        let warnings = template.validate_document(&document, &PagesRenderer(1));
+        assert_eq!(
+            warnings,
+            vec![
+                ExportWarning::MissingSection {
+                    section: "Data Availability".to_string()
+                },
+                ExportWarning::ColumnMismatch {
+                    document: 2,
+                    template: 1
+                },
+            ]
+        );
+    }
+}